@@ -40,6 +40,18 @@ pub struct AtlasEntry {
     pub v1: f32,
 }
 
+/// How `atlas_packer` arranged the entries of an atlas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AtlasLayout {
+    /// Square `tile_size` cells on a `columns` × `rows` grid.
+    #[default]
+    Grid,
+    /// Sprites of any size packed onto shelves; `columns` and `rows` only
+    /// describe the widest shelf and the shelf count.
+    Shelf,
+}
+
 /// Metadata emitted by `atlas_packer` describing the full atlas layout.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TextureAtlasMetadata {
@@ -55,6 +67,13 @@ pub struct TextureAtlasMetadata {
     pub atlas_width: u32,
     /// Atlas height in pixels.
     pub atlas_height: u32,
+    /// Packing layout; metadata written before shelf packing is a grid.
+    #[serde(default)]
+    pub layout: AtlasLayout,
+    /// Packer hash of the source textures; compare against a cached value to
+    /// skip re-uploading an unchanged atlas. `0` for metadata without one.
+    #[serde(default)]
+    pub content_hash: u64,
     /// Entries for each texture.
     pub entries: Vec<AtlasEntry>,
 }
@@ -83,24 +102,37 @@ impl TextureAtlasMetadata {
                 "columns and rows must be greater than zero".into(),
             ));
         }
-        let stride = self.tile_size + self.padding * 2;
-        let expected_width = self.columns * stride;
-        let expected_height = self.rows * stride;
-        if self.atlas_width != expected_width {
-            return Err(AtlasError::Invalid(format!(
-                "atlas_width mismatch (got {}, expected {})",
-                self.atlas_width, expected_width
-            )));
-        }
-        if self.atlas_height != expected_height {
-            return Err(AtlasError::Invalid(format!(
-                "atlas_height mismatch (got {}, expected {})",
-                self.atlas_height, expected_height
-            )));
+        if self.layout == AtlasLayout::Grid {
+            let stride = self.tile_size + self.padding * 2;
+            let expected_width = self.columns * stride;
+            let expected_height = self.rows * stride;
+            if self.atlas_width != expected_width {
+                return Err(AtlasError::Invalid(format!(
+                    "atlas_width mismatch (got {}, expected {})",
+                    self.atlas_width, expected_width
+                )));
+            }
+            if self.atlas_height != expected_height {
+                return Err(AtlasError::Invalid(format!(
+                    "atlas_height mismatch (got {}, expected {})",
+                    self.atlas_height, expected_height
+                )));
+            }
         }
         let mut seen = HashSet::new();
         for entry in &self.entries {
-            if entry.width != self.tile_size || entry.height != self.tile_size {
+            let size_ok = match self.layout {
+                AtlasLayout::Grid => {
+                    entry.width == self.tile_size && entry.height == self.tile_size
+                }
+                AtlasLayout::Shelf => {
+                    entry.width > 0
+                        && entry.height > 0
+                        && entry.width <= self.tile_size
+                        && entry.height <= self.tile_size
+                }
+            };
+            if !size_ok {
                 return Err(AtlasError::Invalid(format!(
                     "entry {} has unexpected dimensions {}x{}",
                     entry.name, entry.width, entry.height
@@ -169,4 +201,27 @@ mod tests {
         let err = TextureAtlasMetadata::parse_str(json).unwrap_err();
         assert!(matches!(err, AtlasError::Invalid(_)));
     }
+
+    #[test]
+    fn shelf_layout_allows_mixed_sprite_sizes() {
+        let json = r#"{
+            "tile_size": 6,
+            "padding": 1,
+            "columns": 2,
+            "rows": 1,
+            "atlas_width": 16,
+            "atlas_height": 8,
+            "layout": "shelf",
+            "entries": [
+                {"name":"a","x":1,"y":1,"width":6,"height":2,"u0":0.0,"v0":0.0,"u1":0.4,"v1":0.4},
+                {"name":"b","x":9,"y":1,"width":3,"height":5,"u0":0.5,"v0":0.0,"u1":0.8,"v1":0.8}
+            ]
+        }"#;
+        let atlas = TextureAtlasMetadata::parse_str(json).unwrap();
+        assert_eq!(atlas.layout, AtlasLayout::Shelf);
+
+        let grid = json.replace(r#""layout": "shelf","#, "");
+        let err = TextureAtlasMetadata::parse_str(&grid).unwrap_err();
+        assert!(matches!(err, AtlasError::Invalid(_)));
+    }
 }
@@ -6,15 +6,16 @@ mod loader;
 mod recipe_registry;
 mod registry;
 
-pub use atlas::{AtlasEntry, AtlasError, TextureAtlasMetadata};
+pub use atlas::{AtlasEntry, AtlasError, AtlasLayout, TextureAtlasMetadata};
 pub use loader::{
     recipe_registry_from_file, recipe_registry_from_str, registry_from_file, registry_from_str,
 };
-pub use recipe_registry::RecipeRegistry;
 pub use recipe_registry::{parse_item_type, parse_item_type_with_blocks};
+pub use recipe_registry::{CraftingGrid, RecipeRegistry, ShapedPattern, CRAFTING_GRID_SIZE};
 pub use registry::{BlockDescriptor, BlockFace, BlockRegistry, HarvestLevel};
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use thiserror::Error;
 
 /// Minimal block definition used to sanity-check packs.
@@ -73,6 +74,13 @@ pub struct BlockDefinition {
     /// "diamond" = diamond tool required
     #[serde(default)]
     pub harvest_level: Option<String>,
+    /// Collision shape for blocks the engine has no built-in shape for.
+    ///
+    /// One of "full", "none", "slab", "stairs", "fence", "door", "ladder", or
+    /// "partial:<n>" for a shape `n` sixteenths tall. When omitted the block
+    /// uses its built-in shape, or a full cube.
+    #[serde(default)]
+    pub collision: Option<String>,
 }
 
 /// Errors emitted during pack loading.
@@ -96,6 +104,20 @@ pub enum AssetError {
     /// Validation error when parsing the light emission range.
     #[error("invalid light emission {0}; expected 0..=15")]
     InvalidLightEmission(u8),
+    /// Validation error when a harvest level is not a known tool tier.
+    #[error("invalid harvest level {0:?}; expected wood, stone, iron or diamond")]
+    InvalidHarvestLevel(String),
+    /// Validation error when a collision shape name is not recognised.
+    #[error("invalid collision shape {0:?}")]
+    InvalidCollisionShape(String),
+    /// Validation error when a block references a texture missing from the atlas.
+    #[error("block {block} references missing texture {texture:?}")]
+    MissingTexture {
+        /// Registry key of the offending block.
+        block: String,
+        /// Atlas entry name that failed to resolve.
+        texture: String,
+    },
 }
 
 /// Parse a JSON string into a list of blocks.
@@ -109,7 +131,19 @@ pub struct RecipeDefinition {
     /// Unique identifier for this recipe (e.g., "wooden_pickaxe")
     pub name: String,
     /// List of input items required (item type string + count)
+    ///
+    /// Ignored for shaped recipes, whose inputs are counted from `pattern`.
+    #[serde(default)]
     pub inputs: Vec<RecipeInput>,
+    /// Optional crafting grid rows (e.g., `["PPP", " S ", " S "]`).
+    ///
+    /// Each character is looked up in `key`; spaces are empty slots. When
+    /// omitted, the recipe is shapeless.
+    #[serde(default)]
+    pub pattern: Option<Vec<String>>,
+    /// Item type identifier for each pattern character.
+    #[serde(default)]
+    pub key: Option<HashMap<char, String>>,
     /// Output item produced
     pub output: RecipeOutput,
 }
@@ -1,7 +1,9 @@
 use std::fs;
 use std::path::Path;
 
-use crate::{AssetError, BlockDescriptor, BlockRegistry, RecipeRegistry};
+use crate::{
+    AssetError, BlockDescriptor, BlockRegistry, RecipeDefinition, RecipeRegistry, ShapedPattern,
+};
 use mdminecraft_core::Recipe;
 
 /// Load a block registry from the provided JSON file path.
@@ -27,9 +29,14 @@ pub fn recipe_registry_from_file(path: &Path) -> Result<RecipeRegistry, AssetErr
 }
 
 /// Load a recipe registry from an in-memory JSON string.
+///
+/// Recipes referencing unknown items, or with malformed patterns, are skipped.
 pub fn recipe_registry_from_str(input: &str) -> Result<RecipeRegistry, AssetError> {
     let defs = crate::load_recipes_from_str(input)?;
-    let recipes: Vec<(String, Recipe)> = defs
+    let (shaped, shapeless): (Vec<_>, Vec<_>) =
+        defs.into_iter().partition(|def| def.pattern.is_some());
+
+    let recipes: Vec<(String, Recipe)> = shapeless
         .into_iter()
         .filter_map(|def| {
             let inputs: Vec<_> = def
@@ -51,7 +58,40 @@ pub fn recipe_registry_from_str(input: &str) -> Result<RecipeRegistry, AssetErro
         })
         .collect();
 
-    Ok(RecipeRegistry::new(recipes))
+    let mut registry = RecipeRegistry::new(recipes);
+    for def in shaped {
+        let Some(pattern) = shaped_pattern(&def) else {
+            continue;
+        };
+        let Some(output) = crate::recipe_registry::parse_item_type(&def.output.item) else {
+            continue;
+        };
+        registry.insert_shaped(def.name, pattern, output, def.output.count);
+    }
+
+    Ok(registry)
+}
+
+/// Resolve a definition's `pattern` rows through its `key` map.
+fn shaped_pattern(def: &RecipeDefinition) -> Option<ShapedPattern> {
+    let key = def.key.as_ref();
+    let rows = def
+        .pattern
+        .as_ref()?
+        .iter()
+        .map(|row| {
+            row.chars()
+                .map(|symbol| match symbol {
+                    ' ' => Some(None),
+                    _ => key?
+                        .get(&symbol)
+                        .and_then(|item| crate::recipe_registry::parse_item_type(item))
+                        .map(Some),
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    ShapedPattern::new(rows)
 }
 
 #[cfg(test)]
@@ -91,6 +131,53 @@ mod tests {
         assert_eq!(stone_sword.output_count, 1); // Default value
     }
 
+    #[test]
+    fn test_shaped_pickaxe_matches_only_its_layout() {
+        use mdminecraft_core::{ItemType, ToolMaterial, ToolType};
+
+        let json = r#"[
+            {
+                "name": "wooden_pickaxe",
+                "pattern": ["PPP", " S ", " S "],
+                "key": {"P": "block:2", "S": "item:1"},
+                "output": {"item": "tool:pickaxe:wood"}
+            },
+            {
+                "name": "planks",
+                "inputs": [{"item": "block:1", "count": 1}],
+                "output": {"item": "block:2", "count": 4}
+            }
+        ]"#;
+        let registry = recipe_registry_from_str(json).unwrap();
+        assert_eq!(registry.len(), 2);
+
+        let pickaxe = registry.get("wooden_pickaxe").unwrap();
+        assert_eq!(
+            pickaxe.inputs,
+            vec![(ItemType::Block(2), 3), (ItemType::Item(1), 2)]
+        );
+        assert_eq!(pickaxe.output_count, 1);
+
+        let p = Some(ItemType::Block(2));
+        let s = Some(ItemType::Item(1));
+        let correct = [[p, p, p], [None, s, None], [None, s, None]];
+        let (name, recipe) = registry.find_match(&correct).unwrap();
+        assert_eq!(name, "wooden_pickaxe");
+        assert_eq!(
+            recipe.output,
+            ItemType::Tool(ToolType::Pickaxe, ToolMaterial::Wood)
+        );
+
+        // Same items, wrong arrangement.
+        let scrambled = [[p, s, p], [None, p, None], [None, s, None]];
+        assert!(registry.find_match(&scrambled).is_none());
+
+        // Shapeless recipes still match from any slot.
+        let log = Some(ItemType::Block(1));
+        let planks = [[None, None, None], [None, None, log], [None, None, None]];
+        assert_eq!(registry.find_match(&planks).unwrap().0, "planks");
+    }
+
     #[test]
     fn test_load_recipes_from_config_file() {
         // Test loading the actual config/recipes.json file
@@ -5,11 +5,117 @@ use std::collections::HashMap;
 
 use crate::BlockRegistry;
 
+/// Side length of the crafting grid.
+pub const CRAFTING_GRID_SIZE: usize = 3;
+
+/// Contents of a crafting grid, indexed `[row][column]`.
+pub type CraftingGrid = [[Option<ItemType>; CRAFTING_GRID_SIZE]; CRAFTING_GRID_SIZE];
+
+/// Grid layout of a shaped recipe, trimmed to its bounding box.
+///
+/// A pattern matches anywhere in the grid and also in its left-right mirror
+/// image. Rotated layouts do not match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapedPattern {
+    width: usize,
+    height: usize,
+    /// Row-major cells; `None` is an empty slot.
+    cells: Vec<Option<ItemType>>,
+}
+
+impl ShapedPattern {
+    /// Build a pattern from rows of cells.
+    ///
+    /// Returns `None` if the rows are ragged, larger than the crafting grid,
+    /// or contain no items.
+    pub fn new(rows: Vec<Vec<Option<ItemType>>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.len() > CRAFTING_GRID_SIZE
+            || width > CRAFTING_GRID_SIZE
+            || rows.iter().any(|row| row.len() != width)
+        {
+            return None;
+        }
+
+        let filled_rows: Vec<usize> = (0..rows.len())
+            .filter(|&y| rows[y].iter().any(Option::is_some))
+            .collect();
+        let filled_cols: Vec<usize> = (0..width)
+            .filter(|&x| rows.iter().any(|row| row[x].is_some()))
+            .collect();
+        let (&top, &bottom) = (filled_rows.first()?, filled_rows.last()?);
+        let (&left, &right) = (filled_cols.first()?, filled_cols.last()?);
+
+        let cells = rows[top..=bottom]
+            .iter()
+            .flat_map(|row| row[left..=right].iter().copied())
+            .collect();
+        Some(Self {
+            width: right - left + 1,
+            height: bottom - top + 1,
+            cells,
+        })
+    }
+
+    /// Width of the trimmed pattern in cells.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the trimmed pattern in cells.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Item at `(x, y)` within the trimmed pattern.
+    pub fn cell(&self, x: usize, y: usize) -> Option<ItemType> {
+        (x < self.width && y < self.height)
+            .then(|| self.cells[y * self.width + x])
+            .flatten()
+    }
+
+    /// Left-right mirror image of this pattern.
+    pub fn mirrored(&self) -> Self {
+        let cells = (0..self.height)
+            .flat_map(|y| (0..self.width).rev().map(move |x| (x, y)))
+            .map(|(x, y)| self.cells[y * self.width + x])
+            .collect();
+        Self {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// Check whether `grid` holds this pattern (or its mirror) and nothing else.
+    pub fn matches(&self, grid: &CraftingGrid) -> bool {
+        let rows = grid.iter().map(|row| row.to_vec()).collect();
+        match Self::new(rows) {
+            Some(placed) => placed == *self || placed == self.mirrored(),
+            None => false,
+        }
+    }
+
+    /// Item counts consumed by one craft, in first-appearance order.
+    pub fn inputs(&self) -> Vec<(ItemType, u32)> {
+        let mut inputs: Vec<(ItemType, u32)> = Vec::new();
+        for item in self.cells.iter().flatten() {
+            match inputs.iter_mut().find(|(existing, _)| existing == item) {
+                Some((_, count)) => *count += 1,
+                None => inputs.push((*item, 1)),
+            }
+        }
+        inputs
+    }
+}
+
 /// Registry of crafting recipes indexed by output item name.
 #[derive(Debug, Clone)]
 pub struct RecipeRegistry {
     /// Map from recipe name/ID to Recipe
     recipes: HashMap<String, Recipe>,
+    /// Grid layouts for shaped recipes; recipes without one are shapeless.
+    patterns: HashMap<String, ShapedPattern>,
 }
 
 impl RecipeRegistry {
@@ -17,14 +123,61 @@ impl RecipeRegistry {
     pub fn new(recipes: Vec<(String, Recipe)>) -> Self {
         Self {
             recipes: recipes.into_iter().collect(),
+            patterns: HashMap::new(),
         }
     }
 
+    /// Register a shaped recipe whose inputs are taken from `pattern`.
+    pub fn insert_shaped(
+        &mut self,
+        name: String,
+        pattern: ShapedPattern,
+        output: ItemType,
+        output_count: u32,
+    ) {
+        let recipe = Recipe::new(pattern.inputs(), output, output_count);
+        self.recipes.insert(name.clone(), recipe);
+        self.patterns.insert(name, pattern);
+    }
+
     /// Get a recipe by its name/ID.
     pub fn get(&self, name: &str) -> Option<&Recipe> {
         self.recipes.get(name)
     }
 
+    /// Get the grid layout of a shaped recipe.
+    pub fn pattern(&self, name: &str) -> Option<&ShapedPattern> {
+        self.patterns.get(name)
+    }
+
+    /// Find the recipe produced by the items laid out in `grid`.
+    ///
+    /// Shaped recipes must match their layout (mirroring allowed); shapeless
+    /// recipes match when the grid holds exactly their inputs in any slots.
+    /// Ties resolve to the lexicographically smallest name so lookups are
+    /// deterministic.
+    pub fn find_match(&self, grid: &CraftingGrid) -> Option<(&String, &Recipe)> {
+        let mut placed: Vec<(ItemType, u32)> = Vec::new();
+        for item in grid.iter().flatten().flatten() {
+            match placed.iter_mut().find(|(existing, _)| existing == item) {
+                Some((_, count)) => *count += 1,
+                None => placed.push((*item, 1)),
+            }
+        }
+
+        self.recipes
+            .iter()
+            .filter(|(name, recipe)| match self.patterns.get(*name) {
+                Some(pattern) => pattern.matches(grid),
+                None => {
+                    !recipe.inputs.is_empty()
+                        && recipe.inputs.len() == placed.len()
+                        && recipe.inputs.iter().all(|input| placed.contains(input))
+                }
+            })
+            .min_by(|(a, _), (b, _)| a.cmp(b))
+    }
+
     /// Get all registered recipe names.
     pub fn recipe_names(&self) -> impl Iterator<Item = &String> {
         self.recipes.keys()
@@ -183,6 +336,34 @@ mod tests {
         assert_eq!(craftable[0].0, "planks");
     }
 
+    #[test]
+    fn test_shaped_pattern_trims_and_mirrors() {
+        let a = Some(ItemType::Block(1));
+        let b = Some(ItemType::Item(1));
+        // Hoe shape: two heads on top, handle below the right one.
+        let pattern = ShapedPattern::new(vec![vec![a, a], vec![None, b], vec![None, b]]).unwrap();
+        assert_eq!((pattern.width(), pattern.height()), (2, 3));
+        assert_eq!(pattern.cell(1, 2), b);
+        assert_eq!(pattern.cell(0, 1), None);
+
+        // Shifted to the right edge of the grid.
+        let shifted = [[None, a, a], [None, None, b], [None, None, b]];
+        assert!(pattern.matches(&shifted));
+
+        // Mirrored layout matches, rotated layout does not.
+        let mirrored = [[a, a, None], [b, None, None], [b, None, None]];
+        assert!(pattern.matches(&mirrored));
+        let rotated = [[None, None, None], [b, b, a], [None, None, a]];
+        assert!(!pattern.matches(&rotated));
+
+        // Stray extra items break the match.
+        let extra = [[None, a, a], [None, None, b], [a, None, b]];
+        assert!(!pattern.matches(&extra));
+
+        assert!(ShapedPattern::new(vec![vec![a, a], vec![b]]).is_none());
+        assert!(ShapedPattern::new(vec![vec![None; 3]; 3]).is_none());
+    }
+
     #[test]
     fn test_parse_item_type() {
         // Test block parsing
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
-use mdminecraft_core::RegistryKey;
-use mdminecraft_world::BlockOpacityProvider;
+use mdminecraft_core::{RegistryKey, ToolMaterial};
+use mdminecraft_world::{BlockDescriptorSource, BlockOpacityProvider, CollisionShape};
 
 use crate::AssetError;
 use crate::BlockTextureConfig;
+use crate::TextureAtlasMetadata;
 use std::collections::BTreeSet;
 
 /// Minimum tool tier required to successfully harvest a block.
@@ -64,8 +65,15 @@ pub struct BlockDescriptor {
     /// Tag keys applied to this block.
     pub tags: BTreeSet<RegistryKey>,
     textures: BlockTextures,
+    /// Whether the pack named any texture; blocks without one (such as air)
+    /// aren't checked against the atlas.
+    textured: bool,
     /// Required tool tier to harvest this block (None = no tool required).
     pub harvest_level: Option<HarvestLevel>,
+    /// Harvest level string as written in the pack, kept for validation.
+    raw_harvest_level: Option<String>,
+    /// Pack-defined collision shape (None = built-in shape or full cube).
+    pub collision: Option<CollisionShape>,
 }
 
 impl BlockDescriptor {
@@ -78,14 +86,15 @@ impl BlockDescriptor {
     pub fn try_from_definition(def: crate::BlockDefinition) -> Result<Self, AssetError> {
         let raw_key = def.key.as_deref().unwrap_or(&def.name);
         let key = RegistryKey::parse(raw_key)
-            .map_err(|err| AssetError::InvalidRegistryKey(err.to_string()))?;
+            .map_err(|err| AssetError::InvalidRegistryKey(format!("{raw_key:?}: {err}")))?;
 
         // Display name remains the short "path" part.
         let name = key.path().to_string();
 
+        let textured = def.texture.is_some() || def.textures.is_some();
         let base_name = def.texture.clone().unwrap_or_else(|| name.clone());
         let textures = BlockTextures::from_config(&base_name, def.textures);
-        let harvest_level = def.harvest_level.and_then(|s| HarvestLevel::parse(&s));
+        let harvest_level = def.harvest_level.as_deref().and_then(HarvestLevel::parse);
         let light_opacity = match def.light_opacity {
             Some(value) if value <= 15 => value,
             Some(value) => return Err(AssetError::InvalidLightOpacity(value)),
@@ -109,10 +118,17 @@ impl BlockDescriptor {
             }
         };
 
+        let collision = match def.collision {
+            Some(raw) => {
+                Some(CollisionShape::parse(&raw).ok_or(AssetError::InvalidCollisionShape(raw))?)
+            }
+            None => None,
+        };
+
         let mut tags = BTreeSet::new();
         for raw_tag in def.tags {
             let tag = RegistryKey::parse(&raw_tag)
-                .map_err(|err| AssetError::InvalidTagKey(err.to_string()))?;
+                .map_err(|err| AssetError::InvalidTagKey(format!("{raw_tag:?}: {err}")))?;
             tags.insert(tag);
         }
 
@@ -124,7 +140,10 @@ impl BlockDescriptor {
             light_emission,
             tags,
             textures,
+            textured,
             harvest_level,
+            raw_harvest_level: def.harvest_level,
+            collision,
         })
     }
 
@@ -148,6 +167,7 @@ impl BlockDescriptor {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         })
     }
 }
@@ -177,9 +197,20 @@ impl BlockRegistry {
     }
 
     /// Resolve a block id by its name.
+    ///
+    /// Accepts a full key (`mdm:stone`), a bare path in the default namespace
+    /// (`stone`), or the display name of a block in another namespace.
     pub fn id_by_name(&self, name: &str) -> Option<u16> {
-        let key = RegistryKey::parse(name).ok()?;
-        self.id_by_key(&key)
+        if let Some(id) = RegistryKey::parse(name)
+            .ok()
+            .and_then(|key| self.id_by_key(&key))
+        {
+            return Some(id);
+        }
+        self.descriptors
+            .iter()
+            .position(|descriptor| descriptor.name == name)
+            .map(|id| id as u16)
     }
 
     /// Resolve a block id by its registry key.
@@ -187,6 +218,18 @@ impl BlockRegistry {
         self.key_to_id.get(key).copied()
     }
 
+    /// Resolve a block id by a fully qualified `namespace:path` key string.
+    ///
+    /// Unlike [`BlockRegistry::id_by_name`], bare names are rejected so saves
+    /// and recipes can't silently depend on the default namespace.
+    pub fn id_by_key_str(&self, key: &str) -> Option<u16> {
+        if !key.contains(':') {
+            return None;
+        }
+        let key = RegistryKey::parse(key).ok()?;
+        self.id_by_key(&key)
+    }
+
     /// Get the harvest level required for a block (None = no tool required).
     pub fn harvest_level(&self, block_id: u16) -> Option<HarvestLevel> {
         self.descriptor(block_id).and_then(|d| d.harvest_level)
@@ -197,6 +240,47 @@ impl BlockRegistry {
         self.descriptor(id).map(|d| &d.key)
     }
 
+    /// Check every descriptor for malformed keys, unknown harvest tiers and
+    /// texture names that don't resolve against `atlas`.
+    ///
+    /// Blocks that name no texture, like air, skip the atlas check.
+    ///
+    /// Keys and tags are re-parsed because descriptors can also be built from
+    /// deserialized [`RegistryKey`]s, which skip [`RegistryKey::parse`].
+    pub fn validate(&self, atlas: &TextureAtlasMetadata) -> Result<(), AssetError> {
+        for descriptor in &self.descriptors {
+            let key = descriptor.key.to_string();
+            RegistryKey::parse(&key)
+                .map_err(|err| AssetError::InvalidRegistryKey(format!("{key:?}: {err}")))?;
+
+            for tag in &descriptor.tags {
+                let tag = tag.to_string();
+                RegistryKey::parse(&tag)
+                    .map_err(|err| AssetError::InvalidTagKey(format!("{tag:?}: {err}")))?;
+            }
+
+            if let Some(raw) = &descriptor.raw_harvest_level {
+                if HarvestLevel::parse(raw).is_none() {
+                    return Err(AssetError::InvalidHarvestLevel(raw.clone()));
+                }
+            }
+
+            if !descriptor.textured {
+                continue;
+            }
+            for face in BlockFace::ALL {
+                let texture = descriptor.texture_for(face);
+                if atlas.entry(texture).is_none() {
+                    return Err(AssetError::MissingTexture {
+                        block: key,
+                        texture: texture.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Return whether the given block has the supplied tag.
     pub fn has_tag(&self, block_id: u16, tag: &RegistryKey) -> bool {
         self.descriptor(block_id)
@@ -227,6 +311,30 @@ impl BlockOpacityProvider for BlockRegistry {
     }
 }
 
+impl BlockDescriptorSource for BlockRegistry {
+    fn block_count(&self) -> usize {
+        self.descriptors.len()
+    }
+
+    fn harvest_tier(&self, block_id: u16) -> Option<ToolMaterial> {
+        self.harvest_level(block_id).map(|level| match level {
+            HarvestLevel::Wood => ToolMaterial::Wood,
+            HarvestLevel::Stone => ToolMaterial::Stone,
+            HarvestLevel::Iron => ToolMaterial::Iron,
+            HarvestLevel::Diamond => ToolMaterial::Diamond,
+        })
+    }
+
+    fn has_tag_path(&self, block_id: u16, tag_path: &str) -> bool {
+        self.descriptor(block_id)
+            .is_some_and(|d| d.tags.iter().any(|tag| tag.path() == tag_path))
+    }
+
+    fn collision_shape(&self, block_id: u16) -> Option<CollisionShape> {
+        self.descriptor(block_id).and_then(|d| d.collision)
+    }
+}
+
 /// Faces corresponding to the block's six sides.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BlockFace {
@@ -244,6 +352,18 @@ pub enum BlockFace {
     West,
 }
 
+impl BlockFace {
+    /// All six faces.
+    pub const ALL: [BlockFace; 6] = [
+        BlockFace::Up,
+        BlockFace::Down,
+        BlockFace::North,
+        BlockFace::South,
+        BlockFace::East,
+        BlockFace::West,
+    ];
+}
+
 #[derive(Debug, Clone)]
 struct BlockTextures {
     up: String,
@@ -407,6 +527,7 @@ mod tests {
                 texture: None,
                 textures: None,
                 harvest_level: None,
+                collision: None,
             },
             BlockDefinition {
                 name: "stone".to_string(),
@@ -419,6 +540,7 @@ mod tests {
                 texture: None,
                 textures: None,
                 harvest_level: Some("wood".to_string()),
+                collision: None,
             },
         ];
 
@@ -448,6 +570,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         })
         .expect("stone parses");
         assert_eq!(stone.light_opacity, 15);
@@ -463,6 +586,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         })
         .expect("glass parses");
         assert_eq!(glass.light_opacity, 0);
@@ -481,6 +605,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         })
         .expect("stone parses");
         assert_eq!(stone.light_emission, 0);
@@ -499,6 +624,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         })
         .expect("torch parses");
         assert_eq!(torch.light_emission, 15);
@@ -514,6 +640,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         })
         .expect("dim torch parses");
         assert_eq!(dim.light_emission, 7);
@@ -532,6 +659,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         };
 
         let err = BlockDescriptor::try_from_definition(def).unwrap_err();
@@ -554,6 +682,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         };
 
         let err = BlockDescriptor::try_from_definition(def).unwrap_err();
@@ -563,6 +692,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lookup_by_explicit_and_defaulted_keys() {
+        let registry = crate::registry_from_str(
+            r#"[
+                {"name": "air"},
+                {"name": "stone", "opaque": true},
+                {"name": "cobblestone", "key": "mdm:cobble", "opaque": true},
+                {"name": "ruby_ore", "key": "gems:ruby_ore", "opaque": true}
+            ]"#,
+        )
+        .expect("pack loads");
+
+        // Defaulted key.
+        assert_eq!(registry.key_by_id(1).unwrap().to_string(), "mdm:stone");
+        assert_eq!(registry.id_by_key_str("mdm:stone"), Some(1));
+        assert_eq!(registry.id_by_name("stone"), Some(1));
+
+        // Explicit keys, including another namespace.
+        assert_eq!(registry.id_by_key_str("mdm:cobble"), Some(2));
+        assert_eq!(registry.id_by_key_str("gems:ruby_ore"), Some(3));
+        assert_eq!(registry.id_by_name("ruby_ore"), Some(3));
+        assert_eq!(
+            registry.id_by_key(&RegistryKey::parse("gems:ruby_ore").unwrap()),
+            Some(3)
+        );
+
+        // Key strings must be fully qualified and known.
+        assert_eq!(registry.id_by_key_str("stone"), None);
+        assert_eq!(registry.id_by_key_str("mdm:ruby_ore"), None);
+        assert_eq!(registry.id_by_key_str("Bad Key:x"), None);
+    }
+
+    fn atlas(names: &[&str]) -> TextureAtlasMetadata {
+        let entries = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| crate::AtlasEntry {
+                name: name.to_string(),
+                x: i as u32 * 16,
+                y: 0,
+                width: 16,
+                height: 16,
+                u0: 0.0,
+                v0: 0.0,
+                u1: 1.0,
+                v1: 1.0,
+            })
+            .collect::<Vec<_>>();
+        TextureAtlasMetadata {
+            tile_size: 16,
+            padding: 0,
+            columns: entries.len() as u32,
+            rows: 1,
+            atlas_width: entries.len() as u32 * 16,
+            atlas_height: 16,
+            layout: crate::AtlasLayout::Grid,
+            content_hash: 0,
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_pack() {
+        let registry = crate::registry_from_str(
+            r#"[
+                {"name": "air", "opaque": false},
+                {"name": "stone", "texture": "stone", "tags": ["mdm:mineable/pickaxe"], "harvest_level": "wood"},
+                {"name": "grass", "textures": {"top": "grass_top", "bottom": "dirt"}}
+            ]"#,
+        )
+        .unwrap();
+
+        registry
+            .validate(&atlas(&["stone", "grass", "grass_top", "dirt"]))
+            .expect("pack is valid");
+
+        let err = registry.validate(&atlas(&["stone", "grass"])).unwrap_err();
+        match err {
+            AssetError::MissingTexture { block, texture } => {
+                assert_eq!(block, "mdm:grass");
+                assert_eq!(texture, "grass_top");
+            }
+            other => panic!("expected MissingTexture, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_harvest_tier() {
+        let registry =
+            crate::registry_from_str(r#"[{"name": "obsidian", "harvest_level": "netherite"}]"#)
+                .unwrap();
+        assert_eq!(registry.harvest_level(0), None);
+
+        let err = registry.validate(&atlas(&["obsidian"])).unwrap_err();
+        match err {
+            AssetError::InvalidHarvestLevel(level) => assert_eq!(level, "netherite"),
+            other => panic!("expected InvalidHarvestLevel, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_malformed_tag_in_pack_reports_value() {
+        let err = crate::registry_from_str(r#"[{"name": "stone", "tags": ["Mineable Pickaxe"]}]"#)
+            .err()
+            .expect("malformed tag rejected");
+        match err {
+            AssetError::InvalidTagKey(message) => assert!(message.contains("Mineable Pickaxe")),
+            other => panic!("expected InvalidTagKey, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_invalid_tag_key_errors() {
         let def = BlockDefinition {
@@ -576,6 +816,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         };
 
         let err = BlockDescriptor::try_from_definition(def).unwrap_err();
@@ -584,4 +825,67 @@ mod tests {
             other => panic!("expected InvalidTagKey, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_block_properties_follow_pack() {
+        let registry = crate::registry_from_str(
+            r#"[
+                {"name": "air"},
+                {"name": "glowstone", "opaque": true, "light_emission": 15},
+                {
+                    "name": "tough_ore",
+                    "opaque": true,
+                    "tags": ["mdm:mineable/pickaxe"],
+                    "harvest_level": "iron"
+                },
+                {"name": "dust", "tags": ["mdm:non_solid"]}
+            ]"#,
+        )
+        .expect("pack parses");
+
+        let properties = mdminecraft_world::BlockPropertiesRegistry::from_registry(&registry);
+
+        assert_eq!(properties.get(1).light_emission, 15);
+        assert_eq!(properties.get(0).light_emission, 0);
+
+        let ore = properties.get(2);
+        assert_eq!(ore.best_tool, Some(mdminecraft_core::ToolType::Pickaxe));
+        assert_eq!(ore.required_tier, Some(ToolMaterial::Iron));
+        assert!(!ore.can_harvest(Some((
+            mdminecraft_core::ToolType::Pickaxe,
+            ToolMaterial::Stone
+        ))));
+        assert!(ore.can_harvest(Some((
+            mdminecraft_core::ToolType::Pickaxe,
+            ToolMaterial::Iron
+        ))));
+
+        assert!(!properties.get(3).is_solid);
+    }
+
+    #[test]
+    fn test_collision_shape_from_pack() {
+        let registry = crate::registry_from_str(
+            r#"[
+                {"name": "air"},
+                {"name": "path", "key": "example:path", "collision": "partial:15"}
+            ]"#,
+        )
+        .expect("pack parses");
+
+        let id = registry.id_by_name("example:path").unwrap();
+        let properties = mdminecraft_world::BlockPropertiesRegistry::from_registry(&registry);
+        assert_eq!(
+            properties.collision_shapes().collision_type(id, 0),
+            mdminecraft_world::CollisionType::Partial {
+                min_y: 0.0,
+                max_y: 15.0 / 16.0
+            }
+        );
+
+        let err = crate::registry_from_str(r#"[{"name": "blob", "collision": "jelly"}]"#)
+            .err()
+            .expect("unknown shape is rejected");
+        assert!(matches!(err, AssetError::InvalidCollisionShape(ref raw) if raw == "jelly"));
+    }
 }
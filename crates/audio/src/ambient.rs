@@ -0,0 +1,127 @@
+//! Ambient loop crossfading.
+
+use crate::AmbientSound;
+use std::time::{Duration, Instant};
+
+/// Time source for audio envelopes.
+///
+/// Swappable so tests can step time by hand.
+pub trait AudioClock {
+    /// Time elapsed since an arbitrary fixed origin.
+    fn now(&self) -> Duration;
+}
+
+/// [`AudioClock`] backed by the monotonic system clock.
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    origin: Instant,
+}
+
+impl MonotonicClock {
+    /// Create a clock whose origin is now.
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioClock for MonotonicClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// One ambient loop ramping linearly between two gains.
+#[derive(Debug, Clone, Copy)]
+struct AmbientVoice {
+    sound: AmbientSound,
+    start_gain: f32,
+    target_gain: f32,
+    start: Duration,
+    duration: Duration,
+}
+
+impl AmbientVoice {
+    fn gain(&self, now: Duration) -> f32 {
+        if self.duration.is_zero() {
+            return self.target_gain;
+        }
+        let t = (now.saturating_sub(self.start).as_secs_f32() / self.duration.as_secs_f32())
+            .clamp(0.0, 1.0);
+        self.start_gain + (self.target_gain - self.start_gain) * t
+    }
+
+    fn is_silent(&self, now: Duration) -> bool {
+        self.target_gain == 0.0 && self.gain(now) == 0.0
+    }
+}
+
+/// Gain envelopes for ambient loops (0.0 to 1.0, before volume settings).
+///
+/// Exactly one sound is active; others are fading out and are dropped once
+/// silent.
+#[derive(Debug, Default)]
+pub(crate) struct AmbientMixer {
+    active: Option<AmbientSound>,
+    voices: Vec<AmbientVoice>,
+}
+
+impl AmbientMixer {
+    /// The sound currently faded in (or fading in).
+    pub fn active(&self) -> Option<AmbientSound> {
+        self.active
+    }
+
+    /// Start fading to `to` over `duration`.
+    ///
+    /// Returns false if `to` is already active. Fades in progress continue
+    /// from their current gain so retargeting never jumps.
+    pub fn crossfade(&mut self, to: AmbientSound, now: Duration, duration: Duration) -> bool {
+        if self.active == Some(to) {
+            return false;
+        }
+
+        for voice in &mut self.voices {
+            voice.start_gain = voice.gain(now);
+            voice.target_gain = if voice.sound == to { 1.0 } else { 0.0 };
+            voice.start = now;
+            voice.duration = duration;
+        }
+        if !self.voices.iter().any(|voice| voice.sound == to) {
+            self.voices.push(AmbientVoice {
+                sound: to,
+                start_gain: 0.0,
+                target_gain: 1.0,
+                start: now,
+                duration,
+            });
+        }
+        self.active = Some(to);
+        true
+    }
+
+    /// Current gain of every audible voice.
+    pub fn gains(&self, now: Duration) -> Vec<(AmbientSound, f32)> {
+        self.voices
+            .iter()
+            .map(|voice| (voice.sound, voice.gain(now)))
+            .collect()
+    }
+
+    /// Drop voices that finished fading out, returning their sounds.
+    pub fn prune(&mut self, now: Duration) -> Vec<AmbientSound> {
+        let (silent, audible): (Vec<_>, Vec<_>) = self
+            .voices
+            .drain(..)
+            .partition(|voice| voice.is_silent(now));
+        self.voices = audible;
+        silent.into_iter().map(|voice| voice.sound).collect()
+    }
+}
@@ -17,10 +17,14 @@
 //! audio.set_listener_position([0.0, 64.0, 0.0]);
 //! ```
 
+mod ambient;
+mod loader;
 mod manager;
 mod settings;
 mod sounds;
 
-pub use manager::AudioManager;
+pub use ambient::{AudioClock, MonotonicClock};
+pub use loader::{FileSoundLoader, SoundLoader};
+pub use manager::{AudioManager, SoundHandle};
 pub use settings::AudioSettings;
 pub use sounds::{AmbientSound, MusicTrack, SoundId};
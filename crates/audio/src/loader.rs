@@ -0,0 +1,39 @@
+//! Sources of encoded sound files.
+
+use crate::SoundId;
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// Reads the encoded bytes for a sound effect.
+///
+/// [`crate::AudioManager`] calls this at most once per [`SoundId`] and keeps
+/// the decoded result, so loaders need no caching of their own.
+pub trait SoundLoader {
+    /// Encoded bytes for `id`, or `None` if the sound has no file.
+    fn load(&self, id: SoundId) -> Result<Option<Vec<u8>>>;
+}
+
+/// Loads sounds from [`SoundId::file_path`] under an asset root.
+#[derive(Debug, Clone)]
+pub struct FileSoundLoader {
+    root: PathBuf,
+}
+
+impl FileSoundLoader {
+    /// Load files relative to `root` (e.g. the `assets` directory).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl SoundLoader for FileSoundLoader {
+    fn load(&self, id: SoundId) -> Result<Option<Vec<u8>>> {
+        let Some(relative) = id.file_path() else {
+            return Ok(None);
+        };
+        let path = self.root.join(relative);
+        std::fs::read(&path)
+            .map(Some)
+            .with_context(|| format!("Failed to read sound {}", path.display()))
+    }
+}
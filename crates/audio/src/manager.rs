@@ -1,26 +1,125 @@
 //! Audio manager for sound playback and music.
 
-use crate::{AudioSettings, MusicTrack, SoundId};
+use crate::ambient::{AmbientMixer, AudioClock, MonotonicClock};
+use crate::{AmbientSound, AudioSettings, MusicTrack, SoundId, SoundLoader};
 use anyhow::Result;
+use glam::Vec3;
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
 use tracing::debug;
 
+/// Fraction of a sound's max distance inside which it plays at full volume.
+const ROLLOFF_REFERENCE_FRACTION: f32 = 0.25;
+
+/// Handle to a playing sound effect, for stopping or inspecting it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SoundHandle(u64);
+
+/// Per-channel gain for a stereo voice.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct StereoGain {
+    /// Left channel gain
+    pub left: f32,
+    /// Right channel gain
+    pub right: f32,
+}
+
+impl StereoGain {
+    /// Same gain on both channels.
+    fn centered(volume: f32) -> Self {
+        Self {
+            left: volume,
+            right: volume,
+        }
+    }
+}
+
 #[cfg(feature = "rodio_backend")]
 mod backend {
     use super::*;
     use anyhow::Context;
+    use rodio::source::ChannelVolume;
     use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
     use std::io::Cursor;
+    use std::sync::atomic::{AtomicU64, Ordering};
     use std::sync::{Arc, Mutex};
 
-    /// Audio data for a loaded sound.
+    /// Decoded samples for a loaded sound, shared by every playback.
     pub struct SoundData {
-        /// Raw audio bytes
-        pub data: Vec<u8>,
+        samples: Arc<[f32]>,
+        channels: u16,
+        sample_rate: u32,
+    }
+
+    impl SoundData {
+        /// Decode an encoded (wav/ogg) file once.
+        pub fn decode(data: Vec<u8>) -> Result<Self> {
+            let decoder =
+                rodio::Decoder::new(Cursor::new(data)).context("Failed to decode audio")?;
+            let channels = decoder.channels();
+            let sample_rate = decoder.sample_rate();
+            Ok(Self {
+                samples: decoder.convert_samples::<f32>().collect(),
+                channels,
+                sample_rate,
+            })
+        }
+
+        fn source(&self) -> SharedSamples {
+            SharedSamples {
+                samples: Arc::clone(&self.samples),
+                position: 0,
+                channels: self.channels,
+                sample_rate: self.sample_rate,
+            }
+        }
+    }
+
+    /// Playback cursor over shared decoded samples; cloning is cheap.
+    #[derive(Clone)]
+    struct SharedSamples {
+        samples: Arc<[f32]>,
+        position: usize,
+        channels: u16,
+        sample_rate: u32,
+    }
+
+    impl Iterator for SharedSamples {
+        type Item = f32;
+
+        fn next(&mut self) -> Option<f32> {
+            let sample = self.samples.get(self.position).copied()?;
+            self.position += 1;
+            Some(sample)
+        }
+    }
+
+    impl Source for SharedSamples {
+        fn current_frame_len(&self) -> Option<usize> {
+            Some(self.samples.len() - self.position)
+        }
+
+        fn channels(&self) -> u16 {
+            self.channels
+        }
+
+        fn sample_rate(&self) -> u32 {
+            self.sample_rate
+        }
+
+        fn total_duration(&self) -> Option<Duration> {
+            let frames = self.samples.len() as f64 / self.channels.max(1) as f64;
+            Some(Duration::from_secs_f64(
+                frames / self.sample_rate.max(1) as f64,
+            ))
+        }
     }
 
     /// Active sound playback state.
     pub struct PlaybackState {
+        /// Handle given to the caller
+        pub handle: SoundHandle,
         /// The audio sink for playback control
         pub sink: Sink,
     }
@@ -33,8 +132,12 @@ mod backend {
         pub stream_handle: OutputStreamHandle,
         /// Music playback sink
         pub music_sink: Option<Sink>,
-        /// Active one-shot sound effects
+        /// Active sound effects; the sink volume is the category volume
         pub active_sounds: Arc<Mutex<Vec<PlaybackState>>>,
+        /// Next sound effect handle
+        pub next_handle: AtomicU64,
+        /// Looping ambient sinks
+        pub ambient_sinks: HashMap<AmbientSound, Sink>,
     }
 
     impl BackendState {
@@ -47,23 +150,88 @@ mod backend {
                 stream_handle,
                 music_sink: None,
                 active_sounds: Arc::new(Mutex::new(Vec::new())),
+                next_handle: AtomicU64::new(0),
+                ambient_sinks: HashMap::new(),
             })
         }
 
-        pub fn play_sound(&self, data: &SoundData, volume: f32) -> Result<()> {
-            let cursor = Cursor::new(data.data.clone());
-            let source = rodio::Decoder::new(cursor).context("Failed to decode audio")?;
+        pub fn start_ambient(&mut self, sound: AmbientSound, data: &SoundData) -> Result<()> {
+            if self.ambient_sinks.contains_key(&sound) {
+                return Ok(());
+            }
+
+            let sink = Sink::try_new(&self.stream_handle).context("Failed to create audio sink")?;
+            sink.set_volume(0.0);
+            sink.append(data.source().repeat_infinite());
+            self.ambient_sinks.insert(sound, sink);
+            Ok(())
+        }
+
+        pub fn set_ambient_volume(&self, sound: AmbientSound, volume: f32) {
+            if let Some(sink) = self.ambient_sinks.get(&sound) {
+                sink.set_volume(volume);
+            }
+        }
+
+        pub fn stop_ambient(&mut self, sound: AmbientSound) {
+            if let Some(sink) = self.ambient_sinks.remove(&sound) {
+                sink.stop();
+            }
+        }
 
+        pub fn play_sound(
+            &self,
+            data: &SoundData,
+            gain: StereoGain,
+            volume: f32,
+            looped: bool,
+        ) -> Result<SoundHandle> {
             let sink = Sink::try_new(&self.stream_handle).context("Failed to create audio sink")?;
             sink.set_volume(volume);
-            sink.append(source);
+            let channels = vec![gain.left, gain.right];
+            if looped {
+                sink.append(ChannelVolume::new(
+                    data.source().repeat_infinite(),
+                    channels,
+                ));
+            } else {
+                sink.append(ChannelVolume::new(data.source(), channels));
+            }
 
+            let handle = SoundHandle(self.next_handle.fetch_add(1, Ordering::Relaxed));
             if let Ok(mut active) = self.active_sounds.lock() {
                 active.retain(|s| !s.sink.empty());
-                active.push(PlaybackState { sink });
+                active.push(PlaybackState { handle, sink });
             }
 
-            Ok(())
+            Ok(handle)
+        }
+
+        pub fn set_sfx_volume(&self, volume: f32) {
+            if let Ok(active) = self.active_sounds.lock() {
+                for state in active.iter() {
+                    state.sink.set_volume(volume);
+                }
+            }
+        }
+
+        pub fn sound_volume(&self, handle: SoundHandle) -> Option<f32> {
+            let active = self.active_sounds.lock().ok()?;
+            active
+                .iter()
+                .find(|s| s.handle == handle)
+                .map(|s| s.sink.volume())
+        }
+
+        pub fn stop_sound(&self, handle: SoundHandle) {
+            if let Ok(mut active) = self.active_sounds.lock() {
+                active.retain(|s| {
+                    if s.handle == handle {
+                        s.sink.stop();
+                    }
+                    s.handle != handle
+                });
+            }
         }
 
         pub fn stop_music(&mut self) {
@@ -109,6 +277,9 @@ mod backend {
 
         pub fn stop_all(&mut self) {
             self.stop_music();
+            for (_, sink) in self.ambient_sinks.drain() {
+                sink.stop();
+            }
             if let Ok(mut active) = self.active_sounds.lock() {
                 for state in active.drain(..) {
                     state.sink.stop();
@@ -121,27 +292,87 @@ mod backend {
 #[cfg(not(feature = "rodio_backend"))]
 mod backend {
     use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
 
     /// Audio data for a loaded sound (stub).
     #[allow(dead_code)]
     pub struct SoundData {
-        /// Raw audio bytes (unused in stub mode)
+        /// Raw audio bytes (never decoded in stub mode)
         pub data: Vec<u8>,
     }
 
+    impl SoundData {
+        pub fn decode(data: Vec<u8>) -> Result<Self> {
+            Ok(Self { data })
+        }
+    }
+
+    /// Silent stand-in for a playing sink.
+    struct NullVoice {
+        handle: SoundHandle,
+        volume: f32,
+    }
+
     /// Backend state stub when rodio is not available.
-    pub struct BackendState;
+    ///
+    /// Looping sounds are tracked as silent voices so volume handling can be
+    /// exercised without an audio device; one-shots finish immediately.
+    #[derive(Default)]
+    pub struct BackendState {
+        voices: Mutex<Vec<NullVoice>>,
+        next_handle: AtomicU64,
+    }
 
     impl BackendState {
         pub fn new() -> Result<Self> {
             debug!("Audio backend: stub (no rodio)");
-            Ok(Self)
+            Ok(Self::default())
+        }
+
+        pub fn play_sound(
+            &self,
+            _data: &SoundData,
+            _gain: StereoGain,
+            volume: f32,
+            looped: bool,
+        ) -> Result<SoundHandle> {
+            let handle = SoundHandle(self.next_handle.fetch_add(1, Ordering::Relaxed));
+            if looped {
+                if let Ok(mut voices) = self.voices.lock() {
+                    voices.push(NullVoice { handle, volume });
+                }
+            }
+            Ok(handle)
+        }
+
+        pub fn set_sfx_volume(&self, volume: f32) {
+            if let Ok(mut voices) = self.voices.lock() {
+                for voice in voices.iter_mut() {
+                    voice.volume = volume;
+                }
+            }
+        }
+
+        pub fn sound_volume(&self, handle: SoundHandle) -> Option<f32> {
+            let voices = self.voices.lock().ok()?;
+            voices.iter().find(|v| v.handle == handle).map(|v| v.volume)
+        }
+
+        pub fn stop_sound(&self, handle: SoundHandle) {
+            if let Ok(mut voices) = self.voices.lock() {
+                voices.retain(|v| v.handle != handle);
+            }
         }
 
-        pub fn play_sound(&self, _data: &SoundData, _volume: f32) -> Result<()> {
+        pub fn start_ambient(&mut self, _sound: AmbientSound, _data: &SoundData) -> Result<()> {
             Ok(())
         }
 
+        pub fn set_ambient_volume(&self, _sound: AmbientSound, _volume: f32) {}
+
+        pub fn stop_ambient(&mut self, _sound: AmbientSound) {}
+
         pub fn stop_music(&mut self) {}
 
         pub fn pause_music(&self) {}
@@ -157,10 +388,14 @@ mod backend {
         pub fn update(&mut self) {}
 
         pub fn active_sound_count(&self) -> usize {
-            0
+            self.voices.lock().map(|v| v.len()).unwrap_or(0)
         }
 
-        pub fn stop_all(&mut self) {}
+        pub fn stop_all(&mut self) {
+            if let Ok(mut voices) = self.voices.lock() {
+                voices.clear();
+            }
+        }
     }
 }
 
@@ -176,12 +411,22 @@ pub struct AudioManager {
     backend: Option<BackendState>,
     /// Current audio settings
     settings: AudioSettings,
-    /// Loaded sound effects cache
-    sounds: HashMap<SoundId, Arc<SoundData>>,
+    /// Decoded sound effects; `None` marks sounds with no file
+    sounds: Mutex<HashMap<SoundId, Option<Arc<SoundData>>>>,
+    /// Reads sound files on first use
+    loader: Option<Box<dyn SoundLoader>>,
     /// Currently playing music track
     current_music: Option<MusicTrack>,
     /// Listener position for 3D audio
     listener_pos: [f32; 3],
+    /// Direction the listener faces, for stereo panning
+    listener_forward: [f32; 3],
+    /// Loaded ambient loops
+    ambient_sounds: HashMap<AmbientSound, Arc<SoundData>>,
+    /// Ambient crossfade envelopes
+    ambient: AmbientMixer,
+    /// Time source for fades
+    clock: Box<dyn AudioClock>,
 }
 
 impl AudioManager {
@@ -204,9 +449,14 @@ impl AudioManager {
         Ok(Self {
             backend,
             settings: AudioSettings::default(),
-            sounds: HashMap::new(),
+            sounds: Mutex::new(HashMap::new()),
+            loader: None,
             current_music: None,
             listener_pos: [0.0, 64.0, 0.0],
+            listener_forward: [0.0, 0.0, -1.0],
+            ambient_sounds: HashMap::new(),
+            ambient: AmbientMixer::default(),
+            clock: Box::new(MonotonicClock::new()),
         })
     }
 
@@ -217,9 +467,14 @@ impl AudioManager {
         Self {
             backend: None,
             settings: AudioSettings::default(),
-            sounds: HashMap::new(),
+            sounds: Mutex::new(HashMap::new()),
+            loader: None,
             current_music: None,
             listener_pos: [0.0, 64.0, 0.0],
+            listener_forward: [0.0, 0.0, -1.0],
+            ambient_sounds: HashMap::new(),
+            ambient: AmbientMixer::default(),
+            clock: Box::new(MonotonicClock::new()),
         }
     }
 
@@ -239,13 +494,17 @@ impl AudioManager {
     }
 
     /// Update audio settings.
+    ///
+    /// Volumes of sounds already playing change immediately. Muting silences
+    /// them without stopping playback, so unmuting picks up where they are.
     pub fn update_settings(&mut self, settings: AudioSettings) {
         self.settings = settings;
 
-        // Update music volume if playing
         if let Some(backend) = &self.backend {
             backend.set_music_volume(self.settings.effective_music_volume());
+            backend.set_sfx_volume(self.settings.effective_sfx_volume());
         }
+        self.apply_ambient_volumes();
     }
 
     /// Set the listener position for 3D audio.
@@ -253,63 +512,161 @@ impl AudioManager {
         self.listener_pos = pos;
     }
 
-    /// Load a sound effect into memory.
+    /// Set the direction the listener faces (need not be normalized).
+    ///
+    /// Only the horizontal component affects panning.
+    pub fn set_listener_orientation(&mut self, forward: [f32; 3]) {
+        self.listener_forward = forward;
+    }
+
+    /// Load a sound effect into memory from encoded bytes.
     pub fn load_sound(&mut self, id: SoundId, data: Vec<u8>) {
-        self.sounds.insert(id, Arc::new(SoundData { data }));
-        debug!("Loaded sound: {:?}", id);
+        match SoundData::decode(data) {
+            Ok(sound) => {
+                if let Ok(mut sounds) = self.sounds.lock() {
+                    sounds.insert(id, Some(Arc::new(sound)));
+                }
+                debug!("Loaded sound: {:?}", id);
+            }
+            Err(e) => tracing::warn!("Failed to decode sound {:?}: {}", id, e),
+        }
+    }
+
+    /// Read sounds that weren't loaded explicitly through `loader` on first use.
+    pub fn set_loader(&mut self, loader: Box<dyn SoundLoader>) {
+        self.loader = Some(loader);
+        if let Ok(mut sounds) = self.sounds.lock() {
+            sounds.retain(|_, sound| sound.is_some());
+        }
+    }
+
+    /// Load and decode `ids` now so their first play doesn't stall.
+    pub fn preload(&self, ids: &[SoundId]) {
+        for &id in ids {
+            self.cached_sound(id);
+        }
+    }
+
+    /// Decoded data for `id`, loading it through the loader on a cache miss.
+    ///
+    /// Failures are cached too, so a missing file is only read once.
+    fn cached_sound(&self, id: SoundId) -> Option<Arc<SoundData>> {
+        let mut sounds = self.sounds.lock().ok()?;
+        if let Some(cached) = sounds.get(&id) {
+            return cached.clone();
+        }
+
+        let loader = self.loader.as_ref()?;
+        let sound = match loader
+            .load(id)
+            .and_then(|data| data.map(SoundData::decode).transpose())
+        {
+            Ok(sound) => sound.map(Arc::new),
+            Err(e) => {
+                tracing::warn!("Failed to load sound {:?}: {}", id, e);
+                None
+            }
+        };
+        sounds.insert(id, sound.clone());
+        sound
     }
 
     /// Play a sound effect.
     ///
-    /// The sound plays at the listener's position (non-positional).
+    /// The sound is non-positional: centered and unattenuated, as for UI sounds.
     pub fn play_sfx(&self, id: SoundId) {
-        self.play_sfx_at(id, self.listener_pos);
+        self.play_with_gain(id, StereoGain::centered(id.default_volume()), false);
+    }
+
+    /// Play a non-positional sound effect on repeat until [`Self::stop_sound`].
+    ///
+    /// Returns `None` if the sound isn't loaded or audio is unavailable.
+    pub fn play_sfx_looped(&self, id: SoundId) -> Option<SoundHandle> {
+        self.play_with_gain(id, StereoGain::centered(id.default_volume()), true)
+    }
+
+    /// Stop a sound started with [`Self::play_sfx_looped`].
+    pub fn stop_sound(&self, handle: SoundHandle) {
+        if let Some(backend) = &self.backend {
+            backend.stop_sound(handle);
+        }
+    }
+
+    /// Current sink volume of a playing sound, or `None` once it has stopped.
+    pub fn sound_volume(&self, handle: SoundHandle) -> Option<f32> {
+        self.backend.as_ref()?.sound_volume(handle)
     }
 
     /// Play a sound effect at a specific world position.
     ///
-    /// Volume is attenuated based on distance from the listener.
+    /// Volume falls off with the inverse square of the distance from the
+    /// listener and the sound is panned toward the side it comes from.
     pub fn play_sfx_at(&self, id: SoundId, position: [f32; 3]) {
-        let volume = self.calculate_volume(id, position);
-        if volume < 0.01 {
-            return; // Too quiet to hear
-        }
-
-        // Check if we have the sound loaded
-        if let Some(sound_data) = self.sounds.get(&id) {
-            if let Some(backend) = &self.backend {
-                if let Err(e) = backend.play_sound(
-                    sound_data.as_ref(),
-                    volume * self.settings.effective_sfx_volume(),
-                ) {
-                    tracing::warn!("Failed to play sound {:?}: {}", id, e);
-                }
-            }
-        } else {
+        self.play_with_gain(id, self.stereo_gain(id, position), false);
+    }
+
+    /// Start a voice; `gain` is baked into the source and the sink volume
+    /// tracks the SFX category volume so settings changes apply live.
+    fn play_with_gain(&self, id: SoundId, gain: StereoGain, looped: bool) -> Option<SoundHandle> {
+        if gain.left.max(gain.right) < 0.01 {
+            return None; // Too quiet to hear
+        }
+
+        let Some(sound_data) = self.cached_sound(id) else {
             // Sound not loaded - this is normal during development
             debug!("Sound not loaded: {:?}", id);
+            return None;
+        };
+        let backend = self.backend.as_ref()?;
+        let volume = self.settings.effective_sfx_volume();
+        match backend.play_sound(sound_data.as_ref(), gain, volume, looped) {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                tracing::warn!("Failed to play sound {:?}: {}", id, e);
+                None
+            }
         }
     }
 
     /// Calculate effective volume based on distance and sound properties.
+    ///
+    /// Inverse-square rolloff, capped at full volume within the reference
+    /// distance, and faded to silence at the sound's max distance.
     fn calculate_volume(&self, id: SoundId, position: [f32; 3]) -> f32 {
         if !id.is_positional() {
             return id.default_volume();
         }
 
-        let dx = position[0] - self.listener_pos[0];
-        let dy = position[1] - self.listener_pos[1];
-        let dz = position[2] - self.listener_pos[2];
-        let distance = (dx * dx + dy * dy + dz * dz).sqrt();
-
+        let distance = Vec3::from(position).distance(Vec3::from(self.listener_pos));
         let max_dist = id.max_distance();
         if distance >= max_dist {
             return 0.0;
         }
 
-        // Linear falloff
-        let falloff = 1.0 - (distance / max_dist);
-        id.default_volume() * falloff
+        let reference = max_dist * ROLLOFF_REFERENCE_FRACTION;
+        let rolloff = (reference / distance.max(reference)).powi(2);
+        let edge_fade = 1.0 - (distance / max_dist);
+        id.default_volume() * rolloff * edge_fade
+    }
+
+    /// Per-channel gain for a sound at `position`.
+    fn stereo_gain(&self, id: SoundId, position: [f32; 3]) -> StereoGain {
+        let gain = StereoGain::centered(self.calculate_volume(id, position));
+        if !id.is_positional() {
+            return gain;
+        }
+
+        let horizontal = |v: Vec3| Vec3::new(v.x, 0.0, v.z);
+        let forward = horizontal(Vec3::from(self.listener_forward));
+        let offset = horizontal(Vec3::from(position) - Vec3::from(self.listener_pos));
+        let right = forward.cross(Vec3::Y).normalize_or_zero();
+        let pan = offset.normalize_or_zero().dot(right).clamp(-1.0, 1.0);
+
+        // Balance control: the far channel drops while the near one stays put.
+        StereoGain {
+            left: gain.left * (1.0 - pan).min(1.0),
+            right: gain.right * (1.0 + pan).min(1.0),
+        }
     }
 
     /// Play background music.
@@ -370,11 +727,71 @@ impl AudioManager {
         }
     }
 
+    /// Replace the time source used for fades.
+    pub fn set_clock(&mut self, clock: Box<dyn AudioClock>) {
+        self.clock = clock;
+    }
+
+    /// Load an ambient loop into memory.
+    pub fn load_ambient(&mut self, sound: AmbientSound, data: Vec<u8>) {
+        match SoundData::decode(data) {
+            Ok(data) => {
+                self.ambient_sounds.insert(sound, Arc::new(data));
+                debug!("Loaded ambient sound: {:?}", sound);
+            }
+            Err(e) => tracing::warn!("Failed to decode ambient {:?}: {}", sound, e),
+        }
+    }
+
+    /// Fade the current ambient loop out and `to` in over `duration`.
+    ///
+    /// Does nothing if `to` is already the active ambient sound.
+    pub fn crossfade_ambient(&mut self, to: AmbientSound, duration: Duration) {
+        if !self.ambient.crossfade(to, self.clock.now(), duration) {
+            return;
+        }
+
+        if let (Some(backend), Some(data)) = (&mut self.backend, self.ambient_sounds.get(&to)) {
+            if let Err(e) = backend.start_ambient(to, data.as_ref()) {
+                tracing::warn!("Failed to play ambient {:?}: {}", to, e);
+            }
+        }
+        self.apply_ambient_volumes();
+    }
+
+    /// The ambient sound faded in (or fading in), if any.
+    pub fn current_ambient(&self) -> Option<AmbientSound> {
+        self.ambient.active()
+    }
+
+    /// Current volume of each ambient loop, including ambient volume settings.
+    pub fn ambient_gains(&self) -> Vec<(AmbientSound, f32)> {
+        let volume = self.settings.effective_ambient_volume();
+        self.ambient
+            .gains(self.clock.now())
+            .into_iter()
+            .map(|(sound, gain)| (sound, gain * volume))
+            .collect()
+    }
+
+    fn apply_ambient_volumes(&self) {
+        if let Some(backend) = &self.backend {
+            for (sound, volume) in self.ambient_gains() {
+                backend.set_ambient_volume(sound, volume);
+            }
+        }
+    }
+
     /// Update audio state (call once per frame).
     ///
-    /// Cleans up finished sounds and handles music transitions.
+    /// Cleans up finished sounds and advances ambient crossfades.
     pub fn update(&mut self) {
+        self.apply_ambient_volumes();
+        let finished = self.ambient.prune(self.clock.now());
         if let Some(backend) = &mut self.backend {
+            for sound in finished {
+                backend.stop_ambient(sound);
+            }
             backend.update();
         }
     }
@@ -387,11 +804,12 @@ impl AudioManager {
             .unwrap_or(0)
     }
 
-    /// Stop all sounds (including music).
+    /// Stop all sounds (including music and ambient loops).
     pub fn stop_all(&mut self) {
         if let Some(backend) = &mut self.backend {
             backend.stop_all();
         }
+        self.ambient = AmbientMixer::default();
     }
 }
 
@@ -404,6 +822,43 @@ impl Default for AudioManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Clock advanced by hand.
+    #[derive(Clone, Default)]
+    struct StepClock(Rc<Cell<Duration>>);
+
+    impl StepClock {
+        fn advance(&self, by: Duration) {
+            self.0.set(self.0.get() + by);
+        }
+    }
+
+    impl AudioClock for StepClock {
+        fn now(&self) -> Duration {
+            self.0.get()
+        }
+    }
+
+    /// Loader that counts how often each sound is read.
+    #[derive(Clone, Default)]
+    struct CountingLoader(Rc<Cell<usize>>);
+
+    impl SoundLoader for CountingLoader {
+        fn load(&self, id: SoundId) -> Result<Option<Vec<u8>>> {
+            self.0.set(self.0.get() + 1);
+            Ok(id.file_path().map(|_| vec![0; 16]))
+        }
+    }
+
+    fn gain_of(manager: &AudioManager, sound: AmbientSound) -> f32 {
+        manager
+            .ambient_gains()
+            .into_iter()
+            .find(|(s, _)| *s == sound)
+            .map_or(0.0, |(_, gain)| gain)
+    }
 
     #[test]
     fn test_stub_manager() {
@@ -442,4 +897,130 @@ mod tests {
         let volume_far = manager.calculate_volume(SoundId::BlockBreak, far_pos);
         assert_eq!(volume_far, 0.0);
     }
+
+    #[test]
+    fn test_positional_sound_pans_toward_source() {
+        let mut manager = AudioManager::stub();
+        manager.set_listener_position([0.0, 64.0, 0.0]);
+        manager.set_listener_orientation([0.0, 0.0, -1.0]);
+
+        // Facing -Z, +X is to the listener's right.
+        let right = manager.stereo_gain(SoundId::BlockBreak, [3.0, 64.0, 0.0]);
+        assert!(right.right > right.left);
+        let left = manager.stereo_gain(SoundId::BlockBreak, [-3.0, 64.0, 0.0]);
+        assert!(left.left > left.right);
+
+        let ahead = manager.stereo_gain(SoundId::BlockBreak, [0.0, 64.0, -3.0]);
+        assert_eq!(ahead.left, ahead.right);
+
+        // Turning around swaps the sides.
+        manager.set_listener_orientation([0.0, 0.0, 1.0]);
+        let behind = manager.stereo_gain(SoundId::BlockBreak, [3.0, 64.0, 0.0]);
+        assert!(behind.left > behind.right);
+
+        // UI sounds stay centered.
+        let ui = manager.stereo_gain(SoundId::ButtonClick, [3.0, 64.0, 0.0]);
+        assert_eq!(
+            ui,
+            StereoGain::centered(SoundId::ButtonClick.default_volume())
+        );
+    }
+
+    #[test]
+    fn test_distant_sources_are_quieter() {
+        let manager = AudioManager::stub();
+        let at = |dx: f32| {
+            manager.calculate_volume(
+                SoundId::BlockBreak,
+                [dx, manager.listener_pos[1], manager.listener_pos[2]],
+            )
+        };
+
+        // Full volume inside the reference distance.
+        assert_eq!(
+            at(1.0),
+            SoundId::BlockBreak.default_volume() * (1.0 - 1.0 / 16.0)
+        );
+        assert!(at(4.0) > at(8.0));
+        assert!(at(8.0) > at(12.0));
+        // Doubling distance past the reference more than halves the volume.
+        assert!(at(8.0) < at(4.0) / 2.0);
+    }
+
+    #[test]
+    fn test_ambient_crossfade_envelope() {
+        let clock = StepClock::default();
+        let mut manager = AudioManager::stub();
+        manager.set_clock(Box::new(clock.clone()));
+        let full = manager.settings().effective_ambient_volume();
+
+        manager.crossfade_ambient(AmbientSound::Forest, Duration::ZERO);
+        assert_eq!(gain_of(&manager, AmbientSound::Forest), full);
+
+        manager.crossfade_ambient(AmbientSound::Cave, Duration::from_secs(2));
+        clock.advance(Duration::from_millis(500));
+        let forest = gain_of(&manager, AmbientSound::Forest);
+        let cave = gain_of(&manager, AmbientSound::Cave);
+        assert!((forest - 0.75 * full).abs() < 1e-5);
+        assert!((cave - 0.25 * full).abs() < 1e-5);
+        assert!((forest + cave - full).abs() < 1e-5);
+
+        // Repeating the active sound doesn't restart the fade.
+        manager.crossfade_ambient(AmbientSound::Cave, Duration::from_secs(2));
+        assert_eq!(gain_of(&manager, AmbientSound::Cave), cave);
+        assert_eq!(manager.current_ambient(), Some(AmbientSound::Cave));
+
+        clock.advance(Duration::from_millis(1500));
+        manager.update();
+        assert_eq!(manager.ambient_gains(), vec![(AmbientSound::Cave, full)]);
+    }
+
+    // The stub backend plays raw bytes; rodio would need a decodable file.
+    #[test]
+    #[cfg(not(feature = "rodio_backend"))]
+    fn test_settings_rescale_playing_sounds() {
+        let mut manager = AudioManager::new().unwrap();
+        manager.load_sound(SoundId::WaterAmbient, vec![0; 16]);
+        let handle = manager.play_sfx_looped(SoundId::WaterAmbient).unwrap();
+        let initial = manager.settings().effective_sfx_volume();
+        assert_eq!(manager.sound_volume(handle), Some(initial));
+
+        let mut settings = manager.settings().clone();
+        settings.set_sfx(settings.sfx * 0.5);
+        manager.update_settings(settings.clone());
+        assert_eq!(manager.sound_volume(handle), Some(initial * 0.5));
+
+        // Muting silences without stopping; unmuting restores the volume.
+        settings.muted = true;
+        manager.update_settings(settings.clone());
+        assert_eq!(manager.sound_volume(handle), Some(0.0));
+        settings.muted = false;
+        manager.update_settings(settings);
+        assert_eq!(manager.sound_volume(handle), Some(initial * 0.5));
+
+        manager.stop_sound(handle);
+        assert_eq!(manager.sound_volume(handle), None);
+    }
+
+    #[test]
+    fn test_preloaded_sounds_are_read_once() {
+        let reads = CountingLoader::default();
+        let mut manager = AudioManager::new().unwrap();
+        manager.set_loader(Box::new(reads.clone()));
+
+        manager.preload(&[SoundId::BlockBreak, SoundId::CaveDrip]);
+        assert_eq!(reads.0.get(), 2);
+
+        for _ in 0..5 {
+            manager.play_sfx(SoundId::BlockBreak);
+            // No file: the miss is cached as well.
+            manager.play_sfx(SoundId::CaveDrip);
+        }
+        assert_eq!(reads.0.get(), 2);
+
+        // Cache misses load lazily, once.
+        manager.play_sfx(SoundId::BlockPlace);
+        manager.play_sfx(SoundId::BlockPlace);
+        assert_eq!(reads.0.get(), 3);
+    }
 }
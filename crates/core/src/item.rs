@@ -380,6 +380,44 @@ impl ItemStack {
         self.count + count <= self.max_stack_size()
     }
 
+    /// Whether `other` may be merged into this stack (same item, durability
+    /// and enchantments).
+    pub fn can_merge_with(&self, other: &ItemStack) -> bool {
+        self.item_type == other.item_type
+            && self.durability == other.durability
+            && self.enchantments == other.enchantments
+    }
+
+    /// Move as many items as fit from `other` into this stack.
+    ///
+    /// Both counts are updated; returns the number of items moved (0 if the
+    /// stacks don't match or this stack is full).
+    pub fn try_merge(&mut self, other: &mut ItemStack) -> u32 {
+        if !self.can_merge_with(other) {
+            return 0;
+        }
+
+        let space = self.max_stack_size().saturating_sub(self.count);
+        let moved = space.min(other.count);
+        self.count += moved;
+        other.count -= moved;
+        moved
+    }
+
+    /// Take `n` items off this stack as a new stack with the same metadata.
+    ///
+    /// Returns `None` if `n` is zero or more than this stack holds.
+    pub fn split(&mut self, n: u32) -> Option<ItemStack> {
+        if n == 0 || n > self.count {
+            return None;
+        }
+
+        self.count -= n;
+        let mut taken = self.clone();
+        taken.count = n;
+        Some(taken)
+    }
+
     /// Damage the tool (reduce durability)
     pub fn damage_tool(&mut self, amount: u32) -> bool {
         if let Some(ref mut durability) = self.durability {
@@ -544,6 +582,46 @@ impl ItemStack {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stack_merge_respects_max_stack_size() {
+        let mut target = ItemStack::new(ItemType::Block(1), 60);
+        let mut source = ItemStack::new(ItemType::Block(1), 10);
+        assert_eq!(target.try_merge(&mut source), 4);
+        assert_eq!((target.count, source.count), (64, 6));
+
+        // A full stack accepts nothing.
+        assert_eq!(target.try_merge(&mut source), 0);
+        assert_eq!(source.count, 6);
+
+        let mut partial = ItemStack::new(ItemType::Block(1), 10);
+        assert_eq!(partial.try_merge(&mut source), 6);
+        assert_eq!((partial.count, source.count), (16, 0));
+
+        let mut other = ItemStack::new(ItemType::Block(2), 5);
+        assert!(!partial.can_merge_with(&other));
+        assert_eq!(partial.try_merge(&mut other), 0);
+
+        let mut worn = ItemStack::new(ItemType::Item(client_item_ids::FLINT_AND_STEEL), 1);
+        worn.damage_durability(3);
+        let fresh = ItemStack::new(ItemType::Item(client_item_ids::FLINT_AND_STEEL), 1);
+        assert!(!worn.can_merge_with(&fresh));
+    }
+
+    #[test]
+    fn test_stack_split() {
+        let mut stack = ItemStack::new(ItemType::Block(1), 10);
+        let taken = stack.split(4).unwrap();
+        assert_eq!((stack.count, taken.count), (6, 4));
+        assert!(stack.can_merge_with(&taken));
+
+        assert!(stack.split(7).is_none());
+        assert!(stack.split(0).is_none());
+        assert_eq!(stack.count, 6);
+
+        let rest = stack.split(6).unwrap();
+        assert_eq!((stack.count, rest.count), (0, 6));
+    }
+
     #[test]
     fn test_tool_material_ordering() {
         assert!(ToolMaterial::Diamond > ToolMaterial::Iron);
@@ -14,6 +14,8 @@ pub mod registry;
 
 use rand::{rngs::StdRng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::ops::Sub;
+use std::time::Duration;
 
 // Re-export commonly used types
 pub use components::{ComponentMap, ComponentValue};
@@ -24,21 +26,131 @@ pub use item::{ItemStack, ItemType, ToolMaterial, ToolType};
 pub use registry::RegistryKey;
 
 /// Fixed tick type (20 TPS => 50 ms per tick).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct SimTick(pub u64);
 
 impl SimTick {
     /// First tick in any deterministic timeline.
     pub const ZERO: Self = Self(0);
 
+    /// Simulation ticks per second.
+    pub const TICKS_PER_SECOND: u64 = 20;
+
+    /// Wall-clock length of one tick.
+    pub const DURATION: Duration = Duration::from_millis(1000 / Self::TICKS_PER_SECOND);
+
     /// Advance by `delta` ticks.
     pub fn advance(self, delta: u64) -> Self {
         Self(self.0 + delta)
     }
+
+    /// Ticks elapsed since `earlier`, or 0 if `earlier` is in the future.
+    pub fn saturating_sub(self, earlier: SimTick) -> u64 {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Wall-clock time covered by this many ticks.
+    pub fn to_duration(self) -> Duration {
+        Self::DURATION.saturating_mul(u32::try_from(self.0).unwrap_or(u32::MAX))
+    }
+
+    /// Whole ticks that fit in `duration` (rounded down).
+    pub fn from_duration(duration: Duration) -> Self {
+        Self((duration.as_millis() / Self::DURATION.as_millis()) as u64)
+    }
+}
+
+impl Sub for SimTick {
+    type Output = u64;
+
+    /// Tick delta; panics on underflow like integer subtraction.
+    /// Use [`SimTick::saturating_sub`] when `rhs` may be later.
+    fn sub(self, rhs: SimTick) -> u64 {
+        self.0 - rhs.0
+    }
 }
 
 /// Helper to derive a reproducible RNG seeded by world + tick domains.
+///
+/// The domains are mixed in order, so swapping or nudging any of them gives an
+/// unrelated stream.
 pub fn scoped_rng(world_seed: u64, chunk_hash: u64, tick: SimTick) -> StdRng {
-    let seed = world_seed ^ chunk_hash ^ tick.0;
+    let seed = splitmix64(splitmix64(splitmix64(world_seed) ^ chunk_hash) ^ tick.0);
     StdRng::seed_from_u64(seed)
 }
+
+/// SplitMix64 finalizer; every input bit affects every output bit.
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_durations_round_trip() {
+        assert_eq!(SimTick(1).to_duration(), Duration::from_millis(50));
+        assert_eq!(SimTick(20).to_duration(), Duration::from_secs(1));
+        for ticks in [0, 1, 7, 20, 6000] {
+            assert_eq!(
+                SimTick::from_duration(SimTick(ticks).to_duration()).0,
+                ticks
+            );
+        }
+        // Partial ticks round down.
+        assert_eq!(
+            SimTick::from_duration(Duration::from_millis(149)),
+            SimTick(2)
+        );
+    }
+
+    #[test]
+    fn tick_subtraction_measures_deltas() {
+        let start = SimTick(100);
+        let later = start.advance(40);
+        assert_eq!(later - start, 40);
+        assert_eq!(later.saturating_sub(start), 40);
+        assert_eq!(start.saturating_sub(later), 0);
+        assert_eq!(SimTick::ZERO.saturating_sub(SimTick(u64::MAX)), 0);
+    }
+
+    fn first_draw(world_seed: u64, chunk_hash: u64, tick: u64) -> u64 {
+        use rand::Rng;
+        scoped_rng(world_seed, chunk_hash, SimTick(tick)).gen()
+    }
+
+    #[test]
+    fn scoped_rng_domains_do_not_collide() {
+        // Plain XOR would give these identical streams.
+        assert_ne!(first_draw(1, 2, 3), first_draw(3, 2, 1));
+        assert_ne!(first_draw(0, 5, 0), first_draw(5, 0, 0));
+
+        let mut draws = std::collections::HashSet::new();
+        for world_seed in 0..8 {
+            for chunk_hash in 0..8 {
+                for tick in 0..8 {
+                    draws.insert(first_draw(world_seed, chunk_hash, tick));
+                }
+            }
+        }
+        assert_eq!(draws.len(), 8 * 8 * 8);
+    }
+
+    #[test]
+    fn scoped_rng_is_deterministic() {
+        use rand::Rng;
+        let mut a = scoped_rng(42, 0xDEAD_BEEF, SimTick(1_200));
+        let mut b = scoped_rng(42, 0xDEAD_BEEF, SimTick(1_200));
+        let a: Vec<u64> = (0..16).map(|_| a.gen()).collect();
+        let b: Vec<u64> = (0..16).map(|_| b.gen()).collect();
+        assert_eq!(a, b);
+        // Pin the stream so an accidental change to the mixing is caught; any
+        // such change reshuffles everything generated from these seeds.
+        assert_eq!(splitmix64(0), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(first_draw(42, 0xDEAD_BEEF, 1_200), 0x8AE2_7A3A_99FC_8B7B);
+    }
+}
@@ -1,27 +1,121 @@
 #![warn(missing_docs)]
 //! ECS schedule helpers wrapping `bevy_ecs` for deterministic staging.
 
-use bevy_ecs::schedule::{Schedule, ScheduleLabel, Schedules};
+use bevy_ecs::schedule::{
+    ExecutorKind, IntoSystemConfigs, LogLevel, Schedule, ScheduleBuildSettings, ScheduleLabel,
+    Schedules,
+};
 use bevy_ecs::world::World;
 use mdminecraft_core::SimTick;
 
-/// Labels for the default deterministic schedule.
+/// First stage of a tick: read input and queue intents.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScheduleLabel)]
-pub struct DefaultSimSchedule;
+pub struct PreSimSchedule;
 
-/// Build the baseline deterministic simulation schedule.
+/// Main stage of a tick: mutate simulation state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScheduleLabel)]
+pub struct SimSchedule;
+
+/// Last stage of a tick: resolve outcomes and flush events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScheduleLabel)]
+pub struct PostSimSchedule;
+
+/// How strictly stage schedules guard against nondeterministic ordering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScheduleOptions {
+    single_threaded: bool,
+    deny_ambiguities: bool,
+}
+
+impl ScheduleOptions {
+    /// Options with every determinism guard enabled.
+    pub fn strict() -> Self {
+        Self {
+            single_threaded: true,
+            deny_ambiguities: true,
+        }
+    }
+
+    /// Run systems on the single-threaded executor.
+    pub fn single_threaded(mut self, enabled: bool) -> Self {
+        self.single_threaded = enabled;
+        self
+    }
+
+    /// Fail schedule initialization when conflicting systems are unordered.
+    pub fn deny_ambiguities(mut self, enabled: bool) -> Self {
+        self.deny_ambiguities = enabled;
+        self
+    }
+
+    /// Build the stage schedules with these options.
+    pub fn build(self) -> Schedules {
+        let mut schedules = Schedules::default();
+        for label in [
+            PreSimSchedule.intern(),
+            SimSchedule.intern(),
+            PostSimSchedule.intern(),
+        ] {
+            schedules.insert(self.stage(label));
+        }
+        schedules
+    }
+
+    fn stage(self, label: impl ScheduleLabel) -> Schedule {
+        let mut schedule = Schedule::new(label);
+        schedule.set_apply_final_deferred(true);
+        if self.single_threaded {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        }
+        if self.deny_ambiguities {
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..Default::default()
+            });
+        }
+        schedule
+    }
+}
+
+/// Build the baseline deterministic simulation schedules, one per stage.
+///
+/// Each stage applies deferred commands before the next one starts.
 pub fn build_default_schedule() -> Schedules {
-    let mut schedules = Schedules::default();
-    let mut schedule = Schedule::new(DefaultSimSchedule);
-    schedule.set_apply_final_deferred(true);
+    ScheduleOptions::default().build()
+}
+
+/// Like [`build_default_schedule`], but single-threaded and rejecting
+/// ambiguous system orderings when a stage is first run or initialized.
+pub fn build_default_schedule_strict() -> Schedules {
+    ScheduleOptions::strict().build()
+}
+
+/// Register `systems` in the given stage, creating the stage if missing.
+pub fn add_system_to_stage<M>(
+    schedules: &mut Schedules,
+    stage: impl ScheduleLabel,
+    systems: impl IntoSystemConfigs<M>,
+) {
+    if let Some(schedule) = schedules.get_mut(stage.intern()) {
+        schedule.add_systems(systems);
+        return;
+    }
+
+    let mut schedule = ScheduleOptions::default().stage(stage);
+    schedule.add_systems(systems);
     schedules.insert(schedule);
-    schedules
 }
 
-/// Run the default schedule for a given tick.
+/// Run every stage, in order, for a given tick.
 pub fn run_tick(world: &mut World, schedules: &mut Schedules, tick: SimTick) {
     tracing::debug!(tick = tick.0, "running deterministic schedule");
-    if let Some(schedule) = schedules.get_mut(DefaultSimSchedule) {
+    if let Some(schedule) = schedules.get_mut(PreSimSchedule) {
+        schedule.run(world);
+    }
+    if let Some(schedule) = schedules.get_mut(SimSchedule) {
+        schedule.run(world);
+    }
+    if let Some(schedule) = schedules.get_mut(PostSimSchedule) {
         schedule.run(world);
     }
 }
@@ -29,18 +123,37 @@ pub fn run_tick(world: &mut World, schedules: &mut Schedules, tick: SimTick) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bevy_ecs::system::{ResMut, Resource};
+    use bevy_ecs::component::Component;
+    use bevy_ecs::system::{Query, ResMut, Resource};
 
     #[derive(Resource, Default)]
     struct Counter(u32);
 
+    #[derive(Resource, Default)]
+    struct Trace(Vec<&'static str>);
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    fn heal(mut query: Query<&mut Health>) {
+        for mut health in &mut query {
+            health.0 += 1;
+        }
+    }
+
+    fn hurt(mut query: Query<&mut Health>) {
+        for mut health in &mut query {
+            health.0 -= 1;
+        }
+    }
+
     #[test]
     fn default_schedule_runs_added_systems() {
         let mut world = World::default();
         world.insert_resource(Counter::default());
         let mut schedules = build_default_schedule();
 
-        if let Some(schedule) = schedules.get_mut(DefaultSimSchedule) {
+        if let Some(schedule) = schedules.get_mut(SimSchedule) {
             schedule.add_systems(|mut counter: ResMut<Counter>| {
                 counter.0 += 1;
             });
@@ -49,4 +162,50 @@ mod tests {
         run_tick(&mut world, &mut schedules, SimTick::ZERO);
         assert_eq!(world.resource::<Counter>().0, 1);
     }
+
+    #[test]
+    fn stages_run_in_order_within_one_tick() {
+        let mut world = World::default();
+        world.insert_resource(Trace::default());
+        let mut schedules = build_default_schedule();
+
+        // Registered out of order on purpose.
+        add_system_to_stage(
+            &mut schedules,
+            PostSimSchedule,
+            |mut trace: ResMut<Trace>| trace.0.push("post"),
+        );
+        add_system_to_stage(&mut schedules, SimSchedule, |mut trace: ResMut<Trace>| {
+            trace.0.push("sim")
+        });
+        add_system_to_stage(
+            &mut schedules,
+            PreSimSchedule,
+            |mut trace: ResMut<Trace>| trace.0.push("pre"),
+        );
+
+        run_tick(&mut world, &mut schedules, SimTick::ZERO);
+        assert_eq!(world.resource::<Trace>().0, ["pre", "sim", "post"]);
+    }
+
+    #[test]
+    fn strict_schedule_rejects_ambiguous_writers() {
+        let mut world = World::default();
+        let mut schedules = build_default_schedule_strict();
+        add_system_to_stage(&mut schedules, SimSchedule, (heal, hurt));
+        let schedule = schedules.get_mut(SimSchedule).unwrap();
+        assert!(schedule.initialize(&mut world).is_err());
+
+        // Ordering the writers resolves the ambiguity.
+        let mut schedules = build_default_schedule_strict();
+        add_system_to_stage(&mut schedules, SimSchedule, (heal, hurt).chain());
+        let schedule = schedules.get_mut(SimSchedule).unwrap();
+        assert!(schedule.initialize(&mut world).is_ok());
+
+        // The relaxed schedule tolerates it.
+        let mut schedules = build_default_schedule();
+        add_system_to_stage(&mut schedules, SimSchedule, (heal, hurt));
+        let schedule = schedules.get_mut(SimSchedule).unwrap();
+        assert!(schedule.initialize(&mut world).is_ok());
+    }
 }
@@ -7,8 +7,47 @@ use anyhow::{Context, Result};
 use mdminecraft_core::DimensionId;
 use std::collections::HashMap;
 
+/// Compression scheme used for a chunk payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeMethod {
+    /// Palette of unique block IDs plus run-length encoded palette indices.
+    PaletteRle,
+}
+
+/// Size accounting for one encoded chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeReport {
+    /// Size of the raw block array (2 bytes per block).
+    pub raw_bytes: usize,
+    /// Size of the palette plus compressed indices.
+    pub encoded_bytes: usize,
+    /// Number of unique block IDs in the chunk.
+    pub palette_size: usize,
+    /// Compression scheme used.
+    pub method: EncodeMethod,
+}
+
+impl EncodeReport {
+    /// Space saved as a percentage of the raw size.
+    pub fn ratio(&self) -> f32 {
+        compression_ratio(self.raw_bytes, self.encoded_bytes.min(self.raw_bytes))
+    }
+}
+
 /// Encode chunk data with palette and RLE compression.
 ///
+/// See [`encode_chunk_data_reported`] for byte counts.
+pub fn encode_chunk_data(
+    dimension: DimensionId,
+    chunk_x: i32,
+    chunk_z: i32,
+    block_data: &[BlockId],
+) -> Result<ChunkDataMessage> {
+    encode_chunk_data_reported(dimension, chunk_x, chunk_z, block_data).map(|(msg, _)| msg)
+}
+
+/// Encode chunk data and report how large the encoded payload is.
+///
 /// Process:
 /// 1. Build palette of unique block IDs in the chunk
 /// 2. Replace block IDs with palette indices
@@ -16,12 +55,12 @@ use std::collections::HashMap;
 /// 4. Calculate CRC32 for validation
 ///
 /// Typical compression: 80-95% for natural terrain.
-pub fn encode_chunk_data(
+pub fn encode_chunk_data_reported(
     dimension: DimensionId,
     chunk_x: i32,
     chunk_z: i32,
     block_data: &[BlockId],
-) -> Result<ChunkDataMessage> {
+) -> Result<(ChunkDataMessage, EncodeReport)> {
     if block_data.len() != CHUNK_VOLUME {
         return Err(anyhow::anyhow!(
             "Invalid chunk data size: expected {}, got {}",
@@ -39,14 +78,24 @@ pub fn encode_chunk_data(
     // Step 3: Calculate CRC32
     let crc32 = calculate_crc32(&palette, &compressed_data);
 
-    Ok(ChunkDataMessage {
-        dimension,
-        chunk_x,
-        chunk_z,
-        palette,
-        compressed_data,
-        crc32,
-    })
+    let report = EncodeReport {
+        raw_bytes: std::mem::size_of_val(block_data),
+        encoded_bytes: compressed_data.len() + palette.len() * std::mem::size_of::<BlockId>(),
+        palette_size: palette.len(),
+        method: EncodeMethod::PaletteRle,
+    };
+
+    Ok((
+        ChunkDataMessage {
+            dimension,
+            chunk_x,
+            chunk_z,
+            palette,
+            compressed_data,
+            crc32,
+        },
+        report,
+    ))
 }
 
 /// Decode chunk data from palette and RLE compressed format.
@@ -349,6 +398,39 @@ mod tests {
         assert_eq!(decoded, block_data);
     }
 
+    #[test]
+    fn test_encode_report_reflects_compressibility() {
+        let air = vec![0u16; CHUNK_VOLUME];
+        let (_, air_report) =
+            encode_chunk_data_reported(DimensionId::DEFAULT, 0, 0, &air).expect("encode air");
+
+        // Deterministic noise over 200 block types.
+        let mut state = 0x2545_f491u32;
+        let noise: Vec<BlockId> = (0..CHUNK_VOLUME)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state % 200) as BlockId
+            })
+            .collect();
+        let (msg, noise_report) =
+            encode_chunk_data_reported(DimensionId::DEFAULT, 0, 0, &noise).expect("encode noise");
+
+        assert_eq!(air_report.raw_bytes, CHUNK_VOLUME * 2);
+        assert_eq!(air_report.palette_size, 1);
+        assert_eq!(air_report.method, EncodeMethod::PaletteRle);
+        assert_eq!(noise_report.palette_size, 200);
+        assert_eq!(
+            noise_report.encoded_bytes,
+            msg.compressed_data.len() + msg.palette.len() * 2
+        );
+
+        assert!(air_report.encoded_bytes * 20 < noise_report.encoded_bytes);
+        assert!(air_report.ratio() > 99.0);
+        assert!(noise_report.ratio() < 60.0);
+    }
+
     #[test]
     fn test_crc32_validation() {
         let block_data = vec![1u16; CHUNK_VOLUME];
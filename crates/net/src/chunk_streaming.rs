@@ -3,8 +3,8 @@
 //! Manages efficient chunk data delivery to clients with distance-based priority
 //! and configurable bandwidth limits.
 
-use crate::chunk_encoding::encode_chunk_data;
-use crate::protocol::{BlockId, ChunkDataMessage, CHUNK_VOLUME};
+use crate::chunk_encoding::encode_chunk_data_reported;
+use crate::protocol::{BlockId, ChunkDataMessage};
 use anyhow::Result;
 use mdminecraft_core::DimensionId;
 use std::cmp::Ordering;
@@ -38,6 +38,9 @@ pub struct ChunkStreamer {
     bytes_sent_this_second: u64,
     last_reset_time: Instant,
 
+    /// Encoded bytes allowed per simulation tick.
+    byte_budget_per_tick: usize,
+
     /// Metrics.
     metrics: StreamingMetrics,
 }
@@ -81,9 +84,12 @@ pub struct StreamingMetrics {
     /// Total chunks sent.
     pub chunks_sent: u64,
 
-    /// Current queue size.
+    /// Current queue size (chunks waiting to be sent).
     pub queue_size: usize,
 
+    /// Encoded bytes sent since the last [`ChunkStreamer::begin_tick`].
+    pub bytes_sent_this_tick: usize,
+
     /// Bandwidth utilization (bytes/sec).
     pub bandwidth_used: u64,
 
@@ -109,10 +115,52 @@ impl ChunkStreamer {
             bandwidth_limit,
             bytes_sent_this_second: 0,
             last_reset_time: Instant::now(),
+            byte_budget_per_tick: usize::MAX,
             metrics: StreamingMetrics::default(),
         }
     }
 
+    /// Cap encoded bytes sent per tick so a fresh join can't flood the link.
+    pub fn with_byte_budget_per_tick(mut self, budget: usize) -> Self {
+        self.byte_budget_per_tick = budget;
+        self
+    }
+
+    /// Change the per-tick byte budget.
+    pub fn set_byte_budget_per_tick(&mut self, budget: usize) {
+        self.byte_budget_per_tick = budget;
+    }
+
+    /// Per-tick byte budget (`usize::MAX` when unlimited).
+    pub fn byte_budget_per_tick(&self) -> usize {
+        self.byte_budget_per_tick
+    }
+
+    /// Start a new tick, refilling the per-tick byte budget.
+    pub fn begin_tick(&mut self) {
+        self.metrics.bytes_sent_this_tick = 0;
+    }
+
+    /// Start a new tick and send queued chunks, nearest first, until the
+    /// budget is spent or the queue is empty.
+    pub fn send_tick(
+        &mut self,
+        chunk_data_provider: &dyn Fn(DimensionId, i32, i32) -> Option<Vec<BlockId>>,
+    ) -> Result<Vec<ChunkDataMessage>> {
+        self.begin_tick();
+        let mut sent = Vec::new();
+        loop {
+            let queued = self.queued_chunks.len();
+            match self.try_send_next_chunk(chunk_data_provider)? {
+                Some(msg) => sent.push(msg),
+                // An unavailable chunk was dropped; keep going.
+                None if self.queued_chunks.len() < queued => {}
+                None => break,
+            }
+        }
+        Ok(sent)
+    }
+
     /// Update player position and recalculate priorities.
     pub fn set_player_position(&mut self, chunk_x: i32, chunk_z: i32) {
         self.set_player_position_in_dimension(DimensionId::DEFAULT, chunk_x, chunk_z);
@@ -187,8 +235,9 @@ impl ChunkStreamer {
 
     /// Try to send the next chunk from the queue.
     ///
-    /// Returns Some(ChunkDataMessage) if a chunk was sent, None if bandwidth limit reached
-    /// or queue is empty.
+    /// Returns Some(ChunkDataMessage) if a chunk was sent, None if the bandwidth limit or
+    /// per-tick budget is reached, or the queue is empty. The first chunk of a tick is
+    /// always allowed through the tick budget so oversized chunks can't stall the queue.
     pub fn try_send_next_chunk(
         &mut self,
         chunk_data_provider: &dyn Fn(DimensionId, i32, i32) -> Option<Vec<BlockId>>,
@@ -223,11 +272,10 @@ impl ChunkStreamer {
         };
 
         // Encode chunk data
-        let encoded = encode_chunk_data(dimension, chunk_x, chunk_z, &block_data)?;
-
-        // Calculate compressed size
-        let uncompressed_size = CHUNK_VOLUME as u64 * 2; // 2 bytes per BlockId
-        let compressed_size = encoded.compressed_data.len() + encoded.palette.len() * 2;
+        let (encoded, report) =
+            encode_chunk_data_reported(dimension, chunk_x, chunk_z, &block_data)?;
+        let uncompressed_size = report.raw_bytes as u64;
+        let compressed_size = report.encoded_bytes;
 
         // Check bandwidth limit (use compressed size)
         if self.bytes_sent_this_second + compressed_size as u64 > self.bandwidth_limit {
@@ -235,6 +283,13 @@ impl ChunkStreamer {
             return Ok(None);
         }
 
+        // Check per-tick budget
+        let spent = self.metrics.bytes_sent_this_tick;
+        if spent > 0 && spent.saturating_add(compressed_size) > self.byte_budget_per_tick {
+            // Would exceed this tick's budget, resume next tick
+            return Ok(None);
+        }
+
         // Send chunk (remove from queue)
         self.send_queue.pop();
         self.queued_chunks.remove(&(dimension, chunk_x, chunk_z));
@@ -242,6 +297,7 @@ impl ChunkStreamer {
 
         // Update metrics
         self.bytes_sent_this_second += compressed_size as u64;
+        self.metrics.bytes_sent_this_tick += compressed_size;
         self.metrics.total_bytes_uncompressed += uncompressed_size;
         self.metrics.total_bytes_compressed += compressed_size as u64;
         self.metrics.chunks_sent += 1;
@@ -330,6 +386,8 @@ impl Default for ChunkStreamer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chunk_encoding::encode_chunk_data;
+    use crate::protocol::CHUNK_VOLUME;
 
     fn make_uniform_chunk(block_id: BlockId) -> Vec<BlockId> {
         vec![block_id; CHUNK_VOLUME]
@@ -425,6 +483,68 @@ mod tests {
         assert_eq!(streamer.sent_count(), 1);
     }
 
+    fn uniform_chunk_size() -> usize {
+        let (_, report) = crate::chunk_encoding::encode_chunk_data_reported(
+            DimensionId::DEFAULT,
+            0,
+            0,
+            &make_uniform_chunk(1),
+        )
+        .expect("Encode failed");
+        report.encoded_bytes
+    }
+
+    #[test]
+    fn test_tick_budget_spreads_chunks_across_ticks() {
+        let size = uniform_chunk_size();
+        let mut streamer = ChunkStreamer::new().with_byte_budget_per_tick(size * 2);
+        for i in 0..5 {
+            streamer.enqueue_chunk(i, 0);
+        }
+        let provider = |_: DimensionId, _: i32, _: i32| Some(make_uniform_chunk(1));
+
+        let per_tick: Vec<usize> = (0..4)
+            .map(|_| streamer.send_tick(&provider).expect("Send failed").len())
+            .collect();
+        assert_eq!(per_tick, vec![2, 2, 1, 0]);
+        assert_eq!(streamer.sent_count(), 5);
+        assert_eq!(streamer.metrics().bytes_sent_this_tick, 0);
+        assert_eq!(streamer.metrics().queue_size, 0);
+
+        // A budget smaller than one chunk still sends one per tick.
+        let mut tight = ChunkStreamer::new().with_byte_budget_per_tick(1);
+        tight.enqueue_chunk(0, 0);
+        tight.enqueue_chunk(1, 0);
+        assert_eq!(tight.send_tick(&provider).unwrap().len(), 1);
+        assert_eq!(tight.metrics().bytes_sent_this_tick, size);
+        assert_eq!(tight.metrics().queue_size, 1);
+    }
+
+    #[test]
+    fn test_tick_budget_sends_nearest_chunks_first() {
+        let size = uniform_chunk_size();
+        let mut streamer = ChunkStreamer::new().with_byte_budget_per_tick(size);
+        streamer.set_player_position(10, -4);
+        for (x, z) in [(0, 0), (10, -4), (14, -4), (11, -3), (10, 2), (8, -5)] {
+            streamer.enqueue_chunk(x, z);
+        }
+        let provider = |_: DimensionId, _: i32, _: i32| Some(make_uniform_chunk(1));
+
+        let mut order = Vec::new();
+        while streamer.queue_size() > 0 {
+            for msg in streamer.send_tick(&provider).expect("Send failed") {
+                order.push((msg.chunk_x, msg.chunk_z));
+            }
+        }
+        let distances: Vec<i32> = order
+            .iter()
+            .map(|(x, z)| (x - 10).abs().max((z + 4).abs()))
+            .collect();
+        assert_eq!(order[0], (10, -4));
+        assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(order.last(), Some(&(0, 0)));
+    }
+
     #[test]
     fn test_metrics() {
         let mut streamer = ChunkStreamer::new();
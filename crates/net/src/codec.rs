@@ -2,9 +2,13 @@
 //!
 //! Provides length-prefixed encoding for reliable delivery over QUIC streams.
 
-use crate::protocol::{ClientMessage, ServerMessage, PROTOCOL_MAGIC, PROTOCOL_VERSION};
+use crate::protocol::{
+    schema_samples, ClientMessage, ServerMessage, PROTOCOL_MAGIC, PROTOCOL_VERSION,
+};
 use anyhow::{Context, Result};
 use blake3::Hash;
+use serde::ser::{self, Serialize};
+use std::sync::OnceLock;
 
 /// Maximum allowed frame size (16 MB).
 /// Prevents memory exhaustion from malicious length prefixes.
@@ -13,27 +17,360 @@ pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
 /// Compute schema hash from protocol definitions.
 ///
 /// This hash is used to ensure client and server have compatible protocol versions.
+/// It covers [`PROTOCOL_VERSION`], [`PROTOCOL_MAGIC`] and the shape (variant
+/// names, field names in order and leaf types including their width) of every
+/// message, so renaming, adding, reordering or retyping a field produces a
+/// different hash.
 pub fn compute_schema_hash() -> u64 {
-    // Hash the serialized message type definitions
+    static HASH: OnceLock<u64> = OnceLock::new();
+    *HASH.get_or_init(|| {
+        let (client, server) = schema_samples();
+        let mut shapes = Vec::with_capacity(client.len() + server.len());
+        for msg in &client {
+            shapes.push(schema_shape(msg).expect("client message serializes"));
+        }
+        for msg in &server {
+            shapes.push(schema_shape(msg).expect("server message serializes"));
+        }
+        hash_schema(PROTOCOL_VERSION, &shapes)
+    })
+}
+
+/// Hash protocol identity plus message shapes.
+fn hash_schema(version: u16, shapes: &[String]) -> u64 {
     let mut hasher = blake3::Hasher::new();
 
     // Include protocol version
-    hasher.update(&PROTOCOL_VERSION.to_le_bytes());
+    hasher.update(&version.to_le_bytes());
 
     // Include protocol magic
     hasher.update(PROTOCOL_MAGIC);
 
-    // Include message type names (deterministic)
-    hasher.update(b"ClientMessage");
-    hasher.update(b"ServerMessage");
-    hasher.update(b"InputBundle");
-    hasher.update(b"ChunkDataMessage");
-    hasher.update(b"EntityDeltaMessage");
+    // Include message shapes
+    for shape in shapes {
+        hasher.update(shape.as_bytes());
+        hasher.update(b"\n");
+    }
 
     let hash: Hash = hasher.finalize();
     u64::from_le_bytes(hash.as_bytes()[0..8].try_into().unwrap())
 }
 
+/// Render the type structure of `value`, ignoring its data.
+fn schema_shape<T: Serialize + ?Sized>(value: &T) -> Result<String, ShapeError> {
+    value.serialize(ShapeSerializer)
+}
+
+type ShapeError = serde::de::value::Error;
+
+/// Serializer that writes a value's type structure instead of its data.
+///
+/// Leaves become their Rust type (`i32`, `f32`, `string`, ...), so a retype
+/// that changes the postcard encoding also changes the shape. Fields keep
+/// declaration order, and sequence elements are reduced to their distinct
+/// shapes.
+struct ShapeSerializer;
+
+macro_rules! leaf_shapes {
+    ($($method:ident: $ty:ty => $name:literal),* $(,)?) => {
+        $(
+            fn $method(self, _: $ty) -> Result<String, ShapeError> {
+                Ok($name.to_string())
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for ShapeSerializer {
+    type Ok = String;
+    type Error = ShapeError;
+    type SerializeSeq = SeqShape;
+    type SerializeTuple = TupleShape;
+    type SerializeTupleStruct = TupleShape;
+    type SerializeTupleVariant = TupleShape;
+    type SerializeMap = MapShape;
+    type SerializeStruct = StructShape;
+    type SerializeStructVariant = StructShape;
+
+    leaf_shapes! {
+        serialize_bool: bool => "bool",
+        serialize_i8: i8 => "i8",
+        serialize_i16: i16 => "i16",
+        serialize_i32: i32 => "i32",
+        serialize_i64: i64 => "i64",
+        serialize_i128: i128 => "i128",
+        serialize_u8: u8 => "u8",
+        serialize_u16: u16 => "u16",
+        serialize_u32: u32 => "u32",
+        serialize_u64: u64 => "u64",
+        serialize_u128: u128 => "u128",
+        serialize_f32: f32 => "f32",
+        serialize_f64: f64 => "f64",
+        serialize_char: char => "char",
+        serialize_str: &str => "string",
+        serialize_bytes: &[u8] => "bytes",
+    }
+
+    fn serialize_none(self) -> Result<String, ShapeError> {
+        Ok("option".to_string())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, ShapeError> {
+        Ok(format!("option<{}>", schema_shape(value)?))
+    }
+
+    fn serialize_unit(self) -> Result<String, ShapeError> {
+        Ok("()".to_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, ShapeError> {
+        Ok("()".to_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<String, ShapeError> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, ShapeError> {
+        schema_shape(value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<String, ShapeError> {
+        Ok(format!("{variant}({})", schema_shape(value)?))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqShape, ShapeError> {
+        Ok(SeqShape::default())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<TupleShape, ShapeError> {
+        Ok(TupleShape::default())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<TupleShape, ShapeError> {
+        Ok(TupleShape::default())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<TupleShape, ShapeError> {
+        Ok(TupleShape {
+            prefix: variant,
+            items: Vec::new(),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapShape, ShapeError> {
+        Ok(MapShape::default())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<StructShape, ShapeError> {
+        Ok(StructShape::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<StructShape, ShapeError> {
+        Ok(StructShape {
+            prefix: variant,
+            fields: Vec::new(),
+        })
+    }
+}
+
+/// Distinct element shapes of a sequence, in first-seen order.
+#[derive(Default)]
+struct SeqShape {
+    items: Vec<String>,
+}
+
+impl ser::SerializeSeq for SeqShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ShapeError> {
+        let shape = schema_shape(value)?;
+        if !self.items.contains(&shape) {
+            self.items.push(shape);
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(format!("[{}]", self.items.join("|")))
+    }
+}
+
+/// Positional element shapes of a tuple, tuple struct or tuple variant.
+#[derive(Default)]
+struct TupleShape {
+    prefix: &'static str,
+    items: Vec<String>,
+}
+
+impl TupleShape {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ShapeError> {
+        self.items.push(schema_shape(value)?);
+        Ok(())
+    }
+
+    fn finish(self) -> String {
+        format!("{}({})", self.prefix, self.items.join(","))
+    }
+}
+
+impl ser::SerializeTuple for TupleShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ShapeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for TupleShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ShapeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleVariant for TupleShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ShapeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(self.finish())
+    }
+}
+
+/// Distinct `key:value` entry shapes of a map, in first-seen order.
+#[derive(Default)]
+struct MapShape {
+    entries: Vec<String>,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for MapShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ShapeError> {
+        self.key = Some(schema_shape(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ShapeError> {
+        let key = self.key.take().unwrap_or_default();
+        let entry = format!("{key}:{}", schema_shape(value)?);
+        if !self.entries.contains(&entry) {
+            self.entries.push(entry);
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(format!("map{{{}}}", self.entries.join("|")))
+    }
+}
+
+/// Named field shapes of a struct or struct variant, in declaration order.
+#[derive(Default)]
+struct StructShape {
+    prefix: &'static str,
+    fields: Vec<String>,
+}
+
+impl StructShape {
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), ShapeError> {
+        self.fields.push(format!("{name}:{}", schema_shape(value)?));
+        Ok(())
+    }
+
+    fn finish(self) -> String {
+        format!("{}{{{}}}", self.prefix, self.fields.join(","))
+    }
+}
+
+impl ser::SerializeStruct for StructShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), ShapeError> {
+        self.push(name, value)
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStructVariant for StructShape {
+    type Ok = String;
+    type Error = ShapeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), ShapeError> {
+        self.push(name, value)
+    }
+
+    fn end(self) -> Result<String, ShapeError> {
+        Ok(self.finish())
+    }
+}
+
 /// Encode a client message with length prefix.
 ///
 /// Frame format: [length: u32][message_type: u8][payload: bytes]
@@ -196,6 +533,96 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[test]
+    fn test_schema_hash_tracks_protocol_shape() {
+        let (client, _) = schema_samples();
+        let shapes = |msgs: &[ClientMessage]| -> Vec<String> {
+            msgs.iter().map(|msg| schema_shape(msg).unwrap()).collect()
+        };
+        let base_hash = hash_schema(PROTOCOL_VERSION, &shapes(&client));
+
+        // Sample values don't matter, only structure.
+        let mut chat = client.clone();
+        chat[2] = ClientMessage::Chat {
+            text: "hello".into(),
+        };
+        assert_eq!(hash_schema(PROTOCOL_VERSION, &shapes(&chat)), base_hash);
+
+        // So does bumping the protocol version.
+        assert_ne!(
+            hash_schema(PROTOCOL_VERSION + 1, &shapes(&client)),
+            base_hash
+        );
+    }
+
+    #[test]
+    fn test_schema_hash_detects_field_changes() {
+        #[derive(serde::Serialize)]
+        struct Base {
+            version: i32,
+            name: String,
+        }
+        #[derive(serde::Serialize)]
+        struct Renamed {
+            protocol_version: i32,
+            name: String,
+        }
+        #[derive(serde::Serialize)]
+        struct Extended {
+            version: i32,
+            name: String,
+            channel: u8,
+        }
+        #[derive(serde::Serialize)]
+        struct Reordered {
+            name: String,
+            version: i32,
+        }
+        #[derive(serde::Serialize)]
+        struct Float {
+            version: f32,
+            name: String,
+        }
+        #[derive(serde::Serialize)]
+        struct Widened {
+            version: i64,
+            name: String,
+        }
+
+        let hash =
+            |shape: Result<String, ShapeError>| hash_schema(PROTOCOL_VERSION, &[shape.unwrap()]);
+        let name = String::from("steve");
+        let base = hash(schema_shape(&Base {
+            version: 1,
+            name: name.clone(),
+        }));
+
+        let changed = [
+            hash(schema_shape(&Renamed {
+                protocol_version: 1,
+                name: name.clone(),
+            })),
+            hash(schema_shape(&Extended {
+                version: 1,
+                name: name.clone(),
+                channel: 0,
+            })),
+            hash(schema_shape(&Reordered {
+                name: name.clone(),
+                version: 1,
+            })),
+            // i32 -> f32 has the same JSON number kind but a different encoding.
+            hash(schema_shape(&Float {
+                version: 1.0,
+                name: name.clone(),
+            })),
+            hash(schema_shape(&Widened { version: 1, name })),
+        ];
+        for changed in changed {
+            assert_ne!(changed, base);
+        }
+    }
+
     #[test]
     fn test_schema_hash_non_zero() {
         let hash = compute_schema_hash();
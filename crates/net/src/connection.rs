@@ -12,6 +12,67 @@ use anyhow::Result;
 use std::net::SocketAddr;
 use tracing::{debug, info, warn};
 
+/// Why a client handshake was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HandshakeRejection {
+    /// Client speaks a different protocol version.
+    VersionMismatch { client: u16, server: u16 },
+    /// Client was built against different message definitions.
+    SchemaMismatch { client: u64, server: u64 },
+}
+
+impl HandshakeRejection {
+    /// Reason sent back to the client.
+    fn reason(&self) -> String {
+        match self {
+            HandshakeRejection::VersionMismatch { server, .. } => {
+                format!("Protocol version mismatch: server uses v{}", server)
+            }
+            HandshakeRejection::SchemaMismatch { .. } => {
+                "Schema mismatch: incompatible client version".to_string()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for HandshakeRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandshakeRejection::VersionMismatch { client, server } => {
+                write!(f, "Protocol version mismatch: {} != {}", client, server)
+            }
+            HandshakeRejection::SchemaMismatch { client, server } => {
+                write!(
+                    f,
+                    "Schema hash mismatch: {:016x} != {:016x}",
+                    client, server
+                )
+            }
+        }
+    }
+}
+
+/// Validate a client's handshake against the server's protocol identity.
+fn check_handshake(
+    version: u16,
+    schema_hash: u64,
+    server_schema_hash: u64,
+) -> Result<(), HandshakeRejection> {
+    if version != PROTOCOL_VERSION {
+        return Err(HandshakeRejection::VersionMismatch {
+            client: version,
+            server: PROTOCOL_VERSION,
+        });
+    }
+    if schema_hash != server_schema_hash {
+        return Err(HandshakeRejection::SchemaMismatch {
+            client: schema_hash,
+            server: server_schema_hash,
+        });
+    }
+    Ok(())
+}
+
 /// Client-side connection wrapping QUIC transport and protocol handling.
 pub struct ClientConnection {
     channel_manager: ChannelManager,
@@ -150,37 +211,10 @@ impl ServerConnection {
                     version, schema_hash
                 );
 
-                // Validate version
-                if version != PROTOCOL_VERSION {
-                    warn!(
-                        "Protocol version mismatch: client={}, server={}",
-                        version, PROTOCOL_VERSION
-                    );
-                    self.reject_handshake(&format!(
-                        "Protocol version mismatch: server uses v{}",
-                        PROTOCOL_VERSION
-                    ))
-                    .await?;
-                    return Err(anyhow::anyhow!(
-                        "Protocol version mismatch: {} != {}",
-                        version,
-                        PROTOCOL_VERSION
-                    ));
-                }
-
-                // Validate schema hash
-                if schema_hash != self.schema_hash {
-                    warn!(
-                        "Schema hash mismatch: client={:016x}, server={:016x}",
-                        schema_hash, self.schema_hash
-                    );
-                    self.reject_handshake("Schema mismatch: incompatible client version")
-                        .await?;
-                    return Err(anyhow::anyhow!(
-                        "Schema hash mismatch: {:016x} != {:016x}",
-                        schema_hash,
-                        self.schema_hash
-                    ));
+                if let Err(rejection) = check_handshake(version, schema_hash, self.schema_hash) {
+                    warn!("{}", rejection);
+                    self.reject_handshake(&rejection.reason()).await?;
+                    return Err(anyhow::anyhow!("{}", rejection));
                 }
 
                 Ok(schema_hash)
@@ -300,6 +334,31 @@ mod tests {
         })
     }
 
+    #[test]
+    fn mismatched_schema_hash_is_refused() {
+        let server_hash = compute_schema_hash();
+        assert_eq!(
+            check_handshake(PROTOCOL_VERSION, server_hash, server_hash),
+            Ok(())
+        );
+
+        let client_hash = server_hash ^ 1;
+        let rejection = check_handshake(PROTOCOL_VERSION, client_hash, server_hash).unwrap_err();
+        assert_eq!(
+            rejection,
+            HandshakeRejection::SchemaMismatch {
+                client: client_hash,
+                server: server_hash,
+            }
+        );
+        assert!(rejection.reason().contains("Schema mismatch"));
+
+        assert!(matches!(
+            check_handshake(PROTOCOL_VERSION + 1, server_hash, server_hash),
+            Err(HandshakeRejection::VersionMismatch { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn test_handshake_success() {
         // Start server
@@ -0,0 +1,185 @@
+//! Length-prefixed, checksummed framing for byte-stream transports.
+//!
+//! QUIC reliable channels already carry one message per stream, but ordered
+//! byte streams (TCP, pipes, replay files) can split or coalesce writes.
+//! [`FramedCodec`] prefixes each payload with its length and CRC32 and
+//! reassembles frames from arbitrarily chunked input.
+//!
+//! Frame format: [length: u32 BE][crc32: u32 BE][payload: bytes]
+
+use crate::codec::MAX_FRAME_SIZE;
+use thiserror::Error;
+
+/// Size of the length + checksum header.
+pub const FRAME_HEADER_LEN: usize = 8;
+
+/// Errors produced while decoding frames.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FrameError {
+    /// The stream ended partway through a frame.
+    #[error("stream ended mid-frame: {buffered} bytes buffered, {expected} expected")]
+    Truncated {
+        /// Bytes left in the buffer.
+        buffered: usize,
+        /// Bytes the pending frame needs (header included).
+        expected: usize,
+    },
+    /// Payload checksum did not match the header.
+    #[error("frame checksum mismatch: expected {expected:08x}, got {actual:08x}")]
+    BadChecksum {
+        /// Checksum from the frame header.
+        expected: u32,
+        /// Checksum of the received payload.
+        actual: u32,
+    },
+    /// Declared length exceeds [`MAX_FRAME_SIZE`].
+    #[error("frame too large: {0} bytes (max {MAX_FRAME_SIZE})")]
+    TooLarge(usize),
+}
+
+/// Encoder and incremental decoder for length-prefixed frames.
+#[derive(Debug, Default)]
+pub struct FramedCodec {
+    buffer: Vec<u8>,
+}
+
+impl FramedCodec {
+    /// Create a codec with an empty receive buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap `payload` in a frame header.
+    pub fn encode(payload: &[u8]) -> Result<Vec<u8>, FrameError> {
+        if payload.len() > MAX_FRAME_SIZE {
+            return Err(FrameError::TooLarge(payload.len()));
+        }
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&crc32fast::hash(payload).to_be_bytes());
+        frame.extend_from_slice(payload);
+        Ok(frame)
+    }
+
+    /// Append bytes read from the transport.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Pop the next complete payload, or `Ok(None)` if more bytes are needed.
+    ///
+    /// A corrupt frame is consumed before its error is returned, so decoding
+    /// can continue with the following frame.
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>, FrameError> {
+        if self.buffer.len() < FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+        let len = u32::from_be_bytes(self.buffer[0..4].try_into().unwrap()) as usize;
+        if len > MAX_FRAME_SIZE {
+            self.buffer.clear();
+            return Err(FrameError::TooLarge(len));
+        }
+        if self.buffer.len() < FRAME_HEADER_LEN + len {
+            return Ok(None);
+        }
+
+        let expected = u32::from_be_bytes(self.buffer[4..8].try_into().unwrap());
+        let frame: Vec<u8> = self.buffer.drain(..FRAME_HEADER_LEN + len).collect();
+        let payload = frame[FRAME_HEADER_LEN..].to_vec();
+        let actual = crc32fast::hash(&payload);
+        if actual != expected {
+            return Err(FrameError::BadChecksum { expected, actual });
+        }
+        Ok(Some(payload))
+    }
+
+    /// Check that the stream ended on a frame boundary.
+    pub fn finish(&self) -> Result<(), FrameError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let expected = if self.buffer.len() < FRAME_HEADER_LEN {
+            FRAME_HEADER_LEN
+        } else {
+            FRAME_HEADER_LEN + u32::from_be_bytes(self.buffer[0..4].try_into().unwrap()) as usize
+        };
+        Err(FrameError::Truncated {
+            buffered: self.buffer.len(),
+            expected,
+        })
+    }
+
+    /// Bytes buffered but not yet returned as a frame.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages() -> Vec<Vec<u8>> {
+        vec![
+            b"handshake".to_vec(),
+            Vec::new(),
+            (0..=255).collect(),
+            b"disconnect".to_vec(),
+        ]
+    }
+
+    #[test]
+    fn frames_reassemble_across_arbitrary_splits() {
+        let stream: Vec<u8> = messages()
+            .iter()
+            .flat_map(|msg| FramedCodec::encode(msg).unwrap())
+            .collect();
+
+        for chunk_size in [1, 3, 7, 8, 13, 64, stream.len()] {
+            let mut codec = FramedCodec::new();
+            let mut decoded = Vec::new();
+            for chunk in stream.chunks(chunk_size) {
+                codec.push(chunk);
+                while let Some(frame) = codec.next_frame().unwrap() {
+                    decoded.push(frame);
+                }
+            }
+            assert_eq!(decoded, messages(), "chunk size {chunk_size}");
+            assert_eq!(codec.finish(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn corrupt_and_truncated_frames_are_reported() {
+        let mut corrupt = FramedCodec::encode(b"payload").unwrap();
+        *corrupt.last_mut().unwrap() ^= 0xFF;
+        let good = FramedCodec::encode(b"next").unwrap();
+
+        let mut codec = FramedCodec::new();
+        codec.push(&corrupt);
+        codec.push(&good);
+        assert!(matches!(
+            codec.next_frame(),
+            Err(FrameError::BadChecksum { .. })
+        ));
+        assert_eq!(codec.next_frame().unwrap(), Some(b"next".to_vec()));
+
+        codec.push(&good[..good.len() - 1]);
+        assert_eq!(codec.next_frame().unwrap(), None);
+        assert_eq!(
+            codec.finish(),
+            Err(FrameError::Truncated {
+                buffered: good.len() - 1,
+                expected: good.len(),
+            })
+        );
+
+        let mut oversized = FramedCodec::new();
+        oversized.push(&(MAX_FRAME_SIZE as u32 + 1).to_be_bytes());
+        oversized.push(&[0; 4]);
+        assert_eq!(
+            oversized.next_frame(),
+            Err(FrameError::TooLarge(MAX_FRAME_SIZE + 1))
+        );
+    }
+}
@@ -7,13 +7,17 @@ mod chunk_streaming;
 mod codec;
 mod connection;
 mod entity_replication;
+mod framing;
 mod prediction;
 mod protocol;
 mod replay;
 mod transport;
 
 pub use channel::{ChannelManager, ChannelType};
-pub use chunk_encoding::{compression_ratio, decode_chunk_data, encode_chunk_data};
+pub use chunk_encoding::{
+    compression_ratio, decode_chunk_data, encode_chunk_data, encode_chunk_data_reported,
+    EncodeMethod, EncodeReport,
+};
 pub use chunk_streaming::{ChunkStreamer, StreamingMetrics};
 pub use codec::{
     compute_schema_hash, decode_client_message, decode_server_message, encode_client_message,
@@ -21,6 +25,7 @@ pub use codec::{
 };
 pub use connection::{ClientConnection, ServerConnection};
 pub use entity_replication::{create_entity_state, EntityReplicationTracker};
+pub use framing::{FrameError, FramedCodec, FRAME_HEADER_LEN};
 pub use prediction::{
     ClientPredictor, EntityInterpolator, PredictionMetrics, ReconciliationResult, ServerSnapshot,
     SnapshotBuffer,
@@ -38,13 +43,15 @@ pub use transport::{ClientEndpoint, ServerEndpoint, TlsMode};
 
 use serde::{Deserialize, Serialize};
 
-/// Schema hash placeholder for on-the-wire compatibility checks.
+/// Schema hash for on-the-wire compatibility checks.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SchemaHash(pub u64);
 
 impl SchemaHash {
-    /// Default development hash; replace when the protocol stabilizes.
-    pub const DEV: Self = Self(0xDEADBEEFDEADBEEF);
+    /// Hash of this build's protocol definitions (see [`compute_schema_hash`]).
+    pub fn current() -> Self {
+        Self(compute_schema_hash())
+    }
 }
 
 /// Message envelope used by early-stage prototypes.
@@ -59,10 +66,10 @@ pub struct MessageEnvelope<T> {
 }
 
 impl<T> MessageEnvelope<T> {
-    /// Wrap the payload with the development schema hash.
+    /// Wrap the payload with this build's schema hash.
     pub fn dev(payload: T, tick: u64) -> Self {
         Self {
-            schema: SchemaHash::DEV,
+            schema: SchemaHash::current(),
             tick,
             payload,
         }
@@ -76,7 +83,7 @@ mod tests {
     #[test]
     fn message_envelope_dev_sets_schema_and_tick() {
         let envelope = MessageEnvelope::dev("hello", 42);
-        assert_eq!(envelope.schema, SchemaHash::DEV);
+        assert_eq!(envelope.schema, SchemaHash(compute_schema_hash()));
         assert_eq!(envelope.tick, 42);
         assert_eq!(envelope.payload, "hello");
     }
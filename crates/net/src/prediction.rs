@@ -8,6 +8,15 @@ use std::collections::{HashMap, VecDeque};
 /// Maximum number of snapshots to keep in the circular buffer.
 const DEFAULT_SNAPSHOT_CAPACITY: usize = 256;
 
+/// Default delay between the newest server tick and the rendered tick.
+pub const DEFAULT_INTERPOLATION_DELAY_TICKS: u64 = 2;
+
+/// Furthest an entity is extrapolated past its newest snapshot.
+const MAX_EXTRAPOLATION_TICKS: f64 = 5.0;
+
+/// Snapshots kept per entity for buffered interpolation.
+const ENTITY_HISTORY_CAPACITY: usize = 32;
+
 /// Error tolerance for position mismatch (in quantized units, 1/16 block).
 const POSITION_ERROR_TOLERANCE: i32 = 2; // ~1/8 block = 12.5mm
 
@@ -131,6 +140,10 @@ pub struct PredictionMetrics {
 
     /// Maximum prediction error distance (in blocks).
     pub max_error_distance: f32,
+
+    /// Times an interpolated entity ran past its newest snapshot and was
+    /// extrapolated instead.
+    pub extrapolation_events: u64,
 }
 
 impl ClientPredictor {
@@ -280,6 +293,15 @@ fn calculate_transform_error(server: &Transform, client: &Transform) -> i32 {
 }
 
 /// Entity interpolator for smooth remote entity movement.
+///
+/// Supports two modes: chasing a single target with [`set_target`] and
+/// [`interpolate`], or buffering tick-stamped snapshots with
+/// [`push_snapshot`] and rendering them a fixed delay behind with [`sample`].
+///
+/// [`set_target`]: EntityInterpolator::set_target
+/// [`interpolate`]: EntityInterpolator::interpolate
+/// [`push_snapshot`]: EntityInterpolator::push_snapshot
+/// [`sample`]: EntityInterpolator::sample
 pub struct EntityInterpolator {
     /// Target transforms for each entity.
     targets: HashMap<EntityId, Transform>,
@@ -289,6 +311,15 @@ pub struct EntityInterpolator {
 
     /// Interpolation speed (alpha increment per tick).
     interpolation_speed: f32,
+
+    /// Tick-ordered snapshots per entity for buffered interpolation.
+    history: HashMap<EntityId, VecDeque<(u64, Transform)>>,
+
+    /// How many ticks behind the newest server tick entities are rendered.
+    interpolation_delay_ticks: u64,
+
+    /// Metrics (only `extrapolation_events` is tracked here).
+    metrics: PredictionMetrics,
 }
 
 impl EntityInterpolator {
@@ -298,9 +329,78 @@ impl EntityInterpolator {
             targets: HashMap::new(),
             alphas: HashMap::new(),
             interpolation_speed,
+            history: HashMap::new(),
+            interpolation_delay_ticks: DEFAULT_INTERPOLATION_DELAY_TICKS,
+            metrics: PredictionMetrics::default(),
+        }
+    }
+
+    /// Set how far behind the newest server tick entities are rendered.
+    ///
+    /// Higher values absorb more jitter at the cost of responsiveness.
+    pub fn set_interpolation_delay_ticks(&mut self, ticks: u64) {
+        self.interpolation_delay_ticks = ticks;
+    }
+
+    /// Current interpolation delay in ticks.
+    pub fn interpolation_delay_ticks(&self) -> u64 {
+        self.interpolation_delay_ticks
+    }
+
+    /// Record an entity transform from the server snapshot at `tick`.
+    ///
+    /// Snapshots may arrive out of order; a repeated tick replaces the old one.
+    pub fn push_snapshot(&mut self, entity_id: EntityId, tick: u64, transform: Transform) {
+        let samples = self.history.entry(entity_id).or_default();
+        match samples.binary_search_by_key(&tick, |(t, _)| *t) {
+            Ok(index) => samples[index].1 = transform,
+            Err(index) => samples.insert(index, (tick, transform)),
+        }
+        if samples.len() > ENTITY_HISTORY_CAPACITY {
+            samples.pop_front();
         }
     }
 
+    /// Transform of `entity_id` at `latest_tick - interpolation_delay_ticks`.
+    ///
+    /// `latest_tick` is the newest server time in ticks and may be fractional.
+    /// The result blends the two snapshots straddling the render tick. When
+    /// the buffer runs dry it extrapolates from the last two snapshots for at
+    /// most a few ticks and counts an extrapolation event.
+    pub fn sample(&mut self, entity_id: EntityId, latest_tick: f64) -> Option<Transform> {
+        let render_tick = latest_tick - self.interpolation_delay_ticks as f64;
+        let samples = self.history.get_mut(&entity_id)?;
+        let (first_tick, first) = samples.front()?;
+        if render_tick <= *first_tick as f64 {
+            return Some(first.clone());
+        }
+
+        let after = samples.partition_point(|(tick, _)| (*tick as f64) < render_tick);
+        if after < samples.len() {
+            // Drop snapshots no longer needed to straddle the render tick.
+            samples.drain(..after - 1);
+            let (from_tick, from) = &samples[0];
+            let (to_tick, to) = &samples[1];
+            let alpha = (render_tick - *from_tick as f64) / (*to_tick - *from_tick) as f64;
+            return Some(interpolate_transform(from, to, alpha as f32));
+        }
+
+        // Buffer underrun: extrapolate along the last known velocity.
+        self.metrics.extrapolation_events += 1;
+        let (last_tick, last) = samples.back()?;
+        let Some((prev_tick, prev)) = samples.iter().rev().nth(1) else {
+            return Some(last.clone());
+        };
+        let overshoot = (render_tick - *last_tick as f64).min(MAX_EXTRAPOLATION_TICKS);
+        let alpha = 1.0 + overshoot / (*last_tick - *prev_tick) as f64;
+        Some(interpolate_transform(prev, last, alpha as f32))
+    }
+
+    /// Interpolation metrics.
+    pub fn metrics(&self) -> &PredictionMetrics {
+        &self.metrics
+    }
+
     /// Set target transform for an entity.
     pub fn set_target(&mut self, entity_id: EntityId, target: Transform) {
         self.targets.insert(entity_id, target);
@@ -331,12 +431,14 @@ impl EntityInterpolator {
     pub fn remove(&mut self, entity_id: EntityId) {
         self.targets.remove(&entity_id);
         self.alphas.remove(&entity_id);
+        self.history.remove(&entity_id);
     }
 
     /// Clear all interpolation state.
     pub fn clear(&mut self) {
         self.targets.clear();
         self.alphas.clear();
+        self.history.clear();
     }
 }
 
@@ -579,6 +681,58 @@ mod tests {
         assert!(result3.is_none());
     }
 
+    #[test]
+    fn test_buffered_interpolation_between_snapshots() {
+        let mut interpolator = EntityInterpolator::new(0.5);
+        interpolator.set_interpolation_delay_ticks(3);
+        interpolator.push_snapshot(7, 10, make_transform(0, 0, 0));
+        interpolator.push_snapshot(7, 12, make_transform(160, 32, 0));
+        interpolator.push_snapshot(7, 14, make_transform(320, 64, 0));
+
+        // Render tick 11 sits halfway between ticks 10 and 12.
+        let mid = interpolator.sample(7, 14.0).unwrap();
+        assert_eq!((mid.x, mid.y), (80, 16));
+
+        let exact = interpolator.sample(7, 15.0).unwrap();
+        assert_eq!(exact.x, 160);
+        assert_eq!(interpolator.metrics().extrapolation_events, 0);
+        assert!(interpolator.sample(8, 15.0).is_none());
+    }
+
+    #[test]
+    fn test_buffered_interpolation_extrapolates_on_underrun() {
+        let mut interpolator = EntityInterpolator::new(0.5);
+        interpolator.set_interpolation_delay_ticks(1);
+        interpolator.push_snapshot(1, 0, make_transform(0, 0, 0));
+        interpolator.push_snapshot(1, 1, make_transform(16, 0, 0));
+
+        // Render tick 3 is two ticks past the newest snapshot.
+        let ahead = interpolator.sample(1, 4.0).unwrap();
+        assert_eq!(ahead.x, 48);
+        assert_eq!(interpolator.metrics().extrapolation_events, 1);
+
+        // Extrapolation is capped.
+        let far = interpolator.sample(1, 100.0).unwrap();
+        assert_eq!(far.x, 16 + 16 * MAX_EXTRAPOLATION_TICKS as i32);
+        assert_eq!(interpolator.metrics().extrapolation_events, 2);
+    }
+
+    #[test]
+    fn test_buffered_interpolation_orders_late_snapshots() {
+        let mut interpolator = EntityInterpolator::new(0.5);
+        interpolator.set_interpolation_delay_ticks(0);
+        interpolator.push_snapshot(2, 0, make_transform(0, 0, 0));
+        interpolator.push_snapshot(2, 4, make_transform(64, 0, 0));
+        // Tick 2 arrives after tick 4 with an off-line position.
+        interpolator.push_snapshot(2, 2, make_transform(0, 0, 64));
+
+        let at_one = interpolator.sample(2, 1.0).unwrap();
+        assert_eq!((at_one.x, at_one.z), (0, 32));
+        let at_three = interpolator.sample(2, 3.0).unwrap();
+        assert_eq!((at_three.x, at_three.z), (32, 32));
+        assert_eq!(interpolator.metrics().extrapolation_events, 0);
+    }
+
     #[test]
     fn test_transform_error_calculation() {
         let server = make_transform(100, 200, 300);
@@ -529,10 +529,189 @@ impl Transform {
     }
 }
 
+/// One sample of every protocol message shape, used to derive the schema hash.
+///
+/// Options are `Some` and vectors hold every variant of their element type so
+/// that nested field names are part of the shape. New variants must be added
+/// here; `schema_samples_cover_every_variant` fails until they are.
+pub(crate) fn schema_samples() -> (Vec<ClientMessage>, Vec<ServerMessage>) {
+    let transform = Transform::from_f32(0.0, 0.0, 0.0, 0.0, 0.0);
+    let client = vec![
+        ClientMessage::Handshake {
+            version: PROTOCOL_VERSION,
+            schema_hash: 0,
+        },
+        ClientMessage::Input(InputBundle {
+            tick: 0,
+            sequence: 0,
+            last_ack_tick: 0,
+            movement: MovementInput::zero(),
+            block_actions: vec![
+                BlockAction::Place {
+                    x: 0,
+                    y: 0,
+                    z: 0,
+                    block_id: 0,
+                },
+                BlockAction::Break { x: 0, y: 0, z: 0 },
+                BlockAction::Interact { x: 0, y: 0, z: 0 },
+            ],
+            inventory_actions: vec![
+                InventoryAction::Move {
+                    from_slot: 0,
+                    to_slot: 0,
+                    amount: 0,
+                },
+                InventoryAction::Drop { slot: 0, amount: 0 },
+                InventoryAction::Craft {
+                    recipe_id: String::new(),
+                },
+            ],
+        }),
+        ClientMessage::Chat {
+            text: String::new(),
+        },
+        ClientMessage::DiagnosticsRequest,
+        ClientMessage::Disconnect {
+            reason: String::new(),
+        },
+    ];
+    let server = vec![
+        ServerMessage::HandshakeResponse {
+            accepted: true,
+            reason: Some(String::new()),
+            player_entity_id: Some(0),
+        },
+        ServerMessage::ChunkData(ChunkDataMessage {
+            dimension: DimensionId::DEFAULT,
+            chunk_x: 0,
+            chunk_z: 0,
+            palette: vec![0],
+            compressed_data: vec![0],
+            crc32: 0,
+        }),
+        ServerMessage::EntityDelta(EntityDeltaMessage {
+            tick: 0,
+            entities: vec![
+                EntityUpdate {
+                    entity_id: 0,
+                    update: EntityUpdateType::Spawn {
+                        transform: transform.clone(),
+                        entity_type: String::new(),
+                    },
+                },
+                EntityUpdate {
+                    entity_id: 0,
+                    update: EntityUpdateType::Despawn,
+                },
+                EntityUpdate {
+                    entity_id: 0,
+                    update: EntityUpdateType::Transform(transform.clone()),
+                },
+                EntityUpdate {
+                    entity_id: 0,
+                    update: EntityUpdateType::Health {
+                        current: 0.0,
+                        max: 0.0,
+                    },
+                },
+            ],
+        }),
+        ServerMessage::Chat {
+            sender: String::new(),
+            text: String::new(),
+        },
+        ServerMessage::ServerState {
+            tick: 0,
+            player_transform: transform,
+        },
+        ServerMessage::DiagnosticsResponse {
+            tick_rate: 0.0,
+            player_count: 0,
+            chunk_count: 0,
+        },
+        ServerMessage::Disconnect {
+            reason: String::new(),
+        },
+    ];
+
+    (client, server)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn schema_samples_cover_every_variant() {
+        let (client, server) = schema_samples();
+
+        let client_tags: Vec<u8> = client
+            .iter()
+            .map(|msg| match msg {
+                ClientMessage::Handshake { .. } => 0,
+                ClientMessage::Input(_) => 1,
+                ClientMessage::Chat { .. } => 2,
+                ClientMessage::DiagnosticsRequest => 3,
+                ClientMessage::Disconnect { .. } => 4,
+            })
+            .collect();
+        assert_eq!(client_tags, (0..5).collect::<Vec<_>>());
+
+        let server_tags: Vec<u8> = server
+            .iter()
+            .map(|msg| match msg {
+                ServerMessage::HandshakeResponse { .. } => 0,
+                ServerMessage::ChunkData(_) => 1,
+                ServerMessage::EntityDelta(_) => 2,
+                ServerMessage::Chat { .. } => 3,
+                ServerMessage::ServerState { .. } => 4,
+                ServerMessage::DiagnosticsResponse { .. } => 5,
+                ServerMessage::Disconnect { .. } => 6,
+            })
+            .collect();
+        assert_eq!(server_tags, (0..7).collect::<Vec<_>>());
+
+        let ClientMessage::Input(bundle) = &client[1] else {
+            panic!("expected input sample");
+        };
+        let block_tags: Vec<u8> = bundle
+            .block_actions
+            .iter()
+            .map(|action| match action {
+                BlockAction::Place { .. } => 0,
+                BlockAction::Break { .. } => 1,
+                BlockAction::Interact { .. } => 2,
+            })
+            .collect();
+        assert_eq!(block_tags, vec![0, 1, 2]);
+        let inventory_tags: Vec<u8> = bundle
+            .inventory_actions
+            .iter()
+            .map(|action| match action {
+                InventoryAction::Move { .. } => 0,
+                InventoryAction::Drop { .. } => 1,
+                InventoryAction::Craft { .. } => 2,
+            })
+            .collect();
+        assert_eq!(inventory_tags, vec![0, 1, 2]);
+
+        let ServerMessage::EntityDelta(delta) = &server[2] else {
+            panic!("expected entity delta sample");
+        };
+        let update_tags: Vec<u8> = delta
+            .entities
+            .iter()
+            .map(|entity| match entity.update {
+                EntityUpdateType::Spawn { .. } => 0,
+                EntityUpdateType::Despawn => 1,
+                EntityUpdateType::Transform(_) => 2,
+                EntityUpdateType::Health { .. } => 3,
+            })
+            .collect();
+        assert_eq!(update_tags, vec![0, 1, 2, 3]);
+    }
+
     #[test]
     fn test_transform_quantization() {
         let transform = Transform::from_f32(10.5, 64.0, -5.25, 90.0, -45.0);
@@ -26,6 +26,121 @@ impl Aabb {
             && self.min[2] <= other.max[2]
             && self.max[2] >= other.min[2]
     }
+
+    /// Tests whether a point lies inside the box; points on a face count as inside.
+    pub fn contains_point(&self, p: [f32; 3]) -> bool {
+        (0..3).all(|axis| p[axis] >= self.min[axis] && p[axis] <= self.max[axis])
+    }
+
+    /// Smallest box enclosing both `self` and `other`.
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: std::array::from_fn(|axis| self.min[axis].min(other.min[axis])),
+            max: std::array::from_fn(|axis| self.max[axis].max(other.max[axis])),
+        }
+    }
+
+    /// Box grown by `margin` on every side (shrunk if negative).
+    pub fn expanded(&self, margin: f32) -> Self {
+        Self {
+            min: self.min.map(|v| v - margin),
+            max: self.max.map(|v| v + margin),
+        }
+    }
+
+    /// Center point of the box.
+    pub fn center(&self) -> [f32; 3] {
+        std::array::from_fn(|axis| (self.min[axis] + self.max[axis]) * 0.5)
+    }
+
+    /// Extent of the box along each axis.
+    pub fn size(&self) -> [f32; 3] {
+        std::array::from_fn(|axis| self.max[axis] - self.min[axis])
+    }
+}
+
+/// Outcome of [`sweep_aabb`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweepResult {
+    /// Fraction of the velocity travelled before the first impact, in `0.0..=1.0`.
+    /// `1.0` when nothing was hit.
+    pub time: f32,
+    /// Unit normal of the face that was hit; zero when nothing was hit.
+    pub normal: [f32; 3],
+    /// Velocity with the component into `normal` removed, for sliding over
+    /// the remaining `1.0 - time` of the step.
+    pub velocity: [f32; 3],
+}
+
+impl SweepResult {
+    /// Whether the sweep hit anything.
+    pub fn hit(&self) -> bool {
+        self.normal != [0.0; 3]
+    }
+}
+
+/// Sweep `moving` along `velocity` and find the earliest impact with `statics`.
+///
+/// Uses the slab method on each axis. Boxes that merely touch `moving` on a
+/// face parallel to the motion do not block it, so sliding along a wall is
+/// unobstructed. Boxes already overlapping `moving` are ignored.
+pub fn sweep_aabb(moving: Aabb, velocity: [f32; 3], statics: &[Aabb]) -> SweepResult {
+    let mut result = SweepResult {
+        time: 1.0,
+        normal: [0.0; 3],
+        velocity,
+    };
+
+    for other in statics {
+        let mut entry = f32::NEG_INFINITY;
+        let mut exit = f32::INFINITY;
+        let mut entry_axis = None;
+        let mut blocked = true;
+
+        for (axis, &v) in velocity.iter().enumerate() {
+            let (axis_entry, axis_exit) = if v > 0.0 {
+                (
+                    (other.min[axis] - moving.max[axis]) / v,
+                    (other.max[axis] - moving.min[axis]) / v,
+                )
+            } else if v < 0.0 {
+                (
+                    (other.max[axis] - moving.min[axis]) / v,
+                    (other.min[axis] - moving.max[axis]) / v,
+                )
+            } else if moving.max[axis] <= other.min[axis] || moving.min[axis] >= other.max[axis] {
+                blocked = false;
+                break;
+            } else {
+                continue;
+            };
+
+            if axis_entry > entry {
+                entry = axis_entry;
+                entry_axis = Some(axis);
+            }
+            exit = exit.min(axis_exit);
+        }
+
+        let Some(axis) = entry_axis else {
+            continue;
+        };
+        if !blocked || entry >= exit || !(0.0..=1.0).contains(&entry) || entry >= result.time {
+            continue;
+        }
+
+        result.time = entry;
+        result.normal = [0.0; 3];
+        result.normal[axis] = -velocity[axis].signum();
+    }
+
+    for (v, n) in result.velocity.iter_mut().zip(result.normal) {
+        if n != 0.0 {
+            *v = 0.0;
+        }
+    }
+
+    result
 }
 
 #[cfg(test)]
@@ -52,4 +167,73 @@ mod tests {
         let b = Aabb::new([1.1, 0.0, 0.0], [2.0, 1.0, 1.0]);
         assert!(!a.intersects(&b));
     }
+
+    #[test]
+    fn aabb_merge_of_disjoint_boxes_spans_both() {
+        let a = Aabb::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let b = Aabb::new([3.0, -2.0, 0.5], [4.0, -1.0, 2.0]);
+        let merged = a.merge(&b);
+
+        assert_eq!(merged, Aabb::new([0.0, -2.0, 0.0], [4.0, 1.0, 2.0]));
+        assert_eq!(merged, b.merge(&a));
+        assert_eq!(merged.size(), [4.0, 3.0, 2.0]);
+        assert_eq!(merged.center(), [2.0, -0.5, 1.0]);
+    }
+
+    #[test]
+    fn aabb_contains_points_on_boundary() {
+        let a = Aabb::new([0.0, 0.0, 0.0], [1.0, 2.0, 1.0]);
+        assert!(a.contains_point([0.5, 1.0, 0.5]));
+        assert!(a.contains_point([0.0, 0.0, 0.0]));
+        assert!(a.contains_point([1.0, 2.0, 1.0]));
+        assert!(a.contains_point([1.0, 0.5, 0.0]));
+        assert!(!a.contains_point([1.01, 0.5, 0.5]));
+        assert!(!a.contains_point([0.5, -0.01, 0.5]));
+
+        let grown = a.expanded(0.5);
+        assert!(grown.contains_point([1.25, 2.25, -0.25]));
+        assert_eq!(grown, Aabb::new([-0.5, -0.5, -0.5], [1.5, 2.5, 1.5]));
+    }
+
+    #[test]
+    fn sweep_slides_along_wall() {
+        let player = Aabb::new([0.0, 0.0, 0.0], [1.0, 2.0, 1.0]);
+        let wall = Aabb::new([2.0, 0.0, -10.0], [3.0, 3.0, 10.0]);
+
+        // Moving diagonally into the wall stops x after half the step.
+        let result = sweep_aabb(player, [2.0, 0.0, 4.0], &[wall]);
+        assert!(result.hit());
+        assert_eq!(result.time, 0.5);
+        assert_eq!(result.normal, [-1.0, 0.0, 0.0]);
+        assert_eq!(result.velocity, [0.0, 0.0, 4.0]);
+
+        // Once flush against the wall, moving parallel to it is unobstructed.
+        let flush = Aabb::new([1.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+        let result = sweep_aabb(flush, [0.0, 0.0, 4.0], &[wall]);
+        assert!(!result.hit());
+        assert_eq!(result.time, 1.0);
+        assert_eq!(result.velocity, [0.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn sweep_hits_corner_and_misses_past_it() {
+        let block = Aabb::new([2.0, 0.0, 2.0], [3.0, 1.0, 3.0]);
+        let mover = Aabb::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+
+        // Overlaps the corner on z slightly later than on x: z is the contact face.
+        let result = sweep_aabb(mover, [2.0, 0.0, 1.6], &[block]);
+        assert!(result.hit());
+        assert!((result.time - 1.0 / 1.6).abs() < 1e-6);
+        assert_eq!(result.normal, [0.0, 0.0, -1.0]);
+        assert_eq!(result.velocity, [2.0, 0.0, 0.0]);
+
+        // Passes beside the corner without ever reaching it on z: no impact.
+        let result = sweep_aabb(mover, [2.0, 0.0, 0.5], &[block]);
+        assert!(!result.hit());
+
+        // The earliest of several impacts wins.
+        let near = Aabb::new([1.5, 0.0, 0.0], [2.0, 1.0, 1.0]);
+        let result = sweep_aabb(mover, [2.0, 0.0, 0.0], &[block, near]);
+        assert_eq!(result.time, 0.25);
+    }
 }
@@ -2,15 +2,20 @@
 
 use glam::{Mat4, Quat, Vec3};
 
+/// Pitch limit just short of straight up/down, avoiding gimbal lock.
+const MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.001;
+
 /// First-person camera for 3D voxel rendering.
 #[derive(Debug, Clone)]
 pub struct Camera {
     /// Camera position in world space
     pub position: Vec3,
-    /// Camera rotation (yaw, pitch, roll)
+    /// Yaw angle in radians
     pub yaw: f32,
     /// Pitch angle in radians
     pub pitch: f32,
+    /// Roll around the view direction in radians; positive tilts up toward the left
+    pub roll: f32,
     /// Field of view in radians
     pub fov: f32,
     /// Aspect ratio (width/height)
@@ -19,20 +24,42 @@ pub struct Camera {
     pub near: f32,
     /// Far clip plane
     pub far: f32,
+    /// View-projection matrix as of the last [`Camera::update`]
+    view_proj: Mat4,
+    /// Inputs `view_proj` was built from
+    view_proj_inputs: MatrixInputs,
+}
+
+/// Everything the view-projection matrix depends on.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct MatrixInputs {
+    position: Vec3,
+    yaw: f32,
+    pitch: f32,
+    roll: f32,
+    fov: f32,
+    aspect: f32,
+    near: f32,
+    far: f32,
 }
 
 impl Camera {
     /// Create a new camera with default settings.
     pub fn new(aspect: f32) -> Self {
-        Self {
+        let mut camera = Self {
             position: Vec3::new(0.0, 100.0, 0.0),
             yaw: 0.0,
             pitch: 0.0,
+            roll: 0.0,
             fov: std::f32::consts::FRAC_PI_3, // 60 degrees
             aspect,
             near: 0.1,
             far: 1000.0,
-        }
+            view_proj: Mat4::IDENTITY,
+            view_proj_inputs: MatrixInputs::default(),
+        };
+        camera.refresh_view_proj();
+        camera
     }
 
     /// Get the forward direction vector.
@@ -42,14 +69,26 @@ impl Camera {
         Vec3::new(yaw_cos * pitch_cos, pitch_sin, yaw_sin * pitch_cos).normalize()
     }
 
-    /// Get the right direction vector.
+    /// Get the right direction vector, tilted by roll.
     pub fn right(&self) -> Vec3 {
-        self.forward().cross(Vec3::Y).normalize()
+        let (roll_sin, roll_cos) = self.roll.sin_cos();
+        self.level_right() * roll_cos + self.level_up() * roll_sin
     }
 
-    /// Get the up direction vector.
+    /// Get the up direction vector, tilted by roll.
     pub fn up(&self) -> Vec3 {
-        self.right().cross(self.forward()).normalize()
+        let (roll_sin, roll_cos) = self.roll.sin_cos();
+        self.level_up() * roll_cos - self.level_right() * roll_sin
+    }
+
+    /// Right vector before roll, always horizontal.
+    fn level_right(&self) -> Vec3 {
+        self.forward().cross(Vec3::Y).normalize()
+    }
+
+    /// Up vector before roll.
+    fn level_up(&self) -> Vec3 {
+        self.level_right().cross(self.forward()).normalize()
     }
 
     /// Build the view matrix.
@@ -62,8 +101,9 @@ impl Camera {
         // (view direction) point at forward(), we need to:
         // 1. Rotate 90° so -Z points at +X when yaw=0
         // 2. Negate yaw so increasing yaw rotates CCW (matching forward())
+        // Roll is applied last, around the camera's own Z (view) axis.
         let adjusted_yaw = -self.yaw - std::f32::consts::FRAC_PI_2;
-        let rotation = Quat::from_euler(glam::EulerRot::YXZ, adjusted_yaw, self.pitch, 0.0);
+        let rotation = Quat::from_euler(glam::EulerRot::YXZ, adjusted_yaw, self.pitch, self.roll);
         Mat4::from_rotation_translation(rotation, self.position).inverse()
     }
 
@@ -74,27 +114,78 @@ impl Camera {
     }
 
     /// Build combined view-projection matrix.
+    ///
+    /// Returns the matrix stored by the last [`Camera::update`], so every
+    /// pipeline can ask for it each frame. If the public fields were written
+    /// since then, the matrix is rebuilt on the fly instead.
     pub fn view_projection_matrix(&self) -> Mat4 {
-        self.projection_matrix() * self.view_matrix()
+        if self.view_proj_inputs == self.matrix_inputs() {
+            self.view_proj
+        } else {
+            self.projection_matrix() * self.view_matrix()
+        }
+    }
+
+    /// Refresh the stored view-projection matrix after direct field writes.
+    ///
+    /// The setters below call this themselves; the renderer also calls it once
+    /// at the start of each frame.
+    pub fn update(&mut self) {
+        if self.view_proj_inputs != self.matrix_inputs() {
+            self.refresh_view_proj();
+        }
+    }
+
+    fn refresh_view_proj(&mut self) {
+        self.view_proj_inputs = self.matrix_inputs();
+        self.view_proj = self.projection_matrix() * self.view_matrix();
+    }
+
+    fn matrix_inputs(&self) -> MatrixInputs {
+        MatrixInputs {
+            position: self.position,
+            yaw: self.yaw,
+            pitch: self.pitch,
+            roll: self.roll,
+            fov: self.fov,
+            aspect: self.aspect,
+            near: self.near,
+            far: self.far,
+        }
     }
 
     /// Update aspect ratio (call when window resizes).
     pub fn set_aspect(&mut self, aspect: f32) {
         self.aspect = aspect;
+        self.update();
     }
 
     /// Move the camera by a direction vector.
     pub fn translate(&mut self, delta: Vec3) {
         self.position += delta;
+        self.update();
     }
 
     /// Rotate the camera by yaw/pitch deltas.
     pub fn rotate(&mut self, yaw_delta: f32, pitch_delta: f32) {
         self.yaw += yaw_delta;
-        self.pitch = (self.pitch + pitch_delta).clamp(
-            -std::f32::consts::FRAC_PI_2 + 0.001,
-            std::f32::consts::FRAC_PI_2 - 0.001,
-        );
+        self.pitch = (self.pitch + pitch_delta).clamp(-MAX_PITCH, MAX_PITCH);
+        self.update();
+    }
+
+    /// Orient the camera toward a world-space point.
+    ///
+    /// Leaves the orientation unchanged if `target` is the camera position.
+    pub fn look_at(&mut self, target: Vec3) {
+        let dir = target - self.position;
+        let len = dir.length();
+        if len <= f32::EPSILON {
+            return;
+        }
+
+        self.yaw = dir.z.atan2(dir.x);
+        self.pitch = (dir.y / len).asin().clamp(-MAX_PITCH, MAX_PITCH);
+        self.update();
     }
 }
 
@@ -206,6 +297,119 @@ mod tests {
         assert!(camera.pitch > -std::f32::consts::FRAC_PI_2);
     }
 
+    #[test]
+    fn look_at_points_forward_at_target() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        camera.position = Vec3::new(4.0, 70.0, -3.0);
+
+        for target in [
+            Vec3::new(20.0, 70.0, -3.0),
+            Vec3::new(-8.0, 75.0, 12.0),
+            Vec3::new(4.5, 60.0, -10.0),
+        ] {
+            camera.look_at(target);
+            let expected = (target - camera.position).normalize();
+            assert!(
+                camera.forward().abs_diff_eq(expected, 1e-4),
+                "forward {:?} should point at {:?}",
+                camera.forward(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn look_at_clamps_pitch_and_ignores_own_position() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        camera.look_at(camera.position + Vec3::Y * 10.0);
+        assert!(camera.pitch < std::f32::consts::FRAC_PI_2);
+
+        camera.look_at(camera.position + Vec3::new(1.0, 0.0, 1.0));
+        let (yaw, pitch) = (camera.yaw, camera.pitch);
+        camera.look_at(camera.position);
+        assert_eq!((camera.yaw, camera.pitch), (yaw, pitch));
+    }
+
+    #[test]
+    fn zero_roll_keeps_the_unrolled_view_matrix() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        camera.position = Vec3::new(3.0, 70.0, -2.0);
+        camera.yaw = 0.8;
+        camera.pitch = -0.3;
+
+        let adjusted_yaw = -camera.yaw - std::f32::consts::FRAC_PI_2;
+        let rotation = Quat::from_euler(glam::EulerRot::YXZ, adjusted_yaw, camera.pitch, 0.0);
+        let unrolled = Mat4::from_rotation_translation(rotation, camera.position).inverse();
+        assert!(camera.view_matrix().abs_diff_eq(unrolled, 1e-6));
+        assert!(camera
+            .right()
+            .abs_diff_eq(camera.forward().cross(Vec3::Y).normalize(), 1e-6));
+    }
+
+    #[test]
+    fn roll_tilts_up_around_the_view_direction() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        camera.yaw = 1.1;
+        camera.pitch = 0.2;
+        let forward = camera.forward();
+
+        // The view matrix and the direction helpers agree at any roll.
+        for roll in [0.7, -2.0, 7.5] {
+            camera.roll = roll;
+            let view_inv = camera.view_matrix().inverse();
+            assert!(view_inv
+                .transform_vector3(Vec3::Y)
+                .abs_diff_eq(camera.up(), 1e-5));
+            assert!(view_inv
+                .transform_vector3(Vec3::X)
+                .abs_diff_eq(camera.right(), 1e-5));
+            assert!(view_inv
+                .transform_vector3(Vec3::NEG_Z)
+                .abs_diff_eq(forward, 1e-5));
+        }
+
+        // Half a turn turns the view upside down.
+        camera.pitch = 0.0;
+        camera.roll = std::f32::consts::PI;
+        assert!(camera.up().abs_diff_eq(Vec3::NEG_Y, 1e-5));
+        let view_inv = camera.view_matrix().inverse();
+        assert!(view_inv
+            .transform_vector3(Vec3::Y)
+            .abs_diff_eq(Vec3::NEG_Y, 1e-5));
+    }
+
+    #[test]
+    fn view_projection_is_stored_until_the_camera_changes() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        let first = camera.view_projection_matrix();
+        assert_eq!(camera.view_proj.to_cols_array(), first.to_cols_array());
+        assert!(first.abs_diff_eq(camera.projection_matrix() * camera.view_matrix(), 1e-6));
+
+        // Setters refresh eagerly.
+        camera.translate(Vec3::X);
+        assert_ne!(camera.view_proj, first);
+        assert_eq!(
+            camera.view_projection_matrix().to_cols_array(),
+            camera.view_proj.to_cols_array()
+        );
+
+        // Plain field writes are rebuilt on the fly until `update` stores them.
+        camera.position.y += 5.0;
+        let stale = camera.view_proj;
+        let moved = camera.view_projection_matrix();
+        assert_ne!(moved, stale);
+        assert!(moved.abs_diff_eq(camera.projection_matrix() * camera.view_matrix(), 1e-6));
+
+        camera.update();
+        assert_eq!(camera.view_proj.to_cols_array(), moved.to_cols_array());
+    }
+
+    #[test]
+    fn camera_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Camera>();
+    }
+
     #[test]
     fn test_view_projection_matrix() {
         let camera = Camera::new(16.0 / 9.0);
@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 
 use crate::mesh::{MeshBuffers, MeshVertex};
+use crate::occlusion::{ChunkBounds, OcclusionCuller, OcclusionInput};
 use mdminecraft_world::{ChunkPos, CHUNK_SIZE_Y, WORLD_MIN_Y};
 
 /// GPU buffer for a chunk mesh with position.
@@ -21,6 +22,10 @@ pub struct ChunkRenderData {
     pub chunk_pos: ChunkPos,
     /// Bind group for chunk uniforms
     pub chunk_bind_group: wgpu::BindGroup,
+    /// World-space bounds of the mesh geometry.
+    pub bounds: ChunkBounds,
+    /// World Y (exclusive) below which the chunk is fully solid, used as an occluder.
+    pub solid_floor_y: Option<i32>,
 }
 
 struct BufferPool {
@@ -72,10 +77,25 @@ impl BufferPool {
     }
 }
 
+/// Chunk counts for one pass over [`ChunkManager::visible_chunks`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Chunks loaded when the pass ran.
+    pub chunks_total: usize,
+    /// Chunks skipped by frustum or occlusion culling.
+    pub chunks_culled: usize,
+    /// Chunks that passed culling.
+    pub chunks_drawn: usize,
+    /// Opaque plus alpha triangles in the drawn chunks.
+    pub triangles: u64,
+}
+
 /// Manages rendering data for multiple chunks.
 pub struct ChunkManager {
     chunks: HashMap<ChunkPos, ChunkRenderData>,
     pool: BufferPool,
+    occlusion: OcclusionCuller,
+    frustum_culling: bool,
 }
 
 impl ChunkManager {
@@ -84,9 +104,23 @@ impl ChunkManager {
         Self {
             chunks: HashMap::new(),
             pool: BufferPool::new(),
+            occlusion: OcclusionCuller::new(),
+            frustum_culling: true,
         }
     }
 
+    /// Enable or disable frustum culling (enabled by default).
+    ///
+    /// Occlusion culling still applies while frustum culling is off.
+    pub fn set_frustum_culling(&mut self, enabled: bool) {
+        self.frustum_culling = enabled;
+    }
+
+    /// Whether chunks outside the view frustum are skipped.
+    pub fn frustum_culling(&self) -> bool {
+        self.frustum_culling
+    }
+
     /// Add or update a chunk's mesh.
     pub fn add_chunk(
         &mut self,
@@ -163,6 +197,19 @@ impl ChunkManager {
             None
         };
 
+        // Vertex Y is relative to the world floor; X/Z are chunk-local.
+        let (min_y, max_y) = mesh_buffers
+            .vertices
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v.position[1]), hi.max(v.position[1]))
+            });
+        let bounds = ChunkBounds::chunk_column(
+            chunk_pos,
+            WORLD_MIN_Y as f32 + min_y,
+            WORLD_MIN_Y as f32 + max_y,
+        );
+
         let render_data = ChunkRenderData {
             vertex_buffer,
             opaque_index_buffer,
@@ -171,13 +218,53 @@ impl ChunkManager {
             alpha_index_count: mesh_buffers.indices_alpha.len() as u32,
             chunk_pos,
             chunk_bind_group,
+            bounds,
+            solid_floor_y: None,
         };
 
         self.chunks.insert(chunk_pos, render_data);
     }
 
+    /// Set the solid-floor occluder for a loaded chunk (see [`crate::chunk_solid_floor`]).
+    pub fn set_chunk_occluder(&mut self, chunk_pos: ChunkPos, solid_floor_y: Option<i32>) {
+        if let Some(data) = self.chunks.get_mut(&chunk_pos) {
+            data.solid_floor_y = solid_floor_y;
+        }
+    }
+
+    /// Recompute occlusion from this frame's camera; results apply to the next frame.
+    ///
+    /// Only chunks that pass `frustum` take part, so off-screen chunks neither
+    /// occlude nor count as occluded.
+    pub fn update_occlusion(&mut self, view_proj: &glam::Mat4, camera_pos: glam::Vec3) {
+        let frustum = Frustum::from_matrix(view_proj);
+        let inputs = self
+            .chunks
+            .values()
+            .filter(|data| frustum.is_chunk_visible(data.chunk_pos))
+            .map(|data| OcclusionInput {
+                chunk_pos: data.chunk_pos,
+                bounds: data.bounds,
+                occluder: data.solid_floor_y.map(|top| {
+                    ChunkBounds::chunk_column(data.chunk_pos, WORLD_MIN_Y as f32, top as f32)
+                }),
+            });
+        self.occlusion.update(view_proj, camera_pos, inputs);
+    }
+
+    /// Whether a chunk was hidden behind other geometry in recent frames.
+    pub fn is_occluded(&self, chunk_pos: ChunkPos) -> bool {
+        self.occlusion.is_hidden(chunk_pos)
+    }
+
+    /// Number of chunks currently skipped due to occlusion.
+    pub fn occluded_count(&self) -> usize {
+        self.occlusion.hidden_count()
+    }
+
     /// Remove a chunk.
     pub fn remove_chunk(&mut self, chunk_pos: &ChunkPos) -> bool {
+        self.occlusion.forget(*chunk_pos);
         if let Some(data) = self.chunks.remove(chunk_pos) {
             self.pool.release(data.vertex_buffer);
             if let Some(buffer) = data.opaque_index_buffer {
@@ -192,6 +279,40 @@ impl ChunkManager {
         }
     }
 
+    /// Whether the chunk at `chunk_pos` should be drawn this frame.
+    pub fn is_drawn(&self, frustum: &Frustum, chunk_pos: ChunkPos) -> bool {
+        (!self.frustum_culling || frustum.is_chunk_visible(chunk_pos))
+            && !self.is_occluded(chunk_pos)
+    }
+
+    /// Chunks that pass culling for `frustum`, with counts for the debug HUD.
+    pub fn visible_chunks(&self, frustum: &Frustum) -> (Vec<&ChunkRenderData>, RenderStats) {
+        cull(
+            self.chunks.values(),
+            |data| {
+                (
+                    data.chunk_pos,
+                    (data.opaque_index_count + data.alpha_index_count) / 3,
+                )
+            },
+            |chunk_pos| self.is_drawn(frustum, chunk_pos),
+        )
+    }
+
+    /// Drop chunks farther than `radius` chunks from `center`, returning how many.
+    pub fn evict_beyond(&mut self, center: ChunkPos, radius: u32) -> usize {
+        let evicted: Vec<ChunkPos> = self
+            .chunks
+            .keys()
+            .copied()
+            .filter(|pos| !within_render_distance(center, *pos, radius))
+            .collect();
+        for pos in &evicted {
+            self.remove_chunk(pos);
+        }
+        evicted.len()
+    }
+
     /// Get all chunks for rendering.
     pub fn chunks(&self) -> impl Iterator<Item = &ChunkRenderData> {
         self.chunks.values()
@@ -204,6 +325,7 @@ impl ChunkManager {
 
     /// Clear all chunks.
     pub fn clear(&mut self) {
+        self.occlusion.clear();
         for (_, data) in self.chunks.drain() {
             self.pool.release(data.vertex_buffer);
             if let Some(buffer) = data.opaque_index_buffer {
@@ -216,6 +338,36 @@ impl ChunkManager {
     }
 }
 
+/// Whether `pos` lies inside the horizontal circle of `radius` chunks around `center`.
+pub(crate) fn within_render_distance(center: ChunkPos, pos: ChunkPos, radius: u32) -> bool {
+    let dx = i64::from(pos.x) - i64::from(center.x);
+    let dz = i64::from(pos.z) - i64::from(center.z);
+    let radius = i64::from(radius);
+    dx * dx + dz * dz <= radius * radius
+}
+
+/// Split `items` into those `is_drawn` accepts, tallying [`RenderStats`].
+fn cull<T>(
+    items: impl IntoIterator<Item = T>,
+    describe: impl Fn(&T) -> (ChunkPos, u32),
+    is_drawn: impl Fn(ChunkPos) -> bool,
+) -> (Vec<T>, RenderStats) {
+    let mut stats = RenderStats::default();
+    let mut drawn = Vec::new();
+    for item in items {
+        let (chunk_pos, triangles) = describe(&item);
+        stats.chunks_total += 1;
+        if is_drawn(chunk_pos) {
+            stats.chunks_drawn += 1;
+            stats.triangles += u64::from(triangles);
+            drawn.push(item);
+        } else {
+            stats.chunks_culled += 1;
+        }
+    }
+    (drawn, stats)
+}
+
 impl Default for ChunkManager {
     fn default() -> Self {
         Self::new()
@@ -232,42 +384,76 @@ pub struct Frustum {
 impl Frustum {
     /// Create frustum from view-projection matrix.
     ///
-    /// Extracts the 6 frustum planes using the Gribb-Hartmann method.
+    /// Extracts the 6 frustum planes using the Gribb-Hartmann method. The
+    /// near plane assumes wgpu's `0..1` clip-space depth range.
     pub fn from_matrix(vp_matrix: &glam::Mat4) -> Self {
-        let m = vp_matrix.to_cols_array();
+        let row0 = vp_matrix.row(0);
+        let row1 = vp_matrix.row(1);
+        let row2 = vp_matrix.row(2);
+        let row3 = vp_matrix.row(3);
 
-        // Extract planes from matrix rows
         // Each plane equation is: Ax + By + Cz + D = 0
-        // Represented as Vec4(A, B, C, D) where (A,B,C) is normal
+        // Represented as Vec4(A, B, C, D) where (A,B,C) is the inward normal
+        let planes = [
+            row3 + row0, // left
+            row3 - row0, // right
+            row3 + row1, // bottom
+            row3 - row1, // top
+            row2,        // near
+            row3 - row2, // far
+        ];
 
-        let left =
-            glam::Vec4::new(m[3] + m[0], m[7] + m[4], m[11] + m[8], m[15] + m[12]).normalize();
-
-        let right =
-            glam::Vec4::new(m[3] - m[0], m[7] - m[4], m[11] - m[8], m[15] - m[12]).normalize();
+        Self {
+            planes: planes.map(Self::normalize_plane),
+        }
+    }
 
-        let bottom =
-            glam::Vec4::new(m[3] + m[1], m[7] + m[5], m[11] + m[9], m[15] + m[13]).normalize();
+    /// Scale a plane so its normal is unit length, making `w` a true distance.
+    fn normalize_plane(plane: glam::Vec4) -> glam::Vec4 {
+        let length = plane.truncate().length();
+        if length > f32::EPSILON {
+            plane / length
+        } else {
+            plane
+        }
+    }
 
-        let top =
-            glam::Vec4::new(m[3] - m[1], m[7] - m[5], m[11] - m[9], m[15] - m[13]).normalize();
+    /// Check if a point lies inside (or on the boundary of) the frustum.
+    pub fn contains_point(&self, point: glam::Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.truncate().dot(point) + plane.w >= 0.0)
+    }
 
-        let near =
-            glam::Vec4::new(m[3] + m[2], m[7] + m[6], m[11] + m[10], m[15] + m[14]).normalize();
+    /// Check if an axis-aligned box intersects or is inside the frustum.
+    ///
+    /// Boxes that straddle a plane are kept; only boxes entirely outside one
+    /// plane are rejected.
+    pub fn contains_aabb(&self, min: glam::Vec3, max: glam::Vec3) -> bool {
+        for plane in &self.planes {
+            let normal = plane.truncate();
 
-        let far =
-            glam::Vec4::new(m[3] - m[2], m[7] - m[6], m[11] - m[10], m[15] - m[14]).normalize();
+            // Find the positive vertex (p-vertex) - the vertex of the AABB
+            // most aligned with the plane normal
+            let p = glam::Vec3::new(
+                if normal.x >= 0.0 { max.x } else { min.x },
+                if normal.y >= 0.0 { max.y } else { min.y },
+                if normal.z >= 0.0 { max.z } else { min.z },
+            );
 
-        Self {
-            planes: [left, right, bottom, top, near, far],
+            // If the p-vertex is outside this plane, the entire AABB is outside
+            if normal.dot(p) + plane.w < 0.0 {
+                return false;
+            }
         }
+
+        true
     }
 
     /// Check if a chunk is visible using AABB vs frustum test.
     ///
     /// Returns true if the chunk's bounding box intersects or is inside the frustum.
     pub fn is_chunk_visible(&self, chunk_pos: ChunkPos) -> bool {
-        // Define chunk AABB (axis-aligned bounding box)
         // Chunks are 16×CHUNK_SIZE_Y×16 blocks in world coordinates
         const CHUNK_SIZE_XZ: f32 = 16.0;
         const CHUNK_SIZE_Y_F32: f32 = CHUNK_SIZE_Y as f32;
@@ -284,27 +470,7 @@ impl Frustum {
             min.z + CHUNK_SIZE_XZ,
         );
 
-        // Test AABB against each frustum plane
-        for plane in &self.planes {
-            let normal = plane.truncate();
-            let d = plane.w;
-
-            // Find the positive vertex (p-vertex) - the vertex of the AABB
-            // most aligned with the plane normal
-            let p = glam::Vec3::new(
-                if normal.x >= 0.0 { max.x } else { min.x },
-                if normal.y >= 0.0 { max.y } else { min.y },
-                if normal.z >= 0.0 { max.z } else { min.z },
-            );
-
-            // If the p-vertex is outside this plane, the entire AABB is outside
-            if normal.dot(p) + d < 0.0 {
-                return false;
-            }
-        }
-
-        // AABB intersects or is inside frustum
-        true
+        self.contains_aabb(min, max)
     }
 }
 
@@ -370,12 +536,14 @@ mod tests {
             block_id: 0,
             light: 0,
             extra: 0,
+            uv_tile: [0.0; 4],
         };
         let mesh = MeshBuffers {
             vertices: vec![vertex],
             indices_opaque: vec![0],
             indices_alpha: Vec::new(),
             hash: MeshHash([0; 32]),
+            unmerged_triangles: 0,
         };
 
         let bind_group = test_bind_group(&device);
@@ -399,12 +567,14 @@ mod tests {
             block_id: 0,
             light: 0,
             extra: 0,
+            uv_tile: [0.0; 4],
         };
         let mesh = MeshBuffers {
             vertices: vec![vertex],
             indices_opaque: vec![0],
             indices_alpha: Vec::new(),
             hash: MeshHash([0; 32]),
+            unmerged_triangles: 0,
         };
 
         let bind_group = test_bind_group(&device);
@@ -414,6 +584,38 @@ mod tests {
         assert_eq!(manager.chunk_count(), 0);
     }
 
+    #[test]
+    fn evict_beyond_drops_out_of_range_chunks() {
+        let (device, queue) = test_device();
+        let mut manager = ChunkManager::new();
+
+        let vertex = MeshVertex {
+            position: [0.0, 0.0, 0.0],
+            normal: [0.0, 1.0, 0.0],
+            uv: [0.0, 0.0],
+            block_id: 0,
+            light: 0,
+            extra: 0,
+            uv_tile: [0.0; 4],
+        };
+        let mesh = MeshBuffers {
+            vertices: vec![vertex],
+            indices_opaque: vec![0],
+            indices_alpha: Vec::new(),
+            hash: MeshHash([0; 32]),
+            unmerged_triangles: 0,
+        };
+
+        for x in -4..=4 {
+            let bind_group = test_bind_group(&device);
+            manager.add_chunk(&device, &queue, &mesh, ChunkPos::new(x, 0), bind_group);
+        }
+
+        assert_eq!(manager.evict_beyond(ChunkPos::new(0, 0), 2), 4);
+        assert_eq!(manager.chunk_count(), 5);
+        assert_eq!(manager.evict_beyond(ChunkPos::new(0, 0), 2), 0);
+    }
+
     #[test]
     fn frustum_includes_nearby_chunk() {
         let vp = glam::Mat4::orthographic_rh(-100.0, 100.0, -100.0, 100.0, -100.0, 100.0);
@@ -421,4 +623,93 @@ mod tests {
         assert!(frustum.is_chunk_visible(ChunkPos::new(0, 0)));
         assert!(!frustum.is_chunk_visible(ChunkPos::new(100, 100)));
     }
+
+    fn looking_down_positive_x() -> Frustum {
+        let mut camera = crate::Camera::new(1.0);
+        camera.position = glam::Vec3::ZERO;
+        camera.near = 0.5;
+        camera.far = 100.0;
+        Frustum::from_matrix(&camera.view_projection_matrix())
+    }
+
+    #[test]
+    fn frustum_contains_points_in_view_only() {
+        let frustum = looking_down_positive_x();
+
+        assert!(frustum.contains_point(glam::Vec3::new(10.0, 0.0, 0.0)));
+        assert!(frustum.contains_point(glam::Vec3::new(10.0, 2.0, -2.0)));
+        // Behind the camera, nearer than the near plane, past the far plane.
+        assert!(!frustum.contains_point(glam::Vec3::new(-10.0, 0.0, 0.0)));
+        assert!(!frustum.contains_point(glam::Vec3::new(0.25, 0.0, 0.0)));
+        assert!(!frustum.contains_point(glam::Vec3::new(150.0, 0.0, 0.0)));
+        // Far outside the 60 degree field of view.
+        assert!(!frustum.contains_point(glam::Vec3::new(10.0, 0.0, 50.0)));
+    }
+
+    #[test]
+    fn frustum_keeps_partially_visible_aabbs() {
+        let frustum = looking_down_positive_x();
+
+        assert!(frustum.contains_aabb(
+            glam::Vec3::new(9.0, -1.0, -1.0),
+            glam::Vec3::new(11.0, 1.0, 1.0)
+        ));
+        // Straddles the left/right planes: corners are outside, the box is not.
+        assert!(frustum.contains_aabb(
+            glam::Vec3::new(10.0, -1.0, -100.0),
+            glam::Vec3::new(11.0, 1.0, 100.0)
+        ));
+        assert!(!frustum.contains_aabb(
+            glam::Vec3::new(-20.0, -1.0, -1.0),
+            glam::Vec3::new(-10.0, 1.0, 1.0)
+        ));
+        assert!(!frustum.contains_aabb(
+            glam::Vec3::new(10.0, 40.0, -1.0),
+            glam::Vec3::new(11.0, 50.0, 1.0)
+        ));
+    }
+
+    #[test]
+    fn chunks_behind_camera_are_culled_only_when_enabled() {
+        let frustum = looking_down_positive_x();
+        let mut manager = ChunkManager::new();
+        let chunks = [
+            (ChunkPos::new(2, 0), 100),
+            (ChunkPos::new(-3, 0), 40),
+            (ChunkPos::new(-4, 1), 10),
+        ];
+        let run = |manager: &ChunkManager| {
+            cull(
+                chunks,
+                |&(pos, tris)| (pos, tris),
+                |pos| manager.is_drawn(&frustum, pos),
+            )
+        };
+
+        manager.set_frustum_culling(false);
+        let (drawn, stats) = run(&manager);
+        assert_eq!(drawn.len(), 3);
+        assert_eq!(
+            stats,
+            RenderStats {
+                chunks_total: 3,
+                chunks_culled: 0,
+                chunks_drawn: 3,
+                triangles: 150,
+            }
+        );
+
+        manager.set_frustum_culling(true);
+        let (drawn, stats) = run(&manager);
+        assert_eq!(drawn, vec![(ChunkPos::new(2, 0), 100)]);
+        assert_eq!(
+            stats,
+            RenderStats {
+                chunks_total: 3,
+                chunks_culled: 2,
+                chunks_drawn: 1,
+                triangles: 100,
+            }
+        );
+    }
 }
@@ -0,0 +1,126 @@
+//! Debug line boxes for chunk boundaries and the camera frustum.
+
+use mdminecraft_world::{ChunkPos, CHUNK_SIZE_X, CHUNK_SIZE_Z, WORLD_MAX_Y, WORLD_MIN_Y};
+
+/// Which debug boxes the wireframe pipeline draws.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WireframeDebug {
+    /// No debug overlay.
+    #[default]
+    None,
+    /// Outline every loaded chunk column.
+    ChunkBounds,
+    /// Chunk outlines plus the camera frustum.
+    ChunkBoundsAndFrustum,
+}
+
+impl WireframeDebug {
+    /// The mode after this one, wrapping back to [`WireframeDebug::None`].
+    pub fn next(self) -> Self {
+        match self {
+            Self::None => Self::ChunkBounds,
+            Self::ChunkBounds => Self::ChunkBoundsAndFrustum,
+            Self::ChunkBoundsAndFrustum => Self::None,
+        }
+    }
+}
+
+/// A hexahedron outlined by its 12 edges.
+///
+/// Corners 0-3 are one face and 4-7 the opposite face, in matching winding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WireframeBox {
+    /// World-space corners.
+    pub corners: [glam::Vec3; 8],
+}
+
+impl WireframeBox {
+    /// Axis-aligned box spanning `min` to `max`.
+    pub fn aabb(min: glam::Vec3, max: glam::Vec3) -> Self {
+        Self {
+            corners: [
+                glam::Vec3::new(min.x, min.y, min.z),
+                glam::Vec3::new(max.x, min.y, min.z),
+                glam::Vec3::new(max.x, min.y, max.z),
+                glam::Vec3::new(min.x, min.y, max.z),
+                glam::Vec3::new(min.x, max.y, min.z),
+                glam::Vec3::new(max.x, max.y, min.z),
+                glam::Vec3::new(max.x, max.y, max.z),
+                glam::Vec3::new(min.x, max.y, max.z),
+            ],
+        }
+    }
+
+    /// Full-height column of the chunk at `pos`.
+    pub fn chunk_column(pos: ChunkPos) -> Self {
+        let min = glam::Vec3::new(
+            (pos.x * CHUNK_SIZE_X as i32) as f32,
+            WORLD_MIN_Y as f32,
+            (pos.z * CHUNK_SIZE_Z as i32) as f32,
+        );
+        let max = glam::Vec3::new(
+            min.x + CHUNK_SIZE_X as f32,
+            (WORLD_MAX_Y + 1) as f32,
+            min.z + CHUNK_SIZE_Z as f32,
+        );
+        Self::aabb(min, max)
+    }
+
+    /// Frustum of `view_proj`, from the near plane (depth 0) to the far plane (depth 1).
+    pub fn frustum(view_proj: &glam::Mat4) -> Self {
+        let inverse = view_proj.inverse();
+        let ndc = |x: f32, y: f32, z: f32| inverse.project_point3(glam::Vec3::new(x, y, z));
+        Self {
+            corners: [
+                ndc(-1.0, -1.0, 0.0),
+                ndc(1.0, -1.0, 0.0),
+                ndc(1.0, 1.0, 0.0),
+                ndc(-1.0, 1.0, 0.0),
+                ndc(-1.0, -1.0, 1.0),
+                ndc(1.0, -1.0, 1.0),
+                ndc(1.0, 1.0, 1.0),
+                ndc(-1.0, 1.0, 1.0),
+            ],
+        }
+    }
+
+    /// Line-list vertices for the 12 edges (24 vertices).
+    pub fn line_vertices(&self) -> [[f32; 3]; 24] {
+        const EDGES: [(usize, usize); 12] = [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 4),
+            (0, 4),
+            (1, 5),
+            (2, 6),
+            (3, 7),
+        ];
+        let mut out = [[0.0; 3]; 24];
+        for (i, (a, b)) in EDGES.into_iter().enumerate() {
+            out[i * 2] = self.corners[a].to_array();
+            out[i * 2 + 1] = self.corners[b].to_array();
+        }
+        out
+    }
+}
+
+/// Boxes to draw for `mode`: one per chunk in `chunks`, plus the frustum if requested.
+pub fn debug_wireframe_boxes(
+    mode: WireframeDebug,
+    chunks: impl IntoIterator<Item = ChunkPos>,
+    view_proj: &glam::Mat4,
+) -> Vec<WireframeBox> {
+    if mode == WireframeDebug::None {
+        return Vec::new();
+    }
+    let mut boxes: Vec<WireframeBox> = chunks.into_iter().map(WireframeBox::chunk_column).collect();
+    if mode == WireframeDebug::ChunkBoundsAndFrustum {
+        boxes.push(WireframeBox::frustum(view_proj));
+    }
+    boxes
+}
@@ -13,6 +13,8 @@ pub struct ChunkMeshStat {
     pub position: ChunkPos,
     /// Number of triangles generated for the chunk.
     pub triangles: usize,
+    /// Triangles the chunk would need without greedy face merging.
+    pub unmerged_triangles: usize,
     /// Mesh hash for determinism comparisons.
     pub hash: MeshHash,
 }
@@ -55,6 +57,7 @@ impl<'a> ChunkMeshDriver<'a> {
                     stats.push(ChunkMeshStat {
                         position: pos,
                         triangles: (mesh.indices_opaque.len() + mesh.indices_alpha.len()) / 3,
+                        unmerged_triangles: mesh.unmerged_triangles,
                         hash: mesh.hash,
                     });
                 }
@@ -134,6 +137,7 @@ mod tests {
         let stats = vec![ChunkMeshStat {
             position: ChunkPos::new(1, -2),
             triangles: 12,
+            unmerged_triangles: 12,
             hash: MeshHash([0; 32]),
         }];
         let path = std::env::temp_dir().join("mesh-metrics-driver.json");
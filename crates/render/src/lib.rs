@@ -6,11 +6,14 @@ use std::cell::RefCell;
 mod cache;
 mod camera;
 mod chunk_manager;
+mod debug_wireframe;
 mod driver;
 mod mesh;
+mod occlusion;
 mod particles;
 mod pipeline;
 mod raycast;
+mod remesh;
 mod screenshot;
 mod texture_atlas;
 mod time;
@@ -19,21 +22,36 @@ mod window;
 
 pub use cache::ChunkMeshCache;
 pub use camera::{Camera, CameraUniform};
-pub use chunk_manager::{ChunkManager, ChunkRenderData, Frustum};
+use chunk_manager::within_render_distance;
+pub use chunk_manager::{ChunkManager, ChunkRenderData, Frustum, RenderStats};
+pub use debug_wireframe::{debug_wireframe_boxes, WireframeBox, WireframeDebug};
 pub use driver::{ChunkMeshDriver, ChunkMeshStat};
 use mdminecraft_assets::TextureAtlasMetadata;
-pub use mesh::{mesh_chunk, mesh_chunk_with_voxel_at, MeshBuffers, MeshHash, MeshVertex};
-pub use particles::{ParticleEmitter, ParticleSystem, ParticleVertex};
+pub use mesh::{
+    mesh_chunk, mesh_chunk_with_voxel_at, MeshBuffers, MeshHash, MeshOptions, MeshVertex,
+};
+pub use occlusion::{
+    chunk_solid_floor, ChunkBounds, OcclusionCuller, OcclusionInput, OCCLUSION_BUFFER_HEIGHT,
+    OCCLUSION_BUFFER_WIDTH,
+};
+pub use particles::{BlendMode, ParticleEmitter, ParticleSystem, ParticleVertex};
 pub use pipeline::{
     ChunkMeshBuffer, ChunkUniform, HighlightUniform, ParticlePipeline, RenderContext,
     SkyboxPipeline, VoxelPipeline, WireframePipeline,
 };
 pub use raycast::{raycast, RaycastHit};
+pub use remesh::RemeshQueue;
 pub use screenshot::{record_texture_readback, write_png, TextureReadback};
 pub use texture_atlas::{atlas_exists, warn_missing_atlas};
 pub use time::{TimeOfDay, TimeUniform};
-pub use ui::{ControlMode, DebugHud, UiManager, UiRenderContext};
-pub use window::{InputContext, InputSnapshot, InputState, WindowConfig, WindowManager};
+pub use ui::{
+    crosshair_shapes, render_crosshair, ControlMode, CrosshairConfig, CrosshairStyle, DebugHud,
+    FrameHistory, UiManager, UiRenderContext,
+};
+pub use window::{
+    apply_radial_deadzone, GamepadButton, GamepadState, InputContext, InputSnapshot, InputState,
+    MouseFilter, WindowConfig, WindowManager, DEFAULT_STICK_DEADZONE,
+};
 
 /// Renderer configuration for headless + onscreen paths.
 #[derive(Debug, Clone)]
@@ -56,6 +74,16 @@ impl Default for RendererConfig {
     }
 }
 
+/// Far plane distance that encloses every chunk within `chunks` of the camera.
+///
+/// Covers one extra chunk horizontally, since the camera can sit anywhere in
+/// its own chunk, plus the full world height.
+fn far_plane_for_render_distance(chunks: u32) -> f32 {
+    let chunk_extent = mdminecraft_world::CHUNK_SIZE_X.max(mdminecraft_world::CHUNK_SIZE_Z);
+    let horizontal = (chunks as f32 + 1.0) * chunk_extent as f32;
+    horizontal.hypot(mdminecraft_world::CHUNK_SIZE_Y as f32)
+}
+
 /// Main renderer owning GPU resources.
 pub struct Renderer {
     config: RendererConfig,
@@ -66,6 +94,9 @@ pub struct Renderer {
     particle_pipeline: Option<ParticlePipeline>,
     camera: Camera,
     ui: Option<RefCell<UiManager>>,
+    remesh_queue: RemeshQueue,
+    render_distance: Option<u32>,
+    debug_wireframe: WireframeDebug,
 }
 
 #[cfg(test)]
@@ -126,6 +157,7 @@ mod tests {
             surface: None,
             device,
             queue,
+            downlevel_flags: adapter.get_downlevel_capabilities().flags,
             config,
             size: (64, 32),
             headless: Some(HeadlessTarget {
@@ -154,6 +186,87 @@ mod tests {
 
         let _ = renderer.atlas_metadata();
     }
+
+    #[test]
+    fn remesh_requests_coalesce_per_frame() {
+        let mut renderer = Renderer::new(RendererConfig {
+            width: 64,
+            height: 32,
+            headless: true,
+        });
+        let pos = mdminecraft_world::ChunkPos::new(4, 7);
+        renderer.request_remesh(pos);
+        renderer.request_remesh(pos);
+        renderer.request_remesh(pos);
+
+        let flushed = renderer.flush_remeshes();
+        assert_eq!(flushed.iter().filter(|p| **p == pos).count(), 1);
+        assert!(renderer.flush_remeshes().is_empty());
+    }
+
+    #[test]
+    fn render_distance_sets_far_plane_and_requests_new_ring() {
+        let mut renderer = Renderer::new(RendererConfig {
+            width: 64,
+            height: 32,
+            headless: true,
+        });
+        let mut chunks = ChunkManager::new();
+        let origin = mdminecraft_world::ChunkPos::new(0, 0);
+
+        assert_eq!(renderer.set_render_distance(4, &mut chunks), 0);
+        assert_eq!(renderer.render_distance(), Some(4));
+        let near_far = renderer.camera().far;
+        assert!(near_far >= 5.0 * mdminecraft_world::CHUNK_SIZE_X as f32);
+        assert!(renderer.flush_remeshes().is_empty());
+
+        renderer.set_render_distance(6, &mut chunks);
+        assert!(renderer.camera().far > near_far);
+        let ring = renderer.flush_remeshes();
+        assert!(ring.contains(&mdminecraft_world::ChunkPos::new(6, 0)));
+        assert!(ring.contains(&mdminecraft_world::ChunkPos::new(0, -5)));
+        assert!(!ring.contains(&mdminecraft_world::ChunkPos::new(4, 0)));
+        assert!(!ring.contains(&mdminecraft_world::ChunkPos::new(5, 5)));
+        assert!(ring.iter().all(|pos| {
+            within_render_distance(origin, *pos, 6) && !within_render_distance(origin, *pos, 4)
+        }));
+
+        renderer.set_render_distance(2, &mut chunks);
+        assert!(renderer.camera().far < near_far);
+        assert!(renderer.flush_remeshes().is_empty());
+    }
+
+    #[test]
+    fn debug_wireframe_draws_one_box_per_loaded_chunk() {
+        let mut renderer = Renderer::new(RendererConfig {
+            width: 64,
+            height: 32,
+            headless: true,
+        });
+        let loaded: Vec<_> = (0..5)
+            .map(|i| mdminecraft_world::ChunkPos::new(i, -i))
+            .collect();
+
+        assert!(renderer.debug_wireframe_boxes(loaded.clone()).is_empty());
+
+        renderer.set_debug_wireframe(WireframeDebug::ChunkBounds);
+        let boxes = renderer.debug_wireframe_boxes(loaded.clone());
+        assert_eq!(boxes.len(), loaded.len());
+        assert_eq!(boxes[1].corners[0], glam::Vec3::new(16.0, -64.0, -16.0));
+        assert_eq!(boxes[1].corners[6], glam::Vec3::new(32.0, 320.0, 0.0));
+
+        renderer.set_debug_wireframe(renderer.debug_wireframe().next());
+        assert_eq!(
+            renderer.debug_wireframe(),
+            WireframeDebug::ChunkBoundsAndFrustum
+        );
+        let boxes = renderer.debug_wireframe_boxes(loaded.clone());
+        assert_eq!(boxes.len(), loaded.len() + 1);
+        let frustum = boxes.last().unwrap();
+        let camera = renderer.camera();
+        let near_center = frustum.corners[..4].iter().sum::<glam::Vec3>() / 4.0;
+        assert!((near_center.distance(camera.position) - camera.near).abs() < 1e-3);
+    }
 }
 
 impl Renderer {
@@ -171,6 +284,9 @@ impl Renderer {
             particle_pipeline: None,
             camera,
             ui: None,
+            remesh_queue: RemeshQueue::new(),
+            render_distance: None,
+            debug_wireframe: WireframeDebug::None,
         }
     }
 
@@ -254,6 +370,63 @@ impl Renderer {
         &self.camera
     }
 
+    /// Render distance in chunks, if one has been set.
+    pub fn render_distance(&self) -> Option<u32> {
+        self.render_distance
+    }
+
+    /// Set the render distance in chunks around the camera.
+    ///
+    /// Moves the far plane to enclose the loaded radius, evicts meshes beyond
+    /// it from `chunk_manager`, and when the distance grows queues remeshes for
+    /// the newly covered ring. Returns the number of chunks evicted.
+    pub fn set_render_distance(&mut self, chunks: u32, chunk_manager: &mut ChunkManager) -> usize {
+        let center = mdminecraft_world::ChunkPos::new(
+            (self.camera.position.x / mdminecraft_world::CHUNK_SIZE_X as f32).floor() as i32,
+            (self.camera.position.z / mdminecraft_world::CHUNK_SIZE_Z as f32).floor() as i32,
+        );
+        self.camera.far = far_plane_for_render_distance(chunks);
+        let evicted = chunk_manager.evict_beyond(center, chunks);
+
+        if let Some(previous) = self.render_distance.filter(|previous| *previous < chunks) {
+            let radius = chunks as i32;
+            for dx in -radius..=radius {
+                for dz in -radius..=radius {
+                    let pos = mdminecraft_world::ChunkPos::new(center.x + dx, center.z + dz);
+                    if within_render_distance(center, pos, chunks)
+                        && !within_render_distance(center, pos, previous)
+                    {
+                        self.remesh_queue.request_single(pos);
+                    }
+                }
+            }
+        }
+        self.render_distance = Some(chunks);
+        evicted
+    }
+
+    /// Choose which debug wireframe boxes to draw.
+    pub fn set_debug_wireframe(&mut self, mode: WireframeDebug) {
+        self.debug_wireframe = mode;
+    }
+
+    /// Current debug wireframe mode.
+    pub fn debug_wireframe(&self) -> WireframeDebug {
+        self.debug_wireframe
+    }
+
+    /// Debug boxes for this frame, given the positions of the loaded chunks.
+    pub fn debug_wireframe_boxes(
+        &self,
+        chunks: impl IntoIterator<Item = mdminecraft_world::ChunkPos>,
+    ) -> Vec<WireframeBox> {
+        debug_wireframe_boxes(
+            self.debug_wireframe,
+            chunks,
+            &self.camera.view_projection_matrix(),
+        )
+    }
+
     /// Access texture atlas metadata if available.
     pub fn atlas_metadata(&self) -> Option<&TextureAtlasMetadata> {
         self.pipeline
@@ -280,6 +453,7 @@ impl Renderer {
 
     /// Begin a new frame and return the render context.
     pub fn begin_frame(&mut self) -> Option<FrameContext> {
+        self.camera.update();
         let context = self.context.as_ref()?;
         let pipeline = self.pipeline.as_ref()?;
         let skybox_pipeline = self.skybox_pipeline.as_ref()?;
@@ -316,6 +490,7 @@ impl Renderer {
         Some(RenderResources {
             device: &context.device,
             queue: &context.queue,
+            downlevel_flags: context.downlevel_flags,
             pipeline,
             skybox_pipeline,
             wireframe_pipeline,
@@ -323,6 +498,18 @@ impl Renderer {
         })
     }
 
+    /// Queue a remesh of `pos` (and its neighbours) to run at frame end.
+    ///
+    /// Multiple requests for the same chunk within a frame coalesce into one remesh.
+    pub fn request_remesh(&mut self, pos: mdminecraft_world::ChunkPos) {
+        self.remesh_queue.request(pos);
+    }
+
+    /// Drain the chunks queued via [`Renderer::request_remesh`] in deterministic order.
+    pub fn flush_remeshes(&mut self) -> Vec<mdminecraft_world::ChunkPos> {
+        self.remesh_queue.drain()
+    }
+
     /// Surface format used by the swapchain (if initialized).
     pub fn surface_format(&self) -> Option<wgpu::TextureFormat> {
         self.context.as_ref().map(|ctx| ctx.config.format)
@@ -361,6 +548,8 @@ pub struct RenderResources<'a> {
     pub device: &'a wgpu::Device,
     /// Command queue.
     pub queue: &'a wgpu::Queue,
+    /// Adapter capabilities beyond the WebGPU baseline.
+    pub downlevel_flags: wgpu::DownlevelFlags,
     /// Voxel rendering pipeline.
     pub pipeline: &'a VoxelPipeline,
     /// Skybox rendering pipeline.
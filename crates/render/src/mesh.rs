@@ -25,6 +25,11 @@ const EXTRA_ALPHA_KIND_END_PORTAL: u8 = 5 << EXTRA_TINT_SHIFT;
 const EXTRA_ALPHA_KIND_FIRE: u8 = 6 << EXTRA_TINT_SHIFT;
 
 /// Hash of the combined vertex/index buffers.
+///
+/// Quads are put in canonical order before hashing (by the minimum corner's
+/// y, then z, then x, then face normal), so the hash depends only on the
+/// chunk's contents, not on the order the meshing passes emit faces in.
+/// Vertices are hashed first, then opaque indices, then alpha indices.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MeshHash(pub [u8; 32]);
 
@@ -47,6 +52,11 @@ pub struct MeshVertex {
     pub light: u8,
     /// Extra per-vertex metadata (packed into the high 8 bits of the `packed_data` attribute).
     pub extra: u8,
+    /// Atlas tile `[u, v, width, height]` that `uv` wraps within.
+    ///
+    /// Greedy-merged quads carry UVs spanning several tiles; the shader folds
+    /// them back into this rect so the texture repeats instead of stretching.
+    pub uv_tile: [f32; 4],
 }
 
 /// Output mesh buffers per chunk.
@@ -60,6 +70,8 @@ pub struct MeshBuffers {
     pub indices_alpha: Vec<u32>,
     /// Stable hash of the vertex + index buffers for cache comparisons.
     pub hash: MeshHash,
+    /// Triangles the mesh would have with no greedy face merging.
+    pub unmerged_triangles: usize,
 }
 
 impl MeshBuffers {
@@ -70,6 +82,25 @@ impl MeshBuffers {
             indices_opaque: Vec::new(),
             indices_alpha: Vec::new(),
             hash: MeshHash([0; 32]),
+            unmerged_triangles: 0,
+        }
+    }
+}
+
+/// Switches for optional meshing passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeshOptions {
+    /// Darken cube face corners by their solid neighbours (see [`MeshVertex::light`]).
+    pub ambient_occlusion: bool,
+    /// Merge coplanar faces of the same block into larger quads.
+    pub greedy: bool,
+}
+
+impl Default for MeshOptions {
+    fn default() -> Self {
+        Self {
+            ambient_occlusion: true,
+            greedy: true,
         }
     }
 }
@@ -84,19 +115,25 @@ pub fn mesh_chunk(
     let origin_x = chunk_pos.x * CHUNK_SIZE_X as i32;
     let origin_z = chunk_pos.z * CHUNK_SIZE_Z as i32;
 
-    mesh_chunk_with_voxel_at(chunk, registry, atlas, |world_x, world_y, world_z| {
-        let local_y = world_y_to_local_y(world_y)?;
-
-        let local_x = world_x - origin_x;
-        let local_z = world_z - origin_z;
-        if !(0..CHUNK_SIZE_X as i32).contains(&local_x)
-            || !(0..CHUNK_SIZE_Z as i32).contains(&local_z)
-        {
-            return None;
-        }
+    mesh_chunk_with_voxel_at(
+        chunk,
+        registry,
+        atlas,
+        MeshOptions::default(),
+        |world_x, world_y, world_z| {
+            let local_y = world_y_to_local_y(world_y)?;
+
+            let local_x = world_x - origin_x;
+            let local_z = world_z - origin_z;
+            if !(0..CHUNK_SIZE_X as i32).contains(&local_x)
+                || !(0..CHUNK_SIZE_Z as i32).contains(&local_z)
+            {
+                return None;
+            }
 
-        Some(chunk.voxel(local_x as usize, local_y, local_z as usize))
-    })
+            Some(chunk.voxel(local_x as usize, local_y, local_z as usize))
+        },
+    )
 }
 
 /// Generate buffers for the given chunk, with access to a world-voxel sampler for neighbor-aware blocks.
@@ -104,12 +141,13 @@ pub fn mesh_chunk_with_voxel_at<F>(
     chunk: &Chunk,
     registry: &BlockRegistry,
     atlas: Option<&TextureAtlasMetadata>,
+    options: MeshOptions,
     voxel_at_world: F,
 ) -> MeshBuffers
 where
     F: Fn(i32, i32, i32) -> Option<Voxel>,
 {
-    let mut builder = MeshBuilder::new(registry, atlas);
+    let mut builder = MeshBuilder::new(registry, atlas, options);
     GreedyMesher::mesh(chunk, &mut builder);
     mesh_fluids(chunk, &mut builder, registry, &voxel_at_world);
     mesh_waterlogged_fluids(chunk, &mut builder, registry, &voxel_at_world);
@@ -888,6 +926,9 @@ struct MeshBuilder<'a> {
     indices_alpha: Vec<u32>,
     registry: &'a BlockRegistry,
     atlas: Option<&'a TextureAtlasMetadata>,
+    options: MeshOptions,
+    /// Triangles saved so far by greedy merging.
+    merged_triangles: usize,
 }
 
 impl<'a> MeshBuilder<'a> {
@@ -897,13 +938,19 @@ impl<'a> MeshBuilder<'a> {
         (ao << 4) | light
     }
 
-    fn new(registry: &'a BlockRegistry, atlas: Option<&'a TextureAtlasMetadata>) -> Self {
+    fn new(
+        registry: &'a BlockRegistry,
+        atlas: Option<&'a TextureAtlasMetadata>,
+        options: MeshOptions,
+    ) -> Self {
         Self {
             vertices: Vec::with_capacity(1024), // Pre-allocate to reduce reallocations
             indices_opaque: Vec::with_capacity(1024 * 6 / 4), // Indices are 1.5x vertices for quads
             indices_alpha: Vec::with_capacity(256),
             registry,
             atlas,
+            options,
+            merged_triangles: 0,
         }
     }
 
@@ -939,6 +986,7 @@ impl<'a> MeshBuilder<'a> {
             [light; 4],
             [3; 4],
             extra,
+            [1.0, 1.0],
         );
     }
 
@@ -953,10 +1001,14 @@ impl<'a> MeshBuilder<'a> {
         light: [u8; 4],
         ao: [u8; 4],
         extra: u8,
+        repeat: [f32; 2],
     ) {
         let base = self.vertices.len() as u32;
 
-        let uvs = self.resolve_uvs(block_id, face);
+        let tile_uvs = self.resolve_uvs(block_id, face);
+        let [u0, v0] = tile_uvs[0];
+        let uv_tile = [u0, v0, tile_uvs[2][0] - u0, tile_uvs[2][1] - v0];
+        let uvs = tile_uvs.map(|[u, v]| [u0 + (u - u0) * repeat[0], v0 + (v - v0) * repeat[1]]);
         let alpha_blended = is_alpha_blended(block_id, self.registry);
         let tint_extra = if self.registry.has_tag(block_id, render_tint_grass_tag()) {
             match face {
@@ -998,6 +1050,7 @@ impl<'a> MeshBuilder<'a> {
                 block_id,
                 light: Self::pack_light_and_ao(light[i], ao[i]),
                 extra,
+                uv_tile,
             });
         }
         let indices = if normal_positive {
@@ -1020,17 +1073,23 @@ impl<'a> MeshBuilder<'a> {
             vertices,
             indices_opaque,
             indices_alpha,
+            merged_triangles,
             ..
         } = self;
+        let (vertices, indices_opaque, indices_alpha) =
+            canonical_quad_order(vertices, &indices_opaque, &indices_alpha);
         let mut hasher = Hasher::new();
         hasher.update(bytemuck::cast_slice(&vertices));
         hasher.update(bytemuck::cast_slice(&indices_opaque));
         hasher.update(bytemuck::cast_slice(&indices_alpha));
+        let unmerged_triangles =
+            (indices_opaque.len() + indices_alpha.len()) / 3 + merged_triangles;
         MeshBuffers {
             vertices,
             indices_opaque,
             indices_alpha,
             hash: MeshHash(*hasher.finalize().as_bytes()),
+            unmerged_triangles,
         }
     }
 
@@ -1060,6 +1119,80 @@ impl<'a> MeshBuilder<'a> {
     }
 }
 
+/// Reorder quads by minimum corner (y, z, x) and then face normal.
+///
+/// Every quad is four consecutive vertices plus six indices whose first entry
+/// is the quad's first vertex; see [`MeshBuilder::push_quad_with_ao_and_extra`].
+fn canonical_quad_order(
+    vertices: Vec<MeshVertex>,
+    indices_opaque: &[u32],
+    indices_alpha: &[u32],
+) -> (Vec<MeshVertex>, Vec<u32>, Vec<u32>) {
+    struct Quad {
+        base: u32,
+        alpha: bool,
+        pattern: [u32; 6],
+    }
+
+    let quads_in = |indices: &[u32], alpha: bool| {
+        indices
+            .chunks_exact(6)
+            .map(|chunk| {
+                let base = chunk[0];
+                let mut pattern = [0u32; 6];
+                for (slot, index) in pattern.iter_mut().zip(chunk) {
+                    *slot = index - base;
+                }
+                Quad {
+                    base,
+                    alpha,
+                    pattern,
+                }
+            })
+            .collect::<Vec<Quad>>()
+    };
+    let mut quads = quads_in(indices_opaque, false);
+    quads.extend(quads_in(indices_alpha, true));
+
+    let key = |quad: &Quad| -> [f32; 6] {
+        let corners = &vertices[quad.base as usize..quad.base as usize + 4];
+        let mut min = [f32::INFINITY; 3];
+        for corner in corners {
+            for (lowest, value) in min.iter_mut().zip(corner.position) {
+                *lowest = lowest.min(value);
+            }
+        }
+        [
+            min[1],
+            min[2],
+            min[0],
+            corners[0].normal[0],
+            corners[0].normal[1],
+            corners[0].normal[2],
+        ]
+    };
+    let mut keyed: Vec<([f32; 6], Quad)> = quads.into_iter().map(|q| (key(&q), q)).collect();
+    // Stable sort: quads with equal keys keep their (deterministic) emission order.
+    keyed.sort_by(|(a, _), (b, _)| {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| x.total_cmp(y))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut sorted_vertices = Vec::with_capacity(vertices.len());
+    let mut opaque = Vec::with_capacity(indices_opaque.len());
+    let mut alpha = Vec::with_capacity(indices_alpha.len());
+    for (_, quad) in keyed {
+        let base = sorted_vertices.len() as u32;
+        sorted_vertices.extend_from_slice(&vertices[quad.base as usize..quad.base as usize + 4]);
+        let out = if quad.alpha { &mut alpha } else { &mut opaque };
+        out.extend(quad.pattern.iter().map(|offset| base + offset));
+    }
+    (sorted_vertices, opaque, alpha)
+}
+
 struct GreedyMesher;
 
 impl GreedyMesher {
@@ -1090,15 +1223,17 @@ impl GreedyMesher {
                 while i < width {
                     let idx = j * width + i;
                     if let Some(cell) = mask[idx] {
+                        let greedy = builder.options.greedy;
                         let mut quad_width = 1;
-                        while i + quad_width < width
+                        while greedy
+                            && i + quad_width < width
                             && mask[j * width + i + quad_width] == Some(cell)
                         {
                             quad_width += 1;
                         }
 
                         let mut quad_height = 1;
-                        'scan: while j + quad_height < height {
+                        'scan: while greedy && j + quad_height < height {
                             for k in 0..quad_width {
                                 if mask[(j + quad_height) * width + i + k] != Some(cell) {
                                     break 'scan;
@@ -1177,23 +1312,23 @@ impl GreedyMesher {
                 if a_opaque && !b_opaque {
                     // Opaque block 'a' facing non-opaque 'b' (air or transparent)
                     let light = a.light_sky.max(a.light_block);
-                    Some(FaceDesc::new(a.id, axis, false, light))
+                    Some(FaceDesc::new(a, axis, false, light))
                 } else if b_opaque && !a_opaque {
                     // Opaque block 'b' facing non-opaque 'a' (air or transparent)
                     let light = b.light_sky.max(b.light_block);
-                    Some(FaceDesc::new(b.id, axis, true, light))
+                    Some(FaceDesc::new(b, axis, true, light))
                 } else if a_solid && !a_opaque && !b_solid {
                     // Transparent block 'a' facing air
                     let light = a.light_sky.max(a.light_block);
-                    Some(FaceDesc::new(a.id, axis, false, light))
+                    Some(FaceDesc::new(a, axis, false, light))
                 } else if b_solid && !b_opaque && !a_solid {
                     // Transparent block 'b' facing air
                     let light = b.light_sky.max(b.light_block);
-                    Some(FaceDesc::new(b.id, axis, true, light))
+                    Some(FaceDesc::new(b, axis, true, light))
                 } else if a_solid && !a_opaque && b_solid && !b_opaque && a.id != b.id {
                     // Two different transparent blocks - render face for 'a'
                     let light = a.light_sky.max(a.light_block);
-                    Some(FaceDesc::new(a.id, axis, false, light))
+                    Some(FaceDesc::new(a, axis, false, light))
                 } else {
                     None
                 }
@@ -1201,12 +1336,12 @@ impl GreedyMesher {
             (Some(a), None) if is_solid(a) => {
                 // Block at chunk edge facing outside
                 let light = a.light_sky.max(a.light_block);
-                Some(FaceDesc::new(a.id, axis, false, light))
+                Some(FaceDesc::new(a, axis, false, light))
             }
             (None, Some(b)) if is_solid(b) => {
                 // Block at chunk edge facing outside
                 let light = b.light_sky.max(b.light_block);
-                Some(FaceDesc::new(b.id, axis, true, light))
+                Some(FaceDesc::new(b, axis, true, light))
             }
             _ => None,
         }
@@ -1347,17 +1482,19 @@ impl GreedyMesher {
                 corner[u_axis] += su;
                 corner[v_axis] += sv;
 
-                ao_out[idx] = ao_corner(
-                    chunk,
-                    builder.registry,
-                    pos,
-                    axis,
-                    normal_dir,
-                    u_axis,
-                    su,
-                    v_axis,
-                    sv,
-                );
+                if builder.options.ambient_occlusion {
+                    ao_out[idx] = ao_corner(
+                        chunk,
+                        builder.registry,
+                        pos,
+                        axis,
+                        normal_dir,
+                        u_axis,
+                        su,
+                        v_axis,
+                        sv,
+                    );
+                }
 
                 let fallback = cell.light;
                 let l0 = sample_light(chunk, adjacent[0], adjacent[1], adjacent[2], fallback);
@@ -1374,14 +1511,16 @@ impl GreedyMesher {
 
         builder.push_quad_with_ao_and_extra(
             cell.block_id,
-            cell.face(),
+            cell.texture_face,
             normal,
             [v0, v1, v2, v3],
             cell.normal[axis] > 0,
             light,
             ao,
             0,
+            [quad_width as f32, quad_height as f32],
         );
+        builder.merged_triangles += (quad_width * quad_height - 1) * 2;
     }
 }
 
@@ -1390,17 +1529,22 @@ struct FaceDesc {
     block_id: BlockId,
     normal: [i8; 3],
     light: u8,
+    /// Face whose texture is drawn; differs from [`Self::face`] for sideways logs.
+    texture_face: BlockFace,
 }
 
 impl FaceDesc {
-    fn new(block_id: BlockId, axis: usize, positive: bool, light: u8) -> Self {
+    fn new(voxel: Voxel, axis: usize, positive: bool, light: u8) -> Self {
         let mut normal = [0i8; 3];
         normal[axis] = if positive { 1 } else { -1 };
-        Self {
-            block_id,
+        let mut desc = Self {
+            block_id: voxel.id,
             normal,
             light,
-        }
+            texture_face: BlockFace::Up,
+        };
+        desc.texture_face = log_texture_face(voxel, desc.face());
+        desc
     }
 
     fn face(&self) -> BlockFace {
@@ -1416,6 +1560,29 @@ impl FaceDesc {
     }
 }
 
+/// Texture face shown on world `face` of `voxel`.
+///
+/// Logs lying along X or Z are an upright log rotated a quarter turn, so their
+/// end grain (`Up`/`Down`) lands on the faces the axis points through and the
+/// bark wraps around the rest. Every other block keeps its own face.
+fn log_texture_face(voxel: Voxel, face: BlockFace) -> BlockFace {
+    if !mdminecraft_world::is_log(voxel.id) {
+        return face;
+    }
+    match (mdminecraft_world::Axis::from_state(voxel.state), face) {
+        (mdminecraft_world::Axis::Y, face) => face,
+        (mdminecraft_world::Axis::X, BlockFace::East) => BlockFace::Up,
+        (mdminecraft_world::Axis::X, BlockFace::West) => BlockFace::Down,
+        (mdminecraft_world::Axis::X, BlockFace::Up) => BlockFace::West,
+        (mdminecraft_world::Axis::X, BlockFace::Down) => BlockFace::East,
+        (mdminecraft_world::Axis::Z, BlockFace::South) => BlockFace::Up,
+        (mdminecraft_world::Axis::Z, BlockFace::North) => BlockFace::Down,
+        (mdminecraft_world::Axis::Z, BlockFace::Up) => BlockFace::North,
+        (mdminecraft_world::Axis::Z, BlockFace::Down) => BlockFace::South,
+        (_, face) => face,
+    }
+}
+
 fn mesh_glass_panes<F>(
     chunk: &Chunk,
     builder: &mut MeshBuilder,
@@ -3403,7 +3570,7 @@ fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
     [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
 }
 
-fn is_opaque(voxel: Voxel, registry: &BlockRegistry) -> bool {
+pub(crate) fn is_opaque(voxel: Voxel, registry: &BlockRegistry) -> bool {
     if is_alpha_blended(voxel.id, registry)
         || mdminecraft_world::is_stairs(voxel.id)
         || mdminecraft_world::is_slab(voxel.id)
@@ -3422,7 +3589,7 @@ fn is_opaque(voxel: Voxel, registry: &BlockRegistry) -> bool {
 }
 
 /// Check if a voxel is a solid (non-air) block that should be rendered
-fn is_solid(voxel: Voxel) -> bool {
+pub(crate) fn is_solid(voxel: Voxel) -> bool {
     voxel.id != BLOCK_AIR
         && !is_fluid(voxel.id)
         && !mdminecraft_world::is_stairs(voxel.id)
@@ -3495,6 +3662,7 @@ mod tests {
                 texture: None,
                 textures: None,
                 harvest_level: None,
+                collision: None,
             }),
             BlockDescriptor::from_definition(BlockDefinition {
                 name: "leaves".to_string(),
@@ -3507,6 +3675,7 @@ mod tests {
                 texture: None,
                 textures: None,
                 harvest_level: None,
+                collision: None,
             }),
         ])
     }
@@ -3547,6 +3716,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 }));
             } else {
                 descriptors.push(BlockDescriptor::simple(&name, opaque));
@@ -3601,6 +3771,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 }));
             } else {
                 descriptors.push(BlockDescriptor::simple(&name, opaque));
@@ -3638,6 +3809,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 }));
             } else {
                 descriptors.push(BlockDescriptor::simple(&name, opaque));
@@ -3668,6 +3840,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 }));
             } else {
                 descriptors.push(BlockDescriptor::simple("solid", true));
@@ -3698,6 +3871,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 }));
             } else {
                 descriptors.push(BlockDescriptor::simple("solid", true));
@@ -3743,6 +3917,176 @@ mod tests {
         assert_ne!(hash_empty, mesh_updated.hash);
     }
 
+    #[test]
+    fn mesh_order_is_canonical_and_hash_stable() {
+        let stone = Voxel {
+            id: 1,
+            state: 0,
+            light_sky: 15,
+            light_block: 0,
+        };
+        let leaves = Voxel { id: 2, ..stone };
+        let build = |first: Voxel, second: Voxel| {
+            let mut chunk = Chunk::new(ChunkPos::new(0, 0));
+            chunk.set_voxel(2, 20, 3, first);
+            chunk.set_voxel(9, 5, 12, second);
+            chunk.set_voxel(4, 5, 1, stone);
+            chunk
+        };
+        let registry = registry();
+
+        let chunk = build(stone, leaves);
+        let first = mesh_chunk(&chunk, &registry, None);
+        let second = mesh_chunk(&chunk, &registry, None);
+        assert_eq!(
+            bytemuck::cast_slice::<_, u8>(&first.vertices),
+            bytemuck::cast_slice::<_, u8>(&second.vertices)
+        );
+        assert_eq!(first.indices_opaque, second.indices_opaque);
+        assert_eq!(first.indices_alpha, second.indices_alpha);
+        assert_eq!(first.hash, second.hash);
+
+        // Quads come out sorted by their minimum corner's (y, z, x).
+        let corners: Vec<[f32; 3]> = first
+            .vertices
+            .chunks_exact(4)
+            .map(|quad| {
+                let min = |axis: usize| {
+                    quad.iter()
+                        .map(|v| v.position[axis])
+                        .fold(f32::INFINITY, f32::min)
+                };
+                [min(1), min(2), min(0)]
+            })
+            .collect();
+        assert!(corners.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let swapped = build(leaves, stone);
+        let swapped_hash = mesh_chunk(&swapped, &registry, None).hash;
+        assert_ne!(swapped_hash, first.hash);
+        assert_eq!(mesh_chunk(&swapped, &registry, None).hash, swapped_hash);
+    }
+
+    #[test]
+    fn ambient_occlusion_darkens_enclosed_corners() {
+        let mut chunk = Chunk::new(ChunkPos::new(0, 0));
+        let stone = Voxel {
+            id: 1,
+            state: 0,
+            light_sky: 15,
+            light_block: 0,
+        };
+        chunk.set_voxel(5, 10, 5, stone);
+        // Two solid neighbours above the floor block's -X and -Z edges.
+        chunk.set_voxel(4, 11, 5, stone);
+        chunk.set_voxel(5, 11, 4, stone);
+        let registry = registry();
+
+        let top_ao = |options: MeshOptions, corner: [f32; 3]| {
+            let mesh = mesh_chunk_with_voxel_at(&chunk, &registry, None, options, |_, _, _| None);
+            mesh.vertices
+                .iter()
+                .find(|v| v.normal == [0.0, 1.0, 0.0] && v.position == corner)
+                .map(|v| v.light >> 4)
+                .expect("top face corner")
+        };
+
+        let enclosed = [5.0, 11.0, 5.0];
+        let open = [6.0, 11.0, 6.0];
+        let on = MeshOptions::default();
+        assert_eq!(top_ao(on, enclosed), 0);
+        assert_eq!(top_ao(on, open), 3);
+        assert!(top_ao(on, enclosed) < top_ao(on, open));
+
+        let off = MeshOptions {
+            ambient_occlusion: false,
+            ..MeshOptions::default()
+        };
+        assert_eq!(top_ao(off, enclosed), 3);
+        assert_eq!(top_ao(off, open), 3);
+    }
+
+    /// Every unit face covered by the mesh, as (normal, min corner).
+    fn unit_faces(mesh: &MeshBuffers) -> std::collections::BTreeSet<([i32; 3], [i32; 3])> {
+        let mut faces = std::collections::BTreeSet::new();
+        for quad in mesh.vertices.chunks_exact(4) {
+            let normal = quad[0].normal.map(|n| n as i32);
+            let mut min = [i32::MAX; 3];
+            let mut max = [i32::MIN; 3];
+            for vertex in quad {
+                for axis in 0..3 {
+                    min[axis] = min[axis].min(vertex.position[axis] as i32);
+                    max[axis] = max[axis].max(vertex.position[axis] as i32);
+                }
+            }
+            let span = |axis: usize| {
+                if min[axis] == max[axis] {
+                    min[axis]..min[axis] + 1
+                } else {
+                    min[axis]..max[axis]
+                }
+            };
+            for x in span(0) {
+                for y in span(1) {
+                    for z in span(2) {
+                        faces.insert((normal, [x, y, z]));
+                    }
+                }
+            }
+        }
+        faces
+    }
+
+    #[test]
+    fn greedy_meshing_merges_slab_without_changing_silhouette() {
+        let mut chunk = Chunk::new(ChunkPos::new(0, 0));
+        let stone = Voxel {
+            id: 1,
+            state: 0,
+            light_sky: 15,
+            light_block: 0,
+        };
+        for z in 0..CHUNK_SIZE_Z {
+            for x in 0..CHUNK_SIZE_X {
+                chunk.set_voxel(x, 10, z, stone);
+            }
+        }
+        let registry = registry();
+        let mesh_with = |greedy| {
+            let options = MeshOptions {
+                greedy,
+                ..MeshOptions::default()
+            };
+            mesh_chunk_with_voxel_at(&chunk, &registry, None, options, |_, _, _| None)
+        };
+
+        let greedy = mesh_with(true);
+        let per_face = mesh_with(false);
+        let triangles = |mesh: &MeshBuffers| mesh.indices_opaque.len() / 3;
+
+        // Top, bottom and four sides each collapse to one quad.
+        assert_eq!(triangles(&greedy), 12);
+        // 256 top + 256 bottom + 4 * 16 side faces, two triangles each.
+        assert_eq!(triangles(&per_face), 1152);
+        assert_eq!(greedy.unmerged_triangles, triangles(&per_face));
+        assert_eq!(per_face.unmerged_triangles, triangles(&per_face));
+        assert_eq!(unit_faces(&greedy), unit_faces(&per_face));
+
+        // Merged quads span 16 tiles of UV space inside a one-tile rect.
+        let top = greedy
+            .vertices
+            .chunks_exact(4)
+            .find(|quad| quad[0].normal == [0.0, 1.0, 0.0])
+            .expect("top quad");
+        let [_, _, tile_w, tile_h] = top[0].uv_tile;
+        let span_u = top.iter().map(|v| v.uv[0]).fold(f32::MIN, f32::max)
+            - top.iter().map(|v| v.uv[0]).fold(f32::MAX, f32::min);
+        let span_v = top.iter().map(|v| v.uv[1]).fold(f32::MIN, f32::max)
+            - top.iter().map(|v| v.uv[1]).fold(f32::MAX, f32::min);
+        assert!((span_u - 16.0 * tile_w).abs() < 1e-5);
+        assert!((span_v - 16.0 * tile_h).abs() < 1e-5);
+    }
+
     #[test]
     fn transparent_block_renders_faces() {
         // Test that transparent blocks (like leaves) render faces when adjacent to air
@@ -4227,8 +4571,12 @@ mod tests {
         let origin_a_x = chunk_pos_a.x * CHUNK_SIZE_X as i32;
         let origin_a_z = chunk_pos_a.z * CHUNK_SIZE_Z as i32;
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
                 let local_y = world_y_to_local_y(wy)?;
 
                 let local_x = wx - origin_a_x;
@@ -4240,42 +4588,49 @@ mod tests {
                 }
 
                 Some(chunk_a.voxel(local_x as usize, local_y, local_z as usize))
-            });
+            },
+        );
 
         let origin_b_x = chunk_pos_b.x * CHUNK_SIZE_X as i32;
         let origin_b_z = chunk_pos_b.z * CHUNK_SIZE_Z as i32;
 
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            let local_y = world_y_to_local_y(wy)?;
-
-            let chunk_x = wx.div_euclid(CHUNK_SIZE_X as i32);
-            let chunk_z = wz.div_euclid(CHUNK_SIZE_Z as i32);
-            let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                let local_y = world_y_to_local_y(wy)?;
 
-            if chunk_pos == chunk_pos_a {
-                let local_x = wx - origin_a_x;
-                let local_z = wz - origin_a_z;
-                if !(0..CHUNK_SIZE_X as i32).contains(&local_x)
-                    || !(0..CHUNK_SIZE_Z as i32).contains(&local_z)
-                {
-                    return None;
+                let chunk_x = wx.div_euclid(CHUNK_SIZE_X as i32);
+                let chunk_z = wz.div_euclid(CHUNK_SIZE_Z as i32);
+                let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
+
+                if chunk_pos == chunk_pos_a {
+                    let local_x = wx - origin_a_x;
+                    let local_z = wz - origin_a_z;
+                    if !(0..CHUNK_SIZE_X as i32).contains(&local_x)
+                        || !(0..CHUNK_SIZE_Z as i32).contains(&local_z)
+                    {
+                        return None;
+                    }
+                    return Some(chunk_a.voxel(local_x as usize, local_y, local_z as usize));
                 }
-                return Some(chunk_a.voxel(local_x as usize, local_y, local_z as usize));
-            }
 
-            if chunk_pos == chunk_pos_b {
-                let local_x = wx - origin_b_x;
-                let local_z = wz - origin_b_z;
-                if !(0..CHUNK_SIZE_X as i32).contains(&local_x)
-                    || !(0..CHUNK_SIZE_Z as i32).contains(&local_z)
-                {
-                    return None;
+                if chunk_pos == chunk_pos_b {
+                    let local_x = wx - origin_b_x;
+                    let local_z = wz - origin_b_z;
+                    if !(0..CHUNK_SIZE_X as i32).contains(&local_x)
+                        || !(0..CHUNK_SIZE_Z as i32).contains(&local_z)
+                    {
+                        return None;
+                    }
+                    return Some(chunk_b.voxel(local_x as usize, local_y, local_z as usize));
                 }
-                return Some(chunk_b.voxel(local_x as usize, local_y, local_z as usize));
-            }
 
-            None
-        });
+                None
+            },
+        );
 
         assert_eq!(mesh_disconnected.indices_alpha.len(), 12);
         assert_eq!(mesh_connected.indices_alpha.len(), 12);
@@ -4636,6 +4991,7 @@ mod tests {
             texture: None,
             textures: None,
             harvest_level: None,
+            collision: None,
         }));
         let registry = BlockRegistry::new(descriptors);
 
@@ -4665,17 +5021,28 @@ mod tests {
             },
         );
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |_wx, _wy, _wz| None);
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |_wx, _wy, _wz| None,
+        );
 
         let pane_world_y = local_y_to_world_y(1);
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            if wx == 16 && wy == pane_world_y && wz == 1 {
-                Some(chunk_b.voxel(0, 1, 1))
-            } else {
-                None
-            }
-        });
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                if wx == 16 && wy == pane_world_y && wz == 1 {
+                    Some(chunk_b.voxel(0, 1, 1))
+                } else {
+                    None
+                }
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_alpha.len(),
@@ -4726,17 +5093,28 @@ mod tests {
             },
         );
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |_wx, _wy, _wz| None);
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |_wx, _wy, _wz| None,
+        );
 
         let bars_world_y = local_y_to_world_y(1);
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            if wx == 16 && wy == bars_world_y && wz == 1 {
-                Some(chunk_b.voxel(0, 1, 1))
-            } else {
-                None
-            }
-        });
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                if wx == 16 && wy == bars_world_y && wz == 1 {
+                    Some(chunk_b.voxel(0, 1, 1))
+                } else {
+                    None
+                }
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_opaque.len(),
@@ -4768,6 +5146,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 }));
             } else if id == interactive_blocks::IRON_BARS as usize {
                 descriptors.push(BlockDescriptor::simple("iron_bars", false));
@@ -4803,17 +5182,28 @@ mod tests {
             },
         );
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |_wx, _wy, _wz| None);
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |_wx, _wy, _wz| None,
+        );
 
         let pane_world_y = local_y_to_world_y(1);
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            if wx == 16 && wy == pane_world_y && wz == 1 {
-                Some(chunk_b.voxel(0, 1, 1))
-            } else {
-                None
-            }
-        });
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                if wx == 16 && wy == pane_world_y && wz == 1 {
+                    Some(chunk_b.voxel(0, 1, 1))
+                } else {
+                    None
+                }
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_alpha.len(),
@@ -4856,8 +5246,12 @@ mod tests {
         let origin_b_x = chunk_b.position().x * CHUNK_SIZE_X as i32;
         let origin_b_z = chunk_b.position().z * CHUNK_SIZE_Z as i32;
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
                 let local_y = world_y_to_local_y(wy)?;
 
                 let ax = wx - origin_a_x;
@@ -4868,25 +5262,34 @@ mod tests {
                 }
 
                 None
-            });
+            },
+        );
 
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            let local_y = world_y_to_local_y(wy)?;
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                let local_y = world_y_to_local_y(wy)?;
 
-            let ax = wx - origin_a_x;
-            let az = wz - origin_a_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az) {
-                return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
-            }
+                let ax = wx - origin_a_x;
+                let az = wz - origin_a_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az)
+                {
+                    return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
+                }
 
-            let bx = wx - origin_b_x;
-            let bz = wz - origin_b_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz) {
-                return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
-            }
+                let bx = wx - origin_b_x;
+                let bz = wz - origin_b_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz)
+                {
+                    return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
+                }
 
-            None
-        });
+                None
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_opaque.len(),
@@ -4929,8 +5332,12 @@ mod tests {
         let origin_b_x = chunk_b.position().x * CHUNK_SIZE_X as i32;
         let origin_b_z = chunk_b.position().z * CHUNK_SIZE_Z as i32;
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
                 let local_y = world_y_to_local_y(wy)?;
 
                 let ax = wx - origin_a_x;
@@ -4941,25 +5348,34 @@ mod tests {
                 }
 
                 None
-            });
+            },
+        );
 
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            let local_y = world_y_to_local_y(wy)?;
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                let local_y = world_y_to_local_y(wy)?;
 
-            let ax = wx - origin_a_x;
-            let az = wz - origin_a_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az) {
-                return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
-            }
+                let ax = wx - origin_a_x;
+                let az = wz - origin_a_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az)
+                {
+                    return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
+                }
 
-            let bx = wx - origin_b_x;
-            let bz = wz - origin_b_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz) {
-                return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
-            }
+                let bx = wx - origin_b_x;
+                let bz = wz - origin_b_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz)
+                {
+                    return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
+                }
 
-            None
-        });
+                None
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_opaque.len(),
@@ -5002,8 +5418,12 @@ mod tests {
         let origin_b_x = chunk_b.position().x * CHUNK_SIZE_X as i32;
         let origin_b_z = chunk_b.position().z * CHUNK_SIZE_Z as i32;
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
                 let local_y = world_y_to_local_y(wy)?;
 
                 let ax = wx - origin_a_x;
@@ -5014,25 +5434,34 @@ mod tests {
                 }
 
                 None
-            });
+            },
+        );
 
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            let local_y = world_y_to_local_y(wy)?;
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                let local_y = world_y_to_local_y(wy)?;
 
-            let ax = wx - origin_a_x;
-            let az = wz - origin_a_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az) {
-                return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
-            }
+                let ax = wx - origin_a_x;
+                let az = wz - origin_a_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az)
+                {
+                    return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
+                }
 
-            let bx = wx - origin_b_x;
-            let bz = wz - origin_b_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz) {
-                return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
-            }
+                let bx = wx - origin_b_x;
+                let bz = wz - origin_b_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz)
+                {
+                    return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
+                }
 
-            None
-        });
+                None
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_opaque.len(),
@@ -5077,8 +5506,12 @@ mod tests {
         let origin_b_x = chunk_b.position().x * CHUNK_SIZE_X as i32;
         let origin_b_z = chunk_b.position().z * CHUNK_SIZE_Z as i32;
 
-        let mesh_disconnected =
-            mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
+        let mesh_disconnected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
                 let local_y = world_y_to_local_y(wy)?;
 
                 let ax = wx - origin_a_x;
@@ -5089,25 +5522,34 @@ mod tests {
                 }
 
                 None
-            });
+            },
+        );
 
-        let mesh_connected = mesh_chunk_with_voxel_at(&chunk_a, &registry, None, |wx, wy, wz| {
-            let local_y = world_y_to_local_y(wy)?;
+        let mesh_connected = mesh_chunk_with_voxel_at(
+            &chunk_a,
+            &registry,
+            None,
+            MeshOptions::default(),
+            |wx, wy, wz| {
+                let local_y = world_y_to_local_y(wy)?;
 
-            let ax = wx - origin_a_x;
-            let az = wz - origin_a_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az) {
-                return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
-            }
+                let ax = wx - origin_a_x;
+                let az = wz - origin_a_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&ax) && (0..CHUNK_SIZE_Z as i32).contains(&az)
+                {
+                    return Some(chunk_a.voxel(ax as usize, local_y, az as usize));
+                }
 
-            let bx = wx - origin_b_x;
-            let bz = wz - origin_b_z;
-            if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz) {
-                return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
-            }
+                let bx = wx - origin_b_x;
+                let bz = wz - origin_b_z;
+                if (0..CHUNK_SIZE_X as i32).contains(&bx) && (0..CHUNK_SIZE_Z as i32).contains(&bz)
+                {
+                    return Some(chunk_b.voxel(bx as usize, local_y, bz as usize));
+                }
 
-            None
-        });
+                None
+            },
+        );
 
         assert_eq!(
             mesh_disconnected.indices_opaque.len(),
@@ -5184,4 +5626,41 @@ mod tests {
             .fold(f32::NEG_INFINITY, f32::max);
         assert_eq!(max_y, 14.0 / 16.0);
     }
+
+    #[test]
+    fn sideways_log_shows_end_grain_along_its_axis() {
+        use mdminecraft_world::{tree_blocks, Axis};
+
+        let log = |axis: Axis| Voxel {
+            id: tree_blocks::LOG,
+            state: axis.to_state(),
+            ..Default::default()
+        };
+
+        for face in [BlockFace::Up, BlockFace::North, BlockFace::East] {
+            assert_eq!(log_texture_face(log(Axis::Y), face), face);
+        }
+
+        let x = log(Axis::X);
+        assert_eq!(log_texture_face(x, BlockFace::East), BlockFace::Up);
+        assert_eq!(log_texture_face(x, BlockFace::West), BlockFace::Down);
+        assert_eq!(log_texture_face(x, BlockFace::North), BlockFace::North);
+        assert!(!matches!(
+            log_texture_face(x, BlockFace::Up),
+            BlockFace::Up | BlockFace::Down
+        ));
+
+        let z = log(Axis::Z);
+        assert_eq!(log_texture_face(z, BlockFace::South), BlockFace::Up);
+        assert_eq!(log_texture_face(z, BlockFace::North), BlockFace::Down);
+        assert_eq!(log_texture_face(z, BlockFace::East), BlockFace::East);
+
+        // Non-log state bits are not read as an axis.
+        let stone = Voxel {
+            id: 1,
+            state: Axis::X.to_state(),
+            ..Default::default()
+        };
+        assert_eq!(log_texture_face(stone, BlockFace::East), BlockFace::East);
+    }
 }
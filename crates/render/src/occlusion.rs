@@ -0,0 +1,381 @@
+//! Coarse CPU occlusion culling for chunk visibility.
+//!
+//! Chunks are visited front-to-back against a small software depth buffer.
+//! Each chunk's mesh bounds are tested against the occluders drawn so far,
+//! then its own solid volume is rasterized so it can hide chunks further away.
+//! Results feed the next frame, and a chunk only counts as hidden after it has
+//! been occluded for several consecutive passes to avoid popping.
+
+use std::collections::HashMap;
+
+use glam::{Mat4, Vec2, Vec3};
+use mdminecraft_assets::BlockRegistry;
+use mdminecraft_world::{
+    local_y_to_world_y, Chunk, ChunkPos, CHUNK_SIZE_X, CHUNK_SIZE_Y, CHUNK_SIZE_Z,
+};
+
+use crate::mesh::{is_opaque, is_solid};
+
+/// Width of the occlusion depth buffer in pixels.
+pub const OCCLUSION_BUFFER_WIDTH: usize = 128;
+
+/// Height of the occlusion depth buffer in pixels.
+pub const OCCLUSION_BUFFER_HEIGHT: usize = 72;
+
+/// Consecutive occluded passes before a chunk is skipped.
+const HIDE_AFTER_PASSES: u8 = 3;
+
+/// Axis-aligned box in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkBounds {
+    /// Minimum corner.
+    pub min: Vec3,
+    /// Maximum corner.
+    pub max: Vec3,
+}
+
+impl ChunkBounds {
+    /// Full-footprint box of a chunk between two world Y levels.
+    pub fn chunk_column(chunk_pos: ChunkPos, min_y: f32, max_y: f32) -> Self {
+        let x = (chunk_pos.x * CHUNK_SIZE_X as i32) as f32;
+        let z = (chunk_pos.z * CHUNK_SIZE_Z as i32) as f32;
+        Self {
+            min: Vec3::new(x, min_y, z),
+            max: Vec3::new(x + CHUNK_SIZE_X as f32, max_y, z + CHUNK_SIZE_Z as f32),
+        }
+    }
+
+    fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    fn corners(&self) -> [Vec3; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            Vec3::new(a.x, a.y, a.z),
+            Vec3::new(b.x, a.y, a.z),
+            Vec3::new(a.x, b.y, a.z),
+            Vec3::new(b.x, b.y, a.z),
+            Vec3::new(a.x, a.y, b.z),
+            Vec3::new(b.x, a.y, b.z),
+            Vec3::new(a.x, b.y, b.z),
+            Vec3::new(b.x, b.y, b.z),
+        ]
+    }
+}
+
+/// Chunk input for one occlusion pass.
+#[derive(Debug, Clone, Copy)]
+pub struct OcclusionInput {
+    /// Chunk being tested.
+    pub chunk_pos: ChunkPos,
+    /// Bounds of the chunk's rendered geometry.
+    pub bounds: ChunkBounds,
+    /// Fully solid volume that hides whatever is behind it (if any).
+    pub occluder: Option<ChunkBounds>,
+}
+
+/// World Y (exclusive) below which every column of `chunk` is solid and opaque.
+///
+/// The slab from the world floor up to this height is a conservative occluder
+/// for the chunk. Returns `None` when any column starts with a see-through block.
+pub fn chunk_solid_floor(chunk: &Chunk, registry: &BlockRegistry) -> Option<i32> {
+    let mut floor = CHUNK_SIZE_Y;
+    for x in 0..CHUNK_SIZE_X {
+        for z in 0..CHUNK_SIZE_Z {
+            let column = (0..floor)
+                .position(|y| {
+                    let voxel = chunk.voxel(x, y, z);
+                    !(is_solid(voxel) && is_opaque(voxel, registry))
+                })
+                .unwrap_or(floor);
+            floor = floor.min(column);
+            if floor == 0 {
+                return None;
+            }
+        }
+    }
+    Some(local_y_to_world_y(floor))
+}
+
+/// Software depth buffer plus per-chunk hysteresis state.
+pub struct OcclusionCuller {
+    depth: Vec<f32>,
+    occluded_passes: HashMap<ChunkPos, u8>,
+}
+
+impl OcclusionCuller {
+    /// Create a culler with nothing hidden.
+    pub fn new() -> Self {
+        Self {
+            depth: vec![f32::INFINITY; OCCLUSION_BUFFER_WIDTH * OCCLUSION_BUFFER_HEIGHT],
+            occluded_passes: HashMap::new(),
+        }
+    }
+
+    /// Run one pass over `chunks` as seen through `view_proj` from `camera_pos`.
+    pub fn update(
+        &mut self,
+        view_proj: &Mat4,
+        camera_pos: Vec3,
+        chunks: impl IntoIterator<Item = OcclusionInput>,
+    ) {
+        self.depth.fill(f32::INFINITY);
+
+        let mut chunks: Vec<OcclusionInput> = chunks.into_iter().collect();
+        chunks.sort_by(|a, b| {
+            let da = a.bounds.center().distance_squared(camera_pos);
+            let db = b.bounds.center().distance_squared(camera_pos);
+            da.total_cmp(&db)
+                .then_with(|| (a.chunk_pos.x, a.chunk_pos.z).cmp(&(b.chunk_pos.x, b.chunk_pos.z)))
+        });
+
+        let mut next = HashMap::new();
+        for chunk in &chunks {
+            if self.is_box_occluded(view_proj, &chunk.bounds) {
+                let passes = self
+                    .occluded_passes
+                    .get(&chunk.chunk_pos)
+                    .copied()
+                    .unwrap_or(0);
+                next.insert(chunk.chunk_pos, passes.saturating_add(1));
+            }
+            if let Some(occluder) = chunk.occluder {
+                self.rasterize_box(view_proj, &occluder);
+            }
+        }
+        self.occluded_passes = next;
+    }
+
+    /// Whether the chunk should be skipped this frame.
+    pub fn is_hidden(&self, chunk_pos: ChunkPos) -> bool {
+        self.occluded_passes
+            .get(&chunk_pos)
+            .is_some_and(|&passes| passes >= HIDE_AFTER_PASSES)
+    }
+
+    /// Number of chunks currently hidden by occlusion.
+    pub fn hidden_count(&self) -> usize {
+        self.occluded_passes
+            .values()
+            .filter(|&&passes| passes >= HIDE_AFTER_PASSES)
+            .count()
+    }
+
+    /// Drop state for a chunk that was unloaded.
+    pub fn forget(&mut self, chunk_pos: ChunkPos) {
+        self.occluded_passes.remove(&chunk_pos);
+    }
+
+    /// Reset all hysteresis state.
+    pub fn clear(&mut self) {
+        self.occluded_passes.clear();
+    }
+
+    /// Screen-space pixel position and NDC depth, or `None` behind the camera.
+    fn project(view_proj: &Mat4, point: Vec3) -> Option<Vec3> {
+        let clip = *view_proj * point.extend(1.0);
+        if clip.w <= 1e-4 {
+            return None;
+        }
+        let ndc = clip.truncate() / clip.w;
+        Some(Vec3::new(
+            (ndc.x * 0.5 + 0.5) * OCCLUSION_BUFFER_WIDTH as f32,
+            (0.5 - ndc.y * 0.5) * OCCLUSION_BUFFER_HEIGHT as f32,
+            ndc.z,
+        ))
+    }
+
+    fn project_corners(view_proj: &Mat4, bounds: &ChunkBounds) -> Option<[Vec3; 8]> {
+        let corners = bounds.corners();
+        let mut projected = [Vec3::ZERO; 8];
+        for (out, corner) in projected.iter_mut().zip(corners) {
+            *out = Self::project(view_proj, corner)?;
+        }
+        Some(projected)
+    }
+
+    /// Clamped pixel rectangle touched by `points`, or `None` when fully off screen.
+    fn pixel_rect(points: &[Vec3]) -> Option<(usize, usize, usize, usize)> {
+        let (mut min, mut max) = (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY));
+        for p in points {
+            min = min.min(p.truncate());
+            max = max.max(p.truncate());
+        }
+        let (w, h) = (
+            OCCLUSION_BUFFER_WIDTH as f32,
+            OCCLUSION_BUFFER_HEIGHT as f32,
+        );
+        if max.x < 0.0 || max.y < 0.0 || min.x >= w || min.y >= h {
+            return None;
+        }
+        Some((
+            min.x.floor().max(0.0) as usize,
+            min.y.floor().max(0.0) as usize,
+            (max.x.ceil().min(w) as usize).max(1),
+            (max.y.ceil().min(h) as usize).max(1),
+        ))
+    }
+
+    /// Conservative test: every touched pixel must already hold something nearer.
+    fn is_box_occluded(&self, view_proj: &Mat4, bounds: &ChunkBounds) -> bool {
+        let Some(points) = Self::project_corners(view_proj, bounds) else {
+            return false;
+        };
+        let Some((x0, y0, x1, y1)) = Self::pixel_rect(&points) else {
+            return false;
+        };
+        let nearest = points.iter().map(|p| p.z).fold(f32::INFINITY, f32::min);
+
+        (y0..y1).all(|y| {
+            let row = &self.depth[y * OCCLUSION_BUFFER_WIDTH..(y + 1) * OCCLUSION_BUFFER_WIDTH];
+            row[x0..x1].iter().all(|&depth| depth < nearest)
+        })
+    }
+
+    /// Write the box's farthest depth into every pixel whose centre it covers.
+    fn rasterize_box(&mut self, view_proj: &Mat4, bounds: &ChunkBounds) {
+        let Some(points) = Self::project_corners(view_proj, bounds) else {
+            return;
+        };
+        let Some((x0, y0, x1, y1)) = Self::pixel_rect(&points) else {
+            return;
+        };
+        let farthest = points.iter().map(|p| p.z).fold(f32::NEG_INFINITY, f32::max);
+        let hull = convex_hull(points.map(|p| p.truncate()));
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if hull_contains(&hull, center) {
+                    let depth = &mut self.depth[y * OCCLUSION_BUFFER_WIDTH + x];
+                    *depth = depth.min(farthest);
+                }
+            }
+        }
+    }
+}
+
+impl Default for OcclusionCuller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counter-clockwise convex hull (Andrew's monotone chain).
+fn convex_hull(mut points: [Vec2; 8]) -> Vec<Vec2> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    let cross = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(16);
+    for &p in points.iter().chain(points.iter().rev().skip(1)) {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull.pop();
+    hull
+}
+
+fn hull_contains(hull: &[Vec2], point: Vec2) -> bool {
+    if hull.len() < 3 {
+        return false;
+    }
+    (0..hull.len()).all(|i| {
+        let a = hull[i];
+        let b = hull[(i + 1) % hull.len()];
+        (b - a).perp_dot(point - a) >= 0.0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera_view_proj(eye: Vec3, target: Vec3) -> Mat4 {
+        let proj = Mat4::perspective_rh(70f32.to_radians(), 16.0 / 9.0, 0.1, 1000.0);
+        proj * Mat4::look_at_rh(eye, target, Vec3::Y)
+    }
+
+    #[test]
+    fn chunk_behind_opaque_wall_is_reported_occluded() {
+        // Camera looks along +Z across a solid wall chunk at z = 1 toward a chunk at z = 3.
+        let eye = Vec3::new(8.0, 70.0, -8.0);
+        let view_proj = camera_view_proj(eye, Vec3::new(8.0, 70.0, 100.0));
+
+        let wall_pos = ChunkPos::new(0, 1);
+        let wall = ChunkBounds::chunk_column(wall_pos, -64.0, 200.0);
+        let hidden_pos = ChunkPos::new(0, 3);
+        let hidden = ChunkBounds::chunk_column(hidden_pos, 40.0, 80.0);
+        let open_pos = ChunkPos::new(0, -3);
+
+        let inputs = [
+            OcclusionInput {
+                chunk_pos: wall_pos,
+                bounds: wall,
+                occluder: Some(wall),
+            },
+            OcclusionInput {
+                chunk_pos: hidden_pos,
+                bounds: hidden,
+                occluder: None,
+            },
+            OcclusionInput {
+                chunk_pos: open_pos,
+                bounds: ChunkBounds::chunk_column(open_pos, 40.0, 80.0),
+                occluder: None,
+            },
+        ];
+
+        let mut culler = OcclusionCuller::new();
+        for pass in 1..=HIDE_AFTER_PASSES {
+            // Hysteresis: not hidden until enough consecutive passes agree.
+            assert!(
+                !culler.is_hidden(hidden_pos),
+                "hidden after {} passes",
+                pass - 1
+            );
+            culler.update(&view_proj, eye, inputs);
+        }
+
+        assert!(culler.is_hidden(hidden_pos));
+        assert!(!culler.is_hidden(wall_pos));
+        assert!(!culler.is_hidden(open_pos));
+        assert_eq!(culler.hidden_count(), 1);
+
+        // Removing the wall reveals the chunk on the very next pass.
+        culler.update(&view_proj, eye, inputs.into_iter().skip(1));
+        assert!(!culler.is_hidden(hidden_pos));
+        assert_eq!(culler.hidden_count(), 0);
+    }
+
+    #[test]
+    fn chunk_peeking_over_wall_stays_visible() {
+        let eye = Vec3::new(8.0, 70.0, -8.0);
+        let view_proj = camera_view_proj(eye, Vec3::new(8.0, 70.0, 100.0));
+
+        let wall_pos = ChunkPos::new(0, 1);
+        let wall = ChunkBounds::chunk_column(wall_pos, -64.0, 60.0);
+        let tall_pos = ChunkPos::new(0, 3);
+
+        let inputs = [
+            OcclusionInput {
+                chunk_pos: wall_pos,
+                bounds: wall,
+                occluder: Some(wall),
+            },
+            OcclusionInput {
+                chunk_pos: tall_pos,
+                bounds: ChunkBounds::chunk_column(tall_pos, 40.0, 120.0),
+                occluder: None,
+            },
+        ];
+
+        let mut culler = OcclusionCuller::new();
+        for _ in 0..HIDE_AFTER_PASSES {
+            culler.update(&view_proj, eye, inputs);
+        }
+        assert!(!culler.is_hidden(tall_pos));
+    }
+}
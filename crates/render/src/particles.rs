@@ -16,11 +16,23 @@ pub struct ParticleVertex {
     pub scale: f32,
 }
 
+/// How particles combine with what is already on screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Standard alpha blending; particles are sorted back-to-front on upload.
+    #[default]
+    Alpha,
+    /// Additive glow; order-independent, so uploaded unsorted.
+    Additive,
+}
+
 /// CPU-side emitter used to build particle batches each frame.
 #[derive(Debug, Default)]
 pub struct ParticleEmitter {
     /// Temporary vertex buffer for GPU upload.
     pub vertices: Vec<ParticleVertex>,
+    /// Blend mode used when drawing this batch.
+    pub blend_mode: BlendMode,
 }
 
 impl ParticleEmitter {
@@ -38,29 +50,55 @@ impl ParticleEmitter {
     pub fn clear(&mut self) {
         self.vertices.clear();
     }
+
+    /// Particles in the order they should be uploaded for a camera at `camera_position`.
+    ///
+    /// Alpha-blended batches are sorted farthest first so nearer particles
+    /// blend over farther ones; additive batches keep spawn order.
+    pub fn upload_order(&self, camera_position: glam::Vec3) -> Vec<ParticleVertex> {
+        let mut vertices = self.vertices.clone();
+        if self.blend_mode == BlendMode::Alpha {
+            let distance =
+                |v: &ParticleVertex| glam::Vec3::from(v.position).distance_squared(camera_position);
+            vertices.sort_by(|a, b| distance(b).total_cmp(&distance(a)));
+        }
+        vertices
+    }
 }
 
 /// GPU upload containing the current frame’s particles.
 pub struct ParticleSystem {
     instance_buffer: wgpu::Buffer,
     instance_count: u32,
+    blend_mode: BlendMode,
 }
 
 impl ParticleSystem {
-    /// Create a GPU buffer from the emitter contents.
-    pub fn from_emitter(device: &wgpu::Device, emitter: &ParticleEmitter) -> Self {
+    /// Create a GPU buffer from the emitter contents, ordered for `camera_position`.
+    pub fn from_emitter(
+        device: &wgpu::Device,
+        emitter: &ParticleEmitter,
+        camera_position: glam::Vec3,
+    ) -> Self {
+        let vertices = emitter.upload_order(camera_position);
         let instance_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Particle Instance Buffer"),
-            contents: bytemuck::cast_slice(&emitter.vertices),
+            contents: bytemuck::cast_slice(&vertices),
             usage: wgpu::BufferUsages::VERTEX,
         });
 
         Self {
             instance_buffer,
-            instance_count: emitter.vertices.len() as u32,
+            instance_count: vertices.len() as u32,
+            blend_mode: emitter.blend_mode,
         }
     }
 
+    /// Blend mode the batch was built for; pick the matching particle pipeline.
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
     /// Draw particle billboards as instanced quads.
     pub fn render<'a>(
         &'a self,
@@ -75,3 +113,48 @@ impl ParticleSystem {
         pass.draw(0..6, 0..self.instance_count);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn particle(position: [f32; 3]) -> ParticleVertex {
+        ParticleVertex {
+            position,
+            color: [1.0, 1.0, 1.0, 0.5],
+            lifetime: 1.0,
+            scale: 0.1,
+        }
+    }
+
+    #[test]
+    fn alpha_particles_upload_back_to_front() {
+        let camera = glam::Vec3::new(0.0, 64.0, 0.0);
+        let mut emitter = ParticleEmitter::new();
+        for position in [
+            [1.0, 64.0, 0.0],
+            [0.0, 64.0, -12.0],
+            [4.0, 60.0, 3.0],
+            [-30.0, 70.0, 0.0],
+        ] {
+            emitter.spawn(particle(position));
+        }
+
+        let distances: Vec<f32> = emitter
+            .upload_order(camera)
+            .iter()
+            .map(|v| glam::Vec3::from(v.position).distance(camera))
+            .collect();
+        assert!(distances.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(distances.first().copied(), Some(30.0f32.hypot(6.0)));
+
+        emitter.blend_mode = BlendMode::Additive;
+        let unsorted: Vec<[f32; 3]> = emitter
+            .upload_order(camera)
+            .iter()
+            .map(|v| v.position)
+            .collect();
+        let spawned: Vec<[f32; 3]> = emitter.vertices.iter().map(|v| v.position).collect();
+        assert_eq!(unsorted, spawned);
+    }
+}
@@ -5,7 +5,9 @@ use wgpu::util::DeviceExt;
 use winit::window::Window;
 
 use crate::camera::{Camera, CameraUniform};
+use crate::debug_wireframe::WireframeBox;
 use crate::mesh::MeshVertex;
+use crate::particles::BlendMode;
 use crate::texture_atlas::{warn_missing_atlas, RuntimeAtlas};
 use mdminecraft_assets::TextureAtlasMetadata;
 
@@ -17,6 +19,8 @@ pub struct RenderContext {
     pub device: wgpu::Device,
     /// Command queue for submitting work to the GPU.
     pub queue: wgpu::Queue,
+    /// Adapter capabilities beyond the WebGPU baseline (e.g. sampling a read-only depth target).
+    pub downlevel_flags: wgpu::DownlevelFlags,
     /// Surface configuration describing swapchain parameters.
     pub config: wgpu::SurfaceConfiguration,
     /// Current backbuffer dimensions in pixels (width, height).
@@ -51,6 +55,7 @@ impl RenderContext {
             .await
             .context("Failed to find suitable GPU adapter")?;
 
+        let downlevel_flags = adapter.get_downlevel_capabilities().flags;
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
@@ -93,6 +98,7 @@ impl RenderContext {
             surface: Some(surface),
             device,
             queue,
+            downlevel_flags,
             config,
             size: (size.width, size.height),
             headless: None,
@@ -115,6 +121,7 @@ impl RenderContext {
             .await
             .context("Failed to find suitable GPU adapter")?;
 
+        let downlevel_flags = adapter.get_downlevel_capabilities().flags;
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
@@ -164,6 +171,7 @@ impl RenderContext {
             surface: None,
             device,
             queue,
+            downlevel_flags,
             config,
             size,
             headless: Some(HeadlessTarget {
@@ -293,6 +301,86 @@ mod tests {
         assert_eq!(u.chunk_offset[1], mdminecraft_world::WORLD_MIN_Y as f32);
         assert_eq!(u.chunk_offset[2], -48.0);
     }
+
+    /// Render a sunlit floor filling the view at `time` and return its mean brightness.
+    fn render_floor_at(time: f32) -> f32 {
+        const SIZE: u32 = 32;
+        let format = wgpu::TextureFormat::Rgba8Unorm;
+        let ctx = pollster::block_on(RenderContext::new_headless((SIZE, SIZE), format))
+            .expect("headless context");
+        let pipeline = VoxelPipeline::new(&ctx).expect("voxel pipeline");
+
+        // A floor quad with no block light and no occlusion, spanning the whole atlas.
+        let corner = |x: f32, z: f32| MeshVertex {
+            position: [x, 10.0, z],
+            normal: [0.0, 1.0, 0.0],
+            uv: [(x + 64.0) / 128.0, (z + 64.0) / 128.0],
+            block_id: 1,
+            light: 3 << 4,
+            extra: 0,
+            uv_tile: [0.0; 4],
+        };
+        let vertices = [
+            corner(-64.0, -64.0),
+            corner(64.0, -64.0),
+            corner(64.0, 64.0),
+            corner(-64.0, 64.0),
+        ];
+        let mesh = ChunkMeshBuffer::new(&ctx.device, &vertices, &[0, 1, 2, 0, 2, 3]);
+        let chunk =
+            pipeline.create_chunk_bind_group(&ctx.device, mdminecraft_world::ChunkPos::new(0, 0));
+
+        let floor_y = mdminecraft_world::WORLD_MIN_Y as f32 + 10.0;
+        let mut camera = Camera::new(1.0);
+        camera.position = glam::Vec3::new(0.0, floor_y + 2.0, 0.0);
+        camera.look_at(glam::Vec3::new(1.0, floor_y, 0.5));
+        pipeline.update_camera(&ctx.queue, &camera);
+
+        let mut time_of_day = crate::time::TimeOfDay::new();
+        time_of_day.set_time(time);
+        pipeline.update_time(&ctx.queue, &time_of_day, 0.0, 0.0);
+
+        let target = ctx.headless.as_ref().expect("headless target");
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = pipeline.begin_render_pass(&mut encoder, &target.view);
+            pass.set_pipeline(pipeline.pipeline());
+            pass.set_bind_group(0, pipeline.camera_bind_group(), &[]);
+            pass.set_bind_group(1, &chunk, &[]);
+            pass.set_bind_group(2, pipeline.texture_bind_group(), &[]);
+            pass.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+            pass.set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            pass.draw_indexed(0..mesh.index_count, 0, 0..1);
+        }
+        let readback = crate::record_texture_readback(
+            &ctx.device,
+            &mut encoder,
+            &target.texture,
+            format,
+            (SIZE, SIZE),
+        );
+        ctx.queue.submit(Some(encoder.finish()));
+        let pixels = readback.read_rgba8(&ctx.device).expect("read back");
+
+        let total: u64 = pixels
+            .chunks_exact(4)
+            .map(|p| p[..3].iter().map(|&c| c as u64).sum::<u64>())
+            .sum();
+        total as f32 / (SIZE * SIZE * 3) as f32
+    }
+
+    #[test]
+    fn midnight_renders_darker_than_noon() {
+        let noon = render_floor_at(0.5);
+        let midnight = render_floor_at(0.0);
+        assert!(noon > 10.0, "noon floor is black ({noon})");
+        assert!(
+            midnight < noon * 0.5,
+            "midnight {midnight} should be well below noon {noon}"
+        );
+    }
 }
 
 /// Create a procedural debug texture atlas (16×16 grid).
@@ -770,6 +858,12 @@ impl VoxelPipeline {
                             shader_location: 3,
                             format: wgpu::VertexFormat::Uint32,
                         },
+                        // uv_tile
+                        wgpu::VertexAttribute {
+                            offset: 36,
+                            shader_location: 4,
+                            format: wgpu::VertexFormat::Float32x4,
+                        },
                     ],
                 }],
             },
@@ -840,6 +934,12 @@ impl VoxelPipeline {
                             shader_location: 3,
                             format: wgpu::VertexFormat::Uint32,
                         },
+                        // uv_tile
+                        wgpu::VertexAttribute {
+                            offset: 36,
+                            shader_location: 4,
+                            format: wgpu::VertexFormat::Float32x4,
+                        },
                     ],
                 }],
             },
@@ -1345,11 +1445,14 @@ pub struct WireframePipeline {
     highlight_buffer: wgpu::Buffer,
     highlight_bind_group: wgpu::BindGroup,
     vertex_buffer: wgpu::Buffer,
+    debug_buffer: wgpu::Buffer,
+    debug_bind_group: wgpu::BindGroup,
 }
 
 /// Particle billboard rendering pipeline.
 pub struct ParticlePipeline {
     render_pipeline: wgpu::RenderPipeline,
+    additive_pipeline: wgpu::RenderPipeline,
     quad_vertex_buffer: wgpu::Buffer,
     globals_buffer: wgpu::Buffer,
     globals_bind_group: wgpu::BindGroup,
@@ -1459,60 +1562,88 @@ impl ParticlePipeline {
             ],
         };
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Particle Render Pipeline"),
-            layout: Some(&layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main",
-                buffers: &[quad_vertex_layout, instance_layout],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: ctx.config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: None,
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: false,
-                depth_compare: wgpu::CompareFunction::LessEqual,
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
+        let create_pipeline = |label: &str, blend: wgpu::BlendState| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[quad_vertex_layout.clone(), instance_layout.clone()],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: ctx.config.format,
+                        blend: Some(blend),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: None,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    unclipped_depth: false,
+                    conservative: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: false,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+        };
+        let render_pipeline =
+            create_pipeline("Particle Render Pipeline", wgpu::BlendState::ALPHA_BLENDING);
+        let additive_pipeline = create_pipeline(
+            "Particle Additive Render Pipeline",
+            wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Zero,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
             },
-            multiview: None,
-        });
+        );
 
         Ok(Self {
             render_pipeline,
+            additive_pipeline,
             quad_vertex_buffer,
             globals_buffer,
             globals_bind_group,
         })
     }
 
-    /// Access the underlying `wgpu::RenderPipeline` for particles.
+    /// Access the underlying `wgpu::RenderPipeline` for alpha-blended particles.
     pub fn pipeline(&self) -> &wgpu::RenderPipeline {
         &self.render_pipeline
     }
 
+    /// Pipeline matching a particle batch's [`BlendMode`].
+    pub fn pipeline_for(&self, mode: BlendMode) -> &wgpu::RenderPipeline {
+        match mode {
+            BlendMode::Alpha => &self.render_pipeline,
+            BlendMode::Additive => &self.additive_pipeline,
+        }
+    }
+
     /// Access the particle globals bind group (viewport size).
     pub fn globals_bind_group(&self) -> &wgpu::BindGroup {
         &self.globals_bind_group
@@ -1610,6 +1741,25 @@ impl WireframePipeline {
             }],
         });
 
+        // Debug boxes are built in world space, so their uniform keeps a zero offset.
+        let debug_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Wireframe Debug Buffer"),
+            contents: bytemuck::bytes_of(&HighlightUniform {
+                position: [0.0; 3],
+                padding: 0.0,
+                color: [1.0, 1.0, 0.0, 0.8],
+            }),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let debug_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Wireframe Debug Bind Group"),
+            layout: &highlight_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: debug_buffer.as_entire_binding(),
+            }],
+        });
+
         // Create cube wireframe vertices (12 edges, 2 vertices per edge = 24 vertices)
         let cube_vertices: Vec<[f32; 3]> = vec![
             // Bottom face edges
@@ -1722,6 +1872,8 @@ impl WireframePipeline {
             highlight_buffer,
             highlight_bind_group,
             vertex_buffer,
+            debug_buffer,
+            debug_bind_group,
         })
     }
 
@@ -1778,4 +1930,37 @@ impl WireframePipeline {
     pub fn highlight_bind_group(&self) -> &wgpu::BindGroup {
         &self.highlight_bind_group
     }
+
+    /// Set the line color for debug boxes.
+    pub fn update_debug_color(&self, queue: &wgpu::Queue, color: [f32; 4]) {
+        let uniform = HighlightUniform {
+            position: [0.0; 3],
+            padding: 0.0,
+            color,
+        };
+        queue.write_buffer(&self.debug_buffer, 0, bytemuck::bytes_of(&uniform));
+    }
+
+    /// Get the bind group for debug boxes (world-space vertices, no offset).
+    pub fn debug_bind_group(&self) -> &wgpu::BindGroup {
+        &self.debug_bind_group
+    }
+
+    /// Upload line-list vertices for `boxes`, returning the buffer and vertex count.
+    pub fn create_debug_vertex_buffer(
+        &self,
+        device: &wgpu::Device,
+        boxes: &[WireframeBox],
+    ) -> Option<(wgpu::Buffer, u32)> {
+        if boxes.is_empty() {
+            return None;
+        }
+        let vertices: Vec<[f32; 3]> = boxes.iter().flat_map(|b| b.line_vertices()).collect();
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Wireframe Debug Vertex Buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        Some((buffer, vertices.len() as u32))
+    }
 }
@@ -1,6 +1,9 @@
 //! Voxel raycasting using DDA (Digital Differential Analyzer) algorithm.
 
-use glam::{IVec3, Vec3};
+use glam::{IVec3, Vec2, Vec3};
+
+/// Upper bound on DDA steps, guarding against non-finite input distances.
+const MAX_RAYCAST_STEPS: u32 = 16_384;
 
 /// Result of a raycast against the voxel world.
 #[derive(Debug, Clone, Copy)]
@@ -13,6 +16,25 @@ pub struct RaycastHit {
     pub distance: f32,
     /// World-space position of the hit point.
     pub hit_pos: Vec3,
+    /// Position of the hit point within the hit face, each axis in `0.0..=1.0`.
+    ///
+    /// X faces map (z, y), Y faces map (x, z) and Z faces map (x, y). Zero when
+    /// the ray started inside the block.
+    pub face_uv: Vec2,
+}
+
+/// Project a block-local hit position onto the face with `normal`.
+fn face_uv(local: Vec3, normal: IVec3) -> Vec2 {
+    let uv = if normal.x != 0 {
+        Vec2::new(local.z, local.y)
+    } else if normal.y != 0 {
+        Vec2::new(local.x, local.z)
+    } else if normal.z != 0 {
+        Vec2::new(local.x, local.y)
+    } else {
+        Vec2::ZERO
+    };
+    uv.clamp(Vec2::ZERO, Vec2::ONE)
 }
 
 /// Performs a DDA raycast through the voxel world.
@@ -100,19 +122,28 @@ where
 
     // Current face normal (which face we entered the voxel from)
     let mut face_normal = IVec3::ZERO;
+    // Distance along the ray at which we entered the current voxel
+    let mut distance = 0.0;
 
-    // Traverse voxels using DDA
-    let max_steps = (max_distance * 2.0) as i32; // Rough upper bound
+    // Traverse voxels using DDA. A ray crosses at most one boundary per unit
+    // travelled along each axis, plus the starting voxel and rounding slack.
+    let max_steps = (max_distance.max(0.0) * direction.abs().dot(Vec3::ONE) + 3.0)
+        .min(MAX_RAYCAST_STEPS as f32) as u32;
     for _ in 0..max_steps {
+        // Stop once the voxel we just entered starts beyond reach
+        if distance > max_distance {
+            break;
+        }
+
         // Check if current voxel is solid
         if is_solid(voxel) {
-            let distance = t_max.min_element() - delta.min_element();
             let hit_pos = origin + direction * distance;
             return Some(RaycastHit {
                 block_pos: voxel,
                 face_normal,
                 distance,
                 hit_pos,
+                face_uv: face_uv(hit_pos - voxel.as_vec3(), face_normal),
             });
         }
 
@@ -120,24 +151,22 @@ where
         if t_max.x < t_max.y && t_max.x < t_max.z {
             // Step in X
             voxel.x += step.x;
+            distance = t_max.x;
             t_max.x += delta.x;
             face_normal = IVec3::new(-step.x, 0, 0);
         } else if t_max.y < t_max.z {
             // Step in Y
             voxel.y += step.y;
+            distance = t_max.y;
             t_max.y += delta.y;
             face_normal = IVec3::new(0, -step.y, 0);
         } else {
             // Step in Z
             voxel.z += step.z;
+            distance = t_max.z;
             t_max.z += delta.z;
             face_normal = IVec3::new(0, 0, -step.z);
         }
-
-        // Check if we've exceeded max distance
-        if t_max.min_element() > max_distance {
-            break;
-        }
     }
 
     None
@@ -164,6 +193,58 @@ mod tests {
         assert_eq!(hit.face_normal, IVec3::new(-1, 0, 0)); // Hit from -X side
     }
 
+    #[test]
+    fn block_entered_just_inside_reach_is_hit() {
+        // The block's face is 4.5 away and the next boundary (5.5) is past reach.
+        let origin = Vec3::new(0.5, 0.5, 0.5);
+        let hit = raycast(origin, Vec3::X, 5.0, |pos| pos == IVec3::new(5, 0, 0))
+            .expect("block face is within reach");
+        assert_eq!(hit.block_pos, IVec3::new(5, 0, 0));
+        assert!((hit.distance - 4.5).abs() < 1e-5);
+
+        // One block further its face (5.5) is out of reach.
+        assert!(raycast(origin, Vec3::X, 5.0, |pos| pos == IVec3::new(6, 0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_raycast_hit_point_lies_on_face() {
+        let origin = Vec3::new(0.5, 0.25, 0.5);
+        let direction = Vec3::new(1.0, 0.2, 0.3).normalize();
+
+        let is_solid = |pos: IVec3| pos.x == 4;
+
+        let hit = raycast(origin, direction, 20.0, is_solid).expect("hit");
+        assert_eq!(hit.face_normal, IVec3::new(-1, 0, 0));
+        assert!((hit.hit_pos.x - 4.0).abs() < 1e-4);
+        assert!((hit.hit_pos - (origin + direction * hit.distance)).length() < 1e-5);
+        assert_eq!(hit.block_pos, hit.hit_pos.floor().as_ivec3());
+
+        let local = hit.hit_pos - hit.block_pos.as_vec3();
+        assert!((hit.face_uv - Vec2::new(local.z, local.y)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_raycast_long_reach_finds_distant_block() {
+        let origin = Vec3::new(0.5, 64.5, 0.5);
+        let direction = Vec3::new(1.0, 0.0, 1.0).normalize();
+
+        let target = IVec3::new(200, 64, 200);
+        let hit = raycast(origin, direction, 300.0, |pos| pos == target).expect("hit");
+        assert_eq!(hit.block_pos, target);
+        assert!(hit.distance > 280.0 && hit.distance <= 300.0);
+    }
+
+    #[test]
+    fn test_raycast_inside_solid_hits_at_zero() {
+        let origin = Vec3::new(2.5, 3.5, 4.5);
+        let is_solid = |pos: IVec3| pos == IVec3::new(2, 3, 4);
+
+        let hit = raycast(origin, Vec3::X, 0.25, is_solid).expect("hit");
+        assert_eq!(hit.block_pos, IVec3::new(2, 3, 4));
+        assert_eq!(hit.distance, 0.0);
+        assert_eq!(hit.face_normal, IVec3::ZERO);
+    }
+
     #[test]
     fn test_raycast_miss() {
         // Ray that doesn't hit anything
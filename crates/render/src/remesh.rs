@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+
+use mdminecraft_world::ChunkPos;
+
+/// Coalesces chunk remesh requests so each chunk is rebuilt at most once per frame.
+///
+/// Requests also invalidate the four horizontal neighbours, since edits on a chunk seam
+/// change which faces the adjacent chunk needs to emit.
+#[derive(Debug, Default, Clone)]
+pub struct RemeshQueue {
+    pending: BTreeSet<ChunkPos>,
+}
+
+impl RemeshQueue {
+    /// Create an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark `pos` and its horizontal neighbours as needing a remesh.
+    pub fn request(&mut self, pos: ChunkPos) {
+        self.pending.insert(pos);
+        self.pending.extend(neighbor_chunk_positions(pos));
+    }
+
+    /// Mark only `pos` as needing a remesh (no neighbour invalidation).
+    pub fn request_single(&mut self, pos: ChunkPos) {
+        self.pending.insert(pos);
+    }
+
+    /// Number of distinct chunks awaiting a remesh.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns true when no remeshes are pending.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Drain all pending chunks in deterministic (sorted) order.
+    pub fn drain(&mut self) -> Vec<ChunkPos> {
+        std::mem::take(&mut self.pending).into_iter().collect()
+    }
+}
+
+fn neighbor_chunk_positions(center: ChunkPos) -> [ChunkPos; 4] {
+    [
+        ChunkPos::new(center.x - 1, center.z),
+        ChunkPos::new(center.x + 1, center.z),
+        ChunkPos::new(center.x, center.z - 1),
+        ChunkPos::new(center.x, center.z + 1),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_requests_coalesce_into_one_remesh() {
+        let mut queue = RemeshQueue::new();
+        let pos = ChunkPos::new(2, -3);
+        queue.request(pos);
+        queue.request(pos);
+        queue.request(pos);
+
+        let flushed = queue.drain();
+        assert_eq!(flushed.iter().filter(|p| **p == pos).count(), 1);
+        // Center plus four neighbours.
+        assert_eq!(flushed.len(), 5);
+        assert!(flushed.contains(&ChunkPos::new(1, -3)));
+        assert!(flushed.contains(&ChunkPos::new(2, -2)));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn adjacent_requests_share_neighbours() {
+        let mut queue = RemeshQueue::new();
+        queue.request(ChunkPos::new(0, 0));
+        queue.request(ChunkPos::new(1, 0));
+        // 5 + 5 minus the two chunks each request already covered.
+        assert_eq!(queue.len(), 8);
+
+        let flushed = queue.drain();
+        let mut sorted = flushed.clone();
+        sorted.sort();
+        assert_eq!(flushed, sorted);
+    }
+}
@@ -2,22 +2,67 @@ use anyhow::{Context, Result};
 use image::codecs::png::{CompressionType, FilterType, PngEncoder};
 use image::ColorType;
 use image::ImageEncoder;
+use image::{Rgba, RgbaImage};
 use std::path::Path;
 use std::sync::mpsc;
 
-/// Pending GPU readback of a texture.
+/// GPU readback of a texture, either still in flight or resolved to RGBA8.
 pub struct TextureReadback {
-    buffer: wgpu::Buffer,
-    padded_bytes_per_row: u32,
+    state: ReadbackState,
     width: u32,
     height: u32,
-    format: wgpu::TextureFormat,
+}
+
+enum ReadbackState {
+    Pending {
+        buffer: wgpu::Buffer,
+        padded_bytes_per_row: u32,
+        format: wgpu::TextureFormat,
+    },
+    Resolved(Vec<u8>),
 }
 
 impl TextureReadback {
+    /// Wrap already-decoded RGBA8 pixels, e.g. a golden PNG loaded from disk.
+    pub fn from_rgba8(size: (u32, u32), rgba: Vec<u8>) -> Result<Self> {
+        let (width, height) = size;
+        let expected = width as usize * height as usize * 4;
+        if rgba.len() != expected {
+            return Err(anyhow::anyhow!(
+                "rgba buffer is {} bytes, expected {expected} for {width}x{height}",
+                rgba.len()
+            ));
+        }
+        Ok(Self {
+            state: ReadbackState::Resolved(rgba),
+            width,
+            height,
+        })
+    }
+
     /// Read back the texture contents as tightly packed RGBA8 bytes.
-    pub fn read_rgba8(self, device: &wgpu::Device) -> Result<Vec<u8>> {
-        let slice = self.buffer.slice(..);
+    pub fn read_rgba8(mut self, device: &wgpu::Device) -> Result<Vec<u8>> {
+        self.resolve(device)?;
+        match self.state {
+            ReadbackState::Resolved(rgba) => Ok(rgba),
+            ReadbackState::Pending { .. } => unreachable!("resolve leaves pixels resolved"),
+        }
+    }
+
+    /// Wait for the GPU copy and keep the pixels for comparison.
+    ///
+    /// Does nothing if the readback is already resolved.
+    pub fn resolve(&mut self, device: &wgpu::Device) -> Result<()> {
+        let ReadbackState::Pending {
+            buffer,
+            padded_bytes_per_row,
+            format,
+        } = &self.state
+        else {
+            return Ok(());
+        };
+
+        let slice = buffer.slice(..);
         let (tx, rx) = mpsc::channel();
         slice.map_async(wgpu::MapMode::Read, move |res| {
             let _ = tx.send(res);
@@ -33,7 +78,7 @@ impl TextureReadback {
         let mut rgba = vec![0u8; (unpadded_bytes_per_row * self.height) as usize];
 
         for row in 0..self.height {
-            let src_offset = (row * self.padded_bytes_per_row) as usize;
+            let src_offset = (row * padded_bytes_per_row) as usize;
             let dst_offset = (row * unpadded_bytes_per_row) as usize;
             let src = &mapped[src_offset..src_offset + unpadded_bytes_per_row as usize];
             let dst = &mut rgba[dst_offset..dst_offset + unpadded_bytes_per_row as usize];
@@ -41,9 +86,9 @@ impl TextureReadback {
         }
 
         drop(mapped);
-        self.buffer.unmap();
+        buffer.unmap();
 
-        match self.format {
+        match format {
             wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => {}
             wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
                 for pixel in rgba.chunks_exact_mut(4) {
@@ -57,7 +102,71 @@ impl TextureReadback {
             }
         }
 
-        Ok(rgba)
+        self.state = ReadbackState::Resolved(rgba);
+        Ok(())
+    }
+
+    /// Resolved RGBA8 pixels, or `None` while the GPU copy is pending.
+    pub fn pixels(&self) -> Option<&[u8]> {
+        match &self.state {
+            ReadbackState::Resolved(rgba) => Some(rgba),
+            ReadbackState::Pending { .. } => None,
+        }
+    }
+
+    /// Root-mean-square difference over all RGBA channels, in 0..=255 units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either readback is unresolved or the sizes differ.
+    pub fn rmse(&self, other: &TextureReadback) -> f64 {
+        let (lhs, rhs) = self.comparable_pixels(other);
+        if lhs.is_empty() {
+            return 0.0;
+        }
+        let sum: f64 = lhs
+            .iter()
+            .zip(rhs)
+            .map(|(&a, &b)| {
+                let delta = f64::from(a) - f64::from(b);
+                delta * delta
+            })
+            .sum();
+        (sum / lhs.len() as f64).sqrt()
+    }
+
+    /// Image marking pixels that differ from `other` in red.
+    ///
+    /// Unchanged pixels are drawn as dimmed grayscale so the changes stand out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either readback is unresolved or the sizes differ.
+    pub fn diff_image(&self, other: &TextureReadback) -> RgbaImage {
+        let (lhs, rhs) = self.comparable_pixels(other);
+        RgbaImage::from_fn(self.width, self.height, |x, y| {
+            let offset = ((y * self.width + x) * 4) as usize;
+            let a = &lhs[offset..offset + 4];
+            if a == &rhs[offset..offset + 4] {
+                let luma =
+                    (u32::from(a[0]) * 299 + u32::from(a[1]) * 587 + u32::from(a[2]) * 114) / 1000;
+                let dimmed = (luma / 4) as u8;
+                Rgba([dimmed, dimmed, dimmed, 255])
+            } else {
+                Rgba([255, 0, 0, 255])
+            }
+        })
+    }
+
+    fn comparable_pixels<'a>(&'a self, other: &'a TextureReadback) -> (&'a [u8], &'a [u8]) {
+        assert_eq!(
+            self.size(),
+            other.size(),
+            "cannot compare readbacks of different sizes"
+        );
+        let lhs = self.pixels().expect("readback not resolved");
+        let rhs = other.pixels().expect("readback not resolved");
+        (lhs, rhs)
     }
 
     /// Pixel dimensions of the readback texture.
@@ -111,11 +220,13 @@ pub fn record_texture_readback(
     );
 
     TextureReadback {
-        buffer,
-        padded_bytes_per_row,
+        state: ReadbackState::Pending {
+            buffer,
+            padded_bytes_per_row,
+            format,
+        },
         width,
         height,
-        format,
     }
 }
 
@@ -233,4 +344,43 @@ mod tests {
         let result = read_back_texture(wgpu::TextureFormat::Bgra8Unorm, (2, 1), &bgra);
         assert_eq!(result, vec![1, 2, 3, 4, 5, 6, 7, 8]);
     }
+
+    #[test]
+    fn rmse_is_zero_for_identical_readbacks() {
+        let rgba: Vec<u8> = (0..4 * 4 * 4).map(|i| (i * 7) as u8).collect();
+        let capture = TextureReadback::from_rgba8((4, 4), rgba.clone()).unwrap();
+        let golden = TextureReadback::from_rgba8((4, 4), rgba).unwrap();
+
+        assert_eq!(capture.rmse(&golden), 0.0);
+        let diff = capture.diff_image(&golden);
+        assert!(diff.pixels().all(|pixel| pixel.0 != [255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn diff_localizes_a_single_changed_pixel() {
+        let rgba = vec![64u8; 4 * 4 * 4];
+        let mut modified = rgba.clone();
+        let offset = (2 * 4 + 1) * 4;
+        modified[offset] = 200;
+
+        let golden = TextureReadback::from_rgba8((4, 4), rgba).unwrap();
+        let capture = TextureReadback::from_rgba8((4, 4), modified).unwrap();
+
+        let rmse = capture.rmse(&golden);
+        let expected = ((136.0f64 * 136.0) / 64.0).sqrt();
+        assert!((rmse - expected).abs() < 1e-9, "rmse {rmse}");
+
+        let diff = capture.diff_image(&golden);
+        let changed: Vec<(u32, u32)> = diff
+            .enumerate_pixels()
+            .filter(|(_, _, pixel)| pixel.0 == [255, 0, 0, 255])
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(changed, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn from_rgba8_rejects_mismatched_length() {
+        assert!(TextureReadback::from_rgba8((2, 2), vec![0; 15]).is_err());
+    }
 }
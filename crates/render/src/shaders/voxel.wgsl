@@ -13,6 +13,9 @@ struct TimeUniform {
     sun_dir: vec4<f32>,
     fog_color: vec4<f32>,
     fog_params: vec4<f32>,
+    sky_color: vec4<f32>,
+    ambient_color: vec4<f32>,
+    sun_color: vec4<f32>,
 }
 
 @group(0) @binding(1)
@@ -37,6 +40,7 @@ struct VertexInput {
     @location(1) normal: vec3<f32>,
     @location(2) uv: vec2<f32>,
     @location(3) packed_data: u32,
+    @location(4) uv_tile: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -46,6 +50,8 @@ struct VertexOutput {
     @location(2) uv: vec2<f32>,
     @location(3) block_id: u32,
     @location(4) light: f32,
+    @location(5) ao: f32,
+    @location(6) @interpolate(flat) uv_tile: vec4<f32>,
 }
 
 @vertex
@@ -56,9 +62,13 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     out.world_pos = world_pos;
     out.normal = in.normal;
     out.uv = in.uv;
+    out.uv_tile = in.uv_tile;
     out.block_id = in.packed_data & 0xFFFFu;
-    let light_value = (in.packed_data >> 16u) & 0xFFu;
+    // Byte 2 packs light (low nibble, 0-15) and ambient occlusion (high nibble, 0-3).
+    let light_value = (in.packed_data >> 16u) & 0x0Fu;
+    let ao_value = (in.packed_data >> 20u) & 0x0Fu;
     out.light = f32(light_value) / 15.0;
+    out.ao = min(f32(ao_value), 3.0) / 3.0;
     return out;
 }
 
@@ -69,17 +79,28 @@ fn apply_fog(color: vec3<f32>, dist: f32) -> vec3<f32> {
     return mix(color, time_uniform.fog_color.rgb, fog_factor);
 }
 
-@fragment
-fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    var color = textureSample(atlas_texture, atlas_sampler, in.uv).rgb;
+// Wrap UVs of greedy-merged quads back into their atlas tile so the texture repeats.
+fn tiled_uv(uv: vec2<f32>, tile: vec4<f32>) -> vec2<f32> {
+    if (tile.z <= 0.0 || tile.w <= 0.0) {
+        return uv;
+    }
+    return tile.xy + fract((uv - tile.xy) / tile.zw) * tile.zw;
+}
+
+fn shade(in: VertexOutput) -> vec4<f32> {
+    // Gradients come from the unwrapped UVs so mip selection stays smooth across tile seams.
+    let uv = tiled_uv(in.uv, in.uv_tile);
+    var color = textureSampleGrad(atlas_texture, atlas_sampler, uv, dpdx(in.uv), dpdy(in.uv)).rgb;
     let sun_dir = normalize(time_uniform.sun_dir.xyz);
     let precipitation = time_uniform.fog_params.w;
     let diffuse = max(dot(in.normal, sun_dir), 0.0);
-    let time_factor = smoothstep(0.0, 0.3, time_uniform.time.x) * (1.0 - smoothstep(0.7, 1.0, time_uniform.time.x));
-    let ambient = mix(0.1, 0.3, time_factor);
-    let sun_contrib = (ambient + diffuse * 0.5) * mix(1.0, 0.65, precipitation);
+    // Ambient and sun colors follow the day cycle (see TimeUniform::from_time_of_day).
+    let daylight = time_uniform.ambient_color.rgb + time_uniform.sun_color.rgb * diffuse;
+    let sun_contrib = daylight * mix(1.0, 0.65, precipitation);
     let artificial_light = in.light * mix(0.4, 0.55, precipitation);
-    color *= sun_contrib + artificial_light;
+    color *= sun_contrib + vec3<f32>(artificial_light);
+    // Fully occluded corners keep half their brightness.
+    color *= mix(0.5, 1.0, in.ao);
     color = mix(color, color * vec3<f32>(0.85, 0.9, 0.95), precipitation * 0.2);
 
     // Water animation and tint
@@ -96,3 +117,13 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
     return vec4<f32>(color, alpha);
 }
+
+@fragment
+fn fs_main_opaque(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(shade(in).rgb, 1.0);
+}
+
+@fragment
+fn fs_main_fluid(in: VertexOutput) -> @location(0) vec4<f32> {
+    return shade(in);
+}
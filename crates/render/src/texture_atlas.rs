@@ -47,8 +47,7 @@ fn bleed_atlas_tile_padding(metadata: &TextureAtlasMetadata, pixels: &mut [u8])
 
     let width = metadata.atlas_width as i32;
     let height = metadata.atlas_height as i32;
-    let tile = metadata.tile_size as i32;
-    if width <= 0 || height <= 0 || tile <= 0 {
+    if width <= 0 || height <= 0 {
         return;
     }
 
@@ -79,16 +78,18 @@ fn bleed_atlas_tile_padding(metadata: &TextureAtlasMetadata, pixels: &mut [u8])
     };
 
     for entry in &metadata.entries {
+        // Shelf-packed sprites vary in size, so bleed each entry's own rect.
+        let (w, h) = (entry.width as i32, entry.height as i32);
         let x0 = entry.x as i32;
         let y0 = entry.y as i32;
-        let x1 = x0 + tile - 1;
-        let y1 = y0 + tile - 1;
+        let x1 = x0 + w - 1;
+        let y1 = y0 + h - 1;
 
-        if x0 < 0 || y0 < 0 || x1 >= width || y1 >= height {
+        if w <= 0 || h <= 0 || x0 < 0 || y0 < 0 || x1 >= width || y1 >= height {
             continue;
         }
 
-        for dy in 0..tile {
+        for dy in 0..h {
             let y = y0 + dy;
             let left = get(x0, y, pixels);
             for dx in 1..=pad {
@@ -101,7 +102,7 @@ fn bleed_atlas_tile_padding(metadata: &TextureAtlasMetadata, pixels: &mut [u8])
             }
         }
 
-        for dx in 0..tile {
+        for dx in 0..w {
             let x = x0 + dx;
             let top = get(x, y0, pixels);
             for dy in 1..=pad {
@@ -202,7 +203,7 @@ pub fn warn_missing_atlas(err: &RuntimeAtlasError) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mdminecraft_assets::AtlasEntry;
+    use mdminecraft_assets::{AtlasEntry, AtlasLayout};
     use std::sync::Mutex;
     use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -218,7 +219,12 @@ mod tests {
 
     fn pixel_at(pixels: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
         let idx = ((y * width + x) * 4) as usize;
-        [pixels[idx], pixels[idx + 1], pixels[idx + 2], pixels[idx + 3]]
+        [
+            pixels[idx],
+            pixels[idx + 1],
+            pixels[idx + 2],
+            pixels[idx + 3],
+        ]
     }
 
     #[test]
@@ -236,6 +242,8 @@ mod tests {
             rows: 1,
             atlas_width: 4,
             atlas_height: 4,
+            layout: AtlasLayout::Grid,
+            content_hash: 0,
             entries: vec![AtlasEntry {
                 name: "tile".to_string(),
                 x: 1,
@@ -285,6 +293,8 @@ mod tests {
             rows: 1,
             atlas_width: 4,
             atlas_height: 4,
+            layout: AtlasLayout::Grid,
+            content_hash: 0,
             entries: vec![AtlasEntry {
                 name: "tile".to_string(),
                 x: 1,
@@ -306,7 +316,9 @@ mod tests {
         std::env::set_var("MDM_ATLAS_META", &meta_path);
         std::env::set_var("MDM_ATLAS_IMAGE", &image_path);
 
-        let err = RuntimeAtlas::load_from_disk().err().expect("expect mismatch");
+        let err = RuntimeAtlas::load_from_disk()
+            .err()
+            .expect("expect mismatch");
         match err {
             RuntimeAtlasError::DimensionMismatch { .. } => {}
             other => panic!("expected dimension mismatch, got {other:?}"),
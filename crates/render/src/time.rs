@@ -124,6 +124,10 @@ pub struct TimeUniform {
     pub fog_params: [f32; 4],
     /// Base sky color tint (rgb) and optional lightning flash (a).
     pub sky_color: [f32; 4],
+    /// Ambient light color (rgb) for the time of day; `a` is unused.
+    pub ambient_color: [f32; 4],
+    /// Direct sunlight color (rgb), fading out as the sun sets; `a` is unused.
+    pub sun_color: [f32; 4],
 }
 
 impl TimeUniform {
@@ -140,22 +144,59 @@ impl TimeUniform {
         let fog_start = mix_scalar(48.0, 24.0, weather_intensity);
         let fog_end = mix_scalar(120.0, 70.0, weather_intensity);
         let night_vision = night_vision.clamp(0.0, 1.0);
+        let ambient = ambient_color_for_time(time.time());
+        let sun = sun_color_for_height(dir[1]);
         Self {
             time: [time.time(), 0.0, 0.0, 0.0],
             sun_dir: [dir[0], dir[1], dir[2], 0.0],
             fog_color: [fog_color[0], fog_color[1], fog_color[2], 0.0],
             fog_params: [fog_start, fog_end, night_vision, weather_intensity],
             sky_color: [fog_color[0], fog_color[1], fog_color[2], 0.0],
+            ambient_color: [ambient[0], ambient[1], ambient[2], 0.0],
+            sun_color: [sun[0], sun[1], sun[2], 0.0],
         }
     }
 }
 
 fn fog_color_for_time(t: f32) -> [f32; 3] {
-    let night = [0.05, 0.07, 0.12];
-    let dawn = [0.5, 0.4, 0.35];
-    let day = [0.7, 0.8, 0.9];
-    let dusk = [0.45, 0.35, 0.4];
+    day_cycle_color(
+        t,
+        [0.05, 0.07, 0.12],
+        [0.5, 0.4, 0.35],
+        [0.7, 0.8, 0.9],
+        [0.45, 0.35, 0.4],
+    )
+}
+
+/// Ambient light: dim blue at night, warm through dawn and dusk.
+fn ambient_color_for_time(t: f32) -> [f32; 3] {
+    day_cycle_color(
+        t,
+        [0.08, 0.09, 0.14],
+        [0.24, 0.18, 0.16],
+        [0.3, 0.3, 0.3],
+        [0.24, 0.17, 0.18],
+    )
+}
 
+/// Direct sunlight for a sun at height `sun_y`: orange near the horizon,
+/// white overhead, and off once the sun has set.
+fn sun_color_for_height(sun_y: f32) -> [f32; 3] {
+    let horizon = [1.0, 0.55, 0.3];
+    let overhead = [1.0, 1.0, 1.0];
+    let color = mix_color(horizon, overhead, smoothstep(0.0, 0.5, sun_y));
+    let strength = 0.5 * smoothstep(-0.05, 0.15, sun_y);
+    color.map(|c| c * strength)
+}
+
+/// Blend between the night, dawn, day and dusk colors over the day cycle.
+fn day_cycle_color(
+    t: f32,
+    night: [f32; 3],
+    dawn: [f32; 3],
+    day: [f32; 3],
+    dusk: [f32; 3],
+) -> [f32; 3] {
     if t < 0.2 {
         mix_color(night, dawn, smoothstep(0.15, 0.2, t))
     } else if t < 0.3 {
@@ -229,6 +270,36 @@ mod tests {
         assert!((len - 1.0).abs() < 1e-4);
     }
 
+    #[test]
+    fn light_colors_follow_the_sun() {
+        let light_at = |t: f32| {
+            let mut time = TimeOfDay::new();
+            time.set_time(t);
+            TimeUniform::from_time_of_day(&time, 0.0, 0.0)
+        };
+        let luminance = |c: [f32; 4]| c[0] + c[1] + c[2];
+
+        let noon = light_at(0.5);
+        let midnight = light_at(0.0);
+        assert!(luminance(noon.ambient_color) > 2.0 * luminance(midnight.ambient_color));
+        assert!(luminance(noon.sun_color) > 1.0);
+        // The sun is below the horizon, so it mustn't light faces from beneath.
+        assert_eq!(luminance(midnight.sun_color), 0.0);
+
+        // Low sun at dawn is warmer than the overhead sun at noon.
+        let dawn = light_at(0.3);
+        assert!(dawn.sun_color[2] / dawn.sun_color[0] < noon.sun_color[2] / noon.sun_color[0]);
+
+        // The ramp is continuous through dusk.
+        let step = (0..100)
+            .map(|i| 0.7 + i as f32 * 0.002)
+            .fold(0.0f32, |max, t| {
+                let (a, b) = (light_at(t), light_at(t + 0.002));
+                max.max((luminance(a.ambient_color) - luminance(b.ambient_color)).abs())
+            });
+        assert!(step < 0.02, "ambient jumps by {step}");
+    }
+
     #[test]
     fn period_name_and_daytime_match_ranges() {
         let mut time = TimeOfDay::new();
@@ -23,6 +23,7 @@ pub struct UiManager {
     context: egui::Context,
     renderer: egui_wgpu::Renderer,
     state: egui_winit::State,
+    crosshair: CrosshairConfig,
 }
 
 impl UiManager {
@@ -43,9 +44,20 @@ impl UiManager {
             context,
             renderer,
             state,
+            crosshair: CrosshairConfig::default(),
         }
     }
 
+    /// Current crosshair appearance.
+    pub fn crosshair(&self) -> CrosshairConfig {
+        self.crosshair
+    }
+
+    /// Change the crosshair appearance (e.g. from the settings menu).
+    pub fn set_crosshair(&mut self, config: CrosshairConfig) {
+        self.crosshair = config;
+    }
+
     /// Handle window event.
     pub fn handle_event(
         &mut self,
@@ -145,12 +157,175 @@ impl fmt::Display for ControlMode {
     }
 }
 
+/// Number of frames kept in the HUD's FPS and frame-time history.
+const HISTORY_LEN: usize = 120;
+
+/// Shape drawn at the screen center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrosshairStyle {
+    /// Horizontal and vertical bars
+    #[default]
+    Cross,
+    /// Filled dot
+    Dot,
+    /// Ring with a dot in the middle
+    CircleDot,
+}
+
+/// Appearance of the gameplay crosshair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrosshairConfig {
+    /// Overall width and height in points
+    pub size: f32,
+    /// Line thickness (and dot diameter) in points
+    pub thickness: f32,
+    /// Empty space left around the center so the targeted block stays visible
+    pub gap: f32,
+    /// Crosshair color
+    pub color: egui::Color32,
+    /// Crosshair shape
+    pub style: CrosshairStyle,
+}
+
+impl Default for CrosshairConfig {
+    fn default() -> Self {
+        Self {
+            size: 10.0,
+            thickness: 2.0,
+            gap: 0.0,
+            color: egui::Color32::WHITE,
+            style: CrosshairStyle::Cross,
+        }
+    }
+}
+
+/// Shapes for a crosshair centered on `center`.
+///
+/// A cross is two bars, or four arms when `gap` is non-zero.
+pub fn crosshair_shapes(config: &CrosshairConfig, center: egui::Pos2) -> Vec<egui::Shape> {
+    let stroke = egui::Stroke::new(config.thickness, config.color);
+    let half = config.size * 0.5;
+    let dot = || egui::Shape::circle_filled(center, config.thickness * 0.5, config.color);
+
+    match config.style {
+        CrosshairStyle::Cross if config.gap <= 0.0 => vec![
+            egui::Shape::line_segment(
+                [
+                    center - egui::vec2(half, 0.0),
+                    center + egui::vec2(half, 0.0),
+                ],
+                stroke,
+            ),
+            egui::Shape::line_segment(
+                [
+                    center - egui::vec2(0.0, half),
+                    center + egui::vec2(0.0, half),
+                ],
+                stroke,
+            ),
+        ],
+        CrosshairStyle::Cross => {
+            let inner = (config.gap * 0.5).min(half);
+            [
+                egui::vec2(1.0, 0.0),
+                egui::vec2(-1.0, 0.0),
+                egui::vec2(0.0, 1.0),
+                egui::vec2(0.0, -1.0),
+            ]
+            .into_iter()
+            .map(|dir| {
+                egui::Shape::line_segment([center + dir * inner, center + dir * half], stroke)
+            })
+            .collect()
+        }
+        CrosshairStyle::Dot => vec![dot()],
+        CrosshairStyle::CircleDot => vec![
+            egui::Shape::circle_stroke(center, half.max(config.gap * 0.5), stroke),
+            dot(),
+        ],
+    }
+}
+
+/// Draw the crosshair at the center of the screen, above all windows.
+pub fn render_crosshair(ctx: &egui::Context, config: &CrosshairConfig) {
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("crosshair"),
+    ));
+    painter.extend(crosshair_shapes(config, ctx.screen_rect().center()));
+}
+
+/// Frame time budget for 60 FPS, in ms.
+const FRAME_BUDGET_60_MS: f32 = 1000.0 / 60.0;
+
+/// Frame time budget for 30 FPS, in ms; slower frames are drawn as spikes.
+const FRAME_BUDGET_30_MS: f32 = 1000.0 / 30.0;
+
+/// Smallest vertical range of the frame-time graph, in ms.
+const GRAPH_MIN_RANGE_MS: f32 = 50.0;
+
+/// Height of the frame-time graph in points.
+const GRAPH_HEIGHT: f32 = 60.0;
+
+/// Fixed-size ring buffer of per-frame samples.
+#[derive(Debug, Clone)]
+pub struct FrameHistory {
+    samples: [f32; HISTORY_LEN],
+    /// Index the next sample is written to
+    head: usize,
+    len: usize,
+}
+
+impl FrameHistory {
+    /// Create an empty history.
+    pub fn new() -> Self {
+        Self {
+            samples: [0.0; HISTORY_LEN],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Record a sample, overwriting the oldest once full.
+    pub fn push(&mut self, sample: f32) {
+        self.samples[self.head] = sample;
+        self.head = (self.head + 1) % HISTORY_LEN;
+        self.len = (self.len + 1).min(HISTORY_LEN);
+    }
+
+    /// Number of samples stored.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no samples have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Samples from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = f32> + '_ {
+        let start = (self.head + HISTORY_LEN - self.len) % HISTORY_LEN;
+        (0..self.len).map(move |i| self.samples[(start + i) % HISTORY_LEN])
+    }
+}
+
+impl Default for FrameHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Aggregated state values rendered via the debug HUD overlay.
 pub struct DebugHud {
     /// Whether the HUD is visible
     pub visible: bool,
     /// FPS history (last 120 frames)
-    fps_history: Vec<f32>,
+    fps_history: FrameHistory,
+    /// Frame time history in ms (last 120 frames)
+    frame_time_history: FrameHistory,
+    /// Whether the frame-time graph is drawn in the HUD
+    pub graph_visible: bool,
     /// Current FPS
     pub fps: f32,
     /// Frame time in ms
@@ -163,6 +338,10 @@ pub struct DebugHud {
     pub chunks_loaded: usize,
     /// Number of chunks visible (after frustum culling)
     pub chunks_visible: usize,
+    /// Number of chunks skipped because they were occluded
+    pub chunks_occluded: usize,
+    /// Number of chunks skipped by frustum or occlusion culling
+    pub chunks_culled: usize,
     /// Total vertices
     pub total_vertices: usize,
     /// Total triangles
@@ -192,13 +371,17 @@ impl DebugHud {
     pub fn new() -> Self {
         Self {
             visible: true,
-            fps_history: Vec::with_capacity(120),
+            fps_history: FrameHistory::new(),
+            frame_time_history: FrameHistory::new(),
+            graph_visible: true,
             fps: 0.0,
             frame_time_ms: 0.0,
             camera_pos: [0.0; 3],
             camera_rot: [0.0; 2],
             chunks_loaded: 0,
             chunks_visible: 0,
+            chunks_occluded: 0,
+            chunks_culled: 0,
             total_vertices: 0,
             total_triangles: 0,
             mining_progress: None,
@@ -219,9 +402,37 @@ impl DebugHud {
         self.fps = if dt > 0.0 { 1.0 / dt } else { 0.0 };
 
         self.fps_history.push(self.fps);
-        if self.fps_history.len() > 120 {
-            self.fps_history.remove(0);
+        self.frame_time_history.push(self.frame_time_ms);
+    }
+
+    /// FPS over the recorded frames, oldest first.
+    pub fn fps_history(&self) -> &FrameHistory {
+        &self.fps_history
+    }
+
+    /// Frame times in ms over the recorded frames, oldest first.
+    pub fn frame_time_history(&self) -> &FrameHistory {
+        &self.frame_time_history
+    }
+
+    /// FPS as `(current, average, min, max)` over the recorded frames.
+    ///
+    /// Average, min and max are zero before the first frame.
+    pub fn fps_stats(&self) -> (f32, f32, f32, f32) {
+        if self.fps_history.is_empty() {
+            return (self.fps, 0.0, 0.0, 0.0);
+        }
+
+        let mut sum = 0.0;
+        let mut min = f32::INFINITY;
+        let mut max = f32::NEG_INFINITY;
+        for fps in self.fps_history.iter() {
+            sum += fps;
+            min = min.min(fps);
+            max = max.max(fps);
         }
+        let avg = sum / self.fps_history.len() as f32;
+        (self.fps, avg, min, max)
     }
 
     /// Toggle HUD visibility.
@@ -246,15 +457,13 @@ impl DebugHud {
                 ui.label(format!("FPS: {:.1}", self.fps));
                 ui.label(format!("Frame Time: {:.2} ms", self.frame_time_ms));
 
-                // FPS graph (simplified - egui 0.26 plot API may differ)
                 if !self.fps_history.is_empty() {
-                    let min_fps = self
-                        .fps_history
-                        .iter()
-                        .cloned()
-                        .fold(f32::INFINITY, f32::min);
-                    let max_fps = self.fps_history.iter().cloned().fold(0.0f32, f32::max);
+                    let (_, avg_fps, min_fps, max_fps) = self.fps_stats();
                     ui.label(format!("FPS range: {:.1} - {:.1}", min_fps, max_fps));
+                    ui.label(format!("FPS average: {:.1}", avg_fps));
+                }
+                if self.graph_visible && !self.frame_time_history.is_empty() {
+                    self.render_fps_graph(ui);
                 }
 
                 ui.add_space(10.0);
@@ -285,6 +494,8 @@ impl DebugHud {
                         0.0
                     }
                 ));
+                ui.label(format!("Chunks Culled: {}", self.chunks_culled));
+                ui.label(format!("Chunks Occluded: {}", self.chunks_occluded));
                 ui.label(format!("Total Vertices: {}", self.total_vertices));
                 ui.label(format!("Total Triangles: {}", self.total_triangles));
                 ui.label(format!(
@@ -328,6 +539,69 @@ impl DebugHud {
                 ui.label("Press F3 to toggle this HUD");
             });
     }
+
+    /// Plot the frame-time history with 60 and 30 FPS reference lines.
+    fn render_fps_graph(&self, ui: &mut egui::Ui) {
+        let size = egui::vec2(ui.available_width(), GRAPH_HEIGHT);
+        let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(160));
+
+        let range_ms = self
+            .frame_time_history
+            .iter()
+            .fold(GRAPH_MIN_RANGE_MS, f32::max);
+        let y_for = |ms: f32| rect.bottom() - (ms / range_ms) * rect.height();
+
+        for (budget, label) in [(FRAME_BUDGET_60_MS, "60"), (FRAME_BUDGET_30_MS, "30")] {
+            let y = y_for(budget);
+            painter.line_segment(
+                [egui::pos2(rect.left(), y), egui::pos2(rect.right(), y)],
+                egui::Stroke::new(1.0, egui::Color32::from_gray(110)),
+            );
+            painter.text(
+                egui::pos2(rect.left() + 2.0, y),
+                egui::Align2::LEFT_BOTTOM,
+                label,
+                egui::FontId::monospace(9.0),
+                egui::Color32::from_gray(160),
+            );
+        }
+
+        let step = rect.width() / (HISTORY_LEN - 1) as f32;
+        let points: Vec<(egui::Pos2, f32)> = self
+            .frame_time_history
+            .iter()
+            .enumerate()
+            .map(|(i, ms)| (egui::pos2(rect.left() + i as f32 * step, y_for(ms)), ms))
+            .collect();
+
+        for (point, ms) in &points {
+            let color = frame_time_color(*ms).gamma_multiply(0.35);
+            painter.line_segment(
+                [*point, egui::pos2(point.x, rect.bottom())],
+                egui::Stroke::new(step.max(1.0), color),
+            );
+        }
+        for pair in points.windows(2) {
+            let ((a, a_ms), (b, b_ms)) = (pair[0], pair[1]);
+            painter.line_segment(
+                [a, b],
+                egui::Stroke::new(1.5, frame_time_color(a_ms.max(b_ms))),
+            );
+        }
+    }
+}
+
+/// Graph color for a frame; frames slower than 30 FPS are red spikes.
+fn frame_time_color(frame_time_ms: f32) -> egui::Color32 {
+    if frame_time_ms > FRAME_BUDGET_30_MS {
+        egui::Color32::from_rgb(230, 60, 60)
+    } else if frame_time_ms > FRAME_BUDGET_60_MS {
+        egui::Color32::from_rgb(230, 200, 60)
+    } else {
+        egui::Color32::from_rgb(80, 200, 100)
+    }
 }
 
 #[cfg(test)]
@@ -353,6 +627,112 @@ mod tests {
         assert!(hud.frame_time_ms > 0.0);
     }
 
+    #[test]
+    fn frame_history_wraps_in_chronological_order() {
+        let mut history = FrameHistory::new();
+        assert!(history.is_empty());
+
+        for i in 0..(HISTORY_LEN + 5) {
+            history.push(i as f32);
+        }
+
+        let samples: Vec<f32> = history.iter().collect();
+        assert_eq!(samples.len(), HISTORY_LEN);
+        assert_eq!(samples[0], 5.0);
+        assert_eq!(samples[HISTORY_LEN - 1], (HISTORY_LEN + 4) as f32);
+        assert!(samples.windows(2).all(|pair| pair[1] == pair[0] + 1.0));
+    }
+
+    #[test]
+    fn fps_stats_match_naive_computation() {
+        let mut hud = DebugHud::new();
+        assert_eq!(hud.fps_stats(), (0.0, 0.0, 0.0, 0.0));
+
+        let dts: Vec<f32> = (0..150).map(|i| 1.0 / (30.0 + (i % 50) as f32)).collect();
+        for &dt in &dts {
+            hud.update_fps(dt);
+        }
+
+        let recent: Vec<f32> = dts[dts.len() - HISTORY_LEN..]
+            .iter()
+            .map(|dt| 1.0 / dt)
+            .collect();
+        let naive_avg = recent.iter().sum::<f32>() / recent.len() as f32;
+        let naive_min = recent.iter().cloned().fold(f32::INFINITY, f32::min);
+        let naive_max = recent.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+        let (cur, avg, min, max) = hud.fps_stats();
+        assert_eq!(cur, 1.0 / dts[dts.len() - 1]);
+        assert!((avg - naive_avg).abs() < 1e-3);
+        assert_eq!(min, naive_min);
+        assert_eq!(max, naive_max);
+        assert_eq!(hud.frame_time_history().len(), HISTORY_LEN);
+    }
+
+    #[test]
+    fn frame_time_graph_flags_spikes_red() {
+        assert_eq!(frame_time_color(40.0), egui::Color32::from_rgb(230, 60, 60));
+        assert_ne!(frame_time_color(16.0), frame_time_color(40.0));
+        assert_ne!(frame_time_color(20.0), frame_time_color(40.0));
+
+        let mut hud = DebugHud::new();
+        for i in 0..HISTORY_LEN {
+            hud.update_fps(if i % 10 == 0 { 0.05 } else { 1.0 / 60.0 });
+        }
+        let ctx = egui::Context::default();
+        for visible in [true, false] {
+            hud.graph_visible = visible;
+            let _ = ctx.run(Default::default(), |ctx| {
+                hud.render(ctx);
+            });
+        }
+    }
+
+    #[test]
+    fn crosshair_defaults_to_plain_white_cross() {
+        let config = CrosshairConfig::default();
+        assert_eq!(config.style, CrosshairStyle::Cross);
+        assert_eq!(config.size, 10.0);
+        assert_eq!(config.thickness, 2.0);
+        assert_eq!(config.gap, 0.0);
+        assert_eq!(config.color, egui::Color32::WHITE);
+    }
+
+    #[test]
+    fn crosshair_segment_count_follows_style() {
+        let center = egui::pos2(100.0, 50.0);
+        let count = |style, gap| {
+            let config = CrosshairConfig {
+                style,
+                gap,
+                ..CrosshairConfig::default()
+            };
+            crosshair_shapes(&config, center).len()
+        };
+
+        assert_eq!(count(CrosshairStyle::Cross, 0.0), 2);
+        assert_eq!(count(CrosshairStyle::Cross, 4.0), 4);
+        assert_eq!(count(CrosshairStyle::Dot, 0.0), 1);
+        assert_eq!(count(CrosshairStyle::CircleDot, 0.0), 2);
+
+        // The gap keeps the center clear.
+        let config = CrosshairConfig {
+            gap: 4.0,
+            ..CrosshairConfig::default()
+        };
+        for shape in crosshair_shapes(&config, center) {
+            let egui::Shape::LineSegment { points, .. } = shape else {
+                panic!("cross arms are line segments");
+            };
+            assert!(points.iter().all(|p| p.distance(center) >= 2.0));
+        }
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            render_crosshair(ctx, &config);
+        });
+    }
+
     #[test]
     fn debug_hud_toggle_visibility() {
         let mut hud = DebugHud::new();
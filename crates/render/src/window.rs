@@ -151,6 +151,126 @@ pub struct InputSnapshot {
     pub cursor_captured: bool,
 }
 
+/// Default radial deadzone for analog sticks.
+pub const DEFAULT_STICK_DEADZONE: f32 = 0.15;
+
+/// Look delta, in mouse pixels per frame, for a fully deflected right stick.
+const GAMEPAD_LOOK_PIXELS: f64 = 15.0;
+
+/// Gamepad buttons, named by position (south is A on Xbox, Cross on PlayStation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    /// Bottom face button
+    South,
+    /// Right face button
+    East,
+    /// Left face button
+    West,
+    /// Top face button
+    North,
+    /// Left bumper
+    LeftShoulder,
+    /// Right bumper
+    RightShoulder,
+    /// Left trigger
+    LeftTrigger,
+    /// Right trigger
+    RightTrigger,
+    /// Left stick click
+    LeftStick,
+    /// Right stick click
+    RightStick,
+    /// Start/options button
+    Start,
+    /// Select/back button
+    Select,
+}
+
+/// Rescale a stick so input inside the radial `deadzone` reads as zero.
+///
+/// The deadzone is applied to the stick's magnitude rather than per axis, so
+/// diagonals keep their direction, and the remaining range is stretched back
+/// to `0.0..=1.0`.
+pub fn apply_radial_deadzone(stick: [f32; 2], deadzone: f32) -> [f32; 2] {
+    let magnitude = stick[0].hypot(stick[1]);
+    if magnitude <= deadzone || magnitude <= f32::EPSILON {
+        return [0.0, 0.0];
+    }
+    let scaled = ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0);
+    let scale = scaled / magnitude;
+    [stick[0] * scale, stick[1] * scale]
+}
+
+/// State of one gamepad, with the deadzone already applied to both sticks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadState {
+    left_stick: [f32; 2],
+    right_stick: [f32; 2],
+    /// Buttons currently held
+    pub buttons: HashSet<GamepadButton>,
+    /// Radial deadzone applied when sticks are set
+    pub deadzone: f32,
+}
+
+impl Default for GamepadState {
+    fn default() -> Self {
+        Self {
+            left_stick: [0.0, 0.0],
+            right_stick: [0.0, 0.0],
+            buttons: HashSet::new(),
+            deadzone: DEFAULT_STICK_DEADZONE,
+        }
+    }
+}
+
+impl GamepadState {
+    /// Set the left stick from raw axes (`+y` is pushed away from the player).
+    pub fn set_left_stick(&mut self, raw: [f32; 2]) {
+        self.left_stick = apply_radial_deadzone(raw, self.deadzone);
+    }
+
+    /// Set the right stick from raw axes (`+y` is pushed away from the player).
+    pub fn set_right_stick(&mut self, raw: [f32; 2]) {
+        self.right_stick = apply_radial_deadzone(raw, self.deadzone);
+    }
+
+    /// Left stick after the deadzone.
+    pub fn left_stick(&self) -> [f32; 2] {
+        self.left_stick
+    }
+
+    /// Right stick after the deadzone.
+    pub fn right_stick(&self) -> [f32; 2] {
+        self.right_stick
+    }
+
+    /// Check if a button is held.
+    pub fn is_pressed(&self, button: GamepadButton) -> bool {
+        self.buttons.contains(&button)
+    }
+}
+
+/// Smoothing and acceleration applied to mouse-look deltas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MouseFilter {
+    /// Multiplier applied after smoothing and acceleration
+    pub sensitivity: f32,
+    /// Extra gain per pixel of motion; `0.0` disables acceleration
+    pub acceleration: f32,
+    /// Weight of the smoothed history in `0.0..1.0`; `0.0` disables smoothing
+    pub smoothing: f32,
+}
+
+impl Default for MouseFilter {
+    fn default() -> Self {
+        Self {
+            sensitivity: 1.0,
+            acceleration: 0.0,
+            smoothing: 0.0,
+        }
+    }
+}
+
 /// Input state tracking.
 #[derive(Debug, Clone)]
 pub struct InputState {
@@ -164,6 +284,8 @@ pub struct InputState {
     pub mouse_delta: (f64, f64),
     /// Raw mouse delta reported by DeviceEvents
     pub raw_mouse_delta: (f64, f64),
+    /// Exponentially smoothed mouse delta, advanced by `filtered_mouse_delta`
+    pub smoothed_mouse_delta: (f64, f64),
     /// Mouse buttons pressed
     pub mouse_buttons: HashSet<MouseButton>,
     /// Mouse buttons clicked this frame
@@ -178,6 +300,8 @@ pub struct InputState {
     pub wants_cursor_capture: bool,
     /// Current context
     pub context: InputContext,
+    /// Latest gamepad state
+    pub gamepad: GamepadState,
 }
 
 impl Default for InputState {
@@ -188,6 +312,7 @@ impl Default for InputState {
             mouse_pos: (0.0, 0.0),
             mouse_delta: (0.0, 0.0),
             raw_mouse_delta: (0.0, 0.0),
+            smoothed_mouse_delta: (0.0, 0.0),
             mouse_buttons: HashSet::new(),
             mouse_clicks: HashSet::new(),
             scroll_delta: 0.0,
@@ -195,6 +320,7 @@ impl Default for InputState {
             focused: true,
             wants_cursor_capture: false,
             context: InputContext::default(),
+            gamepad: GamepadState::default(),
         }
     }
 }
@@ -233,6 +359,31 @@ impl InputState {
         self.keys_pressed.contains(&key)
     }
 
+    /// Gamepad left stick as `(strafe, forward)`, each in `-1.0..=1.0`.
+    ///
+    /// Positive strafe is right, positive forward is ahead. Keyboard movement
+    /// goes through the game's rebindable input bindings instead.
+    pub fn gamepad_movement(&self) -> (f32, f32) {
+        let [x, y] = self.gamepad.left_stick();
+        (x, y)
+    }
+
+    /// Store the latest gamepad state.
+    pub fn apply_gamepad(&mut self, gamepad: &GamepadState) {
+        self.gamepad = gamepad.clone();
+    }
+
+    /// Right-stick look as a mouse-equivalent delta for this frame.
+    ///
+    /// Pushing the stick up looks up, matching a mouse moved away from the player.
+    pub fn gamepad_look_delta(&self) -> (f64, f64) {
+        let [x, y] = self.gamepad.right_stick();
+        (
+            f64::from(x) * GAMEPAD_LOOK_PIXELS,
+            -f64::from(y) * GAMEPAD_LOOK_PIXELS,
+        )
+    }
+
     /// Check if a mouse button is currently pressed.
     pub fn is_mouse_pressed(&self, button: winit::event::MouseButton) -> bool {
         self.mouse_buttons.contains(&button)
@@ -243,6 +394,23 @@ impl InputState {
         self.mouse_clicks.contains(&button)
     }
 
+    /// Mouse delta with smoothing, acceleration and sensitivity applied.
+    ///
+    /// Folds this frame's delta into an exponential moving average weighted
+    /// by `smoothing`, then scales by `1.0 + acceleration * speed` where speed
+    /// is the smoothed magnitude. Call once per frame; each call advances the
+    /// average. The raw `mouse_delta` is left untouched.
+    pub fn filtered_mouse_delta(&mut self, filter: &MouseFilter) -> (f64, f64) {
+        let smoothing = f64::from(filter.smoothing.clamp(0.0, 0.99));
+        let x = self.mouse_delta.0 * (1.0 - smoothing) + self.smoothed_mouse_delta.0 * smoothing;
+        let y = self.mouse_delta.1 * (1.0 - smoothing) + self.smoothed_mouse_delta.1 * smoothing;
+        self.smoothed_mouse_delta = (x, y);
+
+        let speed = x.hypot(y);
+        let gain = f64::from(filter.sensitivity) * (1.0 + f64::from(filter.acceleration) * speed);
+        (x * gain, y * gain)
+    }
+
     /// Reset per-frame state (like mouse delta and clicks).
     pub fn reset_frame(&mut self) {
         self.mouse_delta = (0.0, 0.0);
@@ -479,4 +647,101 @@ mod tests {
         assert!(state.keys_just_pressed.is_empty());
         assert!(state.mouse_clicks.is_empty());
     }
+
+    #[test]
+    fn radial_deadzone_keeps_diagonal_direction() {
+        // Both axes (0.1 < 0.15) and the magnitude (~0.141) are inside the
+        // deadzone, so the stick reads as centred.
+        assert_eq!(apply_radial_deadzone([0.1, 0.1], 0.15), [0.0, 0.0]);
+
+        // Each axis is inside (0.12 < 0.15) but the magnitude (~0.170) is not:
+        // a per-axis deadzone would drop this push, the radial one keeps it.
+        let [x, y] = apply_radial_deadzone([0.12, 0.12], 0.15);
+        assert!(x > 0.0);
+        assert!((x - y).abs() < 1e-6);
+
+        let [x, y] = apply_radial_deadzone([0.5, 0.5], 0.15);
+        assert!(x > 0.0);
+        assert!((x - y).abs() < 1e-6);
+
+        let full = apply_radial_deadzone([1.0, 0.0], 0.15);
+        assert!((full[0] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gamepad_sticks_drive_movement_and_look() {
+        let mut gamepad = GamepadState::default();
+        gamepad.set_left_stick([0.0, 1.0]);
+        gamepad.set_right_stick([1.0, 0.0]);
+        gamepad.buttons.insert(GamepadButton::South);
+
+        let mut state = InputState::new();
+        state.apply_gamepad(&gamepad);
+        assert!(state.gamepad.is_pressed(GamepadButton::South));
+
+        let (strafe, forward) = state.gamepad_movement();
+        assert_eq!(strafe, 0.0);
+        assert!((forward - 1.0).abs() < 1e-6);
+        assert_eq!(state.gamepad_look_delta(), (GAMEPAD_LOOK_PIXELS, 0.0));
+    }
+
+    #[test]
+    fn filtered_mouse_delta_is_zero_without_motion() {
+        let mut state = InputState::new();
+        let filter = MouseFilter {
+            sensitivity: 2.0,
+            acceleration: 0.5,
+            smoothing: 0.5,
+        };
+        assert_eq!(state.filtered_mouse_delta(&filter), (0.0, 0.0));
+    }
+
+    #[test]
+    fn filtered_mouse_delta_accelerates_fast_motion() {
+        let mut state = InputState::new();
+        let filter = MouseFilter {
+            sensitivity: 1.0,
+            acceleration: 0.1,
+            smoothing: 0.0,
+        };
+
+        state.mouse_delta = (1.0, 0.0);
+        let slow = state.filtered_mouse_delta(&filter);
+        assert!((slow.0 - 1.1).abs() < 1e-4);
+
+        // 3-4-5 triangle: speed 50 scales by 1 + 0.1 * 50 = 6.
+        state.mouse_delta = (30.0, -40.0);
+        let fast = state.filtered_mouse_delta(&filter);
+        assert!((fast.0 - 180.0).abs() < 1e-4);
+        assert!((fast.1 + 240.0).abs() < 1e-4);
+        assert_eq!(state.mouse_delta, (30.0, -40.0));
+    }
+
+    #[test]
+    fn filtered_mouse_delta_decays_after_motion_stops() {
+        let mut state = InputState::new();
+        let filter = MouseFilter {
+            smoothing: 0.5,
+            ..MouseFilter::default()
+        };
+
+        state.mouse_delta = (8.0, 4.0);
+        assert_eq!(state.filtered_mouse_delta(&filter), (4.0, 2.0));
+
+        // Each still frame halves what is left rather than dropping to zero.
+        state.reset_frame();
+        assert_eq!(state.filtered_mouse_delta(&filter), (2.0, 1.0));
+
+        state.reset_frame();
+        assert_eq!(state.filtered_mouse_delta(&filter), (1.0, 0.5));
+
+        // Steady motion converges on the raw delta.
+        for _ in 0..32 {
+            state.reset_frame();
+            state.mouse_delta = (8.0, 4.0);
+            state.filtered_mouse_delta(&filter);
+        }
+        let (x, y) = state.filtered_mouse_delta(&filter);
+        assert!((x - 8.0).abs() < 1e-6 && (y - 4.0).abs() < 1e-6);
+    }
 }
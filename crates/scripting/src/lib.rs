@@ -2,30 +2,280 @@
 //! Placeholder scripting hooks (WASM-based API TBD).
 
 use anyhow::Result;
+use glam::{IVec3, Vec3};
 use mdminecraft_core::SimTick;
 
+/// Block identifier as stored in chunks.
+pub type ScriptBlockId = u16;
+
+/// World operations a script may perform.
+///
+/// This is the whole surface a WASM host will expose; the simulation
+/// implements it over its own chunk and event storage.
+pub trait ScriptWorld {
+    /// Block at `pos`, or `None` if the chunk is not loaded.
+    fn get_block(&self, pos: IVec3) -> Option<ScriptBlockId>;
+
+    /// Replace the block at `pos`; returns false if the chunk is not loaded.
+    fn set_block(&mut self, pos: IVec3, id: ScriptBlockId) -> bool;
+
+    /// Spawn a cosmetic particle of `kind` at `position`.
+    fn spawn_particle(&mut self, kind: &str, position: Vec3);
+
+    /// Emit a named event with an opaque payload for other systems to consume.
+    fn emit_event(&mut self, kind: &str, payload: &str);
+}
+
 /// A script context invoked each tick.
+///
+/// Event hooks default to no-ops so scripts only implement what they react to.
 pub trait ScriptContext {
     /// Called once per tick with the deterministic simulation tick.
-    fn on_tick(&mut self, tick: SimTick) -> Result<()>;
+    fn on_tick(&mut self, tick: SimTick, world: &mut dyn ScriptWorld) -> Result<()>;
+
+    /// Called after the block `block_id` at `pos` was broken.
+    fn on_block_break(
+        &mut self,
+        _pos: IVec3,
+        _block_id: ScriptBlockId,
+        _tick: SimTick,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after the block `block_id` was placed at `pos`.
+    fn on_block_place(
+        &mut self,
+        _pos: IVec3,
+        _block_id: ScriptBlockId,
+        _tick: SimTick,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after player `player_id` took `amount` damage.
+    fn on_player_damage(&mut self, _player_id: u64, _amount: f32, _tick: SimTick) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// No-op script used until a proper WASM host lands.
 pub struct NoopScript;
 
 impl ScriptContext for NoopScript {
-    fn on_tick(&mut self, _tick: SimTick) -> Result<()> {
+    fn on_tick(&mut self, _tick: SimTick, _world: &mut dyn ScriptWorld) -> Result<()> {
         Ok(())
     }
 }
 
+/// Fans ticks and events out to every registered script in order.
+///
+/// Dispatch stops at the first script that returns an error; later scripts
+/// do not see that call.
+#[derive(Default)]
+pub struct ScriptDispatcher {
+    scripts: Vec<Box<dyn ScriptContext>>,
+}
+
+impl ScriptDispatcher {
+    /// Create a dispatcher with no scripts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a script after those already added.
+    pub fn add(&mut self, script: Box<dyn ScriptContext>) {
+        self.scripts.push(script);
+    }
+
+    /// Number of registered scripts.
+    pub fn len(&self) -> usize {
+        self.scripts.len()
+    }
+
+    /// Returns true if no scripts are registered.
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    /// Run [`ScriptContext::on_tick`] on every script.
+    pub fn on_tick(&mut self, tick: SimTick, world: &mut dyn ScriptWorld) -> Result<()> {
+        self.scripts
+            .iter_mut()
+            .try_for_each(|script| script.on_tick(tick, world))
+    }
+
+    /// Run [`ScriptContext::on_block_break`] on every script.
+    pub fn on_block_break(
+        &mut self,
+        pos: IVec3,
+        block_id: ScriptBlockId,
+        tick: SimTick,
+    ) -> Result<()> {
+        self.scripts
+            .iter_mut()
+            .try_for_each(|script| script.on_block_break(pos, block_id, tick))
+    }
+
+    /// Run [`ScriptContext::on_block_place`] on every script.
+    pub fn on_block_place(
+        &mut self,
+        pos: IVec3,
+        block_id: ScriptBlockId,
+        tick: SimTick,
+    ) -> Result<()> {
+        self.scripts
+            .iter_mut()
+            .try_for_each(|script| script.on_block_place(pos, block_id, tick))
+    }
+
+    /// Run [`ScriptContext::on_player_damage`] on every script.
+    pub fn on_player_damage(&mut self, player_id: u64, amount: f32, tick: SimTick) -> Result<()> {
+        self.scripts
+            .iter_mut()
+            .try_for_each(|script| script.on_player_damage(player_id, amount, tick))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    /// In-memory world that records every mutation.
+    #[derive(Default)]
+    struct RecordingWorld {
+        blocks: HashMap<IVec3, ScriptBlockId>,
+        set_calls: Vec<(IVec3, ScriptBlockId)>,
+        particles: Vec<(String, Vec3)>,
+        events: Vec<(String, String)>,
+    }
+
+    impl ScriptWorld for RecordingWorld {
+        fn get_block(&self, pos: IVec3) -> Option<ScriptBlockId> {
+            self.blocks.get(&pos).copied()
+        }
+
+        fn set_block(&mut self, pos: IVec3, id: ScriptBlockId) -> bool {
+            self.set_calls.push((pos, id));
+            self.blocks.insert(pos, id);
+            true
+        }
+
+        fn spawn_particle(&mut self, kind: &str, position: Vec3) {
+            self.particles.push((kind.to_string(), position));
+        }
+
+        fn emit_event(&mut self, kind: &str, payload: &str) {
+            self.events.push((kind.to_string(), payload.to_string()));
+        }
+    }
+
+    /// Turns the block under a fixed position to stone every tenth tick.
+    struct Petrify {
+        pos: IVec3,
+    }
+
+    impl ScriptContext for Petrify {
+        fn on_tick(&mut self, tick: SimTick, world: &mut dyn ScriptWorld) -> Result<()> {
+            if !tick.0.is_multiple_of(10) || world.get_block(self.pos) == Some(1) {
+                return Ok(());
+            }
+            world.set_block(self.pos, 1);
+            world.spawn_particle("smoke", self.pos.as_vec3());
+            world.emit_event("petrified", &format!("{:?}", self.pos.to_array()));
+            Ok(())
+        }
+    }
 
     #[test]
     fn noop_script_on_tick_is_ok() {
         let mut script = NoopScript;
-        assert!(script.on_tick(SimTick(1)).is_ok());
+        let mut world = RecordingWorld::default();
+        assert!(script.on_tick(SimTick(1), &mut world).is_ok());
+        assert!(world.set_calls.is_empty());
+    }
+
+    #[test]
+    fn script_set_block_is_recorded() {
+        let pos = IVec3::new(4, 64, -2);
+        let mut world = RecordingWorld::default();
+        world.blocks.insert(pos, 2);
+        let mut script = Petrify { pos };
+
+        for tick in 0..25 {
+            script.on_tick(SimTick(tick), &mut world).unwrap();
+        }
+
+        assert_eq!(world.set_calls, vec![(pos, 1)]);
+        assert_eq!(world.get_block(pos), Some(1));
+        assert_eq!(world.particles.len(), 1);
+        assert_eq!(
+            world.events,
+            vec![("petrified".to_string(), "[4, 64, -2]".to_string())]
+        );
+    }
+
+    /// Logs block breaks into a shared list; optionally fails every call.
+    struct BreakLogger {
+        log: Rc<RefCell<Vec<(IVec3, ScriptBlockId, SimTick)>>>,
+        fail: bool,
+    }
+
+    impl ScriptContext for BreakLogger {
+        fn on_tick(&mut self, _tick: SimTick, _world: &mut dyn ScriptWorld) -> Result<()> {
+            Ok(())
+        }
+
+        fn on_block_break(
+            &mut self,
+            pos: IVec3,
+            block_id: ScriptBlockId,
+            tick: SimTick,
+        ) -> Result<()> {
+            self.log.borrow_mut().push((pos, block_id, tick));
+            if self.fail {
+                anyhow::bail!("script rejected break");
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn dispatcher_delivers_block_break_events() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = ScriptDispatcher::new();
+        dispatcher.add(Box::new(NoopScript));
+        dispatcher.add(Box::new(BreakLogger {
+            log: Rc::clone(&log),
+            fail: false,
+        }));
+
+        let pos = IVec3::new(-7, 12, 300);
+        dispatcher.on_block_break(pos, 3, SimTick(42)).unwrap();
+        dispatcher.on_block_place(pos, 3, SimTick(43)).unwrap();
+        dispatcher.on_player_damage(1, 2.5, SimTick(44)).unwrap();
+
+        assert_eq!(*log.borrow(), vec![(pos, 3, SimTick(42))]);
+    }
+
+    #[test]
+    fn dispatcher_stops_at_first_error() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = ScriptDispatcher::new();
+        for fail in [true, false] {
+            dispatcher.add(Box::new(BreakLogger {
+                log: Rc::clone(&log),
+                fail,
+            }));
+        }
+        assert_eq!(dispatcher.len(), 2);
+
+        assert!(dispatcher
+            .on_block_break(IVec3::ZERO, 1, SimTick(1))
+            .is_err());
+        assert_eq!(log.borrow().len(), 1);
     }
 }
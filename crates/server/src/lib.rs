@@ -2,18 +2,29 @@
 //! Authoritative simulation host scaffolding.
 
 pub mod multiplayer;
+pub mod snapshot;
+
+pub use snapshot::{SimEntity, WorldSnapshot};
 
 use anyhow::Result;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::With;
 use bevy_ecs::schedule::Schedules;
 use bevy_ecs::world::World;
 use mdminecraft_core::SimTick;
 use mdminecraft_ecs::{build_default_schedule, run_tick};
+use std::time::Duration;
+
+/// Most ticks [`Server::run_for`] runs in one call; older backlog is dropped
+/// so a stalled host can't fall into a catch-up spiral.
+pub const MAX_CATCH_UP_TICKS: u32 = 5;
 
 /// Minimal server harness that will be expanded with networking and persistence.
 pub struct Server {
     world: World,
     schedules: Schedules,
     current_tick: SimTick,
+    accumulator: Duration,
 }
 
 impl Server {
@@ -25,6 +36,7 @@ impl Server {
             world,
             schedules,
             current_tick: SimTick::ZERO,
+            accumulator: Duration::ZERO,
         }
     }
 
@@ -34,6 +46,84 @@ impl Server {
         self.current_tick = self.current_tick.advance(1);
         Ok(())
     }
+
+    /// Advance the simulation by `elapsed` wall-clock time.
+    ///
+    /// Runs one tick per [`SimTick::DURATION`] accumulated, at most
+    /// [`MAX_CATCH_UP_TICKS`] per call, and returns how many ticks ran.
+    /// Time left over (less than one tick) carries into the next call.
+    pub fn run_for(&mut self, elapsed: Duration) -> Result<u32> {
+        self.accumulator += elapsed;
+
+        let mut ran = 0;
+        while self.accumulator >= SimTick::DURATION && ran < MAX_CATCH_UP_TICKS {
+            self.tick()?;
+            self.accumulator -= SimTick::DURATION;
+            ran += 1;
+        }
+
+        if self.accumulator >= SimTick::DURATION {
+            tracing::warn!(
+                behind = ?self.accumulator,
+                "server fell behind; dropping simulation backlog"
+            );
+            self.accumulator = Duration::from_nanos(
+                (self.accumulator.as_nanos() % SimTick::DURATION.as_nanos()) as u64,
+            );
+        }
+
+        Ok(ran)
+    }
+
+    /// Tick the next call to [`Server::tick`] will simulate.
+    pub fn current_tick(&self) -> SimTick {
+        self.current_tick
+    }
+
+    /// Simulation world, for spawning entities and inserting resources.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Schedules run each tick, for registering systems.
+    pub fn schedules_mut(&mut self) -> &mut Schedules {
+        &mut self.schedules
+    }
+
+    /// Capture every [`SimEntity`] and the current tick.
+    pub fn snapshot(&self) -> WorldSnapshot {
+        let entities = self
+            .world
+            .iter_entities()
+            .filter_map(|entity| entity.get::<SimEntity>())
+            .map(|sim| (sim.id, sim.components.clone()))
+            .collect();
+        WorldSnapshot {
+            tick: self.current_tick,
+            entities,
+        }
+    }
+
+    /// Replace all [`SimEntity`] state and the current tick with `snapshot`.
+    ///
+    /// Entities are respawned in id order so restored worlds iterate the same
+    /// way regardless of how the snapshot was produced.
+    pub fn restore(&mut self, snapshot: WorldSnapshot) {
+        let existing: Vec<Entity> = self
+            .world
+            .query_filtered::<Entity, With<SimEntity>>()
+            .iter(&self.world)
+            .collect();
+        for entity in existing {
+            self.world.despawn(entity);
+        }
+
+        for (id, components) in snapshot.entities {
+            self.world.spawn(SimEntity { id, components });
+        }
+        self.current_tick = snapshot.tick;
+        self.accumulator = Duration::ZERO;
+    }
 }
 
 impl Default for Server {
@@ -45,6 +135,41 @@ impl Default for Server {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy_ecs::system::Query;
+    use mdminecraft_core::{ComponentMap, ComponentValue, RegistryKey};
+    use mdminecraft_ecs::{add_system_to_stage, SimSchedule};
+
+    fn counter_key() -> RegistryKey {
+        RegistryKey::parse("test:counter").unwrap()
+    }
+
+    /// Server with three entities whose counters evolve each tick.
+    fn seeded_server() -> Server {
+        let mut server = Server::new();
+        for id in 0..3 {
+            let mut components = ComponentMap::new();
+            components.insert(counter_key(), ComponentValue::U64(id));
+            server.world_mut().spawn(SimEntity { id, components });
+        }
+        add_system_to_stage(
+            server.schedules_mut(),
+            SimSchedule,
+            |mut entities: Query<&mut SimEntity>| {
+                for mut sim in &mut entities {
+                    let Some(ComponentValue::U64(value)) = sim.components.get(&counter_key())
+                    else {
+                        continue;
+                    };
+                    let next = value
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(sim.id + 1);
+                    sim.components
+                        .insert(counter_key(), ComponentValue::U64(next));
+                }
+            },
+        );
+        server
+    }
 
     #[test]
     fn tick_advances_sim_tick() {
@@ -53,4 +178,58 @@ mod tests {
         server.tick().expect("tick succeeds");
         assert_eq!(server.current_tick, SimTick::ZERO.advance(1));
     }
+
+    #[test]
+    fn run_for_accumulates_partial_ticks() {
+        let mut server = Server::new();
+        assert_eq!(server.run_for(Duration::from_millis(30)).unwrap(), 0);
+        assert_eq!(server.current_tick(), SimTick::ZERO);
+
+        // The carried 30ms plus 20ms completes a tick.
+        assert_eq!(server.run_for(Duration::from_millis(20)).unwrap(), 1);
+        assert_eq!(server.current_tick(), SimTick(1));
+
+        assert_eq!(server.run_for(SimTick::DURATION).unwrap(), 1);
+        assert_eq!(server.current_tick(), SimTick(2));
+    }
+
+    #[test]
+    fn run_for_clamps_catch_up() {
+        let mut server = Server::new();
+        let ran = server
+            .run_for(Duration::from_secs(2) + Duration::from_millis(10))
+            .unwrap();
+        assert_eq!(ran, MAX_CATCH_UP_TICKS);
+        assert_eq!(server.current_tick(), SimTick(MAX_CATCH_UP_TICKS as u64));
+
+        // The backlog was dropped; only the sub-tick remainder carries over.
+        assert_eq!(server.run_for(Duration::from_millis(39)).unwrap(), 0);
+        assert_eq!(server.run_for(Duration::from_millis(1)).unwrap(), 1);
+    }
+
+    #[test]
+    fn restored_snapshot_continues_deterministically() {
+        let mut uninterrupted = seeded_server();
+        for _ in 0..200 {
+            uninterrupted.tick().unwrap();
+        }
+
+        let mut first = seeded_server();
+        for _ in 0..100 {
+            first.tick().unwrap();
+        }
+        let midpoint = first.snapshot();
+        assert_eq!(midpoint.tick, SimTick(100));
+        assert_eq!(midpoint.entities.len(), 3);
+
+        let mut resumed = seeded_server();
+        resumed.restore(midpoint.clone());
+        assert_eq!(resumed.snapshot(), midpoint);
+        for _ in 0..100 {
+            resumed.tick().unwrap();
+        }
+
+        assert_eq!(resumed.snapshot(), uninterrupted.snapshot());
+        assert_ne!(resumed.snapshot().entities, midpoint.entities);
+    }
 }
@@ -0,0 +1,29 @@
+//! Deterministic simulation snapshots.
+//!
+//! Only state attached through [`SimEntity`] is captured; everything else in
+//! the bevy `World` is treated as derived and rebuilt by systems.
+
+use bevy_ecs::component::Component;
+use mdminecraft_core::{ComponentMap, SimTick};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Persistent simulation state for one entity.
+///
+/// `id` must be unique and stable across save/restore; bevy entity ids are not.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SimEntity {
+    /// Stable identifier used as the snapshot key.
+    pub id: u64,
+    /// Deterministic component data.
+    pub components: ComponentMap,
+}
+
+/// Serializable capture of a [`crate::Server`] between ticks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    /// Tick the server will simulate next.
+    pub tick: SimTick,
+    /// Component data keyed by [`SimEntity::id`].
+    pub entities: BTreeMap<u64, ComponentMap>,
+}
@@ -5,11 +5,12 @@ mod metrics;
 mod micro_worldtest;
 mod snapshot;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use mdminecraft_core::SimTick;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::{self, File};
-use std::io::Write;
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
 use std::path::Path;
 
 pub use metrics::*;
@@ -27,29 +28,113 @@ pub struct EventRecord<'a> {
     pub payload: &'a str,
 }
 
+/// Owned counterpart of [`EventRecord`], as read back by [`JsonlReader`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedEventRecord {
+    /// Simulation tick when the event occurred.
+    pub tick: SimTick,
+    /// Human-readable kind label.
+    pub kind: String,
+    /// Free-form payload for smoke tests.
+    pub payload: String,
+}
+
+impl OwnedEventRecord {
+    /// Borrow as an [`EventRecord`] for writing.
+    pub fn as_record(&self) -> EventRecord<'_> {
+        EventRecord {
+            tick: self.tick,
+            kind: &self.kind,
+            payload: &self.payload,
+        }
+    }
+}
+
+/// Reads the newline-delimited JSON written by [`JsonlSink`].
+///
+/// Blank lines are skipped; malformed lines yield an error naming their
+/// 1-based line number and iteration can continue past them.
+pub struct JsonlReader {
+    lines: Lines<BufReader<File>>,
+    line: usize,
+}
+
+impl JsonlReader {
+    /// Open the event log at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        Ok(Self {
+            lines: BufReader::new(file).lines(),
+            line: 0,
+        })
+    }
+}
+
+impl Iterator for JsonlReader {
+    type Item = Result<OwnedEventRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.lines.next()?;
+            self.line += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    return Some(Err(err).with_context(|| format!("line {}", self.line)));
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(
+                serde_json::from_str(&line)
+                    .with_context(|| format!("line {}: invalid event record", self.line)),
+            );
+        }
+    }
+}
+
 /// A sink that writes newline-delimited JSON to disk.
+///
+/// Writes are buffered; call [`JsonlSink::flush`] before reading the file
+/// back. Dropping the sink flushes on a best-effort basis.
 pub struct JsonlSink {
-    file: File,
+    file: BufWriter<File>,
 }
 
 impl JsonlSink {
     /// Create a new sink at `path`.
     pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let file = File::create(path)?;
+        let file = BufWriter::new(File::create(path)?);
         Ok(Self { file })
     }
 
     /// Append an event to the log.
     pub fn write(&mut self, event: &EventRecord<'_>) -> Result<()> {
-        let line = serde_json::to_string(event)?;
-        self.file.write_all(line.as_bytes())?;
+        serde_json::to_writer(&mut self.file, event)?;
         self.file.write_all(b"\n")?;
         Ok(())
     }
+
+    /// Write any buffered events through to disk.
+    pub fn flush(&mut self) -> Result<()> {
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for JsonlSink {
+    fn drop(&mut self) {
+        if let Err(err) = self.file.flush() {
+            tracing::warn!(%err, "failed to flush event log");
+        }
+    }
 }
 
 /// Mesh metric snapshot for a chunk.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChunkMeshMetric {
     /// Chunk coordinates [x, z].
     pub chunk: [i32; 2],
@@ -83,11 +168,230 @@ impl MeshMetricSink {
     }
 }
 
+/// One difference between a baseline and current set of mesh metrics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricDiff {
+    /// Triangle count moved by more than the allowed tolerance.
+    Triangles {
+        /// Chunk coordinates [x, z].
+        chunk: [i32; 2],
+        /// Count recorded in the baseline.
+        baseline: usize,
+        /// Count measured now.
+        current: usize,
+    },
+    /// Mesh hash differs from the baseline.
+    Hash {
+        /// Chunk coordinates [x, z].
+        chunk: [i32; 2],
+        /// Hash recorded in the baseline.
+        baseline: String,
+        /// Hash measured now.
+        current: String,
+    },
+    /// Chunk is in the baseline but was not meshed now.
+    Missing {
+        /// Chunk coordinates [x, z].
+        chunk: [i32; 2],
+    },
+    /// Chunk was meshed now but is not in the baseline.
+    Added {
+        /// Chunk coordinates [x, z].
+        chunk: [i32; 2],
+    },
+}
+
+/// Compare `current` metrics against the baseline JSON written by
+/// [`MeshMetricSink`], requiring exact triangle counts.
+pub fn compare_metrics(baseline: &Path, current: &[ChunkMeshMetric]) -> Result<Vec<MetricDiff>> {
+    compare_metrics_with_tolerance(baseline, current, 0)
+}
+
+/// Compare `current` metrics against a baseline, ignoring triangle count
+/// changes of at most `triangle_tolerance`.
+///
+/// Diffs are ordered by chunk coordinate. Hash changes are always reported;
+/// callers decide which kinds of diff fail a run.
+pub fn compare_metrics_with_tolerance(
+    baseline: &Path,
+    current: &[ChunkMeshMetric],
+    triangle_tolerance: usize,
+) -> Result<Vec<MetricDiff>> {
+    let data = fs::read_to_string(baseline)
+        .with_context(|| format!("failed to read baseline {}", baseline.display()))?;
+    let baseline: Vec<ChunkMeshMetric> = serde_json::from_str(&data)
+        .with_context(|| format!("failed to parse baseline {}", baseline.display()))?;
+
+    let baseline: BTreeMap<_, _> = baseline.iter().map(|m| (m.chunk, m)).collect();
+    let current: BTreeMap<_, _> = current.iter().map(|m| (m.chunk, m)).collect();
+
+    let mut diffs = Vec::new();
+    for (&chunk, expected) in &baseline {
+        let Some(actual) = current.get(&chunk) else {
+            diffs.push(MetricDiff::Missing { chunk });
+            continue;
+        };
+        if expected.triangles.abs_diff(actual.triangles) > triangle_tolerance {
+            diffs.push(MetricDiff::Triangles {
+                chunk,
+                baseline: expected.triangles,
+                current: actual.triangles,
+            });
+        }
+        if expected.hash != actual.hash {
+            diffs.push(MetricDiff::Hash {
+                chunk,
+                baseline: expected.hash.clone(),
+                current: actual.hash.clone(),
+            });
+        }
+    }
+    diffs.extend(
+        current
+            .keys()
+            .filter(|chunk| !baseline.contains_key(*chunk))
+            .map(|&chunk| MetricDiff::Added { chunk }),
+    );
+    Ok(diffs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    #[test]
+    fn jsonl_sink_flush_persists_every_event() {
+        let path = std::env::temp_dir().join(format!(
+            "events-{}.jsonl",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let mut sink = JsonlSink::create(&path).expect("sink create");
+        for tick in 0..10_000 {
+            sink.write(&EventRecord {
+                tick: SimTick(tick),
+                kind: "tick",
+                payload: "ok",
+            })
+            .expect("write succeeds");
+        }
+        sink.flush().expect("flush succeeds");
+
+        let contents = fs::read_to_string(&path).expect("file readable");
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 10_000);
+        assert!(lines[9_999].contains("9999"));
+        drop(sink);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn jsonl_reader_round_trips_sink_output() {
+        let path = std::env::temp_dir().join(format!(
+            "events-roundtrip-{}.jsonl",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let events = vec![
+            OwnedEventRecord {
+                tick: SimTick(0),
+                kind: "start".into(),
+                payload: "seed=42".into(),
+            },
+            OwnedEventRecord {
+                tick: SimTick(20),
+                kind: "spawn".into(),
+                payload: "{\"mob\":\"cow\"}".into(),
+            },
+            OwnedEventRecord {
+                tick: SimTick(40),
+                kind: "end".into(),
+                payload: String::new(),
+            },
+        ];
+        let mut sink = JsonlSink::create(&path).expect("sink create");
+        for event in &events {
+            sink.write(&event.as_record()).expect("write succeeds");
+        }
+        drop(sink);
+
+        let read: Vec<OwnedEventRecord> = JsonlReader::open(&path)
+            .expect("reader open")
+            .collect::<Result<_>>()
+            .expect("all lines parse");
+        assert_eq!(read, events);
+
+        // Blank lines are skipped and bad lines report their position.
+        fs::write(
+            &path,
+            "\n{\"tick\":1,\"kind\":\"a\",\"payload\":\"\"}\n\nnot json\n",
+        )
+        .expect("rewrite log");
+        let mut reader = JsonlReader::open(&path).expect("reader open");
+        assert_eq!(reader.next().unwrap().unwrap().tick, SimTick(1));
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("line 4"), "{err}");
+        assert!(reader.next().is_none());
+        let _ = fs::remove_file(&path);
+    }
+
+    fn write_baseline(label: &str, metrics: &[ChunkMeshMetric]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "mesh-baseline-{label}-{}.json",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        MeshMetricSink::create(&path)
+            .expect("sink create")
+            .write(metrics)
+            .expect("write succeeds");
+        path
+    }
+
+    fn metric(chunk: [i32; 2], triangles: usize, hash: &str) -> ChunkMeshMetric {
+        ChunkMeshMetric {
+            chunk,
+            triangles,
+            hash: hash.into(),
+        }
+    }
+
+    #[test]
+    fn compare_metrics_identical_has_no_diffs() {
+        let metrics = vec![metric([0, 0], 12, "aa"), metric([1, -1], 40, "bb")];
+        let path = write_baseline("same", &metrics);
+
+        assert!(compare_metrics(&path, &metrics).unwrap().is_empty());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn compare_metrics_reports_changed_hash() {
+        let baseline = vec![metric([0, 0], 12, "aa"), metric([1, -1], 40, "bb")];
+        let path = write_baseline("hash", &baseline);
+        let current = vec![metric([0, 0], 12, "aa"), metric([1, -1], 42, "cc")];
+
+        // Within tolerance only the hash change is reported.
+        let diffs = compare_metrics_with_tolerance(&path, &current, 2).unwrap();
+        assert_eq!(
+            diffs,
+            vec![MetricDiff::Hash {
+                chunk: [1, -1],
+                baseline: "bb".into(),
+                current: "cc".into(),
+            }]
+        );
+        assert_eq!(compare_metrics(&path, &current).unwrap().len(), 2);
+        let _ = fs::remove_file(&path);
+    }
+
     #[test]
     fn mesh_metric_sink_writes_file() {
         let path = std::env::temp_dir().join(format!(
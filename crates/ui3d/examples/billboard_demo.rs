@@ -13,7 +13,7 @@ async fn run() {
     use glam::Mat4;
     use mdminecraft_render::CameraUniform;
     use mdminecraft_ui3d::render::{
-        BillboardEmitter, BillboardFlags, BillboardInstance, BillboardRenderer,
+        BillboardEmitter, BillboardFlags, BillboardInstance, BillboardRenderer, BillboardView,
     };
 
     let instance = wgpu::Instance::default();
@@ -149,7 +149,7 @@ async fn run() {
         sample_count: 1,
         dimension: wgpu::TextureDimension::D2,
         format: wgpu::TextureFormat::Depth32Float,
-        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
         view_formats: &[],
     });
 
@@ -204,6 +204,14 @@ async fn run() {
             &color_view,
             &depth_view,
             &camera_bg,
+            &BillboardView {
+                position: [0.0, 0.0, 4.0],
+                forward: [0.0, 0.0, -1.0],
+                fov_y: std::f32::consts::FRAC_PI_2,
+                viewport_height: 512.0,
+                near: 0.1,
+                far: 100.0,
+            },
             &mut emitter,
         )
         .expect("render");
@@ -2,6 +2,7 @@
 
 use super::{Transform3D, UIComponent};
 use glam::Vec3;
+use mdminecraft_render::Camera;
 
 /// A billboard is a quad that always faces the camera
 ///
@@ -14,9 +15,12 @@ pub struct Billboard {
     /// World transform
     pub transform: Transform3D,
 
-    /// Size of the billboard (width, height) in world units
+    /// Size of the billboard (width, height), interpreted per `size_mode`
     pub size: (f32, f32),
 
+    /// Whether `size` is in world units or screen pixels
+    pub size_mode: SizeMode,
+
     /// Color tint (RGBA)
     pub color: [f32; 4],
 
@@ -44,6 +48,17 @@ pub enum BillboardOrientation {
     Fixed,
 }
 
+/// Units of a billboard's `size`
+///
+/// Resolved to world units by [`Billboard::world_size`] each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeMode {
+    /// Size is in world units - shrinks with distance like any other geometry
+    WorldSpace,
+    /// Size is in viewport pixels - stays the same on screen regardless of distance
+    ScreenSpace,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DepthMode {
     /// Normal depth testing (billboard can be occluded)
@@ -57,6 +72,7 @@ impl Default for Billboard {
         Self {
             transform: Transform3D::default(),
             size: (1.0, 1.0),
+            size_mode: SizeMode::WorldSpace,
             color: [1.0, 1.0, 1.0, 1.0],
             texture_coords: None,
             orientation: BillboardOrientation::Full,
@@ -81,6 +97,12 @@ impl Billboard {
         self
     }
 
+    /// Builder: Set size mode
+    pub fn with_size_mode(mut self, mode: SizeMode) -> Self {
+        self.size_mode = mode;
+        self
+    }
+
     /// Builder: Set color
     pub fn with_color(mut self, color: [f32; 4]) -> Self {
         self.color = color;
@@ -105,6 +127,20 @@ impl Billboard {
         self
     }
 
+    /// Builder: Set depth testing (false renders on top of the scene)
+    pub fn with_depth_test(self, depth_test: bool) -> Self {
+        self.with_depth_mode(if depth_test {
+            DepthMode::Normal
+        } else {
+            DepthMode::AlwaysOnTop
+        })
+    }
+
+    /// Whether scene geometry occludes this billboard
+    pub fn depth_test(&self) -> bool {
+        self.depth_mode == DepthMode::Normal
+    }
+
     /// Get the width of the billboard
     pub fn width(&self) -> f32 {
         self.size.0 * self.transform.scale.x
@@ -115,6 +151,27 @@ impl Billboard {
         self.size.1 * self.transform.scale.y
     }
 
+    /// Resolve the billboard's (width, height) in world units for this camera
+    ///
+    /// Screen-space billboards are scaled by their view depth so they project
+    /// to `size` pixels on a viewport `viewport_height` pixels tall.
+    pub fn world_size(&self, camera: &Camera, viewport_height: f32) -> (f32, f32) {
+        match self.size_mode {
+            SizeMode::WorldSpace => (self.width(), self.height()),
+            SizeMode::ScreenSpace => {
+                let depth = (self.transform.position - camera.position)
+                    .dot(camera.forward())
+                    .max(camera.near);
+                let world_per_pixel =
+                    2.0 * depth * (camera.fov * 0.5).tan() / viewport_height.max(1.0);
+                (
+                    self.width() * world_per_pixel,
+                    self.height() * world_per_pixel,
+                )
+            }
+        }
+    }
+
     /// Calculate the billboard's orientation matrix relative to camera
     pub fn calculate_orientation(&self, camera_position: Vec3, camera_up: Vec3) -> glam::Mat4 {
         match self.orientation {
@@ -204,6 +261,34 @@ mod tests {
         assert_eq!(billboard.depth_mode, DepthMode::AlwaysOnTop);
     }
 
+    #[test]
+    fn screen_space_size_compensates_for_distance() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        camera.position = Vec3::ZERO;
+        let viewport_height = 720.0;
+
+        let projected_height = |distance: f32, mode: SizeMode| {
+            let billboard = Billboard::new(camera.forward() * distance)
+                .with_size(64.0, 32.0)
+                .with_size_mode(mode);
+            let (_, height) = billboard.world_size(&camera, viewport_height);
+            let clip = camera.view_projection_matrix()
+                * (billboard.position() + Vec3::Y * height * 0.5).extend(1.0);
+            // NDC spans [-1, 1]; half the quad sits above the centre line.
+            clip.y / clip.w * viewport_height
+        };
+
+        let near = projected_height(5.0, SizeMode::ScreenSpace);
+        let far = projected_height(80.0, SizeMode::ScreenSpace);
+        assert!((near - 32.0).abs() < 0.01, "near projected to {near}px");
+        assert!((far - 32.0).abs() < 0.01, "far projected to {far}px");
+
+        // World-space billboards shrink with distance.
+        let near = projected_height(5.0, SizeMode::WorldSpace);
+        let far = projected_height(80.0, SizeMode::WorldSpace);
+        assert!(far < near / 10.0);
+    }
+
     #[test]
     fn test_billboard_dimensions() {
         let billboard = Billboard::new(Vec3::ZERO).with_size(2.0, 3.0);
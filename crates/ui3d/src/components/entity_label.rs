@@ -0,0 +1,161 @@
+//! Entity Label Component - Nameplate and health bar anchored above an entity
+
+use super::billboard::{Billboard, BillboardOrientation};
+use super::{Label3D, UIComponent};
+use glam::Vec3;
+
+/// Health bar size (width, height) at full health, in world units
+const HEALTH_BAR_SIZE: (f32, f32) = (0.8, 0.08);
+
+/// Vertical gap between the name text and the health bar below it
+const HEALTH_BAR_GAP: f32 = 0.2;
+
+/// A nameplate that follows an entity: a [`Label3D`] with its name and a
+/// health bar [`Billboard`] just below it
+///
+/// Call [`EntityLabel::update`] once per frame with the entity's current
+/// position; both parts are re-anchored and faded by camera distance.
+#[derive(Debug, Clone)]
+pub struct EntityLabel {
+    /// Name text
+    label: Label3D,
+
+    /// Health bar quad
+    health_bar: Billboard,
+
+    /// Height above the entity's feet at which the name is drawn
+    pub vertical_offset: f32,
+
+    /// Remaining health in `0.0..=1.0`
+    health_fraction: f32,
+
+    /// Distance fade applied to both parts
+    alpha: f32,
+}
+
+impl EntityLabel {
+    /// Create a label showing `name`, drawn `vertical_offset` above the entity
+    pub fn new(name: impl Into<String>, vertical_offset: f32) -> Self {
+        let health_bar = Billboard::new(Vec3::ZERO)
+            .with_size(HEALTH_BAR_SIZE.0, HEALTH_BAR_SIZE.1)
+            .with_orientation(BillboardOrientation::Full);
+
+        Self {
+            label: Label3D::new(Vec3::ZERO, name).with_background(true),
+            health_bar,
+            vertical_offset,
+            health_fraction: 1.0,
+            alpha: 1.0,
+        }
+    }
+
+    /// World position of the name text for an entity at `entity_position`
+    pub fn anchor(&self, entity_position: Vec3) -> Vec3 {
+        entity_position + Vec3::Y * self.vertical_offset
+    }
+
+    /// Re-anchor to the entity and refresh health and distance fade
+    pub fn update(
+        &mut self,
+        entity_position: Vec3,
+        health: f32,
+        max_health: f32,
+        camera_position: Vec3,
+    ) {
+        let anchor = self.anchor(entity_position);
+        self.label.set_position(anchor);
+        self.health_bar
+            .set_position(anchor - Vec3::Y * HEALTH_BAR_GAP);
+
+        self.health_fraction = if max_health > 0.0 {
+            (health / max_health).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.alpha = self.label.calculate_alpha(camera_position);
+
+        self.label.text_mut().color[3] = self.alpha;
+        self.label.set_visible(self.alpha > 0.0);
+
+        // Shrink the bar and shift it from green to red as health drops.
+        self.health_bar.size = (HEALTH_BAR_SIZE.0 * self.health_fraction, HEALTH_BAR_SIZE.1);
+        self.health_bar.color = [
+            1.0 - self.health_fraction,
+            self.health_fraction,
+            0.0,
+            self.alpha,
+        ];
+        self.health_bar
+            .set_visible(self.alpha > 0.0 && self.health_fraction > 0.0);
+    }
+
+    /// Get the name label
+    pub fn label(&self) -> &Label3D {
+        &self.label
+    }
+
+    /// Get the health bar billboard
+    pub fn health_bar(&self) -> &Billboard {
+        &self.health_bar
+    }
+
+    /// Remaining health in `0.0..=1.0` as of the last update
+    pub fn health_fraction(&self) -> f32 {
+        self.health_fraction
+    }
+
+    /// Distance fade as of the last update
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+}
+
+impl UIComponent for EntityLabel {
+    fn position(&self) -> Vec3 {
+        self.label.position()
+    }
+
+    fn set_position(&mut self, position: Vec3) {
+        self.label.set_position(position);
+        self.health_bar
+            .set_position(position - Vec3::Y * HEALTH_BAR_GAP);
+    }
+
+    fn is_visible(&self) -> bool {
+        self.label.is_visible()
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.label.set_visible(visible);
+        self.health_bar.set_visible(visible);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_resolves_above_entity_by_offset() {
+        let mut label = EntityLabel::new("Zombie", 2.3);
+        let entity = Vec3::new(12.5, 64.0, -3.25);
+
+        label.update(entity, 10.0, 20.0, entity + Vec3::new(4.0, 0.0, 0.0));
+
+        assert_eq!(label.position(), entity + Vec3::new(0.0, 2.3, 0.0));
+        assert_eq!(label.label().position(), label.anchor(entity));
+        assert!(label.health_bar().position().y < label.position().y);
+        assert_eq!(label.health_fraction(), 0.5);
+        assert_eq!(label.health_bar().size.0, HEALTH_BAR_SIZE.0 * 0.5);
+    }
+
+    #[test]
+    fn label_fades_out_with_distance() {
+        let mut label = EntityLabel::new("Cow", 1.6);
+        label.update(Vec3::ZERO, 10.0, 10.0, Vec3::new(100.0, 0.0, 0.0));
+
+        assert_eq!(label.alpha(), 0.0);
+        assert!(!label.is_visible());
+        assert!(!label.health_bar().is_visible());
+    }
+}
@@ -69,6 +69,18 @@ impl Label3D {
         self
     }
 
+    /// Builder: Set max line width in world units, wrapping longer tooltips
+    pub fn with_max_width(mut self, width: f32) -> Self {
+        self.text.max_width = width;
+        self
+    }
+
+    /// Builder: Set depth testing (false renders on top of the scene)
+    pub fn with_depth_test(mut self, depth_test: bool) -> Self {
+        self.text.depth_test = depth_test;
+        self
+    }
+
     /// Builder: Set fade distances
     pub fn with_fade_distance(mut self, start: f32, end: f32) -> Self {
         self.fade_start_distance = start;
@@ -3,10 +3,12 @@
 //! This module contains all the UI component types that can be rendered in 3D space.
 
 pub mod billboard;
+pub mod entity_label;
 pub mod label;
 pub mod text3d;
 
-pub use billboard::Billboard;
+pub use billboard::{Billboard, SizeMode};
+pub use entity_label::EntityLabel;
 pub use label::Label3D;
 pub use text3d::Text3D;
 
@@ -62,4 +64,52 @@ impl Transform3D {
         self.rotation = rotation;
         self
     }
+
+    /// Local-to-parent matrix (scale, then rotate, then translate)
+    pub fn to_matrix(&self) -> glam::Mat4 {
+        glam::Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.position)
+    }
+
+    /// Resolve this (local) transform into the space `parent` lives in
+    ///
+    /// Matches `parent.to_matrix() * self.to_matrix()` when the parent scale
+    /// is uniform; non-uniform parent scale under a rotated child would need
+    /// shear, which a `Transform3D` cannot represent.
+    pub fn child_of(&self, parent: &Transform3D) -> Transform3D {
+        Transform3D {
+            position: parent.position + parent.rotation * (parent.scale * self.position),
+            rotation: (parent.rotation * self.rotation).normalize(),
+            scale: parent.scale * self.scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Quat;
+
+    #[test]
+    fn child_world_matrix_is_parent_times_local() {
+        let parent = Transform3D::new(Vec3::new(10.0, 64.0, -5.0))
+            .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::splat(2.0));
+        let child = Transform3D::new(Vec3::new(1.0, 0.5, 0.0))
+            .with_rotation(Quat::from_rotation_x(0.3))
+            .with_scale(Vec3::new(1.0, 0.5, 3.0));
+
+        let world = child.child_of(&parent);
+        let expected = parent.to_matrix() * child.to_matrix();
+        assert!(world.to_matrix().abs_diff_eq(expected, 1e-5));
+
+        // Scale multiplies, rotations stack, and the offset is rotated and scaled:
+        // +X one unit becomes -Z two units after a quarter turn and 2x scale.
+        assert!(world.scale.abs_diff_eq(Vec3::new(2.0, 1.0, 6.0), 1e-6));
+        assert!(world
+            .rotation
+            .abs_diff_eq(parent.rotation * child.rotation, 1e-6));
+        assert!(world
+            .position
+            .abs_diff_eq(Vec3::new(10.0, 65.0, -7.0), 1e-5));
+    }
 }
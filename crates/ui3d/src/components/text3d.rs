@@ -24,10 +24,13 @@ pub struct Text3D {
     /// Whether the text is visible
     pub visible: bool,
 
-    /// Text alignment
+    /// Whether scene geometry occludes the text (false renders through walls)
+    pub depth_test: bool,
+
+    /// Horizontal alignment of each line about the position
     pub alignment: TextAlignment,
 
-    /// Maximum width before wrapping (0 = no wrap)
+    /// Maximum line width in world units before wrapping at whitespace (0 = no wrap)
     pub max_width: f32,
 
     /// Line spacing multiplier
@@ -50,6 +53,7 @@ impl Default for Text3D {
             color: [1.0, 1.0, 1.0, 1.0],
             billboard: true,
             visible: true,
+            depth_test: true,
             alignment: TextAlignment::Center,
             max_width: 0.0,
             line_spacing: 1.2,
@@ -85,13 +89,19 @@ impl Text3D {
         self
     }
 
+    /// Builder: Set depth testing (false renders on top of the scene)
+    pub fn with_depth_test(mut self, depth_test: bool) -> Self {
+        self.depth_test = depth_test;
+        self
+    }
+
     /// Builder: Set alignment
     pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
         self.alignment = alignment;
         self
     }
 
-    /// Builder: Set max width for wrapping
+    /// Builder: Set max line width in world units for wrapping
     pub fn with_max_width(mut self, width: f32) -> Self {
         self.max_width = width;
         self
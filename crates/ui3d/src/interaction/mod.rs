@@ -1,10 +1,424 @@
 //! 3D UI Interaction System
 //!
-//! This module will handle raycasting against UI elements, hover detection,
-//! and click handling for interactive 3D UI components.
-
-// Placeholder for future implementation
-// Will include:
-// - UI raycasting
-// - Event system (hover, click, drag)
-// - Input handling integration
+//! Raycasting against the world-space quads of billboards and labels, with
+//! hover and click detection driven by a camera ray and pointer buttons.
+
+use crate::components::{Billboard, Label3D, UIComponent};
+use crate::layout::LayoutBasis;
+use crate::render::text_renderer::{layout_text_block, TextBlockLayout};
+use crate::render::FontAtlas;
+use glam::{Vec2, Vec3};
+use mdminecraft_render::Camera;
+
+/// Identifier the caller assigns to an interactive component
+pub type UiComponentId = u32;
+
+/// A ray in world space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiRay {
+    /// Ray origin
+    pub origin: Vec3,
+    /// Unit direction
+    pub direction: Vec3,
+    /// Farthest distance that counts as a hit
+    pub max_distance: f32,
+}
+
+impl UiRay {
+    /// Create a ray, normalizing `direction`
+    pub fn new(origin: Vec3, direction: Vec3, max_distance: f32) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize_or_zero(),
+            max_distance,
+        }
+    }
+
+    /// Ray through the centre of `camera`'s view (the crosshair)
+    pub fn from_camera(camera: &Camera, max_distance: f32) -> Self {
+        Self::new(camera.position, camera.forward(), max_distance)
+    }
+}
+
+/// A rectangle in world space that can be hit by a [`UiRay`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiQuad {
+    /// Centre of the rectangle
+    pub center: Vec3,
+    /// Unit axis of the rectangle's width
+    pub right: Vec3,
+    /// Unit axis of the rectangle's height
+    pub up: Vec3,
+    /// Half the width and height
+    pub half_extents: Vec2,
+}
+
+impl UiQuad {
+    /// Quad of `size` (width, height) at `center`, turned toward `camera_position`
+    /// like a billboard
+    pub fn facing(center: Vec3, size: (f32, f32), camera_position: Vec3) -> Self {
+        let basis = LayoutBasis::facing(center, camera_position);
+        Self {
+            center,
+            right: basis.right,
+            up: basis.up,
+            half_extents: Vec2::new(size.0, size.1) * 0.5,
+        }
+    }
+
+    /// Distance along `ray` to this quad, hit from either side
+    pub fn intersect(&self, ray: &UiRay) -> Option<f32> {
+        let normal = self.right.cross(self.up);
+        let denom = ray.direction.dot(normal);
+        if denom.abs() < 1e-6 {
+            return None;
+        }
+
+        let distance = (self.center - ray.origin).dot(normal) / denom;
+        if !(0.0..=ray.max_distance).contains(&distance) {
+            return None;
+        }
+
+        let local = ray.origin + ray.direction * distance - self.center;
+        let inside = local.dot(self.right).abs() <= self.half_extents.x
+            && local.dot(self.up).abs() <= self.half_extents.y;
+        inside.then_some(distance)
+    }
+}
+
+/// Quad covering `layout` plus the label's padding
+///
+/// The text is laid out relative to the label's position, which only sits at
+/// the centre of the block for centred single-line text, so the quad is moved
+/// to the middle of the layout bounds along the billboard axes.
+fn label_quad(label: &Label3D, layout: &TextBlockLayout, camera_position: Vec3) -> UiQuad {
+    let basis = LayoutBasis::facing(label.position(), camera_position);
+    let middle = (layout.min + layout.max) * 0.5;
+    UiQuad {
+        center: label.position() + basis.right * middle.x + basis.up * middle.y,
+        right: basis.right,
+        up: basis.up,
+        half_extents: layout.size() * 0.5 + Vec2::splat(label.padding),
+    }
+}
+
+/// Result of a raycast against UI quads
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiHit {
+    /// Component that was hit
+    pub id: UiComponentId,
+    /// Distance from the ray origin to the hit point
+    pub distance: f32,
+    /// World-space position of the hit point
+    pub hit_pos: Vec3,
+    /// Position within the quad, each axis in `0.0..=1.0` from its bottom-left corner
+    pub uv: Vec2,
+}
+
+/// Pointer buttons held this frame
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UiButtons {
+    /// Left mouse button or equivalent
+    pub primary: bool,
+    /// Right mouse button or equivalent
+    pub secondary: bool,
+}
+
+/// Which pointer button produced a click
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiButton {
+    Primary,
+    Secondary,
+}
+
+/// Interaction state change reported by [`UiRaycaster::update`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiEvent {
+    /// The ray started pointing at a component
+    Hover(UiComponentId),
+    /// The ray stopped pointing at a component
+    Unhover(UiComponentId),
+    /// A button was pressed while pointing at a component
+    Click { id: UiComponentId, button: UiButton },
+}
+
+/// Hit testing and hover/click tracking for 3D UI components
+///
+/// Register this frame's interactive quads, then call [`Self::update`] with
+/// the pointer ray and buttons. Hover state and held buttons carry over
+/// between frames, so events fire only on transitions.
+#[derive(Debug, Default)]
+pub struct UiRaycaster {
+    targets: Vec<(UiComponentId, UiQuad)>,
+    hovered: Option<UiComponentId>,
+    buttons: UiButtons,
+}
+
+impl UiRaycaster {
+    /// Create a raycaster with no targets
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove every target, keeping hover and button state
+    pub fn clear(&mut self) {
+        self.targets.clear();
+    }
+
+    /// Register a quad under `id`
+    pub fn add_quad(&mut self, id: UiComponentId, quad: UiQuad) {
+        self.targets.push((id, quad));
+    }
+
+    /// Register a visible billboard at its world-space size, facing the camera
+    pub fn add_billboard(
+        &mut self,
+        id: UiComponentId,
+        billboard: &Billboard,
+        camera_position: Vec3,
+    ) {
+        if billboard.is_visible() {
+            let size = (billboard.width(), billboard.height());
+            self.add_quad(
+                id,
+                UiQuad::facing(billboard.position(), size, camera_position),
+            );
+        }
+    }
+
+    /// Register a visible label, sized to its wrapped text plus padding
+    pub fn add_label(
+        &mut self,
+        id: UiComponentId,
+        label: &Label3D,
+        atlas: &FontAtlas,
+        camera_position: Vec3,
+    ) {
+        if !label.is_visible() {
+            return;
+        }
+        let layout = layout_text_block(atlas, label.text());
+        self.add_quad(id, label_quad(label, &layout, camera_position));
+    }
+
+    /// Number of registered targets
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns true when no targets are registered
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Component currently under the ray
+    pub fn hovered(&self) -> Option<UiComponentId> {
+        self.hovered
+    }
+
+    /// Nearest target along `ray`; on a tie the first registered wins
+    pub fn raycast(&self, ray: &UiRay) -> Option<UiHit> {
+        let mut nearest: Option<UiHit> = None;
+        for &(id, quad) in &self.targets {
+            let Some(distance) = quad.intersect(ray) else {
+                continue;
+            };
+            if nearest.is_some_and(|hit| hit.distance <= distance) {
+                continue;
+            }
+
+            let hit_pos = ray.origin + ray.direction * distance;
+            let local = hit_pos - quad.center;
+            let uv = Vec2::new(local.dot(quad.right), local.dot(quad.up))
+                / (quad.half_extents * 2.0).max(Vec2::splat(f32::EPSILON))
+                + Vec2::splat(0.5);
+            nearest = Some(UiHit {
+                id,
+                distance,
+                hit_pos,
+                uv: uv.clamp(Vec2::ZERO, Vec2::ONE),
+            });
+        }
+        nearest
+    }
+
+    /// Advance hover and click state for this frame's ray and buttons
+    ///
+    /// Events come in order: `Unhover` of the old target, `Hover` of the new
+    /// one, then a `Click` for each button pressed since the last update while
+    /// over a component.
+    pub fn update(&mut self, ray: &UiRay, buttons: UiButtons) -> Vec<UiEvent> {
+        let mut events = Vec::new();
+
+        let hit = self.raycast(ray).map(|hit| hit.id);
+        if hit != self.hovered {
+            if let Some(id) = self.hovered {
+                events.push(UiEvent::Unhover(id));
+            }
+            if let Some(id) = hit {
+                events.push(UiEvent::Hover(id));
+            }
+            self.hovered = hit;
+        }
+
+        if let Some(id) = self.hovered {
+            let pressed = [
+                (buttons.primary && !self.buttons.primary, UiButton::Primary),
+                (
+                    buttons.secondary && !self.buttons.secondary,
+                    UiButton::Secondary,
+                ),
+            ];
+            for (_, button) in pressed.into_iter().filter(|(pressed, _)| *pressed) {
+                events.push(UiEvent::Click { id, button });
+            }
+        }
+
+        self.buttons = buttons;
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::text3d::TextAlignment;
+
+    const CAMERA: Vec3 = Vec3::new(0.0, 0.0, 10.0);
+
+    fn looking_forward() -> UiRay {
+        UiRay::new(CAMERA, Vec3::NEG_Z, 64.0)
+    }
+
+    fn two_overlapping_quads() -> UiRaycaster {
+        let mut raycaster = UiRaycaster::new();
+        // The far quad is registered first so order can't decide the winner.
+        raycaster.add_billboard(
+            1,
+            &Billboard::new(Vec3::new(0.0, 0.0, -5.0)).with_size(4.0, 4.0),
+            CAMERA,
+        );
+        raycaster.add_quad(
+            2,
+            UiQuad {
+                center: Vec3::new(0.5, 0.0, 0.0),
+                right: Vec3::X,
+                up: Vec3::Y,
+                half_extents: Vec2::ONE,
+            },
+        );
+        raycaster
+    }
+
+    #[test]
+    fn ray_hits_the_closer_of_two_overlapping_quads() {
+        let raycaster = two_overlapping_quads();
+
+        let hit = raycaster.raycast(&looking_forward()).expect("hit");
+        assert_eq!(hit.id, 2);
+        assert!((hit.distance - 10.0).abs() < 1e-4);
+        assert!(hit.hit_pos.abs_diff_eq(Vec3::ZERO, 1e-4));
+        assert!(hit.uv.abs_diff_eq(Vec2::new(0.25, 0.5), 1e-4));
+
+        // Past the near quad's edge only the far one is underneath.
+        let beside = UiRay::new(CAMERA + Vec3::new(-1.0, 0.0, 0.0), Vec3::NEG_Z, 64.0);
+        assert_eq!(raycaster.raycast(&beside).map(|hit| hit.id), Some(1));
+
+        // Out of reach, or pointing away, misses everything.
+        assert!(raycaster
+            .raycast(&UiRay::new(CAMERA, Vec3::NEG_Z, 5.0))
+            .is_none());
+        assert!(raycaster
+            .raycast(&UiRay::new(CAMERA, Vec3::Z, 64.0))
+            .is_none());
+    }
+
+    #[test]
+    fn hover_then_click_events_fire_on_transitions() {
+        let mut raycaster = two_overlapping_quads();
+        let on_near = looking_forward();
+        let on_far = UiRay::new(CAMERA + Vec3::new(-1.0, 0.0, 0.0), Vec3::NEG_Z, 64.0);
+        let away = UiRay::new(CAMERA, Vec3::Z, 64.0);
+        let idle = UiButtons::default();
+        let primary = UiButtons {
+            primary: true,
+            ..Default::default()
+        };
+
+        assert_eq!(raycaster.update(&on_near, idle), vec![UiEvent::Hover(2)]);
+        assert_eq!(raycaster.update(&on_near, idle), vec![]);
+        assert_eq!(
+            raycaster.update(&on_near, primary),
+            vec![UiEvent::Click {
+                id: 2,
+                button: UiButton::Primary
+            }]
+        );
+        // Holding the button while moving doesn't click again.
+        assert_eq!(
+            raycaster.update(&on_far, primary),
+            vec![UiEvent::Unhover(2), UiEvent::Hover(1)]
+        );
+        assert_eq!(raycaster.hovered(), Some(1));
+        assert_eq!(raycaster.update(&away, idle), vec![UiEvent::Unhover(1)]);
+        // Pressing over nothing clicks nothing.
+        assert_eq!(raycaster.update(&away, primary), vec![]);
+
+        // Arriving and pressing in the same frame hovers before clicking.
+        let mut raycaster = two_overlapping_quads();
+        assert_eq!(
+            raycaster.update(&on_far, primary),
+            vec![
+                UiEvent::Hover(1),
+                UiEvent::Click {
+                    id: 1,
+                    button: UiButton::Primary
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn wrapped_left_aligned_label_quad_covers_its_text() {
+        let position = Vec3::new(2.0, 3.0, 0.0);
+        let mut label = Label3D::new(position, "two wrapped lines").with_max_width(3.0);
+        label.text_mut().alignment = TextAlignment::Left;
+        label.padding = 0.1;
+        // Two lines of a 1.0 font at 1.2 spacing, extending right of and below
+        // the first baseline, as `layout_text_block` lays them out.
+        let layout = TextBlockLayout {
+            line_count: 2,
+            min: Vec2::new(0.0, -1.4),
+            max: Vec2::new(3.0, 1.0),
+            ..Default::default()
+        };
+
+        let quad = label_quad(&label, &layout, position + Vec3::Z * 10.0);
+        assert!(quad.center.abs_diff_eq(Vec3::new(3.5, 2.8, 0.0), 1e-4));
+        assert!(quad.half_extents.abs_diff_eq(Vec2::new(1.6, 1.3), 1e-4));
+
+        let mut raycaster = UiRaycaster::new();
+        raycaster.add_quad(1, quad);
+        let ray_at = |x: f32, y: f32| UiRay::new(Vec3::new(x, y, 10.0), Vec3::NEG_Z, 64.0);
+        // The end of the first line and the second line are covered...
+        assert_eq!(
+            raycaster.raycast(&ray_at(4.9, 3.5)).map(|hit| hit.id),
+            Some(1)
+        );
+        assert_eq!(
+            raycaster.raycast(&ray_at(2.5, 1.7)).map(|hit| hit.id),
+            Some(1)
+        );
+        // ...while the empty space left of the text start is not.
+        assert!(raycaster.raycast(&ray_at(1.5, 3.5)).is_none());
+    }
+
+    #[test]
+    fn hidden_components_are_not_targets() {
+        let mut raycaster = UiRaycaster::new();
+        let mut billboard = Billboard::new(Vec3::ZERO);
+        billboard.set_visible(false);
+        raycaster.add_billboard(1, &billboard, CAMERA);
+        assert!(raycaster.is_empty());
+    }
+}
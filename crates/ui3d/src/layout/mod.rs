@@ -1,10 +1,256 @@
 //! 3D UI Layout System
 //!
-//! This module will provide constraint-based layout for positioning UI elements
-//! in 3D space relative to each other or to world objects.
-
-// Placeholder for future implementation
-// Will include:
-// - Constraint-based positioning
-// - Anchoring to world objects
-// - Layout containers (vertical, horizontal, grid)
+//! Containers that position UI elements around a world-space anchor. Children
+//! are laid out along the camera's right and up axes, so a stack of labels
+//! reads the same from any viewing angle.
+
+use crate::components::{Transform3D, UIComponent};
+use glam::Vec3;
+use mdminecraft_render::Camera;
+
+/// How a [`Layout3D`] arranges its children
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    /// One column, first child on top
+    Vertical,
+    /// One row, first child on the left
+    Horizontal,
+    /// Rows of `columns` children, filled left to right then top to bottom
+    Grid { columns: usize },
+}
+
+/// Screen-aligned axes a layout places children along
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutBasis {
+    /// Camera right in world space
+    pub right: Vec3,
+    /// Camera up in world space
+    pub up: Vec3,
+}
+
+impl Default for LayoutBasis {
+    fn default() -> Self {
+        Self {
+            right: Vec3::X,
+            up: Vec3::Y,
+        }
+    }
+}
+
+impl LayoutBasis {
+    /// Axes of `camera`'s view
+    pub fn from_camera(camera: &Camera) -> Self {
+        Self {
+            right: camera.right(),
+            up: camera.up(),
+        }
+    }
+
+    /// Axes of a billboard at `anchor` turned toward `camera_position`
+    ///
+    /// Matches the billboard shaders, so a layout of billboarded labels stays
+    /// aligned with the labels themselves.
+    pub fn facing(anchor: Vec3, camera_position: Vec3) -> Self {
+        let forward = (camera_position - anchor).normalize_or_zero();
+        let right = Vec3::Y.cross(forward);
+        if right.length() < 1e-3 {
+            return Self::default();
+        }
+        let right = right.normalize();
+        Self {
+            right,
+            up: forward.cross(right).normalize(),
+        }
+    }
+}
+
+/// A container positioning its children around an anchor point
+///
+/// Children are centred on `anchor + offset` and `spacing` apart, centre to
+/// centre, along the layout's [`LayoutBasis`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout3D {
+    /// Arrangement of the children
+    pub kind: LayoutKind,
+
+    /// World position the layout follows
+    pub anchor: Vec3,
+
+    /// World-space offset from the anchor to the layout centre
+    pub offset: Vec3,
+
+    /// Distance between neighbouring children, in world units
+    pub spacing: f32,
+
+    /// Axes children are spread along
+    pub basis: LayoutBasis,
+}
+
+impl Layout3D {
+    /// Create a layout of `kind` with `spacing` between children
+    pub fn new(kind: LayoutKind, spacing: f32) -> Self {
+        Self {
+            kind,
+            anchor: Vec3::ZERO,
+            offset: Vec3::ZERO,
+            spacing,
+            basis: LayoutBasis::default(),
+        }
+    }
+
+    /// Create a vertical stack
+    pub fn vertical(spacing: f32) -> Self {
+        Self::new(LayoutKind::Vertical, spacing)
+    }
+
+    /// Create a horizontal row
+    pub fn horizontal(spacing: f32) -> Self {
+        Self::new(LayoutKind::Horizontal, spacing)
+    }
+
+    /// Create a grid `columns` wide
+    pub fn grid(columns: usize, spacing: f32) -> Self {
+        Self::new(LayoutKind::Grid { columns }, spacing)
+    }
+
+    /// Builder: Set the world-space offset from the anchor
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Builder: Set the axes children are spread along
+    pub fn with_basis(mut self, basis: LayoutBasis) -> Self {
+        self.basis = basis;
+        self
+    }
+
+    /// Follow a world position, e.g. the top of a block
+    pub fn anchor_to_world(&mut self, position: Vec3) {
+        self.anchor = position;
+    }
+
+    /// Turn the layout toward `camera_position`, as billboards do
+    pub fn face_camera(&mut self, camera_position: Vec3) {
+        self.basis = LayoutBasis::facing(self.origin(), camera_position);
+    }
+
+    /// World position of the layout centre
+    pub fn origin(&self) -> Vec3 {
+        self.anchor + self.offset
+    }
+
+    /// World positions of `count` children, in child order
+    pub fn positions(&self, count: usize) -> Vec<Vec3> {
+        let (columns, rows) = match self.kind {
+            LayoutKind::Vertical => (1, count),
+            LayoutKind::Horizontal => (count, 1),
+            LayoutKind::Grid { columns } => {
+                let columns = columns.max(1);
+                (columns.min(count), count.div_ceil(columns))
+            }
+        };
+        let centre_column = columns.saturating_sub(1) as f32 * 0.5;
+        let centre_row = rows.saturating_sub(1) as f32 * 0.5;
+        let origin = self.origin();
+
+        (0..count)
+            .map(|index| {
+                let (column, row) = match self.kind {
+                    LayoutKind::Vertical => (0, index),
+                    LayoutKind::Horizontal => (index, 0),
+                    LayoutKind::Grid { .. } => (index % columns, index / columns),
+                };
+                let x = (column as f32 - centre_column) * self.spacing;
+                let y = (centre_row - row as f32) * self.spacing;
+                origin + self.basis.right * x + self.basis.up * y
+            })
+            .collect()
+    }
+
+    /// Move each child transform to its slot, keeping rotation and scale
+    pub fn apply_layout(&self, children: &mut [Transform3D]) {
+        let positions = self.positions(children.len());
+        for (child, position) in children.iter_mut().zip(positions) {
+            child.position = position;
+        }
+    }
+
+    /// Move each component to its slot
+    pub fn apply_to_components(&self, children: &mut [&mut dyn UIComponent]) {
+        let positions = self.positions(children.len());
+        for (child, position) in children.iter_mut().zip(positions) {
+            child.set_position(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Billboard, Label3D};
+
+    fn assert_close(actual: Vec3, expected: Vec3) {
+        assert!(
+            actual.abs_diff_eq(expected, 1e-5),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn vertical_layout_spaces_items_along_camera_up() {
+        let mut camera = Camera::new(16.0 / 9.0);
+        camera.yaw = 0.7;
+        camera.pitch = -0.4;
+        let basis = LayoutBasis::from_camera(&camera);
+        // Looking down, camera up is tilted away from world up.
+        assert!(basis.up.dot(Vec3::Y) < 0.99);
+
+        let block_top = Vec3::new(10.0, 65.0, -3.0);
+        let mut layout = Layout3D::vertical(0.3)
+            .with_offset(Vec3::Y * 0.5)
+            .with_basis(basis);
+        layout.anchor_to_world(block_top);
+
+        let mut children = [Transform3D::default(); 3];
+        children[1].scale = Vec3::splat(2.0);
+        layout.apply_layout(&mut children);
+
+        // First child on top, each the configured gap below the previous one.
+        assert_close(children[0].position - children[1].position, basis.up * 0.3);
+        assert_close(children[1].position - children[2].position, basis.up * 0.3);
+        // Centred on the hover point above the block.
+        assert_close(children[1].position, block_top + Vec3::Y * 0.5);
+        assert_eq!(children[1].scale, Vec3::splat(2.0));
+    }
+
+    #[test]
+    fn horizontal_and_grid_layouts_fill_rows_left_to_right() {
+        let row = Layout3D::horizontal(1.0).positions(3);
+        assert_eq!(row, vec![Vec3::new(-1.0, 0.0, 0.0), Vec3::ZERO, Vec3::X]);
+
+        // Five items in two columns: three rows, the last one half full.
+        let grid = Layout3D::grid(2, 2.0).positions(5);
+        assert_close(grid[0], Vec3::new(-1.0, 2.0, 0.0));
+        assert_close(grid[1], Vec3::new(1.0, 2.0, 0.0));
+        assert_close(grid[2], Vec3::new(-1.0, 0.0, 0.0));
+        assert_close(grid[4], Vec3::new(-1.0, -2.0, 0.0));
+
+        assert!(Layout3D::grid(0, 1.0).positions(0).is_empty());
+    }
+
+    #[test]
+    fn facing_layout_follows_anchor_and_moves_components() {
+        let mut layout = Layout3D::vertical(0.25).with_offset(Vec3::Y);
+        layout.anchor_to_world(Vec3::new(0.0, 64.0, 0.0));
+        layout.face_camera(Vec3::new(0.0, 65.0, 10.0));
+        assert_close(layout.basis.right, Vec3::X);
+        assert_close(layout.basis.up, Vec3::Y);
+
+        let mut name = Label3D::new(Vec3::ZERO, "Steve");
+        let mut bar = Billboard::new(Vec3::ZERO);
+        layout.apply_to_components(&mut [&mut name, &mut bar]);
+        assert_close(name.position(), Vec3::new(0.0, 65.125, 0.0));
+        assert_close(bar.position(), Vec3::new(0.0, 64.875, 0.0));
+    }
+}
@@ -26,17 +26,21 @@ pub mod components;
 pub mod interaction;
 pub mod layout;
 pub mod render;
+pub mod system;
 
 // Re-export commonly used types
-pub use components::{Label3D, Text3D};
+pub use components::{EntityLabel, Label3D, Text3D};
+pub use interaction::{UiEvent, UiRaycaster};
+pub use layout::{Layout3D, LayoutBasis, LayoutKind};
 pub use render::{FontAtlas, TextRenderer};
+pub use system::{Ui3dComponents, Ui3dSystem};
 
 use anyhow::Result;
 
 /// Version of the UI3D crate
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-/// Initialize the 3D UI system with default settings
+/// Log crate initialization. GPU resources are created by [`Ui3dSystem::new`].
 pub fn init() -> Result<()> {
     tracing::info!("Initializing mdminecraft-ui3d v{}", VERSION);
     Ok(())
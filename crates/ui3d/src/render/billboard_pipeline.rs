@@ -1,12 +1,17 @@
 //! Instanced billboard rendering pipeline for 3D UI.
 
+use crate::components::{Billboard, SizeMode};
 use anyhow::Result;
 use bitflags::bitflags;
+use glam::Vec3;
+use mdminecraft_render::Camera;
 use tracing::warn;
 use wgpu::util::DeviceExt;
 
 const INITIAL_CAPACITY: usize = 1_024;
 const MAX_INSTANCES: usize = 32_768;
+/// Default world-space distance over which soft billboards fade into geometry.
+const DEFAULT_SOFT_FADE_DISTANCE: f32 = 0.5;
 
 bitflags! {
     /// Per-instance feature flags.
@@ -15,10 +20,12 @@ bitflags! {
         const EMISSIVE = 0b0001;
         /// Render in overlay pass without depth testing.
         const OVERLAY_NO_DEPTH = 0b0010;
+        /// Rescale so the projected height stays within `min_pixels..=max_pixels`.
+        const SCREEN_SPACE_SIZE = 0b0100;
     }
 }
 
-/// GPU-facing instance data. Packed to 64 bytes for cache-friendly uploads.
+/// GPU-facing instance data: 72 bytes per instance, with no padding.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct BillboardInstance {
@@ -33,6 +40,10 @@ pub struct BillboardInstance {
     pub layer: i16,
     /// Lower bits follow [`BillboardFlags`].
     pub flags: u16,
+    /// Smallest on-screen height in pixels with [`BillboardFlags::SCREEN_SPACE_SIZE`].
+    pub min_pixels: f32,
+    /// Largest on-screen height in pixels with [`BillboardFlags::SCREEN_SPACE_SIZE`].
+    pub max_pixels: f32,
 }
 
 impl Default for BillboardInstance {
@@ -47,11 +58,88 @@ impl Default for BillboardInstance {
             light: 1.0,
             layer: 0,
             flags: BillboardFlags::empty().bits(),
+            min_pixels: 0.0,
+            max_pixels: f32::INFINITY,
         }
     }
 }
 
+/// Pipeline a billboard instance is drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BillboardPipelineVariant {
+    /// Occluded by scene geometry (signs, nameplates).
+    DepthTested,
+    /// Drawn over everything (waypoints).
+    AlwaysOnTop,
+}
+
 impl BillboardInstance {
+    /// Instance for a [`Billboard`] component, honouring its depth-test flag.
+    ///
+    /// Screen-space billboards become [`BillboardFlags::SCREEN_SPACE_SIZE`]
+    /// instances pinned to their pixel height.
+    pub fn from_billboard(billboard: &Billboard) -> Self {
+        let (uv_min, uv_max) = billboard
+            .texture_coords
+            .map(|(u0, v0, u1, v1)| ([u0, v0], [u1, v1]))
+            .unwrap_or(([0.0, 0.0], [1.0, 1.0]));
+        let mut flags = if billboard.depth_test() {
+            BillboardFlags::empty()
+        } else {
+            BillboardFlags::OVERLAY_NO_DEPTH
+        };
+        let (min_pixels, max_pixels) = match billboard.size_mode {
+            SizeMode::WorldSpace => (0.0, f32::INFINITY),
+            SizeMode::ScreenSpace => {
+                flags |= BillboardFlags::SCREEN_SPACE_SIZE;
+                (billboard.height(), billboard.height())
+            }
+        };
+
+        Self {
+            position: billboard.transform.position.to_array(),
+            size: [billboard.width(), billboard.height()],
+            uv_min,
+            uv_max,
+            color: billboard.color,
+            flags: flags.bits(),
+            min_pixels,
+            max_pixels,
+            ..Default::default()
+        }
+    }
+
+    /// World-space size to draw this instance with as seen from `view`.
+    ///
+    /// With [`BillboardFlags::SCREEN_SPACE_SIZE`] the size is scaled, keeping
+    /// its aspect, until the projected height lies in `min_pixels..=max_pixels`;
+    /// otherwise it is returned unchanged.
+    pub fn screen_size(&self, view: &BillboardView) -> [f32; 2] {
+        if !BillboardFlags::from_bits_truncate(self.flags)
+            .contains(BillboardFlags::SCREEN_SPACE_SIZE)
+        {
+            return self.size;
+        }
+
+        let projected = self.size[1] * view.pixels_per_unit(self.position);
+        if projected <= 0.0 || !projected.is_finite() {
+            return self.size;
+        }
+        let target = projected.clamp(self.min_pixels, self.max_pixels.max(self.min_pixels));
+        let scale = target / projected;
+        [self.size[0] * scale, self.size[1] * scale]
+    }
+
+    /// Pipeline selected by this instance's flags.
+    pub fn pipeline_variant(&self) -> BillboardPipelineVariant {
+        if BillboardFlags::from_bits_truncate(self.flags).contains(BillboardFlags::OVERLAY_NO_DEPTH)
+        {
+            BillboardPipelineVariant::AlwaysOnTop
+        } else {
+            BillboardPipelineVariant::DepthTested
+        }
+    }
+
     const ATTRIBS: [wgpu::VertexAttribute; 8] = wgpu::vertex_attr_array![
         1 => Float32x3, // position
         2 => Float32x2, // size
@@ -72,22 +160,108 @@ impl BillboardInstance {
     }
 }
 
+/// Spritesheet animation cycling through frames of an atlas region.
+///
+/// Frames are laid out left-to-right, top-to-bottom in a grid `columns` wide
+/// that fills the `sheet_min..sheet_max` UV region.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BillboardAnimation {
+    pub sheet_min: [f32; 2],
+    pub sheet_max: [f32; 2],
+    pub columns: u16,
+    pub frame_count: u16,
+    /// Playback rate in frames per second (loops).
+    pub fps: f32,
+}
+
+impl BillboardAnimation {
+    /// Frame shown `time` seconds into playback.
+    pub fn frame_at(&self, time: f32) -> u16 {
+        if self.frame_count <= 1 || self.fps <= 0.0 {
+            return 0;
+        }
+        let frame = (time.max(0.0) * self.fps).floor() as u64;
+        (frame % self.frame_count as u64) as u16
+    }
+
+    /// UV rectangle of `frame` within the sheet.
+    pub fn frame_uv(&self, frame: u16) -> ([f32; 2], [f32; 2]) {
+        let columns = self.columns.max(1);
+        let rows = self.frame_count.max(1).div_ceil(columns);
+        let cell_w = (self.sheet_max[0] - self.sheet_min[0]) / columns as f32;
+        let cell_h = (self.sheet_max[1] - self.sheet_min[1]) / rows as f32;
+        let col = (frame % columns) as f32;
+        let row = (frame / columns) as f32;
+
+        let min = [
+            self.sheet_min[0] + col * cell_w,
+            self.sheet_min[1] + row * cell_h,
+        ];
+        (min, [min[0] + cell_w, min[1] + cell_h])
+    }
+
+    fn apply(&self, instance: &mut BillboardInstance, time: f32) {
+        let (uv_min, uv_max) = self.frame_uv(self.frame_at(time));
+        instance.uv_min = uv_min;
+        instance.uv_max = uv_max;
+    }
+}
+
 /// Submission containing a stable ID for deterministic ordering.
 #[derive(Clone, Debug)]
 pub struct BillboardSubmission {
     pub id: u32,
     pub instance: BillboardInstance,
+    /// Spritesheet animation driving `instance`'s UVs (if any).
+    pub animation: Option<BillboardAnimation>,
 }
 
 /// Frame-local collection of billboards. Not thread-safe by design.
+///
+/// Entries are cleared every frame; the animation clock persists across frames.
 #[derive(Default, Debug)]
 pub struct BillboardEmitter {
     entries: Vec<BillboardSubmission>,
+    time: f32,
 }
 
 impl BillboardEmitter {
     pub fn submit(&mut self, id: u32, instance: BillboardInstance) {
-        self.entries.push(BillboardSubmission { id, instance });
+        self.entries.push(BillboardSubmission {
+            id,
+            instance,
+            animation: None,
+        });
+    }
+
+    /// Submit a billboard whose UVs follow `animation` at the emitter's clock.
+    pub fn submit_animated(
+        &mut self,
+        id: u32,
+        mut instance: BillboardInstance,
+        animation: BillboardAnimation,
+    ) {
+        animation.apply(&mut instance, self.time);
+        self.entries.push(BillboardSubmission {
+            id,
+            instance,
+            animation: Some(animation),
+        });
+    }
+
+    /// Advance the animation clock and refresh animated UVs.
+    pub fn update(&mut self, dt: f32) {
+        self.time += dt.max(0.0);
+        for entry in &mut self.entries {
+            if let Some(animation) = entry.animation {
+                animation.apply(&mut entry.instance, self.time);
+            }
+        }
+    }
+
+    /// Seconds accumulated by [`Self::update`].
+    pub fn time(&self) -> f32 {
+        self.time
     }
 
     pub fn clear(&mut self) {
@@ -112,6 +286,88 @@ impl BillboardEmitter {
     }
 }
 
+/// Split submissions into depth-tested and overlay instances.
+///
+/// Depth-tested billboards are sorted back-to-front from `camera_pos` so
+/// overlapping transparent quads blend correctly. Entries arrive sorted by
+/// layer then id, and the sort is stable, so equidistant billboards keep that
+/// order. Overlay billboards are drawn in layer order only.
+fn partition_instances(
+    entries: &[BillboardSubmission],
+    camera_pos: [f32; 3],
+    depth: &mut Vec<BillboardInstance>,
+    overlay: &mut Vec<BillboardInstance>,
+) {
+    depth.clear();
+    overlay.clear();
+
+    for submission in entries {
+        match submission.instance.pipeline_variant() {
+            BillboardPipelineVariant::DepthTested => depth.push(submission.instance),
+            BillboardPipelineVariant::AlwaysOnTop => overlay.push(submission.instance),
+        }
+    }
+
+    let distance_sq = |instance: &BillboardInstance| {
+        let d = [
+            instance.position[0] - camera_pos[0],
+            instance.position[1] - camera_pos[1],
+            instance.position[2] - camera_pos[2],
+        ];
+        d[0] * d[0] + d[1] * d[1] + d[2] * d[2]
+    };
+    depth.sort_by(|a, b| distance_sq(b).total_cmp(&distance_sq(a)));
+}
+
+/// Camera parameters needed on the CPU for sorting, screen-space sizing and
+/// soft-particle fades.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BillboardView {
+    /// Camera position in world space.
+    pub position: [f32; 3],
+    /// Unit view direction in world space.
+    pub forward: [f32; 3],
+    /// Vertical field of view in radians.
+    pub fov_y: f32,
+    /// Height of the render target in pixels.
+    pub viewport_height: f32,
+    /// Near clip plane of the projection used to write the depth buffer.
+    pub near: f32,
+    /// Far clip plane of the projection used to write the depth buffer.
+    pub far: f32,
+}
+
+impl BillboardView {
+    /// View of `camera` rendering to a target `viewport_height` pixels tall.
+    pub fn from_camera(camera: &Camera, viewport_height: f32) -> Self {
+        Self {
+            position: camera.position.to_array(),
+            forward: camera.forward().to_array(),
+            fov_y: camera.fov,
+            viewport_height,
+            near: camera.near,
+            far: camera.far,
+        }
+    }
+
+    /// Screen pixels covered by one world unit at `position`'s view depth.
+    pub fn pixels_per_unit(&self, position: [f32; 3]) -> f32 {
+        let depth = (Vec3::from(position) - Vec3::from(self.position))
+            .dot(Vec3::from(self.forward))
+            .max(self.near);
+        self.viewport_height / (2.0 * depth * (self.fov_y * 0.5).tan())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct SoftParticleUniform {
+    near: f32,
+    far: f32,
+    fade_distance: f32,
+    _pad: f32,
+}
+
 /// Runtime statistics for a draw call.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct BillboardStats {
@@ -125,6 +381,13 @@ pub struct BillboardRenderer {
     pipeline_depth: wgpu::RenderPipeline,
     pipeline_overlay: wgpu::RenderPipeline,
     atlas_bind_group: wgpu::BindGroup,
+    scene_bind_group_layout: wgpu::BindGroupLayout,
+    soft_uniform: wgpu::Buffer,
+    soft_fade_distance: f32,
+    /// Stand-in scene depth bound while soft particles are disabled.
+    fallback_depth_view: wgpu::TextureView,
+    /// Scene depth + soft particle uniform bind group for the current frame.
+    scene_bind_group: Option<wgpu::BindGroup>,
     quad_vertex: wgpu::Buffer,
     quad_index: wgpu::Buffer,
     instance_buffer: wgpu::Buffer,
@@ -182,6 +445,57 @@ impl BillboardRenderer {
             ],
         });
 
+        let scene_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("UI3D Billboard Scene Depth BGL"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let soft_uniform = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("UI3D Billboard Soft Particle Uniform"),
+            size: std::mem::size_of::<SoftParticleUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let fallback_depth_view = device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("UI3D Billboard Fallback Depth"),
+                size: wgpu::Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::R32Float,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            })
+            .create_view(&Default::default());
+
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("UI3D Billboard Shader"),
             source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/billboard.wgsl").into()),
@@ -189,7 +503,11 @@ impl BillboardRenderer {
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("UI3D Billboard Pipeline Layout"),
-            bind_group_layouts: &[camera_bind_group_layout, &atlas_bind_group_layout],
+            bind_group_layouts: &[
+                camera_bind_group_layout,
+                &atlas_bind_group_layout,
+                &scene_bind_group_layout,
+            ],
             push_constant_ranges: &[],
         });
 
@@ -220,9 +538,11 @@ impl BillboardRenderer {
             },
         };
 
+        // Billboards are sorted back-to-front and sample the scene depth for
+        // soft fades, so they test against depth but never write it.
         let depth_state = Some(wgpu::DepthStencilState {
             format: wgpu::TextureFormat::Depth32Float,
-            depth_write_enabled: true,
+            depth_write_enabled: false,
             depth_compare: wgpu::CompareFunction::LessEqual,
             stencil: wgpu::StencilState::default(),
             bias: wgpu::DepthBiasState {
@@ -304,6 +624,11 @@ impl BillboardRenderer {
             pipeline_depth,
             pipeline_overlay,
             atlas_bind_group,
+            scene_bind_group_layout,
+            soft_uniform,
+            soft_fade_distance: 0.0,
+            fallback_depth_view,
+            scene_bind_group: None,
             quad_vertex,
             quad_index,
             instance_buffer,
@@ -345,7 +670,29 @@ impl BillboardRenderer {
         self.instance_capacity = new_cap;
     }
 
+    /// Turn on soft particles if the adapter can sample a depth target while
+    /// it is attached read-only. Returns whether they were enabled.
+    ///
+    /// The depth view passed to [`Self::render`] must then have been created
+    /// with `TEXTURE_BINDING` usage.
+    pub fn enable_soft_particles(&mut self, downlevel_flags: wgpu::DownlevelFlags) -> bool {
+        if !downlevel_flags.contains(wgpu::DownlevelFlags::READ_ONLY_DEPTH_STENCIL) {
+            return false;
+        }
+        self.soft_fade_distance = DEFAULT_SOFT_FADE_DISTANCE;
+        true
+    }
+
+    /// Set the world-space distance over which billboards fade into geometry.
+    ///
+    /// `0.0` disables soft particles.
+    pub fn set_soft_fade_distance(&mut self, distance: f32) {
+        self.soft_fade_distance = distance.max(0.0);
+    }
+
     /// Render billboards. Consumes the emitter for this frame and clears it.
+    ///
+    /// Depth-tested billboards are drawn back-to-front from `view.position`.
     #[allow(clippy::too_many_arguments)]
     pub fn render<'a>(
         &mut self,
@@ -355,26 +702,89 @@ impl BillboardRenderer {
         color_view: &'a wgpu::TextureView,
         depth_view: &'a wgpu::TextureView,
         camera_bind_group: &'a wgpu::BindGroup,
+        view: &BillboardView,
         emitter: &mut BillboardEmitter,
     ) -> Result<BillboardStats> {
+        if !self.prepare(device, queue, Some(depth_view), view, emitter) {
+            return Ok(self.stats);
+        }
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("UI3D Billboard Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: color_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            // Read-only so the same texture can be sampled for soft fades.
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: depth_view,
+                depth_ops: None,
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        self.record(&mut pass, camera_bind_group);
+
+        Ok(self.stats)
+    }
+
+    /// Render billboards into a pass the caller already started.
+    ///
+    /// The pass needs a `Depth32Float` attachment. Soft particles are skipped
+    /// because the depth buffer cannot be sampled from inside the pass.
+    pub fn render_in_pass<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        view: &BillboardView,
+        emitter: &mut BillboardEmitter,
+    ) -> Result<BillboardStats> {
+        if self.prepare(device, queue, None, view, emitter) {
+            let this: &'a Self = self;
+            this.record(pass, camera_bind_group);
+            return Ok(this.stats);
+        }
+        Ok(self.stats)
+    }
+
+    /// Sort, cap and upload this frame's instances, then clear the emitter.
+    ///
+    /// Returns whether anything needs drawing. `scene_depth` is sampled for
+    /// soft particles when they are enabled.
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        scene_depth: Option<&wgpu::TextureView>,
+        view: &BillboardView,
+        emitter: &mut BillboardEmitter,
+    ) -> bool {
         if emitter.is_empty() {
             self.stats = BillboardStats::default();
-            return Ok(self.stats);
+            return false;
         }
 
         emitter.sort_by_layer_then_id();
+        partition_instances(
+            &emitter.entries,
+            view.position,
+            &mut self.depth_instances,
+            &mut self.overlay_instances,
+        );
 
-        self.depth_instances.clear();
-        self.overlay_instances.clear();
-
-        for submission in emitter.entries.iter() {
-            if BillboardFlags::from_bits_truncate(submission.instance.flags)
-                .contains(BillboardFlags::OVERLAY_NO_DEPTH)
-            {
-                self.overlay_instances.push(submission.instance);
-            } else {
-                self.depth_instances.push(submission.instance);
-            }
+        for instance in self
+            .depth_instances
+            .iter_mut()
+            .chain(self.overlay_instances.iter_mut())
+        {
+            instance.size = instance.screen_size(view);
         }
 
         let mut depth_count = self.depth_instances.len();
@@ -421,49 +831,42 @@ impl BillboardRenderer {
             bytemuck::cast_slice(&self.combined[..total]),
         );
 
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("UI3D Billboard Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: color_view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                    view: depth_view,
-                    depth_ops: Some(wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
-                        store: wgpu::StoreOp::Store,
-                    }),
-                    stencil_ops: None,
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
-
-            pass.set_vertex_buffer(0, self.quad_vertex.slice(..));
-            pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
-            pass.set_index_buffer(self.quad_index.slice(..), wgpu::IndexFormat::Uint16);
-            pass.set_bind_group(0, camera_bind_group, &[]);
-            pass.set_bind_group(1, &self.atlas_bind_group, &[]);
-
-            if depth_count > 0 {
-                pass.set_pipeline(&self.pipeline_depth);
-                pass.draw_indexed(0..QUAD_INDICES.len() as u32, 0, 0..depth_count as u32);
-            }
+        // Without a scene depth to sample there is nothing to fade against.
+        let fade_distance = if scene_depth.is_some() {
+            self.soft_fade_distance
+        } else {
+            0.0
+        };
+        queue.write_buffer(
+            &self.soft_uniform,
+            0,
+            bytemuck::bytes_of(&SoftParticleUniform {
+                near: view.near,
+                far: view.far,
+                fade_distance,
+                _pad: 0.0,
+            }),
+        );
 
-            if overlay_count > 0 {
-                pass.set_pipeline(&self.pipeline_overlay);
-                pass.draw_indexed(
-                    0..QUAD_INDICES.len() as u32,
-                    0,
-                    depth_count as u32..(depth_count + overlay_count) as u32,
-                );
-            }
-        }
+        // Rebuilt per frame since the depth view changes on resize.
+        let scene_depth = match scene_depth {
+            Some(depth_view) if fade_distance > 0.0 => depth_view,
+            _ => &self.fallback_depth_view,
+        };
+        self.scene_bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("UI3D Billboard Scene Depth BG"),
+            layout: &self.scene_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(scene_depth),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: self.soft_uniform.as_entire_binding(),
+                },
+            ],
+        }));
 
         emitter.clear();
         self.stats = BillboardStats {
@@ -472,7 +875,41 @@ impl BillboardRenderer {
             draw_calls: ((depth_count > 0) as u32) + ((overlay_count > 0) as u32),
         };
 
-        Ok(self.stats)
+        total > 0
+    }
+
+    /// Record draws for the instances uploaded by [`Self::prepare`].
+    fn record<'a>(
+        &'a self,
+        pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+    ) {
+        let Some(scene_bind_group) = self.scene_bind_group.as_ref() else {
+            return;
+        };
+        let overlay_count = self.stats.overlay_instances as u32;
+        let depth_count = self.stats.instances as u32 - overlay_count;
+
+        pass.set_vertex_buffer(0, self.quad_vertex.slice(..));
+        pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+        pass.set_index_buffer(self.quad_index.slice(..), wgpu::IndexFormat::Uint16);
+        pass.set_bind_group(0, camera_bind_group, &[]);
+        pass.set_bind_group(1, &self.atlas_bind_group, &[]);
+        pass.set_bind_group(2, scene_bind_group, &[]);
+
+        if depth_count > 0 {
+            pass.set_pipeline(&self.pipeline_depth);
+            pass.draw_indexed(0..QUAD_INDICES.len() as u32, 0, 0..depth_count);
+        }
+
+        if overlay_count > 0 {
+            pass.set_pipeline(&self.pipeline_overlay);
+            pass.draw_indexed(
+                0..QUAD_INDICES.len() as u32,
+                0,
+                depth_count..depth_count + overlay_count,
+            );
+        }
     }
 
     #[allow(dead_code)]
@@ -579,7 +1016,7 @@ mod tests {
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Depth32Float,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
             view_formats: &[],
         });
 
@@ -599,7 +1036,7 @@ mod tests {
 
     #[test]
     fn instance_layout_is_packed() {
-        assert_eq!(std::mem::size_of::<BillboardInstance>(), 64);
+        assert_eq!(std::mem::size_of::<BillboardInstance>(), 72);
     }
 
     #[test]
@@ -632,6 +1069,90 @@ mod tests {
         assert_eq!(ids, vec![1, 2, 3]);
     }
 
+    #[test]
+    fn depth_instances_are_submitted_back_to_front() {
+        let at = |z: f32, flags: BillboardFlags| BillboardInstance {
+            position: [0.0, 0.0, z],
+            flags: flags.bits(),
+            ..Default::default()
+        };
+
+        let mut emitter = BillboardEmitter::default();
+        emitter.submit(0, at(-2.0, BillboardFlags::empty()));
+        emitter.submit(1, at(-10.0, BillboardFlags::empty()));
+        emitter.submit(2, at(3.0, BillboardFlags::empty()));
+        emitter.submit(3, at(-5.0, BillboardFlags::empty()));
+        emitter.submit(4, at(-20.0, BillboardFlags::OVERLAY_NO_DEPTH));
+        emitter.sort_by_layer_then_id();
+
+        let mut depth = Vec::new();
+        let mut overlay = Vec::new();
+        partition_instances(&emitter.entries, [0.0, 0.0, 4.0], &mut depth, &mut overlay);
+
+        let depths: Vec<f32> = depth.iter().map(|i| i.position[2]).collect();
+        assert_eq!(depths, vec![-10.0, -5.0, -2.0, 3.0]);
+        assert_eq!(overlay.len(), 1);
+
+        // Moving the camera to the other side reverses the order.
+        partition_instances(
+            &emitter.entries,
+            [0.0, 0.0, -30.0],
+            &mut depth,
+            &mut overlay,
+        );
+        let depths: Vec<f32> = depth.iter().map(|i| i.position[2]).collect();
+        assert_eq!(depths, vec![3.0, -2.0, -5.0, -10.0]);
+    }
+
+    #[test]
+    fn depth_test_flag_selects_pipeline_variant() {
+        let waypoint = Billboard::new(glam::Vec3::new(0.0, 80.0, 0.0)).with_depth_test(false);
+        let sign = waypoint.clone().with_depth_test(true);
+
+        let waypoint = BillboardInstance::from_billboard(&waypoint);
+        let sign = BillboardInstance::from_billboard(&sign);
+        assert_eq!(
+            waypoint.pipeline_variant(),
+            BillboardPipelineVariant::AlwaysOnTop
+        );
+        assert_eq!(
+            sign.pipeline_variant(),
+            BillboardPipelineVariant::DepthTested
+        );
+
+        let mut emitter = BillboardEmitter::default();
+        emitter.submit(0, waypoint);
+        emitter.submit(1, sign);
+        let mut depth = Vec::new();
+        let mut overlay = Vec::new();
+        partition_instances(&emitter.entries, [0.0; 3], &mut depth, &mut overlay);
+        assert_eq!((depth.len(), overlay.len()), (1, 1));
+        assert_eq!(overlay[0].flags, waypoint.flags);
+    }
+
+    #[test]
+    fn animated_billboard_advances_one_frame_per_frame_duration() {
+        let animation = BillboardAnimation {
+            sheet_min: [0.0, 0.5],
+            sheet_max: [1.0, 1.0],
+            columns: 4,
+            frame_count: 4,
+            fps: 8.0,
+        };
+
+        let mut emitter = BillboardEmitter::default();
+        emitter.submit_animated(7, BillboardInstance::default(), animation);
+        assert_eq!(emitter.entries[0].instance.uv_min, [0.0, 0.5]);
+        assert_eq!(emitter.entries[0].instance.uv_max, [0.25, 1.0]);
+
+        emitter.update(1.0 / animation.fps);
+        assert_eq!(emitter.entries[0].instance.uv_min, [0.25, 0.5]);
+        assert_eq!(emitter.entries[0].instance.uv_max, [0.5, 1.0]);
+
+        // Loops back to the first frame after the last one.
+        assert_eq!(animation.frame_at(4.0 / animation.fps), 0);
+    }
+
     #[test]
     fn smoke_renders_single_billboard() {
         let (_instance, device, queue) = test_device();
@@ -702,6 +1223,14 @@ mod tests {
                 &color_view,
                 &depth_view,
                 &camera_bind_group,
+                &BillboardView {
+                    position: [0.0, 0.0, 5.0],
+                    forward: [0.0, 0.0, -1.0],
+                    fov_y: std::f32::consts::FRAC_PI_2,
+                    viewport_height: 4.0,
+                    near: 0.1,
+                    far: 100.0,
+                },
                 &mut emitter,
             )
             .expect("render");
@@ -712,4 +1241,180 @@ mod tests {
         assert_eq!(stats.overlay_instances, 0);
         assert_eq!(stats.draw_calls, 1);
     }
+
+    fn looking_down_x() -> Camera {
+        let mut camera = Camera::new(1.0);
+        camera.position = Vec3::ZERO;
+        camera.look_at(Vec3::X);
+        camera
+    }
+
+    fn nameplate(distance: f32) -> BillboardInstance {
+        BillboardInstance {
+            position: [distance, 0.0, 0.0],
+            size: [2.0, 1.0],
+            flags: BillboardFlags::SCREEN_SPACE_SIZE.bits(),
+            min_pixels: 16.0,
+            max_pixels: 64.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn screen_space_size_clamps_projected_height() {
+        let view = BillboardView::from_camera(&looking_down_x(), 720.0);
+        let pixels = |instance: &BillboardInstance| {
+            instance.screen_size(&view)[1] * view.pixels_per_unit(instance.position)
+        };
+
+        // One block at 5 blocks is ~125px: clamped down, keeping its aspect.
+        let near = nameplate(5.0);
+        assert!((pixels(&near) - 64.0).abs() < 1e-3);
+        let size = near.screen_size(&view);
+        assert!((size[0] - 2.0 * size[1]).abs() < 1e-5);
+        // At 100 blocks it would be ~6px: clamped up.
+        assert!((pixels(&nameplate(100.0)) - 16.0).abs() < 1e-3);
+        // In between it keeps its world size.
+        assert_eq!(nameplate(20.0).screen_size(&view), [2.0, 1.0]);
+
+        // Without the flag the size is left alone.
+        let world = BillboardInstance {
+            flags: 0,
+            ..nameplate(5.0)
+        };
+        assert_eq!(world.screen_size(&view), [2.0, 1.0]);
+    }
+
+    #[test]
+    fn screen_space_billboard_component_maps_to_fixed_pixel_range() {
+        let billboard = Billboard::new(Vec3::new(30.0, 0.0, 0.0))
+            .with_size(48.0, 24.0)
+            .with_size_mode(SizeMode::ScreenSpace);
+        let instance = BillboardInstance::from_billboard(&billboard);
+        assert!(BillboardFlags::from_bits_truncate(instance.flags)
+            .contains(BillboardFlags::SCREEN_SPACE_SIZE));
+
+        let camera = looking_down_x();
+        let view = BillboardView::from_camera(&camera, 720.0);
+        let (width, height) = billboard.world_size(&camera, 720.0);
+        let size = instance.screen_size(&view);
+        assert!((size[0] - width).abs() < 1e-5 && (size[1] - height).abs() < 1e-5);
+    }
+
+    /// Rows of a `size`-square overlay render containing any drawn pixel.
+    fn rendered_rows(instance: BillboardInstance, camera: &Camera, size: u32) -> u32 {
+        let (_instance, device, queue) = test_device();
+        let (camera_layout, camera_bind_group, camera_buf) = make_camera_bind_group(&device);
+        queue.write_buffer(
+            &camera_buf,
+            0,
+            bytemuck::bytes_of(&CameraUniform::from_camera(camera)),
+        );
+
+        let format = wgpu::TextureFormat::Bgra8UnormSrgb;
+        let texture = |label, format, usage| {
+            device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width: size,
+                    height: size,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage,
+                view_formats: &[],
+            })
+        };
+        let atlas = texture(
+            "Atlas",
+            wgpu::TextureFormat::Rgba8UnormSrgb,
+            wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        );
+        queue.write_texture(
+            atlas.as_image_copy(),
+            &vec![255; (size * size * 4) as usize],
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(size * 4),
+                rows_per_image: Some(size),
+            },
+            atlas.size(),
+        );
+        let color = texture(
+            "Test Color",
+            format,
+            wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        );
+        let depth = texture(
+            "Test Depth",
+            wgpu::TextureFormat::Depth32Float,
+            wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        );
+        let atlas_view = atlas.create_view(&Default::default());
+        let atlas_sampler = device.create_sampler(&wgpu::SamplerDescriptor::default());
+
+        let mut renderer =
+            BillboardRenderer::new(&device, format, &camera_layout, &atlas_view, &atlas_sampler)
+                .expect("renderer");
+        let mut emitter = BillboardEmitter::default();
+        emitter.submit(0, instance);
+
+        let mut encoder = device.create_command_encoder(&Default::default());
+        renderer
+            .render(
+                &device,
+                &queue,
+                &mut encoder,
+                &color.create_view(&Default::default()),
+                &depth.create_view(&Default::default()),
+                &camera_bind_group,
+                &BillboardView::from_camera(camera, size as f32),
+                &mut emitter,
+            )
+            .expect("render");
+        let readback = mdminecraft_render::record_texture_readback(
+            &device,
+            &mut encoder,
+            &color,
+            format,
+            (size, size),
+        );
+        queue.submit(Some(encoder.finish()));
+        let pixels = readback.read_rgba8(&device).expect("read back");
+
+        pixels
+            .chunks_exact(size as usize * 4)
+            .filter(|row| row.chunks_exact(4).any(|p| p[3] > 0))
+            .count() as u32
+    }
+
+    #[test]
+    fn screen_space_billboard_keeps_pixel_height_across_distance() {
+        const SIZE: u32 = 128;
+        let camera = looking_down_x();
+        // Overlay, so the uncleared depth buffer can't hide it.
+        let at = |distance: f32, flags: BillboardFlags| BillboardInstance {
+            position: [distance, 0.0, 0.0],
+            flags: (flags | BillboardFlags::OVERLAY_NO_DEPTH).bits(),
+            min_pixels: 32.0,
+            max_pixels: 32.0,
+            ..Default::default()
+        };
+
+        let near = rendered_rows(at(5.0, BillboardFlags::SCREEN_SPACE_SIZE), &camera, SIZE);
+        let far = rendered_rows(at(50.0, BillboardFlags::SCREEN_SPACE_SIZE), &camera, SIZE);
+        assert!(near.abs_diff(32) <= 1, "near billboard is {near}px tall");
+        assert_eq!(near, far);
+
+        // Without the flag the far billboard shrinks with distance.
+        let near = rendered_rows(at(5.0, BillboardFlags::empty()), &camera, SIZE);
+        let far = rendered_rows(at(50.0, BillboardFlags::empty()), &camera, SIZE);
+        assert!(
+            far * 5 < near,
+            "world-space billboard: {near}px near, {far}px far"
+        );
+    }
 }
@@ -11,6 +11,9 @@ use tracing::{debug, info};
 /// Character range for ASCII printable characters
 pub const ASCII_RANGE: std::ops::Range<u32> = 32..127;
 
+/// Distance-field spread (pixels) used by [`FontAtlasBuilder::from_ttf`]
+pub const DEFAULT_SDF_SPREAD: u32 = 4;
+
 /// A font atlas containing pre-rendered glyphs with signed distance fields
 pub struct FontAtlas {
     /// The font used for this atlas
@@ -26,11 +29,20 @@ pub struct FontAtlas {
     /// Glyph metrics and positions in atlas
     glyphs: HashMap<char, GlyphInfo>,
 
+    /// Box drawn in place of characters missing from the atlas
+    notdef: GlyphInfo,
+
+    /// Non-zero kerning adjustments between glyph pairs (at `font_size`)
+    kerning: HashMap<(char, char), f32>,
+
     /// Font size used for rasterization
     pub font_size: f32,
 
     /// Padding around each glyph
     pub padding: u32,
+
+    /// Distance-field spread in pixels; 0 means the atlas stores plain coverage
+    pub sdf_spread: u32,
 }
 
 /// Information about a glyph in the atlas
@@ -60,6 +72,25 @@ impl FontAtlas {
         self.glyphs.get(&c)
     }
 
+    /// Get the `.notdef` box used for characters outside the atlas charset
+    pub fn notdef_glyph(&self) -> &GlyphInfo {
+        &self.notdef
+    }
+
+    /// Glyph to draw for `c`: its own glyph, the `.notdef` box if it is
+    /// missing, or `None` for control characters (which draw nothing)
+    pub fn resolve_glyph(&self, c: char) -> Option<&GlyphInfo> {
+        if c.is_control() {
+            return None;
+        }
+        Some(self.get_glyph(c).unwrap_or(&self.notdef))
+    }
+
+    /// Horizontal kerning between `left` and `right` at the atlas font size
+    pub fn kerning(&self, left: char, right: char) -> f32 {
+        self.kerning.get(&(left, right)).copied().unwrap_or(0.0)
+    }
+
     /// Get the font
     pub fn font(&self) -> &Font {
         &self.font
@@ -71,14 +102,21 @@ impl FontAtlas {
         let mut layouts = Vec::with_capacity(text.len());
         let mut cursor_x = 0.0;
         let _line_height = self.font_size * scale;
+        let mut previous = None;
 
         for c in text.chars() {
             if c == '\n' {
                 cursor_x = 0.0;
+                previous = None;
                 continue;
             }
 
-            if let Some(glyph) = self.get_glyph(c) {
+            if let Some(glyph) = self.resolve_glyph(c) {
+                if let Some(prev) = previous {
+                    cursor_x += self.kerning(prev, c) * scale;
+                }
+                previous = Some(c);
+
                 layouts.push(GlyphLayout {
                     char: c,
                     position_x: cursor_x + glyph.bearing_x * scale,
@@ -100,9 +138,14 @@ impl FontAtlas {
     pub fn measure_text(&self, text: &str, font_size: f32) -> f32 {
         let scale = font_size / self.font_size;
         let mut width = 0.0;
+        let mut previous = None;
 
         for c in text.chars() {
-            if let Some(glyph) = self.get_glyph(c) {
+            if let Some(glyph) = self.resolve_glyph(c) {
+                if let Some(prev) = previous {
+                    width += self.kerning(prev, c) * scale;
+                }
+                previous = Some(c);
                 width += glyph.advance_width * scale;
             }
         }
@@ -138,6 +181,7 @@ pub struct FontAtlasBuilder {
     font_size: f32,
     padding: u32,
     chars: Vec<char>,
+    sdf_spread: u32,
 }
 
 impl FontAtlasBuilder {
@@ -148,9 +192,35 @@ impl FontAtlasBuilder {
             font_size: 48.0, // Default rasterization size
             padding: 2,
             chars: ASCII_RANGE.filter_map(char::from_u32).collect(),
+            sdf_spread: 0,
         }
     }
 
+    /// Rasterize `charset` from TTF/OTF bytes into a signed distance field atlas at `px`
+    ///
+    /// Lets callers drop in custom fonts at runtime without an offline baking step.
+    pub fn from_ttf(
+        font_data: impl Into<Vec<u8>>,
+        px: f32,
+        charset: impl IntoIterator<Item = char>,
+    ) -> Self {
+        Self::new(font_data.into())
+            .with_font_size(px)
+            .with_chars(charset.into_iter().collect())
+            .with_sdf(DEFAULT_SDF_SPREAD)
+    }
+
+    /// Build a signed distance field atlas of `chars` from TTF/OTF bytes at `px` pixels
+    ///
+    /// Characters the atlas doesn't hold draw the font's `.notdef` box.
+    pub fn from_ttf_bytes(
+        bytes: &[u8],
+        px: u32,
+        chars: impl Iterator<Item = char>,
+    ) -> Result<FontAtlas> {
+        Self::from_ttf(bytes, px as f32, chars).build()
+    }
+
     /// Load font from a file
     pub fn from_file(path: &str) -> Result<Self> {
         let font_data =
@@ -183,6 +253,14 @@ impl FontAtlasBuilder {
         self
     }
 
+    /// Store signed distance fields spreading `spread` pixels around each glyph edge
+    ///
+    /// `0` stores plain coverage bitmaps.
+    pub fn with_sdf(mut self, spread: u32) -> Self {
+        self.sdf_spread = spread;
+        self
+    }
+
     /// Build the font atlas
     pub fn build(self) -> Result<FontAtlas> {
         info!(
@@ -195,16 +273,41 @@ impl FontAtlasBuilder {
         let padding = self.padding;
         let font_size = self.font_size;
         let chars = self.chars;
+        let sdf_spread = self.sdf_spread;
 
         // Parse font
         let font = Font::from_bytes(self.font_data, FontSettings::default())
             .map_err(|e| anyhow::anyhow!("Failed to parse font: {}", e))?;
 
-        // Rasterize all glyphs to measure sizes
+        // Rasterize all glyphs to measure sizes; `None` marks the .notdef box
         let mut glyph_data = Vec::new();
-        for &c in &chars {
-            let (metrics, bitmap) = font.rasterize(c, font_size);
-            glyph_data.push((c, metrics, bitmap));
+        let rasterized = chars
+            .iter()
+            .map(|&c| (Some(c), font.rasterize(c, font_size)))
+            .chain(std::iter::once((
+                None,
+                Self::rasterize_notdef(&font, font_size),
+            )));
+        // Blank glyphs like ' ' still get a spread-sized empty field, so every
+        // glyph has a real UV rect to sample.
+        for (c, (metrics, bitmap)) in rasterized {
+            if sdf_spread > 0 {
+                let (metrics, field) = Self::distance_field(metrics, &bitmap, sdf_spread);
+                glyph_data.push((c, metrics, field));
+            } else {
+                glyph_data.push((c, metrics, bitmap));
+            }
+        }
+
+        let mut kerning = HashMap::new();
+        for &left in &chars {
+            for &right in &chars {
+                if let Some(kern) = font.horizontal_kern(left, right, font_size) {
+                    if kern != 0.0 {
+                        kerning.insert((left, right), kern);
+                    }
+                }
+            }
         }
 
         // Pack glyphs into atlas using simple row packing
@@ -218,6 +321,7 @@ impl FontAtlasBuilder {
 
         // Copy glyph bitmaps into atlas
         let mut glyphs = HashMap::new();
+        let mut notdef = None;
         for (i, (c, metrics, bitmap)) in glyph_data.iter().enumerate() {
             let (x, y) = positions[i];
 
@@ -245,21 +349,25 @@ impl FontAtlasBuilder {
                 (y + metrics.height as u32) as f32 / atlas_height as f32,
             );
 
-            glyphs.insert(
-                *c,
-                GlyphInfo {
-                    atlas_x: x,
-                    atlas_y: y,
-                    width: metrics.width as u32,
-                    height: metrics.height as u32,
-                    advance_width: metrics.advance_width,
-                    bearing_x: metrics.xmin as f32,
-                    bearing_y: metrics.ymin as f32,
-                    uv_min,
-                    uv_max,
-                },
-            );
+            let info = GlyphInfo {
+                atlas_x: x,
+                atlas_y: y,
+                width: metrics.width as u32,
+                height: metrics.height as u32,
+                advance_width: metrics.advance_width,
+                bearing_x: metrics.xmin as f32,
+                bearing_y: metrics.ymin as f32,
+                uv_min,
+                uv_max,
+            };
+            match c {
+                Some(c) => {
+                    glyphs.insert(*c, info);
+                }
+                None => notdef = Some(info),
+            }
         }
+        let notdef = notdef.context("Font atlas is missing its .notdef glyph")?;
 
         info!(
             "Font atlas built successfully: {} glyphs, {}x{} texture",
@@ -274,14 +382,98 @@ impl FontAtlasBuilder {
             width: atlas_width,
             height: atlas_height,
             glyphs,
+            notdef,
+            kerning,
             font_size,
             padding,
+            sdf_spread,
         })
     }
 
+    /// Rasterize the font's `.notdef` glyph (index 0), or a hollow box if
+    /// the font leaves it empty
+    fn rasterize_notdef(font: &Font, font_size: f32) -> (fontdue::Metrics, Vec<u8>) {
+        let (metrics, bitmap) = font.rasterize_indexed(0, font_size);
+        if metrics.width > 0 && metrics.height > 0 {
+            return (metrics, bitmap);
+        }
+
+        let width = (font_size * 0.5).ceil().max(3.0) as usize;
+        let height = (font_size * 0.7).ceil().max(3.0) as usize;
+        let border = (font_size / 16.0).ceil().max(1.0) as usize;
+        let mut bitmap = vec![0u8; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                if x < border || y < border || x >= width - border || y >= height - border {
+                    bitmap[y * width + x] = 255;
+                }
+            }
+        }
+
+        let metrics = fontdue::Metrics {
+            xmin: (font_size * 0.1).round() as i32,
+            ymin: 0,
+            width,
+            height,
+            advance_width: width as f32 + font_size * 0.2,
+            ..metrics
+        };
+        (metrics, bitmap)
+    }
+
+    /// Convert a coverage bitmap into a signed distance field
+    ///
+    /// The field is grown by `spread` pixels on every side so the falloff
+    /// outside the glyph fits; metrics are shifted to match. Edges map to
+    /// 128, with +/-`spread` pixels mapping to 255/0.
+    fn distance_field(
+        metrics: fontdue::Metrics,
+        coverage: &[u8],
+        spread: u32,
+    ) -> (fontdue::Metrics, Vec<u8>) {
+        let s = spread as i32;
+        let (src_w, src_h) = (metrics.width as i32, metrics.height as i32);
+        let (dst_w, dst_h) = (src_w + 2 * s, src_h + 2 * s);
+        let inside = |x: i32, y: i32| {
+            x >= 0 && y >= 0 && x < src_w && y < src_h && coverage[(y * src_w + x) as usize] >= 128
+        };
+
+        let mut field = vec![0u8; (dst_w * dst_h) as usize];
+        for oy in 0..dst_h {
+            for ox in 0..dst_w {
+                let (x, y) = (ox - s, oy - s);
+                let here = inside(x, y);
+
+                // Nearest pixel of the opposite state within the spread window.
+                let mut best = (s * s) as f32;
+                for dy in -s..=s {
+                    for dx in -s..=s {
+                        if inside(x + dx, y + dy) != here {
+                            best = best.min((dx * dx + dy * dy) as f32);
+                        }
+                    }
+                }
+
+                let distance = best.sqrt().min(spread as f32) / spread as f32;
+                let signed = if here { distance } else { -distance };
+                field[(oy * dst_w + ox) as usize] =
+                    (128.0 + signed * 127.0).clamp(0.0, 255.0) as u8;
+            }
+        }
+
+        let metrics = fontdue::Metrics {
+            xmin: metrics.xmin - s,
+            ymin: metrics.ymin - s,
+            width: dst_w as usize,
+            height: dst_h as usize,
+            ..metrics
+        };
+        (metrics, field)
+    }
+
     /// Pack glyphs into atlas using row packing algorithm
     fn pack_glyphs_static(
-        glyphs: &[(char, fontdue::Metrics, Vec<u8>)],
+        glyphs: &[(Option<char>, fontdue::Metrics, Vec<u8>)],
         padding: u32,
     ) -> Result<PackedAtlasLayout> {
         let mut current_x = padding;
@@ -331,5 +523,60 @@ mod tests {
         assert!(chars.contains(&'0'));
     }
 
-    // Note: Actual font tests would require a font file
+    fn test_font_bytes() -> Vec<u8> {
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("DejaVuSans.ttf");
+        std::fs::read(path).expect("read test font")
+    }
+
+    #[test]
+    fn from_ttf_builds_sdf_glyphs_for_ascii() {
+        let charset: Vec<char> = ASCII_RANGE.filter_map(char::from_u32).collect();
+        let atlas = FontAtlasBuilder::from_ttf(test_font_bytes(), 24.0, charset.clone())
+            .build()
+            .expect("build atlas");
+
+        assert_eq!(atlas.sdf_spread, DEFAULT_SDF_SPREAD);
+        for c in charset {
+            let glyph = atlas
+                .get_glyph(c)
+                .unwrap_or_else(|| panic!("missing {c:?}"));
+            assert!(glyph.advance_width > 0.0, "{c:?} has no advance");
+        }
+
+        // Visible glyphs carry the spread on every side: the outermost column
+        // sits a full spread outside the outline.
+        let glyph = atlas.get_glyph('O').expect("O");
+        assert!(glyph.width > 2 * DEFAULT_SDF_SPREAD);
+        let row = (glyph.atlas_y + glyph.height / 2) * atlas.width;
+        let left_edge = atlas.texture_data[(row + glyph.atlas_x) as usize];
+        assert!(left_edge <= 1, "edge column is {left_edge}");
+    }
+
+    #[test]
+    fn from_ttf_bytes_gives_every_glyph_a_uv_rect() {
+        let atlas = FontAtlasBuilder::from_ttf_bytes(
+            &test_font_bytes(),
+            24,
+            ASCII_RANGE.filter_map(char::from_u32),
+        )
+        .expect("build atlas");
+
+        // Includes ' ', which has no outline of its own.
+        for c in ASCII_RANGE.filter_map(char::from_u32) {
+            let glyph = atlas
+                .get_glyph(c)
+                .unwrap_or_else(|| panic!("missing {c:?}"));
+            assert!(glyph.uv_max.0 > glyph.uv_min.0, "{c:?} has no UV width");
+            assert!(glyph.uv_max.1 > glyph.uv_min.1, "{c:?} has no UV height");
+            assert!(glyph.advance_width > 0.0, "{c:?} has no advance");
+        }
+
+        // Anything outside the charset falls back to the box glyph.
+        let fallback = atlas.resolve_glyph('\u{2603}').expect("fallback");
+        assert_eq!(fallback.uv_min, atlas.notdef_glyph().uv_min);
+        assert!(fallback.uv_max.0 > fallback.uv_min.0);
+    }
 }
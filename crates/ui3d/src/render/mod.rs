@@ -7,7 +7,8 @@ pub mod text_renderer;
 
 #[cfg(feature = "ui3d_billboards")]
 pub use billboard_pipeline::{
-    BillboardEmitter, BillboardFlags, BillboardInstance, BillboardRenderer, BillboardStats,
+    BillboardAnimation, BillboardEmitter, BillboardFlags, BillboardInstance,
+    BillboardPipelineVariant, BillboardRenderer, BillboardStats, BillboardView,
 };
 pub use font_atlas::{FontAtlas, FontAtlasBuilder};
-pub use text_renderer::TextRenderer;
+pub use text_renderer::{TextBatch, TextBatchDraw, TextBlockLayout, TextRenderer};
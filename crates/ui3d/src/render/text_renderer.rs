@@ -1,19 +1,40 @@
 //! Text Renderer - GPU-accelerated text rendering in 3D
 
-use super::font_atlas::FontAtlas;
-use crate::components::Text3D;
+use super::font_atlas::{FontAtlas, GlyphLayout};
+use crate::components::text3d::TextAlignment;
+use crate::components::{Label3D, Text3D};
 use anyhow::Result;
 use bytemuck::{Pod, Zeroable};
+use glam::{Vec2, Vec3};
+use mdminecraft_render::CameraUniform;
+use std::ops::Range;
+
+/// Initial batch buffer capacity in glyphs (4 vertices and 6 indices each)
+const INITIAL_BATCH_GLYPHS: usize = 1_024;
+
+/// Initial single-label buffer capacity in glyphs
+const INITIAL_LABEL_GLYPHS: usize = 64;
 
 /// Text rendering pipeline and resources
 pub struct TextRenderer {
     pipeline: wgpu::RenderPipeline,
     pipeline_fixed: wgpu::RenderPipeline,
+    pipeline_overlay: wgpu::RenderPipeline,
+    pipeline_fixed_overlay: wgpu::RenderPipeline,
     font_bind_group: wgpu::BindGroup,
     #[allow(dead_code)]
     // Texture must stay alive for the bind group even if unused directly.
     font_texture: wgpu::Texture,
     atlas: FontAtlas,
+    batch: TextBatch,
+    batch_vertex_buffer: wgpu::Buffer,
+    batch_index_buffer: wgpu::Buffer,
+    batch_capacity: usize,
+    label_camera_buffer: wgpu::Buffer,
+    label_camera_bind_group: wgpu::BindGroup,
+    label_vertex_buffer: wgpu::Buffer,
+    label_index_buffer: wgpu::Buffer,
+    label_capacity: usize,
 }
 
 /// Vertex format for text rendering
@@ -43,8 +64,105 @@ impl TextVertex {
     }
 }
 
+/// Glyphs of a [`Text3D`] after wrapping and alignment, with their bounds
+///
+/// Coordinates are relative to the text's position in the same units as its
+/// font size. Lines stack downward from the first baseline at `y = 0`.
+#[derive(Debug, Clone, Default)]
+pub struct TextBlockLayout {
+    /// Positioned glyphs of every line
+    pub glyphs: Vec<GlyphLayout>,
+    /// Number of lines after wrapping
+    pub line_count: usize,
+    /// Bottom-left corner of the bounding box
+    pub min: Vec2,
+    /// Top-right corner of the bounding box
+    pub max: Vec2,
+}
+
+impl TextBlockLayout {
+    /// Width and height of the bounding box
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+}
+
+/// Split `text` into lines at `\n` and, when `max_width` is positive, at the
+/// whitespace before the first word that would overflow it
+///
+/// A word wider than `max_width` gets a line of its own rather than being split.
+fn wrap_lines(atlas: &FontAtlas, text: &str, font_size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        if max_width <= 0.0 {
+            lines.push(paragraph.to_string());
+            continue;
+        }
+
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if line.is_empty() {
+                line.push_str(word);
+                continue;
+            }
+            let candidate = format!("{line} {word}");
+            if atlas.measure_text(&candidate, font_size) > max_width {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Wrap `text` to its `max_width` and align each line about its position
+///
+/// Left-aligned lines start at the position, centered lines straddle it and
+/// right-aligned lines end at it. Lines are `font_size * line_spacing` apart.
+pub(crate) fn layout_text_block(atlas: &FontAtlas, text: &Text3D) -> TextBlockLayout {
+    if text.text.is_empty() {
+        return TextBlockLayout::default();
+    }
+
+    let lines = wrap_lines(atlas, &text.text, text.font_size, text.max_width);
+    let line_advance = text.font_size * text.line_spacing;
+    let align = |width: f32| match text.alignment {
+        TextAlignment::Left => 0.0,
+        TextAlignment::Center => -width * 0.5,
+        TextAlignment::Right => -width,
+    };
+
+    let mut glyphs = Vec::new();
+    let mut block_width: f32 = 0.0;
+    for (index, line) in lines.iter().enumerate() {
+        let width = atlas.measure_text(line, text.font_size);
+        block_width = block_width.max(width);
+        let (x, y) = (align(width), -(index as f32) * line_advance);
+        glyphs.extend(
+            atlas
+                .layout_text(line, text.font_size)
+                .into_iter()
+                .map(|glyph| GlyphLayout {
+                    position_x: glyph.position_x + x,
+                    position_y: glyph.position_y + y,
+                    ..glyph
+                }),
+        );
+    }
+
+    let left = align(block_width);
+    TextBlockLayout {
+        glyphs,
+        line_count: lines.len(),
+        min: Vec2::new(left, text.font_size - lines.len() as f32 * line_advance),
+        max: Vec2::new(left + block_width, text.font_size),
+    }
+}
+
 fn build_text_mesh(atlas: &FontAtlas, text: &Text3D) -> (Vec<TextVertex>, Vec<u32>) {
-    let layouts = atlas.layout_text(&text.text, text.font_size);
+    let layouts = layout_text_block(atlas, text).glyphs;
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
 
@@ -103,6 +221,124 @@ fn build_text_mesh(atlas: &FontAtlas, text: &Text3D) -> (Vec<TextVertex>, Vec<u3
     (vertices, indices)
 }
 
+/// Mesh for a label seen from `camera_position`, with its distance fade
+/// folded into the vertex alpha
+fn build_label_mesh(
+    atlas: &FontAtlas,
+    label: &Label3D,
+    camera_position: Vec3,
+) -> (Vec<TextVertex>, Vec<u32>) {
+    let alpha = label.calculate_alpha(camera_position);
+    if !label.text().visible || alpha <= 0.0 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let (mut vertices, indices) = build_text_mesh(atlas, label.text());
+    for vertex in &mut vertices {
+        vertex.color[3] *= alpha;
+    }
+    (vertices, indices)
+}
+
+/// One draw call's slice of a [`TextBatch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextBatchDraw {
+    /// Whether the text faces the camera
+    pub billboard: bool,
+    /// Whether the text is hidden behind world geometry
+    pub depth_test: bool,
+    /// Range into [`TextBatch::indices`]
+    pub indices: Range<u32>,
+}
+
+/// Meshes of many text components merged into one vertex and index buffer
+///
+/// Components sharing billboard/depth-test flags form a single draw call, so
+/// a frame of ordinary labels is one draw no matter how many there are.
+#[derive(Debug, Clone, Default)]
+pub struct TextBatch {
+    pub vertices: Vec<TextVertex>,
+    pub indices: Vec<u32>,
+    /// Number of glyph quads in the batch
+    pub glyph_count: usize,
+    /// Draw calls in submission order (depth-tested before always-on-top)
+    pub draws: Vec<TextBatchDraw>,
+}
+
+impl TextBatch {
+    /// Merge the meshes of every visible component in `texts`
+    pub fn build(atlas: &FontAtlas, texts: &[&Text3D]) -> Self {
+        let mut batch = Self::default();
+        batch.rebuild(atlas, texts);
+        batch
+    }
+
+    /// Rebuild in place, reusing the existing allocations
+    fn rebuild(&mut self, atlas: &FontAtlas, texts: &[&Text3D]) {
+        self.vertices.clear();
+        self.indices.clear();
+        self.draws.clear();
+        self.glyph_count = 0;
+
+        let mut visible: Vec<&Text3D> = texts.iter().copied().filter(|t| t.visible).collect();
+        // Stable, so components keep their submission order within a draw.
+        visible.sort_by_key(|t| (!t.depth_test, !t.billboard));
+
+        for text in visible {
+            let (vertices, indices) = build_text_mesh(atlas, text);
+            if indices.is_empty() {
+                continue;
+            }
+
+            let base_vertex = self.vertices.len() as u32;
+            let first_index = self.indices.len() as u32;
+            self.glyph_count += vertices.len() / 4;
+            self.vertices.extend_from_slice(&vertices);
+            self.indices
+                .extend(indices.iter().map(|index| index + base_vertex));
+            let end = self.indices.len() as u32;
+
+            match self.draws.last_mut() {
+                Some(draw)
+                    if draw.billboard == text.billboard && draw.depth_test == text.depth_test =>
+                {
+                    draw.indices.end = end;
+                }
+                _ => self.draws.push(TextBatchDraw {
+                    billboard: text.billboard,
+                    depth_test: text.depth_test,
+                    indices: first_index..end,
+                }),
+            }
+        }
+    }
+
+    /// Check whether the batch has nothing to draw
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+/// Measure `text` as (width, height, line_count) at font size `scale`.
+///
+/// Width is the widest line's advance sum; height covers every line at the
+/// atlas line height. Empty text measures as zero lines.
+pub(crate) fn measure_text_block(atlas: &FontAtlas, text: &str, scale: f32) -> (f32, f32, usize) {
+    if text.is_empty() {
+        return (0.0, 0.0, 0);
+    }
+
+    let mut width: f32 = 0.0;
+    let mut line_count = 0;
+    for line in text.split('\n') {
+        width = width.max(atlas.measure_text(line, scale));
+        line_count += 1;
+    }
+
+    let height = atlas.line_height(scale) * line_count as f32;
+    (width, height, line_count)
+}
+
 impl TextRenderer {
     /// Create a new text renderer
     pub fn new(
@@ -212,102 +448,266 @@ impl TextRenderer {
             push_constant_ranges: &[],
         });
 
-        // Create render pipeline (billboard variant)
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Text Pipeline (Billboard)"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main",
-                buffers: &[TextVertex::desc()],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: surface_format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: None, // Don't cull for billboards
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: false, // UI doesn't write depth
-                depth_compare: wgpu::CompareFunction::Less,
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview: None,
-        });
+        // Distance-field atlases need the thresholding fragment shader
+        let fragment_entry = if atlas.sdf_spread > 0 {
+            "fs_main_sdf"
+        } else {
+            "fs_main"
+        };
 
-        // Create fixed orientation pipeline
-        let pipeline_fixed = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Text Pipeline (Fixed)"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main_fixed",
-                buffers: &[TextVertex::desc()],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: surface_format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: false,
-                depth_compare: wgpu::CompareFunction::Less,
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview: None,
+        let create_pipeline =
+            |label: &str, vs_entry: &str, cull_mode: Option<wgpu::Face>, depth_test: bool| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: Some(label),
+                    layout: Some(&pipeline_layout),
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: vs_entry,
+                        buffers: &[TextVertex::desc()],
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: fragment_entry,
+                        targets: &[Some(wgpu::ColorTargetState {
+                            format: surface_format,
+                            blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                            write_mask: wgpu::ColorWrites::ALL,
+                        })],
+                    }),
+                    primitive: wgpu::PrimitiveState {
+                        topology: wgpu::PrimitiveTopology::TriangleList,
+                        strip_index_format: None,
+                        front_face: wgpu::FrontFace::Ccw,
+                        cull_mode,
+                        polygon_mode: wgpu::PolygonMode::Fill,
+                        unclipped_depth: false,
+                        conservative: false,
+                    },
+                    depth_stencil: Some(wgpu::DepthStencilState {
+                        format: wgpu::TextureFormat::Depth32Float,
+                        depth_write_enabled: false, // UI doesn't write depth
+                        // Always-on-top text ignores scene depth (e.g. waypoints)
+                        depth_compare: if depth_test {
+                            wgpu::CompareFunction::Less
+                        } else {
+                            wgpu::CompareFunction::Always
+                        },
+                        stencil: wgpu::StencilState::default(),
+                        bias: wgpu::DepthBiasState::default(),
+                    }),
+                    multisample: wgpu::MultisampleState::default(),
+                    multiview: None,
+                })
+            };
+
+        // Don't cull billboards; fixed-orientation text is one-sided
+        let pipeline = create_pipeline("Text Pipeline (Billboard)", "vs_main", None, true);
+        let pipeline_fixed = create_pipeline(
+            "Text Pipeline (Fixed)",
+            "vs_main_fixed",
+            Some(wgpu::Face::Back),
+            true,
+        );
+        let pipeline_overlay =
+            create_pipeline("Text Pipeline (Billboard, Overlay)", "vs_main", None, false);
+        let pipeline_fixed_overlay = create_pipeline(
+            "Text Pipeline (Fixed, Overlay)",
+            "vs_main_fixed",
+            Some(wgpu::Face::Back),
+            false,
+        );
+
+        let (batch_vertex_buffer, batch_index_buffer) =
+            Self::create_glyph_buffers(device, "Text Batch", INITIAL_BATCH_GLYPHS);
+        let (label_vertex_buffer, label_index_buffer) =
+            Self::create_glyph_buffers(device, "Text Label", INITIAL_LABEL_GLYPHS);
+
+        // Labels drawn with their own camera need a bind group of their own
+        let label_camera_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Text Label Camera"),
+            size: std::mem::size_of::<CameraUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let label_camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Text Label Camera Bind Group"),
+            layout: camera_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: label_camera_buffer.as_entire_binding(),
+            }],
         });
 
         Ok(Self {
             pipeline,
             pipeline_fixed,
+            pipeline_overlay,
+            pipeline_fixed_overlay,
             font_bind_group,
             font_texture,
             atlas,
+            batch: TextBatch::default(),
+            batch_vertex_buffer,
+            batch_index_buffer,
+            batch_capacity: INITIAL_BATCH_GLYPHS,
+            label_camera_buffer,
+            label_camera_bind_group,
+            label_vertex_buffer,
+            label_index_buffer,
+            label_capacity: INITIAL_LABEL_GLYPHS,
         })
     }
 
+    fn create_glyph_buffers(
+        device: &wgpu::Device,
+        label: &str,
+        glyphs: usize,
+    ) -> (wgpu::Buffer, wgpu::Buffer) {
+        let vertices = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(&format!("{label} Vertices")),
+            size: (glyphs * 4 * std::mem::size_of::<TextVertex>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let indices = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(&format!("{label} Indices")),
+            size: (glyphs * 6 * std::mem::size_of::<u32>()) as u64,
+            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        (vertices, indices)
+    }
+
+    /// Draw every visible component in `texts` from one shared buffer
+    ///
+    /// Issues one draw call per billboard/depth-test combination present,
+    /// which is a single draw for a frame of ordinary labels. Returns the
+    /// uploaded batch.
+    pub fn render_batch<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        texts: &[&Text3D],
+    ) -> &'a TextBatch {
+        self.batch.rebuild(&self.atlas, texts);
+        if self.batch.is_empty() {
+            return &self.batch;
+        }
+
+        if self.batch.glyph_count > self.batch_capacity {
+            self.batch_capacity = self.batch.glyph_count.next_power_of_two();
+            (self.batch_vertex_buffer, self.batch_index_buffer) =
+                Self::create_glyph_buffers(device, "Text Batch", self.batch_capacity);
+        }
+        queue.write_buffer(
+            &self.batch_vertex_buffer,
+            0,
+            bytemuck::cast_slice(&self.batch.vertices),
+        );
+        queue.write_buffer(
+            &self.batch_index_buffer,
+            0,
+            bytemuck::cast_slice(&self.batch.indices),
+        );
+
+        let this: &'a Self = self;
+        pass.set_bind_group(0, camera_bind_group, &[]);
+        pass.set_bind_group(1, &this.font_bind_group, &[]);
+        pass.set_vertex_buffer(0, this.batch_vertex_buffer.slice(..));
+        pass.set_index_buffer(this.batch_index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        for draw in &this.batch.draws {
+            pass.set_pipeline(this.pipeline_variant(draw.billboard, draw.depth_test));
+            pass.draw_indexed(draw.indices.clone(), 0, 0..1);
+        }
+        &this.batch
+    }
+
+    /// Draw a single label as seen by `camera`
+    ///
+    /// Uploads the label's glyph quads (tinted by its color and faded by its
+    /// distance from the camera) and the camera into buffers owned by the
+    /// renderer, then issues one draw. Returns the number of glyphs drawn.
+    /// Use [`Self::render_batch`] for many labels in one pass.
+    pub fn draw_label<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        pass: &mut wgpu::RenderPass<'a>,
+        label: &Label3D,
+        camera: &CameraUniform,
+    ) -> usize {
+        let camera_position = Vec3::new(
+            camera.camera_pos[0],
+            camera.camera_pos[1],
+            camera.camera_pos[2],
+        );
+        let (vertices, indices) = build_label_mesh(&self.atlas, label, camera_position);
+        if indices.is_empty() {
+            return 0;
+        }
+
+        let glyphs = vertices.len() / 4;
+        if glyphs > self.label_capacity {
+            self.label_capacity = glyphs.next_power_of_two();
+            (self.label_vertex_buffer, self.label_index_buffer) =
+                Self::create_glyph_buffers(device, "Text Label", self.label_capacity);
+        }
+        queue.write_buffer(&self.label_camera_buffer, 0, bytemuck::bytes_of(camera));
+        queue.write_buffer(
+            &self.label_vertex_buffer,
+            0,
+            bytemuck::cast_slice(&vertices),
+        );
+        queue.write_buffer(&self.label_index_buffer, 0, bytemuck::cast_slice(&indices));
+
+        let this: &'a Self = self;
+        pass.set_pipeline(this.pipeline_for(label.text()));
+        pass.set_bind_group(0, &this.label_camera_bind_group, &[]);
+        pass.set_bind_group(1, &this.font_bind_group, &[]);
+        pass.set_vertex_buffer(0, this.label_vertex_buffer.slice(..));
+        pass.set_index_buffer(this.label_index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        glyphs
+    }
+
     /// Generate mesh for a Text3D component
     pub fn generate_text_mesh(&self, text: &Text3D) -> (Vec<TextVertex>, Vec<u32>) {
         build_text_mesh(&self.atlas, text)
     }
 
-    /// Get the render pipeline
+    /// Wrap and align a Text3D component, returning its glyphs and bounding box
+    ///
+    /// Layout containers can size around [`TextBlockLayout::size`].
+    pub fn layout(&self, text: &Text3D) -> TextBlockLayout {
+        layout_text_block(&self.atlas, text)
+    }
+
+    /// Measure text before rendering, for centering labels and sizing panels.
+    ///
+    /// Returns `(width, height, line_count)` in the same units as `scale`.
+    pub fn measure(&self, text: &str, scale: f32) -> (f32, f32, usize) {
+        measure_text_block(&self.atlas, text, scale)
+    }
+
+    /// Get the depth-tested render pipeline
     pub fn pipeline(&self, billboard: bool) -> &wgpu::RenderPipeline {
-        if billboard {
-            &self.pipeline
-        } else {
-            &self.pipeline_fixed
+        self.pipeline_variant(billboard, true)
+    }
+
+    /// Get the pipeline matching a text component's billboard and depth-test flags
+    pub fn pipeline_for(&self, text: &Text3D) -> &wgpu::RenderPipeline {
+        self.pipeline_variant(text.billboard, text.depth_test)
+    }
+
+    /// Get the pipeline for a billboard/fixed and depth-tested/always-on-top combination
+    pub fn pipeline_variant(&self, billboard: bool, depth_test: bool) -> &wgpu::RenderPipeline {
+        match (billboard, depth_test) {
+            (true, true) => &self.pipeline,
+            (false, true) => &self.pipeline_fixed,
+            (true, false) => &self.pipeline_overlay,
+            (false, false) => &self.pipeline_fixed_overlay,
         }
     }
 
@@ -326,7 +726,7 @@ impl TextRenderer {
 mod tests {
     use super::*;
     use crate::render::font_atlas::FontAtlasBuilder;
-    use glam::Vec3;
+    use mdminecraft_render::{record_texture_readback, Camera};
     use std::path::PathBuf;
 
     fn test_font_path() -> PathBuf {
@@ -368,6 +768,126 @@ mod tests {
         assert_eq!(indices[5], 3);
     }
 
+    #[test]
+    fn measure_multiline_uses_longest_line() {
+        let atlas = load_test_atlas();
+        let (width, height, line_count) = measure_text_block(&atlas, "AB\nA", 2.0);
+
+        assert_eq!(line_count, 2);
+        assert_eq!(width, atlas.measure_text("AB", 2.0));
+        assert!(width > atlas.measure_text("A", 2.0));
+        assert_eq!(height, atlas.line_height(2.0) * 2.0);
+
+        assert_eq!(measure_text_block(&atlas, "", 2.0), (0.0, 0.0, 0));
+    }
+
+    fn wrapping_atlas() -> FontAtlas {
+        let path = test_font_path();
+        FontAtlasBuilder::from_file(path.to_str().expect("utf8 path"))
+            .expect("load font")
+            .with_font_size(8.0)
+            .with_padding(1)
+            .with_chars(vec!['A', 'B', ' '])
+            .build()
+            .expect("build atlas")
+    }
+
+    #[test]
+    fn long_text_wraps_at_whitespace_within_max_width() {
+        let atlas = wrapping_atlas();
+        let two_words = atlas.measure_text("AB AB", 1.0);
+        let text = Text3D::new(Vec3::ZERO, "AB AB AB AB AB\nA")
+            .with_font_size(1.0)
+            .with_max_width(two_words + 0.01);
+
+        // Two words per line, then the explicit break starts a fresh line.
+        let lines = wrap_lines(&atlas, &text.text, 1.0, text.max_width);
+        assert_eq!(lines, ["AB AB", "AB AB", "AB", "A"]);
+
+        let layout = layout_text_block(&atlas, &text);
+        assert_eq!(layout.line_count, 4);
+        assert!((layout.size().x - two_words).abs() < 1e-5);
+        assert!((layout.size().y - 4.0 * text.line_spacing).abs() < 1e-5);
+
+        // Without a max width only the newline breaks.
+        let unwrapped = layout_text_block(&atlas, &text.clone().with_max_width(0.0));
+        assert_eq!(unwrapped.line_count, 2);
+        assert!(unwrapped.size().x > two_words);
+
+        // A word wider than the limit still gets a line of its own.
+        assert_eq!(wrap_lines(&atlas, "ABAB A", 1.0, 0.1), ["ABAB", "A"]);
+    }
+
+    #[test]
+    fn center_alignment_offsets_each_line_by_half_its_width() {
+        let atlas = wrapping_atlas();
+        let text = Text3D::new(Vec3::ZERO, "ABB\nA").with_font_size(1.0);
+        let left = layout_text_block(&atlas, &text.clone().with_alignment(TextAlignment::Left));
+        let center = layout_text_block(&atlas, &text.clone().with_alignment(TextAlignment::Center));
+        let right = layout_text_block(&atlas, &text.with_alignment(TextAlignment::Right));
+
+        let widths = [atlas.measure_text("ABB", 1.0), atlas.measure_text("A", 1.0)];
+        let line_of_glyph = [0, 0, 0, 1];
+        for (i, &line) in line_of_glyph.iter().enumerate() {
+            let width = widths[line];
+            let offset = center.glyphs[i].position_x - left.glyphs[i].position_x;
+            assert!((offset + width * 0.5).abs() < 1e-5, "glyph {i}");
+            let offset = right.glyphs[i].position_x - left.glyphs[i].position_x;
+            assert!((offset + width).abs() < 1e-5, "glyph {i}");
+        }
+        // The second line sits one line advance below the first.
+        let drop = left.glyphs[0].position_y - left.glyphs[3].position_y;
+        assert!((drop - 1.2).abs() < 1e-5);
+
+        // The centered block straddles the position.
+        assert!((center.min.x + widths[0] * 0.5).abs() < 1e-5);
+        assert!((center.max.x - widths[0] * 0.5).abs() < 1e-5);
+        assert_eq!(left.min.x, 0.0);
+    }
+
+    #[test]
+    fn missing_glyphs_use_notdef_and_control_chars_are_skipped() {
+        let atlas = load_test_atlas();
+        let text = Text3D::new(Vec3::ZERO, "A\u{20ac}\tB\u{7}").with_font_size(1.0);
+        let (vertices, indices) = build_text_mesh(&atlas, &text);
+
+        // 'A', the notdef box for the euro sign, and 'B'; tab and bell draw nothing.
+        assert_eq!(vertices.len(), 12);
+        assert_eq!(indices.len(), 18);
+
+        let notdef = atlas.notdef_glyph();
+        assert_eq!(vertices[4].uv, [notdef.uv_min.0, notdef.uv_min.1]);
+        assert_eq!(
+            atlas.measure_text("A\u{20ac}", 1.0),
+            atlas.measure_text("A", 1.0) + notdef.advance_width / atlas.font_size
+        );
+    }
+
+    #[test]
+    fn batching_three_labels_merges_into_one_buffer() {
+        let atlas = load_test_atlas();
+        let a = Text3D::new(Vec3::ZERO, "AB").with_font_size(1.0);
+        let b = Text3D::new(Vec3::new(2.0, 0.0, 0.0), "A").with_font_size(1.0);
+        let c = Text3D::new(Vec3::new(4.0, 0.0, 0.0), "BBA").with_font_size(1.0);
+
+        let batch = TextBatch::build(&atlas, &[&a, &b, &c]);
+
+        assert_eq!(batch.glyph_count, 6);
+        assert_eq!(batch.vertices.len(), 6 * 4);
+        assert_eq!(batch.indices.len(), 6 * 6);
+        assert_eq!(
+            batch.draws,
+            vec![TextBatchDraw {
+                billboard: true,
+                depth_test: true,
+                indices: 0..36,
+            }]
+        );
+        // Later labels index past the earlier labels' vertices.
+        assert_eq!(batch.indices[12], 8);
+        assert_eq!(*batch.indices.iter().max().unwrap(), 23);
+    }
+
     #[test]
     fn build_text_mesh_empty_text_is_empty() {
         let atlas = load_test_atlas();
@@ -376,4 +896,150 @@ mod tests {
         assert!(vertices.is_empty());
         assert!(indices.is_empty());
     }
+
+    #[test]
+    fn label_mesh_is_tinted_and_faded() {
+        let atlas = load_test_atlas();
+        let label = Label3D::new(Vec3::ZERO, "AB")
+            .with_font_size(1.0)
+            .with_color([1.0, 0.5, 0.0, 0.8])
+            .with_fade_distance(10.0, 20.0);
+
+        let (vertices, indices) = build_label_mesh(&atlas, &label, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(indices.len(), 12);
+        assert!(vertices.iter().all(|v| v.color == [1.0, 0.5, 0.0, 0.8]));
+
+        let (vertices, _) = build_label_mesh(&atlas, &label, Vec3::new(15.0, 0.0, 0.0));
+        assert!(vertices.iter().all(|v| (v.color[3] - 0.4).abs() < 1e-6));
+
+        let (vertices, indices) = build_label_mesh(&atlas, &label, Vec3::new(25.0, 0.0, 0.0));
+        assert!(vertices.is_empty() && indices.is_empty());
+    }
+
+    fn test_device() -> (wgpu::Instance, wgpu::Device, wgpu::Queue) {
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter: true,
+        }))
+        .expect("adapter");
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+                .expect("device");
+
+        (instance, device, queue)
+    }
+
+    #[test]
+    fn draw_label_renders_tinted_glyph_offscreen() {
+        const SIZE: u32 = 64;
+        let format = wgpu::TextureFormat::Rgba8Unorm;
+        let (_instance, device, queue) = test_device();
+
+        let camera_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Test Camera Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        // A full block glyph has solid coverage, so its pixels are exactly the tint.
+        let path = test_font_path();
+        let atlas = FontAtlasBuilder::from_file(path.to_str().expect("utf8 path"))
+            .expect("load font")
+            .with_font_size(32.0)
+            .with_padding(1)
+            .with_chars(vec!['\u{2588}'])
+            .build()
+            .expect("build atlas");
+        let mut renderer =
+            TextRenderer::new(&device, &queue, format, &camera_layout, atlas).expect("renderer");
+
+        let extent = wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        };
+        let target = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Test Label Target"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let depth = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Test Label Depth"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Depth32Float,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut camera = Camera::new(1.0);
+        camera.position = Vec3::new(-3.0, 0.5, 0.0);
+        camera.look_at(Vec3::new(0.0, 0.5, 0.0));
+        let camera = CameraUniform::from_camera(&camera);
+        let label = Label3D::new(Vec3::ZERO, "\u{2588}").with_color([0.2, 0.8, 0.4, 1.0]);
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        let glyphs = {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Test Label Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            renderer.draw_label(&device, &queue, &mut pass, &label, &camera)
+        };
+        assert_eq!(glyphs, 1);
+        let readback =
+            record_texture_readback(&device, &mut encoder, &target, format, (SIZE, SIZE));
+        queue.submit(Some(encoder.finish()));
+        let pixels = readback.read_rgba8(&device).expect("read back");
+
+        let lit: Vec<&[u8]> = pixels
+            .chunks_exact(4)
+            .filter(|p| p[..3] != [0, 0, 0])
+            .collect();
+        assert!(!lit.is_empty(), "label drew no pixels");
+        // Edges blend toward the black background, but every drawn pixel keeps
+        // the tint's hue and the glyph interior is the tint itself.
+        assert!(lit.iter().all(|p| p[1] >= p[0] && p[1] >= p[2]));
+        let tint = [51u8, 204, 102];
+        assert!(lit
+            .iter()
+            .any(|p| p[..3].iter().zip(tint).all(|(&c, t)| c.abs_diff(t) <= 2)));
+    }
 }
@@ -14,6 +14,21 @@ var atlas_texture: texture_2d<f32>;
 @group(1) @binding(1)
 var atlas_sampler: sampler;
 
+// Soft particles: fade depth-tested billboards where they meet scene geometry.
+struct SoftParticleUniform {
+    near: f32,
+    far: f32,
+    // World-space distance over which the fade happens; <= 0 disables it.
+    fade_distance: f32,
+    _pad: f32,
+}
+
+@group(2) @binding(0)
+// Bound as an unfilterable float texture so the GL backend can load from it.
+var scene_depth: texture_2d<f32>;
+@group(2) @binding(1)
+var<uniform> soft: SoftParticleUniform;
+
 struct VSOut {
     @builtin(position) clip: vec4<f32>,
     @location(0) uv: vec2<f32>,
@@ -65,6 +80,11 @@ fn vs_main(
     return out;
 }
 
+// Convert a [0, 1] perspective depth value into view-space distance.
+fn linear_depth(depth: f32) -> f32 {
+    return soft.near * soft.far / (soft.far - depth * (soft.far - soft.near));
+}
+
 @fragment
 fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
     var c = textureSample(atlas_texture, atlas_sampler, in.uv) * in.color;
@@ -73,5 +93,11 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
         let lit = mix(0.35, 1.0, l);
         c = vec4<f32>(c.rgb * lit, c.a);
     }
+    if ((in.flags & 0x2u) == 0u && soft.fade_distance > 0.0) {
+        let scene = textureLoad(scene_depth, vec2<i32>(in.clip.xy), 0).r;
+        let gap = linear_depth(scene) - linear_depth(in.clip.z);
+        // Colour is premultiplied, so scale every channel.
+        c = c * clamp(gap / soft.fade_distance, 0.0, 1.0);
+    }
     return c;
 }
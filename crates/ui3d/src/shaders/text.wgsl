@@ -3,13 +3,10 @@
 // This shader renders text using a font atlas texture with signed distance fields (SDF).
 // It supports billboarding, text color, and smooth edges at any scale.
 
-// Camera uniforms (shared with other pipelines)
+// Camera uniforms (mdminecraft_render::CameraUniform, shared with other pipelines)
 struct CameraUniforms {
     view_proj: mat4x4<f32>,
-    view: mat4x4<f32>,
-    projection: mat4x4<f32>,
-    camera_position: vec3<f32>,
-    _padding: f32,
+    camera_pos: vec4<f32>,
 }
 
 @group(0) @binding(0)
@@ -42,7 +39,7 @@ fn vs_main(vertex: VertexInput) -> VertexOutput {
 
     // Calculate billboard orientation
     // Make the quad face the camera
-    let to_camera = normalize(camera.camera_position - vertex.billboard_center);
+    let to_camera = normalize(camera.camera_pos.xyz - vertex.billboard_center);
     let up = vec3<f32>(0.0, 1.0, 0.0);
     let right = normalize(cross(up, to_camera));
     let billboard_up = cross(to_camera, right);
@@ -67,16 +64,25 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Sample the font atlas
     let distance = textureSample(font_texture, font_sampler, in.uv).r;
 
-    // SDF-based alpha
-    // For now, we're using regular bitmap, so just use the sampled value as alpha
-    // TODO: When we implement true SDF, use:
-    // let alpha = smoothstep(0.45, 0.55, distance);
+    // Coverage atlas: the sampled value is the alpha
     let alpha = distance;
 
     // Output color with alpha
     return vec4<f32>(in.color.rgb, in.color.a * alpha);
 }
 
+// Signed distance field atlas: the glyph edge sits at 0.5
+@fragment
+fn fs_main_sdf(in: VertexOutput) -> @location(0) vec4<f32> {
+    let distance = textureSample(font_texture, font_sampler, in.uv).r;
+
+    // Antialias over roughly one screen pixel regardless of scale
+    let width = max(fwidth(distance), 1e-4);
+    let alpha = smoothstep(0.5 - width, 0.5 + width, distance);
+
+    return vec4<f32>(in.color.rgb, in.color.a * alpha);
+}
+
 // Variant without billboarding for fixed-orientation text
 @vertex
 fn vs_main_fixed(vertex: VertexInput) -> VertexOutput {
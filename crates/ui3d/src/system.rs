@@ -0,0 +1,306 @@
+//! UI3D System - GPU bootstrap and single draw entry point
+//!
+//! [`Ui3dSystem`] owns the text renderer and (with `ui3d_billboards`) the
+//! billboard renderer, so integrating 3D UI is one constructor call plus one
+//! `render` call per frame inside an existing render pass.
+
+#[cfg(feature = "ui3d_billboards")]
+use crate::components::UIComponent;
+use crate::components::{Billboard, Text3D};
+use crate::render::font_atlas::FontAtlas;
+use crate::render::text_renderer::TextRenderer;
+#[cfg(feature = "ui3d_billboards")]
+use crate::render::{BillboardEmitter, BillboardInstance, BillboardRenderer, BillboardView};
+use anyhow::Result;
+use mdminecraft_render::Camera;
+
+/// Components to draw in one frame
+#[derive(Debug, Default)]
+pub struct Ui3dComponents<'a> {
+    /// Text (use [`crate::Label3D::text`] for labels)
+    pub texts: Vec<&'a Text3D>,
+
+    /// Billboards (drawn only with the `ui3d_billboards` feature)
+    pub billboards: Vec<&'a Billboard>,
+}
+
+/// GPU resources for 3D UI and the per-frame draw entry point
+pub struct Ui3dSystem {
+    /// Text renderer; absent until a font atlas is supplied
+    text: Option<TextRenderer>,
+
+    #[cfg(feature = "ui3d_billboards")]
+    billboards: BillboardRenderer,
+    #[cfg(feature = "ui3d_billboards")]
+    billboard_emitter: BillboardEmitter,
+    /// Plain white atlas so untextured billboards show their tint.
+    #[cfg(feature = "ui3d_billboards")]
+    #[allow(dead_code)]
+    billboard_atlas: wgpu::Texture,
+}
+
+impl Ui3dSystem {
+    /// Create every ui3d renderer against the caller's camera bind group layout
+    ///
+    /// Text is drawn only when a `font` atlas is supplied.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        surface_format: wgpu::TextureFormat,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        font: Option<FontAtlas>,
+    ) -> Result<Self> {
+        let text = font
+            .map(|atlas| {
+                TextRenderer::new(
+                    device,
+                    queue,
+                    surface_format,
+                    camera_bind_group_layout,
+                    atlas,
+                )
+            })
+            .transpose()?;
+
+        #[cfg(feature = "ui3d_billboards")]
+        let (billboards, billboard_atlas) = {
+            let size = wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            };
+            let atlas = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("UI3D Default Billboard Atlas"),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &atlas,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &[255, 255, 255, 255],
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4),
+                    rows_per_image: Some(1),
+                },
+                size,
+            );
+
+            let view = atlas.create_view(&Default::default());
+            let sampler = device.create_sampler(&wgpu::SamplerDescriptor::default());
+            let renderer = BillboardRenderer::new(
+                device,
+                surface_format,
+                camera_bind_group_layout,
+                &view,
+                &sampler,
+            )?;
+            (renderer, atlas)
+        };
+
+        tracing::info!(text = text.is_some(), "UI3D system initialized");
+
+        Ok(Self {
+            text,
+            #[cfg(feature = "ui3d_billboards")]
+            billboards,
+            #[cfg(feature = "ui3d_billboards")]
+            billboard_emitter: BillboardEmitter::default(),
+            #[cfg(feature = "ui3d_billboards")]
+            billboard_atlas,
+        })
+    }
+
+    /// Get the text renderer, if a font was supplied
+    pub fn text_renderer(&self) -> Option<&TextRenderer> {
+        self.text.as_ref()
+    }
+
+    /// Draw `components` into `pass`, which must have a `Depth32Float` attachment
+    ///
+    /// `viewport_height` is the pass's target height in pixels, used to size
+    /// screen-space billboards. Billboards draw first so text stays legible
+    /// on top of them.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        camera: &Camera,
+        viewport_height: f32,
+        components: &Ui3dComponents,
+    ) -> Result<()> {
+        let Self {
+            text,
+            #[cfg(feature = "ui3d_billboards")]
+            billboards,
+            #[cfg(feature = "ui3d_billboards")]
+            billboard_emitter,
+            ..
+        } = self;
+
+        #[cfg(feature = "ui3d_billboards")]
+        {
+            for (id, billboard) in components.billboards.iter().enumerate() {
+                if billboard.is_visible() {
+                    billboard_emitter
+                        .submit(id as u32, BillboardInstance::from_billboard(billboard));
+                }
+            }
+            let view = BillboardView::from_camera(camera, viewport_height);
+            billboards.render_in_pass(
+                device,
+                queue,
+                pass,
+                camera_bind_group,
+                &view,
+                billboard_emitter,
+            )?;
+        }
+        #[cfg(not(feature = "ui3d_billboards"))]
+        let _ = (camera, viewport_height);
+
+        if let Some(text) = text.as_mut() {
+            text.render_batch(device, queue, pass, camera_bind_group, &components.texts);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdminecraft_render::CameraUniform;
+
+    fn test_device() -> (wgpu::Instance, wgpu::Device, wgpu::Queue) {
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter: true,
+        }))
+        .expect("adapter");
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+                .expect("device");
+
+        (instance, device, queue)
+    }
+
+    fn target(device: &wgpu::Device, format: wgpu::TextureFormat) -> wgpu::TextureView {
+        device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("UI3D System Test Target"),
+                size: wgpu::Extent3d {
+                    width: 4,
+                    height: 4,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            })
+            .create_view(&Default::default())
+    }
+
+    #[test]
+    fn headless_system_renders_empty_frame() {
+        let (_instance, device, queue) = test_device();
+        let format = wgpu::TextureFormat::Bgra8UnormSrgb;
+
+        let camera_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Test Camera BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let camera = Camera::new(1.0);
+        let camera_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Test Camera Buffer"),
+            size: std::mem::size_of::<CameraUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        queue.write_buffer(
+            &camera_buffer,
+            0,
+            bytemuck::bytes_of(&CameraUniform::from_camera(&camera)),
+        );
+        let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Test Camera BG"),
+            layout: &camera_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: camera_buffer.as_entire_binding(),
+            }],
+        });
+
+        let mut system =
+            Ui3dSystem::new(&device, &queue, format, &camera_layout, None).expect("system");
+        assert!(system.text_renderer().is_none());
+
+        let color = target(&device, format);
+        let depth = target(&device, wgpu::TextureFormat::Depth32Float);
+        let mut encoder = device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("UI3D System Test Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &color,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &depth,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            system
+                .render(
+                    &device,
+                    &queue,
+                    &mut pass,
+                    &camera_bind_group,
+                    &camera,
+                    4.0,
+                    &Ui3dComponents::default(),
+                )
+                .expect("render");
+        }
+        queue.submit(Some(encoder.finish()));
+        device.poll(wgpu::Maintain::Wait);
+    }
+}
@@ -5,10 +5,6 @@ use crate::{interactive_blocks, ChestState, DispenserState, HopperState, ItemMan
 use mdminecraft_core::{DimensionId, ItemStack as CoreItemStack};
 use std::collections::{BTreeMap, HashMap};
 
-fn stacks_match_for_merge(a: &CoreItemStack, b: &CoreItemStack) -> bool {
-    a.item_type == b.item_type && a.durability == b.durability && a.enchantments == b.enchantments
-}
-
 pub fn comparator_signal_from_core_slots(slots: &[Option<CoreItemStack>]) -> u8 {
     if slots.is_empty() {
         return 0;
@@ -90,7 +86,7 @@ pub fn can_insert_one_into_core_slots(
     debug_assert_eq!(stack.count, 1);
 
     for existing in slots.iter().flatten() {
-        if stacks_match_for_merge(existing, stack) && existing.count < existing.max_stack_size() {
+        if existing.can_merge_with(stack) && existing.count < existing.max_stack_size() {
             return true;
         }
     }
@@ -105,7 +101,7 @@ pub fn insert_one_into_core_slots(
     debug_assert_eq!(stack.count, 1);
 
     for existing in slots.iter_mut().flatten() {
-        if stacks_match_for_merge(existing, &stack) && existing.count < existing.max_stack_size() {
+        if existing.can_merge_with(&stack) && existing.count < existing.max_stack_size() {
             existing.count = existing.count.saturating_add(1);
             return true;
         }
@@ -155,7 +151,7 @@ fn restore_one_into_core_slot(
     }
 
     match slots[idx].as_mut() {
-        Some(existing) if stacks_match_for_merge(existing, &stack) => {
+        Some(existing) if existing.can_merge_with(&stack) => {
             existing.count = existing.count.saturating_add(1);
         }
         None => {
@@ -1,10 +1,11 @@
 //! Block properties - hardness, mining requirements, drops
 
 use crate::{
-    farming_blocks, interactive_blocks, redstone_blocks, BLOCK_AIR, BLOCK_BEDROCK, BLOCK_BOOKSHELF,
-    BLOCK_BROWN_MUSHROOM, BLOCK_CAVE_VINES, BLOCK_CLAY, BLOCK_COAL_ORE, BLOCK_COBBLESTONE,
-    BLOCK_COBBLESTONE_WALL, BLOCK_CRAFTING_TABLE, BLOCK_CRYING_OBSIDIAN, BLOCK_DIAMOND_ORE,
-    BLOCK_DIRT, BLOCK_DOUBLE_OAK_SLAB, BLOCK_DOUBLE_STONE_BRICK_SLAB, BLOCK_DOUBLE_STONE_SLAB,
+    farming_blocks, interactive_blocks, redstone_blocks, BlockOpacityProvider, CollisionShape,
+    CollisionShapeRegistry, BLOCK_AIR, BLOCK_BEDROCK, BLOCK_BOOKSHELF, BLOCK_BROWN_MUSHROOM,
+    BLOCK_CAVE_VINES, BLOCK_CLAY, BLOCK_COAL_ORE, BLOCK_COBBLESTONE, BLOCK_COBBLESTONE_WALL,
+    BLOCK_CRAFTING_TABLE, BLOCK_CRYING_OBSIDIAN, BLOCK_DIAMOND_ORE, BLOCK_DIRT,
+    BLOCK_DOUBLE_OAK_SLAB, BLOCK_DOUBLE_STONE_BRICK_SLAB, BLOCK_DOUBLE_STONE_SLAB,
     BLOCK_END_PORTAL, BLOCK_END_PORTAL_FRAME, BLOCK_END_STONE, BLOCK_FIRE, BLOCK_FURNACE,
     BLOCK_FURNACE_LIT, BLOCK_GHAST_TEAR_ORE, BLOCK_GLASS, BLOCK_GLISTERING_MELON_ORE,
     BLOCK_GLOWSTONE, BLOCK_GLOWSTONE_DUST_ORE, BLOCK_GLOW_LICHEN, BLOCK_GOLD_ORE, BLOCK_GRASS,
@@ -18,6 +19,38 @@ use crate::{
 };
 use mdminecraft_core::{ToolMaterial, ToolType};
 
+/// Tags that mark the best tool for a block, by tag path.
+const MINEABLE_TAGS: [(&str, ToolType); 4] = [
+    ("mineable/pickaxe", ToolType::Pickaxe),
+    ("mineable/axe", ToolType::Axe),
+    ("mineable/shovel", ToolType::Shovel),
+    ("mineable/hoe", ToolType::Hoe),
+];
+
+/// Tag path for blocks that entities pass through.
+const NON_SOLID_TAG: &str = "non_solid";
+
+/// Pack-defined block metadata read by [`BlockPropertiesRegistry::from_registry`].
+///
+/// Implemented by the asset pack's block registry. Base light emission comes
+/// from the [`BlockOpacityProvider`] supertrait.
+pub trait BlockDescriptorSource: BlockOpacityProvider {
+    /// Number of defined blocks; ids run from `0` to `block_count() - 1`.
+    fn block_count(&self) -> usize;
+
+    /// Minimum tool tier needed to harvest the block, if the pack sets one.
+    fn harvest_tier(&self, block_id: u16) -> Option<ToolMaterial>;
+
+    /// Whether the block has a tag with this path in any namespace
+    /// (e.g. `"mineable/pickaxe"` matches `mdm:mineable/pickaxe`).
+    fn has_tag_path(&self, block_id: u16, tag_path: &str) -> bool;
+
+    /// Collision shape the pack assigns to the block, if any.
+    fn collision_shape(&self, _block_id: u16) -> Option<CollisionShape> {
+        None
+    }
+}
+
 /// Properties of a block type
 #[derive(Debug, Clone)]
 pub struct BlockProperties {
@@ -35,6 +68,12 @@ pub struct BlockProperties {
 
     /// Whether this block is solid (affects collision)
     pub is_solid: bool,
+
+    /// Resistance to explosions (higher absorbs more blast energy)
+    pub blast_resistance: f32,
+
+    /// Block-light level emitted by this block (0-15)
+    pub light_emission: u8,
 }
 
 impl Default for BlockProperties {
@@ -45,6 +84,8 @@ impl Default for BlockProperties {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 1.0,
+            light_emission: 0,
         }
     }
 }
@@ -58,6 +99,8 @@ impl BlockProperties {
             required_tier: None,
             instant_break: true,
             is_solid: false,
+            blast_resistance: 0.0,
+            light_emission: 0,
         }
     }
 
@@ -69,6 +112,8 @@ impl BlockProperties {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.5,
+            light_emission: 0,
         }
     }
 
@@ -80,6 +125,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Wood),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 6.0,
+            light_emission: 0,
         }
     }
 
@@ -91,6 +138,8 @@ impl BlockProperties {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 3.0,
+            light_emission: 0,
         }
     }
 
@@ -102,6 +151,8 @@ impl BlockProperties {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.5,
+            light_emission: 0,
         }
     }
 
@@ -113,6 +164,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Stone),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 3.0,
+            light_emission: 0,
         }
     }
 
@@ -124,6 +177,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Iron),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 3.0,
+            light_emission: 0,
         }
     }
 
@@ -135,6 +190,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Wood),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 3.0,
+            light_emission: 0,
         }
     }
 
@@ -146,6 +203,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Iron),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 3.0,
+            light_emission: 0,
         }
     }
 
@@ -157,6 +216,8 @@ impl BlockProperties {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.3,
+            light_emission: 0,
         }
     }
 
@@ -168,6 +229,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Diamond),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 1_200.0,
+            light_emission: 0,
         }
     }
 
@@ -179,6 +242,8 @@ impl BlockProperties {
             required_tier: Some(ToolMaterial::Diamond),
             instant_break: false,
             is_solid: true,
+            blast_resistance: 3_600_000.0,
+            light_emission: 0,
         }
     }
 
@@ -190,6 +255,8 @@ impl BlockProperties {
             required_tier: None,
             instant_break: true,
             is_solid: false,
+            blast_resistance: 100.0,
+            light_emission: 0,
         }
     }
 
@@ -256,6 +323,7 @@ impl BlockProperties {
 /// Block properties registry
 pub struct BlockPropertiesRegistry {
     properties: Vec<BlockProperties>,
+    collision_shapes: CollisionShapeRegistry,
 }
 
 impl BlockPropertiesRegistry {
@@ -281,6 +349,8 @@ impl BlockPropertiesRegistry {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.5,
+            light_emission: 0,
         };
         properties[BLOCK_SNOW as usize] = BlockProperties {
             hardness: 0.2,
@@ -288,6 +358,8 @@ impl BlockPropertiesRegistry {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.1,
+            light_emission: 0,
         };
         properties[BLOCK_CLAY as usize] = BlockProperties {
             hardness: 0.6,
@@ -295,6 +367,8 @@ impl BlockPropertiesRegistry {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.6,
+            light_emission: 0,
         };
         properties[BLOCK_BEDROCK as usize] = BlockProperties::bedrock();
         properties[BLOCK_END_PORTAL_FRAME as usize] = BlockProperties::bedrock();
@@ -340,6 +414,8 @@ impl BlockPropertiesRegistry {
             required_tier: None,
             instant_break: false,
             is_solid: true,
+            blast_resistance: 0.3,
+            light_emission: 15,
         };
 
         // Non-solid interaction blocks (collision should ignore these; shapes handled elsewhere).
@@ -386,7 +462,55 @@ impl BlockPropertiesRegistry {
         properties[BLOCK_HANGING_ROOTS as usize] = BlockProperties::air();
         properties[BLOCK_SCULK_VEIN as usize] = BlockProperties::air();
 
-        Self { properties }
+        Self {
+            properties,
+            collision_shapes: CollisionShapeRegistry::new(),
+        }
+    }
+
+    /// Create a registry from pack-defined blocks.
+    ///
+    /// Starts from the built-in table ([`BlockPropertiesRegistry::new`]) and
+    /// overrides it with what the pack specifies: `harvest_level` sets the
+    /// required tier, a `mineable/<tool>` tag the best tool, a `non_solid` tag
+    /// turns off collision, `collision` fills [`Self::collision_shapes`], and
+    /// light emission always comes from the pack.
+    pub fn from_registry(registry: &impl BlockDescriptorSource) -> Self {
+        let mut properties = Self::new().properties;
+        if properties.len() < registry.block_count() {
+            properties.resize(registry.block_count(), BlockProperties::default());
+        }
+
+        for (id, props) in properties
+            .iter_mut()
+            .enumerate()
+            .take(registry.block_count())
+        {
+            let id = id as u16;
+            if let Some(tier) = registry.harvest_tier(id) {
+                props.required_tier = Some(tier);
+            }
+            if let Some((_, tool)) = MINEABLE_TAGS
+                .iter()
+                .find(|(tag, _)| registry.has_tag_path(id, tag))
+            {
+                props.best_tool = Some(*tool);
+            }
+            if registry.has_tag_path(id, NON_SOLID_TAG) {
+                props.is_solid = false;
+            }
+            props.light_emission = registry.base_block_light_emission(id);
+        }
+
+        Self {
+            properties,
+            collision_shapes: CollisionShapeRegistry::from_registry(registry),
+        }
+    }
+
+    /// Pack-defined collision shapes for blocks outside the built-in table.
+    pub fn collision_shapes(&self) -> &CollisionShapeRegistry {
+        &self.collision_shapes
     }
 
     /// Get properties for a block ID
@@ -493,4 +617,64 @@ mod tests {
         assert!(wheat.instant_break);
         assert!(!wheat.is_solid);
     }
+
+    struct PackBlocks {
+        harvest: Vec<Option<ToolMaterial>>,
+        tags: Vec<Vec<&'static str>>,
+        emission: Vec<u8>,
+    }
+
+    impl BlockOpacityProvider for PackBlocks {
+        fn light_opacity(&self, _block_id: u16) -> u8 {
+            15
+        }
+
+        fn base_block_light_emission(&self, block_id: u16) -> u8 {
+            self.emission[block_id as usize]
+        }
+    }
+
+    impl BlockDescriptorSource for PackBlocks {
+        fn block_count(&self) -> usize {
+            self.harvest.len()
+        }
+
+        fn harvest_tier(&self, block_id: u16) -> Option<ToolMaterial> {
+            self.harvest[block_id as usize]
+        }
+
+        fn has_tag_path(&self, block_id: u16, tag_path: &str) -> bool {
+            self.tags[block_id as usize].contains(&tag_path)
+        }
+    }
+
+    #[test]
+    fn test_from_registry_overrides_builtin_table() {
+        let count = 300;
+        let mut pack = PackBlocks {
+            harvest: vec![None; count],
+            tags: vec![Vec::new(); count],
+            emission: vec![0; count],
+        };
+        pack.harvest[BLOCK_DIRT as usize] = Some(ToolMaterial::Stone);
+        pack.tags[BLOCK_DIRT as usize] = vec!["mineable/pickaxe"];
+        pack.tags[BLOCK_GLASS as usize] = vec!["non_solid"];
+        pack.emission[299] = 12;
+
+        let registry = BlockPropertiesRegistry::from_registry(&pack);
+
+        let dirt = registry.get(BLOCK_DIRT);
+        assert_eq!(dirt.required_tier, Some(ToolMaterial::Stone));
+        assert_eq!(dirt.best_tool, Some(ToolType::Pickaxe));
+        assert!(!registry.get(BLOCK_GLASS).is_solid);
+        // Unspecified fields keep the built-in values.
+        let stone = registry.get(BLOCK_STONE);
+        assert_eq!(stone.required_tier, Some(ToolMaterial::Wood));
+        assert_eq!(stone.hardness, BlockProperties::stone().hardness);
+        // Ids past the built-in table get defaults plus pack data.
+        assert!(registry.get(299).is_solid);
+        assert_eq!(registry.get(299).light_emission, 12);
+        // Emission follows the pack even where the table disagrees.
+        assert_eq!(registry.get(BLOCK_GLOWSTONE).light_emission, 0);
+    }
 }
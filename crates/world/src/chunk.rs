@@ -362,6 +362,8 @@ bitflags::bitflags! {
     pub struct DirtyFlags: u8 {
         const MESH = 0b0000_0001;
         const LIGHT = 0b0000_0010;
+        /// Voxel data changed since the chunk was last saved.
+        const SAVE = 0b0000_0100;
     }
 }
 
@@ -420,7 +422,7 @@ impl Chunk {
         Self {
             position,
             sections,
-            dirty: DirtyFlags::all(),
+            dirty: DirtyFlags::MESH | DirtyFlags::LIGHT,
         }
     }
 
@@ -465,16 +467,29 @@ impl Chunk {
         let idx = Self::index(x, local_y, z);
         if self.sections[section].voxels[idx] != voxel {
             self.sections[section].voxels[idx] = voxel;
-            self.dirty.insert(DirtyFlags::MESH | DirtyFlags::LIGHT);
+            self.dirty.insert(DirtyFlags::all());
         }
     }
 
-    /// Consume and return the current dirty flags.
+    /// Consume and return the mesh/light dirty flags.
+    ///
+    /// [`DirtyFlags::SAVE`] is not reported or cleared here; it stays set until
+    /// [`Chunk::mark_saved`].
     pub fn take_dirty_flags(&mut self) -> DirtyFlags {
-        let flags = self.dirty;
-        self.dirty = DirtyFlags::empty();
+        let flags = self.dirty - DirtyFlags::SAVE;
+        self.dirty &= DirtyFlags::SAVE;
         flags
     }
+
+    /// Whether voxel data changed since the chunk was last saved or loaded.
+    pub fn needs_save(&self) -> bool {
+        self.dirty.contains(DirtyFlags::SAVE)
+    }
+
+    /// Record that the chunk's current voxel data is persisted.
+    pub fn mark_saved(&mut self) {
+        self.dirty.remove(DirtyFlags::SAVE);
+    }
 }
 
 #[cfg(test)]
@@ -582,6 +597,29 @@ mod tests {
         assert!(chunk.take_dirty_flags().is_empty());
     }
 
+    #[test]
+    fn save_flag_survives_mesh_consumption_until_marked_saved() {
+        let mut chunk = Chunk::new(ChunkPos::new(0, 0));
+        assert!(!chunk.needs_save());
+
+        chunk.set_voxel(
+            0,
+            0,
+            0,
+            Voxel {
+                id: BLOCK_STONE,
+                ..Default::default()
+            },
+        );
+        let flags = chunk.take_dirty_flags();
+        assert!(flags.contains(DirtyFlags::MESH));
+        assert!(!flags.contains(DirtyFlags::SAVE));
+        assert!(chunk.needs_save());
+
+        chunk.mark_saved();
+        assert!(!chunk.needs_save());
+    }
+
     #[test]
     fn test_chunk_pos_ordering() {
         // ChunkPos implements Ord for BTreeMap determinism
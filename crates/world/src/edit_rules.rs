@@ -0,0 +1,172 @@
+//! Edit rules - spawn protection and build-height limits for block edits.
+//!
+//! Every player-initiated place/break is validated against [`EditRules`]
+//! before the world is mutated. Denials carry a typed [`EditDenied`] reason
+//! so callers can surface a message instead of silently ignoring input.
+
+use crate::chunk::{WORLD_MAX_Y, WORLD_MIN_Y};
+use glam::IVec3;
+use std::fmt;
+
+/// Kind of edit being attempted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditKind {
+    Place,
+    Break,
+}
+
+/// Reason a block edit was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditDenied {
+    /// The target lies within the protected area around spawn.
+    SpawnProtected { radius: u32 },
+    /// The target is above the highest buildable Y.
+    AboveBuildLimit { max_y: i32 },
+    /// The target is below the lowest buildable Y.
+    BelowBuildLimit { min_y: i32 },
+}
+
+impl fmt::Display for EditDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditDenied::SpawnProtected { radius } => {
+                write!(f, "Cannot edit blocks within {radius} blocks of spawn")
+            }
+            EditDenied::AboveBuildLimit { max_y } => {
+                write!(f, "Height limit for building is {max_y}")
+            }
+            EditDenied::BelowBuildLimit { min_y } => {
+                write!(f, "Cannot build below Y={min_y}")
+            }
+        }
+    }
+}
+
+/// Configurable constraints on where blocks may be placed or broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditRules {
+    /// Horizontal (square) radius around spawn where non-operators cannot edit.
+    /// `0` disables spawn protection.
+    pub spawn_protection_radius: u32,
+    /// Lowest Y at which blocks may be placed.
+    pub min_build_y: i32,
+    /// Highest Y at which blocks may be placed.
+    pub max_build_y: i32,
+}
+
+impl Default for EditRules {
+    fn default() -> Self {
+        Self {
+            spawn_protection_radius: 0,
+            min_build_y: WORLD_MIN_Y,
+            max_build_y: WORLD_MAX_Y,
+        }
+    }
+}
+
+impl EditRules {
+    /// Check whether an edit at `pos` is allowed.
+    ///
+    /// Operators bypass spawn protection but not the build limit. The build
+    /// limit only applies to placement so players can still clear blocks
+    /// that were generated out of range.
+    pub fn check(
+        &self,
+        kind: EditKind,
+        pos: IVec3,
+        spawn: IVec3,
+        is_op: bool,
+    ) -> Result<(), EditDenied> {
+        if kind == EditKind::Place {
+            if pos.y > self.max_build_y {
+                return Err(EditDenied::AboveBuildLimit {
+                    max_y: self.max_build_y,
+                });
+            }
+            if pos.y < self.min_build_y {
+                return Err(EditDenied::BelowBuildLimit {
+                    min_y: self.min_build_y,
+                });
+            }
+        }
+
+        if !is_op && self.is_spawn_protected(pos, spawn) {
+            return Err(EditDenied::SpawnProtected {
+                radius: self.spawn_protection_radius,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Whether `pos` falls inside the spawn protection square.
+    pub fn is_spawn_protected(&self, pos: IVec3, spawn: IVec3) -> bool {
+        if self.spawn_protection_radius == 0 {
+            return false;
+        }
+        let dx = pos.x.abs_diff(spawn.x);
+        let dz = pos.z.abs_diff(spawn.z);
+        dx.max(dz) <= self.spawn_protection_radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> EditRules {
+        EditRules {
+            spawn_protection_radius: 16,
+            min_build_y: WORLD_MIN_Y,
+            max_build_y: 128,
+        }
+    }
+
+    #[test]
+    fn placing_inside_spawn_protection_is_denied() {
+        let rules = rules();
+        let spawn = IVec3::new(100, 70, -40);
+
+        let inside = IVec3::new(110, 72, -30);
+        assert_eq!(
+            rules.check(EditKind::Place, inside, spawn, false),
+            Err(EditDenied::SpawnProtected { radius: 16 })
+        );
+        assert_eq!(
+            rules.check(EditKind::Break, inside, spawn, false),
+            Err(EditDenied::SpawnProtected { radius: 16 })
+        );
+
+        // Operators bypass protection; the edge of the square is still protected.
+        assert_eq!(rules.check(EditKind::Place, inside, spawn, true), Ok(()));
+        assert!(rules.is_spawn_protected(IVec3::new(116, 0, -56), spawn));
+        assert_eq!(
+            rules.check(EditKind::Place, IVec3::new(117, 72, -40), spawn, false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn placing_above_build_limit_is_rejected() {
+        let rules = rules();
+        let spawn = IVec3::ZERO;
+        let far = IVec3::new(500, 128, 500);
+
+        assert_eq!(rules.check(EditKind::Place, far, spawn, false), Ok(()));
+        assert_eq!(
+            rules.check(EditKind::Place, far + IVec3::Y, spawn, true),
+            Err(EditDenied::AboveBuildLimit { max_y: 128 })
+        );
+        assert_eq!(
+            rules.check(EditKind::Break, far + IVec3::Y, spawn, false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn default_rules_disable_spawn_protection() {
+        let rules = EditRules::default();
+        let spawn = IVec3::new(0, 64, 0);
+        assert_eq!(rules.check(EditKind::Place, spawn, spawn, false), Ok(()));
+    }
+}
@@ -4,9 +4,10 @@
 
 use crate::chunk::{BlockId, Chunk, ChunkPos, Voxel, CHUNK_SIZE_X, CHUNK_SIZE_Y, CHUNK_SIZE_Z};
 use crate::terrain::blocks;
+use mdminecraft_core::{scoped_rng, SimTick};
 use rand::rngs::StdRng;
-use rand::{Rng, SeedableRng};
-use std::collections::{BTreeSet, HashMap};
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// Block IDs for farming system
 pub mod farming_blocks {
@@ -110,11 +111,20 @@ pub fn can_till(block_id: BlockId) -> bool {
 }
 
 /// Crop growth system using deterministic random ticks
+///
+/// Each crop's growth roll is drawn from an RNG scoped to the world seed, the crop's
+/// position and the sim tick, so the outcome depends only on which ticks have run —
+/// not on frame timing, how ticks are batched, or which other crops are loaded. A
+/// world reloaded mid-growth resumes exactly where it left off.
 pub struct CropGrowthSystem {
     /// World seed for determinism
     world_seed: u64,
     /// Positions of crops that need updates (BTreeSet for deterministic iteration)
     crop_positions: BTreeSet<CropPosition>,
+    /// Growth stage of each tracked crop as of the last tick (kept once fully grown)
+    stages: BTreeMap<CropPosition, u8>,
+    /// Last sim tick that was processed
+    last_tick: Option<u64>,
     /// Dirty chunks that need mesh rebuilding (BTreeSet for deterministic iteration)
     dirty_chunks: BTreeSet<ChunkPos>,
 }
@@ -128,12 +138,24 @@ pub struct CropPosition {
     pub z: u8,
 }
 
+impl CropPosition {
+    /// Hash used to scope this crop's growth RNG.
+    fn growth_hash(&self) -> u64 {
+        (self.chunk.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (self.chunk.z as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+            ^ u64::from_le_bytes([self.x, self.y, self.z, 0, 0, 0, 0, 0])
+                .wrapping_mul(0x1656_67B1_9E37_79F9)
+    }
+}
+
 impl CropGrowthSystem {
     /// Create a new crop growth system
     pub fn new(world_seed: u64) -> Self {
         Self {
             world_seed,
             crop_positions: BTreeSet::new(),
+            stages: BTreeMap::new(),
+            last_tick: None,
             dirty_chunks: BTreeSet::new(),
         }
     }
@@ -146,6 +168,16 @@ impl CropGrowthSystem {
     /// Unregister a crop (when broken or fully grown)
     pub fn unregister_crop(&mut self, pos: CropPosition) {
         self.crop_positions.remove(&pos);
+        self.stages.remove(&pos);
+    }
+
+    /// Growth stage of a crop as of the last tick.
+    ///
+    /// Returns `None` for positions that are not tracked or haven't been ticked
+    /// since they were registered. Fully grown crops keep reporting their final
+    /// stage until unregistered.
+    pub fn stage_at(&self, pos: CropPosition) -> Option<u8> {
+        self.stages.get(&pos).copied()
     }
 
     /// Unregister all crops in a chunk (e.g. when unloading it).
@@ -168,19 +200,23 @@ impl CropGrowthSystem {
         for pos in to_remove {
             self.crop_positions.remove(&pos);
         }
+        self.stages.retain(|pos, _| pos.chunk != chunk);
     }
 
     /// Tick crop growth (called each game tick)
     pub fn tick(&mut self, tick: u64, chunks: &mut HashMap<ChunkPos, Chunk>) {
-        // Create a seeded RNG for this tick
-        let tick_seed = self.world_seed.wrapping_add(tick.wrapping_mul(0x5EED_CAFE));
-        let mut rng = StdRng::seed_from_u64(tick_seed);
+        self.last_tick = Some(tick);
 
         // Collect crops to update (avoid borrow issues)
         let crops_to_check: Vec<CropPosition> = self.crop_positions.iter().copied().collect();
 
         for pos in crops_to_check {
+            if !self.refresh_stage(pos, chunks) {
+                continue;
+            }
+
             // Random tick chance (1 in 100 per tick, roughly 1 growth every 5 seconds at 20 TPS)
+            let mut rng = scoped_rng(self.world_seed, pos.growth_hash(), SimTick(tick));
             if rng.gen_ratio(1, 100) {
                 self.try_grow_crop(pos, chunks, &mut rng);
             }
@@ -190,6 +226,38 @@ impl CropGrowthSystem {
         self.update_farmland_hydration(tick, chunks);
     }
 
+    /// Run every tick after the last processed one, up to and including `tick`.
+    ///
+    /// Produces the same world as calling [`tick`](Self::tick) once per sim tick.
+    pub fn advance_to(&mut self, tick: u64, chunks: &mut HashMap<ChunkPos, Chunk>) {
+        let first = self.last_tick.map_or(tick, |last| last.saturating_add(1));
+        for t in first..=tick {
+            self.tick(t, chunks);
+        }
+    }
+
+    /// Record the current stage of a registered crop.
+    ///
+    /// Returns false (and stops growing it) once the block is no longer a crop or
+    /// is fully grown. Crops in unloaded chunks stay registered.
+    fn refresh_stage(&mut self, pos: CropPosition, chunks: &HashMap<ChunkPos, Chunk>) -> bool {
+        let Some(chunk) = chunks.get(&pos.chunk) else {
+            return false;
+        };
+        let voxel = chunk.voxel(pos.x as usize, pos.y as usize, pos.z as usize);
+        let Some((crop_type, stage)) = CropType::from_block_id(voxel.id) else {
+            self.unregister_crop(pos);
+            return false;
+        };
+
+        self.stages.insert(pos, stage);
+        if stage >= crop_type.max_stage() {
+            self.crop_positions.remove(&pos);
+            return false;
+        }
+        true
+    }
+
     /// Try to grow a crop at the given position
     fn try_grow_crop(
         &mut self,
@@ -241,6 +309,7 @@ impl CropGrowthSystem {
                 },
             );
             self.dirty_chunks.insert(pos.chunk);
+            self.stages.insert(pos, new_stage);
         }
 
         // Unregister if now fully grown
@@ -1344,4 +1413,101 @@ mod tests {
         // Farmland should stay dry (water too far - manhattan distance 5 > 4)
         assert_eq!(farmland.id, farming_blocks::FARMLAND);
     }
+
+    /// Wheat on wet farmland in full light, at local (5, 64, 5).
+    fn wheat_field() -> (HashMap<ChunkPos, Chunk>, CropPosition) {
+        let mut chunk = create_test_chunk();
+        chunk.set_voxel(
+            5,
+            63,
+            5,
+            Voxel {
+                id: farming_blocks::FARMLAND_WET,
+                state: 0,
+                light_sky: 15,
+                light_block: 0,
+            },
+        );
+        chunk.set_voxel(
+            5,
+            64,
+            5,
+            Voxel {
+                id: farming_blocks::WHEAT_0,
+                state: 0,
+                light_sky: 15,
+                light_block: 0,
+            },
+        );
+        let mut chunks = HashMap::new();
+        chunks.insert(ChunkPos::new(0, 0), chunk);
+        let pos = CropPosition {
+            chunk: ChunkPos::new(0, 0),
+            x: 5,
+            y: 64,
+            z: 5,
+        };
+        (chunks, pos)
+    }
+
+    #[test]
+    fn test_stage_at_tracks_growth() {
+        let (mut chunks, pos) = wheat_field();
+        let mut system = CropGrowthSystem::new(12345);
+        system.register_crop(pos);
+        assert_eq!(system.stage_at(pos), None);
+
+        system.tick(0, &mut chunks);
+        assert_eq!(system.stage_at(pos), Some(0));
+
+        system.unregister_crop(pos);
+        assert_eq!(system.stage_at(pos), None);
+    }
+
+    #[test]
+    fn test_crop_matures_at_same_tick_regardless_of_batching() {
+        const SEED: u64 = 777;
+        const PLANTED: u64 = 1_000;
+
+        // Reference: one tick at a time.
+        let (mut chunks, pos) = wheat_field();
+        let mut system = CropGrowthSystem::new(SEED);
+        system.register_crop(pos);
+        let mature_tick = (PLANTED + 1..PLANTED + 50_000)
+            .find(|&tick| {
+                system.tick(tick, &mut chunks);
+                system.stage_at(pos) == Some(7)
+            })
+            .expect("wheat matures");
+        assert_eq!(system.crop_count(), 0);
+
+        // Uneven batches, with the world reloaded (fresh system) halfway through.
+        let (mut chunks, _) = wheat_field();
+        let mut system = CropGrowthSystem::new(SEED);
+        system.register_crop(pos);
+        system.tick(PLANTED + 1, &mut chunks);
+        let reload_tick = PLANTED + (mature_tick - PLANTED) / 2;
+        let mut reloaded = false;
+        let mut tick = PLANTED + 1;
+        for batch in [1u64, 7, 33, 150].into_iter().cycle() {
+            let target = (tick + batch).min(mature_tick - 1);
+            if !reloaded && target >= reload_tick {
+                system.advance_to(reload_tick, &mut chunks);
+                system = CropGrowthSystem::new(SEED);
+                system.register_crop(pos);
+                system.tick(reload_tick + 1, &mut chunks);
+                reloaded = true;
+            }
+            system.advance_to(target, &mut chunks);
+            tick = target;
+            if tick == mature_tick - 1 {
+                break;
+            }
+        }
+        assert!(reloaded);
+        assert_ne!(system.stage_at(pos), Some(7));
+
+        system.advance_to(mature_tick, &mut chunks);
+        assert_eq!(system.stage_at(pos), Some(7));
+    }
 }
@@ -230,6 +230,8 @@ pub struct FluidSimulator {
     dirty_chunks: HashSet<ChunkPos>,
     /// Dirty chunks that need block-light recomputation
     dirty_light_chunks: HashSet<ChunkPos>,
+    /// Maximum cells processed per tick (`None` = unlimited)
+    update_budget: Option<usize>,
 }
 
 impl FluidSimulator {
@@ -240,9 +242,21 @@ impl FluidSimulator {
             current_tick: 0,
             dirty_chunks: HashSet::new(),
             dirty_light_chunks: HashSet::new(),
+            update_budget: None,
         }
     }
 
+    /// Cap the number of cells processed per tick (at least one).
+    ///
+    /// Due updates beyond the cap stay queued and run on later ticks, oldest
+    /// first and then in coordinate order. Spreading work over more ticks
+    /// changes when cells update relative to each other, so where flows meet
+    /// (water against lava, competing sources) the settled world may differ
+    /// from an uncapped run.
+    pub fn set_update_budget(&mut self, max_cells: usize) {
+        self.update_budget = Some(max_cells.max(1));
+    }
+
     /// Schedule a fluid update at a position
     pub fn schedule_update(&mut self, pos: FluidPos, delay: u32) {
         let tick = self.current_tick + delay as u64;
@@ -260,20 +274,29 @@ impl FluidSimulator {
         self.current_tick += 1;
 
         // Collect updates that are due
-        let due_updates: Vec<FluidPos> = self
+        let mut due_updates: Vec<(u64, FluidPos)> = self
             .pending_updates
             .iter()
             .filter(|(_, &tick)| tick <= self.current_tick)
-            .map(|(pos, _)| *pos)
+            .map(|(pos, &tick)| (tick, *pos))
             .collect();
 
+        // Over budget: keep the oldest updates, the rest wait for the next tick
+        if let Some(budget) = self.update_budget {
+            if due_updates.len() > budget {
+                due_updates.sort_unstable();
+                due_updates.truncate(budget);
+                due_updates.sort_unstable_by_key(|&(_, pos)| pos);
+            }
+        }
+
         // Remove due updates from pending
-        for pos in &due_updates {
+        for (_, pos) in &due_updates {
             self.pending_updates.remove(pos);
         }
 
         // Process each update
-        for pos in due_updates {
+        for (_, pos) in due_updates {
             self.process_update(pos, chunks);
         }
     }
@@ -590,6 +613,11 @@ impl FluidSimulator {
         self.pending_updates.len()
     }
 
+    /// Positions with a queued update, in coordinate order
+    pub fn pending_cells(&self) -> impl Iterator<Item = FluidPos> + '_ {
+        self.pending_updates.keys().copied()
+    }
+
     /// Notify the simulator that a fluid block was placed
     pub fn on_fluid_placed(&mut self, pos: FluidPos, fluid_type: FluidType) {
         self.schedule_update(pos, fluid_type.flow_speed());
@@ -1908,4 +1936,99 @@ mod tests {
         assert_eq!(ly, local_y(64));
         assert_eq!(lz, 6);
     }
+
+    /// Stone floor at y=63 across the chunk with a few water sources on it.
+    fn pool_world() -> (FluidSimulator, HashMap<ChunkPos, Chunk>) {
+        let mut sim = FluidSimulator::new();
+        let mut chunk = create_test_chunk();
+        for z in 0..CHUNK_SIZE_Z {
+            for x in 0..CHUNK_SIZE_X {
+                chunk.set_voxel(
+                    x,
+                    local_y(63),
+                    z,
+                    Voxel {
+                        id: blocks::STONE,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        for (x, z) in [(2, 3), (12, 4), (7, 12)] {
+            chunk.set_voxel(
+                x,
+                local_y(64),
+                z,
+                Voxel {
+                    id: blocks::WATER,
+                    ..Default::default()
+                },
+            );
+            sim.on_fluid_placed(FluidPos::new(x as i32, 64, z as i32), FluidType::Water);
+        }
+        let mut chunks = HashMap::new();
+        chunks.insert(ChunkPos::new(0, 0), chunk);
+        (sim, chunks)
+    }
+
+    fn run_until_idle(sim: &mut FluidSimulator, chunks: &mut HashMap<ChunkPos, Chunk>) -> u32 {
+        let mut ticks = 0;
+        while sim.pending_count() > 0 {
+            sim.tick(chunks);
+            ticks += 1;
+            assert!(ticks < 10_000, "fluid simulation never settled");
+        }
+        ticks
+    }
+
+    #[test]
+    fn test_update_budget_limits_cells_per_tick() {
+        let (mut sim, mut chunks) = pool_world();
+        sim.set_update_budget(2);
+        let queued: Vec<FluidPos> = sim.pending_cells().collect();
+        assert_eq!(queued.len(), 3);
+
+        // Only two of the three sources spread this tick; the third stays queued.
+        sim.tick(&mut chunks);
+        assert!(sim.pending_cells().any(|pos| pos == queued[2]));
+        let chunk = &chunks[&ChunkPos::new(0, 0)];
+        assert_eq!(chunk.voxel(13, local_y(64), 4).id, blocks::AIR);
+        assert_eq!(chunk.voxel(3, local_y(64), 3).id, BLOCK_WATER_FLOWING);
+    }
+
+    #[test]
+    fn test_zero_update_budget_still_makes_progress() {
+        let (mut sim, mut chunks) = pool_world();
+        sim.set_update_budget(0);
+
+        // Treated as a budget of one: a single source spreads per tick.
+        sim.tick(&mut chunks);
+        let chunk = &chunks[&ChunkPos::new(0, 0)];
+        assert_eq!(chunk.voxel(3, local_y(64), 3).id, BLOCK_WATER_FLOWING);
+        assert_eq!(chunk.voxel(13, local_y(64), 4).id, blocks::AIR);
+        run_until_idle(&mut sim, &mut chunks);
+    }
+
+    #[test]
+    fn test_budgeted_pool_settles_like_unbudgeted() {
+        let (mut unbudgeted, mut expected) = pool_world();
+        let unbudgeted_ticks = run_until_idle(&mut unbudgeted, &mut expected);
+
+        let (mut budgeted, mut actual) = pool_world();
+        budgeted.set_update_budget(8);
+        let budgeted_ticks = run_until_idle(&mut budgeted, &mut actual);
+
+        assert!(budgeted_ticks > unbudgeted_ticks);
+        let expected = &expected[&ChunkPos::new(0, 0)];
+        let actual = &actual[&ChunkPos::new(0, 0)];
+        for z in 0..CHUNK_SIZE_Z {
+            for x in 0..CHUNK_SIZE_X {
+                assert_eq!(
+                    actual.voxel(x, local_y(64), z),
+                    expected.voxel(x, local_y(64), z),
+                    "mismatch at ({x}, 64, {z})"
+                );
+            }
+        }
+    }
 }
@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 /// Smelting time per item in seconds.
 pub const SMELT_TIME_SECONDS: f32 = 10.0;
 
+/// Smelting time per item in simulation ticks (`SMELT_TIME_SECONDS` at 20 TPS).
+pub const SMELT_TIME_TICKS: u32 = 200;
+
 /// A smelting recipe: input item -> output item.
 #[derive(Debug, Clone, Copy)]
 pub struct SmeltRecipe {
@@ -102,6 +105,85 @@ pub fn is_fuel(item: ItemType) -> bool {
     get_fuel_value(item) > 0.0
 }
 
+/// Smelting recipes and fuel values consulted by [`FurnaceState::tick`].
+#[derive(Debug, Clone, Default)]
+pub struct SmeltingRegistry {
+    recipes: Vec<SmeltRecipe>,
+    fuels: Vec<FuelValue>,
+}
+
+impl SmeltingRegistry {
+    /// Create an empty registry (nothing smelts, nothing burns).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry holding [`SMELT_RECIPES`] and [`FUEL_VALUES`].
+    pub fn builtin() -> Self {
+        Self {
+            recipes: SMELT_RECIPES.to_vec(),
+            fuels: FUEL_VALUES.to_vec(),
+        }
+    }
+
+    /// Add a recipe, replacing any existing recipe for the same input.
+    pub fn add_recipe(&mut self, recipe: SmeltRecipe) {
+        self.recipes.retain(|r| r.input != recipe.input);
+        self.recipes.push(recipe);
+    }
+
+    /// Add a fuel, replacing any existing burn value for the same item.
+    pub fn add_fuel(&mut self, fuel: FuelValue) {
+        self.fuels.retain(|f| f.item != fuel.item);
+        self.fuels.push(fuel);
+    }
+
+    /// Smelting output for `input`, if it has a recipe.
+    pub fn smelt_output(&self, input: ItemType) -> Option<ItemType> {
+        self.recipes
+            .iter()
+            .find(|r| r.input == input)
+            .map(|r| r.output)
+    }
+
+    /// Burn value of `item` in smelted items (0.0 if it isn't fuel).
+    pub fn fuel_value(&self, item: ItemType) -> f32 {
+        self.fuels
+            .iter()
+            .find(|f| f.item == item)
+            .map(|f| f.burn_value)
+            .unwrap_or(0.0)
+    }
+
+    /// Burn time of one `item` in ticks (0 if it isn't fuel).
+    fn fuel_ticks(&self, item: ItemType) -> u32 {
+        progress_to_ticks(self.fuel_value(item))
+    }
+}
+
+/// Something that happened during [`FurnaceState::tick`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmeltEvent {
+    /// One item was taken from the fuel slot and lit.
+    FuelConsumed { fuel: ItemType },
+    /// One input item was smelted into the output slot.
+    ItemSmelted { input: ItemType, output: ItemType },
+}
+
+/// Outcome of [`FurnaceState::tick`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SmeltResult {
+    /// Events in the order they happened.
+    pub events: Vec<SmeltEvent>,
+    /// Whether the furnace went from lit to unlit or back.
+    pub lit_changed: bool,
+}
+
+/// Convert a fraction of one smelt (progress or fuel) to whole ticks.
+fn progress_to_ticks(fraction: f32) -> u32 {
+    (fraction.max(0.0) * SMELT_TIME_TICKS as f32).round() as u32
+}
+
 /// State of a furnace in the world.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FurnaceState {
@@ -197,6 +279,89 @@ impl FurnaceState {
         was_lit != self.is_lit
     }
 
+    /// Advance the furnace by `dt_ticks` simulation ticks.
+    ///
+    /// Pure tick-based counterpart of [`FurnaceState::update`]: progress and
+    /// fuel are counted in whole ticks, so running `n` ticks at once gives the
+    /// same state as `n` single-tick calls. Fuel is only lit while the input
+    /// can be smelted and the output slot has room; otherwise progress resets.
+    pub fn tick(&mut self, dt_ticks: u32, recipes: &SmeltingRegistry) -> SmeltResult {
+        let was_lit = self.is_lit;
+        let mut events = Vec::new();
+        let mut progress = progress_to_ticks(self.smelt_progress);
+        let mut fuel = progress_to_ticks(self.fuel_remaining);
+
+        for _ in 0..dt_ticks {
+            let Some((input, output)) = self.next_smelt(recipes) else {
+                progress = 0;
+                break;
+            };
+
+            if fuel == 0 {
+                let Some((fuel_type, fuel_count)) = &mut self.fuel else {
+                    break;
+                };
+                let burn_ticks = recipes.fuel_ticks(*fuel_type);
+                if burn_ticks == 0 || *fuel_count == 0 {
+                    break;
+                }
+                events.push(SmeltEvent::FuelConsumed { fuel: *fuel_type });
+                fuel = burn_ticks;
+                *fuel_count -= 1;
+                if *fuel_count == 0 {
+                    self.fuel = None;
+                }
+            }
+
+            fuel -= 1;
+            progress += 1;
+            if progress >= SMELT_TIME_TICKS {
+                progress = 0;
+                self.finish_smelt(input, output);
+                events.push(SmeltEvent::ItemSmelted { input, output });
+            }
+        }
+
+        self.smelt_progress = progress as f32 / SMELT_TIME_TICKS as f32;
+        self.fuel_remaining = fuel as f32 / SMELT_TIME_TICKS as f32;
+        self.is_lit = fuel > 0;
+        SmeltResult {
+            events,
+            lit_changed: was_lit != self.is_lit,
+        }
+    }
+
+    /// Input and output of the next smelt, if the input has a recipe and the
+    /// output slot has room.
+    fn next_smelt(&self, recipes: &SmeltingRegistry) -> Option<(ItemType, ItemType)> {
+        let (input, _) = self.input?;
+        let output = recipes.smelt_output(input)?;
+        match self.output {
+            None => Some((input, output)),
+            Some((out_type, out_count))
+                if out_type == output && out_count < output.max_stack_size() =>
+            {
+                Some((input, output))
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Move one item from the input slot to the output slot as `output`.
+    fn finish_smelt(&mut self, input: ItemType, output: ItemType) {
+        if let Some((input_type, input_count)) = &mut self.input {
+            debug_assert_eq!(*input_type, input);
+            *input_count -= 1;
+            if *input_count == 0 {
+                self.input = None;
+            }
+        }
+        match &mut self.output {
+            Some((_, out_count)) => *out_count += 1,
+            None => self.output = Some((output, 1)),
+        }
+    }
+
     /// Check if the furnace can smelt (has valid input and room for output).
     fn can_smelt(&self) -> bool {
         if let Some((input_type, _)) = &self.input {
@@ -722,4 +887,130 @@ mod tests {
 
         assert_eq!(furnace.output, Some((ItemType::GoldIngot, 1)));
     }
+
+    #[test]
+    fn test_smelt_time_ticks_matches_seconds() {
+        assert_eq!(
+            SMELT_TIME_TICKS as f32,
+            SMELT_TIME_SECONDS * mdminecraft_core::SimTick::TICKS_PER_SECOND as f32
+        );
+    }
+
+    #[test]
+    fn test_tick_without_fuel_makes_no_progress() {
+        let recipes = SmeltingRegistry::builtin();
+        let mut furnace = FurnaceState::new();
+        furnace.add_input(ItemType::IronOre, 3);
+
+        let result = furnace.tick(SMELT_TIME_TICKS * 2, &recipes);
+
+        assert!(result.events.is_empty());
+        assert!(!result.lit_changed);
+        assert!(!furnace.is_lit);
+        assert_eq!(furnace.smelt_progress, 0.0);
+        assert_eq!(furnace.input, Some((ItemType::IronOre, 3)));
+        assert!(furnace.output.is_none());
+    }
+
+    #[test]
+    fn test_tick_with_fuel_smelts_input() {
+        let recipes = SmeltingRegistry::builtin();
+        let mut furnace = FurnaceState::new();
+        furnace.add_input(ItemType::IronOre, 2);
+        furnace.add_fuel(ItemType::Coal, 1);
+
+        let result = furnace.tick(1, &recipes);
+        assert_eq!(
+            result.events,
+            vec![SmeltEvent::FuelConsumed {
+                fuel: ItemType::Coal
+            }]
+        );
+        assert!(result.lit_changed);
+        assert!(furnace.fuel.is_none());
+        assert!(furnace.smelt_progress > 0.0);
+
+        let result = furnace.tick(SMELT_TIME_TICKS - 1, &recipes);
+        assert_eq!(
+            result.events,
+            vec![SmeltEvent::ItemSmelted {
+                input: ItemType::IronOre,
+                output: ItemType::IronIngot
+            }]
+        );
+        assert!(!result.lit_changed);
+        assert_eq!(furnace.input, Some((ItemType::IronOre, 1)));
+        assert_eq!(furnace.output, Some((ItemType::IronIngot, 1)));
+        assert_eq!(furnace.smelt_progress, 0.0);
+        assert!(furnace.is_lit);
+    }
+
+    #[test]
+    fn test_tick_batched_matches_single_ticks() {
+        let recipes = SmeltingRegistry::builtin();
+        let mut batched = FurnaceState::new();
+        batched.add_input(ItemType::Cobblestone, 5);
+        batched.add_fuel(ItemType::Stick, 40);
+        let mut stepped = batched.clone();
+
+        let total = SMELT_TIME_TICKS * 4 + 37;
+        let batched_events = batched.tick(total, &recipes).events;
+        let stepped_events: Vec<_> = (0..total)
+            .flat_map(|_| stepped.tick(1, &recipes).events)
+            .collect();
+
+        assert_eq!(batched_events, stepped_events);
+        assert_eq!(batched.output, stepped.output);
+        assert_eq!(batched.fuel, stepped.fuel);
+        assert_eq!(batched.smelt_progress, stepped.smelt_progress);
+        assert_eq!(batched.fuel_remaining, stepped.fuel_remaining);
+        // Each stick burns for a quarter of a smelt.
+        assert_eq!(batched.output, Some((ItemType::Stone, 4)));
+        assert_eq!(batched.fuel, Some((ItemType::Stick, 40 - 17)));
+    }
+
+    #[test]
+    fn test_tick_pauses_when_output_full() {
+        let recipes = SmeltingRegistry::builtin();
+        let mut furnace = FurnaceState::new();
+        let max_stack = ItemType::IronIngot.max_stack_size();
+        furnace.output = Some((ItemType::IronIngot, max_stack));
+        furnace.add_input(ItemType::IronOre, 1);
+        furnace.add_fuel(ItemType::Coal, 1);
+
+        let result = furnace.tick(SMELT_TIME_TICKS * 2, &recipes);
+
+        assert!(result.events.is_empty());
+        assert!(!furnace.is_lit);
+        assert_eq!(furnace.input, Some((ItemType::IronOre, 1)));
+        assert_eq!(furnace.fuel, Some((ItemType::Coal, 1)));
+        assert_eq!(furnace.output, Some((ItemType::IronIngot, max_stack)));
+
+        // Taking the output lets it resume.
+        furnace.take_output();
+        let result = furnace.tick(SMELT_TIME_TICKS, &recipes);
+        assert_eq!(result.events.len(), 2);
+        assert_eq!(furnace.output, Some((ItemType::IronIngot, 1)));
+    }
+
+    #[test]
+    fn test_tick_uses_registry_recipes() {
+        let mut recipes = SmeltingRegistry::new();
+        let mut furnace = FurnaceState::new();
+        furnace.input = Some((ItemType::IronOre, 1));
+        furnace.fuel = Some((ItemType::Coal, 1));
+        assert!(furnace.tick(SMELT_TIME_TICKS, &recipes).events.is_empty());
+
+        recipes.add_recipe(SmeltRecipe {
+            input: ItemType::IronOre,
+            output: ItemType::GoldIngot,
+        });
+        recipes.add_fuel(FuelValue {
+            item: ItemType::Coal,
+            burn_value: 1.0,
+        });
+        furnace.tick(SMELT_TIME_TICKS, &recipes);
+        assert_eq!(furnace.output, Some((ItemType::GoldIngot, 1)));
+        assert!(!furnace.is_lit);
+    }
 }
@@ -54,22 +54,27 @@ impl Heightmap {
                 let world_x = chunk_origin_x + local_x as i32;
                 let world_z = chunk_origin_z + local_z as i32;
 
-                // Sample noise at world coordinates
-                let noise_value = noise.sample_height(world_x as f64, world_z as f64);
-
-                // Convert noise [-1.0, 1.0] to height [BASE - VAR, BASE + VAR]
-                let height = BASE_HEIGHT + (noise_value * HEIGHT_VARIATION as f64) as i32;
-
-                // Clamp to valid range
-                let clamped_height = height.clamp(MIN_HEIGHT, MAX_HEIGHT);
-
-                *cell = clamped_height;
+                *cell = Self::column_height(&noise, world_x, world_z);
             }
         }
 
         Self { heights }
     }
 
+    /// Compute the height of a single world column without building a full heightmap.
+    ///
+    /// Produces exactly the value [`Heightmap::generate`] stores for that column.
+    pub fn column_height(noise: &LayeredNoise, world_x: i32, world_z: i32) -> i32 {
+        // Sample noise at world coordinates
+        let noise_value = noise.sample_height(world_x as f64, world_z as f64);
+
+        // Convert noise [-1.0, 1.0] to height [BASE - VAR, BASE + VAR]
+        let height = BASE_HEIGHT + (noise_value * HEIGHT_VARIATION as f64) as i32;
+
+        // Clamp to valid range
+        height.clamp(MIN_HEIGHT, MAX_HEIGHT)
+    }
+
     /// Get the height at a specific local (x, z) coordinate within the chunk.
     ///
     /// # Arguments
@@ -721,8 +721,16 @@ pub enum CollisionType {
 }
 
 /// Get collision type for a block
+///
+/// Blocks without a built-in shape collide as full cubes; use
+/// [`CollisionShapeRegistry::collision_type`] to honour pack-defined shapes.
 pub fn get_collision_type(block_id: BlockId, state: BlockState) -> CollisionType {
-    match block_id {
+    builtin_collision_type(block_id, state).unwrap_or(CollisionType::Full)
+}
+
+/// Collision type from the hardcoded block table, if the block has one.
+fn builtin_collision_type(block_id: BlockId, state: BlockState) -> Option<CollisionType> {
+    let collision = match block_id {
         blocks::AIR => CollisionType::None,
         blocks::WATER => CollisionType::None,
 
@@ -817,10 +825,231 @@ pub fn get_collision_type(block_id: BlockId, state: BlockState) -> CollisionType
             max_y: 0.875,
         },
 
-        _ => CollisionType::Full,
+        _ => return None,
+    };
+    Some(collision)
+}
+
+/// Collision shape a block pack can assign to a block.
+///
+/// Unlike [`CollisionType`], a shape is independent of block state; state-driven
+/// shapes (slabs, stairs, doors) resolve against the voxel's state in
+/// [`CollisionShape::collision_type`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CollisionShape {
+    /// Full block collision
+    Full,
+    /// No collision
+    None,
+    /// Fixed partial height (0.0-1.0)
+    Partial { min_y: f32, max_y: f32 },
+    /// Bottom or top half, chosen by the slab state bit
+    Slab,
+    /// Half-block step, like the built-in stairs
+    Stairs,
+    /// Fence collision (1.5 blocks high)
+    Fence,
+    /// Door collision, open or closed by state
+    Door,
+    /// Climbable, no collision
+    Ladder,
+}
+
+impl CollisionShape {
+    /// Parse a shape name as written in block packs.
+    ///
+    /// Accepts `full`, `none`, `slab`, `stairs`, `fence`, `door`, `ladder`, and
+    /// `partial:<n>` for a shape `n` sixteenths tall (1-16).
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        if let Some(height) = s.strip_prefix("partial:") {
+            let sixteenths: u8 = height.parse().ok()?;
+            if !(1..=16).contains(&sixteenths) {
+                return None;
+            }
+            return Some(CollisionShape::Partial {
+                min_y: 0.0,
+                max_y: sixteenths as f32 / 16.0,
+            });
+        }
+        match s.as_str() {
+            "full" => Some(CollisionShape::Full),
+            "none" => Some(CollisionShape::None),
+            "slab" => Some(CollisionShape::Slab),
+            "stairs" => Some(CollisionShape::Stairs),
+            "fence" => Some(CollisionShape::Fence),
+            "door" => Some(CollisionShape::Door),
+            "ladder" => Some(CollisionShape::Ladder),
+            _ => None,
+        }
+    }
+
+    /// Collision type for a block with this shape in the given state.
+    pub fn collision_type(self, state: BlockState) -> CollisionType {
+        match self {
+            CollisionShape::Full => CollisionType::Full,
+            CollisionShape::None => CollisionType::None,
+            CollisionShape::Partial { min_y, max_y } => CollisionType::Partial { min_y, max_y },
+            CollisionShape::Slab => match SlabPosition::from_state(state) {
+                SlabPosition::Bottom => CollisionType::Partial {
+                    min_y: 0.0,
+                    max_y: 0.5,
+                },
+                SlabPosition::Top => CollisionType::Partial {
+                    min_y: 0.5,
+                    max_y: 1.0,
+                },
+            },
+            CollisionShape::Stairs => CollisionType::Partial {
+                min_y: 0.0,
+                max_y: 0.5,
+            },
+            CollisionShape::Fence => CollisionType::Fence,
+            CollisionShape::Door => CollisionType::Door {
+                open: is_door_open(state),
+            },
+            CollisionShape::Ladder => CollisionType::Ladder,
+        }
+    }
+}
+
+/// Pack-defined collision shapes, consulted for blocks the built-in table doesn't know.
+#[derive(Debug, Clone, Default)]
+pub struct CollisionShapeRegistry {
+    shapes: Vec<Option<CollisionShape>>,
+}
+
+impl CollisionShapeRegistry {
+    /// Create an empty registry; every block uses the built-in table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed shapes from the pack's block descriptors.
+    pub fn from_registry(registry: &impl crate::BlockDescriptorSource) -> Self {
+        let mut shapes = Self::new();
+        for id in 0..registry.block_count() {
+            if let Some(shape) = registry.collision_shape(id as BlockId) {
+                shapes.set(id as BlockId, shape);
+            }
+        }
+        shapes
+    }
+
+    /// Assign a shape to a block.
+    pub fn set(&mut self, block_id: BlockId, shape: CollisionShape) {
+        let index = block_id as usize;
+        if self.shapes.len() <= index {
+            self.shapes.resize(index + 1, None);
+        }
+        self.shapes[index] = Some(shape);
+    }
+
+    /// The shape assigned to a block, if any.
+    pub fn shape(&self, block_id: BlockId) -> Option<CollisionShape> {
+        self.shapes.get(block_id as usize).copied().flatten()
+    }
+
+    /// Collision type for a block, falling back to its pack shape when the
+    /// built-in table has no entry, and to a full cube after that.
+    pub fn collision_type(&self, block_id: BlockId, state: BlockState) -> CollisionType {
+        builtin_collision_type(block_id, state)
+            .or_else(|| {
+                self.shape(block_id)
+                    .map(|shape| shape.collision_type(state))
+            })
+            .unwrap_or(CollisionType::Full)
+    }
+}
+
+/// Axis-aligned box in block-local coordinates (the block spans 0.0-1.0 on each axis).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockBox {
+    pub min: glam::Vec3,
+    pub max: glam::Vec3,
+}
+
+impl BlockBox {
+    /// Box spanning `min` to `max`.
+    pub fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+        Self {
+            min: glam::Vec3::from(min),
+            max: glam::Vec3::from(max),
+        }
+    }
+}
+
+/// Collision boxes for a stair block: a full half slab plus its step footprint(s).
+///
+/// Bottom stairs have the slab at y=0.0..0.5 and the step above it; top
+/// (upside-down) stairs are mirrored vertically. Returns up to 3 boxes.
+pub fn stairs_collision_boxes(
+    facing: Facing,
+    shape: StairsShape,
+    top: bool,
+) -> ([BlockBox; 3], usize) {
+    let (slab_y, step_y) = if top {
+        ((0.5, 1.0), (0.0, 0.5))
+    } else {
+        ((0.0, 0.5), (0.5, 1.0))
+    };
+
+    let mut boxes = [BlockBox::new([0.0; 3], [0.0; 3]); 3];
+    boxes[0] = BlockBox::new([0.0, slab_y.0, 0.0], [1.0, slab_y.1, 1.0]);
+    let (footprints, footprint_count) = stairs_step_footprints(facing, shape);
+    for (slot, footprint) in boxes[1..]
+        .iter_mut()
+        .zip(footprints.iter().take(footprint_count))
+    {
+        *slot = BlockBox::new(
+            [footprint.min_x, step_y.0, footprint.min_z],
+            [footprint.max_x, step_y.1, footprint.max_z],
+        );
+    }
+    (boxes, 1 + footprint_count)
+}
+
+/// Collision box of a door panel.
+///
+/// Closed doors sit against the facing side of the block. Open doors use a
+/// simplified hinge and always swing "left" from their facing direction.
+pub fn door_collision_box(facing: Facing, open: bool) -> BlockBox {
+    let thickness = 3.0 / 16.0;
+    let far = 1.0 - thickness;
+    match (facing, open) {
+        (Facing::North, false) | (Facing::East, true) => {
+            BlockBox::new([0.0, 0.0, 0.0], [1.0, 1.0, thickness])
+        }
+        (Facing::South, false) | (Facing::West, true) => {
+            BlockBox::new([0.0, 0.0, far], [1.0, 1.0, 1.0])
+        }
+        (Facing::East, false) | (Facing::South, true) => {
+            BlockBox::new([far, 0.0, 0.0], [1.0, 1.0, 1.0])
+        }
+        (Facing::West, false) | (Facing::North, true) => {
+            BlockBox::new([0.0, 0.0, 0.0], [thickness, 1.0, 1.0])
+        }
     }
 }
 
+/// Whether a fence or wall at `block_id` extends an arm toward `neighbor_id`.
+///
+/// Fences join fences, walls join walls, and both join fence gates and full cubes.
+pub fn fence_connects_to(block_id: BlockId, neighbor_id: BlockId) -> bool {
+    let is_wall = |id: BlockId| {
+        matches!(
+            id,
+            interactive_blocks::COBBLESTONE_WALL | interactive_blocks::STONE_BRICK_WALL
+        )
+    };
+    let same_family = if is_wall(block_id) {
+        is_wall(neighbor_id)
+    } else {
+        is_fence(neighbor_id)
+    };
+    same_family || is_fence_gate(neighbor_id) || is_full_cube_block(neighbor_id)
+}
+
 /// Block interaction manager
 pub struct InteractionManager {
     /// Dirty chunks that need mesh rebuilding
@@ -1215,6 +1444,189 @@ mod tests {
         }
     }
 
+    fn boxes(pair: ([BlockBox; 3], usize)) -> Vec<BlockBox> {
+        let (boxes, count) = pair;
+        boxes[..count].to_vec()
+    }
+
+    #[test]
+    fn straight_stairs_collision_boxes_follow_facing_and_half() {
+        let bottom_slab = BlockBox::new([0.0, 0.0, 0.0], [1.0, 0.5, 1.0]);
+        let top_slab = BlockBox::new([0.0, 0.5, 0.0], [1.0, 1.0, 1.0]);
+        // (facing, step footprint as [min_x, min_z] .. [max_x, max_z])
+        let cases = [
+            (Facing::North, [0.0, 0.0], [1.0, 0.5]),
+            (Facing::South, [0.0, 0.5], [1.0, 1.0]),
+            (Facing::East, [0.5, 0.0], [1.0, 1.0]),
+            (Facing::West, [0.0, 0.0], [0.5, 1.0]),
+        ];
+
+        for (facing, min, max) in cases {
+            assert_eq!(
+                boxes(stairs_collision_boxes(facing, StairsShape::Straight, false)),
+                vec![
+                    bottom_slab,
+                    BlockBox::new([min[0], 0.5, min[1]], [max[0], 1.0, max[1]]),
+                ],
+                "bottom stairs facing {facing:?}"
+            );
+            assert_eq!(
+                boxes(stairs_collision_boxes(facing, StairsShape::Straight, true)),
+                vec![
+                    top_slab,
+                    BlockBox::new([min[0], 0.0, min[1]], [max[0], 0.5, max[1]]),
+                ],
+                "top stairs facing {facing:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn corner_stairs_collision_boxes() {
+        let outer = boxes(stairs_collision_boxes(
+            Facing::North,
+            StairsShape::OuterLeft,
+            false,
+        ));
+        assert_eq!(
+            outer[1],
+            BlockBox::new([0.0, 0.5, 0.0], [0.5, 1.0, 0.5]),
+            "outer-left step keeps only the front-left quarter"
+        );
+        assert_eq!(outer.len(), 2);
+
+        let inner = boxes(stairs_collision_boxes(
+            Facing::North,
+            StairsShape::InnerRight,
+            false,
+        ));
+        assert_eq!(inner.len(), 3);
+        assert_eq!(inner[1], BlockBox::new([0.0, 0.5, 0.0], [1.0, 1.0, 0.5]));
+        assert_eq!(inner[2], BlockBox::new([0.5, 0.5, 0.5], [1.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn stairs_collide_as_half_step_in_either_half() {
+        for top in [false, true] {
+            let state = Facing::East.to_state() | if top { STAIRS_TOP_BIT } else { 0 };
+            assert_eq!(
+                get_collision_type(interactive_blocks::OAK_STAIRS, state),
+                CollisionType::Partial {
+                    min_y: 0.0,
+                    max_y: 0.5
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn door_collision_open_and_closed() {
+        let closed = set_door_open(Facing::North.to_state(), false);
+        let open = set_door_open(Facing::North.to_state(), true);
+        assert_eq!(
+            get_collision_type(interactive_blocks::OAK_DOOR_LOWER, closed),
+            CollisionType::Door { open: false }
+        );
+        assert_eq!(
+            get_collision_type(interactive_blocks::IRON_DOOR_UPPER, open),
+            CollisionType::Door { open: true }
+        );
+
+        let t = 3.0 / 16.0;
+        let cases = [
+            (
+                Facing::North,
+                BlockBox::new([0.0, 0.0, 0.0], [1.0, 1.0, t]),
+                BlockBox::new([0.0, 0.0, 0.0], [t, 1.0, 1.0]),
+            ),
+            (
+                Facing::South,
+                BlockBox::new([0.0, 0.0, 1.0 - t], [1.0, 1.0, 1.0]),
+                BlockBox::new([1.0 - t, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            ),
+            (
+                Facing::East,
+                BlockBox::new([1.0 - t, 0.0, 0.0], [1.0, 1.0, 1.0]),
+                BlockBox::new([0.0, 0.0, 0.0], [1.0, 1.0, t]),
+            ),
+            (
+                Facing::West,
+                BlockBox::new([0.0, 0.0, 0.0], [t, 1.0, 1.0]),
+                BlockBox::new([0.0, 0.0, 1.0 - t], [1.0, 1.0, 1.0]),
+            ),
+        ];
+        for (facing, closed_box, open_box) in cases {
+            assert_eq!(door_collision_box(facing, false), closed_box, "{facing:?}");
+            assert_eq!(door_collision_box(facing, true), open_box, "{facing:?}");
+        }
+    }
+
+    #[test]
+    fn fence_and_wall_connections() {
+        let fence = interactive_blocks::OAK_FENCE;
+        let wall = interactive_blocks::COBBLESTONE_WALL;
+
+        assert!(fence_connects_to(fence, fence));
+        assert!(fence_connects_to(fence, interactive_blocks::OAK_FENCE_GATE));
+        assert!(fence_connects_to(fence, blocks::STONE));
+        assert!(!fence_connects_to(fence, wall));
+        assert!(!fence_connects_to(fence, blocks::AIR));
+        assert!(!fence_connects_to(fence, interactive_blocks::GLASS_PANE));
+        assert!(!fence_connects_to(fence, interactive_blocks::OAK_STAIRS));
+
+        assert!(fence_connects_to(
+            wall,
+            interactive_blocks::STONE_BRICK_WALL
+        ));
+        assert!(fence_connects_to(wall, interactive_blocks::OAK_FENCE_GATE));
+        assert!(fence_connects_to(wall, blocks::STONE));
+        assert!(!fence_connects_to(wall, fence));
+        assert!(!fence_connects_to(wall, blocks::WATER));
+    }
+
+    #[test]
+    fn collision_shape_registry_only_fills_unknown_blocks() {
+        let custom: BlockId = 900;
+        let mut shapes = CollisionShapeRegistry::new();
+        assert_eq!(shapes.collision_type(custom, 0), CollisionType::Full);
+
+        shapes.set(custom, CollisionShape::Slab);
+        shapes.set(interactive_blocks::LADDER, CollisionShape::Full);
+        assert_eq!(
+            shapes.collision_type(custom, SlabPosition::Top.to_state(0)),
+            CollisionType::Partial {
+                min_y: 0.5,
+                max_y: 1.0
+            }
+        );
+        // The built-in table still wins for blocks it knows.
+        assert_eq!(
+            shapes.collision_type(interactive_blocks::LADDER, 0),
+            CollisionType::Ladder
+        );
+        assert_eq!(shapes.collision_type(blocks::STONE, 0), CollisionType::Full);
+    }
+
+    #[test]
+    fn collision_shape_parse() {
+        assert_eq!(CollisionShape::parse("Fence"), Some(CollisionShape::Fence));
+        assert_eq!(CollisionShape::parse("none"), Some(CollisionShape::None));
+        assert_eq!(
+            CollisionShape::parse("partial:4"),
+            Some(CollisionShape::Partial {
+                min_y: 0.0,
+                max_y: 0.25
+            })
+        );
+        assert_eq!(CollisionShape::parse("partial:0"), None);
+        assert_eq!(CollisionShape::parse("partial:17"), None);
+        assert_eq!(CollisionShape::parse("cactus"), None);
+        assert_eq!(
+            CollisionShape::Door.collision_type(set_door_open(0, true)),
+            CollisionType::Door { open: true }
+        );
+    }
+
     #[test]
     fn snow_layers_roundtrip() {
         assert_eq!(snow_layers(0), 1);
@@ -72,6 +72,21 @@ impl ItemStack {
         amount - added
     }
 
+    /// Move as many items as fit from `other` into this stack.
+    ///
+    /// Both counts are updated; returns the number of items moved (0 if the
+    /// stacks don't match or this stack is full).
+    pub fn try_merge(&mut self, other: &mut ItemStack) -> u8 {
+        if !self.can_merge(other) {
+            return 0;
+        }
+
+        let moved = self.remaining_space().min(other.count);
+        self.count += moved;
+        other.count -= moved;
+        moved
+    }
+
     /// Try to remove items from this stack, returning the amount actually removed.
     pub fn remove(&mut self, amount: u8) -> u8 {
         let removed = amount.min(self.count);
@@ -203,6 +218,38 @@ impl Inventory {
         Some(stack)
     }
 
+    /// Shift-click transfer of the stack in `slot` into `dest`.
+    ///
+    /// Tops up matching stacks in `dest` first, then puts whatever is left in
+    /// its first empty slot. Anything that doesn't fit stays in `slot`.
+    /// Returns whether any items moved.
+    pub fn quick_move_into(&mut self, slot: usize, dest: &mut Inventory) -> bool {
+        let Some(mut stack) = self.take(slot) else {
+            return false;
+        };
+        let original = stack.count;
+
+        for existing in dest.slots.iter_mut().flatten() {
+            if stack.count == 0 {
+                break;
+            }
+            existing.try_merge(&mut stack);
+        }
+
+        if stack.count > 0 {
+            if let Some(empty) = dest.slots.iter_mut().find(|entry| entry.is_none()) {
+                *empty = Some(stack);
+                return true;
+            }
+        }
+
+        let moved = stack.count < original;
+        if stack.count > 0 {
+            self.slots[slot] = Some(stack);
+        }
+        moved
+    }
+
     /// Remove a specific amount of an item type from the inventory.
     /// Returns the actual amount removed.
     pub fn remove_item(&mut self, item_id: ItemId, amount: u8) -> u8 {
@@ -624,4 +671,80 @@ mod tests {
         assert_eq!(inv.get(0).unwrap().count, 64);
         assert_eq!(inv.get(1).unwrap().count, 6);
     }
+
+    #[test]
+    fn item_stack_try_merge() {
+        let mut dest = ItemStack::new(1, 60);
+        let mut src = ItemStack::new(1, 10);
+        assert_eq!(dest.try_merge(&mut src), 4);
+        assert_eq!(dest.count, 64);
+        assert_eq!(src.count, 6);
+
+        let mut other = ItemStack::new(2, 5);
+        let mut dest = ItemStack::new(1, 1);
+        assert_eq!(dest.try_merge(&mut other), 0);
+        assert_eq!(other.count, 5);
+    }
+
+    #[test]
+    fn quick_move_merges_into_partial_stack() {
+        let mut src = Inventory::new();
+        let mut dest = Inventory::new();
+        src.set(3, Some(ItemStack::new(1, 10)));
+        dest.set(0, Some(ItemStack::new(2, 5)));
+        dest.set(7, Some(ItemStack::new(1, 20)));
+
+        assert!(src.quick_move_into(3, &mut dest));
+        assert!(src.get(3).is_none());
+        assert_eq!(dest.get(7).unwrap().count, 30);
+        assert_eq!(dest.empty_slots(), INVENTORY_SIZE - 2);
+    }
+
+    #[test]
+    fn quick_move_overflow_splits_across_two_slots() {
+        let mut src = Inventory::new();
+        let mut dest = Inventory::new();
+        src.set(0, Some(ItemStack::new(1, 10)));
+        dest.set(0, Some(ItemStack::new(2, 1)));
+        dest.set(1, Some(ItemStack::new(1, 60)));
+
+        assert!(src.quick_move_into(0, &mut dest));
+        assert!(src.is_empty());
+        assert_eq!(dest.get(1).unwrap().count, 64);
+        // The first empty slot takes the remainder.
+        assert_eq!(dest.get(2), Some(&ItemStack::new(1, 6)));
+    }
+
+    #[test]
+    fn quick_move_into_full_inventory_is_noop() {
+        let mut src = Inventory::new();
+        let mut dest = Inventory::new();
+        src.set(4, Some(ItemStack::new(1, 10)));
+        for slot in 0..INVENTORY_SIZE {
+            dest.set(slot, Some(ItemStack::new(2, 64)));
+        }
+        dest.set(9, Some(ItemStack::new(1, 64)));
+
+        assert!(!src.quick_move_into(4, &mut dest));
+        assert_eq!(src.get(4), Some(&ItemStack::new(1, 10)));
+        assert_eq!(dest.count_item(1), 64);
+
+        // Empty source slots move nothing either.
+        assert!(!src.quick_move_into(0, &mut dest));
+    }
+
+    #[test]
+    fn quick_move_partial_fit_keeps_remainder_in_source() {
+        let mut src = Inventory::new();
+        let mut dest = Inventory::new();
+        src.set(0, Some(ItemStack::new(1, 10)));
+        for slot in 0..INVENTORY_SIZE {
+            dest.set(slot, Some(ItemStack::new(2, 64)));
+        }
+        dest.set(5, Some(ItemStack::new(1, 61)));
+
+        assert!(src.quick_move_into(0, &mut dest));
+        assert_eq!(dest.get(5).unwrap().count, 64);
+        assert_eq!(src.get(0).unwrap().count, 7);
+    }
 }
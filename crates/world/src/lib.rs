@@ -10,7 +10,9 @@ mod crafting;
 mod dispenser;
 mod drop_item;
 mod dungeon;
+mod edit_rules;
 mod enchanting;
+mod explosion;
 mod farming;
 mod fluid;
 mod fortress;
@@ -27,6 +29,8 @@ mod mob;
 mod noise;
 mod pathfinding;
 mod persist;
+mod placement;
+mod player_controller;
 mod portals;
 mod potion;
 mod projectile;
@@ -34,6 +38,7 @@ mod redstone;
 mod respawn_anchor;
 mod ruin;
 mod storage;
+mod structure_placer;
 mod structure_template;
 mod structures;
 mod sugar_cane;
@@ -42,6 +47,7 @@ mod time;
 mod trees;
 mod village;
 mod weather;
+mod world_view;
 
 pub use aquifer::*;
 pub use armor::*;
@@ -75,7 +81,9 @@ pub use crafting::*;
 pub use dispenser::*;
 pub use drop_item::*;
 pub use dungeon::*;
+pub use edit_rules::*;
 pub use enchanting::*;
+pub use explosion::*;
 pub use farming::*;
 pub use fluid::*;
 pub use fortress::*;
@@ -92,6 +100,8 @@ pub use mob::*;
 pub use noise::*;
 pub use pathfinding::*;
 pub use persist::*;
+pub use placement::*;
+pub use player_controller::*;
 pub use portals::*;
 pub use potion::*;
 pub use projectile::*;
@@ -99,6 +109,7 @@ pub use redstone::*;
 pub use respawn_anchor::*;
 pub use ruin::*;
 pub use storage::*;
+pub use structure_placer::*;
 pub use structures::{worldgen_structure_kind_at, WorldgenStructureKind};
 pub use sugar_cane::*;
 pub use terrain::*;
@@ -106,3 +117,4 @@ pub use time::*;
 pub use trees::*;
 pub use village::*;
 pub use weather::*;
+pub use world_view::*;
@@ -32,6 +32,12 @@ impl BlockPos {
     pub fn new(chunk: ChunkPos, local: LocalPos) -> Self {
         Self { chunk, local }
     }
+
+    /// Position of a world-space block, or `None` if `y` is outside the world.
+    pub fn from_world(x: i32, y: i32, z: i32) -> Option<Self> {
+        let (chunk, x, y, z) = crate::chunk_local_coords(x, y, z)?;
+        Some(Self::new(chunk, LocalPos { x, y, z }))
+    }
 }
 
 /// Light propagation queue entry.
@@ -914,6 +920,389 @@ pub fn recompute_block_light_local(
     seam_chunks
 }
 
+/// Flood-fills sky and block light across a group of loaded chunks in one call.
+///
+/// The chunks in the region have their light cleared and re-seeded from their own sources
+/// (open sky at the top of the world, emissive blocks) and from the existing light on the
+/// borders of loaded neighbors outside the region. A single BFS then runs over every loaded
+/// chunk, so light crosses seams without a separate [`stitch_light_seams`] pass.
+///
+/// Light only ever increases outside the region; when a change can darken neighbors, include
+/// them in the region.
+///
+/// The queues used by [`LightPropagator::on_block_changed`] are kept between calls, so reuse
+/// one propagator for a batch of edits.
+pub struct LightPropagator<'a> {
+    registry: &'a dyn BlockOpacityProvider,
+    removal: VecDeque<(BlockPos, u8)>,
+    borders: Vec<BlockPos>,
+    sources: Vec<(BlockPos, u8)>,
+}
+
+impl<'a> LightPropagator<'a> {
+    /// Create a propagator using `registry` for opacity and emission.
+    pub fn new(registry: &'a dyn BlockOpacityProvider) -> Self {
+        Self {
+            registry,
+            removal: VecDeque::new(),
+            borders: Vec::new(),
+            sources: Vec::new(),
+        }
+    }
+
+    /// Relight both sky and block light for `region`.
+    ///
+    /// Returns every loaded chunk whose light values changed.
+    pub fn propagate_all(
+        &self,
+        chunks: &mut HashMap<ChunkPos, Chunk>,
+        region: impl IntoIterator<Item = ChunkPos>,
+    ) -> BTreeSet<ChunkPos> {
+        let region: BTreeSet<ChunkPos> = region.into_iter().collect();
+        let mut changed = self.propagate(chunks, region.iter().copied(), LightType::Skylight);
+        changed.extend(self.propagate(chunks, region, LightType::BlockLight));
+        changed
+    }
+
+    /// Relight one light channel for `region`.
+    ///
+    /// Returns every loaded chunk whose `light_type` values changed.
+    pub fn propagate(
+        &self,
+        chunks: &mut HashMap<ChunkPos, Chunk>,
+        region: impl IntoIterator<Item = ChunkPos>,
+        light_type: LightType,
+    ) -> BTreeSet<ChunkPos> {
+        let region: BTreeSet<ChunkPos> = region
+            .into_iter()
+            .filter(|pos| chunks.contains_key(pos))
+            .collect();
+
+        // Snapshot the old light so we only report chunks that actually changed.
+        let mut before: Vec<(ChunkPos, Vec<u8>)> = Vec::with_capacity(region.len());
+        let mut queue: VecDeque<(BlockPos, u8)> = VecDeque::new();
+
+        for chunk_pos in region.iter().copied() {
+            let Some(chunk) = chunks.get_mut(&chunk_pos) else {
+                continue;
+            };
+            let mut levels = Vec::with_capacity(CHUNK_SIZE_X * CHUNK_SIZE_Y * CHUNK_SIZE_Z);
+            for y in 0..CHUNK_SIZE_Y {
+                for z in 0..CHUNK_SIZE_Z {
+                    for x in 0..CHUNK_SIZE_X {
+                        let mut voxel = chunk.voxel(x, y, z);
+                        let level = LightType::voxel_level(&voxel, light_type);
+                        levels.push(level);
+                        if level != 0 {
+                            LightType::set_voxel_level(&mut voxel, light_type, 0);
+                            chunk.set_voxel(x, y, z, voxel);
+                        }
+                    }
+                }
+            }
+            before.push((chunk_pos, levels));
+
+            match light_type {
+                LightType::Skylight => {
+                    let y = CHUNK_SIZE_Y - 1;
+                    for z in 0..CHUNK_SIZE_Z {
+                        for x in 0..CHUNK_SIZE_X {
+                            let voxel = chunk.voxel(x, y, z);
+                            let level = MAX_LIGHT_LEVEL
+                                .saturating_sub(light_opacity_for_voxel(voxel, self.registry));
+                            seed_light(chunk, &mut queue, chunk_pos, x, y, z, level, light_type);
+                        }
+                    }
+                }
+                LightType::BlockLight => {
+                    for y in 0..CHUNK_SIZE_Y {
+                        for z in 0..CHUNK_SIZE_Z {
+                            for x in 0..CHUNK_SIZE_X {
+                                let voxel = chunk.voxel(x, y, z);
+                                let level = crate::block_light_emission(
+                                    voxel.id,
+                                    voxel.state,
+                                    self.registry,
+                                );
+                                seed_light(
+                                    chunk, &mut queue, chunk_pos, x, y, z, level, light_type,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Import light already present on the borders of loaded neighbors outside the region.
+        for chunk_pos in region.iter().copied() {
+            let borders = [
+                (
+                    ChunkPos::new(chunk_pos.x - 1, chunk_pos.z),
+                    Some(CHUNK_SIZE_X - 1),
+                    None,
+                ),
+                (ChunkPos::new(chunk_pos.x + 1, chunk_pos.z), Some(0), None),
+                (
+                    ChunkPos::new(chunk_pos.x, chunk_pos.z - 1),
+                    None,
+                    Some(CHUNK_SIZE_Z - 1),
+                ),
+                (ChunkPos::new(chunk_pos.x, chunk_pos.z + 1), None, Some(0)),
+            ];
+            for (neighbor_pos, fixed_x, fixed_z) in borders {
+                if region.contains(&neighbor_pos) {
+                    continue;
+                }
+                let Some(neighbor) = chunks.get(&neighbor_pos) else {
+                    continue;
+                };
+                for y in 0..CHUNK_SIZE_Y {
+                    for i in 0..CHUNK_SIZE_X.max(CHUNK_SIZE_Z) {
+                        let x = fixed_x.unwrap_or(i);
+                        let z = fixed_z.unwrap_or(i);
+                        if x >= CHUNK_SIZE_X || z >= CHUNK_SIZE_Z {
+                            continue;
+                        }
+                        let voxel = neighbor.voxel(x, y, z);
+                        enqueue_seed(
+                            &mut queue,
+                            self.registry,
+                            neighbor_pos,
+                            x,
+                            y,
+                            z,
+                            voxel,
+                            light_type,
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut changed: BTreeSet<ChunkPos> = flood_light(chunks, self.registry, queue, light_type)
+            .into_iter()
+            .filter(|pos| !region.contains(pos))
+            .collect();
+
+        for (chunk_pos, levels) in before {
+            let Some(chunk) = chunks.get(&chunk_pos) else {
+                continue;
+            };
+            let mut old = levels.into_iter();
+            'scan: for y in 0..CHUNK_SIZE_Y {
+                for z in 0..CHUNK_SIZE_Z {
+                    for x in 0..CHUNK_SIZE_X {
+                        let level = LightType::voxel_level(&chunk.voxel(x, y, z), light_type);
+                        if old.next() != Some(level) {
+                            changed.insert(chunk_pos);
+                            break 'scan;
+                        }
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Update light after the voxel at `pos` changed from `old` to `new`.
+    ///
+    /// Call this once the new voxel has been written. Light that `old` carried or enabled is
+    /// removed with a reverse BFS, then the boundary of the darkened area (plus any emission
+    /// from `new`) is flooded back in, so only voxels the edit can affect are touched.
+    ///
+    /// Returns the loaded chunks whose light may have changed.
+    pub fn on_block_changed(
+        &mut self,
+        chunks: &mut HashMap<ChunkPos, Chunk>,
+        pos: BlockPos,
+        old: crate::chunk::Voxel,
+        new: crate::chunk::Voxel,
+    ) -> BTreeSet<ChunkPos> {
+        let mut changed = BTreeSet::new();
+        if !chunks.contains_key(&pos.chunk) {
+            return changed;
+        }
+        changed.insert(pos.chunk);
+
+        for light_type in [LightType::Skylight, LightType::BlockLight] {
+            let removal = &mut self.removal;
+            // Lit voxels bordering the cleared area, and light sources inside it.
+            let borders = &mut self.borders;
+            let sources = &mut self.sources;
+            removal.clear();
+            borders.clear();
+            sources.clear();
+
+            if let Some(chunk) = chunks.get_mut(&pos.chunk) {
+                let mut voxel = chunk.voxel(pos.local.x, pos.local.y, pos.local.z);
+                LightType::set_voxel_level(&mut voxel, light_type, 0);
+                chunk.set_voxel(pos.local.x, pos.local.y, pos.local.z, voxel);
+            }
+            removal.push_back((pos, LightType::voxel_level(&old, light_type)));
+
+            while let Some((current, level)) = removal.pop_front() {
+                if light_type == LightType::Skylight && current.local.y == CHUNK_SIZE_Y - 1 {
+                    // Cleared a voxel that sees open sky; re-seed it.
+                    sources.push((current, MAX_LIGHT_LEVEL));
+                }
+
+                for (dx, dy, dz) in [
+                    (0, 1, 0),
+                    (0, -1, 0),
+                    (1, 0, 0),
+                    (-1, 0, 0),
+                    (0, 0, 1),
+                    (0, 0, -1),
+                ] {
+                    let Some((neighbor_chunk, local)) = neighbor_block(current, dx, dy, dz) else {
+                        continue;
+                    };
+                    let Some(chunk) = chunks.get_mut(&neighbor_chunk) else {
+                        continue;
+                    };
+                    let mut voxel = chunk.voxel(local.x, local.y, local.z);
+                    let neighbor_level = LightType::voxel_level(&voxel, light_type);
+                    if neighbor_level == 0 {
+                        continue;
+                    }
+
+                    // Skylight travels straight down without decaying, so an equal level
+                    // below may also have come from the cleared voxel.
+                    let lit_by_current = neighbor_level < level
+                        || (light_type == LightType::Skylight
+                            && dy == -1
+                            && neighbor_level == level);
+                    let neighbor = BlockPos::new(neighbor_chunk, local);
+                    if lit_by_current {
+                        LightType::set_voxel_level(&mut voxel, light_type, 0);
+                        chunk.set_voxel(local.x, local.y, local.z, voxel);
+                        changed.insert(neighbor_chunk);
+                        removal.push_back((neighbor, neighbor_level));
+                    } else {
+                        borders.push(neighbor);
+                    }
+                }
+            }
+
+            if light_type == LightType::BlockLight {
+                let emission = crate::block_light_emission(new.id, new.state, self.registry);
+                sources.push((pos, emission));
+            }
+
+            let mut seeds: VecDeque<(BlockPos, u8)> = VecDeque::new();
+            for border in borders.drain(..) {
+                // A border can itself be cleared later through another path.
+                let level = chunks.get(&border.chunk).map_or(0, |chunk| {
+                    let voxel = chunk.voxel(border.local.x, border.local.y, border.local.z);
+                    LightType::voxel_level(&voxel, light_type)
+                });
+                if level > 0 {
+                    seeds.push_back((border, level));
+                }
+            }
+            for (source, level) in sources.drain(..) {
+                let Some(chunk) = chunks.get_mut(&source.chunk) else {
+                    continue;
+                };
+                let BlockPos {
+                    chunk: chunk_pos,
+                    local: LocalPos { x, y, z },
+                } = source;
+                let level = if light_type == LightType::Skylight {
+                    level.saturating_sub(light_opacity_for_voxel(
+                        chunk.voxel(x, y, z),
+                        self.registry,
+                    ))
+                } else {
+                    level
+                };
+                seed_light(chunk, &mut seeds, chunk_pos, x, y, z, level, light_type);
+            }
+
+            changed.extend(flood_light(chunks, self.registry, seeds, light_type));
+        }
+
+        changed
+    }
+}
+
+/// Spread light outward from `queue`, whose entries already hold their level in the world.
+///
+/// Returns every chunk in which a voxel's light was raised.
+fn flood_light(
+    chunks: &mut HashMap<ChunkPos, Chunk>,
+    registry: &dyn BlockOpacityProvider,
+    mut queue: VecDeque<(BlockPos, u8)>,
+    light_type: LightType,
+) -> BTreeSet<ChunkPos> {
+    let mut changed = BTreeSet::new();
+    while let Some((pos, level)) = queue.pop_front() {
+        for (dx, dy, dz) in [
+            (0, 1, 0),
+            (0, -1, 0),
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ] {
+            let Some((neighbor_chunk, local)) = neighbor_block(pos, dx, dy, dz) else {
+                continue;
+            };
+            let Some(chunk) = chunks.get_mut(&neighbor_chunk) else {
+                continue;
+            };
+
+            let voxel = chunk.voxel(local.x, local.y, local.z);
+            let opacity = light_opacity_for_voxel(voxel, registry);
+            if opacity >= MAX_LIGHT_LEVEL {
+                continue;
+            }
+            let decay = if light_type == LightType::Skylight && dy == -1 {
+                opacity
+            } else {
+                opacity.max(1)
+            };
+            let new_level = level.saturating_sub(decay);
+            if new_level == 0 || LightType::voxel_level(&voxel, light_type) >= new_level {
+                continue;
+            }
+
+            let mut updated = voxel;
+            LightType::set_voxel_level(&mut updated, light_type, new_level);
+            chunk.set_voxel(local.x, local.y, local.z, updated);
+            changed.insert(neighbor_chunk);
+            queue.push_back((BlockPos::new(neighbor_chunk, local), new_level));
+        }
+    }
+    changed
+}
+
+/// Write a light source into a freshly cleared voxel and queue it for propagation.
+#[allow(clippy::too_many_arguments)]
+fn seed_light(
+    chunk: &mut Chunk,
+    queue: &mut VecDeque<(BlockPos, u8)>,
+    chunk_pos: ChunkPos,
+    x: usize,
+    y: usize,
+    z: usize,
+    level: u8,
+    light_type: LightType,
+) {
+    if level == 0 {
+        return;
+    }
+    let mut voxel = chunk.voxel(x, y, z);
+    if LightType::voxel_level(&voxel, light_type) >= level {
+        return;
+    }
+    LightType::set_voxel_level(&mut voxel, light_type, level);
+    chunk.set_voxel(x, y, z, voxel);
+    queue.push_back((BlockPos::new(chunk_pos, LocalPos { x, y, z }), level));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1458,4 +1847,271 @@ mod tests {
         assert_eq!(chunk_b.voxel(0, 50, 8).light_sky, MAX_LIGHT_LEVEL - 1);
         assert_eq!(chunk_b.voxel(1, 50, 8).light_sky, MAX_LIGHT_LEVEL - 2);
     }
+
+    #[test]
+    fn light_propagator_spreads_torch_across_chunk_seam() {
+        let mut chunks = HashMap::new();
+        let pos_a = ChunkPos::new(0, 0);
+        let pos_b = ChunkPos::new(1, 0);
+        let pos_far = ChunkPos::new(5, 5);
+        let mut chunk_a = Chunk::new(pos_a);
+
+        // Block 1 is a transparent emitter (level 15) in `EmissiveRegistry`.
+        let torch = LocalPos {
+            x: CHUNK_SIZE_X - 1,
+            y: 64,
+            z: 8,
+        };
+        chunk_a.set_voxel(
+            torch.x,
+            torch.y,
+            torch.z,
+            Voxel {
+                id: 1,
+                ..Default::default()
+            },
+        );
+        chunks.insert(pos_a, chunk_a);
+        chunks.insert(pos_b, Chunk::new(pos_b));
+        chunks.insert(pos_far, Chunk::new(pos_far));
+
+        let registry = EmissiveRegistry;
+        let changed =
+            LightPropagator::new(&registry).propagate(&mut chunks, [pos_a], LightType::BlockLight);
+        assert_eq!(changed, BTreeSet::from([pos_a, pos_b]));
+
+        let chunk_a = &chunks[&pos_a];
+        assert_eq!(
+            chunk_a.voxel(torch.x, torch.y, torch.z).light_block,
+            MAX_LIGHT_LEVEL
+        );
+        assert_eq!(
+            chunk_a.voxel(torch.x - 3, torch.y, torch.z).light_block,
+            MAX_LIGHT_LEVEL - 3
+        );
+
+        // Light keeps decaying by one per block into the neighbor chunk.
+        let chunk_b = &chunks[&pos_b];
+        for step in 0..5 {
+            let expected = MAX_LIGHT_LEVEL - 1 - step as u8;
+            assert_eq!(chunk_b.voxel(step, torch.y, torch.z).light_block, expected);
+        }
+        assert_eq!(
+            chunk_b.voxel(0, torch.y + 2, torch.z + 3).light_block,
+            MAX_LIGHT_LEVEL - 6
+        );
+        assert_eq!(chunk_b.voxel(14, torch.y, torch.z).light_block, 0);
+
+        // Re-running is a no-op, and skylight is untouched by a block-light pass.
+        let changed =
+            LightPropagator::new(&registry).propagate(&mut chunks, [pos_a], LightType::BlockLight);
+        assert!(changed.is_empty());
+        assert_eq!(chunks[&pos_b].voxel(0, torch.y, torch.z).light_sky, 0);
+    }
+
+    #[test]
+    fn light_propagator_imports_light_from_neighbor_outside_region() {
+        let mut chunks = HashMap::new();
+        let pos_a = ChunkPos::new(0, 0);
+        let pos_b = ChunkPos::new(0, 1);
+        let mut chunk_a = Chunk::new(pos_a);
+        let mut chunk_b = Chunk::new(pos_b);
+
+        // Roof over chunk B so its only skylight comes through the seam with chunk A.
+        for x in 0..CHUNK_SIZE_X {
+            for z in 0..CHUNK_SIZE_Z {
+                chunk_b.set_voxel(
+                    x,
+                    200,
+                    z,
+                    Voxel {
+                        id: 2,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        let registry = MockRegistry;
+        let _ = init_skylight(&mut chunk_a, &registry);
+        chunks.insert(pos_a, chunk_a);
+        chunks.insert(pos_b, chunk_b);
+
+        let changed = LightPropagator::new(&registry).propagate_all(&mut chunks, [pos_b]);
+        assert_eq!(changed, BTreeSet::from([pos_b]));
+
+        let chunk_b = &chunks[&pos_b];
+        assert_eq!(chunk_b.voxel(4, 201, 4).light_sky, MAX_LIGHT_LEVEL);
+        assert_eq!(chunk_b.voxel(4, 100, 0).light_sky, MAX_LIGHT_LEVEL - 1);
+        assert_eq!(chunk_b.voxel(4, 100, 3).light_sky, MAX_LIGHT_LEVEL - 4);
+        // Chunk A was outside the region and already fully lit.
+        assert_eq!(chunks[&pos_a].voxel(4, 100, 15).light_sky, MAX_LIGHT_LEVEL);
+    }
+
+    fn assert_same_light(actual: &HashMap<ChunkPos, Chunk>, expected: &HashMap<ChunkPos, Chunk>) {
+        for (pos, expected_chunk) in expected {
+            let actual_chunk = &actual[pos];
+            for y in 0..CHUNK_SIZE_Y {
+                for z in 0..CHUNK_SIZE_Z {
+                    for x in 0..CHUNK_SIZE_X {
+                        let a = actual_chunk.voxel(x, y, z);
+                        let e = expected_chunk.voxel(x, y, z);
+                        assert_eq!(
+                            (a.light_sky, a.light_block),
+                            (e.light_sky, e.light_block),
+                            "light mismatch in {pos:?} at ({x}, {y}, {z})"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// One chunk with an opaque roof at y=200 over x < 8; optionally with a hole at (2, 8).
+    fn overhang_world(hole: bool) -> HashMap<ChunkPos, Chunk> {
+        let pos = ChunkPos::new(0, 0);
+        let mut chunk = Chunk::new(pos);
+        for x in 0..8 {
+            for z in 0..CHUNK_SIZE_Z {
+                if hole && (x, z) == (2, 8) {
+                    continue;
+                }
+                chunk.set_voxel(
+                    x,
+                    200,
+                    z,
+                    Voxel {
+                        id: 2,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        let mut chunks = HashMap::from([(pos, chunk)]);
+        LightPropagator::new(&MockRegistry).propagate_all(&mut chunks, [pos]);
+        chunks
+    }
+
+    #[test]
+    fn breaking_overhang_block_lets_skylight_in() {
+        let registry = MockRegistry;
+        let pos = ChunkPos::new(0, 0);
+        let mut chunks = overhang_world(false);
+        assert_eq!(chunks[&pos].voxel(2, 150, 8).light_sky, MAX_LIGHT_LEVEL - 6);
+
+        let roof = BlockPos::new(pos, LocalPos { x: 2, y: 200, z: 8 });
+        let old = chunks[&pos].voxel(2, 200, 8);
+        chunks
+            .get_mut(&pos)
+            .unwrap()
+            .set_voxel(2, 200, 8, Voxel::default());
+        let dirty = LightPropagator::new(&registry).on_block_changed(
+            &mut chunks,
+            roof,
+            old,
+            Voxel::default(),
+        );
+
+        assert_eq!(dirty, BTreeSet::from([pos]));
+        assert_eq!(chunks[&pos].voxel(2, 150, 8).light_sky, MAX_LIGHT_LEVEL);
+        assert_eq!(chunks[&pos].voxel(3, 150, 8).light_sky, MAX_LIGHT_LEVEL - 1);
+        assert_same_light(&chunks, &overhang_world(true));
+    }
+
+    #[test]
+    fn placing_solid_block_casts_shadow() {
+        let registry = MockRegistry;
+        let pos_a = ChunkPos::new(0, 0);
+        let pos_b = ChunkPos::new(1, 0);
+        let stone = Voxel {
+            id: 2,
+            ..Default::default()
+        };
+        let open_world = || {
+            let mut chunks =
+                HashMap::from([(pos_a, Chunk::new(pos_a)), (pos_b, Chunk::new(pos_b))]);
+            LightPropagator::new(&registry).propagate_all(&mut chunks, [pos_a, pos_b]);
+            chunks
+        };
+
+        let mut chunks = open_world();
+        let block = BlockPos::new(
+            pos_a,
+            LocalPos {
+                x: CHUNK_SIZE_X - 1,
+                y: 100,
+                z: 8,
+            },
+        );
+        let old = chunks[&pos_a].voxel(CHUNK_SIZE_X - 1, 100, 8);
+        chunks
+            .get_mut(&pos_a)
+            .unwrap()
+            .set_voxel(CHUNK_SIZE_X - 1, 100, 8, stone);
+        let dirty =
+            LightPropagator::new(&registry).on_block_changed(&mut chunks, block, old, stone);
+
+        let chunk_a = &chunks[&pos_a];
+        assert_eq!(chunk_a.voxel(CHUNK_SIZE_X - 1, 100, 8).light_sky, 0);
+        assert_eq!(
+            chunk_a.voxel(CHUNK_SIZE_X - 1, 99, 8).light_sky,
+            MAX_LIGHT_LEVEL - 1
+        );
+        assert_eq!(
+            chunk_a.voxel(CHUNK_SIZE_X - 1, 10, 8).light_sky,
+            MAX_LIGHT_LEVEL - 1
+        );
+        assert_eq!(
+            chunk_a.voxel(CHUNK_SIZE_X - 1, 101, 8).light_sky,
+            MAX_LIGHT_LEVEL
+        );
+        assert_eq!(dirty, BTreeSet::from([pos_a]));
+
+        let mut expected = open_world();
+        expected
+            .get_mut(&pos_a)
+            .unwrap()
+            .set_voxel(CHUNK_SIZE_X - 1, 100, 8, stone);
+        LightPropagator::new(&registry).propagate_all(&mut expected, [pos_a, pos_b]);
+        assert_same_light(&chunks, &expected);
+    }
+
+    #[test]
+    fn breaking_torch_at_seam_clears_light_in_both_chunks() {
+        let registry = EmissiveRegistry;
+        let pos_a = ChunkPos::new(0, 0);
+        let pos_b = ChunkPos::new(0, -1);
+        let mut chunks = HashMap::from([(pos_a, Chunk::new(pos_a)), (pos_b, Chunk::new(pos_b))]);
+        let torch = Voxel {
+            id: 1,
+            ..Default::default()
+        };
+        let at = BlockPos::new(pos_a, LocalPos { x: 4, y: 64, z: 0 });
+
+        let mut propagator = LightPropagator::new(&registry);
+        chunks.get_mut(&pos_a).unwrap().set_voxel(4, 64, 0, torch);
+        let placed = propagator.on_block_changed(&mut chunks, at, Voxel::default(), torch);
+        assert_eq!(placed, BTreeSet::from([pos_a, pos_b]));
+        assert_eq!(
+            chunks[&pos_b].voxel(4, 64, CHUNK_SIZE_Z - 2).light_block,
+            MAX_LIGHT_LEVEL - 2
+        );
+
+        let old = chunks[&pos_a].voxel(4, 64, 0);
+        chunks
+            .get_mut(&pos_a)
+            .unwrap()
+            .set_voxel(4, 64, 0, Voxel::default());
+        let broken = propagator.on_block_changed(&mut chunks, at, old, Voxel::default());
+        assert_eq!(broken, BTreeSet::from([pos_a, pos_b]));
+        for pos in [pos_a, pos_b] {
+            let chunk = &chunks[&pos];
+            for y in 50..80 {
+                for z in 0..CHUNK_SIZE_Z {
+                    for x in 0..CHUNK_SIZE_X {
+                        assert_eq!(chunk.voxel(x, y, z).light_block, 0);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -1,6 +1,18 @@
 //! Noise generation utilities for terrain generation.
 //!
-//! Provides deterministic noise functions for heightmap and biome generation.
+//! Terrain, biome, cave and aquifer generation sample [`NoiseGenerator`] (via
+//! [`LayeredNoise`] for heightmaps), which wraps the `noise` crate's Perlin
+//! tables. [`Noise`] is an opt-in, dependency-free alternative for callers that
+//! need cross-platform bit-identical samples; world generation does not use
+//! it, so switching a sampler over would change existing worlds.
+//!
+//! Determinism guarantees:
+//! - Every generator is seeded explicitly; no global or thread-local RNG is used.
+//! - [`Noise`] hashes lattice coordinates with wrapping integer arithmetic and
+//!   combines gradients using only `+`, `-`, `*` and `floor`, which IEEE 754
+//!   defines exactly, so its samples are bit-identical across platforms.
+//! - Octave summation for every generator goes through [`fbm`], whose
+//!   accumulation order is fixed.
 
 use noise::{NoiseFn, Perlin, Simplex};
 
@@ -122,45 +134,19 @@ impl NoiseGenerator {
     ///
     /// Returns value in range [-1.0, 1.0].
     pub fn sample_2d(&self, x: f64, y: f64) -> f64 {
-        let mut value = 0.0;
-        let mut amplitude = 1.0;
-        let mut frequency = self.config.frequency;
-        let mut max_value = 0.0;
-
-        for _ in 0..self.config.octaves {
-            value += self.perlin.get([x * frequency, y * frequency]) * amplitude;
-            max_value += amplitude;
-
-            amplitude *= self.config.persistence;
-            frequency *= self.config.lacunarity;
-        }
-
-        // Normalize to [-1.0, 1.0]
-        value / max_value
+        fbm(&self.config, |frequency| {
+            self.perlin.get([x * frequency, y * frequency])
+        })
     }
 
     /// Generate noise value at 3D coordinates with multi-octave sampling.
     ///
     /// Returns value in range [-1.0, 1.0].
     pub fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64 {
-        let mut value = 0.0;
-        let mut amplitude = 1.0;
-        let mut frequency = self.config.frequency;
-        let mut max_value = 0.0;
-
-        for _ in 0..self.config.octaves {
-            value += self
-                .perlin
+        fbm(&self.config, |frequency| {
+            self.perlin
                 .get([x * frequency, y * frequency, z * frequency])
-                * amplitude;
-            max_value += amplitude;
-
-            amplitude *= self.config.persistence;
-            frequency *= self.config.lacunarity;
-        }
-
-        // Normalize to [-1.0, 1.0]
-        value / max_value
+        })
     }
 
     /// Sample noise and map to a specific range.
@@ -195,48 +181,232 @@ impl SimplexGenerator {
     ///
     /// Returns value in range [-1.0, 1.0].
     pub fn sample_2d(&self, x: f64, y: f64) -> f64 {
-        let mut value = 0.0;
-        let mut amplitude = 1.0;
-        let mut frequency = self.config.frequency;
-        let mut max_value = 0.0;
-
-        for _ in 0..self.config.octaves {
-            value += self.simplex.get([x * frequency, y * frequency]) * amplitude;
-            max_value += amplitude;
-
-            amplitude *= self.config.persistence;
-            frequency *= self.config.lacunarity;
-        }
-
-        // Normalize to [-1.0, 1.0]
-        value / max_value
+        fbm(&self.config, |frequency| {
+            self.simplex.get([x * frequency, y * frequency])
+        })
     }
 
     /// Generate noise value at 3D coordinates with multi-octave sampling.
     ///
     /// Returns value in range [-1.0, 1.0].
     pub fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64 {
-        let mut value = 0.0;
-        let mut amplitude = 1.0;
-        let mut frequency = self.config.frequency;
-        let mut max_value = 0.0;
-
-        for _ in 0..self.config.octaves {
-            value += self
-                .simplex
+        fbm(&self.config, |frequency| {
+            self.simplex
                 .get([x * frequency, y * frequency, z * frequency])
-                * amplitude;
-            max_value += amplitude;
+        })
+    }
+}
+
+/// Sum octaves of a noise function (fractal Brownian motion).
+///
+/// `sample` is called once per octave with that octave's frequency and should
+/// return a value in [-1.0, 1.0]. Each octave multiplies the frequency by
+/// `config.lacunarity` and the amplitude by `config.persistence`; the result is
+/// normalized back to [-1.0, 1.0]. `config.seed` is ignored here.
+pub fn fbm(config: &NoiseConfig, mut sample: impl FnMut(f64) -> f64) -> f64 {
+    let mut value = 0.0;
+    let mut amplitude = 1.0;
+    let mut frequency = config.frequency;
+    let mut max_value = 0.0;
+
+    for _ in 0..config.octaves {
+        value += sample(frequency) * amplitude;
+        max_value += amplitude;
+
+        amplitude *= config.persistence;
+        frequency *= config.lacunarity;
+    }
+
+    // Normalize to [-1.0, 1.0]
+    value / max_value
+}
+
+/// Seeded gradient noise with platform-independent output.
+///
+/// Unlike [`NoiseGenerator`], which relies on the `noise` crate's permutation
+/// tables, gradients are picked by hashing lattice coordinates with the seed,
+/// so the same seed yields bit-identical samples on every platform and
+/// dependency version.
+///
+/// This is an opt-in API: chunk generation still samples [`NoiseGenerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Noise {
+    seed: u64,
+}
+
+impl Noise {
+    /// Create a noise source from an explicit seed.
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Seed this noise source was created with.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Single-octave 2D gradient noise in [-1.0, 1.0].
+    pub fn sample2d(&self, x: f64, y: f64) -> f64 {
+        let (x0, fx) = lattice(x);
+        let (y0, fy) = lattice(y);
+        let u = fade(fx);
+        let v = fade(fy);
+
+        let g00 = self.grad2(x0, y0, fx, fy);
+        let g10 = self.grad2(x0 + 1, y0, fx - 1.0, fy);
+        let g01 = self.grad2(x0, y0 + 1, fx, fy - 1.0);
+        let g11 = self.grad2(x0 + 1, y0 + 1, fx - 1.0, fy - 1.0);
+
+        lerp(v, lerp(u, g00, g10), lerp(u, g01, g11)).clamp(-1.0, 1.0)
+    }
+
+    /// Single-octave 3D gradient noise in [-1.0, 1.0].
+    pub fn sample3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (x0, fx) = lattice(x);
+        let (y0, fy) = lattice(y);
+        let (z0, fz) = lattice(z);
+        let u = fade(fx);
+        let v = fade(fy);
+        let w = fade(fz);
+
+        let g000 = self.grad3(x0, y0, z0, fx, fy, fz);
+        let g100 = self.grad3(x0 + 1, y0, z0, fx - 1.0, fy, fz);
+        let g010 = self.grad3(x0, y0 + 1, z0, fx, fy - 1.0, fz);
+        let g110 = self.grad3(x0 + 1, y0 + 1, z0, fx - 1.0, fy - 1.0, fz);
+        let g001 = self.grad3(x0, y0, z0 + 1, fx, fy, fz - 1.0);
+        let g101 = self.grad3(x0 + 1, y0, z0 + 1, fx - 1.0, fy, fz - 1.0);
+        let g011 = self.grad3(x0, y0 + 1, z0 + 1, fx, fy - 1.0, fz - 1.0);
+        let g111 = self.grad3(x0 + 1, y0 + 1, z0 + 1, fx - 1.0, fy - 1.0, fz - 1.0);
+
+        let near = lerp(v, lerp(u, g000, g100), lerp(u, g010, g110));
+        let far = lerp(v, lerp(u, g001, g101), lerp(u, g011, g111));
+        lerp(w, near, far).clamp(-1.0, 1.0)
+    }
+
+    /// Multi-octave 2D noise using the octave settings in `config`.
+    pub fn fbm2d(&self, x: f64, y: f64, config: &NoiseConfig) -> f64 {
+        fbm(config, |frequency| {
+            self.sample2d(x * frequency, y * frequency)
+        })
+    }
+
+    /// Multi-octave 3D noise using the octave settings in `config`.
+    pub fn fbm3d(&self, x: f64, y: f64, z: f64, config: &NoiseConfig) -> f64 {
+        fbm(config, |frequency| {
+            self.sample3d(x * frequency, y * frequency, z * frequency)
+        })
+    }
+
+    /// Single-octave 4D gradient noise in [-1.0, 1.0].
+    ///
+    /// Useful for animating or slicing a 3D field along a fourth axis: nearby
+    /// `w` values give gradually changing output.
+    pub fn sample4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        let cells = [lattice(x), lattice(y), lattice(z), lattice(w)];
+
+        let mut corners = [0.0; 16];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let mut lattice_pos = [0i64; 4];
+            let mut offset = [0.0; 4];
+            for (axis, &(base, frac)) in cells.iter().enumerate() {
+                let bit = (i >> axis) & 1;
+                lattice_pos[axis] = base + bit as i64;
+                offset[axis] = frac - bit as f64;
+            }
+            *corner = self.grad4(lattice_pos, offset);
+        }
+
+        // Collapse one axis at a time (x first), halving the corner set each pass.
+        let mut len = corners.len();
+        for &(_, frac) in &cells {
+            let t = fade(frac);
+            len /= 2;
+            for i in 0..len {
+                corners[i] = lerp(t, corners[2 * i], corners[2 * i + 1]);
+            }
+        }
+        corners[0].clamp(-1.0, 1.0)
+    }
 
-            amplitude *= self.config.persistence;
-            frequency *= self.config.lacunarity;
+    /// Multi-octave 4D noise using the octave settings in `config`.
+    pub fn fbm4d(&self, x: f64, y: f64, z: f64, w: f64, config: &NoiseConfig) -> f64 {
+        fbm(config, |frequency| {
+            self.sample4d(x * frequency, y * frequency, z * frequency, w * frequency)
+        })
+    }
+
+    fn hash(&self, coords: &[i64]) -> u64 {
+        let mut h = self.seed ^ 0x9E37_79B9_7F4A_7C15;
+        for &coord in coords {
+            h ^= coord as u64;
+            h = h.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            h ^= h >> 31;
+        }
+        h = h.wrapping_mul(0x94D0_49BB_1331_11EB);
+        h ^ (h >> 29)
+    }
+
+    fn grad2(&self, ix: i64, iy: i64, dx: f64, dy: f64) -> f64 {
+        match self.hash(&[ix, iy, 0]) & 7 {
+            0 => dx + dy,
+            1 => -dx + dy,
+            2 => dx - dy,
+            3 => -dx - dy,
+            4 => dx,
+            5 => -dx,
+            6 => dy,
+            _ => -dy,
         }
+    }
 
-        // Normalize to [-1.0, 1.0]
-        value / max_value
+    fn grad3(&self, ix: i64, iy: i64, iz: i64, dx: f64, dy: f64, dz: f64) -> f64 {
+        // The 12 cube-edge gradients of improved Perlin noise (4 repeated to fill 16).
+        match self.hash(&[ix, iy, iz]) & 15 {
+            0 | 12 => dx + dy,
+            1 | 13 => -dx + dy,
+            2 => dx - dy,
+            3 => -dx - dy,
+            4 => dx + dz,
+            5 => -dx + dz,
+            6 => dx - dz,
+            7 => -dx - dz,
+            8 => dy + dz,
+            9 | 14 => -dy + dz,
+            10 => dy - dz,
+            _ => -dy - dz,
+        }
+    }
+
+    fn grad4(&self, lattice_pos: [i64; 4], offset: [f64; 4]) -> f64 {
+        // 32 gradients: one axis zeroed, the other three set to +/-1.
+        let h = self.hash(&lattice_pos);
+        let zero_axis = (h & 3) as usize;
+        let mut sign_bits = h >> 2;
+        let mut dot = 0.0;
+        for (axis, &d) in offset.iter().enumerate() {
+            if axis == zero_axis {
+                continue;
+            }
+            dot += if sign_bits & 1 == 0 { d } else { -d };
+            sign_bits >>= 1;
+        }
+        dot
     }
 }
 
+fn lattice(v: f64) -> (i64, f64) {
+    let floor = v.floor();
+    (floor as i64, v - floor)
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
 /// Combines multiple noise layers for terrain generation.
 pub struct LayeredNoise {
     continental: NoiseGenerator,
@@ -509,4 +679,66 @@ mod tests {
         assert!((-1.0..=1.0).contains(&erosion));
         assert!((-1.0..=1.0).contains(&peaks));
     }
+
+    #[test]
+    fn noise_same_seed_is_identical() {
+        let a = Noise::new(0xDEAD_BEEF);
+        let b = Noise::new(0xDEAD_BEEF);
+        let config = NoiseConfig {
+            octaves: 5,
+            frequency: 0.03,
+            ..Default::default()
+        };
+
+        for i in -20..20 {
+            let x = i as f64 * 1.37;
+            let y = i as f64 * -0.61;
+            let z = i as f64 * 2.11;
+            assert_eq!(a.sample2d(x, y).to_bits(), b.sample2d(x, y).to_bits());
+            assert_eq!(a.sample3d(x, y, z).to_bits(), b.sample3d(x, y, z).to_bits());
+            assert_eq!(
+                a.fbm3d(x, y, z, &config).to_bits(),
+                b.fbm3d(x, y, z, &config).to_bits()
+            );
+        }
+
+        assert_ne!(
+            Noise::new(1).sample2d(3.3, 4.4),
+            Noise::new(2).sample2d(3.3, 4.4)
+        );
+    }
+
+    #[test]
+    fn noise_matches_recorded_reference_values() {
+        // Recorded bit patterns; a change here means worlds would regenerate differently.
+        let noise = Noise::new(42);
+        assert_eq!(noise.sample2d(12.25, -7.5).to_bits(), 0xbfbc_b000_0000_0000);
+        assert_eq!(
+            noise.sample3d(12.25, -7.5, 3.75).to_bits(),
+            0x3fe7_5070_0000_0000
+        );
+
+        let config = NoiseConfig {
+            frequency: 0.05,
+            ..Default::default()
+        };
+        assert_eq!(
+            noise.fbm2d(100.5, 200.25, &config).to_bits(),
+            0xbf81_a218_ec2d_5c12
+        );
+    }
+
+    #[test]
+    fn noise_stays_in_range_and_is_zero_on_lattice() {
+        let noise = Noise::new(7);
+        for x in -30..30 {
+            for z in -30..30 {
+                let (fx, fz) = (x as f64 * 0.173, z as f64 * 0.311);
+                assert!((-1.0..=1.0).contains(&noise.sample2d(fx, fz)));
+                assert!((-1.0..=1.0).contains(&noise.sample3d(fx, fz * 0.5, fz)));
+            }
+        }
+        assert_eq!(noise.sample2d(5.0, -3.0), 0.0);
+        assert_eq!(noise.sample3d(5.0, -3.0, 8.0), 0.0);
+    }
 }
@@ -1,6 +1,10 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
 
+use glam::IVec3;
+
+use crate::{is_fluid, BlockPropertiesRegistry, WorldView};
+
 /// A 2D grid position used by the deterministic pathfinder.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GridPos {
@@ -15,15 +19,15 @@ impl GridPos {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct OpenNode {
+struct OpenNode<P> {
     /// Total estimated cost.
     f: i32,
     /// Cost so far.
     g: i32,
-    pos: GridPos,
+    pos: P,
 }
 
-impl Ord for OpenNode {
+impl<P: Ord> Ord for OpenNode<P> {
     fn cmp(&self, other: &Self) -> Ordering {
         // BinaryHeap is a max-heap; invert comparisons so the smallest (f, g, pos) is popped first.
         other
@@ -34,7 +38,7 @@ impl Ord for OpenNode {
     }
 }
 
-impl PartialOrd for OpenNode {
+impl<P: Ord> PartialOrd for OpenNode<P> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -136,10 +140,323 @@ pub fn astar_path_4dir(
     None
 }
 
+/// Default node budget for [`AStarPathfinder`]; keeps a single search well under a tick.
+pub const DEFAULT_PATH_NODE_BUDGET: usize = 2_048;
+
+/// Deterministic A* over standable voxels for ground mobs.
+///
+/// A position is standable when its feet and head blocks are passable (not solid,
+/// not fluid, chunk loaded) and the block below is solid. From there a mob can walk
+/// to a horizontal neighbor on the same level, step up one block (given headroom),
+/// or drop down at most `max_drop` blocks; deeper gaps and fluids are avoided.
+/// Each move costs `1 + |dy|`, so the 3D Manhattan heuristic stays admissible.
+pub struct AStarPathfinder<'a> {
+    registry: &'a BlockPropertiesRegistry,
+    max_nodes: usize,
+    max_drop: i32,
+}
+
+impl<'a> AStarPathfinder<'a> {
+    /// Pathfinder with the default node budget and a 3-block safe drop.
+    pub fn new(registry: &'a BlockPropertiesRegistry) -> Self {
+        Self {
+            registry,
+            max_nodes: DEFAULT_PATH_NODE_BUDGET,
+            max_drop: 3,
+        }
+    }
+
+    /// Limit how many nodes a search may expand before giving up.
+    pub fn with_node_budget(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Limit how far a path may drop in a single move.
+    pub fn with_max_drop(mut self, max_drop: i32) -> Self {
+        self.max_drop = max_drop.max(0);
+        self
+    }
+
+    /// Find a path of standable positions from `start` to `goal`, both inclusive.
+    ///
+    /// Returns `None` when either end is not standable, no path exists, or the node
+    /// budget runs out first.
+    pub fn find_path(
+        &self,
+        world: &impl WorldView,
+        start: IVec3,
+        goal: IVec3,
+    ) -> Option<Vec<IVec3>> {
+        type Key = (i32, i32, i32);
+        fn key(pos: IVec3) -> Key {
+            (pos.x, pos.y, pos.z)
+        }
+        fn heuristic(a: IVec3, b: IVec3) -> i32 {
+            let d = (a - b).abs();
+            d.x + d.y + d.z
+        }
+
+        if !self.is_standable(world, start) || !self.is_standable(world, goal) {
+            return None;
+        }
+        if start == goal {
+            return Some(vec![start]);
+        }
+
+        let mut open = BinaryHeap::new();
+        open.push(OpenNode {
+            g: 0,
+            f: heuristic(start, goal),
+            pos: key(start),
+        });
+
+        let mut came_from: BTreeMap<Key, IVec3> = BTreeMap::new();
+        let mut g_score: BTreeMap<Key, i32> = BTreeMap::new();
+        g_score.insert(key(start), 0);
+        let mut closed: BTreeSet<Key> = BTreeSet::new();
+
+        let mut expansions = 0usize;
+        while let Some(node) = open.pop() {
+            if !closed.insert(node.pos) {
+                continue;
+            }
+            let pos = IVec3::new(node.pos.0, node.pos.1, node.pos.2);
+
+            if pos == goal {
+                let mut path = vec![goal];
+                let mut cur = goal;
+                while let Some(prev) = came_from.get(&key(cur)).copied() {
+                    path.push(prev);
+                    cur = prev;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            expansions += 1;
+            if expansions > self.max_nodes {
+                return None;
+            }
+
+            for neighbor in self.moves(world, pos) {
+                if closed.contains(&key(neighbor)) {
+                    continue;
+                }
+                let tentative_g = node.g.saturating_add(1 + (neighbor.y - pos.y).abs());
+                let best_g = g_score.get(&key(neighbor)).copied().unwrap_or(i32::MAX);
+                if tentative_g >= best_g {
+                    continue;
+                }
+
+                came_from.insert(key(neighbor), pos);
+                g_score.insert(key(neighbor), tentative_g);
+                open.push(OpenNode {
+                    g: tentative_g,
+                    f: tentative_g.saturating_add(heuristic(neighbor, goal)),
+                    pos: key(neighbor),
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Positions reachable in one move from `pos`, in a fixed order.
+    fn moves(&self, world: &impl WorldView, pos: IVec3) -> Vec<IVec3> {
+        const HORIZONTAL: [IVec3; 4] = [
+            IVec3::new(-1, 0, 0),
+            IVec3::new(1, 0, 0),
+            IVec3::new(0, 0, -1),
+            IVec3::new(0, 0, 1),
+        ];
+
+        let mut out = Vec::with_capacity(4);
+        for offset in HORIZONTAL {
+            let side = pos + offset;
+            if self.is_standable(world, side) {
+                out.push(side);
+                continue;
+            }
+
+            // Step up: jump into the block above the neighbor, needing headroom here.
+            let up = side + IVec3::Y;
+            if self.is_passable(world, pos + IVec3::Y * 2) && self.is_standable(world, up) {
+                out.push(up);
+                continue;
+            }
+
+            // Drop down: walk off the edge while the column stays clear.
+            if !self.is_passable(world, side) || !self.is_passable(world, side + IVec3::Y) {
+                continue;
+            }
+            for drop in 1..=self.max_drop {
+                let below = side - IVec3::Y * drop;
+                if !self.is_passable(world, below) {
+                    break;
+                }
+                if self.is_standable(world, below) {
+                    out.push(below);
+                    break;
+                }
+            }
+        }
+        out
+    }
+
+    /// Feet and head fit at `pos`, with solid ground beneath.
+    fn is_standable(&self, world: &impl WorldView, pos: IVec3) -> bool {
+        self.is_passable(world, pos)
+            && self.is_passable(world, pos + IVec3::Y)
+            && world.is_solid_at(pos.x, pos.y - 1, pos.z, self.registry)
+    }
+
+    /// A loaded, non-solid, non-fluid block.
+    fn is_passable(&self, world: &impl WorldView, pos: IVec3) -> bool {
+        world
+            .voxel_at(pos.x, pos.y, pos.z)
+            .is_some_and(|voxel| !self.registry.get(voxel.id).is_solid && !is_fluid(voxel.id))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{astar_path_4dir, GridPos};
-    use std::collections::BTreeSet;
+    use super::{astar_path_4dir, AStarPathfinder, GridPos};
+    use crate::{
+        BlockPropertiesRegistry, Chunk, ChunkPos, Voxel, WorldView, BLOCK_STONE, BLOCK_WATER,
+    };
+    use glam::IVec3;
+    use std::collections::{BTreeSet, HashMap};
+
+    fn block(id: u16) -> Voxel {
+        Voxel {
+            id,
+            ..Default::default()
+        }
+    }
+
+    /// One loaded chunk with a stone floor at y = 63 (mobs stand at y = 64).
+    fn flat_world() -> HashMap<ChunkPos, Chunk> {
+        let mut chunks = HashMap::new();
+        chunks.insert(ChunkPos::new(0, 0), Chunk::new(ChunkPos::new(0, 0)));
+        for x in 0..16 {
+            for z in 0..16 {
+                chunks.set_voxel_at(x, 63, z, block(BLOCK_STONE));
+            }
+        }
+        chunks
+    }
+
+    /// Stone walls either side of a 1-wide corridor along x at z = 5.
+    fn corridor_world() -> HashMap<ChunkPos, Chunk> {
+        let mut chunks = flat_world();
+        for x in 0..16 {
+            for y in 64..66 {
+                chunks.set_voxel_at(x, y, 4, block(BLOCK_STONE));
+                chunks.set_voxel_at(x, y, 6, block(BLOCK_STONE));
+            }
+        }
+        chunks
+    }
+
+    #[test]
+    fn voxel_path_follows_straight_corridor() {
+        let registry = BlockPropertiesRegistry::new();
+        let chunks = corridor_world();
+        let path = AStarPathfinder::new(&registry)
+            .find_path(&chunks, IVec3::new(1, 64, 5), IVec3::new(6, 64, 5))
+            .expect("corridor is walkable");
+        let expected: Vec<IVec3> = (1..=6).map(|x| IVec3::new(x, 64, 5)).collect();
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn voxel_path_steps_up_one_block() {
+        let registry = BlockPropertiesRegistry::new();
+        let mut chunks = corridor_world();
+        // A one-block ledge blocks the corridor from x = 4 onward.
+        for x in 4..16 {
+            chunks.set_voxel_at(x, 64, 5, block(BLOCK_STONE));
+        }
+        for x in 0..16 {
+            chunks.set_voxel_at(x, 66, 4, block(BLOCK_STONE));
+            chunks.set_voxel_at(x, 66, 6, block(BLOCK_STONE));
+        }
+
+        let path = AStarPathfinder::new(&registry)
+            .find_path(&chunks, IVec3::new(1, 64, 5), IVec3::new(6, 65, 5))
+            .expect("ledge can be climbed");
+        assert_eq!(
+            path,
+            vec![
+                IVec3::new(1, 64, 5),
+                IVec3::new(2, 64, 5),
+                IVec3::new(3, 64, 5),
+                IVec3::new(4, 65, 5),
+                IVec3::new(5, 65, 5),
+                IVec3::new(6, 65, 5),
+            ]
+        );
+
+        // A two-block wall is too tall to climb.
+        for x in 4..16 {
+            chunks.set_voxel_at(x, 65, 5, block(BLOCK_STONE));
+        }
+        assert!(AStarPathfinder::new(&registry)
+            .find_path(&chunks, IVec3::new(1, 64, 5), IVec3::new(6, 66, 5))
+            .is_none());
+    }
+
+    #[test]
+    fn voxel_path_avoids_fluid_and_deep_gaps() {
+        let registry = BlockPropertiesRegistry::new();
+        let mut chunks = corridor_world();
+        chunks.set_voxel_at(3, 64, 5, block(BLOCK_WATER));
+        let start = IVec3::new(1, 64, 5);
+        let goal = IVec3::new(6, 64, 5);
+        assert!(AStarPathfinder::new(&registry)
+            .find_path(&chunks, start, goal)
+            .is_none());
+
+        // Replace the water with a pit deeper than the safe drop.
+        chunks.set_voxel_at(3, 64, 5, block(crate::BLOCK_AIR));
+        for y in 56..64 {
+            chunks.set_voxel_at(3, y, 5, block(crate::BLOCK_AIR));
+        }
+        assert!(AStarPathfinder::new(&registry)
+            .find_path(&chunks, start, goal)
+            .is_none());
+    }
+
+    #[test]
+    fn voxel_path_unreachable_target_returns_none() {
+        let registry = BlockPropertiesRegistry::new();
+        let mut chunks = flat_world();
+        // Wall the goal in on all four sides, two blocks high.
+        let goal = IVec3::new(10, 64, 10);
+        for (dx, dz) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            for y in 64..67 {
+                chunks.set_voxel_at(goal.x + dx, y, goal.z + dz, block(BLOCK_STONE));
+            }
+        }
+
+        let pathfinder = AStarPathfinder::new(&registry).with_node_budget(10_000);
+        assert!(pathfinder
+            .find_path(&chunks, IVec3::new(1, 64, 1), goal)
+            .is_none());
+
+        // With the wall opened, a tiny budget still gives up instead of searching on.
+        for y in 64..67 {
+            chunks.set_voxel_at(goal.x - 1, y, goal.z, block(crate::BLOCK_AIR));
+        }
+        assert!(pathfinder
+            .find_path(&chunks, IVec3::new(1, 64, 1), goal)
+            .is_some());
+        assert!(AStarPathfinder::new(&registry)
+            .with_node_budget(4)
+            .find_path(&chunks, IVec3::new(1, 64, 1), goal)
+            .is_none());
+    }
 
     #[test]
     fn astar_returns_straight_path_in_open_space() {
@@ -12,6 +12,7 @@ use anyhow::{Context, Result};
 use crc32fast::Hasher;
 use mdminecraft_core::DimensionId;
 use mdminecraft_core::ItemStack as CoreItemStack;
+use mdminecraft_core::ItemType as CoreItemType;
 use mdminecraft_core::SimTick;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -24,20 +25,28 @@ use tracing::{debug, info, instrument, warn};
 /// Magic number for region file identification ("MDRG" = mdminecraft region).
 const REGION_MAGIC: u32 = 0x4D445247;
 
-/// Current region file format version.
-const REGION_VERSION: u16 = 1;
+/// Current chunk data format version.
+///
+/// Region files record the version their chunks were written with; older data is
+/// upgraded on load by the [`ChunkMigration`] steps registered on the [`RegionStore`].
+pub const CHUNK_FORMAT_VERSION: u32 = 1;
 
 /// Magic number for the world meta file ("MDWM" = mdminecraft world meta).
 const WORLD_META_MAGIC: u32 = 0x4D44574D;
 
 /// Current world meta file format version.
-const WORLD_META_VERSION: u16 = 1;
+const WORLD_META_VERSION: u16 = 2;
 
 /// Magic number for the world state file ("MDWS" = mdminecraft world state).
 const WORLD_STATE_MAGIC: u32 = 0x4D445753;
 
 /// Current world state file format version.
-const WORLD_STATE_VERSION: u16 = 2;
+const WORLD_STATE_VERSION: u16 = 3;
+
+/// Current [`PlayerSave`] format version.
+///
+/// Bump this and add a step to [`PlayerSave::migrate`] whenever persisted item ids change.
+pub const PLAYER_SAVE_VERSION: u32 = 1;
 
 /// Region size in chunks (32x32 chunks per region).
 const REGION_SIZE: i32 = 32;
@@ -52,10 +61,10 @@ struct RegionHeader {
 }
 
 impl RegionHeader {
-    fn new(crc32: u32, payload_len: u32) -> Self {
+    fn new(version: u16, crc32: u32, payload_len: u32) -> Self {
         Self {
             magic: REGION_MAGIC,
-            version: REGION_VERSION,
+            version,
             crc32,
             payload_len,
         }
@@ -105,6 +114,34 @@ pub struct WorldMeta {
     /// Whether the End boss has been defeated in this world.
     #[serde(default)]
     pub end_boss_defeated: bool,
+    /// Chunk format version the world was last saved with.
+    pub format_version: u32,
+}
+
+/// World meta as written by v1 saves (no format version; chunks are format 1).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorldMetaV1 {
+    world_seed: u64,
+    end_boss_defeated: bool,
+}
+
+impl From<WorldMetaV1> for WorldMeta {
+    fn from(meta: WorldMetaV1) -> Self {
+        Self {
+            world_seed: meta.world_seed,
+            end_boss_defeated: meta.end_boss_defeated,
+            format_version: 1,
+        }
+    }
+}
+
+/// One upgrade step for chunk data saved by an older build.
+pub trait ChunkMigration: Send + Sync {
+    /// Format version this step reads; it produces `source_version() + 1`.
+    fn source_version(&self) -> u32;
+
+    /// Upgrade `chunk` in place.
+    fn migrate(&self, chunk: &mut Chunk) -> Result<()>;
 }
 
 /// Global world state that must survive save/load cycles.
@@ -148,6 +185,8 @@ pub struct PlayerTransform {
 /// Persisted player state for singleplayer saves.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerSave {
+    /// Format version (see [`PLAYER_SAVE_VERSION`]).
+    pub version: u32,
     pub transform: PlayerTransform,
     pub spawn_point: WorldPoint,
     pub hotbar: [Option<CoreItemStack>; 9],
@@ -162,6 +201,82 @@ pub struct PlayerSave {
     pub status_effects: StatusEffects,
 }
 
+impl PlayerSave {
+    /// Upgrade a save written by an older build to [`PLAYER_SAVE_VERSION`].
+    ///
+    /// Steps run one version at a time so each item-id change only has to
+    /// describe its own remapping. Saves from a newer build are rejected.
+    pub fn migrate(mut self) -> Result<Self> {
+        if self.version > PLAYER_SAVE_VERSION {
+            anyhow::bail!(
+                "Unsupported player save version {} (newest supported is {}). Game upgrade required.",
+                self.version,
+                PLAYER_SAVE_VERSION
+            );
+        }
+
+        while self.version < PLAYER_SAVE_VERSION {
+            match self.version {
+                0 => self.migrate_v0_to_v1(),
+                other => unreachable!("no migration step from player save version {other}"),
+            }
+            self.version += 1;
+        }
+
+        Ok(self)
+    }
+
+    /// v0 used temporary item ids for dropped-item conversions.
+    fn migrate_v0_to_v1(&mut self) {
+        for stack in self.hotbar.iter_mut().flatten() {
+            if let CoreItemType::Item(id) = stack.item_type {
+                match id {
+                    100 => stack.item_type = CoreItemType::Item(3), // Stick
+                    101 => stack.item_type = CoreItemType::Item(6), // Feather
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Player state as written before [`PlayerSave`] carried a version (world state v2).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PlayerSaveV0 {
+    transform: PlayerTransform,
+    spawn_point: WorldPoint,
+    hotbar: [Option<CoreItemStack>; 9],
+    hotbar_selected: usize,
+    inventory: Inventory,
+    health: f32,
+    hunger: f32,
+    xp_level: u32,
+    xp_current: u32,
+    xp_next_level_xp: u32,
+    armor: PlayerArmor,
+    status_effects: StatusEffects,
+}
+
+impl From<PlayerSaveV0> for PlayerSave {
+    fn from(save: PlayerSaveV0) -> Self {
+        Self {
+            version: 0,
+            transform: save.transform,
+            spawn_point: save.spawn_point,
+            hotbar: save.hotbar,
+            hotbar_selected: save.hotbar_selected,
+            inventory: save.inventory,
+            health: save.health,
+            hunger: save.hunger,
+            xp_level: save.xp_level,
+            xp_current: save.xp_current,
+            xp_next_level_xp: save.xp_next_level_xp,
+            armor: save.armor,
+            status_effects: save.status_effects,
+        }
+    }
+}
+
 /// World entities persisted outside of chunk voxel data.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WorldEntitiesState {
@@ -217,7 +332,31 @@ pub struct WorldState {
     pub block_entities: BlockEntitiesState,
 }
 
+/// World state as written by v2 saves (unversioned player data).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorldStateV2 {
+    tick: SimTick,
+    sim_time: SimTime,
+    weather: WeatherToggle,
+    weather_next_change_tick: SimTick,
+    player: Option<PlayerSaveV0>,
+    entities: WorldEntitiesState,
+    block_entities: BlockEntitiesState,
+}
+
 impl WorldState {
+    fn from_v2(state: WorldStateV2) -> Self {
+        Self {
+            tick: state.tick,
+            sim_time: state.sim_time,
+            weather: state.weather,
+            weather_next_change_tick: state.weather_next_change_tick,
+            player: state.player.map(PlayerSave::from),
+            entities: state.entities,
+            block_entities: state.block_entities,
+        }
+    }
+
     fn from_v1(state: WorldStateV1) -> Self {
         let remaining_seconds =
             (state.next_weather_change_seconds - state.weather_timer_seconds).max(0.0);
@@ -294,6 +433,8 @@ fn chunk_to_region(chunk_pos: ChunkPos) -> (i32, i32) {
 /// Region file manager for saving/loading chunks.
 pub struct RegionStore {
     world_dir: PathBuf,
+    /// Registered chunk upgrade steps, keyed by the version they upgrade from.
+    migrations: BTreeMap<u32, Box<dyn ChunkMigration>>,
 }
 
 impl RegionStore {
@@ -301,7 +442,54 @@ impl RegionStore {
     pub fn new<P: AsRef<Path>>(world_dir: P) -> Result<Self> {
         let world_dir = world_dir.as_ref().to_path_buf();
         fs::create_dir_all(&world_dir).context("Failed to create world directory")?;
-        Ok(Self { world_dir })
+        Ok(Self {
+            world_dir,
+            migrations: BTreeMap::new(),
+        })
+    }
+
+    /// Register a chunk upgrade step.
+    ///
+    /// A step from the current [`format_version`](Self::format_version) raises it by
+    /// one, so chunks saved afterwards are written in the upgraded format.
+    pub fn register_migration(&mut self, migration: impl ChunkMigration + 'static) {
+        self.migrations
+            .insert(migration.source_version(), Box::new(migration));
+    }
+
+    /// Newest chunk format this store can read; chunks are saved in this format.
+    pub fn format_version(&self) -> u32 {
+        let mut version = CHUNK_FORMAT_VERSION;
+        while self.migrations.contains_key(&version) {
+            version += 1;
+        }
+        version
+    }
+
+    fn ensure_supported_version(&self, version: u32, what: &str) -> Result<()> {
+        let supported = self.format_version();
+        if version > supported {
+            anyhow::bail!(
+                "Unsupported {} format version {} (newest supported is {}). Game upgrade required.",
+                what,
+                version,
+                supported
+            );
+        }
+        Ok(())
+    }
+
+    /// Run the registered steps that upgrade `chunk` from `version` to [`Self::format_version`].
+    fn migrate_chunk(&self, chunk: &mut Chunk, version: u32) -> Result<()> {
+        for from in version..self.format_version() {
+            let step = self
+                .migrations
+                .get(&from)
+                .with_context(|| format!("No chunk migration from format version {}", from))?;
+            step.migrate(chunk)
+                .with_context(|| format!("Failed to migrate chunk from format version {}", from))?;
+        }
+        Ok(())
     }
 
     fn world_meta_path(&self) -> PathBuf {
@@ -339,10 +527,33 @@ impl RegionStore {
     }
 
     /// Load world meta.
+    ///
+    /// Fails if the world was saved in a chunk format newer than [`Self::format_version`].
     pub fn load_world_meta(&self) -> Result<WorldMeta> {
         let path = self.world_meta_path();
-        self.read_world_blob(&path, WORLD_META_MAGIC, WORLD_META_VERSION)
-            .with_context(|| format!("Failed to load world meta from {}", path.display()))
+        let (header, decoded) = self
+            .read_world_blob_payload(&path, WORLD_META_MAGIC)
+            .with_context(|| format!("Failed to load world meta from {}", path.display()))?;
+
+        let meta = match header.version {
+            1 => {
+                let v1: WorldMetaV1 =
+                    bincode::deserialize(&decoded).context("Failed to decode world meta v1")?;
+                WorldMeta::from(v1)
+            }
+            WORLD_META_VERSION => {
+                bincode::deserialize(&decoded).context("Failed to decode world meta")?
+            }
+            other => anyhow::bail!(
+                "Unsupported world meta version {} (expected {}). World upgrade required.",
+                other,
+                WORLD_META_VERSION
+            ),
+        };
+
+        self.ensure_supported_version(meta.format_version, "world")
+            .with_context(|| format!("Failed to load world meta from {}", path.display()))?;
+        Ok(meta)
     }
 
     /// Check if a world state blob exists on disk.
@@ -364,41 +575,56 @@ impl RegionStore {
             .read_world_blob_payload(&path, WORLD_STATE_MAGIC)
             .with_context(|| format!("Failed to load world state from {}", path.display()))?;
 
-        match header.version {
+        let mut state = match header.version {
             1 => {
                 let v1: WorldStateV1 =
                     bincode::deserialize(&decoded).context("Failed to decode world state v1")?;
-                Ok(WorldState::from_v1(v1))
+                WorldState::from_v1(v1)
+            }
+            2 => {
+                let v2: WorldStateV2 =
+                    bincode::deserialize(&decoded).context("Failed to decode world state v2")?;
+                WorldState::from_v2(v2)
             }
             WORLD_STATE_VERSION => {
-                let v2: WorldState =
-                    bincode::deserialize(&decoded).context("Failed to decode world state")?;
-                Ok(v2)
+                bincode::deserialize(&decoded).context("Failed to decode world state")?
             }
             other => anyhow::bail!(
                 "Unsupported world state version {} (expected {}). World upgrade required.",
                 other,
                 WORLD_STATE_VERSION
             ),
-        }
+        };
+
+        state.player = state
+            .player
+            .map(PlayerSave::migrate)
+            .transpose()
+            .with_context(|| format!("Failed to migrate player save in {}", path.display()))?;
+        Ok(state)
     }
 
     /// Save a chunk to its region file.
+    ///
+    /// Clears the chunk's save-dirty flag once the region file is written.
     #[instrument(skip(self, chunk), fields(chunk_pos = ?chunk.position()))]
-    pub fn save_chunk(&self, chunk: &Chunk) -> Result<()> {
+    pub fn save_chunk(&self, chunk: &mut Chunk) -> Result<()> {
         self.save_chunk_in_dimension(DimensionId::DEFAULT, chunk)
     }
 
     /// Save a chunk to its region file in the specified dimension.
+    ///
+    /// Clears the chunk's save-dirty flag once the region file is written.
     #[instrument(skip(self, chunk), fields(dimension = %dimension.as_str(), chunk_pos = ?chunk.position()))]
-    pub fn save_chunk_in_dimension(&self, dimension: DimensionId, chunk: &Chunk) -> Result<()> {
+    pub fn save_chunk_in_dimension(&self, dimension: DimensionId, chunk: &mut Chunk) -> Result<()> {
         let (region_x, region_z) = chunk_to_region(chunk.position());
         debug!(region_x, region_z, "Saving chunk to region");
 
-        // Load existing region or create new one.
-        let mut region_data = self
-            .load_region(dimension, region_x, region_z)
-            .unwrap_or_default();
+        // Load existing region (upgrading its chunks to the current format) or create new one.
+        let mut region_data = match self.load_region(dimension, region_x, region_z) {
+            Ok((version, data)) => self.upgrade_region(version, data)?,
+            Err(_) => HashMap::new(),
+        };
 
         // Serialize chunk data.
         let chunk_data = serialize_chunk(chunk)?;
@@ -409,6 +635,7 @@ impl RegionStore {
 
         // Write region file.
         self.write_region(dimension, region_x, region_z, &region_data)?;
+        chunk.mark_saved();
 
         debug!(chunk_count = region_data.len(), "Chunk saved successfully");
         Ok(())
@@ -426,7 +653,8 @@ impl RegionStore {
         let (region_x, region_z) = chunk_to_region(pos);
         debug!(region_x, region_z, "Loading chunk from region");
 
-        let region_data = self.load_region(dimension, region_x, region_z)?;
+        let (version, region_data) = self.load_region(dimension, region_x, region_z)?;
+        self.ensure_supported_version(version, "chunk")?;
 
         let chunk_data = region_data.get(&pos).context("Chunk not found in region")?;
         debug!(
@@ -434,19 +662,45 @@ impl RegionStore {
             "Found chunk data in region"
         );
 
-        let chunk = deserialize_chunk(pos, chunk_data)?;
+        let mut chunk = deserialize_chunk(pos, chunk_data)?;
+        if version < self.format_version() {
+            debug!(
+                from = version,
+                to = self.format_version(),
+                "Migrating chunk data"
+            );
+            self.migrate_chunk(&mut chunk, version)?;
+        }
         debug!("Chunk loaded successfully");
         Ok(chunk)
     }
 
-    /// Load an entire region file into memory.
+    /// Bring every chunk of a loaded region to the current format, so the region is
+    /// never written back with chunks in mixed formats.
+    fn upgrade_region(
+        &self,
+        version: u32,
+        mut region_data: HashMap<ChunkPos, Vec<u8>>,
+    ) -> Result<HashMap<ChunkPos, Vec<u8>>> {
+        self.ensure_supported_version(version, "chunk")?;
+        if version < self.format_version() {
+            for (pos, data) in region_data.iter_mut() {
+                let mut chunk = deserialize_chunk(*pos, data)?;
+                self.migrate_chunk(&mut chunk, version)?;
+                *data = serialize_chunk(&chunk)?;
+            }
+        }
+        Ok(region_data)
+    }
+
+    /// Load an entire region file into memory, along with its chunk format version.
     #[instrument(skip(self), fields(region_x, region_z))]
     fn load_region(
         &self,
         dimension: DimensionId,
         region_x: i32,
         region_z: i32,
-    ) -> Result<HashMap<ChunkPos, Vec<u8>>> {
+    ) -> Result<(u32, HashMap<ChunkPos, Vec<u8>>)> {
         let region_path = self.region_path(dimension, region_x, region_z);
         debug!(path = %region_path.display(), "Loading region file");
 
@@ -461,13 +715,6 @@ impl RegionStore {
         file.read_exact(&mut header_bytes)
             .context("Failed to read region header")?;
         let header = RegionHeader::from_bytes(&header_bytes)?;
-        if header.version != REGION_VERSION {
-            anyhow::bail!(
-                "Unsupported region version {} (expected {}). World upgrade required.",
-                header.version,
-                REGION_VERSION
-            );
-        }
         debug!(
             version = header.version,
             payload_len = header.payload_len,
@@ -517,7 +764,7 @@ impl RegionStore {
             chunk_count = region_data.len(),
             "Region file loaded successfully"
         );
-        Ok(region_data)
+        Ok((u32::from(header.version), region_data))
     }
 
     /// Write an entire region file to disk.
@@ -553,7 +800,9 @@ impl RegionStore {
         debug!(crc32 = format!("{:08X}", crc32), "CRC32 computed");
 
         // Create header.
-        let header = RegionHeader::new(crc32, compressed.len() as u32);
+        let version = u16::try_from(self.format_version())
+            .context("Chunk format version does not fit in region header")?;
+        let header = RegionHeader::new(version, crc32, compressed.len() as u32);
 
         // Write to file.
         if let Some(parent) = region_path.parent() {
@@ -590,7 +839,7 @@ impl RegionStore {
 
         // Try to load region map and verify the chunk key is present. Any parse error -> false.
         match self.load_region(dimension, region_x, region_z) {
-            Ok(map) => map.contains_key(&pos),
+            Ok((_, map)) => map.contains_key(&pos),
             Err(err) => {
                 tracing::warn!(
                     "Failed to inspect region {}: {}; returning chunk_exists=false",
@@ -632,57 +881,6 @@ impl RegionStore {
         Ok(())
     }
 
-    fn read_world_blob<T: for<'de> Deserialize<'de>>(
-        &self,
-        path: &Path,
-        expected_magic: u32,
-        expected_version: u16,
-    ) -> Result<T> {
-        let mut file = File::open(path).context("Failed to open world blob file")?;
-
-        let mut header_bytes = [0u8; 14];
-        file.read_exact(&mut header_bytes)
-            .context("Failed to read world blob header")?;
-        let header = WorldBlobHeader::from_bytes(&header_bytes)?;
-
-        if header.magic != expected_magic {
-            anyhow::bail!(
-                "Invalid world blob magic: expected 0x{:08X}, got 0x{:08X}",
-                expected_magic,
-                header.magic
-            );
-        }
-
-        if header.version != expected_version {
-            anyhow::bail!(
-                "Unsupported world blob version {} (expected {}). World upgrade required.",
-                header.version,
-                expected_version
-            );
-        }
-
-        let mut compressed = vec![0u8; header.payload_len as usize];
-        file.read_exact(&mut compressed)
-            .context("Failed to read world blob payload")?;
-
-        let mut hasher = Hasher::new();
-        hasher.update(&compressed);
-        let computed_crc = hasher.finalize();
-
-        if computed_crc != header.crc32 {
-            anyhow::bail!(
-                "World blob CRC32 mismatch: expected {:08X}, got {:08X}",
-                header.crc32,
-                computed_crc
-            );
-        }
-
-        let decompressed =
-            zstd::decode_all(&compressed[..]).context("Failed to decompress world blob")?;
-        let decoded = bincode::deserialize(&decompressed).context("Failed to decode world blob")?;
-        Ok(decoded)
-    }
-
     fn read_world_blob_payload(
         &self,
         path: &Path,
@@ -766,6 +964,7 @@ fn deserialize_chunk(pos: ChunkPos, data: &[u8]) -> Result<Chunk> {
         let y = idx / (CHUNK_SIZE_X * CHUNK_SIZE_Z);
         chunk.set_voxel(x, y, z, voxel);
     }
+    chunk.mark_saved();
 
     Ok(chunk)
 }
@@ -775,17 +974,16 @@ mod tests {
     use super::*;
     use crate::chunk::BLOCK_AIR;
     use mdminecraft_core::DimensionId;
-    use mdminecraft_core::ItemType as CoreItemType;
     use std::env;
 
     #[test]
     fn region_header_roundtrip() {
-        let header = RegionHeader::new(0xDEADBEEF, 1234);
+        let header = RegionHeader::new(CHUNK_FORMAT_VERSION as u16, 0xDEADBEEF, 1234);
         let bytes = header.to_bytes();
         let decoded = RegionHeader::from_bytes(&bytes).unwrap();
 
         assert_eq!(decoded.magic, REGION_MAGIC);
-        assert_eq!(decoded.version, REGION_VERSION);
+        assert_eq!(u32::from(decoded.version), CHUNK_FORMAT_VERSION);
         assert_eq!(decoded.crc32, 0xDEADBEEF);
         assert_eq!(decoded.payload_len, 1234);
     }
@@ -821,9 +1019,11 @@ mod tests {
             light_block: 0,
         };
         chunk.set_voxel(8, 64, 8, test_voxel);
+        assert!(chunk.needs_save());
 
         // Save chunk.
-        store.save_chunk(&chunk).expect("Failed to save chunk");
+        store.save_chunk(&mut chunk).expect("Failed to save chunk");
+        assert!(!chunk.needs_save());
 
         // Load chunk.
         let loaded = store.load_chunk(pos).expect("Failed to load chunk");
@@ -896,7 +1096,7 @@ mod tests {
             },
         );
         store
-            .save_chunk_in_dimension(DimensionId::Overworld, &overworld)
+            .save_chunk_in_dimension(DimensionId::Overworld, &mut overworld)
             .unwrap();
 
         let mut nether = Chunk::new(pos);
@@ -912,7 +1112,7 @@ mod tests {
             },
         );
         store
-            .save_chunk_in_dimension(DimensionId::Nether, &nether)
+            .save_chunk_in_dimension(DimensionId::Nether, &mut nether)
             .unwrap();
 
         assert!(store.chunk_exists_in_dimension(DimensionId::Overworld, pos));
@@ -970,8 +1170,8 @@ mod tests {
         chunk2.set_voxel(1, 1, 1, voxel2);
 
         // Save both chunks.
-        store.save_chunk(&chunk1).unwrap();
-        store.save_chunk(&chunk2).unwrap();
+        store.save_chunk(&mut chunk1).unwrap();
+        store.save_chunk(&mut chunk2).unwrap();
 
         // Load and verify both chunks.
         let loaded1 = store.load_chunk(pos1).unwrap();
@@ -996,8 +1196,8 @@ mod tests {
 
         // Save a single chunk in region (0,0)
         let pos_present = ChunkPos::new(0, 0);
-        let chunk = Chunk::new(pos_present);
-        store.save_chunk(&chunk).unwrap();
+        let mut chunk = Chunk::new(pos_present);
+        store.save_chunk(&mut chunk).unwrap();
 
         // Different chunk in same region (0,1) should report false without load error.
         let pos_absent = ChunkPos::new(0, 1);
@@ -1018,8 +1218,8 @@ mod tests {
 
         // Save one chunk
         let pos_present = ChunkPos::new(5, 5);
-        let chunk = Chunk::new(pos_present);
-        store.save_chunk(&chunk).unwrap();
+        let mut chunk = Chunk::new(pos_present);
+        store.save_chunk(&mut chunk).unwrap();
 
         // Try to load a different chunk in same region
         let pos_absent = ChunkPos::new(5, 6);
@@ -1131,9 +1331,9 @@ mod tests {
             },
         );
 
-        store.save_chunk(&chunk1).unwrap();
-        store.save_chunk(&chunk2).unwrap();
-        store.save_chunk(&chunk3).unwrap();
+        store.save_chunk(&mut chunk1).unwrap();
+        store.save_chunk(&mut chunk2).unwrap();
+        store.save_chunk(&mut chunk3).unwrap();
 
         // Load and verify
         let loaded1 = store.load_chunk(pos1).unwrap();
@@ -1196,6 +1396,7 @@ mod tests {
         let meta = WorldMeta {
             world_seed: 12345,
             end_boss_defeated: false,
+            format_version: CHUNK_FORMAT_VERSION,
         };
         store.save_world_meta(&meta).unwrap();
         assert!(store.world_meta_exists());
@@ -1296,6 +1497,261 @@ mod tests {
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    /// Test v1 -> v2 step: stone became cobblestone.
+    struct StoneToCobblestone;
+
+    impl ChunkMigration for StoneToCobblestone {
+        fn source_version(&self) -> u32 {
+            1
+        }
+
+        fn migrate(&self, chunk: &mut Chunk) -> Result<()> {
+            for y in 0..crate::chunk::CHUNK_SIZE_Y {
+                for z in 0..CHUNK_SIZE_Z {
+                    for x in 0..CHUNK_SIZE_X {
+                        let voxel = chunk.voxel(x, y, z);
+                        if voxel.id == crate::chunk::BLOCK_STONE {
+                            chunk.set_voxel(
+                                x,
+                                y,
+                                z,
+                                Voxel {
+                                    id: crate::chunk::BLOCK_COBBLESTONE,
+                                    ..voxel
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn stone_voxel() -> Voxel {
+        Voxel {
+            id: crate::chunk::BLOCK_STONE,
+            state: 3,
+            light_sky: 15,
+            light_block: 0,
+        }
+    }
+
+    #[test]
+    fn v1_chunk_is_upgraded_by_registered_migration() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let temp_dir = env::temp_dir().join(format!("mdminecraft_test_chunk_mig_{}", timestamp));
+
+        // Written by a build that only knows format 1.
+        let old_store = RegionStore::new(&temp_dir).unwrap();
+        assert_eq!(old_store.format_version(), CHUNK_FORMAT_VERSION);
+        let pos = ChunkPos::new(2, 3);
+        let mut chunk = Chunk::new(pos);
+        chunk.set_voxel(4, 10, 5, stone_voxel());
+        chunk.set_voxel(
+            4,
+            11,
+            5,
+            Voxel {
+                id: 42,
+                ..stone_voxel()
+            },
+        );
+        old_store.save_chunk(&mut chunk).unwrap();
+
+        let mut store = RegionStore::new(&temp_dir).unwrap();
+        store.register_migration(StoneToCobblestone);
+        assert_eq!(store.format_version(), 2);
+
+        let loaded = store.load_chunk(pos).unwrap();
+        let upgraded = loaded.voxel(4, 10, 5);
+        assert_eq!(upgraded.id, crate::chunk::BLOCK_COBBLESTONE);
+        assert_eq!(upgraded.state, 3);
+        assert_eq!(upgraded.light_sky, 15);
+        assert_eq!(loaded.voxel(4, 11, 5).id, 42);
+
+        // Saving a neighbor rewrites the region as v2 with the old chunk upgraded once.
+        store
+            .save_chunk(&mut Chunk::new(ChunkPos::new(3, 3)))
+            .unwrap();
+        let (version, _) = store.load_region(DimensionId::DEFAULT, 0, 0).unwrap();
+        assert_eq!(version, 2);
+        let reloaded = store.load_chunk(pos).unwrap();
+        assert_eq!(reloaded.voxel(4, 10, 5).id, crate::chunk::BLOCK_COBBLESTONE);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn newer_format_version_is_refused() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let temp_dir = env::temp_dir().join(format!("mdminecraft_test_newer_fmt_{}", timestamp));
+
+        let mut new_store = RegionStore::new(&temp_dir).unwrap();
+        new_store.register_migration(StoneToCobblestone);
+        let pos = ChunkPos::new(0, 0);
+        let mut chunk = Chunk::new(pos);
+        chunk.set_voxel(1, 1, 1, stone_voxel());
+        new_store.save_chunk(&mut chunk).unwrap();
+        new_store
+            .save_world_meta(&WorldMeta {
+                world_seed: 7,
+                end_boss_defeated: false,
+                format_version: new_store.format_version(),
+            })
+            .unwrap();
+
+        let old_store = RegionStore::new(&temp_dir).unwrap();
+        let Err(err) = old_store.load_chunk(pos) else {
+            panic!("old build loaded a newer chunk format");
+        };
+        assert!(format!("{err:#}").contains("Game upgrade required"));
+        let err = old_store.load_world_meta().unwrap_err();
+        assert!(format!("{err:#}").contains("newest supported is 1"));
+
+        // An old build must not clobber the newer region when saving into it.
+        assert!(old_store
+            .save_chunk(&mut Chunk::new(ChunkPos::new(1, 0)))
+            .is_err());
+        assert_eq!(
+            new_store.load_chunk(pos).unwrap().voxel(1, 1, 1).id,
+            crate::chunk::BLOCK_STONE
+        );
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn world_meta_v1_loads_as_format_version_1() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let temp_dir = env::temp_dir().join(format!("mdminecraft_test_meta_v1_{}", timestamp));
+        let store = RegionStore::new(&temp_dir).unwrap();
+
+        let v1 = WorldMetaV1 {
+            world_seed: 99,
+            end_boss_defeated: true,
+        };
+        store
+            .write_world_blob(&store.world_meta_path(), WORLD_META_MAGIC, 1, &v1)
+            .unwrap();
+
+        let meta = store.load_world_meta().unwrap();
+        assert_eq!(meta.world_seed, 99);
+        assert!(meta.end_boss_defeated);
+        assert_eq!(meta.format_version, 1);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    fn test_player_v0(hotbar: [Option<CoreItemStack>; 9]) -> PlayerSaveV0 {
+        PlayerSaveV0 {
+            transform: PlayerTransform {
+                dimension: DimensionId::Overworld,
+                x: 1.5,
+                y: 70.0,
+                z: -2.5,
+                yaw: 0.25,
+                pitch: 0.0,
+            },
+            spawn_point: WorldPoint {
+                dimension: DimensionId::Overworld,
+                x: 0.0,
+                y: 64.0,
+                z: 0.0,
+            },
+            hotbar,
+            hotbar_selected: 2,
+            inventory: Inventory::new(),
+            health: 20.0,
+            hunger: 18.0,
+            xp_level: 0,
+            xp_current: 0,
+            xp_next_level_xp: 7,
+            armor: PlayerArmor::new(),
+            status_effects: StatusEffects::new(),
+        }
+    }
+
+    #[test]
+    fn player_save_v0_migrates_legacy_item_ids() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let temp_dir = env::temp_dir().join(format!("mdminecraft_test_player_mig_{}", timestamp));
+        let store = RegionStore::new(&temp_dir).unwrap();
+
+        let mut hotbar: [Option<CoreItemStack>; 9] = Default::default();
+        hotbar[0] = Some(CoreItemStack::new(CoreItemType::Item(100), 5));
+        hotbar[1] = Some(CoreItemStack::new(CoreItemType::Item(101), 3));
+        hotbar[2] = Some(CoreItemStack::new(CoreItemType::Item(7), 12));
+
+        let v2 = WorldStateV2 {
+            tick: SimTick(5),
+            sim_time: SimTime::new(24000),
+            weather: WeatherToggle::new(),
+            weather_next_change_tick: SimTick(600),
+            player: Some(test_player_v0(hotbar)),
+            entities: WorldEntitiesState::default(),
+            block_entities: BlockEntitiesState::default(),
+        };
+        store
+            .write_world_blob(&store.world_state_path(), WORLD_STATE_MAGIC, 2, &v2)
+            .unwrap();
+
+        let loaded = store.load_world_state().unwrap();
+        let player = loaded.player.expect("player migrated");
+        assert_eq!(player.version, PLAYER_SAVE_VERSION);
+        let ids: Vec<_> = player
+            .hotbar
+            .iter()
+            .take(3)
+            .map(|slot| slot.as_ref().map(|stack| (stack.item_type, stack.count)))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                Some((CoreItemType::Item(3), 5)),
+                Some((CoreItemType::Item(6), 3)),
+                Some((CoreItemType::Item(7), 12)),
+            ]
+        );
+        assert_eq!(player.hotbar_selected, 2);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn current_player_save_passes_through_unchanged() {
+        let mut hotbar: [Option<CoreItemStack>; 9] = Default::default();
+        // Id 100 is a valid current item and must not be remapped again.
+        hotbar[0] = Some(CoreItemStack::new(CoreItemType::Item(100), 2));
+
+        let mut save = PlayerSave::from(test_player_v0(hotbar));
+        save.version = PLAYER_SAVE_VERSION;
+        let before = bincode::serialize(&save).unwrap();
+
+        let migrated = save.migrate().unwrap();
+        assert_eq!(bincode::serialize(&migrated).unwrap(), before);
+
+        let mut future = migrated;
+        future.version = PLAYER_SAVE_VERSION + 1;
+        assert!(future.migrate().is_err());
+    }
+
     #[test]
     fn world_state_roundtrip_with_content_is_stable_over_cycles() {
         use mdminecraft_core::Enchantment;
@@ -1342,6 +1798,7 @@ mod tests {
         ));
 
         let player = PlayerSave {
+            version: PLAYER_SAVE_VERSION,
             transform: PlayerTransform {
                 dimension: DimensionId::Overworld,
                 x: 10.5,
@@ -0,0 +1,110 @@
+//! Placement orientation for directional blocks.
+//!
+//! Derives the initial [`BlockState`] of a block from the placing player's yaw
+//! and the face that was clicked. Facing follows the same convention as
+//! stairs and beds: the state stores the direction the player was looking.
+
+use crate::chunk::{BlockId, BlockState, BLOCK_FURNACE, BLOCK_FURNACE_LIT};
+use crate::interaction::Facing;
+use crate::trees::tree_blocks;
+use glam::IVec3;
+
+const AXIS_MASK: BlockState = 0x03;
+
+/// Axis a pillar-like block (logs) runs along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    /// Axis perpendicular to the clicked face.
+    pub fn from_normal(normal: IVec3) -> Self {
+        if normal.x != 0 {
+            Axis::X
+        } else if normal.z != 0 {
+            Axis::Z
+        } else {
+            Axis::Y
+        }
+    }
+
+    /// Decode from state bits (vertical is 0 so generated logs stay upright).
+    pub fn from_state(state: BlockState) -> Self {
+        match state & AXIS_MASK {
+            1 => Axis::X,
+            2 => Axis::Z,
+            _ => Axis::Y,
+        }
+    }
+
+    /// Encode into state bits.
+    pub fn to_state(self) -> BlockState {
+        match self {
+            Axis::Y => 0,
+            Axis::X => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+
+/// Check if a block is a log (pillar with an axis).
+pub fn is_log(block_id: BlockId) -> bool {
+    matches!(
+        block_id,
+        tree_blocks::LOG | tree_blocks::BIRCH_LOG | tree_blocks::PINE_LOG
+    )
+}
+
+/// Initial state for a block placed by a player looking along `player_yaw`
+/// who clicked the face with normal `hit_normal`.
+///
+/// Blocks without an orientation get state `0`.
+pub fn placement_state(block_id: BlockId, player_yaw: f32, hit_normal: IVec3) -> BlockState {
+    if matches!(block_id, BLOCK_FURNACE | BLOCK_FURNACE_LIT) {
+        return Facing::from_yaw(player_yaw).to_state();
+    }
+
+    if is_log(block_id) {
+        return Axis::from_normal(hit_normal).to_state();
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk::BLOCK_STONE;
+
+    #[test]
+    fn furnace_faces_player_look_direction() {
+        // Yaw of pi looks toward -Z (north).
+        let state = placement_state(BLOCK_FURNACE, std::f32::consts::PI, IVec3::Y);
+        assert_eq!(Facing::from_state(state), Facing::North);
+        assert_eq!(state, Facing::North.to_state());
+
+        let state = placement_state(BLOCK_FURNACE, 0.0, IVec3::Y);
+        assert_eq!(Facing::from_state(state), Facing::South);
+    }
+
+    #[test]
+    fn log_axis_follows_clicked_face() {
+        let side_x = placement_state(tree_blocks::LOG, 0.0, IVec3::new(1, 0, 0));
+        assert_eq!(Axis::from_state(side_x), Axis::X);
+
+        let side_z = placement_state(tree_blocks::BIRCH_LOG, 0.0, IVec3::new(0, 0, -1));
+        assert_eq!(Axis::from_state(side_z), Axis::Z);
+
+        let top = placement_state(tree_blocks::PINE_LOG, 0.0, IVec3::Y);
+        assert_eq!(Axis::from_state(top), Axis::Y);
+        assert_eq!(top, 0);
+    }
+
+    #[test]
+    fn plain_blocks_have_default_state() {
+        assert_eq!(placement_state(BLOCK_STONE, 1.0, IVec3::X), 0);
+    }
+}
@@ -0,0 +1,995 @@
+//! Player movement against block collision: gravity, jumping, ladders,
+//! swimming, wall sliding and step-up.
+//!
+//! Everything here works on a [`WorldView`], so movement can be simulated
+//! headlessly over a plain chunk map.
+
+use glam::{Vec2, Vec3};
+
+use crate::{
+    door_collision_box, fence_connects_to, interactive_blocks, is_fence_gate, is_fence_gate_open,
+    is_full_cube_block, is_stairs, is_trapdoor, is_trapdoor_open, stairs_collision_boxes,
+    stairs_shape_at, BlockPropertiesRegistry, CollisionType, Facing, FluidType, Voxel, WorldView,
+    BLOCK_BREWING_STAND, WORLD_MIN_Y,
+};
+
+/// Axis-aligned box in world coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CollisionBox {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl CollisionBox {
+    /// Box of `size` centred on `center`.
+    pub fn from_center_size(center: Vec3, size: Vec3) -> Self {
+        let half_size = size * 0.5;
+        Self {
+            min: center - half_size,
+            max: center + half_size,
+        }
+    }
+
+    /// Whether the boxes overlap; touching faces don't count.
+    pub fn intersects(&self, other: &CollisionBox) -> bool {
+        self.min.x < other.max.x
+            && self.max.x > other.min.x
+            && self.min.y < other.max.y
+            && self.max.y > other.min.y
+            && self.min.z < other.max.z
+            && self.max.z > other.min.z
+    }
+
+    /// The box moved by `offset`.
+    pub fn offset(&self, offset: Vec3) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+}
+
+/// Collision boxes of a single block (at most 8).
+#[derive(Debug, Clone, Copy)]
+pub struct BlockCollision {
+    boxes: [CollisionBox; 8],
+    len: usize,
+}
+
+impl BlockCollision {
+    fn empty() -> Self {
+        Self {
+            boxes: [CollisionBox {
+                min: Vec3::ZERO,
+                max: Vec3::ZERO,
+            }; 8],
+            len: 0,
+        }
+    }
+
+    fn single(collision_box: CollisionBox) -> Self {
+        let mut collision = Self::empty();
+        collision.push(collision_box);
+        collision
+    }
+
+    fn push(&mut self, collision_box: CollisionBox) {
+        debug_assert!(self.len < self.boxes.len());
+        if self.len >= self.boxes.len() {
+            return;
+        }
+        self.boxes[self.len] = collision_box;
+        self.len += 1;
+    }
+
+    /// The block's boxes in world coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = &CollisionBox> {
+        self.boxes[..self.len].iter()
+    }
+
+    /// Returns true when the block has no collision.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Collision boxes of the block at a world position.
+///
+/// Everything below the world collides as a full cube so nothing falls out;
+/// unloaded chunks and the space above the world are empty.
+pub fn block_collision_boxes(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    x: i32,
+    y: i32,
+    z: i32,
+) -> BlockCollision {
+    if y < WORLD_MIN_Y {
+        let min = Vec3::new(x as f32, y as f32, z as f32);
+        return BlockCollision::single(CollisionBox {
+            min,
+            max: min + Vec3::ONE,
+        });
+    }
+    match world.voxel_at(x, y, z) {
+        Some(voxel) => voxel_collision_boxes(world, blocks, x, y, z, voxel),
+        None => BlockCollision::empty(),
+    }
+}
+
+/// Collision boxes of `voxel` placed at a world position.
+///
+/// Neighbors are read from `world` for stair corners and fence, wall and pane
+/// connections.
+pub fn voxel_collision_boxes(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    x: i32,
+    y: i32,
+    z: i32,
+    voxel: Voxel,
+) -> BlockCollision {
+    if !blocks.get(voxel.id).is_solid {
+        return BlockCollision::empty();
+    }
+
+    let origin = Vec3::new(x as f32, y as f32, z as f32);
+    let local = |min: [f32; 3], max: [f32; 3]| CollisionBox {
+        min: origin + Vec3::from(min),
+        max: origin + Vec3::from(max),
+    };
+    let connected = |connects_to: &dyn Fn(Voxel) -> bool| {
+        let at = |dx: i32, dz: i32| world.voxel_at(x + dx, y, z + dz).is_some_and(connects_to);
+        (at(-1, 0), at(1, 0), at(0, -1), at(0, 1))
+    };
+    let facing = Facing::from_state(voxel.state);
+
+    match blocks
+        .collision_shapes()
+        .collision_type(voxel.id, voxel.state)
+    {
+        CollisionType::None | CollisionType::Ladder => {
+            if is_fence_gate(voxel.id) && is_fence_gate_open(voxel.state) {
+                // Open gates swing like doors but keep their fence height.
+                let gate = door_collision_box(facing, true);
+                let max = [gate.max.x, 1.5, gate.max.z];
+                return BlockCollision::single(local(gate.min.to_array(), max));
+            }
+            if is_trapdoor(voxel.id) && is_trapdoor_open(voxel.state) {
+                // Open trapdoors stand against the facing side like a closed door.
+                let hatch = door_collision_box(facing, false);
+                return BlockCollision::single(local(hatch.min.to_array(), hatch.max.to_array()));
+            }
+            BlockCollision::empty()
+        }
+        CollisionType::Door { open } => {
+            let door = door_collision_box(facing, open);
+            BlockCollision::single(local(door.min.to_array(), door.max.to_array()))
+        }
+        CollisionType::Full => {
+            if !matches!(
+                voxel.id,
+                interactive_blocks::GLASS_PANE | interactive_blocks::IRON_BARS
+            ) {
+                return BlockCollision::single(local([0.0; 3], [1.0; 3]));
+            }
+
+            // Panes: a thin post, optionally with connecting arms.
+            let half = 1.0 / 16.0;
+            let (post_min, post_max) = (0.5 - half, 0.5 + half);
+            let (west, east, north, south) = connected(&|neighbor: Voxel| {
+                matches!(
+                    neighbor.id,
+                    interactive_blocks::GLASS_PANE | interactive_blocks::IRON_BARS
+                ) || is_full_cube_block(neighbor.id)
+            });
+
+            let any_x = west || east;
+            let any_z = north || south;
+            if !any_x && !any_z {
+                return BlockCollision::single(local(
+                    [post_min, 0.0, post_min],
+                    [post_max, 1.0, post_max],
+                ));
+            }
+
+            let mut collision = BlockCollision::empty();
+            if any_x {
+                let min_x = if west { 0.0 } else { post_min };
+                let max_x = if east { 1.0 } else { post_max };
+                collision.push(local([min_x, 0.0, post_min], [max_x, 1.0, post_max]));
+            }
+            if any_z {
+                let min_z = if north { 0.0 } else { post_min };
+                let max_z = if south { 1.0 } else { post_max };
+                collision.push(local([post_min, 0.0, min_z], [post_max, 1.0, max_z]));
+            }
+            collision
+        }
+        CollisionType::Partial { min_y, max_y } => {
+            if voxel.id == BLOCK_BREWING_STAND {
+                let pad = 4.0 / 16.0;
+                return BlockCollision::single(local(
+                    [pad, min_y, pad],
+                    [1.0 - pad, max_y, 1.0 - pad],
+                ));
+            }
+
+            if is_stairs(voxel.id) {
+                let top = (voxel.state & 0x04) != 0;
+                let voxel_at = |x: i32, y: i32, z: i32| world.voxel_at(x, y, z);
+                let shape = stairs_shape_at(x, y, z, voxel, &voxel_at);
+                let (boxes, count) = stairs_collision_boxes(facing, shape, top);
+
+                let mut collision = BlockCollision::empty();
+                for stair_box in boxes.into_iter().take(count) {
+                    collision.push(local(stair_box.min.to_array(), stair_box.max.to_array()));
+                }
+                return collision;
+            }
+
+            BlockCollision::single(local([0.0, min_y, 0.0], [1.0, max_y, 1.0]))
+        }
+        CollisionType::Fence => {
+            if is_fence_gate(voxel.id) {
+                let half = 1.5 / 16.0;
+                let (min, max) = match facing {
+                    Facing::North | Facing::South => {
+                        ([0.0, 0.0, 0.5 - half], [1.0, 1.5, 0.5 + half])
+                    }
+                    Facing::East | Facing::West => ([0.5 - half, 0.0, 0.0], [0.5 + half, 1.5, 1.0]),
+                };
+                return BlockCollision::single(local(min, max));
+            }
+
+            let (west, east, north, south) =
+                connected(&|neighbor: Voxel| fence_connects_to(voxel.id, neighbor.id));
+
+            // Center post plus one arm per connection, so corners don't over-collide.
+            let is_wall = matches!(
+                voxel.id,
+                interactive_blocks::COBBLESTONE_WALL | interactive_blocks::STONE_BRICK_WALL
+            );
+            let (post_half, arm_half) = if is_wall {
+                (3.0 / 16.0, 3.0 / 16.0)
+            } else {
+                (0.125, 1.0 / 16.0)
+            };
+            let (post_min, post_max) = (0.5 - post_half, 0.5 + post_half);
+            let (arm_min, arm_max) = (0.5 - arm_half, 0.5 + arm_half);
+
+            let mut collision = BlockCollision::empty();
+            collision.push(local([post_min, 0.0, post_min], [post_max, 1.5, post_max]));
+            if west {
+                collision.push(local([0.0, 0.0, arm_min], [0.5, 1.5, arm_max]));
+            }
+            if east {
+                collision.push(local([0.5, 0.0, arm_min], [1.0, 1.5, arm_max]));
+            }
+            if north {
+                collision.push(local([arm_min, 0.0, 0.0], [arm_max, 1.5, 0.5]));
+            }
+            if south {
+                collision.push(local([arm_min, 0.0, 0.5], [arm_max, 1.5, 1.0]));
+            }
+            collision
+        }
+    }
+}
+
+/// Integer block range `[min, max)` overlapped by `aabb` on each axis.
+fn block_range(aabb: &CollisionBox) -> ([i32; 3], [i32; 3]) {
+    (
+        [
+            aabb.min.x.floor() as i32,
+            aabb.min.y.floor() as i32,
+            aabb.min.z.floor() as i32,
+        ],
+        [
+            aabb.max.x.ceil() as i32,
+            aabb.max.y.ceil() as i32,
+            aabb.max.z.ceil() as i32,
+        ],
+    )
+}
+
+/// Whether `aabb` overlaps the collision of any block.
+pub fn collides_with_world(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    aabb: &CollisionBox,
+) -> bool {
+    let (min, max) = block_range(aabb);
+    for bx in min[0]..max[0] {
+        for by in min[1]..max[1] {
+            for bz in min[2]..max[2] {
+                let collision = block_collision_boxes(world, blocks, bx, by, bz);
+                if collision.iter().any(|block_box| aabb.intersects(block_box)) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Whether `aabb` overlaps any climbable block.
+pub fn touches_ladder(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    aabb: &CollisionBox,
+) -> bool {
+    let (min, max) = block_range(aabb);
+    for bx in min[0]..max[0] {
+        for by in min[1]..max[1] {
+            for bz in min[2]..max[2] {
+                let Some(voxel) = world.voxel_at(bx, by, bz) else {
+                    continue;
+                };
+                if blocks
+                    .collision_shapes()
+                    .collision_type(voxel.id, voxel.state)
+                    == CollisionType::Ladder
+                {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Move along X, then Y, then Z, zeroing the velocity on any axis that collides.
+/// Returns the applied offset and the resulting velocity.
+fn move_axis_separated(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    aabb: &CollisionBox,
+    velocity: Vec3,
+) -> (Vec3, Vec3) {
+    let mut offset = Vec3::ZERO;
+    let mut result_velocity = velocity;
+
+    for axis in 0..3 {
+        if velocity[axis] == 0.0 {
+            continue;
+        }
+        let mut delta = Vec3::ZERO;
+        delta[axis] = velocity[axis];
+        if collides_with_world(world, blocks, &aabb.offset(offset + delta)) {
+            result_velocity[axis] = 0.0;
+        } else {
+            offset[axis] += velocity[axis];
+        }
+    }
+
+    (offset, result_velocity)
+}
+
+/// How far `aabb` can drop, up to `max_down`, before landing on something.
+///
+/// Probes in 1/64-block increments and returns the (non-positive) Y offset.
+pub fn step_down_offset(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    aabb: &CollisionBox,
+    max_down: f32,
+) -> f32 {
+    const STEP: f32 = 1.0 / 64.0;
+    if max_down <= 0.0 {
+        return 0.0;
+    }
+
+    let mut down = 0.0;
+    while down < max_down {
+        let next = (down + STEP).min(max_down);
+        if collides_with_world(world, blocks, &aabb.offset(Vec3::new(0.0, -next, 0.0))) {
+            break;
+        }
+        down = next;
+    }
+    -down
+}
+
+/// Move `aabb` by `velocity` with wall sliding, stepping up ledges no taller
+/// than `step_height` when walking along the ground.
+///
+/// Returns the applied offset and the velocity left after collisions.
+pub fn move_with_collision(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    aabb: &CollisionBox,
+    velocity: Vec3,
+    step_height: f32,
+) -> (Vec3, Vec3) {
+    let (base_offset, base_velocity) = move_axis_separated(world, blocks, aabb, velocity);
+
+    if step_height <= 0.0 || velocity.y > 0.0 {
+        return (base_offset, base_velocity);
+    }
+
+    let blocked_x = velocity.x != 0.0 && base_velocity.x == 0.0;
+    let blocked_z = velocity.z != 0.0 && base_velocity.z == 0.0;
+    if !(blocked_x || blocked_z) {
+        return (base_offset, base_velocity);
+    }
+
+    // Only step when we're on (or very near) the ground.
+    let ground_probe = aabb.offset(Vec3::new(0.0, -0.1, 0.0));
+    if !collides_with_world(world, blocks, &ground_probe) {
+        return (base_offset, base_velocity);
+    }
+
+    let step_up = Vec3::new(0.0, step_height, 0.0);
+    let stepped = aabb.offset(step_up);
+    if collides_with_world(world, blocks, &stepped) {
+        return (base_offset, base_velocity);
+    }
+
+    // Try the horizontal move from the stepped position, then drop back down onto the surface.
+    let horizontal = Vec3::new(velocity.x, 0.0, velocity.z);
+    let (step_horizontal_offset, step_horizontal_velocity) =
+        move_axis_separated(world, blocks, &stepped, horizontal);
+    let step_down = step_down_offset(
+        world,
+        blocks,
+        &stepped.offset(step_horizontal_offset),
+        step_height,
+    );
+    let step_offset = step_up + step_horizontal_offset + Vec3::new(0.0, step_down, 0.0);
+
+    let base_h = Vec2::new(base_offset.x, base_offset.z).length_squared();
+    let step_h = Vec2::new(step_offset.x, step_offset.z).length_squared();
+    if step_h <= base_h {
+        return (base_offset, base_velocity);
+    }
+
+    let mut result_velocity = base_velocity;
+    result_velocity.x = step_horizontal_velocity.x;
+    result_velocity.z = step_horizontal_velocity.z;
+    (step_offset, result_velocity)
+}
+
+/// Fall damage, in health points, for a fall of `fall_distance` blocks.
+///
+/// Vanilla curve: the first 3 blocks are free, then 1 point per block.
+pub fn fall_damage(fall_distance: f32) -> f32 {
+    (fall_distance - 3.0).max(0.0)
+}
+
+/// What broke a fall, as seen by [`FallTracker::update`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Footing {
+    /// In the air; the fall continues.
+    Air,
+    /// Standing on a block; a fall in progress lands.
+    Ground,
+    /// Holding a ladder; cancels the fall.
+    Ladder,
+    /// In water; cancels the fall.
+    Water,
+}
+
+/// A fall that ended on the ground with damage to apply.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FallLanding {
+    /// Blocks between the peak of the fall and the landing height.
+    pub distance: f32,
+    /// Damage from [`fall_damage`].
+    pub damage: f32,
+}
+
+/// Tracks the highest point since the player last stood on something.
+///
+/// The peak starts at the last ground height and rises with jumps; water and
+/// ladders clear it, so a fall only counts from where the player left them.
+#[derive(Debug, Clone, Default)]
+pub struct FallTracker {
+    peak_y: Option<f32>,
+}
+
+impl FallTracker {
+    /// Tracker with no fall in progress.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget any fall in progress (teleports, respawns).
+    pub fn reset(&mut self) {
+        self.peak_y = None;
+    }
+
+    /// Highest feet Y of the fall in progress.
+    pub fn peak_y(&self) -> Option<f32> {
+        self.peak_y
+    }
+
+    /// Record this tick's feet height and footing.
+    ///
+    /// Returns a landing when the player reaches the ground after a fall that
+    /// deals damage. Ladders and water end the fall without damage.
+    pub fn update(&mut self, feet_y: f32, footing: Footing) -> Option<FallLanding> {
+        match footing {
+            Footing::Air => {
+                self.peak_y = Some(self.peak_y.map_or(feet_y, |peak| peak.max(feet_y)));
+                None
+            }
+            Footing::Ladder | Footing::Water => {
+                self.peak_y = None;
+                None
+            }
+            Footing::Ground => {
+                // Standing height is where the next fall starts from.
+                let distance = self.peak_y.replace(feet_y)? - feet_y;
+                let damage = fall_damage(distance);
+                (damage > 0.0).then_some(FallLanding { distance, damage })
+            }
+        }
+    }
+}
+
+/// What the player is trying to do this step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveIntent {
+    /// Desired horizontal velocity in blocks per second (`y` is ignored).
+    pub horizontal: Vec3,
+    /// Jump, climb a ladder or swim up.
+    pub jump: bool,
+    /// Climb down a ladder or swim down.
+    pub descend: bool,
+    /// Fluid the player is in, if any.
+    pub fluid: Option<FluidType>,
+    /// Slow Falling: weaker gravity and a low terminal velocity while falling.
+    pub slow_falling: bool,
+    /// Scale applied to jump strength (Jump Boost).
+    pub jump_multiplier: f32,
+}
+
+impl Default for MoveIntent {
+    fn default() -> Self {
+        Self {
+            horizontal: Vec3::ZERO,
+            jump: false,
+            descend: false,
+            fluid: None,
+            slow_falling: false,
+            jump_multiplier: 1.0,
+        }
+    }
+}
+
+/// Outcome of a [`PlayerController::step`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveResult {
+    /// Eye position after the move.
+    pub position: Vec3,
+    /// Set on the step the player lands from a damaging fall.
+    pub landing: Option<FallLanding>,
+    /// Whether the player was touching a ladder at the start of the step.
+    pub on_ladder: bool,
+}
+
+/// Player movement state and physics constants.
+///
+/// Positions passed in and out are the player's eye; the collision box hangs
+/// `eye_height` below it.
+#[derive(Debug, Clone)]
+pub struct PlayerController {
+    /// Current velocity in blocks per second.
+    pub velocity: Vec3,
+    /// Whether the player is standing on something.
+    pub on_ground: bool,
+    /// Downward acceleration in blocks per second squared (negative).
+    pub gravity: f32,
+    /// Initial upward velocity of a jump.
+    pub jump_strength: f32,
+    /// Fastest downward velocity (negative).
+    pub terminal_velocity: f32,
+    /// Tallest ledge the player walks up without jumping.
+    pub step_height: f32,
+    /// Collision box height.
+    pub height: f32,
+    /// Collision box width (and depth).
+    pub width: f32,
+    /// Eye height above the feet.
+    pub eye_height: f32,
+    /// Fall in progress, for fall damage.
+    pub fall: FallTracker,
+}
+
+impl Default for PlayerController {
+    fn default() -> Self {
+        Self {
+            velocity: Vec3::ZERO,
+            on_ground: false,
+            gravity: -20.0,
+            jump_strength: 8.0,
+            terminal_velocity: -50.0,
+            step_height: Self::STEP_HEIGHT,
+            height: 1.8,
+            width: 0.6,
+            eye_height: 1.62,
+            fall: FallTracker::new(),
+        }
+    }
+}
+
+impl PlayerController {
+    /// Tolerance used when snapping feet onto a surface.
+    pub const GROUND_EPS: f32 = 0.001;
+    /// Vanilla-ish step height. Uses a power-of-two fraction for determinism.
+    pub const STEP_HEIGHT: f32 = 19.0 / 32.0;
+    /// Vertical speed while climbing a ladder.
+    const CLIMB_SPEED: f32 = 3.0;
+
+    /// Controller with the default player constants.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collision box for a player whose eye is at `eye`.
+    pub fn aabb(&self, eye: Vec3) -> CollisionBox {
+        let feet = eye - Vec3::new(0.0, self.eye_height, 0.0);
+        let size = Vec3::new(self.width, self.height, self.width);
+        CollisionBox::from_center_size(feet + Vec3::new(0.0, self.height * 0.5, 0.0), size)
+    }
+
+    /// Advance the player by `dt` seconds from eye position `eye`.
+    pub fn step(
+        &mut self,
+        eye: Vec3,
+        input: MoveIntent,
+        world: &impl WorldView,
+        blocks: &BlockPropertiesRegistry,
+        dt: f32,
+    ) -> MoveResult {
+        let on_ladder = touches_ladder(world, blocks, &self.aabb(eye));
+        if on_ladder {
+            // Vanilla-ish: ladders cancel gravity and clamp vertical speed.
+            self.velocity.y = if input.jump {
+                Self::CLIMB_SPEED
+            } else if input.descend {
+                -Self::CLIMB_SPEED
+            } else {
+                self.velocity.y.clamp(-1.0, 0.0)
+            };
+        } else if let Some(fluid) = input.fluid {
+            let (swim_speed, gravity_multiplier, terminal_velocity, drag) = match fluid {
+                FluidType::Lava => (2.0, 0.1, -2.0, 0.85),
+                FluidType::Water => (4.0, 0.2, -4.0, 0.9),
+            };
+
+            // Vanilla-ish: fluids allow swimming up/down (simplified).
+            if input.jump {
+                self.velocity.y = swim_speed;
+            } else if input.descend {
+                self.velocity.y = -swim_speed;
+            } else {
+                self.velocity.y += self.gravity * gravity_multiplier * dt;
+                self.velocity.y = self.velocity.y.max(terminal_velocity);
+            }
+            self.velocity.y *= drag;
+        } else {
+            let (gravity, terminal_velocity) = if input.slow_falling && self.velocity.y < 0.0 {
+                (self.gravity * 0.2, -3.0)
+            } else {
+                (self.gravity, self.terminal_velocity)
+            };
+            self.velocity.y = (self.velocity.y + gravity * dt).max(terminal_velocity);
+        }
+
+        let movement = Vec3::new(
+            input.horizontal.x * dt,
+            self.velocity.y * dt,
+            input.horizontal.z * dt,
+        );
+        let (offset, new_velocity) =
+            move_with_collision(world, blocks, &self.aabb(eye), movement, self.step_height);
+        let position = eye + offset;
+
+        if new_velocity.y == 0.0 && self.velocity.y != 0.0 {
+            // Hit something vertically; landing only counts when falling.
+            if self.velocity.y < 0.0 {
+                self.on_ground = true;
+            }
+            self.velocity.y = 0.0;
+        } else {
+            self.on_ground = false;
+        }
+
+        // Standing on ground (for jump detection)?
+        let feet_probe = self.aabb(position).offset(Vec3::new(0.0, -0.1, 0.0));
+        if collides_with_world(world, blocks, &feet_probe) {
+            self.on_ground = true;
+        }
+
+        let footing = if on_ladder {
+            Footing::Ladder
+        } else if input.fluid == Some(FluidType::Water) {
+            Footing::Water
+        } else if self.on_ground {
+            Footing::Ground
+        } else {
+            Footing::Air
+        };
+        let landing = self.fall.update(self.aabb(position).min.y, footing);
+
+        if input.jump && self.on_ground && input.fluid.is_none() {
+            self.velocity.y = self.jump_strength * input.jump_multiplier;
+            self.on_ground = false;
+        }
+
+        MoveResult {
+            position,
+            landing,
+            on_ladder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chunk, ChunkPos, BLOCK_STONE};
+    use std::collections::HashMap;
+
+    const DT: f32 = 1.0 / 20.0;
+
+    fn block(id: u16) -> Voxel {
+        Voxel {
+            id,
+            ..Default::default()
+        }
+    }
+
+    /// A loaded chunk with a stone floor whose top is at y=64.
+    fn floor_world() -> HashMap<ChunkPos, Chunk> {
+        let mut world = HashMap::new();
+        world.insert(ChunkPos::new(0, 0), Chunk::new(ChunkPos::new(0, 0)));
+        for x in 0..16 {
+            for z in 0..16 {
+                world.set_voxel_at(x, 63, z, block(BLOCK_STONE));
+            }
+        }
+        world
+    }
+
+    /// Eye position for a player standing with feet at `(x, feet_y, z)`.
+    fn eye(controller: &PlayerController, x: f32, feet_y: f32, z: f32) -> Vec3 {
+        Vec3::new(x, feet_y + controller.eye_height, z)
+    }
+
+    fn walk(
+        controller: &mut PlayerController,
+        mut position: Vec3,
+        input: MoveIntent,
+        world: &HashMap<ChunkPos, Chunk>,
+        steps: usize,
+    ) -> Vec3 {
+        let blocks = BlockPropertiesRegistry::new();
+        for _ in 0..steps {
+            position = controller
+                .step(position, input, world, &blocks, DT)
+                .position;
+        }
+        position
+    }
+
+    #[test]
+    fn walking_steps_up_a_slab_but_not_a_full_block() {
+        let mut world = floor_world();
+        world.set_voxel_at(6, 64, 5, block(interactive_blocks::STONE_SLAB));
+        world.set_voxel_at(6, 64, 9, block(BLOCK_STONE));
+        let east = MoveIntent {
+            horizontal: Vec3::new(4.0, 0.0, 0.0),
+            ..Default::default()
+        };
+
+        let mut controller = PlayerController::new();
+        let start = eye(&controller, 5.5, 64.0, 5.5);
+        let end = walk(&mut controller, start, east, &world, 6);
+        assert!(end.x > 6.5, "walked onto the slab (x = {})", end.x);
+        let feet_y = end.y - controller.eye_height;
+        assert!(
+            (feet_y - 64.5).abs() < 1e-4,
+            "feet on the slab top, got {feet_y}"
+        );
+
+        let mut controller = PlayerController::new();
+        let start = eye(&controller, 5.5, 64.0, 9.5);
+        let end = walk(&mut controller, start, east, &world, 10);
+        assert!(
+            end.x < 6.0 - controller.width * 0.5 + 1e-4,
+            "a full block is too tall to step up (x = {})",
+            end.x
+        );
+        assert!((end.y - start.y).abs() < 1e-4);
+    }
+
+    #[test]
+    fn walking_diagonally_into_a_wall_slides_along_it() {
+        let mut world = floor_world();
+        for z in 0..16 {
+            for y in 64..67 {
+                world.set_voxel_at(8, y, z, block(BLOCK_STONE));
+            }
+        }
+
+        let mut controller = PlayerController::new();
+        let start = eye(&controller, 7.5, 64.0, 2.5);
+        let input = MoveIntent {
+            horizontal: Vec3::new(4.0, 0.0, 4.0),
+            ..Default::default()
+        };
+        let end = walk(&mut controller, start, input, &world, 10);
+
+        assert!(
+            end.x <= 8.0 - controller.width * 0.5 + 1e-4,
+            "stopped at the wall"
+        );
+        assert!(end.z > start.z + 1.5, "kept moving along the wall");
+        assert!(controller.on_ground);
+    }
+
+    #[test]
+    fn ladder_climbs_against_gravity() {
+        let mut world = floor_world();
+        for y in 64..70 {
+            world.set_voxel_at(5, y, 5, block(interactive_blocks::LADDER));
+        }
+        let blocks = BlockPropertiesRegistry::new();
+
+        let mut controller = PlayerController::new();
+        let start = eye(&controller, 5.5, 64.0, 5.5);
+        let climb = MoveIntent {
+            jump: true,
+            ..Default::default()
+        };
+        let result = controller.step(start, climb, &world, &blocks, DT);
+        assert!(result.on_ladder);
+        assert!((result.position.y - start.y - 3.0 * DT).abs() < 1e-4);
+
+        let position = walk(&mut controller, result.position, climb, &world, 9);
+        assert!(position.y > start.y + 1.4, "still climbing");
+
+        // Letting go on a ladder slides down slowly instead of free-falling.
+        let result = controller.step(position, MoveIntent::default(), &world, &blocks, DT);
+        assert!(controller.velocity.y >= -1.0);
+        assert!(result.position.y > position.y - 0.1);
+    }
+
+    #[test]
+    fn falling_caps_at_terminal_velocity_and_reports_distance() {
+        let world = floor_world();
+        let blocks = BlockPropertiesRegistry::new();
+        let mut controller = PlayerController::new();
+        let mut position = eye(&controller, 5.5, 160.0, 5.5);
+        controller.fall.update(160.0, Footing::Ground);
+
+        let mut landed = None;
+        for _ in 0..200 {
+            let result = controller.step(position, MoveIntent::default(), &world, &blocks, DT);
+            position = result.position;
+            assert!(controller.velocity.y >= controller.terminal_velocity);
+            if let Some(landing) = result.landing {
+                landed = Some(landing);
+                break;
+            }
+            if position.y < 90.0 {
+                assert_eq!(controller.velocity.y, controller.terminal_velocity);
+            }
+        }
+
+        // A blocked fall stops short of the floor (by at most one step's travel) and
+        // reports the distance fallen so far.
+        let landing = landed.expect("player lands on the floor");
+        let feet_y = position.y - controller.eye_height;
+        assert!(feet_y >= 64.0 && feet_y < 64.0 - controller.terminal_velocity * DT);
+        assert!((landing.distance - (160.0 - feet_y)).abs() < 1e-3);
+        assert_eq!(landing.damage, fall_damage(landing.distance));
+        assert_eq!(controller.velocity.y, 0.0);
+
+        let position = walk(&mut controller, position, MoveIntent::default(), &world, 40);
+        let feet_y = position.y - controller.eye_height;
+        assert!(
+            (64.0..64.1).contains(&feet_y),
+            "settles on the floor, got {feet_y}"
+        );
+        assert!(controller.on_ground);
+    }
+
+    #[test]
+    fn collision_boxes_match_block_shapes() {
+        let mut world = floor_world();
+        let blocks = BlockPropertiesRegistry::new();
+        world.set_voxel_at(2, 64, 2, block(interactive_blocks::OAK_FENCE));
+        world.set_voxel_at(3, 64, 2, block(interactive_blocks::OAK_FENCE));
+
+        let fence: Vec<_> = block_collision_boxes(&world, &blocks, 2, 64, 2)
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(fence.len(), 2, "post plus the east arm");
+        assert!(fence.iter().all(|b| b.max.y == 65.5));
+        assert_eq!(fence[1].max.x, 3.0);
+
+        assert!(block_collision_boxes(&world, &blocks, 2, 65, 2).is_empty());
+        assert_eq!(
+            block_collision_boxes(&world, &blocks, 0, WORLD_MIN_Y - 1, 0)
+                .iter()
+                .count(),
+            1,
+            "below the world is solid"
+        );
+        assert!(block_collision_boxes(&world, &blocks, 40, 64, 0).is_empty());
+    }
+
+    /// Feed a straight drop from `from` to `to` into a tracker, one block per tick.
+    fn drop(
+        tracker: &mut FallTracker,
+        from: f32,
+        to: f32,
+        landing: Footing,
+    ) -> Option<FallLanding> {
+        assert_eq!(tracker.update(from, Footing::Ground), None);
+        let mut y = from;
+        while y > to + 1.0 {
+            y -= 1.0;
+            assert_eq!(tracker.update(y, Footing::Air), None);
+        }
+        tracker.update(to, landing)
+    }
+
+    #[test]
+    fn short_fall_deals_no_damage() {
+        assert_eq!(fall_damage(2.0), 0.0);
+        assert_eq!(fall_damage(3.0), 0.0);
+        let mut tracker = FallTracker::new();
+        assert_eq!(drop(&mut tracker, 66.0, 64.0, Footing::Ground), None);
+    }
+
+    #[test]
+    fn ten_block_fall_deals_seven_points() {
+        assert_eq!(fall_damage(10.0), 7.0);
+        let mut tracker = FallTracker::new();
+        assert_eq!(
+            drop(&mut tracker, 74.0, 64.0, Footing::Ground),
+            Some(FallLanding {
+                distance: 10.0,
+                damage: 7.0
+            })
+        );
+        assert_eq!(tracker.peak_y(), Some(64.0), "the next fall starts here");
+    }
+
+    #[test]
+    fn fall_measures_from_the_peak_of_a_jump() {
+        let mut tracker = FallTracker::new();
+        tracker.update(64.0, Footing::Ground);
+        for y in [65.0, 66.0, 65.0, 63.0, 60.0, 58.0] {
+            tracker.update(y, Footing::Air);
+        }
+        assert_eq!(tracker.peak_y(), Some(66.0));
+        let landing = tracker.update(56.0, Footing::Ground).unwrap();
+        assert_eq!(landing.distance, 10.0);
+    }
+
+    #[test]
+    fn water_and_ladders_break_a_fall() {
+        let mut tracker = FallTracker::new();
+        assert_eq!(drop(&mut tracker, 84.0, 64.0, Footing::Water), None);
+        // Touching the bottom afterwards doesn't count the fall above the water.
+        assert_eq!(tracker.update(63.0, Footing::Ground), None);
+
+        let mut tracker = FallTracker::new();
+        assert_eq!(drop(&mut tracker, 84.0, 70.0, Footing::Ladder), None);
+        assert_eq!(tracker.update(70.0, Footing::Ground), None);
+
+        // A reset (teleport) forgets the height fallen so far.
+        let mut tracker = FallTracker::new();
+        tracker.update(100.0, Footing::Air);
+        tracker.reset();
+        tracker.update(65.0, Footing::Air);
+        assert_eq!(tracker.update(64.0, Footing::Ground), None);
+    }
+}
@@ -183,10 +183,19 @@ impl StatusEffect {
     }
 }
 
+/// Emitted by [`StatusEffects::tick`] when an effect runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EffectExpired {
+    /// The effect that is no longer active.
+    pub effect_type: StatusEffectType,
+}
+
 /// Collection of active status effects on an entity.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StatusEffects {
-    /// Active effects (only one of each type allowed).
+    /// Active effects. The first entry of each type is the active one; any later
+    /// entries of that type are weaker but longer effects waiting underneath,
+    /// ordered by amplifier (descending) with strictly increasing durations.
     effects: Vec<StatusEffect>,
 }
 
@@ -196,29 +205,55 @@ impl StatusEffects {
         Self::default()
     }
 
-    /// Add or upgrade a status effect.
-    /// If an effect of the same type exists:
-    /// - If new effect has higher amplifier, replace it
-    /// - If same amplifier, extend duration
+    /// Add or upgrade a status effect (see [`StatusEffects::apply`]).
     pub fn add(&mut self, effect: StatusEffect) {
-        if let Some(existing) = self
+        self.apply(effect);
+    }
+
+    /// Apply a status effect using vanilla stacking rules.
+    ///
+    /// Against an active effect of the same type:
+    /// - a higher amplifier replaces it;
+    /// - the same amplifier refreshes it if the new duration is longer;
+    /// - a lower amplifier is ignored unless it lasts longer, in which case it
+    ///   waits underneath and takes over once the stronger effect runs out.
+    ///
+    /// A replaced effect that would have outlasted its replacement is likewise
+    /// kept underneath.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let effect_type = effect.effect_type;
+        let at = self
+            .effects
+            .iter()
+            .position(|e| e.effect_type == effect_type)
+            .unwrap_or(self.effects.len());
+
+        let mut stack: Vec<StatusEffect> = self
             .effects
-            .iter_mut()
-            .find(|e| e.effect_type == effect.effect_type)
-        {
-            if effect.amplifier > existing.amplifier {
-                // Higher level replaces
-                *existing = effect;
-            } else if effect.amplifier == existing.amplifier
-                && effect.duration_ticks > existing.duration_ticks
-            {
-                // Same level, longer duration extends
-                existing.duration_ticks = effect.duration_ticks;
+            .iter()
+            .filter(|e| e.effect_type == effect_type)
+            .copied()
+            .collect();
+        self.effects.retain(|e| e.effect_type != effect_type);
+        stack.push(effect);
+        // Stable: an existing effect stays ahead of an equal-amplifier newcomer.
+        stack.sort_by_key(|e| std::cmp::Reverse(e.amplifier));
+
+        let mut layers: Vec<StatusEffect> = Vec::with_capacity(stack.len());
+        for effect in stack {
+            match layers.last_mut() {
+                Some(top) if top.amplifier == effect.amplifier => {
+                    if effect.duration_ticks > top.duration_ticks {
+                        *top = effect;
+                    }
+                }
+                // Weaker and gone no later than a stronger layer: never visible.
+                Some(top) if effect.duration_ticks <= top.duration_ticks => {}
+                _ => layers.push(effect),
             }
-            // Lower level or shorter duration: ignore
-        } else {
-            self.effects.push(effect);
         }
+
+        self.effects.splice(at..at, layers);
     }
 
     /// Remove an effect by type.
@@ -249,24 +284,46 @@ impl StatusEffects {
         self.effects.iter().find(|e| e.effect_type == effect_type)
     }
 
-    /// Update all effects, removing expired ones.
-    /// Returns a list of effects that expired this tick.
-    pub fn tick(&mut self) -> Vec<StatusEffectType> {
-        let mut expired = Vec::new();
-        self.effects.retain_mut(|effect| {
-            if effect.tick() {
-                expired.push(effect.effect_type);
-                false
+    /// Advance all effects by `dt_ticks`, removing the ones that ran out.
+    ///
+    /// Effects waiting underneath a stronger one count down at the same time.
+    /// Returns one event per effect type that is no longer active; a type whose
+    /// stronger layer ran out but has a weaker one left is not reported.
+    pub fn tick(&mut self, dt_ticks: u32) -> Vec<EffectExpired> {
+        for effect in &mut self.effects {
+            effect.duration_ticks = if effect.effect_type.is_instant() {
+                0
             } else {
-                true
+                effect.duration_ticks.saturating_sub(dt_ticks)
+            };
+        }
+
+        let mut expired: Vec<EffectExpired> = Vec::new();
+        for effect in &self.effects {
+            let effect_type = effect.effect_type;
+            let any_left = self
+                .effects
+                .iter()
+                .any(|e| e.effect_type == effect_type && e.duration_ticks > 0);
+            if !any_left && !expired.iter().any(|e| e.effect_type == effect_type) {
+                expired.push(EffectExpired { effect_type });
             }
-        });
+        }
+        self.effects.retain(|effect| effect.duration_ticks > 0);
         expired
     }
 
-    /// Get iterator over active effects.
+    /// Get iterator over active effects (one per type).
     pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
-        self.effects.iter()
+        self.effects
+            .iter()
+            .enumerate()
+            .filter(|(index, effect)| {
+                !self.effects[..*index]
+                    .iter()
+                    .any(|e| e.effect_type == effect.effect_type)
+            })
+            .map(|(_, effect)| effect)
     }
 
     /// Check if any effects are active.
@@ -1231,13 +1288,18 @@ mod tests {
         effects.add(StatusEffect::new(StatusEffectType::Strength, 0, 100)); // Long duration
 
         // First tick - no expiry
-        let expired = effects.tick();
+        let expired = effects.tick(1);
         assert!(expired.is_empty());
         assert!(effects.has(StatusEffectType::Speed));
 
         // Second tick - speed expires
-        let expired = effects.tick();
-        assert!(expired.contains(&StatusEffectType::Speed));
+        let expired = effects.tick(1);
+        assert_eq!(
+            expired,
+            vec![EffectExpired {
+                effect_type: StatusEffectType::Speed
+            }]
+        );
         assert!(!effects.has(StatusEffectType::Speed));
         assert!(effects.has(StatusEffectType::Strength));
     }
@@ -1247,8 +1309,13 @@ mod tests {
         let mut effects = StatusEffects::new();
         effects.add(StatusEffect::instant(StatusEffectType::InstantHealth, 0));
 
-        let expired = effects.tick();
-        assert!(expired.contains(&StatusEffectType::InstantHealth));
+        let expired = effects.tick(1);
+        assert_eq!(
+            expired,
+            vec![EffectExpired {
+                effect_type: StatusEffectType::InstantHealth
+            }]
+        );
         assert!(!effects.has(StatusEffectType::InstantHealth));
     }
 
@@ -1618,4 +1685,129 @@ mod tests {
         // Very high slowness would go negative, but clamped to 0
         // (this tests the max(0.0) behavior)
     }
+
+    #[test]
+    fn test_apply_higher_amplifier_replaces() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::new(StatusEffectType::Strength, 0, 400));
+        effects.apply(StatusEffect::new(StatusEffectType::Strength, 1, 600));
+
+        let active = effects.get(StatusEffectType::Strength).unwrap();
+        assert_eq!((active.amplifier, active.duration_ticks), (1, 600));
+        assert_eq!(effects.iter().count(), 1);
+
+        // The weaker, shorter effect is gone for good.
+        assert!(effects.tick(600).contains(&EffectExpired {
+            effect_type: StatusEffectType::Strength
+        }));
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn test_apply_equal_amplifier_refreshes_duration() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::new(StatusEffectType::Haste, 1, 300));
+        effects.tick(200);
+        assert_eq!(
+            effects.get(StatusEffectType::Haste).unwrap().duration_ticks,
+            100
+        );
+
+        effects.apply(StatusEffect::new(StatusEffectType::Haste, 1, 300));
+        assert_eq!(
+            effects.get(StatusEffectType::Haste).unwrap().duration_ticks,
+            300
+        );
+
+        // A shorter one of the same level doesn't cut it back.
+        effects.apply(StatusEffect::new(StatusEffectType::Haste, 1, 10));
+        assert_eq!(
+            effects.get(StatusEffectType::Haste).unwrap().duration_ticks,
+            300
+        );
+    }
+
+    #[test]
+    fn test_apply_lower_shorter_amplifier_is_ignored() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::new(StatusEffectType::Speed, 2, 600));
+        effects.apply(StatusEffect::new(StatusEffectType::Speed, 0, 600));
+        effects.apply(StatusEffect::new(StatusEffectType::Speed, 1, 100));
+
+        assert_eq!(effects.amplifier(StatusEffectType::Speed), Some(2));
+        let expired = effects.tick(600);
+        assert_eq!(
+            expired,
+            vec![EffectExpired {
+                effect_type: StatusEffectType::Speed
+            }]
+        );
+        assert!(!effects.has(StatusEffectType::Speed));
+    }
+
+    #[test]
+    fn test_apply_lower_longer_amplifier_takes_over_later() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::new(StatusEffectType::Speed, 1, 200));
+        effects.apply(StatusEffect::new(StatusEffectType::Speed, 0, 1000));
+
+        // The stronger effect stays active and is the only one visible.
+        assert_eq!(effects.amplifier(StatusEffectType::Speed), Some(1));
+        assert_eq!(effects.iter().count(), 1);
+
+        // When it runs out the weaker one takes over with its remaining time;
+        // the type is still active, so nothing is reported.
+        assert!(effects.tick(200).is_empty());
+        let active = effects.get(StatusEffectType::Speed).unwrap();
+        assert_eq!((active.amplifier, active.duration_ticks), (0, 800));
+
+        assert_eq!(
+            effects.tick(800),
+            vec![EffectExpired {
+                effect_type: StatusEffectType::Speed
+            }]
+        );
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn test_replaced_longer_effect_resumes_after_upgrade() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::new(StatusEffectType::Resistance, 0, 1000));
+        effects.apply(StatusEffect::new(StatusEffectType::Resistance, 1, 100));
+        assert_eq!(effects.amplifier(StatusEffectType::Resistance), Some(1));
+
+        assert!(effects.tick(100).is_empty());
+        let active = effects.get(StatusEffectType::Resistance).unwrap();
+        assert_eq!((active.amplifier, active.duration_ticks), (0, 900));
+    }
+
+    #[test]
+    fn test_distinct_effects_tick_independently() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::new(StatusEffectType::Speed, 0, 50));
+        effects.apply(StatusEffect::new(StatusEffectType::NightVision, 0, 120));
+        effects.apply(StatusEffect::new(StatusEffectType::Poison, 1, 80));
+
+        assert!(effects.tick(40).is_empty());
+        assert_eq!(
+            effects.tick(40),
+            vec![
+                EffectExpired {
+                    effect_type: StatusEffectType::Speed
+                },
+                EffectExpired {
+                    effect_type: StatusEffectType::Poison
+                },
+            ]
+        );
+        assert_eq!(
+            effects
+                .get(StatusEffectType::NightVision)
+                .unwrap()
+                .duration_ticks,
+            40
+        );
+        assert_eq!(effects.iter().count(), 1);
+    }
 }
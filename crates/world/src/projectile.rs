@@ -2,9 +2,12 @@
 //!
 //! Provides projectile physics, collision detection, and damage calculation.
 
+use glam::{DVec3, IVec3};
 use mdminecraft_core::DimensionId;
 use serde::{Deserialize, Serialize};
 
+use crate::{BlockPropertiesRegistry, Mob, WorldView};
+
 /// Ownership information for projectiles that have thrower-dependent behavior.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum ProjectileOwner {
@@ -592,6 +595,126 @@ impl Projectile {
     }
 }
 
+/// What a projectile ran into during [`ProjectileManager::step`].
+///
+/// The projectile is removed from the manager and handed back so the caller
+/// can apply its damage, knockback or impact effect.
+#[derive(Debug, Clone)]
+pub enum ProjectileHit {
+    /// Hit the face of a solid block.
+    Block {
+        /// The projectile, positioned at the impact point.
+        projectile: Projectile,
+        /// Block that was hit.
+        pos: IVec3,
+        /// Outward normal of the face that was entered (zero if the projectile
+        /// started the tick inside the block).
+        normal: IVec3,
+    },
+    /// Hit a mob's bounding box.
+    Entity {
+        /// The projectile, positioned at the impact point.
+        projectile: Projectile,
+        /// Index into the `mobs` slice passed to `step`.
+        mob_index: usize,
+        /// Where the projectile entered the mob's box.
+        point: DVec3,
+    },
+}
+
+impl Projectile {
+    /// Segment covered during the last [`Projectile::update`].
+    fn swept_segment(&self) -> (DVec3, DVec3) {
+        (
+            DVec3::new(self.prev_x, self.prev_y, self.prev_z),
+            DVec3::new(self.x, self.y, self.z),
+        )
+    }
+
+    fn move_to(&mut self, point: DVec3) {
+        self.x = point.x;
+        self.y = point.y;
+        self.z = point.z;
+    }
+}
+
+/// First solid block along `from..to`, as `(t, block, entered face normal)`.
+///
+/// Walks every voxel the segment crosses, so a fast projectile can't skip over
+/// a thin wall between ticks.
+fn first_block_hit(
+    world: &impl WorldView,
+    blocks: &BlockPropertiesRegistry,
+    from: DVec3,
+    to: DVec3,
+) -> Option<(f64, IVec3, IVec3)> {
+    let mut cell = from.floor().as_ivec3();
+    if world.is_solid_at(cell.x, cell.y, cell.z, blocks) {
+        return Some((0.0, cell, IVec3::ZERO));
+    }
+
+    let delta = (to - from).to_array();
+    let origin = from.to_array();
+    let mut step = [0; 3];
+    let mut t_max = [f64::INFINITY; 3];
+    let mut t_delta = [f64::INFINITY; 3];
+    for axis in 0..3 {
+        if delta[axis] > 0.0 {
+            step[axis] = 1;
+            t_max[axis] = (cell[axis] as f64 + 1.0 - origin[axis]) / delta[axis];
+            t_delta[axis] = 1.0 / delta[axis];
+        } else if delta[axis] < 0.0 {
+            step[axis] = -1;
+            t_max[axis] = (cell[axis] as f64 - origin[axis]) / delta[axis];
+            t_delta[axis] = -1.0 / delta[axis];
+        }
+    }
+
+    loop {
+        let axis = if t_max[0] <= t_max[1] && t_max[0] <= t_max[2] {
+            0
+        } else if t_max[1] <= t_max[2] {
+            1
+        } else {
+            2
+        };
+        let t = t_max[axis];
+        if t > 1.0 {
+            return None;
+        }
+        cell[axis] += step[axis];
+        t_max[axis] += t_delta[axis];
+        if world.is_solid_at(cell.x, cell.y, cell.z, blocks) {
+            let mut normal = IVec3::ZERO;
+            normal[axis] = -step[axis];
+            return Some((t, cell, normal));
+        }
+    }
+}
+
+/// Fraction of `from..to` at which the segment enters the box `min..max`.
+fn segment_entry_fraction(min: DVec3, max: DVec3, from: DVec3, to: DVec3) -> Option<f64> {
+    let delta = to - from;
+    let mut t_enter = 0.0_f64;
+    let mut t_exit = 1.0_f64;
+    for axis in 0..3 {
+        if delta[axis].abs() < 1.0e-12 {
+            if from[axis] < min[axis] || from[axis] > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let t0 = (min[axis] - from[axis]) / delta[axis];
+        let t1 = (max[axis] - from[axis]) / delta[axis];
+        t_enter = t_enter.max(t0.min(t1));
+        t_exit = t_exit.min(t0.max(t1));
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+    Some(t_enter)
+}
+
 /// Manages projectiles in the world
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectileManager {
@@ -623,6 +746,81 @@ impl ProjectileManager {
         });
     }
 
+    /// Move projectiles in `dimension` one tick and resolve what they hit.
+    ///
+    /// Each projectile's path this tick is swept against solid blocks and the
+    /// bounding boxes of live `mobs` in the same dimension; the nearest impact
+    /// wins, mobs winning ties. Projectiles that hit something are removed and
+    /// returned in the order they were spawned. Expired projectiles are removed
+    /// silently, as in [`ProjectileManager::update`].
+    pub fn step(
+        &mut self,
+        dimension: DimensionId,
+        world: &impl WorldView,
+        blocks: &BlockPropertiesRegistry,
+        mobs: &[Mob],
+    ) -> Vec<ProjectileHit> {
+        let mut hits = Vec::new();
+        let mut remaining = Vec::with_capacity(self.projectiles.len());
+
+        for mut projectile in self.projectiles.drain(..) {
+            if projectile.dimension != dimension {
+                if !projectile.dead {
+                    remaining.push(projectile);
+                }
+                continue;
+            }
+            if projectile.update() {
+                continue;
+            }
+            if projectile.stuck {
+                remaining.push(projectile);
+                continue;
+            }
+
+            let (from, to) = projectile.swept_segment();
+            let block_hit = first_block_hit(world, blocks, from, to);
+            let max_t = block_hit.map_or(1.0, |(t, _, _)| t);
+
+            let hitbox = projectile.projectile_type.hitbox_radius();
+            let mob_hit = mobs
+                .iter()
+                .enumerate()
+                .filter(|(_, mob)| mob.dimension == dimension && !mob.dead)
+                .filter_map(|(index, mob)| {
+                    let radius = mob.mob_type.size() as f64;
+                    let pos = DVec3::new(mob.x, mob.y, mob.z);
+                    let min = pos - DVec3::new(radius, 0.0, radius) - hitbox;
+                    let max = pos + DVec3::new(radius, radius * 2.0, radius) + hitbox;
+                    let t = segment_entry_fraction(min, max, from, to)?;
+                    (t <= max_t).then_some((index, t))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((mob_index, t)) = mob_hit {
+                let point = from.lerp(to, t);
+                projectile.move_to(point);
+                hits.push(ProjectileHit::Entity {
+                    projectile,
+                    mob_index,
+                    point,
+                });
+            } else if let Some((t, pos, normal)) = block_hit {
+                projectile.move_to(from.lerp(to, t));
+                hits.push(ProjectileHit::Block {
+                    projectile,
+                    pos,
+                    normal,
+                });
+            } else {
+                remaining.push(projectile);
+            }
+        }
+
+        self.projectiles = remaining;
+        hits
+    }
+
     /// Check for collision with a point (mob/player position)
     /// Returns the damage if hit
     pub fn check_hit(
@@ -655,6 +853,8 @@ impl ProjectileManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Chunk, ChunkPos, MobType, Voxel, BLOCK_STONE};
+    use std::collections::HashMap;
 
     #[test]
     fn test_arrow_creation() {
@@ -1061,4 +1261,125 @@ mod tests {
         // Potion should have slight upward arc added
         assert!(potion.vel_y > 0.0);
     }
+
+    fn world_with_wall(wall_x: i32) -> HashMap<ChunkPos, Chunk> {
+        let mut chunks = HashMap::new();
+        chunks.insert(ChunkPos::new(0, 0), Chunk::new(ChunkPos::new(0, 0)));
+        for y in 60..70 {
+            for z in 0..4 {
+                chunks.set_voxel_at(
+                    wall_x,
+                    y,
+                    z,
+                    Voxel {
+                        id: BLOCK_STONE,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        chunks
+    }
+
+    fn fast_arrow(x: f64, speed: f64) -> ProjectileManager {
+        let mut manager = ProjectileManager::new();
+        manager.spawn(
+            DimensionId::DEFAULT,
+            Projectile::new(x, 64.5, 1.5, speed, 0.0, 0.0, ProjectileType::Arrow, 1.0),
+        );
+        manager
+    }
+
+    #[test]
+    fn test_fast_arrow_hits_wall_without_tunneling() {
+        let world = world_with_wall(5);
+        let blocks = BlockPropertiesRegistry::new();
+        // Three blocks per tick: the wall is crossed between two positions.
+        let mut manager = fast_arrow(0.5, 3.0);
+
+        assert!(manager
+            .step(DimensionId::DEFAULT, &world, &blocks, &[])
+            .is_empty());
+        let hits = manager.step(DimensionId::DEFAULT, &world, &blocks, &[]);
+
+        assert_eq!(hits.len(), 1);
+        let ProjectileHit::Block {
+            projectile,
+            pos,
+            normal,
+        } = &hits[0]
+        else {
+            panic!("expected a block hit, got {:?}", hits[0]);
+        };
+        assert_eq!(*pos, IVec3::new(5, 64, 1));
+        assert_eq!(*normal, IVec3::new(-1, 0, 0));
+        assert!((projectile.x - 5.0).abs() < 1.0e-9);
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[test]
+    fn test_arrow_passing_through_mob_box_hits_mob() {
+        let world = world_with_wall(12);
+        let blocks = BlockPropertiesRegistry::new();
+        let mut dead = Mob::new(2.5, 64.0, 1.5, MobType::Zombie);
+        dead.dead = true;
+        let other_dimension = Mob {
+            dimension: DimensionId::Nether,
+            ..Mob::new(2.5, 64.0, 1.5, MobType::Zombie)
+        };
+        let target = Mob::new(3.0, 64.0, 1.5, MobType::Zombie);
+        let mobs = [dead, other_dimension, target];
+        // Both ends of the first tick's path lie outside the zombie's box.
+        let mut manager = fast_arrow(0.5, 5.0);
+
+        let hits = manager.step(DimensionId::DEFAULT, &world, &blocks, &mobs);
+
+        assert_eq!(hits.len(), 1);
+        let ProjectileHit::Entity {
+            projectile,
+            mob_index,
+            point,
+        } = &hits[0]
+        else {
+            panic!("expected an entity hit, got {:?}", hits[0]);
+        };
+        assert_eq!(*mob_index, 2);
+        let expected_x =
+            3.0 - MobType::Zombie.size() as f64 - ProjectileType::Arrow.hitbox_radius();
+        assert!((point.x - expected_x).abs() < 1.0e-6);
+        assert_eq!(projectile.x, point.x);
+        assert!(projectile.damage() > 0.0);
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[test]
+    fn test_block_in_front_of_mob_takes_the_hit() {
+        let world = world_with_wall(2);
+        let blocks = BlockPropertiesRegistry::new();
+        let mobs = [Mob::new(4.0, 64.0, 1.5, MobType::Zombie)];
+        let mut manager = fast_arrow(0.5, 5.0);
+
+        let hits = manager.step(DimensionId::DEFAULT, &world, &blocks, &mobs);
+
+        assert!(matches!(
+            hits.as_slice(),
+            [ProjectileHit::Block { pos, .. }] if *pos == IVec3::new(2, 64, 1)
+        ));
+    }
+
+    #[test]
+    fn test_arrow_flying_past_everything_keeps_flying() {
+        let world = world_with_wall(40);
+        let blocks = BlockPropertiesRegistry::new();
+        let mobs = [Mob::new(3.0, 64.0, 8.0, MobType::Zombie)];
+        let mut manager = fast_arrow(0.5, 2.0);
+
+        for _ in 0..5 {
+            assert!(manager
+                .step(DimensionId::DEFAULT, &world, &blocks, &mobs)
+                .is_empty());
+        }
+        assert_eq!(manager.count(), 1);
+        assert!(manager.projectiles[0].x > 9.0);
+    }
 }
@@ -41,6 +41,10 @@ pub mod mechanical_blocks {
 /// Maximum redstone power level
 pub const MAX_POWER: u8 = 15;
 
+/// Most events kept between `drain_events` calls; later ones are dropped so an
+/// undrained simulator cannot grow without bound.
+pub const MAX_PENDING_EVENTS: usize = 4096;
+
 const PISTON_PUSH_LIMIT: usize = 12;
 
 /// Type of redstone component
@@ -334,6 +338,26 @@ impl RedstonePos {
     }
 }
 
+/// A visible redstone state change, for audio and particle hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedstoneEvent {
+    /// A wire's power level changed.
+    PowerChanged { pos: RedstonePos, old: u8, new: u8 },
+    /// A component switched state (lever flipped, lamp lit, door opened, ...).
+    ComponentToggled { pos: RedstonePos },
+}
+
+impl RedstoneEvent {
+    /// Position of the block that changed.
+    pub fn pos(&self) -> RedstonePos {
+        match *self {
+            RedstoneEvent::PowerChanged { pos, .. } | RedstoneEvent::ComponentToggled { pos } => {
+                pos
+            }
+        }
+    }
+}
+
 /// Pending button deactivation
 #[derive(Debug, Clone, Copy)]
 struct ButtonTimer {
@@ -389,6 +413,8 @@ pub struct RedstoneSimulator {
     dirty_geometry_chunks: std::collections::HashSet<ChunkPos>,
     /// Positions where block-ID (geometry) changes occurred (used for waking other sims).
     dirty_geometry_positions: BTreeSet<RedstonePos>,
+    /// State changes since the last `drain_events`.
+    events: Vec<RedstoneEvent>,
 }
 
 impl RedstoneSimulator {
@@ -406,6 +432,7 @@ impl RedstoneSimulator {
             dirty_light_chunks: std::collections::HashSet::new(),
             dirty_geometry_chunks: std::collections::HashSet::new(),
             dirty_geometry_positions: BTreeSet::new(),
+            events: Vec::new(),
         }
     }
 
@@ -1701,9 +1728,39 @@ impl RedstoneSimulator {
             if old.light_block != voxel.light_block {
                 self.dirty_light_chunks.insert(chunk_pos);
             }
+            self.record_event(pos, old, voxel);
+        }
+    }
+
+    fn record_event(&mut self, pos: RedstonePos, old: Voxel, new: Voxel) {
+        if self.events.len() >= MAX_PENDING_EVENTS {
+            return;
+        }
+        let is_wire = |voxel: Voxel| voxel.id == redstone_blocks::REDSTONE_WIRE;
+        if is_wire(old) && is_wire(new) {
+            let (old_power, new_power) = (get_power_level(old.state), get_power_level(new.state));
+            if old_power != new_power {
+                self.events.push(RedstoneEvent::PowerChanged {
+                    pos,
+                    old: old_power,
+                    new: new_power,
+                });
+            }
+        } else if old.id != new.id || old.state != new.state {
+            self.events.push(RedstoneEvent::ComponentToggled { pos });
         }
     }
 
+    /// Take the state changes recorded since the last call, ordered by position.
+    ///
+    /// Events at the same position stay in the order they happened. At most
+    /// [`MAX_PENDING_EVENTS`] are kept between calls.
+    pub fn drain_events(&mut self) -> Vec<RedstoneEvent> {
+        let mut events = std::mem::take(&mut self.events);
+        events.sort_by_key(RedstoneEvent::pos);
+        events
+    }
+
     /// Take the set of dirty chunks (clears internal state)
     pub fn take_dirty_chunks(&mut self) -> HashSet<ChunkPos> {
         std::mem::take(&mut self.dirty_chunks)
@@ -3381,4 +3438,114 @@ mod tests {
             ));
         }
     }
+
+    #[test]
+    fn test_lever_flip_emits_one_toggle_event() {
+        let mut sim = RedstoneSimulator::new();
+        let mut chunks = HashMap::new();
+        let mut chunk = create_test_chunk();
+        chunk.set_voxel(
+            5,
+            local_y(64),
+            5,
+            Voxel {
+                id: redstone_blocks::LEVER,
+                ..Default::default()
+            },
+        );
+        chunks.insert(ChunkPos::new(0, 0), chunk);
+
+        let pos = RedstonePos::new(5, 64, 5);
+        sim.toggle_lever(pos, &mut chunks);
+        sim.tick(&mut chunks);
+
+        assert_eq!(
+            sim.drain_events(),
+            vec![RedstoneEvent::ComponentToggled { pos }]
+        );
+        assert!(sim.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_undrained_events_are_capped() {
+        let mut sim = RedstoneSimulator::new();
+        let mut chunks = HashMap::new();
+        let mut chunk = create_test_chunk();
+        chunk.set_voxel(
+            5,
+            local_y(64),
+            5,
+            Voxel {
+                id: redstone_blocks::LEVER,
+                ..Default::default()
+            },
+        );
+        chunks.insert(ChunkPos::new(0, 0), chunk);
+
+        let pos = RedstonePos::new(5, 64, 5);
+        for _ in 0..MAX_PENDING_EVENTS + 10 {
+            sim.toggle_lever(pos, &mut chunks);
+        }
+
+        assert_eq!(sim.drain_events().len(), MAX_PENDING_EVENTS);
+        sim.toggle_lever(pos, &mut chunks);
+        assert_eq!(sim.drain_events().len(), 1);
+    }
+
+    #[test]
+    fn test_powering_wire_emits_power_changed_per_cell() {
+        let mut sim = RedstoneSimulator::new();
+        let mut chunks = HashMap::new();
+        let mut chunk = create_test_chunk();
+        chunk.set_voxel(
+            5,
+            local_y(64),
+            5,
+            Voxel {
+                id: redstone_blocks::LEVER,
+                ..Default::default()
+            },
+        );
+        for x in 6..9 {
+            chunk.set_voxel(
+                x,
+                local_y(64),
+                5,
+                Voxel {
+                    id: redstone_blocks::REDSTONE_WIRE,
+                    ..Default::default()
+                },
+            );
+        }
+        chunks.insert(ChunkPos::new(0, 0), chunk);
+
+        let lever = RedstonePos::new(5, 64, 5);
+        sim.toggle_lever(lever, &mut chunks);
+        while sim.pending_count() > 0 {
+            sim.tick(&mut chunks);
+        }
+
+        let wire = |x| RedstonePos::new(x, 64, 5);
+        assert_eq!(
+            sim.drain_events(),
+            vec![
+                RedstoneEvent::ComponentToggled { pos: lever },
+                RedstoneEvent::PowerChanged {
+                    pos: wire(6),
+                    old: 0,
+                    new: 14,
+                },
+                RedstoneEvent::PowerChanged {
+                    pos: wire(7),
+                    old: 0,
+                    new: 13,
+                },
+                RedstoneEvent::PowerChanged {
+                    pos: wire(8),
+                    old: 0,
+                    new: 12,
+                },
+            ]
+        );
+    }
 }
@@ -7,13 +7,22 @@ use mdminecraft_core::DimensionId;
 
 use crate::{Chunk, ChunkKey, ChunkPos};
 
+/// Hook invoked with a chunk that has unsaved changes just before it is evicted.
+pub type ChunkSaveCallback = Box<dyn FnMut(ChunkKey, &Chunk) + Send>;
+
 /// In-memory chunk arena with an LRU eviction policy.
 /// Uses BTreeMap for deterministic iteration order (critical for multiplayer sync).
+///
+/// Inserting past `capacity` evicts the least recently used chunk. `ensure_chunk*`
+/// and `get_mut*` count as a use; `get*` does not. Evicted chunks with
+/// [`DirtyFlags::SAVE`](crate::DirtyFlags::SAVE) set are handed to the save callback
+/// (typically backed by a `RegionStore`) before being dropped.
 pub struct ChunkStorage {
     /// Chunks stored with deterministic key ordering.
     chunks: BTreeMap<ChunkKey, Chunk>,
     lru: LruCache<ChunkKey, ()>,
     capacity: usize,
+    save_callback: Option<ChunkSaveCallback>,
 }
 
 impl ChunkStorage {
@@ -25,7 +34,37 @@ impl ChunkStorage {
             chunks: BTreeMap::new(),
             lru: LruCache::new(cap),
             capacity,
+            save_callback: None,
+        }
+    }
+
+    /// Install the hook that persists dirty chunks on eviction.
+    pub fn set_save_callback(&mut self, callback: impl FnMut(ChunkKey, &Chunk) + Send + 'static) {
+        self.save_callback = Some(Box::new(callback));
+    }
+
+    /// Maximum number of resident chunks.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the maximum chunk count, evicting least recently used chunks if over it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_to_capacity();
+        let cap = NonZeroUsize::new(capacity.max(1)).expect("capacity is at least 1 after max(1)");
+        self.lru.resize(cap);
+    }
+
+    /// Evict least recently used chunks until at most `capacity` remain.
+    ///
+    /// Returns the number of chunks evicted.
+    pub fn evict_to_capacity(&mut self) -> usize {
+        let mut evicted = 0;
+        while self.chunks.len() > self.capacity && self.evict_lru() {
+            evicted += 1;
         }
+        evicted
     }
 
     /// Number of resident chunks.
@@ -106,13 +145,26 @@ impl ChunkStorage {
 
     fn evict_if_needed(&mut self) {
         while self.chunks.len() >= self.capacity {
-            if let Some((oldest, _)) = self.lru.pop_lru() {
-                self.chunks.remove(&oldest);
-            } else {
+            if !self.evict_lru() {
                 break;
             }
         }
     }
+
+    /// Drop the least recently used chunk, saving it first if dirty.
+    fn evict_lru(&mut self) -> bool {
+        let Some((oldest, _)) = self.lru.pop_lru() else {
+            return false;
+        };
+        if let Some(chunk) = self.chunks.remove(&oldest) {
+            if chunk.needs_save() {
+                if let Some(save) = self.save_callback.as_mut() {
+                    save(oldest, &chunk);
+                }
+            }
+        }
+        true
+    }
 }
 
 #[cfg(test)]
@@ -136,6 +188,68 @@ mod tests {
         assert!(storage.get(c).is_some());
     }
 
+    #[test]
+    fn accessing_a_chunk_makes_it_most_recent() {
+        let mut storage = ChunkStorage::new(3);
+        let a = ChunkPos::new(0, 0);
+        let b = ChunkPos::new(1, 0);
+        let c = ChunkPos::new(2, 0);
+        storage.ensure_chunk(a);
+        storage.ensure_chunk(b);
+        storage.ensure_chunk(c);
+
+        // Touch `a` so `b` becomes the least recently used.
+        assert!(storage.get_mut(a).is_some());
+        storage.set_capacity(2);
+        assert_eq!(storage.len(), 2);
+        assert!(storage.get(a).is_some());
+        assert!(storage.get(b).is_none());
+        assert!(storage.get(c).is_some());
+    }
+
+    #[test]
+    fn evicting_dirty_chunk_calls_save_hook_once() {
+        use crate::{RegionStore, Voxel, BLOCK_STONE};
+        use std::sync::{Arc, Mutex};
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("mdminecraft_evict_save_{timestamp}"));
+        let store = RegionStore::new(&dir).unwrap();
+
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let mut storage = ChunkStorage::new(4);
+        let sink = Arc::clone(&saved);
+        storage.set_save_callback(move |key, chunk| {
+            assert!(chunk.needs_save());
+            sink.lock().unwrap().push(key.pos);
+        });
+
+        let stone = Voxel {
+            id: BLOCK_STONE,
+            ..Default::default()
+        };
+        let dirty = ChunkPos::new(0, 0);
+        let clean = ChunkPos::new(1, 0);
+        let persisted = ChunkPos::new(2, 0);
+        storage.ensure_chunk(dirty).set_voxel(1, 2, 3, stone);
+        storage.ensure_chunk(clean);
+        // Edited, then written to disk: saving clears the flag, so eviction
+        // must not write it again.
+        let chunk = storage.ensure_chunk(persisted);
+        chunk.set_voxel(1, 2, 3, stone);
+        store.save_chunk(chunk).unwrap();
+
+        storage.set_capacity(0);
+        assert!(storage.is_empty());
+        assert_eq!(storage.evict_to_capacity(), 0);
+        assert_eq!(*saved.lock().unwrap(), vec![dirty]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn iter_positions_covers_resident_chunks() {
         let mut storage = ChunkStorage::new(2);
@@ -0,0 +1,291 @@
+//! Pluggable post-terrain placement passes (ore veins, small structures).
+//!
+//! Placers run after base terrain and the built-in structure passes. Each one is
+//! seeded per chunk so generation stays deterministic regardless of the order in
+//! which chunks are produced.
+
+use mdminecraft_core::{scoped_rng, SimTick};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::chunk::{
+    world_y_to_local_y, BlockId, Chunk, ChunkPos, Voxel, CHUNK_SIZE_X, CHUNK_SIZE_Z, WORLD_MAX_Y,
+    WORLD_MIN_Y,
+};
+
+/// A generation pass that adds blocks to a freshly generated chunk.
+///
+/// `place` may be called for chunks in any order. A structure that crosses a
+/// chunk border must be derived from the chunk that owns it (see
+/// [`structure_rng`]) and written only where it overlaps `chunk`, so every
+/// neighbor places its own slice of the same structure exactly once.
+pub trait StructurePlacer: Send + Sync {
+    /// Add this pass's blocks to `chunk`, located at `pos`.
+    fn place(&self, chunk: &mut Chunk, pos: ChunkPos, seed: u64);
+}
+
+/// Deterministic RNG for the structures owned by the chunk at `pos`.
+pub fn structure_rng(seed: u64, pos: ChunkPos) -> StdRng {
+    let chunk_hash = (pos.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (pos.z as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    scoped_rng(seed, chunk_hash, SimTick::ZERO)
+}
+
+/// Ordered list of placers run after base terrain.
+#[derive(Default)]
+pub struct StructurePlacerRegistry {
+    placers: Vec<Box<dyn StructurePlacer>>,
+}
+
+impl StructurePlacerRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a placer; placers run in registration order.
+    pub fn register(&mut self, placer: impl StructurePlacer + 'static) {
+        self.placers.push(Box::new(placer));
+    }
+
+    /// Number of registered placers.
+    pub fn len(&self) -> usize {
+        self.placers.len()
+    }
+
+    /// Returns true when no placers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.placers.is_empty()
+    }
+
+    /// Run every placer on `chunk`.
+    ///
+    /// Each placer gets its own seed derived from `world_seed` and its index, so
+    /// registering a new placer doesn't reshuffle the output of earlier ones.
+    pub fn place_all(&self, chunk: &mut Chunk, pos: ChunkPos, world_seed: u64) {
+        for (index, placer) in self.placers.iter().enumerate() {
+            let seed = world_seed ^ (index as u64 + 1).wrapping_mul(0xD6E8_FEB8_6659_FD93);
+            placer.place(chunk, pos, seed);
+        }
+    }
+}
+
+/// Scatters random-walk ore veins through a host block.
+#[derive(Debug, Clone, Copy)]
+pub struct OreVeinPlacer {
+    /// Block placed by the vein.
+    pub ore: BlockId,
+    /// Only voxels of this block are replaced.
+    pub host: BlockId,
+    /// Veins started in each chunk.
+    pub veins_per_chunk: u32,
+    /// Blocks visited by each vein's random walk.
+    pub vein_size: u32,
+    /// Lowest world Y a vein may start at.
+    pub min_y: i32,
+    /// Highest world Y a vein may start at.
+    pub max_y: i32,
+}
+
+impl OreVeinPlacer {
+    /// World positions of the veins owned by `owner`.
+    ///
+    /// Veins start inside `owner` but may wander into neighboring chunks.
+    pub fn veins(&self, seed: u64, owner: ChunkPos) -> Vec<(i32, i32, i32)> {
+        let mut rng = structure_rng(seed, owner);
+        let min_y = self.min_y.max(WORLD_MIN_Y);
+        let max_y = self.max_y.min(WORLD_MAX_Y);
+        let mut blocks = Vec::new();
+        if min_y > max_y {
+            return blocks;
+        }
+
+        for _ in 0..self.veins_per_chunk {
+            let mut x = owner.x * CHUNK_SIZE_X as i32 + rng.gen_range(0..CHUNK_SIZE_X as i32);
+            let mut y = rng.gen_range(min_y..=max_y);
+            let mut z = owner.z * CHUNK_SIZE_Z as i32 + rng.gen_range(0..CHUNK_SIZE_Z as i32);
+            for _ in 0..self.vein_size {
+                blocks.push((x, y, z));
+                match rng.gen_range(0..6) {
+                    0 => x += 1,
+                    1 => x -= 1,
+                    2 => y = (y + 1).min(WORLD_MAX_Y),
+                    3 => y = (y - 1).max(WORLD_MIN_Y),
+                    4 => z += 1,
+                    _ => z -= 1,
+                }
+            }
+        }
+        blocks
+    }
+
+    /// How many chunks away a vein can reach from its owner.
+    fn reach_chunks(&self) -> i32 {
+        self.vein_size
+            .div_ceil(CHUNK_SIZE_X.min(CHUNK_SIZE_Z) as u32) as i32
+    }
+}
+
+impl StructurePlacer for OreVeinPlacer {
+    fn place(&self, chunk: &mut Chunk, pos: ChunkPos, seed: u64) {
+        let reach = self.reach_chunks();
+        for dz in -reach..=reach {
+            for dx in -reach..=reach {
+                let owner = ChunkPos::new(pos.x + dx, pos.z + dz);
+                for (x, y, z) in self.veins(seed, owner) {
+                    if !is_in_chunk(pos, x, z) {
+                        continue;
+                    }
+                    let Some(local_y) = world_y_to_local_y(y) else {
+                        continue;
+                    };
+                    let local_x = x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
+                    let local_z = z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
+                    if chunk.voxel(local_x, local_y, local_z).id != self.host {
+                        continue;
+                    }
+                    chunk.set_voxel(
+                        local_x,
+                        local_y,
+                        local_z,
+                        Voxel {
+                            id: self.ore,
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn is_in_chunk(pos: ChunkPos, world_x: i32, world_z: i32) -> bool {
+    world_x.div_euclid(CHUNK_SIZE_X as i32) == pos.x
+        && world_z.div_euclid(CHUNK_SIZE_Z as i32) == pos.z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk::{BLOCK_COAL_ORE, BLOCK_STONE, CHUNK_SIZE_Y};
+    use std::collections::BTreeSet;
+
+    fn stone_chunk(pos: ChunkPos) -> Chunk {
+        let mut chunk = Chunk::new(pos);
+        for y in 0..CHUNK_SIZE_Y {
+            for z in 0..CHUNK_SIZE_Z {
+                for x in 0..CHUNK_SIZE_X {
+                    chunk.set_voxel(
+                        x,
+                        y,
+                        z,
+                        Voxel {
+                            id: BLOCK_STONE,
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+        }
+        chunk
+    }
+
+    fn ore_positions(chunk: &Chunk) -> BTreeSet<(i32, i32, i32)> {
+        let pos = chunk.position();
+        let mut out = BTreeSet::new();
+        for y in 0..CHUNK_SIZE_Y {
+            for z in 0..CHUNK_SIZE_Z {
+                for x in 0..CHUNK_SIZE_X {
+                    if chunk.voxel(x, y, z).id == BLOCK_COAL_ORE {
+                        out.insert((
+                            pos.x * CHUNK_SIZE_X as i32 + x as i32,
+                            crate::chunk::local_y_to_world_y(y),
+                            pos.z * CHUNK_SIZE_Z as i32 + z as i32,
+                        ));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    const VEINS: OreVeinPlacer = OreVeinPlacer {
+        ore: BLOCK_COAL_ORE,
+        host: BLOCK_STONE,
+        veins_per_chunk: 4,
+        vein_size: 12,
+        min_y: 0,
+        max_y: 64,
+    };
+
+    #[test]
+    fn same_seed_and_chunk_place_identically() {
+        let pos = ChunkPos::new(3, -7);
+        let mut registry = StructurePlacerRegistry::new();
+        registry.register(VEINS);
+
+        let mut a = stone_chunk(pos);
+        let mut b = stone_chunk(pos);
+        registry.place_all(&mut a, pos, 42);
+        registry.place_all(&mut b, pos, 42);
+        assert!(!ore_positions(&a).is_empty());
+        assert_eq!(ore_positions(&a), ore_positions(&b));
+
+        let mut other_seed = stone_chunk(pos);
+        registry.place_all(&mut other_seed, pos, 43);
+        assert_ne!(ore_positions(&a), ore_positions(&other_seed));
+    }
+
+    #[test]
+    fn straddling_vein_is_split_between_chunks_not_duplicated() {
+        let seed = 7;
+        let owner = ChunkPos::new(0, 0);
+        let east = ChunkPos::new(1, 0);
+
+        // Find a seed whose owner chunk has a vein crossing into the east neighbor.
+        let seed = (seed..seed + 500)
+            .find(|&s| {
+                VEINS
+                    .veins(s, owner)
+                    .iter()
+                    .any(|&(x, _, z)| is_in_chunk(east, x, z))
+            })
+            .expect("some seed produces a straddling vein");
+
+        let mut owner_chunk = stone_chunk(owner);
+        let mut east_chunk = stone_chunk(east);
+        VEINS.place(&mut owner_chunk, owner, seed);
+        VEINS.place(&mut east_chunk, east, seed);
+
+        // Every block of the owner's veins lands in whichever chunk contains it.
+        for (x, y, z) in VEINS.veins(seed, owner) {
+            let placed = if is_in_chunk(owner, x, z) {
+                &owner_chunk
+            } else if is_in_chunk(east, x, z) {
+                &east_chunk
+            } else {
+                continue;
+            };
+            assert!(ore_positions(placed).contains(&(x, y, z)));
+        }
+
+        // And each chunk contains exactly the union of veins that reach it — the east
+        // chunk doesn't regenerate its own copy of the straddling vein.
+        for (pos, chunk) in [(owner, &owner_chunk), (east, &east_chunk)] {
+            let reach = VEINS.reach_chunks();
+            let mut expected = BTreeSet::new();
+            for dz in -reach..=reach {
+                for dx in -reach..=reach {
+                    let source = ChunkPos::new(pos.x + dx, pos.z + dz);
+                    expected.extend(
+                        VEINS
+                            .veins(seed, source)
+                            .into_iter()
+                            .filter(|&(x, _, z)| is_in_chunk(pos, x, z)),
+                    );
+                }
+            }
+            assert_eq!(ore_positions(chunk), expected);
+        }
+    }
+}
@@ -11,10 +11,11 @@ use crate::chunk::{
 use crate::dungeon::DungeonGenerator;
 use crate::fortress::FortressGenerator;
 use crate::geode::GeodeGenerator;
-use crate::heightmap::Heightmap;
+use crate::heightmap::{Heightmap, BASE_HEIGHT, HEIGHT_VARIATION};
 use crate::mineshaft::MineshaftGenerator;
-use crate::noise::{NoiseConfig, NoiseGenerator};
+use crate::noise::{LayeredNoise, Noise, NoiseConfig, NoiseGenerator};
 use crate::ruin::RuinGenerator;
+use crate::structure_placer::{StructurePlacer, StructurePlacerRegistry};
 use crate::trees::{generate_tree_positions, Tree, TreeType};
 use crate::village::VillageGenerator;
 use mdminecraft_core::DimensionId;
@@ -74,6 +75,8 @@ pub mod blocks {
 pub struct TerrainGenerator {
     world_seed: u64,
     biome_assigner: BiomeAssigner,
+    height_noise: LayeredNoise,
+    w_noise: Noise,
     density_noise: NoiseGenerator,
     cave_noise: NoiseGenerator,
     aquifer_gen: AquiferGenerator,
@@ -83,6 +86,7 @@ pub struct TerrainGenerator {
     mineshaft_gen: MineshaftGenerator,
     village_gen: VillageGenerator,
     fortress_gen: FortressGenerator,
+    structure_placers: StructurePlacerRegistry,
 }
 
 impl TerrainGenerator {
@@ -107,6 +111,8 @@ impl TerrainGenerator {
         Self {
             world_seed,
             biome_assigner: BiomeAssigner::new(world_seed),
+            height_noise: LayeredNoise::new(world_seed),
+            w_noise: Noise::new(world_seed ^ 0x5753_4C49_4345), // "WSLICE"
             density_noise: NoiseGenerator::new(density_config),
             cave_noise: NoiseGenerator::new(cave_config),
             aquifer_gen: AquiferGenerator::new(world_seed),
@@ -116,6 +122,7 @@ impl TerrainGenerator {
             mineshaft_gen: MineshaftGenerator::new(world_seed),
             village_gen: VillageGenerator::new(world_seed),
             fortress_gen: FortressGenerator::new(world_seed),
+            structure_placers: StructurePlacerRegistry::new(),
         }
     }
 
@@ -138,12 +145,8 @@ impl TerrainGenerator {
                 let world_x = chunk_origin_x + local_x as i32;
                 let world_z = chunk_origin_z + local_z as i32;
 
-                let base_height = heightmap.get(local_x, local_z);
                 let biome = self.biome_assigner.get_biome(world_x, world_z);
-                let biome_data = BiomeData::get(biome);
-
-                // Adjust base height by biome
-                let target_height = (base_height as f32 + biome_data.height_modifier * 20.0) as i32;
+                let target_height = Self::target_height(heightmap.get(local_x, local_z), biome);
 
                 for local_y in 0..CHUNK_SIZE_Y {
                     let world_y = local_y_to_world_y(local_y);
@@ -176,32 +179,7 @@ impl TerrainGenerator {
                         continue;
                     }
 
-                    // Density calculation
-                    // 1. Vertical Gradient: Positive below target_height, negative above.
-                    // Scale factor controls slope steepness.
-                    let vertical_gradient = (target_height - world_y) as f64 / 20.0;
-
-                    // 2. 3D Noise: Adds variation/overhangs
-                    let noise_val = self.density_noise.sample_3d(
-                        world_x as f64,
-                        world_y as f64,
-                        world_z as f64,
-                    );
-
-                    // 3. Cave Noise: Subtracts density (but not below y=5 to preserve bedrock area)
-                    let cave_val =
-                        self.cave_noise
-                            .sample_3d(world_x as f64, world_y as f64, world_z as f64);
-                    // Use absolute value for cave tunnels (worm-like), but don't carve below y=5
-                    let cave_modifier = if local_y >= 5 && cave_val.abs() < 0.15 {
-                        -10.0
-                    } else {
-                        0.0
-                    };
-
-                    let density = vertical_gradient + noise_val + cave_modifier;
-
-                    if density > 0.0 {
+                    if self.is_solid_terrain(world_x, world_y, world_z, target_height) {
                         // Solid block
                         let block_id = if world_y > target_height - 4 && world_y <= target_height {
                             if world_y == target_height {
@@ -254,6 +232,8 @@ impl TerrainGenerator {
         self.mineshaft_gen.try_generate_mineshaft(&mut chunk);
         self.village_gen
             .try_generate_village(&mut chunk, &self.biome_assigner);
+        self.structure_placers
+            .place_all(&mut chunk, chunk_pos, self.world_seed);
 
         // Population pass: Add trees
         self.populate_trees(&mut chunk, chunk_origin_x, chunk_origin_z);
@@ -264,6 +244,101 @@ impl TerrainGenerator {
         chunk
     }
 
+    /// Add a placement pass (ore veins, small structures) that runs after base
+    /// terrain and the built-in structures in [`TerrainGenerator::generate_chunk`].
+    pub fn register_structure_placer(&mut self, placer: impl StructurePlacer + 'static) {
+        self.structure_placers.register(placer);
+    }
+
+    /// Biome at a world column, without generating the chunk.
+    pub fn biome_at(&self, world_x: i32, world_z: i32) -> BiomeId {
+        self.biome_assigner.get_biome(world_x, world_z)
+    }
+
+    /// Y of the topmost solid base-terrain block at a world column.
+    ///
+    /// Samples only the column's density (no chunk allocation, ores, structures
+    /// or vegetation), so minimaps and structure placement can query it cheaply.
+    /// Matches the surface produced by [`TerrainGenerator::generate_chunk`]
+    /// before decoration passes run.
+    pub fn surface_height_at(&self, world_x: i32, world_z: i32) -> i32 {
+        let biome = self.biome_at(world_x, world_z);
+        let base_height = Heightmap::column_height(&self.height_noise, world_x, world_z);
+        let target_height = Self::target_height(base_height, biome);
+
+        let floor_y = local_y_to_world_y(4);
+        (floor_y + 1..=WORLD_MAX_Y)
+            .rev()
+            .find(|&world_y| self.is_solid_terrain(world_x, world_y, world_z, target_height))
+            .unwrap_or(floor_y)
+    }
+
+    /// Terrain height at `(x, z)` in the W slice `w`, with `y` adding overhang-scale detail.
+    ///
+    /// Height comes from 4D noise rather than per-slice phase offsets, so
+    /// adjacent W slices change gradually (W is scaled like a horizontal axis)
+    /// while distant slices are effectively unrelated.
+    ///
+    /// This is an opt-in query for W-travel experiments: chunk generation does
+    /// not call it, and its heights do not match [`Self::surface_height_at`].
+    pub fn terrain_height_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        let shape = NoiseConfig {
+            octaves: 4,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            frequency: 0.005,
+            seed: 0,
+        };
+        let detail = NoiseConfig {
+            octaves: 2,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            frequency: 0.04,
+            seed: 0,
+        };
+
+        // Sample shape noise with (x, z, w) as the first three axes so the
+        // height field is independent of `y`.
+        let base = self.w_noise.fbm4d(x, z, w, 0.0, &shape);
+        let overhang = self.w_noise.fbm4d(x, y, z, w, &detail);
+
+        BASE_HEIGHT as f64 + base * HEIGHT_VARIATION as f64 + overhang * 4.0
+    }
+
+    /// Heightmap height adjusted by the biome's height modifier.
+    fn target_height(base_height: i32, biome: BiomeId) -> i32 {
+        let biome_data = BiomeData::get(biome);
+        (base_height as f32 + biome_data.height_modifier * 20.0) as i32
+    }
+
+    /// Whether the density field is solid at a world position above the stone floor.
+    fn is_solid_terrain(
+        &self,
+        world_x: i32,
+        world_y: i32,
+        world_z: i32,
+        target_height: i32,
+    ) -> bool {
+        // Density calculation
+        // 1. Vertical Gradient: Positive below target_height, negative above.
+        // Scale factor controls slope steepness.
+        let vertical_gradient = (target_height - world_y) as f64 / 20.0;
+
+        // 2. 3D Noise: Adds variation/overhangs
+        let noise_val =
+            self.density_noise
+                .sample_3d(world_x as f64, world_y as f64, world_z as f64);
+
+        // 3. Cave Noise: Subtracts density (callers only query above the y=5 floor)
+        let cave_val = self
+            .cave_noise
+            .sample_3d(world_x as f64, world_y as f64, world_z as f64);
+        // Use absolute value for cave tunnels (worm-like)
+        let cave_modifier = if cave_val.abs() < 0.15 { -10.0 } else { 0.0 };
+
+        vertical_gradient + noise_val + cave_modifier > 0.0
+    }
+
     /// Populate chunk with trees based on biome.
     fn populate_trees(&self, chunk: &mut Chunk, chunk_origin_x: i32, chunk_origin_z: i32) {
         let chunk_pos = chunk.position();
@@ -965,6 +1040,36 @@ mod tests {
         assert_eq!(chunk.position(), ChunkPos::new(0, 0));
     }
 
+    #[test]
+    fn registered_structure_placers_run_after_base_terrain() {
+        let pos = ChunkPos::new(2, -1);
+        let count_marker = |chunk: &Chunk| {
+            let mut count = 0;
+            for y in 0..CHUNK_SIZE_Y {
+                for z in 0..CHUNK_SIZE_Z {
+                    for x in 0..CHUNK_SIZE_X {
+                        if chunk.voxel(x, y, z).id == crate::BLOCK_AMETHYST_BLOCK {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+            count
+        };
+
+        let mut gen = TerrainGenerator::new(12345);
+        let baseline = count_marker(&gen.generate_chunk(pos));
+        gen.register_structure_placer(crate::OreVeinPlacer {
+            ore: crate::BLOCK_AMETHYST_BLOCK,
+            host: blocks::STONE,
+            veins_per_chunk: 8,
+            vein_size: 8,
+            min_y: -40,
+            max_y: 0,
+        });
+        assert!(count_marker(&gen.generate_chunk(pos)) > baseline);
+    }
+
     #[test]
     fn overworld_generation_has_surface_above_void_floor() {
         let gen = TerrainGenerator::new(123);
@@ -1543,4 +1648,81 @@ mod tests {
         let top_voxel = chunk.voxel(8, CHUNK_SIZE_Y - 1, 8);
         assert_eq!(top_voxel.id, blocks::AIR);
     }
+
+    #[test]
+    fn surface_height_at_matches_generated_chunk() {
+        let gen = TerrainGenerator::new(12345);
+        let pos = ChunkPos::new(3, -2);
+        let chunk = gen.generate_chunk(pos);
+
+        let terrain_blocks = [
+            blocks::STONE,
+            blocks::DIRT,
+            blocks::GRASS,
+            blocks::SAND,
+            blocks::GRAVEL,
+            blocks::SNOW,
+        ];
+
+        let mut compared = 0;
+        for local_z in 0..CHUNK_SIZE_Z {
+            for local_x in 0..CHUNK_SIZE_X {
+                let Some(top) = (0..CHUNK_SIZE_Y).rev().find(|&y| {
+                    let id = chunk.voxel(local_x, y, local_z).id;
+                    id != blocks::AIR && id != blocks::WATER
+                }) else {
+                    continue;
+                };
+
+                // Skip columns topped by vegetation or structures.
+                if !terrain_blocks.contains(&chunk.voxel(local_x, top, local_z).id) {
+                    continue;
+                }
+
+                let world_x = pos.x * CHUNK_SIZE_X as i32 + local_x as i32;
+                let world_z = pos.z * CHUNK_SIZE_Z as i32 + local_z as i32;
+                assert_eq!(
+                    gen.surface_height_at(world_x, world_z),
+                    local_y_to_world_y(top),
+                    "surface mismatch at ({world_x}, {world_z})"
+                );
+                assert_eq!(
+                    gen.biome_at(world_x, world_z),
+                    gen.biome_assigner().get_biome(world_x, world_z)
+                );
+                compared += 1;
+            }
+        }
+
+        assert!(compared > 0, "expected at least one bare terrain column");
+    }
+
+    #[test]
+    fn terrain_height_4d_is_coherent_across_adjacent_w_slices() {
+        let gen = TerrainGenerator::new(2024);
+
+        let mut max_adjacent_diff: f64 = 0.0;
+        let mut max_distant_diff: f64 = 0.0;
+        for i in 0..64 {
+            let x = i as f64 * 37.0 - 900.0;
+            let z = i as f64 * -23.0 + 400.0;
+            let y = 64.0;
+
+            let here = gen.terrain_height_4d(x, y, z, 10.0);
+            let next = gen.terrain_height_4d(x, y, z, 11.0);
+            let far = gen.terrain_height_4d(x, y, z, 5_000.0);
+
+            max_adjacent_diff = max_adjacent_diff.max((here - next).abs());
+            max_distant_diff = max_distant_diff.max((here - far).abs());
+        }
+
+        assert!(
+            max_adjacent_diff < 4.0,
+            "adjacent W slices diverged by {max_adjacent_diff}"
+        );
+        assert!(
+            max_distant_diff > 4.0,
+            "distant W slices should be able to differ, max diff {max_distant_diff}"
+        );
+    }
 }
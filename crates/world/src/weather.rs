@@ -3,8 +3,23 @@
 //! Provides weather state management and event emission for replay/testing.
 //! Weather changes are deterministic and logged for CI reproducibility.
 
+use mdminecraft_core::SimTick;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
+/// Ticks for [`WeatherToggle::intensity`] to ramp fully in or out (5 seconds at 20 TPS).
+pub const WEATHER_RAMP_TICKS: u32 = 100;
+
+/// Delay range for a fresh schedule (45..120 seconds at 20 TPS).
+const RESCHEDULE_DELAY_TICKS: std::ops::Range<u64> = 900..2400;
+
+/// Delay range between automatic transitions (60..150 seconds at 20 TPS).
+const TRANSITION_DELAY_TICKS: std::ops::Range<u64> = 1200..3000;
+
+const RESCHEDULE_SALT: u64 = 0xC0FFEE;
+const TRANSITION_SALT: u64 = 0x0057_4541_5448_4552;
+
 /// Weather state affecting ambient lighting and gameplay.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum WeatherState {
@@ -17,11 +32,59 @@ pub enum WeatherState {
     Thunderstorm,
 }
 
+impl WeatherState {
+    /// Roll the state that follows this one at a scheduled transition.
+    fn next(self, rng: &mut StdRng) -> Self {
+        match self {
+            WeatherState::Clear => {
+                if rng.gen_bool(0.55) {
+                    if rng.gen_bool(0.12) {
+                        WeatherState::Thunderstorm
+                    } else {
+                        WeatherState::Precipitation
+                    }
+                } else {
+                    WeatherState::Clear
+                }
+            }
+            WeatherState::Precipitation => {
+                let roll: f32 = rng.gen();
+                if roll < 0.70 {
+                    WeatherState::Clear
+                } else if roll < 0.82 {
+                    WeatherState::Thunderstorm
+                } else {
+                    WeatherState::Precipitation
+                }
+            }
+            WeatherState::Thunderstorm => {
+                let roll: f32 = rng.gen();
+                if roll < 0.58 {
+                    WeatherState::Precipitation
+                } else if roll < 0.76 {
+                    WeatherState::Thunderstorm
+                } else {
+                    WeatherState::Clear
+                }
+            }
+        }
+    }
+}
+
 /// Component attached to world singleton for weather management.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Only `state` is serialized; `WorldState` persists the schedule separately as
+/// `weather_next_change_tick`, and intensity ramps back in after a load.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct WeatherToggle {
     /// Current weather state.
     pub state: WeatherState,
+    /// Tick at which the next transition is rolled.
+    #[serde(skip)]
+    next_change: SimTick,
+    /// Precipitation intensity, ramping towards the current state's target.
+    #[serde(skip)]
+    intensity: f32,
 }
 
 impl WeatherToggle {
@@ -29,9 +92,63 @@ impl WeatherToggle {
     pub fn new() -> Self {
         Self {
             state: WeatherState::Clear,
+            next_change: SimTick::ZERO,
+            intensity: 0.0,
         }
     }
 
+    /// Tick at which the next transition is rolled.
+    pub fn next_change_tick(&self) -> SimTick {
+        self.next_change
+    }
+
+    /// Restore a saved transition schedule.
+    pub fn set_next_change_tick(&mut self, tick: SimTick) {
+        self.next_change = tick;
+    }
+
+    /// Schedule the next transition a seed-derived delay after `tick`.
+    ///
+    /// Used for a fresh world and after the weather is set by hand.
+    pub fn reschedule(&mut self, tick: SimTick, seed: u64) {
+        let mut rng = transition_rng(seed, tick, RESCHEDULE_SALT);
+        self.next_change = tick.advance(rng.gen_range(RESCHEDULE_DELAY_TICKS));
+    }
+
+    /// Advance the weather by one simulation tick.
+    ///
+    /// Once `tick` reaches the scheduled change, the next state is rolled from
+    /// `seed` and `tick` alone, so a seed always produces the same timeline.
+    /// Returns the transition if the state changed.
+    pub fn tick(&mut self, tick: SimTick, seed: u64) -> Option<WeatherChanged> {
+        let mut change = None;
+        if tick >= self.next_change {
+            let mut rng = transition_rng(seed, tick, TRANSITION_SALT);
+            self.next_change = tick.advance(rng.gen_range(TRANSITION_DELAY_TICKS));
+            let next = self.state.next(&mut rng);
+            if next != self.state {
+                change = Some(WeatherChanged::new(self.state, next));
+                self.state = next;
+            }
+        }
+
+        let target = if self.is_precipitating() { 1.0 } else { 0.0 };
+        let step = 1.0 / WEATHER_RAMP_TICKS as f32;
+        self.intensity = if self.intensity < target {
+            (self.intensity + step).min(target)
+        } else {
+            (self.intensity - step).max(target)
+        };
+        change
+    }
+
+    /// Precipitation intensity in `0.0..=1.0`, for spawn rates and sky blending.
+    ///
+    /// Ramps over [`WEATHER_RAMP_TICKS`] ticks instead of snapping on a change.
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
     /// Set the weather state (emits WeatherChanged event in ECS context).
     pub fn set_state(&mut self, state: WeatherState) {
         self.state = state;
@@ -90,6 +207,10 @@ impl WeatherChanged {
     }
 }
 
+fn transition_rng(seed: u64, tick: SimTick, salt: u64) -> StdRng {
+    StdRng::seed_from_u64(seed ^ tick.0.wrapping_mul(0xD6E8_FEB8_6659_FD93) ^ salt)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +256,68 @@ mod tests {
         assert_eq!(event.from, WeatherState::Clear);
         assert_eq!(event.to, WeatherState::Precipitation);
     }
+
+    fn timeline(seed: u64, ticks: u64) -> Vec<(u64, WeatherChanged)> {
+        let mut weather = WeatherToggle::new();
+        weather.reschedule(SimTick::ZERO, seed);
+        (0..ticks)
+            .filter_map(|tick| Some((tick, weather.tick(SimTick(tick), seed)?)))
+            .collect()
+    }
+
+    #[test]
+    fn fixed_seed_reproduces_weather_timeline() {
+        let a = timeline(42, 100_000);
+        assert!(a.len() > 5, "expected several transitions, got {}", a.len());
+        assert_eq!(a, timeline(42, 100_000));
+        assert_ne!(a, timeline(43, 100_000));
+
+        // Every transition starts from the state the previous one ended in.
+        let mut state = WeatherState::Clear;
+        for (_, change) in &a {
+            assert_eq!(change.from, state);
+            assert_ne!(change.from, change.to);
+            state = change.to;
+        }
+    }
+
+    #[test]
+    fn first_transition_waits_for_schedule() {
+        let mut weather = WeatherToggle::new();
+        weather.reschedule(SimTick(500), 7);
+        let due = weather.next_change_tick();
+        assert!(RESCHEDULE_DELAY_TICKS.contains(&(due.0 - 500)));
+
+        for tick in 500..due.0 {
+            assert_eq!(weather.tick(SimTick(tick), 7), None);
+        }
+        weather.tick(due, 7);
+        assert!(weather.next_change_tick() > due);
+    }
+
+    #[test]
+    fn intensity_ramps_rather_than_snapping() {
+        let mut weather = WeatherToggle::new();
+        weather.set_next_change_tick(SimTick(u64::MAX));
+        weather.set_state(WeatherState::Precipitation);
+        assert_eq!(weather.intensity(), 0.0);
+
+        let mut previous = weather.intensity();
+        for tick in 0..WEATHER_RAMP_TICKS as u64 {
+            weather.tick(SimTick(tick), 1);
+            let intensity = weather.intensity();
+            assert!(intensity > previous && intensity - previous < 0.02);
+            previous = intensity;
+        }
+        assert!((weather.intensity() - 1.0).abs() < 1e-4);
+
+        weather.set_state(WeatherState::Clear);
+        weather.tick(SimTick(1_000), 1);
+        let fading = weather.intensity();
+        assert!(fading > 0.9 && fading < 1.0);
+        for tick in 0..WEATHER_RAMP_TICKS as u64 {
+            weather.tick(SimTick(1_001 + tick), 1);
+        }
+        assert_eq!(weather.intensity(), 0.0);
+    }
 }
@@ -0,0 +1,145 @@
+//! World-coordinate block access over a set of loaded chunks.
+
+use std::collections::HashMap;
+
+use crate::{
+    world_y_to_local_y, BlockPropertiesRegistry, Chunk, ChunkPos, ChunkStorage, Voxel,
+    CHUNK_SIZE_X, CHUNK_SIZE_Z,
+};
+
+/// Split a world block position into its chunk and in-chunk coordinates.
+///
+/// Returns `None` when `y` lies outside `WORLD_MIN_Y..=WORLD_MAX_Y`.
+pub fn chunk_local_coords(x: i32, y: i32, z: i32) -> Option<(ChunkPos, usize, usize, usize)> {
+    let local_y = world_y_to_local_y(y)?;
+    let chunk_pos = ChunkPos::new(
+        x.div_euclid(CHUNK_SIZE_X as i32),
+        z.div_euclid(CHUNK_SIZE_Z as i32),
+    );
+    let local_x = x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
+    let local_z = z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
+    Some((chunk_pos, local_x, local_y, local_z))
+}
+
+/// Block queries in world coordinates, independent of how chunks are stored.
+pub trait WorldView {
+    /// Voxel at a world position, or `None` if the position is outside the
+    /// world's height range or its chunk is not loaded.
+    fn voxel_at(&self, x: i32, y: i32, z: i32) -> Option<Voxel>;
+
+    /// Overwrite the voxel at a world position.
+    ///
+    /// Returns `false` (and changes nothing) if the chunk is not loaded or
+    /// `y` is out of range.
+    fn set_voxel_at(&mut self, x: i32, y: i32, z: i32, voxel: Voxel) -> bool;
+
+    /// Whether the block at a world position is solid. Unloaded and
+    /// out-of-world positions count as not solid.
+    fn is_solid_at(&self, x: i32, y: i32, z: i32, registry: &BlockPropertiesRegistry) -> bool {
+        self.voxel_at(x, y, z)
+            .is_some_and(|voxel| registry.get(voxel.id).is_solid)
+    }
+}
+
+impl WorldView for HashMap<ChunkPos, Chunk> {
+    fn voxel_at(&self, x: i32, y: i32, z: i32) -> Option<Voxel> {
+        let (chunk_pos, local_x, local_y, local_z) = chunk_local_coords(x, y, z)?;
+        Some(self.get(&chunk_pos)?.voxel(local_x, local_y, local_z))
+    }
+
+    fn set_voxel_at(&mut self, x: i32, y: i32, z: i32, voxel: Voxel) -> bool {
+        let Some((chunk_pos, local_x, local_y, local_z)) = chunk_local_coords(x, y, z) else {
+            return false;
+        };
+        let Some(chunk) = self.get_mut(&chunk_pos) else {
+            return false;
+        };
+        chunk.set_voxel(local_x, local_y, local_z, voxel);
+        true
+    }
+}
+
+impl WorldView for ChunkStorage {
+    fn voxel_at(&self, x: i32, y: i32, z: i32) -> Option<Voxel> {
+        let (chunk_pos, local_x, local_y, local_z) = chunk_local_coords(x, y, z)?;
+        Some(self.get(chunk_pos)?.voxel(local_x, local_y, local_z))
+    }
+
+    fn set_voxel_at(&mut self, x: i32, y: i32, z: i32, voxel: Voxel) -> bool {
+        let Some((chunk_pos, local_x, local_y, local_z)) = chunk_local_coords(x, y, z) else {
+            return false;
+        };
+        let Some(chunk) = self.get_mut(chunk_pos) else {
+            return false;
+        };
+        chunk.set_voxel(local_x, local_y, local_z, voxel);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BLOCK_AIR, BLOCK_DIRT, BLOCK_STONE, BLOCK_WATER, WORLD_MAX_Y, WORLD_MIN_Y};
+
+    fn block(id: u16) -> Voxel {
+        Voxel {
+            id,
+            ..Default::default()
+        }
+    }
+
+    fn two_chunks() -> HashMap<ChunkPos, Chunk> {
+        let mut chunks = HashMap::new();
+        for pos in [ChunkPos::new(-1, 0), ChunkPos::new(0, 0)] {
+            chunks.insert(pos, Chunk::new(pos));
+        }
+        chunks
+    }
+
+    #[test]
+    fn reads_and_writes_cross_chunk_boundaries() {
+        let mut chunks = two_chunks();
+        assert!(chunks.set_voxel_at(-1, 10, 5, block(BLOCK_STONE)));
+        assert!(chunks.set_voxel_at(0, 10, 5, block(BLOCK_DIRT)));
+
+        // x = -1 is the last column of chunk -1; x = 0 the first of chunk 0.
+        let west = &chunks[&ChunkPos::new(-1, 0)];
+        let local_y = world_y_to_local_y(10).unwrap();
+        assert_eq!(west.voxel(CHUNK_SIZE_X - 1, local_y, 5).id, BLOCK_STONE);
+        let east = &chunks[&ChunkPos::new(0, 0)];
+        assert_eq!(east.voxel(0, local_y, 5).id, BLOCK_DIRT);
+
+        assert_eq!(chunks.voxel_at(-1, 10, 5).map(|v| v.id), Some(BLOCK_STONE));
+        assert_eq!(chunks.voxel_at(0, 10, 5).map(|v| v.id), Some(BLOCK_DIRT));
+        assert_eq!(chunks.voxel_at(1, 10, 5).map(|v| v.id), Some(BLOCK_AIR));
+
+        // Chunk 1 is not loaded.
+        assert_eq!(chunks.voxel_at(16, 10, 5), None);
+        assert!(!chunks.set_voxel_at(16, 10, 5, block(BLOCK_STONE)));
+    }
+
+    #[test]
+    fn positions_outside_world_height_are_none() {
+        let mut chunks = two_chunks();
+        assert_eq!(chunks.voxel_at(0, WORLD_MIN_Y - 1, 0), None);
+        assert_eq!(chunks.voxel_at(0, WORLD_MAX_Y + 1, 0), None);
+        assert!(chunks.voxel_at(0, WORLD_MIN_Y, 0).is_some());
+        assert!(!chunks.set_voxel_at(0, WORLD_MIN_Y - 1, 0, block(BLOCK_STONE)));
+    }
+
+    #[test]
+    fn is_solid_at_uses_block_properties() {
+        let registry = BlockPropertiesRegistry::new();
+        let mut storage = ChunkStorage::new(4);
+        storage.ensure_chunk(ChunkPos::new(0, -1));
+        assert!(storage.set_voxel_at(3, 0, -1, block(BLOCK_STONE)));
+        assert!(storage.set_voxel_at(4, 0, -1, block(BLOCK_WATER)));
+
+        assert!(storage.is_solid_at(3, 0, -1, &registry));
+        assert!(!storage.is_solid_at(4, 0, -1, &registry));
+        assert!(!storage.is_solid_at(5, 0, -1, &registry));
+        assert!(!storage.is_solid_at(3, 0, 0, &registry));
+        assert!(!storage.is_solid_at(3, WORLD_MIN_Y - 1, -1, &registry));
+    }
+}
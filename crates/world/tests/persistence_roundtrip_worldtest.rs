@@ -104,7 +104,7 @@ fn persistence_roundtrip_worldtest() {
     let mut save_times = Vec::new();
     let mut bytes_written: u64 = 0;
 
-    for chunk in &chunks {
+    for chunk in &mut chunks {
         let save_start = Instant::now();
         store.save_chunk(chunk).expect("Failed to save chunk");
         let save_time = save_start.elapsed().as_micros();
@@ -101,7 +101,7 @@ mod fuzz_chunk_deserializer {
             }
 
             // Save chunk
-            store.save_chunk(&chunk).expect("Save should succeed");
+            store.save_chunk(&mut chunk).expect("Save should succeed");
 
             // Load chunk
             let loaded = store.load_chunk(pos).expect("Load should succeed");
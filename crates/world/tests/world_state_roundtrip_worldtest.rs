@@ -20,7 +20,7 @@ use mdminecraft_world::{
     BlockEntitiesState, BlockEntityKey, BrewingStandState, EnchantingTableState, FurnaceState,
     ItemManager, Mob, MobType, Projectile, ProjectileManager, RegionStore, SimTime, StatusEffect,
     StatusEffectType, StatusEffects, WeatherToggle, WorldEntitiesState, WorldMeta, WorldPoint,
-    WorldState,
+    WorldState, CHUNK_FORMAT_VERSION,
 };
 use std::env;
 use std::time::Instant;
@@ -71,6 +71,7 @@ fn make_initial_state() -> WorldState {
     status_effects.add(StatusEffect::new(StatusEffectType::Speed, 1, 200));
 
     let player = mdminecraft_world::PlayerSave {
+        version: mdminecraft_world::PLAYER_SAVE_VERSION,
         transform: mdminecraft_world::PlayerTransform {
             dimension: DimensionId::Overworld,
             x: 10.5,
@@ -211,6 +212,7 @@ fn world_state_roundtrip_worldtest() {
         .save_world_meta(&WorldMeta {
             world_seed: WORLD_SEED,
             end_boss_defeated: false,
+            format_version: CHUNK_FORMAT_VERSION,
         })
         .expect("failed to save world meta");
 
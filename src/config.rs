@@ -1,6 +1,7 @@
 use anyhow::Result;
-use mdminecraft_assets::{BlockDescriptor, BlockRegistry};
+use mdminecraft_assets::{BlockDescriptor, BlockRegistry, TextureAtlasMetadata};
 use mdminecraft_core::RegistryKey;
+use mdminecraft_render::{CrosshairConfig, CrosshairStyle};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeSet, HashMap},
@@ -34,9 +35,35 @@ pub struct ControlsConfig {
     pub ambient_volume: f32,
     /// Whether audio is muted.
     pub audio_muted: bool,
+    /// Crosshair appearance.
+    pub crosshair: CrosshairSettings,
     pub bindings: BindingOverrides,
 }
 
+/// Crosshair appearance as stored in the controls file.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CrosshairSettings {
+    /// Overall width and height in points.
+    pub size: f32,
+    /// Line thickness (and dot diameter) in points.
+    pub thickness: f32,
+    /// Empty space left around the center in points.
+    pub gap: f32,
+    /// RGBA color.
+    pub color: [u8; 4],
+    pub style: CrosshairShape,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CrosshairShape {
+    #[default]
+    Cross,
+    Dot,
+    CircleDot,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct BindingOverrides {
@@ -59,11 +86,43 @@ impl Default for ControlsConfig {
             sfx_volume: 1.0,
             ambient_volume: 0.7,
             audio_muted: false,
+            crosshair: CrosshairSettings::default(),
             bindings: BindingOverrides::default(),
         }
     }
 }
 
+impl Default for CrosshairSettings {
+    fn default() -> Self {
+        let config = CrosshairConfig::default();
+        Self {
+            size: config.size,
+            thickness: config.thickness,
+            gap: config.gap,
+            color: config.color.to_array(),
+            style: CrosshairShape::default(),
+        }
+    }
+}
+
+impl CrosshairSettings {
+    /// Crosshair config for the UI, with sizes clamped to sane ranges.
+    pub fn to_config(self) -> CrosshairConfig {
+        let [r, g, b, a] = self.color;
+        CrosshairConfig {
+            size: self.size.clamp(1.0, 64.0),
+            thickness: self.thickness.clamp(0.5, 16.0),
+            gap: self.gap.clamp(0.0, 32.0),
+            color: egui::Color32::from_rgba_unmultiplied(r, g, b, a),
+            style: match self.style {
+                CrosshairShape::Cross => CrosshairStyle::Cross,
+                CrosshairShape::Dot => CrosshairStyle::Dot,
+                CrosshairShape::CircleDot => CrosshairStyle::CircleDot,
+            },
+        }
+    }
+}
+
 impl ControlsConfig {
     /// Load controls configuration from the default path.
     pub fn load() -> Self {
@@ -120,6 +179,22 @@ pub fn load_block_registry() -> BlockRegistry {
     )
 }
 
+/// Warn when the block pack names textures the loaded atlas doesn't provide.
+///
+/// Faces with a missing texture still mesh with placeholder UVs, so a mismatch
+/// is logged rather than failing the load.
+pub fn check_block_registry_textures(
+    registry: &BlockRegistry,
+    atlas: Option<&TextureAtlasMetadata>,
+) {
+    let Some(atlas) = atlas else {
+        return;
+    };
+    if let Err(err) = registry.validate(atlas) {
+        warn!("Block pack doesn't match the texture atlas: {err}");
+    }
+}
+
 fn default_block_registry() -> BlockRegistry {
     BlockRegistry::new(vec![
         BlockDescriptor::simple("air", false),
@@ -267,6 +342,17 @@ mod tests {
         assert_eq!(desc.texture_for(BlockFace::Up), "blocks/stone");
     }
 
+    #[test]
+    fn shipped_blocks_resolve_against_shipped_atlas() {
+        let registry = load_block_registry_strict().expect("block registry should load");
+        let atlas = TextureAtlasMetadata::load_file("assets/atlas/atlas.json")
+            .expect("atlas metadata should load");
+        atlas.validate().expect("atlas metadata should be valid");
+        registry
+            .validate(&atlas)
+            .expect("every block texture should be in the atlas");
+    }
+
     #[test]
     fn disabled_packs_are_ignored_when_loading_block_registry() {
         let timestamp = SystemTime::now()
@@ -309,4 +395,29 @@ mod tests {
 
         let _ = fs::remove_dir_all(&packs_root);
     }
+
+    #[test]
+    fn crosshair_settings_load_from_controls_file() {
+        let cfg: ControlsConfig = toml::from_str(
+            r#"
+[crosshair]
+size = 14.0
+gap = 3.0
+color = [255, 0, 0, 255]
+style = "circle_dot"
+"#,
+        )
+        .expect("controls parse");
+        let crosshair = cfg.crosshair.to_config();
+        assert_eq!(crosshair.size, 14.0);
+        assert_eq!(crosshair.thickness, CrosshairConfig::default().thickness);
+        assert_eq!(crosshair.gap, 3.0);
+        assert_eq!(crosshair.color, egui::Color32::RED);
+        assert_eq!(crosshair.style, CrosshairStyle::CircleDot);
+
+        assert_eq!(
+            ControlsConfig::default().crosshair.to_config(),
+            CrosshairConfig::default()
+        );
+    }
 }
@@ -5,12 +5,12 @@ use crate::{
     automation::protocol,
     command_script::CommandScriptPlayer,
     commands,
-    config::{load_block_registry, ControlsConfig},
+    config::{check_block_registry_textures, load_block_registry, ControlsConfig},
     content_pack_loot, content_pack_spawns, content_packs,
     input::{ActionState, InputProcessor},
     scripted_input::ScriptedInputPlayer,
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use glam::IVec3;
 use mdminecraft_assets::BlockRegistry;
 use mdminecraft_audio::{AudioManager, AudioSettings, SoundId};
@@ -20,35 +20,39 @@ use mdminecraft_core::{
     ToolMaterial, ToolType,
 };
 use mdminecraft_render::{
-    mesh_chunk_with_voxel_at, raycast, ChunkManager, ControlMode, DebugHud, Frustum, InputContext,
-    InputState, ParticleEmitter, ParticleSystem, ParticleVertex, RaycastHit, Renderer,
-    RendererConfig, TimeOfDay, UiRenderContext, WindowConfig, WindowManager,
+    mesh_chunk_with_voxel_at, raycast, render_crosshair, ChunkManager, ControlMode, DebugHud,
+    Frustum, InputContext, InputState, MeshOptions, ParticleEmitter, ParticleSystem,
+    ParticleVertex, RaycastHit, RenderStats, Renderer, RendererConfig, TimeOfDay, UiRenderContext,
+    WindowConfig, WindowManager,
 };
 #[cfg(feature = "ui3d_billboards")]
+use mdminecraft_ui3d::components::UIComponent;
+#[cfg(feature = "ui3d_billboards")]
 use mdminecraft_ui3d::render::{
-    BillboardEmitter, BillboardFlags, BillboardInstance, BillboardRenderer,
+    BillboardEmitter, BillboardFlags, BillboardInstance, BillboardRenderer, BillboardView,
+    FontAtlas, FontAtlasBuilder,
 };
+#[cfg(feature = "ui3d_billboards")]
+use mdminecraft_ui3d::{EntityLabel, Text3D, TextRenderer};
 use mdminecraft_world::{
-    get_fluid_type, interactive_blocks,
-    lighting::{
-        init_skylight, recompute_skylight_local as recompute_skylight_local_world,
-        stitch_light_seams, LightType,
-    },
+    chunk_local_coords, get_fluid_type, interactive_blocks,
+    lighting::{recompute_skylight_local as recompute_skylight_local_world, LightPropagator},
     local_y_to_world_y, world_y_to_local_y, ArmorPiece, ArmorSlot, BiomeId, BlockEntitiesState,
     BlockEntityKey, BlockId, BlockPropertiesRegistry, BlockState, BrewingStandState, ChestState,
-    Chunk, ChunkPos, CropGrowthSystem, CropPosition, DamageKind, DispenserState,
-    EnchantingTableState, FluidPos, FluidSimulator, FluidType, FurnaceState, HopperState,
-    InteractionManager, Inventory, ItemManager, ItemType as DroppedItemType, Mob, MobSpawner,
-    MobType, PlayerArmor, PlayerSave, PlayerTransform, PotionType, Projectile, ProjectileManager,
-    RedstonePos, RedstoneSimulator, RegionStore, SimTime, StatusEffectType, StatusEffects,
-    SugarCaneGrowthSystem, SugarCanePosition, TerrainGenerator, Voxel, WeatherState, WeatherToggle,
-    WorldEntitiesState, WorldMeta, WorldPoint, WorldState, BLOCK_AIR, BLOCK_BOOKSHELF,
-    BLOCK_BREWING_STAND, BLOCK_BROWN_MUSHROOM, BLOCK_COBBLESTONE, BLOCK_CRAFTING_TABLE,
-    BLOCK_CRYING_OBSIDIAN, BLOCK_ENCHANTING_TABLE, BLOCK_END_PORTAL, BLOCK_END_PORTAL_FRAME,
-    BLOCK_FURNACE, BLOCK_FURNACE_LIT, BLOCK_GLOWSTONE, BLOCK_ICE, BLOCK_NETHER_PORTAL,
-    BLOCK_OAK_LOG, BLOCK_OAK_PLANKS, BLOCK_OBSIDIAN, BLOCK_RESPAWN_ANCHOR, BLOCK_SNOW,
-    BLOCK_SUGAR_CANE, BLOCK_WATER, CHUNK_SIZE_X, CHUNK_SIZE_Y, CHUNK_SIZE_Z, WORLD_MAX_Y,
-    WORLD_MIN_Y,
+    Chunk, ChunkPos, CollisionBox, CropGrowthSystem, CropPosition, DamageKind, DispenserState,
+    EditDenied, EditKind, EditRules, EnchantingTableState, FluidPos, FluidSimulator, FluidType,
+    FurnaceState, HopperState, InteractionManager, Inventory, ItemManager,
+    ItemType as DroppedItemType, Mob, MobSpawner, MobType, MoveIntent, PlayerArmor,
+    PlayerController, PlayerSave, PlayerTransform, PotionType, Projectile, ProjectileManager,
+    RedstoneEvent, RedstonePos, RedstoneSimulator, RegionStore, SimTime, SmeltingRegistry,
+    StatusEffectType, StatusEffects, SugarCaneGrowthSystem, SugarCanePosition, TerrainGenerator,
+    Voxel, WeatherState, WeatherToggle, WorldEntitiesState, WorldMeta, WorldPoint, WorldState,
+    WorldView, BLOCK_AIR, BLOCK_BOOKSHELF, BLOCK_BREWING_STAND, BLOCK_BROWN_MUSHROOM,
+    BLOCK_COBBLESTONE, BLOCK_CRAFTING_TABLE, BLOCK_CRYING_OBSIDIAN, BLOCK_ENCHANTING_TABLE,
+    BLOCK_END_PORTAL, BLOCK_END_PORTAL_FRAME, BLOCK_FURNACE, BLOCK_FURNACE_LIT, BLOCK_GLOWSTONE,
+    BLOCK_ICE, BLOCK_NETHER_PORTAL, BLOCK_OAK_LOG, BLOCK_OAK_PLANKS, BLOCK_OBSIDIAN,
+    BLOCK_RESPAWN_ANCHOR, BLOCK_SNOW, BLOCK_SUGAR_CANE, BLOCK_WATER, CHUNK_FORMAT_VERSION,
+    CHUNK_SIZE_X, CHUNK_SIZE_Y, CHUNK_SIZE_Z, WORLD_MAX_Y, WORLD_MIN_Y,
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::Deserialize;
@@ -61,8 +65,10 @@ use std::{
     sync::{Arc, OnceLock},
 };
 
+const CHUNK_UNLOAD_MARGIN: i32 = 2; // chunks kept loaded past the render distance
 const MAX_PARTICLES: usize = 8_192;
 const MAX_ARROW_PROJECTILES_IN_FLIGHT: usize = 32;
+const FLUID_UPDATE_BUDGET: usize = 4_096; // fluid cells processed per tick
 const PRECIPITATION_SPAWN_RATE: f32 = 480.0;
 const PRECIPITATION_RADIUS: f32 = 18.0;
 const PRECIPITATION_CEILING_OFFSET: f32 = 12.0;
@@ -105,6 +111,8 @@ const NETHER_PORTAL_CHARGE_TICKS: u16 = 80;
 const NETHER_PORTAL_COOLDOWN_TICKS: u16 = 100;
 const NETHER_PORTAL_SEARCH_RADIUS: i32 = 16;
 const WORLDGEN_CHEST_LOOT_SALT: u64 = 0x0043_4845_5354_4C4F_u64; // "CHESTLO"
+/// How long a denied-edit reason stays on screen.
+const EDIT_DENIED_MESSAGE_DURATION: std::time::Duration = std::time::Duration::from_secs(2);
 
 struct UnsupportedBlockChanges {
     removed: Vec<(IVec3, BlockId)>,
@@ -383,9 +391,12 @@ impl Hotbar {
         }
     }
 
-    /// Consume one of the selected item (for eating food)
-    /// Returns true if item was consumed
-    fn consume_selected(&mut self) -> bool {
+    /// Use up one of the selected item (placing, eating, throwing, ...).
+    /// Creative mode keeps the stack. Returns true if an item was removed.
+    fn consume_selected(&mut self, gamemode: commands::Gamemode) -> bool {
+        if gamemode == commands::Gamemode::Creative {
+            return false;
+        }
         if let Some(item) = self.slots[self.selected].as_mut() {
             if item.count > 1 {
                 item.count -= 1;
@@ -407,20 +418,7 @@ impl Hotbar {
 
         // Merge into existing stacks first.
         for existing in self.slots.iter_mut().flatten() {
-            if !stacks_match_for_merge(existing, &stack) {
-                continue;
-            }
-
-            let max = existing.max_stack_size();
-            if existing.count >= max {
-                continue;
-            }
-
-            let space = max - existing.count;
-            let to_add = space.min(stack.count);
-            existing.count += to_add;
-            stack.count -= to_add;
-
+            existing.try_merge(&mut stack);
             if stack.count == 0 {
                 return None;
             }
@@ -441,10 +439,7 @@ impl Hotbar {
                 return None;
             }
 
-            let mut placed = stack.clone();
-            placed.count = max;
-            *slot = Some(placed);
-            stack.count -= max;
+            *slot = stack.split(max);
         }
 
         Some(stack)
@@ -587,20 +582,7 @@ impl MainInventory {
 
         // Merge into existing stacks first.
         for existing in self.slots.iter_mut().flatten() {
-            if !stacks_match_for_merge(existing, &stack) {
-                continue;
-            }
-
-            let max = existing.max_stack_size();
-            if existing.count >= max {
-                continue;
-            }
-
-            let space = max - existing.count;
-            let to_add = space.min(stack.count);
-            existing.count += to_add;
-            stack.count -= to_add;
-
+            existing.try_merge(&mut stack);
             if stack.count == 0 {
                 return None;
             }
@@ -621,10 +603,7 @@ impl MainInventory {
                 return None;
             }
 
-            let mut placed = stack.clone();
-            placed.count = max;
-            *slot = Some(placed);
-            stack.count -= max;
+            *slot = stack.split(max);
         }
 
         Some(stack)
@@ -741,12 +720,22 @@ impl AABB {
             && self.min.z < other.max.z
             && self.max.z > other.min.z
     }
+}
+
+impl From<CollisionBox> for AABB {
+    fn from(collision_box: CollisionBox) -> Self {
+        Self {
+            min: collision_box.min,
+            max: collision_box.max,
+        }
+    }
+}
 
-    /// Offset the AABB by a vector
-    fn offset(&self, offset: glam::Vec3) -> Self {
+impl From<AABB> for CollisionBox {
+    fn from(aabb: AABB) -> Self {
         Self {
-            min: self.min + offset,
-            max: self.max + offset,
+            min: aabb.min,
+            max: aabb.max,
         }
     }
 }
@@ -765,12 +754,6 @@ impl<const N: usize> AabbSet<N> {
         }
     }
 
-    fn single(aabb: AABB) -> Self {
-        let mut set = Self::empty();
-        set.push(aabb);
-        set
-    }
-
     fn push(&mut self, aabb: AABB) {
         debug_assert!(self.len < N);
         if self.len >= N {
@@ -787,17 +770,9 @@ impl<const N: usize> AabbSet<N> {
 
 /// Player physics state
 struct PlayerPhysics {
-    velocity: glam::Vec3,
-    on_ground: bool,
-    gravity: f32,
-    jump_strength: f32,
-    terminal_velocity: f32,
-    player_height: f32,
-    eye_height: f32,
-    player_width: f32,
+    /// Movement, collision and fall tracking
+    controller: PlayerController,
     physics_enabled: bool,
-    /// Previous Y position for fall damage calculation
-    last_ground_y: f32,
     /// Time since last jump press (for double-jump flight toggle)
     last_jump_press_time: f32,
 }
@@ -812,6 +787,42 @@ struct MiningProgress {
     time_required: f32,
 }
 
+/// Fluid simulator capped at [`FLUID_UPDATE_BUDGET`] cells per tick.
+fn budgeted_fluid_simulator() -> FluidSimulator {
+    let mut sim = FluidSimulator::new();
+    sim.set_update_budget(FLUID_UPDATE_BUDGET);
+    sim
+}
+
+/// Font for mob nameplates: `MDM_NAMEPLATE_FONT` if set, else a common system font.
+///
+/// Returns `None` when no font can be loaded; nameplates then show only their
+/// health bars.
+#[cfg(feature = "ui3d_billboards")]
+fn load_nameplate_font() -> Option<FontAtlas> {
+    let candidates = std::env::var("MDM_NAMEPLATE_FONT").ok().into_iter().chain(
+        [
+            "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+            "/usr/share/fonts/TTF/DejaVuSans.ttf",
+            "/usr/share/fonts/truetype/liberation/LiberationSans-Regular.ttf",
+            "/Library/Fonts/Arial.ttf",
+            "C:\\Windows\\Fonts\\arial.ttf",
+        ]
+        .map(String::from),
+    );
+    for path in candidates {
+        let Ok(bytes) = std::fs::read(&path) else {
+            continue;
+        };
+        match FontAtlasBuilder::from_ttf(bytes, 48.0, ' '..='~').build() {
+            Ok(atlas) => return Some(atlas),
+            Err(err) => tracing::warn!(%path, ?err, "Failed to build nameplate font"),
+        }
+    }
+    tracing::warn!("No nameplate font found; mob names will not be drawn");
+    None
+}
+
 /// Helper: given time slices, return how many frames are needed to finish mining.
 #[cfg(test)]
 fn frames_to_complete(time_required: f32, dt_slices: &[f32]) -> usize {
@@ -1664,22 +1675,10 @@ fn calculate_attack_damage(tool: Option<(ToolType, ToolMaterial)>) -> f32 {
 }
 
 impl PlayerPhysics {
-    const GROUND_EPS: f32 = 0.001;
-    /// Vanilla-ish step height. Uses a power-of-two fraction for determinism.
-    const STEP_HEIGHT: f32 = 19.0 / 32.0;
-
     fn new() -> Self {
         Self {
-            velocity: glam::Vec3::ZERO,
-            on_ground: false,
-            gravity: -20.0,
-            jump_strength: 8.0,
-            terminal_velocity: -50.0,
-            player_height: 1.8,
-            eye_height: 1.62,
-            player_width: 0.6,
+            controller: PlayerController::new(),
             physics_enabled: true,
-            last_ground_y: 100.0, // Initial spawn height
             last_jump_press_time: 10.0,
         }
     }
@@ -1687,17 +1686,14 @@ impl PlayerPhysics {
     fn toggle_physics(&mut self) {
         self.physics_enabled = !self.physics_enabled;
         if !self.physics_enabled {
-            self.velocity = glam::Vec3::ZERO;
-            self.on_ground = false;
+            self.controller.velocity = glam::Vec3::ZERO;
+            self.controller.on_ground = false;
         }
     }
 
     /// Build an AABB using the camera position (eye). Feet are offset down by `eye_height`.
     fn get_aabb(&self, camera_pos: glam::Vec3) -> AABB {
-        let feet = camera_pos - glam::Vec3::new(0.0, self.eye_height, 0.0);
-        let size = glam::Vec3::new(self.player_width, self.player_height, self.player_width);
-        let center = feet + glam::Vec3::new(0.0, self.player_height * 0.5, 0.0);
-        AABB::from_center_size(center, size)
+        self.controller.aabb(camera_pos).into()
     }
 }
 
@@ -1750,6 +1746,10 @@ pub struct GameWorldOptions {
     pub screenshot: Option<ScreenshotConfig>,
     pub record: Option<RecordConfig>,
     pub commentary: Option<crate::commentary::CommentaryConfig>,
+    /// Spawn protection and build-height limits for player edits.
+    pub edit_rules: EditRules,
+    /// Whether the local player bypasses spawn protection.
+    pub player_is_op: bool,
 }
 
 impl Default for GameWorldOptions {
@@ -1761,6 +1761,8 @@ impl Default for GameWorldOptions {
             screenshot: None,
             record: None,
             commentary: None,
+            edit_rules: EditRules::default(),
+            player_is_op: false,
         }
     }
 }
@@ -2007,10 +2009,18 @@ pub struct GameWorld {
     hotbar: Hotbar,
     player_physics: PlayerPhysics,
     player_health: PlayerHealth,
-    chunks_visible: usize,
+    chunk_stats: RenderStats,
+    /// Survival applies mining time and consumes placed blocks; creative does neither.
+    gamemode: commands::Gamemode,
     mining_progress: Option<MiningProgress>,
     spawn_point: glam::Vec3,
     spawn_point_dimension: DimensionId,
+    /// Spawn protection and build-height limits for player edits.
+    edit_rules: EditRules,
+    /// Whether the local player bypasses spawn protection.
+    player_is_op: bool,
+    /// Most recent reason a place/break was rejected, with when it happened (for HUD feedback).
+    last_edit_denied: Option<(EditDenied, Instant)>,
     active_dimension: DimensionId,
     portal_charge_ticks: u16,
     portal_cooldown_ticks: u16,
@@ -2030,10 +2040,8 @@ pub struct GameWorld {
     particle_emitter: ParticleEmitter,
     particles: Vec<ParticleInstance>,
     weather: WeatherToggle,
-    weather_next_change_tick: SimTick,
     precipitation_accumulator: f32,
     rng: StdRng,
-    weather_blend: f32,
     lightning_flash_ticks: u8,
     /// Last frame delta time (seconds)
     frame_dt: f32,
@@ -2049,6 +2057,12 @@ pub struct GameWorld {
     billboard_renderer: Option<BillboardRenderer>,
     #[cfg(feature = "ui3d_billboards")]
     billboard_emitter: BillboardEmitter,
+    /// Nameplates keyed by mob id, re-anchored to their mob every frame.
+    #[cfg(feature = "ui3d_billboards")]
+    mob_labels: HashMap<u64, EntityLabel>,
+    /// Draws nameplate text; absent without a GPU or a font.
+    #[cfg(feature = "ui3d_billboards")]
+    nameplate_text: Option<TextRenderer>,
     /// Dropped item manager for block drops and pickups
     item_manager: ItemManager,
     /// Pack-driven loot overrides for block/mob drops.
@@ -2112,6 +2126,8 @@ pub struct GameWorld {
     open_furnace_pos: Option<BlockEntityKey>,
     /// Furnace states by position
     furnaces: BTreeMap<BlockEntityKey, FurnaceState>,
+    /// Smelting recipes and fuel values furnaces tick against
+    smelting: SmeltingRegistry,
     /// Whether enchanting table UI is open
     enchanting_open: bool,
     /// Currently open enchanting table position (if any)
@@ -2203,6 +2219,26 @@ impl GameWorld {
         (forward, right)
     }
 
+    /// Radius in chunks out to which meshes stay loaded for `render_distance`.
+    fn loaded_chunk_radius(render_distance: i32) -> u32 {
+        (render_distance + CHUNK_UNLOAD_MARGIN).max(0) as u32
+    }
+
+    /// Apply camera-facing settings (field of view) from the controls config.
+    fn apply_controls_to_camera(
+        camera: &mut mdminecraft_render::Camera,
+        controls: &ControlsConfig,
+    ) {
+        camera.fov = controls.fov_degrees.clamp(30.0, 150.0).to_radians();
+    }
+
+    /// Apply UI-facing settings (crosshair) from the controls config.
+    fn apply_controls_to_ui(renderer: &Renderer, controls: &ControlsConfig) {
+        if let Some(mut ui) = renderer.ui_mut() {
+            ui.set_crosshair(controls.crosshair.to_config());
+        }
+    }
+
     fn audio_settings_from_controls(controls: &ControlsConfig) -> AudioSettings {
         AudioSettings {
             master: controls.master_volume.clamp(0.0, 1.0),
@@ -2313,7 +2349,8 @@ impl GameWorld {
         &self,
         camera_pos: glam::Vec3,
     ) -> (Option<FluidType>, Option<FluidType>) {
-        let feet_pos = camera_pos - glam::Vec3::new(0.0, self.player_physics.eye_height, 0.0);
+        let feet_pos =
+            camera_pos - glam::Vec3::new(0.0, self.player_physics.controller.eye_height, 0.0);
         let feet_sample = feet_pos + glam::Vec3::new(0.0, 0.1, 0.0);
         (
             self.fluid_at_world_pos(camera_pos),
@@ -2360,6 +2397,7 @@ impl GameWorld {
             chunk,
             &self.registry,
             self.renderer.atlas_metadata(),
+            MeshOptions::default(),
             |wx, wy, wz| {
                 let local_y = world_y_to_local_y(wy)?;
 
@@ -2418,20 +2456,20 @@ impl GameWorld {
             chunk_pos,
             chunk_bind_group,
         );
+        self.chunk_manager.set_chunk_occluder(
+            chunk_pos,
+            mdminecraft_render::chunk_solid_floor(chunk, &self.registry),
+        );
         true
     }
 
-    fn upload_chunk_mesh_and_neighbors(&mut self, chunk_pos: ChunkPos) -> u32 {
-        let mut uploads = 0u32;
-        if self.upload_chunk_mesh(chunk_pos) {
-            uploads += 1;
-        }
-        for neighbor in Self::neighbor_chunk_positions(chunk_pos) {
-            if self.upload_chunk_mesh(neighbor) {
-                uploads += 1;
+    /// Rebuild every chunk queued via `Renderer::request_remesh` this frame.
+    fn flush_chunk_remeshes(&mut self) {
+        for chunk_pos in self.renderer.flush_remeshes() {
+            if self.upload_chunk_mesh(chunk_pos) {
+                self.debug_hud.chunk_uploads_last_frame += 1;
             }
         }
-        uploads
     }
     /// Create a new game world
     pub fn new(
@@ -2450,6 +2488,8 @@ impl GameWorld {
             screenshot,
             record,
             commentary,
+            edit_rules,
+            player_is_op,
         } = options;
 
         // Create window
@@ -2486,16 +2526,37 @@ impl GameWorld {
                 resources.pipeline.atlas_view(),
                 resources.pipeline.atlas_sampler(),
             ) {
-                Ok(r) => Some(r),
+                Ok(mut r) => {
+                    r.enable_soft_particles(resources.downlevel_flags);
+                    Some(r)
+                }
                 Err(err) => {
                     tracing::warn!(?err, "Failed to initialize billboard renderer");
                     None
                 }
             }
         };
+        #[cfg(feature = "ui3d_billboards")]
+        let nameplate_text = load_nameplate_font().and_then(|font| {
+            let resources = renderer.render_resources().expect("GPU not initialized");
+            let format = renderer
+                .surface_format()
+                .unwrap_or(wgpu::TextureFormat::Bgra8UnormSrgb);
+            TextRenderer::new(
+                resources.device,
+                resources.queue,
+                format,
+                resources.pipeline.camera_bind_group_layout(),
+                font,
+            )
+            .map_err(|err| tracing::warn!(?err, "Failed to initialize nameplate text renderer"))
+            .ok()
+        });
 
         // Load block registry
         let registry = load_block_registry();
+        check_block_registry_textures(&registry, renderer.atlas_metadata());
+        let block_properties = BlockPropertiesRegistry::from_registry(&registry);
 
         // Setup persistence and generator
         let save_path = PathBuf::from("saves/default");
@@ -2506,16 +2567,11 @@ impl GameWorld {
 
         let (world_seed, end_boss_defeated, loaded_state) = {
             let meta = if region_store.world_meta_exists() {
-                match region_store.load_world_meta() {
-                    Ok(meta) => meta,
-                    Err(err) => {
-                        tracing::warn!(?err, "Failed to load world meta; generating new seed");
-                        WorldMeta {
-                            world_seed: rand::random(),
-                            end_boss_defeated: false,
-                        }
-                    }
-                }
+                // Never fall back to a fresh seed here: the new meta would be
+                // saved over the existing (possibly newer) world.
+                region_store
+                    .load_world_meta()
+                    .context("Refusing to open the save")?
             } else {
                 let world_seed = std::env::var("MDM_WORLD_SEED")
                     .ok()
@@ -2525,6 +2581,7 @@ impl GameWorld {
                 let meta = WorldMeta {
                     world_seed,
                     end_boss_defeated: false,
+                    format_version: CHUNK_FORMAT_VERSION,
                 };
                 if let Err(err) = region_store.save_world_meta(&meta) {
                     tracing::warn!(?err, "Failed to save world meta");
@@ -2551,7 +2608,7 @@ impl GameWorld {
         let terrain_generator = TerrainGenerator::new(world_seed);
         let render_distance = controls.render_distance.clamp(2, 16);
 
-        let chunk_manager = ChunkManager::new();
+        let mut chunk_manager = ChunkManager::new();
         let chunks = HashMap::new();
         let rng = StdRng::seed_from_u64(world_seed ^ 0x5eed_a11c);
 
@@ -2561,24 +2618,18 @@ impl GameWorld {
         let mut loaded_player: Option<PlayerSave> = None;
         let mut loaded_entities = WorldEntitiesState::default();
         let mut loaded_block_entities = BlockEntitiesState::default();
-        let weather_next_change_tick = if let Some(state) = loaded_state {
+        if let Some(state) = loaded_state {
             sim_tick = state.tick;
             sim_time = state.sim_time;
             weather = state.weather;
+            weather.set_next_change_tick(state.weather_next_change_tick);
             loaded_player = state.player;
             loaded_entities = state.entities;
             loaded_block_entities = state.block_entities;
-            state.weather_next_change_tick
         } else {
             // Deterministic initial schedule (based on seed + tick).
-            let delay_ticks = Self::weather_delay_ticks(
-                world_seed,
-                sim_tick,
-                900..2400, // 45..120 seconds at 20 TPS
-                0xC0FFEE_u64,
-            );
-            sim_tick.advance(delay_ticks)
-        };
+            weather.reschedule(sim_tick, world_seed);
+        }
 
         let initial_dimension = loaded_player
             .as_ref()
@@ -2603,12 +2654,18 @@ impl GameWorld {
             renderer.camera_mut().pitch = -0.3;
         }
 
-        renderer.camera_mut().fov = controls.fov_degrees.clamp(30.0, 150.0).to_radians();
+        Self::apply_controls_to_camera(renderer.camera_mut(), &controls);
+        Self::apply_controls_to_ui(&renderer, &controls);
+        renderer.set_render_distance(
+            Self::loaded_chunk_radius(render_distance),
+            &mut chunk_manager,
+        );
 
         let mut audio = AudioManager::new()?;
         audio.update_settings(Self::audio_settings_from_controls(controls.as_ref()));
         let camera_pos = renderer.camera().position;
         audio.set_listener_position([camera_pos.x, camera_pos.y, camera_pos.z]);
+        audio.set_listener_orientation(renderer.camera().forward().to_array());
 
         let spawn_table = content_pack_spawns::load_mob_spawn_table_lenient(Path::new(
             content_packs::CONTENT_PACKS_DIR,
@@ -2679,7 +2736,7 @@ impl GameWorld {
             chunk_manager,
             chunks,
             registry,
-            block_properties: BlockPropertiesRegistry::new(),
+            block_properties,
             input,
             last_frame: Instant::now(),
             debug_hud,
@@ -2690,10 +2747,14 @@ impl GameWorld {
             hotbar: Hotbar::new(),
             player_physics: PlayerPhysics::new(),
             player_health: PlayerHealth::new(),
-            chunks_visible: 0,
+            chunk_stats: RenderStats::default(),
+            gamemode: commands::Gamemode::Survival,
             mining_progress: None,
             spawn_point: glam::Vec3::ZERO, // Temp
             spawn_point_dimension: initial_spawn_dimension,
+            edit_rules,
+            player_is_op,
+            last_edit_denied: None,
             active_dimension: initial_dimension,
             portal_charge_ticks: 0,
             portal_cooldown_ticks: 0,
@@ -2718,10 +2779,8 @@ impl GameWorld {
             particle_emitter: ParticleEmitter::new(),
             particles: Vec::new(),
             weather,
-            weather_next_change_tick,
             precipitation_accumulator: 0.0,
             rng,
-            weather_blend: 0.0,
             lightning_flash_ticks: 0,
             frame_dt: 0.0,
             player_state: PlayerState::Alive,
@@ -2732,6 +2791,10 @@ impl GameWorld {
             billboard_renderer,
             #[cfg(feature = "ui3d_billboards")]
             billboard_emitter: BillboardEmitter::default(),
+            #[cfg(feature = "ui3d_billboards")]
+            mob_labels: HashMap::new(),
+            #[cfg(feature = "ui3d_billboards")]
+            nameplate_text,
             item_manager: dropped_items,
             loot_tables,
             inventory_open: false,
@@ -2744,7 +2807,7 @@ impl GameWorld {
             mob_spawner,
             mobs,
             next_mob_id,
-            fluid_sim: FluidSimulator::new(),
+            fluid_sim: budgeted_fluid_simulator(),
             redstone_sim: RedstoneSimulator::new(),
             crop_growth: CropGrowthSystem::new(world_seed),
             sugar_cane_growth: SugarCaneGrowthSystem::new(world_seed),
@@ -2758,6 +2821,7 @@ impl GameWorld {
             furnace_open: false,
             open_furnace_pos: None,
             furnaces,
+            smelting: SmeltingRegistry::builtin(),
             enchanting_open: false,
             open_enchanting_pos: None,
             enchanting_tables,
@@ -2869,10 +2933,10 @@ impl GameWorld {
 
             // Setup camera
             world.renderer.camera_mut().position =
-                spawn_feet + glam::Vec3::new(0.0, PlayerPhysics::new().eye_height, 0.0);
+                spawn_feet + glam::Vec3::new(0.0, PlayerController::new().eye_height, 0.0);
             world.renderer.camera_mut().yaw = 0.0;
             world.renderer.camera_mut().pitch = -0.3;
-            world.player_physics.last_ground_y = spawn_feet.y;
+            world.player_physics.controller.fall.reset();
 
             // Optional debug mob spawn for visibility testing.
             if spawn_test_mobs {
@@ -2928,6 +2992,8 @@ impl GameWorld {
             screenshot,
             record,
             commentary,
+            edit_rules,
+            player_is_op,
         } = options;
 
         let renderer_config = RendererConfig {
@@ -2942,9 +3008,13 @@ impl GameWorld {
 
         #[cfg(feature = "ui3d_billboards")]
         let billboard_renderer = None;
+        #[cfg(feature = "ui3d_billboards")]
+        let nameplate_text = None;
 
         // Load block registry
         let registry = load_block_registry();
+        check_block_registry_textures(&registry, renderer.atlas_metadata());
+        let block_properties = BlockPropertiesRegistry::from_registry(&registry);
 
         // Setup persistence and generator
         let region_store = RegionStore::new(&save_path).unwrap_or_else(|_| {
@@ -2954,16 +3024,9 @@ impl GameWorld {
 
         let (world_seed, end_boss_defeated, loaded_state) = {
             if region_store.world_meta_exists() {
-                let meta = match region_store.load_world_meta() {
-                    Ok(meta) => meta,
-                    Err(err) => {
-                        tracing::warn!(?err, "Failed to load world meta; starting fresh");
-                        WorldMeta {
-                            world_seed: world_seed_override.unwrap_or_else(rand::random),
-                            end_boss_defeated: false,
-                        }
-                    }
-                };
+                let meta = region_store
+                    .load_world_meta()
+                    .context("Refusing to open the save")?;
 
                 if let Some(expected_seed) = world_seed_override {
                     if meta.world_seed != expected_seed {
@@ -3000,6 +3063,7 @@ impl GameWorld {
                 let meta = WorldMeta {
                     world_seed,
                     end_boss_defeated: false,
+                    format_version: CHUNK_FORMAT_VERSION,
                 };
                 if let Err(err) = region_store.save_world_meta(&meta) {
                     tracing::warn!(?err, "Failed to save world meta");
@@ -3025,7 +3089,7 @@ impl GameWorld {
         let terrain_generator = TerrainGenerator::new(world_seed);
         let render_distance = controls.render_distance.clamp(2, 16);
 
-        let chunk_manager = ChunkManager::new();
+        let mut chunk_manager = ChunkManager::new();
         let chunks = HashMap::new();
         let rng = StdRng::seed_from_u64(world_seed ^ 0x5eed_a11c);
 
@@ -3035,24 +3099,18 @@ impl GameWorld {
         let mut loaded_player: Option<PlayerSave> = None;
         let mut loaded_entities = WorldEntitiesState::default();
         let mut loaded_block_entities = BlockEntitiesState::default();
-        let weather_next_change_tick = if let Some(state) = loaded_state {
+        if let Some(state) = loaded_state {
             sim_tick = state.tick;
             sim_time = state.sim_time;
             weather = state.weather;
+            weather.set_next_change_tick(state.weather_next_change_tick);
             loaded_player = state.player;
             loaded_entities = state.entities;
             loaded_block_entities = state.block_entities;
-            state.weather_next_change_tick
         } else {
             // Deterministic initial schedule (based on seed + tick).
-            let delay_ticks = Self::weather_delay_ticks(
-                world_seed,
-                sim_tick,
-                900..2400, // 45..120 seconds at 20 TPS
-                0xC0FFEE_u64,
-            );
-            sim_tick.advance(delay_ticks)
-        };
+            weather.reschedule(sim_tick, world_seed);
+        }
 
         let initial_dimension = loaded_player
             .as_ref()
@@ -3077,7 +3135,12 @@ impl GameWorld {
             renderer.camera_mut().pitch = -0.3;
         }
 
-        renderer.camera_mut().fov = controls.fov_degrees.clamp(30.0, 150.0).to_radians();
+        Self::apply_controls_to_camera(renderer.camera_mut(), &controls);
+        Self::apply_controls_to_ui(&renderer, &controls);
+        renderer.set_render_distance(
+            Self::loaded_chunk_radius(render_distance),
+            &mut chunk_manager,
+        );
 
         let mut audio = if no_audio {
             AudioManager::stub()
@@ -3093,6 +3156,7 @@ impl GameWorld {
         audio.update_settings(Self::audio_settings_from_controls(controls.as_ref()));
         let camera_pos = renderer.camera().position;
         audio.set_listener_position([camera_pos.x, camera_pos.y, camera_pos.z]);
+        audio.set_listener_orientation(renderer.camera().forward().to_array());
 
         let spawn_table = content_pack_spawns::load_mob_spawn_table_lenient(Path::new(
             content_packs::CONTENT_PACKS_DIR,
@@ -3163,7 +3227,7 @@ impl GameWorld {
             chunk_manager,
             chunks,
             registry,
-            block_properties: BlockPropertiesRegistry::new(),
+            block_properties,
             input,
             last_frame: Instant::now(),
             debug_hud,
@@ -3174,10 +3238,14 @@ impl GameWorld {
             hotbar: Hotbar::new(),
             player_physics: PlayerPhysics::new(),
             player_health: PlayerHealth::new(),
-            chunks_visible: 0,
+            chunk_stats: RenderStats::default(),
+            gamemode: commands::Gamemode::Survival,
             mining_progress: None,
             spawn_point: glam::Vec3::ZERO, // Temp
             spawn_point_dimension: initial_spawn_dimension,
+            edit_rules,
+            player_is_op,
+            last_edit_denied: None,
             active_dimension: initial_dimension,
             portal_charge_ticks: 0,
             portal_cooldown_ticks: 0,
@@ -3202,10 +3270,8 @@ impl GameWorld {
             particle_emitter: ParticleEmitter::new(),
             particles: Vec::new(),
             weather,
-            weather_next_change_tick,
             precipitation_accumulator: 0.0,
             rng,
-            weather_blend: 0.0,
             lightning_flash_ticks: 0,
             frame_dt: 0.0,
             player_state: PlayerState::Alive,
@@ -3216,6 +3282,10 @@ impl GameWorld {
             billboard_renderer,
             #[cfg(feature = "ui3d_billboards")]
             billboard_emitter: BillboardEmitter::default(),
+            #[cfg(feature = "ui3d_billboards")]
+            mob_labels: HashMap::new(),
+            #[cfg(feature = "ui3d_billboards")]
+            nameplate_text,
             item_manager: dropped_items,
             loot_tables,
             inventory_open: false,
@@ -3228,7 +3298,7 @@ impl GameWorld {
             mob_spawner,
             mobs,
             next_mob_id,
-            fluid_sim: FluidSimulator::new(),
+            fluid_sim: budgeted_fluid_simulator(),
             redstone_sim: RedstoneSimulator::new(),
             crop_growth: CropGrowthSystem::new(world_seed),
             sugar_cane_growth: SugarCaneGrowthSystem::new(world_seed),
@@ -3242,6 +3312,7 @@ impl GameWorld {
             furnace_open: false,
             open_furnace_pos: None,
             furnaces,
+            smelting: SmeltingRegistry::builtin(),
             enchanting_open: false,
             open_enchanting_pos: None,
             enchanting_tables,
@@ -3349,10 +3420,10 @@ impl GameWorld {
 
             // Setup camera
             world.renderer.camera_mut().position =
-                spawn_feet + glam::Vec3::new(0.0, PlayerPhysics::new().eye_height, 0.0);
+                spawn_feet + glam::Vec3::new(0.0, PlayerController::new().eye_height, 0.0);
             world.renderer.camera_mut().yaw = 0.0;
             world.renderer.camera_mut().pitch = -0.3;
-            world.player_physics.last_ground_y = spawn_feet.y;
+            world.player_physics.controller.fall.reset();
         }
 
         world.enter_gameplay();
@@ -3376,6 +3447,7 @@ impl GameWorld {
         if self.player_state == PlayerState::Alive {
             self.update_camera(dt);
         }
+        self.sync_audio_listener();
 
         // Raycast for block selection (only if alive)
         if self.input.cursor_captured && self.player_state == PlayerState::Alive {
@@ -3888,7 +3960,10 @@ impl GameWorld {
             return None;
         }
 
-        match mdminecraft_world::get_collision_type(voxel.id, voxel.state) {
+        match block_properties
+            .collision_shapes()
+            .collision_type(voxel.id, voxel.state)
+        {
             mdminecraft_world::CollisionType::None | mdminecraft_world::CollisionType::Ladder => {
                 None
             }
@@ -3899,549 +3974,6 @@ impl GameWorld {
         }
     }
 
-    fn collision_aabbs_for_voxel(
-        chunks: &HashMap<ChunkPos, Chunk>,
-        block_properties: &BlockPropertiesRegistry,
-        block_x: i32,
-        block_y: i32,
-        block_z: i32,
-        voxel: &Voxel,
-    ) -> AabbSet<8> {
-        if !block_properties.get(voxel.id).is_solid {
-            return AabbSet::empty();
-        }
-
-        let full = || AABB {
-            min: glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-            max: glam::Vec3::new(
-                block_x as f32 + 1.0,
-                block_y as f32 + 1.0,
-                block_z as f32 + 1.0,
-            ),
-        };
-
-        let voxel_at = |x: i32, y: i32, z: i32| -> Option<Voxel> {
-            let local_y = world_y_to_local_y(y)?;
-            let chunk_x = x.div_euclid(CHUNK_SIZE_X as i32);
-            let chunk_z = z.div_euclid(CHUNK_SIZE_Z as i32);
-            let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
-            let chunk = chunks.get(&chunk_pos)?;
-            let local_x = x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-            let local_z = z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-            Some(chunk.voxel(local_x, local_y, local_z))
-        };
-
-        match mdminecraft_world::get_collision_type(voxel.id, voxel.state) {
-            mdminecraft_world::CollisionType::None | mdminecraft_world::CollisionType::Ladder => {
-                if mdminecraft_world::is_fence_gate(voxel.id)
-                    && mdminecraft_world::is_fence_gate_open(voxel.state)
-                {
-                    let thickness = 3.0 / 16.0;
-                    let facing = mdminecraft_world::Facing::from_state(voxel.state);
-
-                    // Simplified hinge: gates always swing "left" from their facing direction.
-                    let (min, max) = match facing {
-                        mdminecraft_world::Facing::North => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + thickness,
-                                block_y as f32 + 1.5,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::South => (
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0 - thickness,
-                                block_y as f32,
-                                block_z as f32,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.5,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::East => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.5,
-                                block_z as f32 + thickness,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::West => (
-                            glam::Vec3::new(
-                                block_x as f32,
-                                block_y as f32,
-                                block_z as f32 + 1.0 - thickness,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.5,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                    };
-
-                    return AabbSet::single(AABB { min, max });
-                }
-
-                if mdminecraft_world::is_trapdoor(voxel.id)
-                    && mdminecraft_world::is_trapdoor_open(voxel.state)
-                {
-                    let thickness = 3.0 / 16.0;
-                    let facing = mdminecraft_world::Facing::from_state(voxel.state);
-
-                    let (min, max) = match facing {
-                        mdminecraft_world::Facing::North => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + thickness,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::South => (
-                            glam::Vec3::new(
-                                block_x as f32,
-                                block_y as f32,
-                                block_z as f32 + 1.0 - thickness,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::East => (
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0 - thickness,
-                                block_y as f32,
-                                block_z as f32,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::West => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + thickness,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                    };
-
-                    return AabbSet::single(AABB { min, max });
-                }
-
-                AabbSet::empty()
-            }
-            mdminecraft_world::CollisionType::Door { open } => {
-                let thickness = 3.0 / 16.0;
-                let facing = mdminecraft_world::Facing::from_state(voxel.state);
-
-                let (min, max) = if open {
-                    // Simplified hinge: doors always swing "left" from their facing direction.
-                    match facing {
-                        mdminecraft_world::Facing::North => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + thickness,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::South => (
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0 - thickness,
-                                block_y as f32,
-                                block_z as f32,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::East => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + thickness,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::West => (
-                            glam::Vec3::new(
-                                block_x as f32,
-                                block_y as f32,
-                                block_z as f32 + 1.0 - thickness,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                    }
-                } else {
-                    match facing {
-                        mdminecraft_world::Facing::North => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + thickness,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::South => (
-                            glam::Vec3::new(
-                                block_x as f32,
-                                block_y as f32,
-                                block_z as f32 + 1.0 - thickness,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::East => (
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0 - thickness,
-                                block_y as f32,
-                                block_z as f32,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::West => (
-                            glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                            glam::Vec3::new(
-                                block_x as f32 + thickness,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                    }
-                };
-
-                AabbSet::single(AABB { min, max })
-            }
-            mdminecraft_world::CollisionType::Full => {
-                if voxel.id == mdminecraft_world::interactive_blocks::GLASS_PANE
-                    || voxel.id == mdminecraft_world::interactive_blocks::IRON_BARS
-                {
-                    // Glass pane collision: a thin post, optionally with connecting arms.
-                    let thickness = 2.0 / 16.0;
-                    let half = thickness * 0.5;
-
-                    let connects_to = |neighbor: Voxel| -> bool {
-                        matches!(
-                            neighbor.id,
-                            mdminecraft_world::interactive_blocks::GLASS_PANE
-                                | mdminecraft_world::interactive_blocks::IRON_BARS
-                        ) || mdminecraft_world::is_full_cube_block(neighbor.id)
-                    };
-
-                    let connect_west =
-                        voxel_at(block_x - 1, block_y, block_z).is_some_and(connects_to);
-                    let connect_east =
-                        voxel_at(block_x + 1, block_y, block_z).is_some_and(connects_to);
-                    let connect_north =
-                        voxel_at(block_x, block_y, block_z - 1).is_some_and(connects_to);
-                    let connect_south =
-                        voxel_at(block_x, block_y, block_z + 1).is_some_and(connects_to);
-
-                    let post_min_x = block_x as f32 + 0.5 - half;
-                    let post_max_x = block_x as f32 + 0.5 + half;
-                    let post_min_z = block_z as f32 + 0.5 - half;
-                    let post_max_z = block_z as f32 + 0.5 + half;
-
-                    let any_x = connect_west || connect_east;
-                    let any_z = connect_north || connect_south;
-                    if !any_x && !any_z {
-                        return AabbSet::single(AABB {
-                            min: glam::Vec3::new(post_min_x, block_y as f32, post_min_z),
-                            max: glam::Vec3::new(post_max_x, block_y as f32 + 1.0, post_max_z),
-                        });
-                    }
-
-                    let mut set = AabbSet::empty();
-                    if any_x {
-                        let min_x = if connect_west {
-                            block_x as f32
-                        } else {
-                            post_min_x
-                        };
-                        let max_x = if connect_east {
-                            block_x as f32 + 1.0
-                        } else {
-                            post_max_x
-                        };
-                        set.push(AABB {
-                            min: glam::Vec3::new(min_x, block_y as f32, post_min_z),
-                            max: glam::Vec3::new(max_x, block_y as f32 + 1.0, post_max_z),
-                        });
-                    }
-                    if any_z {
-                        let min_z = if connect_north {
-                            block_z as f32
-                        } else {
-                            post_min_z
-                        };
-                        let max_z = if connect_south {
-                            block_z as f32 + 1.0
-                        } else {
-                            post_max_z
-                        };
-                        set.push(AABB {
-                            min: glam::Vec3::new(post_min_x, block_y as f32, min_z),
-                            max: glam::Vec3::new(post_max_x, block_y as f32 + 1.0, max_z),
-                        });
-                    }
-                    return set;
-                }
-
-                AabbSet::single(full())
-            }
-            mdminecraft_world::CollisionType::Partial { min_y, max_y } => {
-                if voxel.id == mdminecraft_world::BLOCK_BREWING_STAND {
-                    let pad = 4.0 / 16.0;
-                    return AabbSet::single(AABB {
-                        min: glam::Vec3::new(
-                            block_x as f32 + pad,
-                            block_y as f32 + min_y,
-                            block_z as f32 + pad,
-                        ),
-                        max: glam::Vec3::new(
-                            block_x as f32 + 1.0 - pad,
-                            block_y as f32 + max_y,
-                            block_z as f32 + 1.0 - pad,
-                        ),
-                    });
-                }
-
-                if mdminecraft_world::is_stairs(voxel.id) {
-                    let facing = mdminecraft_world::Facing::from_state(voxel.state);
-                    let top = (voxel.state & 0x04) != 0;
-                    let shape = mdminecraft_world::stairs_shape_at(
-                        block_x, block_y, block_z, *voxel, &voxel_at,
-                    );
-                    let (footprints, footprint_count) =
-                        mdminecraft_world::stairs_step_footprints(facing, shape);
-
-                    let mut set = AabbSet::empty();
-                    if top {
-                        // Upside-down stairs: full top half + a lower half-footprint step.
-                        let upper_min_y = block_y as f32 + max_y;
-                        set.push(AABB {
-                            min: glam::Vec3::new(block_x as f32, upper_min_y, block_z as f32),
-                            max: glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.0,
-                                block_z as f32 + 1.0,
-                            ),
-                        });
-
-                        let lower_min_y = block_y as f32 + min_y;
-                        let lower_max_y = block_y as f32 + max_y;
-                        for footprint in footprints.iter().take(footprint_count) {
-                            set.push(AABB {
-                                min: glam::Vec3::new(
-                                    block_x as f32 + footprint.min_x,
-                                    lower_min_y,
-                                    block_z as f32 + footprint.min_z,
-                                ),
-                                max: glam::Vec3::new(
-                                    block_x as f32 + footprint.max_x,
-                                    lower_max_y,
-                                    block_z as f32 + footprint.max_z,
-                                ),
-                            });
-                        }
-                    } else {
-                        // Normal stairs: full bottom half + an upper half-footprint step.
-                        set.push(AABB {
-                            min: glam::Vec3::new(
-                                block_x as f32,
-                                block_y as f32 + min_y,
-                                block_z as f32,
-                            ),
-                            max: glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + max_y,
-                                block_z as f32 + 1.0,
-                            ),
-                        });
-
-                        let upper_min_y = block_y as f32 + max_y;
-                        for footprint in footprints.iter().take(footprint_count) {
-                            set.push(AABB {
-                                min: glam::Vec3::new(
-                                    block_x as f32 + footprint.min_x,
-                                    upper_min_y,
-                                    block_z as f32 + footprint.min_z,
-                                ),
-                                max: glam::Vec3::new(
-                                    block_x as f32 + footprint.max_x,
-                                    block_y as f32 + 1.0,
-                                    block_z as f32 + footprint.max_z,
-                                ),
-                            });
-                        }
-                    }
-                    return set;
-                }
-
-                AabbSet::single(AABB {
-                    min: glam::Vec3::new(block_x as f32, block_y as f32 + min_y, block_z as f32),
-                    max: glam::Vec3::new(
-                        block_x as f32 + 1.0,
-                        block_y as f32 + max_y,
-                        block_z as f32 + 1.0,
-                    ),
-                })
-            }
-            mdminecraft_world::CollisionType::Fence => {
-                if mdminecraft_world::is_fence_gate(voxel.id) {
-                    let thickness = 3.0 / 16.0;
-                    let half = thickness * 0.5;
-                    let facing = mdminecraft_world::Facing::from_state(voxel.state);
-
-                    let (min, max) = match facing {
-                        mdminecraft_world::Facing::North | mdminecraft_world::Facing::South => (
-                            glam::Vec3::new(
-                                block_x as f32,
-                                block_y as f32,
-                                block_z as f32 + 0.5 - half,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 1.0,
-                                block_y as f32 + 1.5,
-                                block_z as f32 + 0.5 + half,
-                            ),
-                        ),
-                        mdminecraft_world::Facing::East | mdminecraft_world::Facing::West => (
-                            glam::Vec3::new(
-                                block_x as f32 + 0.5 - half,
-                                block_y as f32,
-                                block_z as f32,
-                            ),
-                            glam::Vec3::new(
-                                block_x as f32 + 0.5 + half,
-                                block_y as f32 + 1.5,
-                                block_z as f32 + 1.0,
-                            ),
-                        ),
-                    };
-
-                    return AabbSet::single(AABB { min, max });
-                }
-
-                let is_wall = matches!(
-                    voxel.id,
-                    mdminecraft_world::interactive_blocks::COBBLESTONE_WALL
-                        | mdminecraft_world::interactive_blocks::STONE_BRICK_WALL
-                );
-
-                let connects_to = |neighbor: Voxel| -> bool {
-                    if is_wall {
-                        matches!(
-                            neighbor.id,
-                            mdminecraft_world::interactive_blocks::COBBLESTONE_WALL
-                                | mdminecraft_world::interactive_blocks::STONE_BRICK_WALL
-                        ) || mdminecraft_world::is_fence_gate(neighbor.id)
-                            || mdminecraft_world::is_full_cube_block(neighbor.id)
-                    } else {
-                        mdminecraft_world::is_fence(neighbor.id)
-                            || mdminecraft_world::is_fence_gate(neighbor.id)
-                            || mdminecraft_world::is_full_cube_block(neighbor.id)
-                    }
-                };
-
-                let connect_west = voxel_at(block_x - 1, block_y, block_z).is_some_and(connects_to);
-                let connect_east = voxel_at(block_x + 1, block_y, block_z).is_some_and(connects_to);
-                let connect_north =
-                    voxel_at(block_x, block_y, block_z - 1).is_some_and(connects_to);
-                let connect_south =
-                    voxel_at(block_x, block_y, block_z + 1).is_some_and(connects_to);
-
-                // Fence collision: center post + optional connecting arms (multi-AABB), avoiding
-                // over-colliding corners when connected in multiple directions.
-                let (post_min_x, post_max_x, post_min_z, post_max_z, arm_thickness) = if matches!(
-                    voxel.id,
-                    mdminecraft_world::interactive_blocks::COBBLESTONE_WALL
-                        | mdminecraft_world::interactive_blocks::STONE_BRICK_WALL
-                ) {
-                    let thickness = 6.0 / 16.0;
-                    let half = thickness * 0.5;
-                    (
-                        block_x as f32 + 0.5 - half,
-                        block_x as f32 + 0.5 + half,
-                        block_z as f32 + 0.5 - half,
-                        block_z as f32 + 0.5 + half,
-                        thickness,
-                    )
-                } else {
-                    (
-                        block_x as f32 + 0.375,
-                        block_x as f32 + 0.625,
-                        block_z as f32 + 0.375,
-                        block_z as f32 + 0.625,
-                        2.0 / 16.0,
-                    )
-                };
-
-                let arm_half = arm_thickness * 0.5;
-                let arm_min_x = block_x as f32 + 0.5 - arm_half;
-                let arm_max_x = block_x as f32 + 0.5 + arm_half;
-                let arm_min_z = block_z as f32 + 0.5 - arm_half;
-                let arm_max_z = block_z as f32 + 0.5 + arm_half;
-
-                let mut set = AabbSet::empty();
-                set.push(AABB {
-                    min: glam::Vec3::new(post_min_x, block_y as f32, post_min_z),
-                    max: glam::Vec3::new(post_max_x, block_y as f32 + 1.5, post_max_z),
-                });
-
-                if connect_west {
-                    set.push(AABB {
-                        min: glam::Vec3::new(block_x as f32, block_y as f32, arm_min_z),
-                        max: glam::Vec3::new(block_x as f32 + 0.5, block_y as f32 + 1.5, arm_max_z),
-                    });
-                }
-                if connect_east {
-                    set.push(AABB {
-                        min: glam::Vec3::new(block_x as f32 + 0.5, block_y as f32, arm_min_z),
-                        max: glam::Vec3::new(block_x as f32 + 1.0, block_y as f32 + 1.5, arm_max_z),
-                    });
-                }
-                if connect_north {
-                    set.push(AABB {
-                        min: glam::Vec3::new(arm_min_x, block_y as f32, block_z as f32),
-                        max: glam::Vec3::new(arm_max_x, block_y as f32 + 1.5, block_z as f32 + 0.5),
-                    });
-                }
-                if connect_south {
-                    set.push(AABB {
-                        min: glam::Vec3::new(arm_min_x, block_y as f32, block_z as f32 + 0.5),
-                        max: glam::Vec3::new(arm_max_x, block_y as f32 + 1.5, block_z as f32 + 1.0),
-                    });
-                }
-
-                set
-            }
-        }
-    }
-
     fn block_collision_aabbs_at(
         chunks: &HashMap<ChunkPos, Chunk>,
         block_properties: &BlockPropertiesRegistry,
@@ -4449,31 +3981,19 @@ impl GameWorld {
         block_y: i32,
         block_z: i32,
     ) -> AabbSet<8> {
-        let Some(local_y) = world_y_to_local_y(block_y) else {
-            if block_y < WORLD_MIN_Y {
-                return AabbSet::single(AABB {
-                    min: glam::Vec3::new(block_x as f32, block_y as f32, block_z as f32),
-                    max: glam::Vec3::new(
-                        block_x as f32 + 1.0,
-                        block_y as f32 + 1.0,
-                        block_z as f32 + 1.0,
-                    ),
-                });
-            }
-
-            return AabbSet::empty();
-        };
-
-        let chunk_x = block_x.div_euclid(CHUNK_SIZE_X as i32);
-        let chunk_z = block_z.div_euclid(CHUNK_SIZE_Z as i32);
-        let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
-        let Some(chunk) = chunks.get(&chunk_pos) else {
-            return AabbSet::empty();
-        };
-        let local_x = block_x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-        let local_z = block_z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-        let voxel = chunk.voxel(local_x, local_y, local_z);
-        Self::collision_aabbs_for_voxel(chunks, block_properties, block_x, block_y, block_z, &voxel)
+        let mut set = AabbSet::empty();
+        for collision_box in mdminecraft_world::block_collision_boxes(
+            chunks,
+            block_properties,
+            block_x,
+            block_y,
+            block_z,
+        )
+        .iter()
+        {
+            set.push(AABB::from(*collision_box));
+        }
+        set
     }
 
     /// Check if an AABB collides with any solid blocks in the world
@@ -4482,28 +4002,7 @@ impl GameWorld {
         block_properties: &BlockPropertiesRegistry,
         aabb: &AABB,
     ) -> bool {
-        // Get the range of blocks the AABB might intersect
-        let min_x = aabb.min.x.floor() as i32;
-        let min_y = aabb.min.y.floor() as i32;
-        let min_z = aabb.min.z.floor() as i32;
-        let max_x = aabb.max.x.ceil() as i32;
-        let max_y = aabb.max.y.ceil() as i32;
-        let max_z = aabb.max.z.ceil() as i32;
-
-        for bx in min_x..max_x {
-            for by in min_y..max_y {
-                for bz in min_z..max_z {
-                    let block_aabbs =
-                        Self::block_collision_aabbs_at(chunks, block_properties, bx, by, bz);
-                    for block_aabb in block_aabbs.iter() {
-                        if aabb.intersects(block_aabb) {
-                            return true;
-                        }
-                    }
-                }
-            }
-        }
-        false
+        mdminecraft_world::collides_with_world(chunks, block_properties, &(*aabb).into())
     }
 
     fn projectile_point_collides_with_world(
@@ -4962,7 +4461,7 @@ impl GameWorld {
             if !collides(candidate_x, mob.y, mob.z) {
                 mob.x = candidate_x;
             } else {
-                let step_y = mob.y + PlayerPhysics::STEP_HEIGHT as f64;
+                let step_y = mob.y + PlayerController::STEP_HEIGHT as f64;
                 if !collides(candidate_x, step_y, mob.z) && !collides(mob.x, step_y, mob.z) {
                     mob.y = step_y;
                     mob.x = candidate_x;
@@ -4977,7 +4476,7 @@ impl GameWorld {
             if !collides(mob.x, mob.y, candidate_z) {
                 mob.z = candidate_z;
             } else {
-                let step_y = mob.y + PlayerPhysics::STEP_HEIGHT as f64;
+                let step_y = mob.y + PlayerController::STEP_HEIGHT as f64;
                 if !collides(mob.x, step_y, candidate_z) && !collides(mob.x, step_y, mob.z) {
                     mob.y = step_y;
                     mob.z = candidate_z;
@@ -5013,8 +4512,8 @@ impl GameWorld {
                     else {
                         continue;
                     };
-                    let foot_y = ground_world_y as f32 + top_offset + PlayerPhysics::GROUND_EPS;
-                    if foot_y > mob.y as f32 + PlayerPhysics::GROUND_EPS {
+                    let foot_y = ground_world_y as f32 + top_offset + PlayerController::GROUND_EPS;
+                    if foot_y > mob.y as f32 + PlayerController::GROUND_EPS {
                         continue;
                     }
                     if !collides(mob.x, foot_y as f64, mob.z) {
@@ -5036,181 +4535,6 @@ impl GameWorld {
         }
     }
 
-    fn aabb_touches_ladder(chunks: &HashMap<ChunkPos, Chunk>, aabb: &AABB) -> bool {
-        let min_x = aabb.min.x.floor() as i32;
-        let min_y = aabb.min.y.floor() as i32;
-        let min_z = aabb.min.z.floor() as i32;
-        let max_x = aabb.max.x.ceil() as i32;
-        let max_y = aabb.max.y.ceil() as i32;
-        let max_z = aabb.max.z.ceil() as i32;
-
-        for bx in min_x..max_x {
-            for by in min_y..max_y {
-                let Some(local_y) = world_y_to_local_y(by) else {
-                    continue;
-                };
-                for bz in min_z..max_z {
-                    let chunk_x = bx.div_euclid(CHUNK_SIZE_X as i32);
-                    let chunk_z = bz.div_euclid(CHUNK_SIZE_Z as i32);
-                    let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
-                    let Some(chunk) = chunks.get(&chunk_pos) else {
-                        continue;
-                    };
-                    let local_x = bx.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-                    let local_z = bz.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-                    let voxel = chunk.voxel(local_x, local_y, local_z);
-                    if matches!(
-                        mdminecraft_world::get_collision_type(voxel.id, voxel.state),
-                        mdminecraft_world::CollisionType::Ladder
-                    ) {
-                        return true;
-                    }
-                }
-            }
-        }
-        false
-    }
-
-    /// Move with collision detection, returning the actual position after collision resolution.
-    /// Uses sweep testing along each axis separately for wall sliding.
-    fn move_with_collision_axis_separated(
-        chunks: &HashMap<ChunkPos, Chunk>,
-        block_properties: &BlockPropertiesRegistry,
-        current_aabb: &AABB,
-        velocity: glam::Vec3,
-    ) -> (glam::Vec3, glam::Vec3) {
-        let mut result_offset = glam::Vec3::ZERO;
-        let mut result_velocity = velocity;
-
-        // Move along X axis
-        if velocity.x != 0.0 {
-            let test_aabb =
-                current_aabb.offset(glam::Vec3::new(velocity.x, 0.0, 0.0) + result_offset);
-            if !Self::aabb_collides_with_world(chunks, block_properties, &test_aabb) {
-                result_offset.x += velocity.x;
-            } else {
-                result_velocity.x = 0.0;
-            }
-        }
-
-        // Move along Y axis
-        if velocity.y != 0.0 {
-            let test_aabb =
-                current_aabb.offset(glam::Vec3::new(0.0, velocity.y, 0.0) + result_offset);
-            if !Self::aabb_collides_with_world(chunks, block_properties, &test_aabb) {
-                result_offset.y += velocity.y;
-            } else {
-                result_velocity.y = 0.0;
-            }
-        }
-
-        // Move along Z axis
-        if velocity.z != 0.0 {
-            let test_aabb =
-                current_aabb.offset(glam::Vec3::new(0.0, 0.0, velocity.z) + result_offset);
-            if !Self::aabb_collides_with_world(chunks, block_properties, &test_aabb) {
-                result_offset.z += velocity.z;
-            } else {
-                result_velocity.z = 0.0;
-            }
-        }
-
-        (result_offset, result_velocity)
-    }
-
-    fn step_down_offset(
-        chunks: &HashMap<ChunkPos, Chunk>,
-        block_properties: &BlockPropertiesRegistry,
-        base_aabb: &AABB,
-        max_down: f32,
-    ) -> f32 {
-        const STEP: f32 = 1.0 / 64.0;
-        if max_down <= 0.0 {
-            return 0.0;
-        }
-
-        let mut down = 0.0;
-        while down < max_down {
-            let next = (down + STEP).min(max_down);
-            let test_aabb = base_aabb.offset(glam::Vec3::new(0.0, -next, 0.0));
-            if Self::aabb_collides_with_world(chunks, block_properties, &test_aabb) {
-                break;
-            }
-            down = next;
-        }
-        -down
-    }
-
-    /// Move with collision detection, returning the actual position after collision resolution.
-    /// Uses axis-separated testing for wall sliding, plus an optional step-up for walking.
-    fn move_with_collision(
-        chunks: &HashMap<ChunkPos, Chunk>,
-        block_properties: &BlockPropertiesRegistry,
-        current_aabb: &AABB,
-        velocity: glam::Vec3,
-        step_height: f32,
-    ) -> (glam::Vec3, glam::Vec3) {
-        let (base_offset, base_velocity) = Self::move_with_collision_axis_separated(
-            chunks,
-            block_properties,
-            current_aabb,
-            velocity,
-        );
-
-        if step_height <= 0.0 || velocity.y > 0.0 {
-            return (base_offset, base_velocity);
-        }
-
-        let blocked_x = velocity.x != 0.0 && base_velocity.x == 0.0;
-        let blocked_z = velocity.z != 0.0 && base_velocity.z == 0.0;
-        if !(blocked_x || blocked_z) {
-            return (base_offset, base_velocity);
-        }
-
-        // Only step when we're on (or very near) the ground.
-        let ground_probe = current_aabb.offset(glam::Vec3::new(0.0, -0.1, 0.0));
-        if !Self::aabb_collides_with_world(chunks, block_properties, &ground_probe) {
-            return (base_offset, base_velocity);
-        }
-
-        let step_up = glam::Vec3::new(0.0, step_height, 0.0);
-        let stepped_aabb = current_aabb.offset(step_up);
-        if Self::aabb_collides_with_world(chunks, block_properties, &stepped_aabb) {
-            return (base_offset, base_velocity);
-        }
-
-        // Try the horizontal move from the stepped position (no vertical move in the step attempt).
-        let horizontal_velocity = glam::Vec3::new(velocity.x, 0.0, velocity.z);
-        let (step_horizontal_offset, step_horizontal_velocity) =
-            Self::move_with_collision_axis_separated(
-                chunks,
-                block_properties,
-                &stepped_aabb,
-                horizontal_velocity,
-            );
-        let stepped_after_horizontal = stepped_aabb.offset(step_horizontal_offset);
-
-        // Drop down by up to step height to land on the surface.
-        let step_down = Self::step_down_offset(
-            chunks,
-            block_properties,
-            &stepped_after_horizontal,
-            step_height,
-        );
-        let step_offset = step_up + step_horizontal_offset + glam::Vec3::new(0.0, step_down, 0.0);
-
-        let base_h = glam::Vec2::new(base_offset.x, base_offset.z).length_squared();
-        let step_h = glam::Vec2::new(step_offset.x, step_offset.z).length_squared();
-        if step_h <= base_h {
-            return (base_offset, base_velocity);
-        }
-
-        let mut result_velocity = base_velocity;
-        result_velocity.x = step_horizontal_velocity.x;
-        result_velocity.z = step_horizontal_velocity.z;
-        (step_offset, result_velocity)
-    }
-
     fn apply_player_knockback_displacement(&mut self, knockback: glam::Vec3, max_horizontal: f32) {
         if knockback.length_squared() <= 1.0e-8 {
             return;
@@ -5231,13 +4555,13 @@ impl GameWorld {
         };
 
         let camera_pos = self.renderer.camera().position;
-        let current_aabb = self.player_physics.get_aabb(camera_pos);
-        let (offset, _) = Self::move_with_collision(
+        let current_aabb = self.player_physics.controller.aabb(camera_pos);
+        let (offset, _) = mdminecraft_world::move_with_collision(
             &self.chunks,
             &self.block_properties,
             &current_aabb,
             knockback,
-            PlayerPhysics::STEP_HEIGHT,
+            PlayerController::STEP_HEIGHT,
         );
         if offset.length_squared() > 1.0e-8 {
             self.renderer.camera_mut().position = camera_pos + offset;
@@ -5278,7 +4602,7 @@ impl GameWorld {
             // Feet rest slightly above block top to avoid initial intersection.
             glam::Vec3::new(
                 world_x as f32 + 0.5,
-                top_world_y + PlayerPhysics::GROUND_EPS,
+                top_world_y + PlayerController::GROUND_EPS,
                 world_z as f32 + 0.5,
             )
         })
@@ -5287,6 +4611,7 @@ impl GameWorld {
     fn player_save(&self) -> PlayerSave {
         let camera = self.renderer.camera();
         PlayerSave {
+            version: mdminecraft_world::PLAYER_SAVE_VERSION,
             transform: PlayerTransform {
                 dimension: self.active_dimension,
                 x: camera.position.x as f64,
@@ -5406,17 +4731,6 @@ impl GameWorld {
 
         self.hotbar.slots = save.hotbar;
         self.hotbar.selected = save.hotbar_selected.min(8);
-
-        // Migrate legacy item IDs that were previously used for dropped-item conversions.
-        for slot in self.hotbar.slots.iter_mut().flatten() {
-            if let ItemType::Item(id) = slot.item_type {
-                match id {
-                    100 => slot.item_type = ItemType::Item(3), // Stick
-                    101 => slot.item_type = ItemType::Item(6), // Feather
-                    _ => {}
-                }
-            }
-        }
         self.main_inventory = Self::main_inventory_from_persisted_inventory(save.inventory);
 
         self.player_health.current = save.health.clamp(0.0, self.player_health.max);
@@ -5446,9 +4760,9 @@ impl GameWorld {
         self.renderer.camera_mut().yaw = save.transform.yaw;
         self.renderer.camera_mut().pitch = save.transform.pitch;
 
-        self.player_physics.velocity = glam::Vec3::ZERO;
-        self.player_physics.on_ground = false;
-        self.player_physics.last_ground_y = self.spawn_point.y;
+        self.player_physics.controller.velocity = glam::Vec3::ZERO;
+        self.player_physics.controller.on_ground = false;
+        self.player_physics.controller.fall.reset();
 
         self.player_state = PlayerState::Alive;
         self.death_message.clear();
@@ -5526,6 +4840,7 @@ impl GameWorld {
         let meta = WorldMeta {
             world_seed: self.world_seed,
             end_boss_defeated: self.end_boss_defeated,
+            format_version: self.region_store.format_version(),
         };
         if let Err(err) = self.region_store.save_world_meta(&meta) {
             tracing::warn!(?err, "Failed to save world meta");
@@ -5535,7 +4850,7 @@ impl GameWorld {
             tick: self.sim_tick,
             sim_time: self.sim_time,
             weather: self.weather,
-            weather_next_change_tick: self.weather_next_change_tick,
+            weather_next_change_tick: self.weather.next_change_tick(),
             player: Some(self.player_save()),
             entities: self.world_entities_state(),
             block_entities: self.block_entities_state(),
@@ -5546,7 +4861,7 @@ impl GameWorld {
     }
 
     fn persist_loaded_chunks(&mut self) {
-        for (pos, chunk) in &self.chunks {
+        for (pos, chunk) in &mut self.chunks {
             if let Err(err) = self
                 .region_store
                 .save_chunk_in_dimension(self.active_dimension, chunk)
@@ -5662,6 +4977,16 @@ impl GameWorld {
             PhysicalKey::Code(KeyCode::F3) => {
                 self.debug_hud.toggle();
             }
+            PhysicalKey::Code(KeyCode::F6) => {
+                let mode = self.renderer.debug_wireframe().next();
+                self.renderer.set_debug_wireframe(mode);
+                tracing::info!("Debug wireframe: {:?}", mode);
+            }
+            PhysicalKey::Code(KeyCode::F7) => {
+                let enabled = !self.chunk_manager.frustum_culling();
+                self.chunk_manager.set_frustum_culling(enabled);
+                tracing::info!(enabled, "Frustum culling toggled");
+            }
             PhysicalKey::Code(KeyCode::Slash) => {
                 self.open_command_prompt("/");
             }
@@ -5706,13 +5031,7 @@ impl GameWorld {
             }
             PhysicalKey::Code(KeyCode::KeyO) => {
                 self.weather.toggle();
-                let delay_ticks = Self::weather_delay_ticks(
-                    self.world_seed,
-                    self.sim_tick,
-                    900..2400, // 45..120 seconds at 20 TPS
-                    0xB16B_00B5_u64,
-                );
-                self.weather_next_change_tick = self.sim_tick.advance(delay_ticks);
+                self.weather.reschedule(self.sim_tick, self.world_seed);
                 tracing::info!(state = ?self.weather.state, "Weather toggled");
             }
             PhysicalKey::Code(KeyCode::KeyE) => {
@@ -6101,7 +5420,7 @@ impl GameWorld {
     }
 
     fn weather_intensity(&self) -> f32 {
-        self.weather_blend
+        self.weather.intensity()
     }
 
     fn lightning_flash_intensity(&self) -> f32 {
@@ -6130,68 +5449,9 @@ impl GameWorld {
         }
     }
 
-    fn weather_delay_ticks(
-        world_seed: u64,
-        tick: SimTick,
-        range: std::ops::Range<u64>,
-        salt: u64,
-    ) -> u64 {
-        let seed = world_seed ^ tick.0.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ salt;
-        let mut rng = StdRng::seed_from_u64(seed);
-        rng.gen_range(range)
-    }
-
     fn tick_weather(&mut self) {
-        if self.sim_tick < self.weather_next_change_tick {
-            return;
-        }
-
-        let seed = self.world_seed
-            ^ self.sim_tick.0.wrapping_mul(0xD6E8_FEB8_6659_FD93)
-            ^ 0x0057_4541_5448_4552_u64;
-        let mut rng = StdRng::seed_from_u64(seed);
-
-        let delay_ticks = rng.gen_range(1200..3000); // 60..150 seconds at 20 TPS
-        self.weather_next_change_tick = self.sim_tick.advance(delay_ticks);
-
-        let target_state = match self.weather.state {
-            WeatherState::Clear => {
-                if rng.gen_bool(0.55) {
-                    if rng.gen_bool(0.12) {
-                        WeatherState::Thunderstorm
-                    } else {
-                        WeatherState::Precipitation
-                    }
-                } else {
-                    WeatherState::Clear
-                }
-            }
-            WeatherState::Precipitation => {
-                let roll: f32 = rng.gen();
-                if roll < 0.70 {
-                    WeatherState::Clear
-                } else if roll < 0.82 {
-                    WeatherState::Thunderstorm
-                } else {
-                    WeatherState::Precipitation
-                }
-            }
-            WeatherState::Thunderstorm => {
-                let roll: f32 = rng.gen();
-                if roll < 0.58 {
-                    WeatherState::Precipitation
-                } else if roll < 0.76 {
-                    WeatherState::Thunderstorm
-                } else {
-                    WeatherState::Clear
-                }
-            }
-        };
-
-        if target_state != self.weather.state {
-            let previous = self.weather.state;
-            self.weather.set_state(target_state);
-            tracing::info!(?previous, ?target_state, "Weather changed");
+        if let Some(change) = self.weather.tick(self.sim_tick, self.world_seed) {
+            tracing::info!(previous = ?change.from, target_state = ?change.to, "Weather changed");
         }
     }
 
@@ -6710,6 +5970,7 @@ impl GameWorld {
         if self.active_dimension == DimensionId::Overworld && self.weather.is_precipitating() {
             return dirty_chunks;
         }
+        let (edit_rules, spawn) = self.active_edit_rules();
 
         let camera_pos = self.renderer.camera().position;
         let base_x = camera_pos.x.floor() as i32;
@@ -6720,16 +5981,7 @@ impl GameWorld {
             ^ 0x4649_5245_5449_434B_u64; // "FIRETICK"
         let mut rng = StdRng::seed_from_u64(seed);
 
-        let chunk_and_local = |pos: IVec3| -> Option<(ChunkPos, usize, usize, usize)> {
-            let local_y = world_y_to_local_y(pos.y)?;
-            let chunk_pos = ChunkPos::new(
-                pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-            );
-            let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-            let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-            Some((chunk_pos, local_x, local_y, local_z))
-        };
+        let chunk_and_local = |pos: IVec3| chunk_local_coords(pos.x, pos.y, pos.z);
 
         for _attempt in 0..FIRE_TICK_ATTEMPTS_PER_INTERVAL {
             let x = base_x + rng.gen_range(-FIRE_TICK_RADIUS_BLOCKS..=FIRE_TICK_RADIUS_BLOCKS);
@@ -6840,6 +6092,13 @@ impl GameWorld {
                 }
 
                 let target_pos = IVec3::new(fire_pos.x + dx, fire_pos.y, fire_pos.z + dz);
+                // Fire doesn't spread into protected ground or past the build limit.
+                if edit_rules
+                    .check(EditKind::Place, target_pos, spawn, false)
+                    .is_err()
+                {
+                    continue;
+                }
                 let target_support = IVec3::new(target_pos.x, target_pos.y - 1, target_pos.z);
                 let Some(target_support_voxel) = self.get_voxel_at(target_support) else {
                     continue;
@@ -6967,7 +6226,7 @@ impl GameWorld {
     }
 
     fn update_weather(&mut self, dt: f32) {
-        if self.weather.is_precipitating()
+        if self.weather.intensity() > 0.0
             && self.active_dimension == DimensionId::Overworld
             && !self.is_camera_underwater(self.renderer.camera().position)
         {
@@ -6975,15 +6234,6 @@ impl GameWorld {
         } else {
             self.precipitation_accumulator = 0.0;
         }
-
-        // Smoothly blend precipitation intensity for shaders/HUD
-        let target = if self.weather.is_precipitating() {
-            1.0
-        } else {
-            0.0
-        };
-        let smoothing = 1.0 - (-dt * 3.0).exp();
-        self.weather_blend += (target - self.weather_blend) * smoothing;
     }
 
     fn spawn_precipitation_particles(&mut self, dt: f32) {
@@ -7000,7 +6250,7 @@ impl GameWorld {
             return;
         }
 
-        self.precipitation_accumulator += PRECIPITATION_SPAWN_RATE * dt;
+        self.precipitation_accumulator += PRECIPITATION_SPAWN_RATE * self.weather.intensity() * dt;
         let elevation_bias = ((camera_pos.y - 64.0).max(0.0) / 600.0).clamp(0.0, 1.0);
         let effective_temperature = biome.temperature - elevation_bias;
         let is_snow = effective_temperature < 0.25;
@@ -7306,6 +6556,7 @@ impl GameWorld {
     #[cfg(feature = "ui3d_billboards")]
     fn populate_billboards(&mut self) {
         self.billboard_emitter.clear();
+        let camera_pos = self.renderer.camera().position;
 
         if let Some(hit) = self.selected_block {
             let pos = glam::Vec3::new(
@@ -7382,7 +6633,36 @@ impl GameWorld {
                     ..Default::default()
                 },
             );
+
+            let label = self
+                .mob_labels
+                .entry(mob.id)
+                .or_insert_with(|| EntityLabel::new(format!("{:?}", mob.mob_type), height + 0.5));
+            label.update(
+                glam::Vec3::new(mob.x as f32, mob.y as f32, mob.z as f32),
+                mob.health,
+                mob.mob_type.max_health(),
+                camera_pos,
+            );
+
+            let bar = label.health_bar();
+            if bar.is_visible() {
+                self.billboard_emitter.submit(
+                    2,
+                    BillboardInstance {
+                        flags: BillboardFlags::EMISSIVE.bits(),
+                        ..BillboardInstance::from_billboard(bar)
+                    },
+                );
+            }
         }
+
+        let active_dimension = self.active_dimension;
+        let mobs = &self.mobs;
+        self.mob_labels.retain(|id, _| {
+            mobs.iter()
+                .any(|mob| mob.id == *id && !mob.dead && mob.dimension == active_dimension)
+        });
     }
 
     fn current_control_mode(&self) -> ControlMode {
@@ -7446,9 +6726,7 @@ impl GameWorld {
             0
         };
 
-        let mut fall_damage: Option<f32> = None;
-
-        {
+        let landing = {
             let physics = &mut self.player_physics;
 
             // Handle double-jump to toggle fly mode
@@ -7471,52 +6749,6 @@ impl GameWorld {
                 physics.last_jump_press_time += dt;
             }
 
-            let on_ladder = Self::aabb_touches_ladder(&self.chunks, &physics.get_aabb(camera_pos));
-            if on_ladder {
-                // Vanilla-ish: ladders cancel gravity and clamp vertical speed.
-                let climb_speed = 3.0;
-                if actions.jump {
-                    physics.velocity.y = climb_speed;
-                } else if actions.crouch {
-                    physics.velocity.y = -climb_speed;
-                } else {
-                    physics.velocity.y = physics.velocity.y.clamp(-1.0, 0.0);
-                }
-            } else if in_water || in_lava {
-                let (swim_speed, gravity_multiplier, terminal_velocity, drag) = if in_lava {
-                    (2.0, 0.1, -2.0, 0.85)
-                } else {
-                    (4.0, 0.2, -4.0, 0.9)
-                };
-
-                // Vanilla-ish: fluids allow swimming up/down (simplified).
-                if actions.jump {
-                    physics.velocity.y = swim_speed;
-                } else if actions.crouch {
-                    physics.velocity.y = -swim_speed;
-                } else {
-                    physics.velocity.y += physics.gravity * gravity_multiplier * dt;
-                    physics.velocity.y = physics.velocity.y.max(terminal_velocity);
-                }
-
-                physics.velocity.y *= drag;
-            } else {
-                // Apply gravity
-                let mut gravity = physics.gravity;
-                let mut terminal_velocity = physics.terminal_velocity;
-                if self.status_effects.has(StatusEffectType::SlowFalling)
-                    && physics.velocity.y < 0.0
-                {
-                    gravity *= 0.2;
-                    terminal_velocity = -3.0;
-                }
-
-                physics.velocity.y += gravity * dt;
-                if physics.velocity.y < terminal_velocity {
-                    physics.velocity.y = terminal_velocity;
-                }
-            }
-
             // Calculate horizontal movement
             let mut axis = glam::Vec2::new(actions.move_x, actions.move_y);
             if axis.length_squared() > 1.0 {
@@ -7536,81 +6768,46 @@ impl GameWorld {
                 move_speed *= (base + bonus).min(1.0);
             }
 
-            // Build movement velocity vector
-            let mut move_velocity = glam::Vec3::ZERO;
-            if axis.length_squared() > 0.0 {
-                let move_dir = forward_h * axis.y + right_h * axis.x;
-                move_velocity = move_dir * move_speed * dt;
-            }
-            move_velocity.y = physics.velocity.y * dt;
-
-            // Get current AABB and apply movement with collision
-            let current_aabb = physics.get_aabb(camera_pos);
-            let (offset, new_velocity) = Self::move_with_collision(
-                &self.chunks,
-                &self.block_properties,
-                &current_aabb,
-                move_velocity,
-                PlayerPhysics::STEP_HEIGHT,
-            );
-
-            camera_pos += offset;
-
-            // Update velocity based on collision results (for Y axis mainly)
-            let was_on_ground = physics.on_ground;
-            let was_falling = physics.velocity.y < 0.0;
-
-            if new_velocity.y == 0.0 && physics.velocity.y != 0.0 {
-                // We hit something vertically
-                if was_falling {
-                    // Hit ground
-                    let player_aabb = physics.get_aabb(camera_pos);
-                    let ground_y = player_aabb.min.y;
-
-                    if !was_on_ground {
-                        fall_damage = Some(physics.last_ground_y - ground_y);
-                    }
-
-                    physics.on_ground = true;
-                    physics.last_ground_y = ground_y;
-                }
-                physics.velocity.y = 0.0;
+            let fluid = if in_lava {
+                Some(FluidType::Lava)
+            } else if in_water {
+                Some(FluidType::Water)
             } else {
-                physics.on_ground = false;
-            }
+                None
+            };
+            let jump_multiplier = self
+                .status_effects
+                .amplifier(StatusEffectType::JumpBoost)
+                .map(|amp| 1.0 + 0.2 * (amp + 1) as f32)
+                .unwrap_or(1.0);
+            let intent = MoveIntent {
+                horizontal: (forward_h * axis.y + right_h * axis.x) * move_speed,
+                jump: actions.jump,
+                descend: actions.crouch,
+                fluid,
+                slow_falling: self.status_effects.has(StatusEffectType::SlowFalling),
+                jump_multiplier,
+            };
 
-            // Check if standing on ground (for jump detection)
-            let feet_check_aabb = physics
-                .get_aabb(camera_pos)
-                .offset(glam::Vec3::new(0.0, -0.1, 0.0));
-            if Self::aabb_collides_with_world(
+            let result = physics.controller.step(
+                camera_pos,
+                intent,
                 &self.chunks,
                 &self.block_properties,
-                &feet_check_aabb,
-            ) {
-                physics.on_ground = true;
-                physics.last_ground_y = physics.get_aabb(camera_pos).min.y;
-            }
-
-            if actions.jump && physics.on_ground && !in_water && !in_lava {
-                let jump_boost = self
-                    .status_effects
-                    .amplifier(StatusEffectType::JumpBoost)
-                    .map(|amp| 1.0 + 0.2 * (amp + 1) as f32)
-                    .unwrap_or(1.0);
-                physics.velocity.y = physics.jump_strength * jump_boost;
-                physics.on_ground = false;
-            }
-        }
+                dt,
+            );
+            camera_pos = result.position;
+            result.landing
+        };
 
-        if let Some(dist) = fall_damage {
+        if let Some(landing) = landing {
             let (eye_fluid, feet_fluid) = self.camera_eye_and_feet_fluids(camera_pos);
             let in_water =
                 eye_fluid == Some(FluidType::Water) || feet_fluid == Some(FluidType::Water);
             if in_water {
                 // Vanilla-ish: landing in water negates fall damage.
             } else if !self.status_effects.has(StatusEffectType::SlowFalling) {
-                self.calculate_fall_damage(dist);
+                self.calculate_fall_damage(landing.distance);
             }
         }
 
@@ -7653,8 +6850,8 @@ impl GameWorld {
             let velocity = movement.normalize() * speed * dt;
 
             // Apply collision detection for fly mode (like original Minecraft)
-            let current_aabb = self.player_physics.get_aabb(position);
-            let (offset, _) = Self::move_with_collision(
+            let current_aabb = self.player_physics.controller.aabb(position);
+            let (offset, _) = mdminecraft_world::move_with_collision(
                 &self.chunks,
                 &self.block_properties,
                 &current_aabb,
@@ -7702,7 +6899,7 @@ impl GameWorld {
         self.update_dropped_items();
 
         // Update furnaces
-        self.update_furnaces(dt);
+        self.update_furnaces();
 
         // Update brewing stands
         self.update_brewing_stands(dt);
@@ -7777,6 +6974,25 @@ impl GameWorld {
         // Update redstone
         self.update_pressure_plates();
         self.redstone_sim.tick(&mut self.chunks);
+        for event in self.redstone_sim.drain_events() {
+            let RedstoneEvent::ComponentToggled { pos } = event else {
+                continue;
+            };
+            // Only the parts a player handles click; wires, lamps and torches stay quiet.
+            let Some(voxel) = self.get_voxel_at(IVec3::new(pos.x, pos.y, pos.z)) else {
+                continue;
+            };
+            let player_facing = matches!(
+                voxel.id,
+                mdminecraft_world::redstone_blocks::LEVER
+                    | mdminecraft_world::redstone_blocks::STONE_BUTTON
+                    | mdminecraft_world::redstone_blocks::OAK_BUTTON
+            ) || mdminecraft_world::is_door_lower(voxel.id);
+            if player_facing {
+                let center = [pos.x as f32 + 0.5, pos.y as f32 + 0.5, pos.z as f32 + 0.5];
+                self.audio.play_sfx_at(SoundId::BlockHit, center);
+            }
+        }
         let dirty_redstone_geometry_positions = self.redstone_sim.take_dirty_geometry_positions();
         let dirty_redstone_geometry_chunks = self.redstone_sim.take_dirty_geometry_chunks();
         let dirty_redstone = self.redstone_sim.take_dirty_chunks();
@@ -7868,7 +7084,8 @@ impl GameWorld {
         let in_water = eye_in_water || feet_fluid == Some(FluidType::Water);
         let in_lava = eye_fluid == Some(FluidType::Lava) || feet_fluid == Some(FluidType::Lava);
         let in_fire = {
-            let feet_pos = camera_pos - glam::Vec3::new(0.0, self.player_physics.eye_height, 0.0);
+            let feet_pos =
+                camera_pos - glam::Vec3::new(0.0, self.player_physics.controller.eye_height, 0.0);
             let feet_sample = feet_pos + glam::Vec3::new(0.0, 0.1, 0.0);
             let eye_block = IVec3::new(
                 camera_pos.x.floor() as i32,
@@ -8023,7 +7240,8 @@ impl GameWorld {
             }
         }
 
-        let feet_pos = camera_pos - glam::Vec3::new(0.0, self.player_physics.eye_height, 0.0);
+        let feet_pos =
+            camera_pos - glam::Vec3::new(0.0, self.player_physics.controller.eye_height, 0.0);
         let feet_sample = feet_pos + glam::Vec3::new(0.0, 0.1, 0.0);
         let feet_block = IVec3::new(
             feet_sample.x.floor() as i32,
@@ -8052,7 +7270,8 @@ impl GameWorld {
             }
         }
 
-        let feet_pos = camera_pos - glam::Vec3::new(0.0, self.player_physics.eye_height, 0.0);
+        let feet_pos =
+            camera_pos - glam::Vec3::new(0.0, self.player_physics.controller.eye_height, 0.0);
         let feet_sample = feet_pos + glam::Vec3::new(0.0, 0.1, 0.0);
         let feet_block = IVec3::new(
             feet_sample.x.floor() as i32,
@@ -8094,12 +7313,12 @@ impl GameWorld {
         self.switch_dimension(target)?;
 
         if let Some(feet) = self.ensure_or_create_portal_near(target_x, target_z, preferred_axis) {
-            let eye_height = self.player_physics.eye_height;
+            let eye_height = self.player_physics.controller.eye_height;
             let camera = self.renderer.camera_mut();
             camera.position = feet + glam::Vec3::new(0.0, eye_height, 0.0);
-            self.player_physics.velocity = glam::Vec3::ZERO;
-            self.player_physics.on_ground = false;
-            self.player_physics.last_ground_y = feet.y;
+            self.player_physics.controller.velocity = glam::Vec3::ZERO;
+            self.player_physics.controller.on_ground = false;
+            self.player_physics.controller.fall.reset();
         }
 
         Ok(())
@@ -8113,7 +7332,7 @@ impl GameWorld {
             _ => return Ok(()),
         };
 
-        let eye_height = self.player_physics.eye_height;
+        let eye_height = self.player_physics.controller.eye_height;
         if target == DimensionId::End {
             self.renderer.camera_mut().position = glam::Vec3::new(0.0, 80.0 + eye_height, 0.0);
         } else {
@@ -8132,7 +7351,7 @@ impl GameWorld {
         preferred_axis: PortalAxis,
     ) -> Option<glam::Vec3> {
         let camera_pos = self.renderer.camera().position;
-        let center_y = (camera_pos.y - self.player_physics.eye_height)
+        let center_y = (camera_pos.y - self.player_physics.controller.eye_height)
             .floor()
             .clamp(WORLD_MIN_Y as f32, WORLD_MAX_Y as f32) as i32;
         let center = IVec3::new(target_x.floor() as i32, center_y, target_z.floor() as i32);
@@ -8151,7 +7370,7 @@ impl GameWorld {
 
             return Some(glam::Vec3::new(
                 bottom.x as f32 + 0.5,
-                bottom.y as f32 + PlayerPhysics::GROUND_EPS,
+                bottom.y as f32 + PlayerController::GROUND_EPS,
                 bottom.z as f32 + 0.5,
             ));
         }
@@ -8159,7 +7378,7 @@ impl GameWorld {
         let spawn_feet = self
             .find_safe_spawn_near(target_x, target_z)
             .unwrap_or_else(|| {
-                camera_pos - glam::Vec3::new(0.0, self.player_physics.eye_height, 0.0)
+                camera_pos - glam::Vec3::new(0.0, self.player_physics.controller.eye_height, 0.0)
             });
 
         let base_x = spawn_feet.x.floor() as i32;
@@ -8193,12 +7412,12 @@ impl GameWorld {
                         return Some(match axis {
                             PortalAxis::X => glam::Vec3::new(
                                 origin.x as f32 + 1.5,
-                                origin.y as f32 + 1.0 + PlayerPhysics::GROUND_EPS,
+                                origin.y as f32 + 1.0 + PlayerController::GROUND_EPS,
                                 origin.z as f32 + 0.5,
                             ),
                             PortalAxis::Z => glam::Vec3::new(
                                 origin.x as f32 + 0.5,
-                                origin.y as f32 + 1.0 + PlayerPhysics::GROUND_EPS,
+                                origin.y as f32 + 1.0 + PlayerController::GROUND_EPS,
                                 origin.z as f32 + 1.5,
                             ),
                         });
@@ -8258,16 +7477,7 @@ impl GameWorld {
 
         let set_voxel =
             |chunks: &mut HashMap<ChunkPos, Chunk>, pos: IVec3, voxel: Voxel| -> Option<()> {
-                let chunk_pos = ChunkPos::new(
-                    pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                    pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-                );
-                let chunk = chunks.get_mut(&chunk_pos)?;
-                let local_y = world_y_to_local_y(pos.y)?;
-                let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-                let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-                chunk.set_voxel(local_x, local_y, local_z, voxel);
-                Some(())
+                chunks.set_voxel_at(pos.x, pos.y, pos.z, voxel).then_some(())
             };
 
         let check_pos = |pos: IVec3| -> Option<()> {
@@ -8378,7 +7588,8 @@ impl GameWorld {
         for pos in self.chunks.keys() {
             let dx = pos.x - center_chunk_x;
             let dz = pos.z - center_chunk_z;
-            if dx * dx + dz * dz > (radius + 2) * (radius + 2) {
+            let keep = radius + CHUNK_UNLOAD_MARGIN;
+            if dx * dx + dz * dz > keep * keep {
                 chunks_to_unload.push(*pos);
             }
         }
@@ -8387,10 +7598,10 @@ impl GameWorld {
         for pos in chunks_to_unload {
             self.crop_growth.unregister_chunk(pos);
             self.sugar_cane_growth.unregister_chunk(pos);
-            if let Some(chunk) = self.chunks.remove(&pos) {
+            if let Some(mut chunk) = self.chunks.remove(&pos) {
                 if let Err(e) = self
                     .region_store
-                    .save_chunk_in_dimension(self.active_dimension, &chunk)
+                    .save_chunk_in_dimension(self.active_dimension, &mut chunk)
                 {
                     tracing::error!("Failed to save chunk {:?}: {}", pos, e);
                 }
@@ -8586,12 +7797,8 @@ impl GameWorld {
             for base in sugar_cane_bases_to_register {
                 self.sugar_cane_growth.register_base(base);
             }
-            self.init_chunk_skylight(pos);
-            let affected = mdminecraft_world::recompute_block_light_local(
-                &mut self.chunks,
-                &self.registry,
-                pos,
-            );
+            let affected =
+                LightPropagator::new(&self.registry).propagate_all(&mut self.chunks, [pos]);
             let mut mesh_refresh = std::collections::BTreeSet::new();
             mesh_refresh.insert(pos);
             mesh_refresh.extend(Self::neighbor_chunk_positions(pos));
@@ -9037,7 +8244,10 @@ impl GameWorld {
         let camera = self.renderer.camera();
         self.debug_hud.camera_pos = [camera.position.x, camera.position.y, camera.position.z];
         self.debug_hud.camera_rot = [camera.yaw, camera.pitch];
-        self.debug_hud.chunks_visible = self.chunks_visible;
+        self.debug_hud.chunks_loaded = self.chunk_stats.chunks_total;
+        self.debug_hud.chunks_visible = self.chunk_stats.chunks_drawn;
+        self.debug_hud.chunks_culled = self.chunk_stats.chunks_culled;
+        self.debug_hud.total_triangles = self.chunk_stats.triangles as usize;
         self.debug_hud.control_mode = self.current_control_mode();
         self.debug_hud.cursor_captured = self.input.cursor_captured;
         self.debug_hud.mouse_sensitivity = self.controls.mouse_sensitivity;
@@ -9048,6 +8258,7 @@ impl GameWorld {
         if self.player_state == PlayerState::Alive {
             self.update_camera(self.frame_dt);
         }
+        self.sync_audio_listener();
 
         // Raycast for block selection (only if alive)
         if self.input.cursor_captured && self.player_state == PlayerState::Alive {
@@ -9082,6 +8293,15 @@ impl GameWorld {
         self.render();
     }
 
+    /// Move the audio listener to the camera so positional sounds pan and fade
+    /// relative to where the player is looking this frame.
+    fn sync_audio_listener(&mut self) {
+        let camera = self.renderer.camera();
+        self.audio.set_listener_position(camera.position.to_array());
+        self.audio
+            .set_listener_orientation(camera.forward().to_array());
+    }
+
     fn update_camera(&mut self, dt: f32) {
         let actions = self.actions.clone();
 
@@ -9238,8 +8458,13 @@ impl GameWorld {
         }
 
         if let Some(hit) = self.selected_block {
-            // Left click/hold: mine block
-            if self.input.is_mouse_pressed(MouseButton::Left) {
+            // Left click/hold: mine block (creative breaks one block per click)
+            let mining_input = if self.gamemode == commands::Gamemode::Creative {
+                self.input.is_mouse_clicked(MouseButton::Left)
+            } else {
+                self.input.is_mouse_pressed(MouseButton::Left)
+            };
+            if mining_input {
                 self.handle_mining(hit, dt);
             } else {
                 // Reset mining progress if not holding left click
@@ -9260,7 +8485,7 @@ impl GameWorld {
                         let old_piece = self.player_armor.equip(armor_piece);
 
                         // Consume the item from hotbar.
-                        let _ = self.hotbar.consume_selected();
+                        let _ = self.hotbar.consume_selected(self.gamemode);
                         equipped_armor = true;
                         tracing::info!("Equipped armor");
 
@@ -9284,13 +8509,13 @@ impl GameWorld {
                     // Check if we're holding food and try to eat it
                     let hunger_restore = food_hunger_restore(food_type);
                     if self.player_health.eat(hunger_restore) {
-                        self.hotbar.consume_selected();
+                        self.hotbar.consume_selected(self.gamemode);
                         // Skip other interactions when eating
                     }
                 } else if let Some(potion_id) = self.hotbar.selected_potion() {
                     // Check if we're holding a potion and try to drink it
-                    if self.drink_potion(potion_id) {
-                        self.hotbar.consume_selected();
+                    // Creative keeps the potion, so there is no bottle to hand back.
+                    if self.drink_potion(potion_id) && self.hotbar.consume_selected(self.gamemode) {
                         self.return_stack_to_storage_or_spill(ItemStack::new(
                             ItemType::Item(CORE_ITEM_GLASS_BOTTLE),
                             1,
@@ -9300,7 +8525,7 @@ impl GameWorld {
                 } else if let Some(potion_id) = self.hotbar.selected_splash_potion() {
                     // Check if we're holding a splash potion and throw it
                     self.throw_splash_potion(potion_id);
-                    self.hotbar.consume_selected();
+                    self.hotbar.consume_selected(self.gamemode);
                     // Skip other interactions when throwing
                 } else if self
                     .hotbar
@@ -9308,14 +8533,14 @@ impl GameWorld {
                     .is_some_and(|stack| stack.item_type == ItemType::Item(CORE_ITEM_ENDER_PEARL))
                 {
                     self.throw_ender_pearl();
-                    self.hotbar.consume_selected();
+                    self.hotbar.consume_selected(self.gamemode);
                 } else if self
                     .hotbar
                     .selected_item()
                     .is_some_and(|stack| stack.item_type == ItemType::Item(104))
                 {
                     self.throw_egg();
-                    self.hotbar.consume_selected();
+                    self.hotbar.consume_selected(self.gamemode);
                 } else if self.try_use_bucket(hit) {
                     // Skip other interactions when using buckets
                 } else if self.try_use_flint_and_steel(hit) {
@@ -9335,13 +8560,25 @@ impl GameWorld {
             return false;
         };
 
-        let Some((new_bucket_id, changed_positions)) = try_bucket_interaction(
+        let (rules, spawn) = self.active_edit_rules();
+        let is_op = self.player_is_op;
+        let mut checked = None;
+        let interaction = try_bucket_interaction(
             self.active_dimension,
             bucket_id,
             hit,
             &mut self.chunks,
             &mut self.fluid_sim,
-        ) else {
+            &mut |kind, pos| {
+                let result = rules.check(kind, pos, spawn, is_op);
+                checked = Some((pos, result));
+                result.is_ok()
+            },
+        );
+        if let Some((pos, result)) = checked {
+            self.record_edit_result(pos, result);
+        }
+        let Some((new_bucket_id, changed_positions)) = interaction else {
             return false;
         };
 
@@ -9377,6 +8614,9 @@ impl GameWorld {
         if world_y_to_local_y(ignite_pos.y).is_none() {
             return false;
         }
+        if !self.edit_allowed(EditKind::Place, ignite_pos) {
+            return true;
+        }
 
         if let Some(changed_positions) = try_activate_nether_portal(&mut self.chunks, ignite_pos) {
             if !changed_positions.is_empty() {
@@ -9609,7 +8849,7 @@ impl GameWorld {
             bed_pos.z as f32 + 0.5,
         );
         self.spawn_point_dimension = DimensionId::Overworld;
-        self.player_physics.last_ground_y = self.spawn_point.y;
+        self.player_physics.controller.fall.reset();
 
         // Advance simulation time to sunrise (time_of_day = 0.25).
         let ticks_per_day = self.sim_time.ticks_per_day.max(1);
@@ -9712,7 +8952,7 @@ impl GameWorld {
             anchor_pos.z as f32 + 0.5,
         );
         self.spawn_point_dimension = self.active_dimension;
-        self.player_physics.last_ground_y = self.spawn_point.y;
+        self.player_physics.controller.fall.reset();
         tracing::info!(
             "Set respawn point to respawn anchor at {:?} in {:?}",
             anchor_pos,
@@ -9838,6 +9078,15 @@ impl GameWorld {
             return false;
         }
 
+        let till_pos = IVec3::new(
+            chunk_pos.x * CHUNK_SIZE_X as i32 + local_x as i32,
+            local_y_to_world_y(local_y),
+            chunk_pos.z * CHUNK_SIZE_Z as i32 + local_z as i32,
+        );
+        if !self.edit_allowed(EditKind::Place, till_pos) {
+            return true;
+        }
+
         let Some(chunk) = self.chunks.get_mut(&chunk_pos) else {
             return false;
         };
@@ -9861,7 +9110,7 @@ impl GameWorld {
             }
         }
 
-        self.debug_hud.chunk_uploads_last_frame += self.upload_chunk_mesh_and_neighbors(chunk_pos);
+        self.renderer.request_remesh(chunk_pos);
         true
     }
 
@@ -9899,6 +9148,15 @@ impl GameWorld {
             return true;
         }
 
+        let plant_pos = IVec3::new(
+            chunk_pos.x * CHUNK_SIZE_X as i32 + local_x as i32,
+            local_y_to_world_y(local_y + 1),
+            chunk_pos.z * CHUNK_SIZE_Z as i32 + local_z as i32,
+        );
+        if !self.edit_allowed(EditKind::Place, plant_pos) {
+            return true;
+        }
+
         let Some(chunk) = self.chunks.get_mut(&chunk_pos) else {
             return true;
         };
@@ -9927,9 +9185,9 @@ impl GameWorld {
             z: local_z as u8,
         });
 
-        let _ = self.hotbar.consume_selected();
+        let _ = self.hotbar.consume_selected(self.gamemode);
 
-        self.debug_hud.chunk_uploads_last_frame += self.upload_chunk_mesh_and_neighbors(chunk_pos);
+        self.renderer.request_remesh(chunk_pos);
         true
     }
 
@@ -9956,13 +9214,13 @@ impl GameWorld {
             return false;
         }
 
-        let _ = self.hotbar.consume_selected();
+        let _ = self.hotbar.consume_selected(self.gamemode);
 
         let chunk_pos = ChunkPos::new(
             block_pos.x.div_euclid(CHUNK_SIZE_X as i32),
             block_pos.z.div_euclid(CHUNK_SIZE_Z as i32),
         );
-        self.debug_hud.chunk_uploads_last_frame += self.upload_chunk_mesh_and_neighbors(chunk_pos);
+        self.renderer.request_remesh(chunk_pos);
         true
     }
 
@@ -10160,8 +9418,7 @@ impl GameWorld {
                     RedstonePos::new(hit.block_pos.x, hit.block_pos.y, hit.block_pos.z),
                     &mut self.chunks,
                 );
-                self.debug_hud.chunk_uploads_last_frame +=
-                    self.upload_chunk_mesh_and_neighbors(chunk_pos);
+                self.renderer.request_remesh(chunk_pos);
                 true
             }
             Some(mdminecraft_world::redstone_blocks::STONE_BUTTON)
@@ -10170,8 +9427,7 @@ impl GameWorld {
                     RedstonePos::new(hit.block_pos.x, hit.block_pos.y, hit.block_pos.z),
                     &mut self.chunks,
                 );
-                self.debug_hud.chunk_uploads_last_frame +=
-                    self.upload_chunk_mesh_and_neighbors(chunk_pos);
+                self.renderer.request_remesh(chunk_pos);
                 true
             }
             Some(mdminecraft_world::redstone_blocks::REDSTONE_REPEATER) => {
@@ -10198,8 +9454,7 @@ impl GameWorld {
                 );
 
                 self.schedule_redstone_updates_around(hit.block_pos);
-                self.debug_hud.chunk_uploads_last_frame +=
-                    self.upload_chunk_mesh_and_neighbors(chunk_pos);
+                self.renderer.request_remesh(chunk_pos);
                 true
             }
             Some(mdminecraft_world::redstone_blocks::REDSTONE_COMPARATOR) => {
@@ -10226,15 +9481,57 @@ impl GameWorld {
                 );
 
                 self.schedule_redstone_updates_around(hit.block_pos);
-                self.debug_hud.chunk_uploads_last_frame +=
-                    self.upload_chunk_mesh_and_neighbors(chunk_pos);
+                self.renderer.request_remesh(chunk_pos);
                 true
             }
             _ => false,
         }
     }
 
+    /// Validate a player edit against spawn protection and the build limit.
+    ///
+    /// Records the denial reason so the HUD can explain why nothing happened.
+    fn edit_allowed(&mut self, kind: EditKind, pos: IVec3) -> bool {
+        let (rules, spawn) = self.active_edit_rules();
+        let result = rules.check(kind, pos, spawn, self.player_is_op);
+        self.record_edit_result(pos, result)
+    }
+
+    /// Edit rules for the active dimension and the spawn they protect.
+    ///
+    /// Spawn protection only applies in the dimension that holds the spawn point.
+    fn active_edit_rules(&self) -> (EditRules, IVec3) {
+        let mut rules = self.edit_rules;
+        if self.active_dimension != self.spawn_point_dimension {
+            rules.spawn_protection_radius = 0;
+        }
+        (rules, self.spawn_point.floor().as_ivec3())
+    }
+
+    /// Remember the outcome of an edit check for the HUD; returns whether it was allowed.
+    fn record_edit_result(&mut self, pos: IVec3, result: Result<(), EditDenied>) -> bool {
+        match result {
+            Ok(()) => {
+                self.last_edit_denied = None;
+                true
+            }
+            Err(reason) => {
+                if self.last_edit_denied.map(|(last, _)| last) != Some(reason) {
+                    tracing::info!("Edit denied at {:?}: {}", pos, reason);
+                }
+                self.last_edit_denied = Some((reason, Instant::now()));
+                false
+            }
+        }
+    }
+
     fn handle_mining(&mut self, hit: RaycastHit, dt: f32) {
+        if !self.edit_allowed(EditKind::Break, hit.block_pos) {
+            self.mining_progress = None;
+            self.debug_hud.mining_progress = None;
+            return;
+        }
+
         let chunk_x = hit.block_pos.x.div_euclid(16);
         let chunk_z = hit.block_pos.z.div_euclid(16);
         let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
@@ -10255,514 +9552,561 @@ impl GameWorld {
         // Get block properties
         let block_props = self.block_properties.get(block_id);
 
-        // Check if we're starting to mine a new block
-        let mining_new_block = self
+        let instant_break = self.gamemode == commands::Gamemode::Creative;
+        let mut progress = self.mining_progress.take();
+        let mining_complete =
+            Self::advance_mining(&mut progress, self.gamemode, hit.block_pos, dt, || {
+                self.block_mining_time(block_id, hit.block_pos)
+            });
+        self.mining_progress = progress;
+
+        self.debug_hud.mining_progress = self
             .mining_progress
             .as_ref()
-            .map(|p| p.block_pos != hit.block_pos)
-            .unwrap_or(true);
+            .map(|p| (p.time_mining / p.time_required * 100.0).min(100.0));
+
+        if !mining_complete {
+            return;
+        }
+
+        // Mine the block!
+        let mut spawn_particles_at: Option<glam::Vec3> = None;
+        let mut mined = false;
+        let mut removed_extra: Option<IVec3> = None;
+        let mut mined_block_state: Option<BlockState> = None;
+        let mut mined_voxel_before: Option<Voxel> = None;
+        let mut left_water = false;
+
+        if let Some(chunk) = self.chunks.get_mut(&chunk_pos) {
+            let local_x = hit.block_pos.x.rem_euclid(16) as usize;
+            let Some(local_y) = world_y_to_local_y(hit.block_pos.y) else {
+                return;
+            };
+            let local_z = hit.block_pos.z.rem_euclid(16) as usize;
 
-        if mining_new_block {
-            // Calculate mining time based on tool and block properties
+            // Check if tool can harvest this block (creative ignores harvest level)
             let tool = self.hotbar.selected_tool();
-            let (eye_fluid, feet_fluid) =
-                self.camera_eye_and_feet_fluids(self.renderer.camera().position);
-            let in_water =
-                eye_fluid == Some(FluidType::Water) || feet_fluid == Some(FluidType::Water);
-            let has_aqua_affinity = self
-                .player_armor
-                .total_enchantment_level(EnchantmentType::AquaAffinity)
-                > 0;
-            let apply_water_penalty = in_water && !has_aqua_affinity;
-
-            let mut mining_time = block_props.calculate_mining_time(tool, apply_water_penalty);
-
-            // Apply Efficiency enchantment bonus
-            // Each level of Efficiency adds 26% mining speed (Minecraft formula: (level^2 + 1) bonus)
-            // Simplified: multiply speed by 1 + (0.26 * level)
-            if let Some(item) = self.hotbar.selected_item() {
-                let efficiency_level = item.enchantment_level(EnchantmentType::Efficiency);
-                if efficiency_level > 0 {
-                    let speed_bonus = 1.0 + 0.26 * efficiency_level as f32;
-                    mining_time /= speed_bonus;
-                    tracing::debug!(
-                        "Efficiency {} applied: speed bonus {:.0}%",
-                        efficiency_level,
-                        (speed_bonus - 1.0) * 100.0
-                    );
-                }
+            let can_harvest = instant_break || block_props.can_harvest(tool);
+
+            if can_harvest {
+                tracing::info!(
+                    "Successfully mined block {} at {:?}",
+                    block_id,
+                    hit.block_pos
+                );
+            } else {
+                tracing::warn!(
+                    "Mined block {} but cannot harvest (wrong tool tier)",
+                    block_id
+                );
             }
 
-            self.mining_progress = Some(MiningProgress {
-                block_pos: hit.block_pos,
-                time_mining: 0.0,
-                time_required: mining_time,
+            let mined_voxel = chunk.voxel(local_x, local_y, local_z);
+            mined_block_state = Some(mined_voxel.state);
+            mined_voxel_before = Some(mined_voxel);
+
+            left_water = self.active_dimension != DimensionId::Nether
+                && mdminecraft_world::block_supports_waterlogging(mined_voxel.id)
+                && mdminecraft_world::is_waterlogged(mined_voxel.state);
+
+            // Remove the block (leave water behind when waterlogged).
+            if left_water {
+                chunk.set_voxel(
+                    local_x,
+                    local_y,
+                    local_z,
+                    Voxel {
+                        id: FluidType::Water.source_block_id(),
+                        state: 0,
+                        light_sky: 0,
+                        light_block: 0,
+                    },
+                );
+            } else {
+                chunk.set_voxel(local_x, local_y, local_z, Voxel::default());
+            }
+
+            if mdminecraft_world::CropType::is_crop(block_id) {
+                self.crop_growth.unregister_crop(CropPosition {
+                    chunk: chunk_pos,
+                    x: local_x as u8,
+                    y: local_y as u8,
+                    z: local_z as u8,
+                });
+            }
+
+            removed_extra = Self::try_remove_other_door_half(
+                chunk, local_x, local_y, local_z, block_id,
+            )
+            .map(|other_local_y| {
+                IVec3::new(
+                    hit.block_pos.x,
+                    local_y_to_world_y(other_local_y),
+                    hit.block_pos.z,
+                )
             });
+            spawn_particles_at = Some(glam::Vec3::new(
+                hit.block_pos.x as f32 + 0.5,
+                hit.block_pos.y as f32 + 0.5,
+                hit.block_pos.z as f32 + 0.5,
+            ));
+            mined = true;
+        }
 
-            tracing::debug!(
-                "Started mining block {} at {:?} (requires {:.2}s)",
+        if mined && removed_extra.is_none() && mdminecraft_world::is_bed(block_id) {
+            if let Some(state) = mined_block_state {
+                removed_extra = Self::try_remove_other_bed_half(
+                    &mut self.chunks,
+                    hit.block_pos,
+                    block_id,
+                    state,
+                );
+            }
+        }
+
+        if mined
+            && removed_extra.is_none()
+            && matches!(
                 block_id,
-                hit.block_pos,
-                mining_time
-            );
+                mdminecraft_world::mechanical_blocks::PISTON
+                    | mdminecraft_world::mechanical_blocks::PISTON_HEAD
+            )
+        {
+            if let Some(state) = mined_block_state {
+                removed_extra = Self::try_remove_other_piston_part(
+                    &mut self.chunks,
+                    hit.block_pos,
+                    block_id,
+                    state,
+                );
+            }
         }
 
-        // Update mining progress
-        if let Some(progress) = &mut self.mining_progress {
-            progress.time_mining += dt;
+        if mined {
+            // Notify fluid sim to update neighbors.
+            if left_water {
+                self.fluid_sim.on_fluid_placed(
+                    FluidPos::new(hit.block_pos.x, hit.block_pos.y, hit.block_pos.z),
+                    FluidType::Water,
+                );
+            } else {
+                self.fluid_sim.on_fluid_removed(
+                    FluidPos::new(hit.block_pos.x, hit.block_pos.y, hit.block_pos.z),
+                    &self.chunks,
+                );
+            }
+            if let Some(extra) = removed_extra {
+                self.fluid_sim
+                    .on_fluid_removed(FluidPos::new(extra.x, extra.y, extra.z), &self.chunks);
+            }
 
-            let percent = (progress.time_mining / progress.time_required * 100.0).min(100.0);
-            self.debug_hud.mining_progress = Some(percent);
+            // Damage tool durability (creative tools never wear out)
+            let held = self.hotbar.selected_item_mut().filter(|_| !instant_break);
+            if let Some(item) = held {
+                if matches!(item.item_type, ItemType::Tool(_, _)) {
+                    item.damage_durability(1);
+                    if item.is_broken() {
+                        tracing::info!("Tool broke!");
+                        // Remove the broken tool
+                        self.hotbar.slots[self.hotbar.selected] = None;
+                    }
+                }
+            }
+        }
 
-            // Check if mining is complete
-            if progress.time_mining >= progress.time_required {
-                // Mine the block!
-                let mut spawn_particles_at: Option<glam::Vec3> = None;
-                let mut mined = false;
-                let mut removed_extra: Option<IVec3> = None;
-                let mut mined_block_state: Option<BlockState> = None;
-                let mut left_water = false;
+        if mined {
+            self.on_block_entity_removed(hit.block_pos, block_id);
 
-                if let Some(chunk) = self.chunks.get_mut(&chunk_pos) {
-                    let local_x = hit.block_pos.x.rem_euclid(16) as usize;
-                    let Some(local_y) = world_y_to_local_y(hit.block_pos.y) else {
-                        return;
-                    };
-                    let local_z = hit.block_pos.z.rem_euclid(16) as usize;
+            let mut changed_positions = vec![hit.block_pos];
+            if let Some(extra) = removed_extra {
+                changed_positions.push(extra);
+            }
 
-                    // Check if tool can harvest this block
-                    let tool = self.hotbar.selected_tool();
-                    let can_harvest = block_props.can_harvest(tool);
+            let support_changes = Self::remove_unsupported_blocks(
+                &mut self.chunks,
+                &self.block_properties,
+                changed_positions,
+            );
 
-                    if can_harvest {
-                        tracing::info!(
-                            "Successfully mined block {} at {:?}",
-                            block_id,
-                            hit.block_pos
-                        );
-                    } else {
-                        tracing::warn!(
-                            "Mined block {} but cannot harvest (wrong tool tier)",
-                            block_id
-                        );
-                    }
+            for (pos, removed_block_id) in &support_changes.removed {
+                if !mdminecraft_world::CropType::is_crop(*removed_block_id) {
+                    continue;
+                }
 
-                    let mined_voxel = chunk.voxel(local_x, local_y, local_z);
-                    mined_block_state = Some(mined_voxel.state);
+                let removed_chunk = ChunkPos::new(
+                    pos.x.div_euclid(CHUNK_SIZE_X as i32),
+                    pos.z.div_euclid(CHUNK_SIZE_Z as i32),
+                );
+                self.crop_growth.unregister_crop(CropPosition {
+                    chunk: removed_chunk,
+                    x: pos.x.rem_euclid(CHUNK_SIZE_X as i32) as u8,
+                    y: pos.y as u8,
+                    z: pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as u8,
+                });
+            }
 
-                    left_water = self.active_dimension != DimensionId::Nether
-                        && mdminecraft_world::block_supports_waterlogging(mined_voxel.id)
-                        && mdminecraft_world::is_waterlogged(mined_voxel.state);
+            // Notify redstone sim for any neighbor-dependent updates.
+            self.schedule_redstone_updates_around(hit.block_pos);
+            if let Some(extra) = removed_extra {
+                self.schedule_redstone_updates_around(extra);
+            }
+            for (pos, _) in &support_changes.removed {
+                self.schedule_redstone_updates_around(*pos);
+            }
+            for pos in &support_changes.moved {
+                self.schedule_redstone_updates_around(*pos);
+            }
 
-                    // Remove the block (leave water behind when waterlogged).
-                    if left_water {
-                        chunk.set_voxel(
-                            local_x,
-                            local_y,
-                            local_z,
-                            Voxel {
-                                id: FluidType::Water.source_block_id(),
-                                state: 0,
-                                light_sky: 0,
-                                light_block: 0,
-                            },
-                        );
-                    } else {
-                        chunk.set_voxel(local_x, local_y, local_z, Voxel::default());
-                    }
+            let mut affected_chunks = std::collections::BTreeSet::new();
+            affected_chunks.insert(chunk_pos);
+            if let Some(extra) = removed_extra {
+                affected_chunks.insert(ChunkPos::new(
+                    extra.x.div_euclid(CHUNK_SIZE_X as i32),
+                    extra.z.div_euclid(CHUNK_SIZE_Z as i32),
+                ));
+            }
+            for (pos, removed_block_id) in &support_changes.removed {
+                self.on_block_entity_removed(*pos, *removed_block_id);
 
-                    if mdminecraft_world::CropType::is_crop(block_id) {
-                        self.crop_growth.unregister_crop(CropPosition {
-                            chunk: chunk_pos,
-                            x: local_x as u8,
-                            y: local_y as u8,
-                            z: local_z as u8,
-                        });
-                    }
+                affected_chunks.insert(ChunkPos::new(
+                    pos.x.div_euclid(CHUNK_SIZE_X as i32),
+                    pos.z.div_euclid(CHUNK_SIZE_Z as i32),
+                ));
 
-                    removed_extra = Self::try_remove_other_door_half(
-                        chunk, local_x, local_y, local_z, block_id,
-                    )
-                    .map(|other_local_y| {
-                        IVec3::new(
-                            hit.block_pos.x,
-                            local_y_to_world_y(other_local_y),
-                            hit.block_pos.z,
-                        )
-                    });
-                    spawn_particles_at = Some(glam::Vec3::new(
-                        hit.block_pos.x as f32 + 0.5,
-                        hit.block_pos.y as f32 + 0.5,
-                        hit.block_pos.z as f32 + 0.5,
-                    ));
-                    mined = true;
-                }
+                self.fluid_sim
+                    .on_fluid_removed(FluidPos::new(pos.x, pos.y, pos.z), &self.chunks);
 
-                if mined && removed_extra.is_none() && mdminecraft_world::is_bed(block_id) {
-                    if let Some(state) = mined_block_state {
-                        removed_extra = Self::try_remove_other_bed_half(
-                            &mut self.chunks,
-                            hit.block_pos,
-                            block_id,
-                            state,
-                        );
-                    }
-                }
+                let should_drop = if mdminecraft_world::is_door_upper(*removed_block_id) {
+                    let lower_pos = IVec3::new(pos.x, pos.y - 1, pos.z);
+                    !support_changes.removed.iter().any(|(other_pos, other_id)| {
+                        *other_pos == lower_pos && mdminecraft_world::is_door_lower(*other_id)
+                    })
+                } else {
+                    *removed_block_id != interactive_blocks::BED_HEAD
+                };
 
-                if mined
-                    && removed_extra.is_none()
-                    && matches!(
-                        block_id,
-                        mdminecraft_world::mechanical_blocks::PISTON
-                            | mdminecraft_world::mechanical_blocks::PISTON_HEAD
-                    )
-                {
-                    if let Some(state) = mined_block_state {
-                        removed_extra = Self::try_remove_other_piston_part(
-                            &mut self.chunks,
-                            hit.block_pos,
-                            block_id,
-                            state,
-                        );
-                    }
-                }
+                if should_drop {
+                    let drop_x = pos.x as f64 + 0.5;
+                    let drop_y = pos.y as f64 + 0.5;
+                    let drop_z = pos.z as f64 + 0.5;
 
-                if mined {
-                    // Notify fluid sim to update neighbors.
-                    if left_water {
-                        self.fluid_sim.on_fluid_placed(
-                            FluidPos::new(hit.block_pos.x, hit.block_pos.y, hit.block_pos.z),
-                            FluidType::Water,
-                        );
-                    } else {
-                        self.fluid_sim.on_fluid_removed(
-                            FluidPos::new(hit.block_pos.x, hit.block_pos.y, hit.block_pos.z),
-                            &self.chunks,
-                        );
-                    }
-                    if let Some(extra) = removed_extra {
-                        self.fluid_sim.on_fluid_removed(
-                            FluidPos::new(extra.x, extra.y, extra.z),
-                            &self.chunks,
-                        );
-                    }
+                    if let Some(table) = self.loot_tables.block.get(removed_block_id) {
+                        let mut rng = {
+                            let pos_seed = (pos.x as u64)
+                                ^ ((pos.y as u64).rotate_left(21))
+                                ^ ((pos.z as u64).rotate_left(42));
+                            let seed = self.world_seed
+                                ^ self.sim_tick.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                                ^ pos_seed.wrapping_mul(0xD6E8_FEB8_6659_FD93)
+                                ^ 0x10B1_0B15_EA4E_102E_u64;
+                            StdRng::seed_from_u64(seed)
+                        };
 
-                    // Damage tool durability
-                    if let Some(item) = self.hotbar.selected_item_mut() {
-                        if matches!(item.item_type, ItemType::Tool(_, _)) {
-                            item.damage_durability(1);
-                            if item.is_broken() {
-                                tracing::info!("Tool broke!");
-                                // Remove the broken tool
-                                self.hotbar.slots[self.hotbar.selected] = None;
-                            }
+                        for (drop_type, count) in table.roll(&mut rng) {
+                            self.item_manager.spawn_item(
+                                self.active_dimension,
+                                drop_x,
+                                drop_y,
+                                drop_z,
+                                drop_type,
+                                count,
+                            );
                         }
+                    } else if let Some((drop_type, count)) =
+                        DroppedItemType::from_block(*removed_block_id)
+                    {
+                        self.item_manager.spawn_item(
+                            self.active_dimension,
+                            drop_x,
+                            drop_y,
+                            drop_z,
+                            drop_type,
+                            count,
+                        );
                     }
                 }
+            }
 
-                if mined {
-                    self.on_block_entity_removed(hit.block_pos, block_id);
+            for pos in &support_changes.moved {
+                affected_chunks.insert(ChunkPos::new(
+                    pos.x.div_euclid(CHUNK_SIZE_X as i32),
+                    pos.z.div_euclid(CHUNK_SIZE_Z as i32),
+                ));
+                self.fluid_sim
+                    .on_fluid_removed(FluidPos::new(pos.x, pos.y, pos.z), &self.chunks);
+            }
 
-                    let mut changed_positions = vec![hit.block_pos];
-                    if let Some(extra) = removed_extra {
-                        changed_positions.push(extra);
-                    }
+            let mut mesh_refresh = std::collections::BTreeSet::new();
+            let single_block_edit = removed_extra.is_none()
+                && support_changes.removed.is_empty()
+                && support_changes.moved.is_empty();
+            let light_pos = mdminecraft_world::lighting::BlockPos::from_world(
+                hit.block_pos.x,
+                hit.block_pos.y,
+                hit.block_pos.z,
+            );
+            if let (true, Some(old), Some(light_pos)) =
+                (single_block_edit, mined_voxel_before, light_pos)
+            {
+                // Only the mined voxel changed, so relight just the voxels it affected.
+                let new = self.get_voxel_at(hit.block_pos).unwrap_or_default();
+                mesh_refresh.insert(chunk_pos);
+                mesh_refresh.extend(Self::neighbor_chunk_positions(chunk_pos));
+                mesh_refresh.extend(LightPropagator::new(&self.registry).on_block_changed(
+                    &mut self.chunks,
+                    light_pos,
+                    old,
+                    new,
+                ));
+            } else {
+                for dirty_chunk in affected_chunks {
+                    mesh_refresh.extend(self.recompute_skylight_local(dirty_chunk));
+                    mesh_refresh.insert(dirty_chunk);
+                    mesh_refresh.extend(Self::neighbor_chunk_positions(dirty_chunk));
 
-                    let support_changes = Self::remove_unsupported_blocks(
+                    let affected = mdminecraft_world::recompute_block_light_local(
                         &mut self.chunks,
-                        &self.block_properties,
-                        changed_positions,
+                        &self.registry,
+                        dirty_chunk,
                     );
+                    mesh_refresh.extend(affected);
+                }
+            }
 
-                    for (pos, removed_block_id) in &support_changes.removed {
-                        if !mdminecraft_world::CropType::is_crop(*removed_block_id) {
-                            continue;
-                        }
+            for chunk_pos in mesh_refresh {
+                if self.upload_chunk_mesh(chunk_pos) {
+                    self.debug_hud.chunk_uploads_last_frame += 1;
+                }
+            }
 
-                        let removed_chunk = ChunkPos::new(
-                            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-                        );
-                        self.crop_growth.unregister_crop(CropPosition {
-                            chunk: removed_chunk,
-                            x: pos.x.rem_euclid(CHUNK_SIZE_X as i32) as u8,
-                            y: pos.y as u8,
-                            z: pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as u8,
-                        });
-                    }
+            // Spawn dropped item if harvested successfully (creative breaks drop nothing)
+            let tool = self.hotbar.selected_tool();
+            let can_harvest = self.block_properties.get(block_id).can_harvest(tool);
+            if !instant_break && can_harvest {
+                let drop_x = hit.block_pos.x as f64 + 0.5;
+                let drop_y = hit.block_pos.y as f64 + 0.5;
+                let drop_z = hit.block_pos.z as f64 + 0.5;
+
+                // Check for Silk Touch and Fortune enchantments on the tool
+                let (has_silk_touch, fortune_level) =
+                    if let Some(stack) = &self.hotbar.slots[self.hotbar.selected] {
+                        let silk_touch = stack.has_enchantment(EnchantmentType::SilkTouch);
+                        let fortune = stack.enchantment_level(EnchantmentType::Fortune);
+                        (silk_touch, fortune)
+                    } else {
+                        (false, 0)
+                    };
 
-                    // Notify redstone sim for any neighbor-dependent updates.
-                    self.schedule_redstone_updates_around(hit.block_pos);
-                    if let Some(extra) = removed_extra {
-                        self.schedule_redstone_updates_around(extra);
-                    }
-                    for (pos, _) in &support_changes.removed {
-                        self.schedule_redstone_updates_around(*pos);
-                    }
-                    for pos in &support_changes.moved {
-                        self.schedule_redstone_updates_around(*pos);
-                    }
+                let mut rng = {
+                    let pos_seed = (hit.block_pos.x as u64)
+                        ^ ((hit.block_pos.y as u64).rotate_left(21))
+                        ^ ((hit.block_pos.z as u64).rotate_left(42));
+                    let seed = self.world_seed
+                        ^ self.sim_tick.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                        ^ pos_seed.wrapping_mul(0xD6E8_FEB8_6659_FD93)
+                        ^ 0xDBA0_11A5_115D_1EAF_u64;
+                    StdRng::seed_from_u64(seed)
+                };
 
-                    let mut affected_chunks = std::collections::BTreeSet::new();
-                    affected_chunks.insert(chunk_pos);
-                    if let Some(extra) = removed_extra {
-                        affected_chunks.insert(ChunkPos::new(
-                            extra.x.div_euclid(CHUNK_SIZE_X as i32),
-                            extra.z.div_euclid(CHUNK_SIZE_Z as i32),
-                        ));
+                if let Some(table) = self.loot_tables.block.get(&block_id) {
+                    for (drop_type, count) in table.roll(&mut rng) {
+                        self.item_manager.spawn_item(
+                            self.active_dimension,
+                            drop_x,
+                            drop_y,
+                            drop_z,
+                            drop_type,
+                            count,
+                        );
                     }
-                    for (pos, removed_block_id) in &support_changes.removed {
-                        self.on_block_entity_removed(*pos, *removed_block_id);
-
-                        affected_chunks.insert(ChunkPos::new(
-                            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-                        ));
+                } else {
+                    let random = (rng.gen::<u32>() as f64) / (u32::MAX as f64);
 
-                        self.fluid_sim
-                            .on_fluid_removed(FluidPos::new(pos.x, pos.y, pos.z), &self.chunks);
+                    let is_leaf_block = matches!(
+                        block_id,
+                        mdminecraft_world::tree_blocks::LEAVES
+                            | mdminecraft_world::tree_blocks::BIRCH_LEAVES
+                            | mdminecraft_world::tree_blocks::PINE_LEAVES
+                    );
 
-                        let should_drop = if mdminecraft_world::is_door_upper(*removed_block_id) {
-                            let lower_pos = IVec3::new(pos.x, pos.y - 1, pos.z);
-                            !support_changes.removed.iter().any(|(other_pos, other_id)| {
-                                *other_pos == lower_pos
-                                    && mdminecraft_world::is_door_lower(*other_id)
-                            })
+                    // Determine what to drop based on enchantments.
+                    let drop = if is_leaf_block {
+                        if has_silk_touch {
+                            DroppedItemType::silk_touch_drop(block_id)
                         } else {
-                            *removed_block_id != interactive_blocks::BED_HEAD
-                        };
+                            DroppedItemType::from_leaves_random(block_id, random)
+                        }
+                    } else if has_silk_touch {
+                        DroppedItemType::silk_touch_drop(block_id)
+                    } else if fortune_level > 0 {
+                        DroppedItemType::fortune_drop(block_id, fortune_level, random)
+                    } else {
+                        DroppedItemType::from_block(block_id)
+                    };
 
-                        if should_drop {
-                            let drop_x = pos.x as f64 + 0.5;
-                            let drop_y = pos.y as f64 + 0.5;
-                            let drop_z = pos.z as f64 + 0.5;
-
-                            if let Some(table) = self.loot_tables.block.get(removed_block_id) {
-                                let mut rng = {
-                                    let pos_seed = (pos.x as u64)
-                                        ^ ((pos.y as u64).rotate_left(21))
-                                        ^ ((pos.z as u64).rotate_left(42));
-                                    let seed = self.world_seed
-                                        ^ self.sim_tick.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
-                                        ^ pos_seed.wrapping_mul(0xD6E8_FEB8_6659_FD93)
-                                        ^ 0x10B1_0B15_EA4E_102E_u64;
-                                    StdRng::seed_from_u64(seed)
-                                };
+                    if let Some((drop_type, count)) = drop {
+                        self.item_manager.spawn_item(
+                            self.active_dimension,
+                            drop_x,
+                            drop_y,
+                            drop_z,
+                            drop_type,
+                            count,
+                        );
+                        tracing::debug!(
+                            "Dropped {:?} x{} at ({:.1}, {:.1}, {:.1}){}",
+                            drop_type,
+                            count,
+                            drop_x,
+                            drop_y,
+                            drop_z,
+                            if has_silk_touch {
+                                " (Silk Touch)"
+                            } else if fortune_level > 0 {
+                                " (Fortune)"
+                            } else {
+                                ""
+                            }
+                        );
 
-                                for (drop_type, count) in table.roll(&mut rng) {
-                                    self.item_manager.spawn_item(
-                                        self.active_dimension,
-                                        drop_x,
-                                        drop_y,
-                                        drop_z,
-                                        drop_type,
-                                        count,
-                                    );
-                                }
-                            } else if let Some((drop_type, count)) =
-                                DroppedItemType::from_block(*removed_block_id)
-                            {
+                        // Vanilla-ish: breaking grass can drop seeds.
+                        if block_id == mdminecraft_world::BLOCK_GRASS && !has_silk_touch {
+                            // Keep a simple 1/8 chance; deterministic via the per-block RNG.
+                            if random < 0.125 {
                                 self.item_manager.spawn_item(
                                     self.active_dimension,
                                     drop_x,
                                     drop_y,
                                     drop_z,
-                                    drop_type,
-                                    count,
+                                    DroppedItemType::WheatSeeds,
+                                    1,
                                 );
                             }
                         }
-                    }
-
-                    for pos in &support_changes.moved {
-                        affected_chunks.insert(ChunkPos::new(
-                            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-                        ));
-                        self.fluid_sim
-                            .on_fluid_removed(FluidPos::new(pos.x, pos.y, pos.z), &self.chunks);
-                    }
-
-                    let mut mesh_refresh = std::collections::BTreeSet::new();
-                    for dirty_chunk in affected_chunks {
-                        mesh_refresh.extend(self.recompute_skylight_local(dirty_chunk));
-                        mesh_refresh.insert(dirty_chunk);
-                        mesh_refresh.extend(Self::neighbor_chunk_positions(dirty_chunk));
 
-                        let affected = mdminecraft_world::recompute_block_light_local(
-                            &mut self.chunks,
-                            &self.registry,
-                            dirty_chunk,
-                        );
-                        mesh_refresh.extend(affected);
-                    }
-
-                    for chunk_pos in mesh_refresh {
-                        if self.upload_chunk_mesh(chunk_pos) {
-                            self.debug_hud.chunk_uploads_last_frame += 1;
+                        // Vanilla-ish: mature wheat drops extra seeds in addition to wheat.
+                        if block_id == mdminecraft_world::farming_blocks::WHEAT_7 {
+                            let extra_seeds = ((random * 3.0).floor() as u32).min(2);
+                            let seeds = 1 + extra_seeds;
+                            self.item_manager.spawn_item(
+                                self.active_dimension,
+                                drop_x,
+                                drop_y,
+                                drop_z,
+                                DroppedItemType::WheatSeeds,
+                                seeds,
+                            );
                         }
-                    }
 
-                    // Spawn dropped item if harvested successfully
-                    let tool = self.hotbar.selected_tool();
-                    let can_harvest = self.block_properties.get(block_id).can_harvest(tool);
-                    if can_harvest {
-                        let drop_x = hit.block_pos.x as f64 + 0.5;
-                        let drop_y = hit.block_pos.y as f64 + 0.5;
-                        let drop_z = hit.block_pos.z as f64 + 0.5;
-
-                        // Check for Silk Touch and Fortune enchantments on the tool
-                        let (has_silk_touch, fortune_level) =
-                            if let Some(stack) = &self.hotbar.slots[self.hotbar.selected] {
-                                let silk_touch = stack.has_enchantment(EnchantmentType::SilkTouch);
-                                let fortune = stack.enchantment_level(EnchantmentType::Fortune);
-                                (silk_touch, fortune)
-                            } else {
-                                (false, 0)
-                            };
-
-                        let mut rng = {
-                            let pos_seed = (hit.block_pos.x as u64)
-                                ^ ((hit.block_pos.y as u64).rotate_left(21))
-                                ^ ((hit.block_pos.z as u64).rotate_left(42));
-                            let seed = self.world_seed
-                                ^ self.sim_tick.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
-                                ^ pos_seed.wrapping_mul(0xD6E8_FEB8_6659_FD93)
-                                ^ 0xDBA0_11A5_115D_1EAF_u64;
-                            StdRng::seed_from_u64(seed)
-                        };
-
-                        if let Some(table) = self.loot_tables.block.get(&block_id) {
-                            for (drop_type, count) in table.roll(&mut rng) {
+                        // Vanilla-ish: mature carrots/potatoes drop extra produce in addition to the base drop.
+                        if block_id == mdminecraft_world::farming_blocks::CARROTS_3 {
+                            let extra = ((random * 4.0).floor() as u32).min(3);
+                            if extra > 0 {
                                 self.item_manager.spawn_item(
                                     self.active_dimension,
                                     drop_x,
                                     drop_y,
                                     drop_z,
-                                    drop_type,
-                                    count,
+                                    DroppedItemType::Carrot,
+                                    extra,
                                 );
                             }
-                        } else {
-                            let random = (rng.gen::<u32>() as f64) / (u32::MAX as f64);
-
-                            let is_leaf_block = matches!(
-                                block_id,
-                                mdminecraft_world::tree_blocks::LEAVES
-                                    | mdminecraft_world::tree_blocks::BIRCH_LEAVES
-                                    | mdminecraft_world::tree_blocks::PINE_LEAVES
-                            );
-
-                            // Determine what to drop based on enchantments.
-                            let drop = if is_leaf_block {
-                                if has_silk_touch {
-                                    DroppedItemType::silk_touch_drop(block_id)
-                                } else {
-                                    DroppedItemType::from_leaves_random(block_id, random)
-                                }
-                            } else if has_silk_touch {
-                                DroppedItemType::silk_touch_drop(block_id)
-                            } else if fortune_level > 0 {
-                                DroppedItemType::fortune_drop(block_id, fortune_level, random)
-                            } else {
-                                DroppedItemType::from_block(block_id)
-                            };
-
-                            if let Some((drop_type, count)) = drop {
+                        }
+                        if block_id == mdminecraft_world::farming_blocks::POTATOES_3 {
+                            let extra = ((random * 4.0).floor() as u32).min(3);
+                            if extra > 0 {
                                 self.item_manager.spawn_item(
                                     self.active_dimension,
                                     drop_x,
                                     drop_y,
                                     drop_z,
-                                    drop_type,
-                                    count,
-                                );
-                                tracing::debug!(
-                                    "Dropped {:?} x{} at ({:.1}, {:.1}, {:.1}){}",
-                                    drop_type,
-                                    count,
-                                    drop_x,
-                                    drop_y,
-                                    drop_z,
-                                    if has_silk_touch {
-                                        " (Silk Touch)"
-                                    } else if fortune_level > 0 {
-                                        " (Fortune)"
-                                    } else {
-                                        ""
-                                    }
+                                    DroppedItemType::Potato,
+                                    extra,
                                 );
-
-                                // Vanilla-ish: breaking grass can drop seeds.
-                                if block_id == mdminecraft_world::BLOCK_GRASS && !has_silk_touch {
-                                    // Keep a simple 1/8 chance; deterministic via the per-block RNG.
-                                    if random < 0.125 {
-                                        self.item_manager.spawn_item(
-                                            self.active_dimension,
-                                            drop_x,
-                                            drop_y,
-                                            drop_z,
-                                            DroppedItemType::WheatSeeds,
-                                            1,
-                                        );
-                                    }
-                                }
-
-                                // Vanilla-ish: mature wheat drops extra seeds in addition to wheat.
-                                if block_id == mdminecraft_world::farming_blocks::WHEAT_7 {
-                                    let extra_seeds = ((random * 3.0).floor() as u32).min(2);
-                                    let seeds = 1 + extra_seeds;
-                                    self.item_manager.spawn_item(
-                                        self.active_dimension,
-                                        drop_x,
-                                        drop_y,
-                                        drop_z,
-                                        DroppedItemType::WheatSeeds,
-                                        seeds,
-                                    );
-                                }
-
-                                // Vanilla-ish: mature carrots/potatoes drop extra produce in addition to the base drop.
-                                if block_id == mdminecraft_world::farming_blocks::CARROTS_3 {
-                                    let extra = ((random * 4.0).floor() as u32).min(3);
-                                    if extra > 0 {
-                                        self.item_manager.spawn_item(
-                                            self.active_dimension,
-                                            drop_x,
-                                            drop_y,
-                                            drop_z,
-                                            DroppedItemType::Carrot,
-                                            extra,
-                                        );
-                                    }
-                                }
-                                if block_id == mdminecraft_world::farming_blocks::POTATOES_3 {
-                                    let extra = ((random * 4.0).floor() as u32).min(3);
-                                    if extra > 0 {
-                                        self.item_manager.spawn_item(
-                                            self.active_dimension,
-                                            drop_x,
-                                            drop_y,
-                                            drop_z,
-                                            DroppedItemType::Potato,
-                                            extra,
-                                        );
-                                    }
-                                }
                             }
                         }
                     }
                 }
+            }
+        }
 
-                if let Some(center) = spawn_particles_at {
-                    self.spawn_block_break_particles(center, block_id);
-                }
+        if let Some(center) = spawn_particles_at {
+            self.spawn_block_break_particles(center, block_id);
+        }
 
-                // Reset mining progress
-                self.mining_progress = None;
-                self.debug_hud.mining_progress = None;
+        // Reset mining progress
+        self.mining_progress = None;
+        self.debug_hud.mining_progress = None;
+    }
+
+    /// Advance the mining state for `block_pos`, returning `true` once the block should break.
+    ///
+    /// Survival starts a fresh [`MiningProgress`] (timed by `mining_time`) whenever the
+    /// target changes; creative breaks instantly and never tracks progress.
+    fn advance_mining(
+        progress: &mut Option<MiningProgress>,
+        gamemode: commands::Gamemode,
+        block_pos: IVec3,
+        dt: f32,
+        mining_time: impl FnOnce() -> f32,
+    ) -> bool {
+        if gamemode == commands::Gamemode::Creative {
+            *progress = None;
+            return true;
+        }
+
+        let progress = match progress {
+            Some(p) if p.block_pos == block_pos => p,
+            _ => progress.insert(MiningProgress {
+                block_pos,
+                time_mining: 0.0,
+                time_required: mining_time(),
+            }),
+        };
+        progress.time_mining += dt;
+        progress.time_mining >= progress.time_required
+    }
+
+    /// Seconds needed to break `block_id` with the held tool, accounting for water and Efficiency.
+    fn block_mining_time(&self, block_id: BlockId, block_pos: IVec3) -> f32 {
+        let block_props = self.block_properties.get(block_id);
+        // Calculate mining time based on tool and block properties
+        let tool = self.hotbar.selected_tool();
+        let (eye_fluid, feet_fluid) =
+            self.camera_eye_and_feet_fluids(self.renderer.camera().position);
+        let in_water = eye_fluid == Some(FluidType::Water) || feet_fluid == Some(FluidType::Water);
+        let has_aqua_affinity = self
+            .player_armor
+            .total_enchantment_level(EnchantmentType::AquaAffinity)
+            > 0;
+        let apply_water_penalty = in_water && !has_aqua_affinity;
+
+        let mut mining_time = block_props.calculate_mining_time(tool, apply_water_penalty);
+
+        // Apply Efficiency enchantment bonus
+        // Each level of Efficiency adds 26% mining speed (Minecraft formula: (level^2 + 1) bonus)
+        // Simplified: multiply speed by 1 + (0.26 * level)
+        if let Some(item) = self.hotbar.selected_item() {
+            let efficiency_level = item.enchantment_level(EnchantmentType::Efficiency);
+            if efficiency_level > 0 {
+                let speed_bonus = 1.0 + 0.26 * efficiency_level as f32;
+                mining_time /= speed_bonus;
+                tracing::debug!(
+                    "Efficiency {} applied: speed bonus {:.0}%",
+                    efficiency_level,
+                    (speed_bonus - 1.0) * 100.0
+                );
             }
         }
+
+        tracing::debug!(
+            "Started mining block {} at {:?} (requires {:.2}s)",
+            block_id,
+            block_pos,
+            mining_time
+        );
+
+        mining_time
     }
 
     fn handle_block_placement(&mut self, hit: RaycastHit) {
@@ -10938,6 +10282,40 @@ impl GameWorld {
                 }
             }
 
+            if !self.edit_allowed(EditKind::Place, place_pos) {
+                return;
+            }
+            // Beds and doors also need their second half to be editable.
+            let second_half = if block_id == interactive_blocks::BED_FOOT {
+                Self::bed_other_half_pos(place_pos, interactive_blocks::BED_FOOT, place_state)
+                    .map(|(pos, _)| pos)
+            } else if mdminecraft_world::is_door_lower(block_id) {
+                Some(place_pos + IVec3::Y)
+            } else {
+                None
+            };
+            if second_half.is_some_and(|pos| !self.edit_allowed(EditKind::Place, pos)) {
+                return;
+            }
+
+            // Voxels the placement may overwrite, for relighting: the target and its face
+            // neighbours, which hold any second half (door tops, bed heads).
+            let voxels_before: Vec<(IVec3, Voxel)> = [
+                IVec3::ZERO,
+                IVec3::X,
+                IVec3::NEG_X,
+                IVec3::Y,
+                IVec3::NEG_Y,
+                IVec3::Z,
+                IVec3::NEG_Z,
+            ]
+            .into_iter()
+            .filter_map(|offset| {
+                let pos = place_pos + offset;
+                self.get_voxel_at(pos).map(|voxel| (pos, voxel))
+            })
+            .collect();
+
             let chunk_x = place_pos.x.div_euclid(16);
             let chunk_z = place_pos.z.div_euclid(16);
             let chunk_pos = ChunkPos::new(chunk_x, chunk_z);
@@ -11076,14 +10454,7 @@ impl GameWorld {
                 }
 
                 // Decrease block count.
-                if let Some(item) = self.hotbar.selected_item_mut() {
-                    if item.count > 0 {
-                        item.count -= 1;
-                        if item.count == 0 {
-                            self.hotbar.slots[self.hotbar.selected] = None;
-                        }
-                    }
-                }
+                self.hotbar.consume_selected(self.gamemode);
             }
 
             if placed {
@@ -11096,23 +10467,6 @@ impl GameWorld {
                     ));
                 }
 
-                // Update lighting (skylight + seams) for any affected chunks (including
-                // cross-chunk multi-block placements like beds).
-                let mut skylight_affected = std::collections::BTreeSet::new();
-                for pos in &dirty_chunks {
-                    skylight_affected.extend(self.recompute_skylight_local(*pos));
-                }
-
-                // Update blocklight and refresh affected meshes.
-                let mut affected = std::collections::BTreeSet::new();
-                for pos in &dirty_chunks {
-                    affected.extend(mdminecraft_world::recompute_block_light_local(
-                        &mut self.chunks,
-                        &self.registry,
-                        *pos,
-                    ));
-                }
-
                 // Refresh the changed chunk + neighbors (geometry/connectivity) and any chunks
                 // touched by lighting updates (includes diagonals).
                 let mut mesh_refresh = std::collections::BTreeSet::new();
@@ -11120,8 +10474,31 @@ impl GameWorld {
                     mesh_refresh.insert(*pos);
                     mesh_refresh.extend(Self::neighbor_chunk_positions(*pos));
                 }
-                mesh_refresh.extend(skylight_affected);
-                mesh_refresh.extend(affected);
+
+                // Relight just the voxels each placed block affects (both halves of beds
+                // and doors, possibly across a chunk seam).
+                let mut propagator = LightPropagator::new(&self.registry);
+                for (pos, old) in voxels_before
+                    .iter()
+                    .filter(|(pos, _)| *pos == place_pos || Some(*pos) == placed_extra)
+                {
+                    let Some(light_pos) =
+                        mdminecraft_world::lighting::BlockPos::from_world(pos.x, pos.y, pos.z)
+                    else {
+                        continue;
+                    };
+                    let new = self
+                        .chunks
+                        .voxel_at(pos.x, pos.y, pos.z)
+                        .unwrap_or_default();
+                    mesh_refresh.extend(propagator.on_block_changed(
+                        &mut self.chunks,
+                        light_pos,
+                        *old,
+                        new,
+                    ));
+                }
+
                 for pos in mesh_refresh {
                     if self.upload_chunk_mesh(pos) {
                         self.debug_hud.chunk_uploads_last_frame += 1;
@@ -11377,7 +10754,11 @@ impl GameWorld {
             return Some(mdminecraft_world::Facing::from_yaw(camera_yaw).to_state());
         }
 
-        Some(0)
+        Some(mdminecraft_world::placement_state(
+            block_id,
+            camera_yaw,
+            face_normal,
+        ))
     }
 
     fn double_slab_block_id(slab_id: BlockId) -> Option<BlockId> {
@@ -11525,7 +10906,7 @@ impl GameWorld {
         let player = (self.player_state == PlayerState::Alive).then(|| {
             (
                 self.renderer.camera().position,
-                self.player_physics.eye_height,
+                self.player_physics.controller.eye_height,
             )
         });
 
@@ -11841,48 +11222,19 @@ impl GameWorld {
         let mut moved = Vec::new();
 
         let voxel_at = |chunks: &HashMap<ChunkPos, Chunk>, pos: IVec3| -> Option<Voxel> {
-            let local_y = world_y_to_local_y(pos.y)?;
-
-            let chunk_pos = ChunkPos::new(
-                pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-            );
-            let chunk = chunks.get(&chunk_pos)?;
-            let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-            let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-            Some(chunk.voxel(local_x, local_y, local_z))
+            chunks.voxel_at(pos.x, pos.y, pos.z)
         };
 
         let is_solid_at = |chunks: &HashMap<ChunkPos, Chunk>, pos: IVec3| -> bool {
-            voxel_at(chunks, pos).is_some_and(|voxel| block_properties.get(voxel.id).is_solid)
+            chunks.is_solid_at(pos.x, pos.y, pos.z, block_properties)
         };
 
         let set_voxel_at =
             |chunks: &mut HashMap<ChunkPos, Chunk>, pos: IVec3, voxel: Voxel| -> bool {
-                let Some(local_y) = world_y_to_local_y(pos.y) else {
-                    return false;
-                };
-                let chunk_pos = ChunkPos::new(
-                    pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                    pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-                );
-                let Some(chunk) = chunks.get_mut(&chunk_pos) else {
-                    return false;
-                };
-                let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-                let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-                chunk.set_voxel(local_x, local_y, local_z, voxel);
-                true
+                chunks.set_voxel_at(pos.x, pos.y, pos.z, voxel)
             };
         let can_set_voxel_at = |chunks: &HashMap<ChunkPos, Chunk>, pos: IVec3| -> bool {
-            if world_y_to_local_y(pos.y).is_none() {
-                return false;
-            }
-            let chunk_pos = ChunkPos::new(
-                pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-            );
-            chunks.contains_key(&chunk_pos)
+            chunks.voxel_at(pos.x, pos.y, pos.z).is_some()
         };
 
         let neighbor_offsets = [
@@ -12080,31 +11432,6 @@ impl GameWorld {
         UnsupportedBlockChanges { removed, moved }
     }
 
-    /// Initialize skylight for a chunk and stitch across neighboring seams.
-    ///
-    /// This is intended for newly loaded/generated chunks. For geometry edits (which can
-    /// decrease skylight), use [`Self::recompute_skylight_local`] instead.
-    fn init_chunk_skylight(&mut self, chunk_pos: ChunkPos) {
-        if let Some(chunk) = self.chunks.get_mut(&chunk_pos) {
-            let _ = init_skylight(chunk, &self.registry);
-        }
-
-        let neighbors = [
-            chunk_pos,
-            ChunkPos::new(chunk_pos.x + 1, chunk_pos.z),
-            ChunkPos::new(chunk_pos.x - 1, chunk_pos.z),
-            ChunkPos::new(chunk_pos.x, chunk_pos.z + 1),
-            ChunkPos::new(chunk_pos.x, chunk_pos.z - 1),
-        ];
-
-        for pos in neighbors {
-            if self.chunks.contains_key(&pos) {
-                let _ =
-                    stitch_light_seams(&mut self.chunks, &self.registry, pos, LightType::Skylight);
-            }
-        }
-    }
-
     /// Recompute skylight for a local neighborhood around `center`.
     ///
     /// This handles both increases and decreases by clearing and reinitializing skylight for a
@@ -12117,9 +11444,7 @@ impl GameWorld {
     }
 
     fn render(&mut self) {
-        let camera_pos = self.renderer.camera().position;
-        self.audio
-            .set_listener_position([camera_pos.x, camera_pos.y, camera_pos.z]);
+        self.flush_chunk_remeshes();
 
         let mut close_inventory_requested = false;
         let mut close_crafting_requested = false;
@@ -12136,8 +11461,8 @@ impl GameWorld {
         let mut enchanting_result: Option<EnchantingResult> = None;
         let mut spill_items: Vec<ItemStack> = Vec::new();
         let mut pause_action = PauseMenuAction::None;
-        let initial_fov_degrees = self.renderer.camera().fov.to_degrees();
-        let mut fov_degrees = initial_fov_degrees;
+        let mut fov_degrees = self.renderer.camera().fov.to_degrees();
+        let initial_pause_view = self.pause_menu_view;
         let initial_render_distance = self.render_distance;
         let mut render_distance = initial_render_distance;
         let mut input_bindings_changed = false;
@@ -12288,6 +11613,7 @@ impl GameWorld {
                 Some(ParticleSystem::from_emitter(
                     resources.device,
                     &self.particle_emitter,
+                    self.renderer.camera().position,
                 ))
             };
 
@@ -12407,8 +11733,9 @@ impl GameWorld {
             let view_proj = camera.projection_matrix() * camera.view_matrix();
             let frustum = Frustum::from_matrix(&view_proj);
 
-            // Render voxels with frustum culling
-            self.chunks_visible = 0;
+            // Render voxels with frustum + occlusion culling
+            let (visible_chunks, chunk_stats) = self.chunk_manager.visible_chunks(&frustum);
+            self.chunk_stats = chunk_stats;
             {
                 let mut render_pass = resources
                     .pipeline
@@ -12418,13 +11745,7 @@ impl GameWorld {
                 render_pass.set_bind_group(0, resources.pipeline.camera_bind_group(), &[]);
                 render_pass.set_bind_group(2, resources.pipeline.texture_bind_group(), &[]);
 
-                for chunk_data in self.chunk_manager.chunks() {
-                    if !frustum.is_chunk_visible(chunk_data.chunk_pos) {
-                        continue;
-                    }
-
-                    self.chunks_visible += 1;
-
+                for chunk_data in &visible_chunks {
                     let Some(index_buffer) = chunk_data.opaque_index_buffer.as_ref() else {
                         continue;
                     };
@@ -12447,13 +11768,9 @@ impl GameWorld {
                 render_pass.set_bind_group(2, resources.pipeline.texture_bind_group(), &[]);
 
                 let cam_pos = camera.position;
-                let mut fluid_chunks: Vec<_> = self
-                    .chunk_manager
-                    .chunks()
-                    .filter(|chunk_data| {
-                        chunk_data.alpha_index_count != 0
-                            && frustum.is_chunk_visible(chunk_data.chunk_pos)
-                    })
+                let mut fluid_chunks: Vec<_> = visible_chunks
+                    .iter()
+                    .filter(|chunk_data| chunk_data.alpha_index_count != 0)
                     .collect();
 
                 fluid_chunks.sort_by(|a, b| {
@@ -12483,6 +11800,10 @@ impl GameWorld {
                 }
             }
 
+            // Occlusion results from this frame's camera cull chunks next frame.
+            self.chunk_manager.update_occlusion(&view_proj, camera.position);
+            self.debug_hud.chunks_occluded = self.chunk_manager.occluded_count();
+
             if let Some(system) = particle_system.as_ref() {
                 let depth_view = resources.pipeline.depth_view();
                 let mut render_pass = resources.particle_pipeline.begin_render_pass(
@@ -12490,7 +11811,11 @@ impl GameWorld {
                     target_view,
                     depth_view,
                 );
-                render_pass.set_pipeline(resources.particle_pipeline.pipeline());
+                render_pass.set_pipeline(
+                    resources
+                        .particle_pipeline
+                        .pipeline_for(system.blend_mode()),
+                );
                 render_pass.set_bind_group(0, resources.pipeline.camera_bind_group(), &[]);
                 render_pass.set_bind_group(
                     1,
@@ -12536,6 +11861,38 @@ impl GameWorld {
                 render_pass.draw(0..24, 0..1);
             }
 
+            // Render chunk bounds / frustum debug boxes (F6)
+            let debug_boxes = self
+                .renderer
+                .debug_wireframe_boxes(self.chunk_manager.chunks().map(|chunk| chunk.chunk_pos));
+            if let Some((debug_vertices, debug_vertex_count)) = resources
+                .wireframe_pipeline
+                .create_debug_vertex_buffer(resources.device, &debug_boxes)
+            {
+                // Yellow normally, red while frustum culling is off (F7).
+                let debug_color = if self.chunk_manager.frustum_culling() {
+                    [1.0, 1.0, 0.0, 0.8]
+                } else {
+                    [1.0, 0.2, 0.2, 0.8]
+                };
+                resources
+                    .wireframe_pipeline
+                    .update_debug_color(resources.queue, debug_color);
+
+                let depth_view = resources.pipeline.depth_view();
+                let mut render_pass = resources.wireframe_pipeline.begin_render_pass(
+                    &mut encoder,
+                    target_view,
+                    depth_view,
+                );
+
+                render_pass.set_pipeline(resources.wireframe_pipeline.pipeline());
+                render_pass.set_bind_group(0, resources.pipeline.camera_bind_group(), &[]);
+                render_pass.set_bind_group(1, resources.wireframe_pipeline.debug_bind_group(), &[]);
+                render_pass.set_vertex_buffer(0, debug_vertices.slice(..));
+                render_pass.draw(0..debug_vertex_count, 0..1);
+            }
+
             #[cfg(feature = "ui3d_billboards")]
             if let Some(renderer) = self.billboard_renderer.as_mut() {
                 let depth_view = resources.pipeline.depth_view();
@@ -12546,12 +11903,38 @@ impl GameWorld {
                     target_view,
                     depth_view,
                     resources.pipeline.camera_bind_group(),
+                    &BillboardView::from_camera(camera, render_size.1 as f32),
                     &mut self.billboard_emitter,
                 ) {
                     tracing::warn!(?err, "Billboard rendering failed");
                 }
             }
 
+            // Mob names, after the billboards so the text sits over the health bars.
+            #[cfg(feature = "ui3d_billboards")]
+            if let Some(text) = self.nameplate_text.as_mut() {
+                let names: Vec<&Text3D> = self
+                    .mob_labels
+                    .values()
+                    .filter(|label| label.is_visible())
+                    .map(|label| label.label().text())
+                    .collect();
+                if !names.is_empty() {
+                    let mut render_pass = resources.wireframe_pipeline.begin_render_pass(
+                        &mut encoder,
+                        target_view,
+                        resources.pipeline.depth_view(),
+                    );
+                    text.render_batch(
+                        resources.device,
+                        resources.queue,
+                        &mut render_pass,
+                        resources.pipeline.camera_bind_group(),
+                        &names,
+                    );
+                }
+            }
+
             // Render UI overlay
             let is_dead = self.player_state == PlayerState::Dead;
             let death_msg = self.death_message.clone();
@@ -12575,6 +11958,7 @@ impl GameWorld {
                     pixels_per_point: 1.0,
                 };
 
+                let crosshair = ui.crosshair();
                 ui.render(
                     UiRenderContext {
                         device: resources.device,
@@ -12586,6 +11970,9 @@ impl GameWorld {
                     self.window.as_ref().expect("window missing"),
                     |ctx| {
                         self.debug_hud.render(ctx);
+                        if self.actions.context == InputContext::Gameplay && !is_dead {
+                            render_crosshair(ctx, &crosshair);
+                        }
                         render_hotbar(ctx, &self.hotbar, &self.registry);
                         render_xp_bar(ctx, &self.player_xp);
                         if let Some(dragon) = self.mobs.iter().find(|mob| {
@@ -12605,6 +11992,11 @@ impl GameWorld {
                         render_armor_bar(ctx, &self.player_armor);
                         render_tool_durability(ctx, &self.hotbar);
                         render_status_effects_overlay(ctx, &self.status_effects);
+                        if let Some((reason, at)) = self.last_edit_denied {
+                            if at.elapsed() < EDIT_DENIED_MESSAGE_DURATION {
+                                render_edit_denied(ctx, reason);
+                            }
+                        }
 
                         // Show inventory if open
                         if inventory_open {
@@ -13168,18 +12560,23 @@ impl GameWorld {
             }
         }
 
-        if (fov_degrees - initial_fov_degrees).abs() > f32::EPSILON {
-            self.renderer.camera_mut().fov = fov_degrees.to_radians();
-        }
         if render_distance != initial_render_distance {
             self.render_distance = render_distance;
+            self.renderer.set_render_distance(
+                Self::loaded_chunk_radius(render_distance),
+                &mut self.chunk_manager,
+            );
         }
         if input_bindings_changed {
             self.input_processor = InputProcessor::new(&self.controls);
         }
         if !Arc::ptr_eq(&initial_controls, &self.controls) {
-            self.audio
-                .update_settings(Self::audio_settings_from_controls(&self.controls));
+            self.apply_live_controls();
+        }
+        if initial_pause_view == PauseMenuView::Options
+            && self.pause_menu_view != PauseMenuView::Options
+        {
+            self.save_pause_controls();
         }
 
         // Handle enchanting result - apply enchantment to selected item
@@ -13210,10 +12607,8 @@ impl GameWorld {
 
     /// Calculate and apply fall damage
     fn calculate_fall_damage(&mut self, fall_distance: f32) {
-        // Minecraft formula: damage = fall_distance - 3.0
-        // Player takes damage for falls > 3 blocks
-        if fall_distance > 3.0 {
-            let raw_damage = (fall_distance - 3.0) * 1.0; // 1 damage per block fallen
+        let raw_damage = mdminecraft_world::fall_damage(fall_distance);
+        if raw_damage > 0.0 {
             let actual_damage = self.player_armor.take_damage(raw_damage, DamageKind::Fall);
             self.player_health.damage(actual_damage);
             tracing::info!(
@@ -13247,7 +12642,7 @@ impl GameWorld {
         self.enter_menu();
 
         // Stop player movement
-        self.player_physics.velocity = glam::Vec3::ZERO;
+        self.player_physics.controller.velocity = glam::Vec3::ZERO;
     }
 
     fn find_safe_spawn_near(&self, world_x: f32, world_z: f32) -> Option<glam::Vec3> {
@@ -13299,7 +12694,7 @@ impl GameWorld {
                     let world_y = local_y_to_world_y(local_y);
                     return Some(glam::Vec3::new(
                         x as f32 + 0.5,
-                        world_y as f32 + PlayerPhysics::GROUND_EPS,
+                        world_y as f32 + PlayerController::GROUND_EPS,
                         z as f32 + 0.5,
                     ));
                 }
@@ -13368,7 +12763,7 @@ impl GameWorld {
                     let world_y = local_y_to_world_y(y_usize);
                     Some(glam::Vec3::new(
                         x as f32 + 0.5,
-                        world_y as f32 + PlayerPhysics::GROUND_EPS,
+                        world_y as f32 + PlayerController::GROUND_EPS,
                         z as f32 + 0.5,
                     ))
                 };
@@ -13430,7 +12825,7 @@ impl GameWorld {
         self.chunks.clear();
         self.chunk_manager = ChunkManager::new();
         self.redstone_sim = RedstoneSimulator::new();
-        self.fluid_sim = FluidSimulator::new();
+        self.fluid_sim = budgeted_fluid_simulator();
         self.selected_block = None;
 
         let camera = self.renderer.camera_mut();
@@ -13448,7 +12843,7 @@ impl GameWorld {
             _ => {}
         }
 
-        let eye_height = self.player_physics.eye_height;
+        let eye_height = self.player_physics.controller.eye_height;
         let provisional_y = if target == DimensionId::Nether {
             80.0 + eye_height
         } else {
@@ -13456,8 +12851,8 @@ impl GameWorld {
         };
 
         camera.position = glam::Vec3::new(new_x, provisional_y, new_z);
-        self.player_physics.velocity = glam::Vec3::ZERO;
-        self.player_physics.on_ground = false;
+        self.player_physics.controller.velocity = glam::Vec3::ZERO;
+        self.player_physics.controller.on_ground = false;
 
         self.active_dimension = target;
 
@@ -13469,7 +12864,7 @@ impl GameWorld {
         if let Some(feet) = self.find_safe_spawn_near(camera_pos.x, camera_pos.z) {
             let camera = self.renderer.camera_mut();
             camera.position = feet + glam::Vec3::new(0.0, eye_height, 0.0);
-            self.player_physics.last_ground_y = feet.y;
+            self.player_physics.controller.fall.reset();
         }
 
         if target == DimensionId::End {
@@ -13598,21 +12993,19 @@ impl GameWorld {
                 tracing::warn!("Respawn fallback dimension switch failed: {err:#}");
             }
 
-            let eye_height = self.player_physics.eye_height;
+            let eye_height = self.player_physics.controller.eye_height;
             let camera = self.renderer.camera_mut();
             camera.position = glam::Vec3::new(0.5, 100.0 + eye_height, 0.5);
-            self.player_physics.velocity = glam::Vec3::ZERO;
-            self.player_physics.on_ground = false;
+            self.player_physics.controller.velocity = glam::Vec3::ZERO;
+            self.player_physics.controller.on_ground = false;
 
             let load_limit = if self.window.is_none() { 8 } else { usize::MAX };
             self.update_chunks(load_limit);
             if let Some(feet) = self.find_safe_spawn_near(0.0, 0.0) {
                 let camera = self.renderer.camera_mut();
                 camera.position = feet + glam::Vec3::new(0.0, eye_height, 0.0);
-                self.player_physics.last_ground_y = feet.y;
-            } else {
-                self.player_physics.last_ground_y = 100.0;
             }
+            self.player_physics.controller.fall.reset();
 
             // Re-capture cursor for gameplay
             self.enter_gameplay();
@@ -13620,11 +13013,11 @@ impl GameWorld {
         }
 
         // Respawn player at spawn point and force-load chunks so it isn't a black screen.
-        let eye_height = self.player_physics.eye_height;
+        let eye_height = self.player_physics.controller.eye_height;
         let camera = self.renderer.camera_mut();
         camera.position = self.spawn_point + glam::Vec3::new(0.0, eye_height, 0.0);
-        self.player_physics.velocity = glam::Vec3::ZERO;
-        self.player_physics.on_ground = false;
+        self.player_physics.controller.velocity = glam::Vec3::ZERO;
+        self.player_physics.controller.on_ground = false;
 
         let load_limit = if self.window.is_none() { 8 } else { usize::MAX };
         self.update_chunks(load_limit);
@@ -13660,18 +13053,16 @@ impl GameWorld {
                 }
                 let camera = self.renderer.camera_mut();
                 camera.position = glam::Vec3::new(0.5, 100.0 + eye_height, 0.5);
-                self.player_physics.velocity = glam::Vec3::ZERO;
-                self.player_physics.on_ground = false;
+                self.player_physics.controller.velocity = glam::Vec3::ZERO;
+                self.player_physics.controller.on_ground = false;
 
                 let load_limit = if self.window.is_none() { 8 } else { usize::MAX };
                 self.update_chunks(load_limit);
                 if let Some(feet) = self.find_safe_spawn_near(0.0, 0.0) {
                     let camera = self.renderer.camera_mut();
                     camera.position = feet + glam::Vec3::new(0.0, eye_height, 0.0);
-                    self.player_physics.last_ground_y = feet.y;
-                } else {
-                    self.player_physics.last_ground_y = 100.0;
                 }
+                self.player_physics.controller.fall.reset();
 
                 // Re-capture cursor for gameplay
                 self.enter_gameplay();
@@ -13689,10 +13080,8 @@ impl GameWorld {
         {
             let camera = self.renderer.camera_mut();
             camera.position = feet + glam::Vec3::new(0.0, eye_height, 0.0);
-            self.player_physics.last_ground_y = feet.y;
-        } else {
-            self.player_physics.last_ground_y = self.spawn_point.y;
         }
+        self.player_physics.controller.fall.reset();
 
         if should_consume_anchor_charge {
             let Some(anchor_pos) = anchor_pos_for_consume else {
@@ -13763,7 +13152,7 @@ impl GameWorld {
         // Get player position (feet position)
         let camera_pos = self.renderer.camera().position;
         let player_x = camera_pos.x as f64;
-        let player_y = (camera_pos.y - self.player_physics.eye_height) as f64;
+        let player_y = (camera_pos.y - self.player_physics.controller.eye_height) as f64;
         let player_z = camera_pos.z as f64;
 
         // Create closure to get ground height
@@ -13844,8 +13233,8 @@ impl GameWorld {
         let player_x = player_pos.x as f64;
         let player_z = player_pos.z as f64;
         let player_eye_y = player_pos.y as f64;
-        let player_feet_y = (player_pos.y - self.player_physics.eye_height) as f64;
-        let player_center_y = player_feet_y + (self.player_physics.player_height * 0.5) as f64;
+        let player_feet_y = (player_pos.y - self.player_physics.controller.eye_height) as f64;
+        let player_center_y = player_feet_y + (self.player_physics.controller.height * 0.5) as f64;
         let player_aabb = self.player_physics.get_aabb(player_pos);
         let player_block_x = player_x.floor() as i32;
         let player_block_z = player_z.floor() as i32;
@@ -15417,8 +14806,7 @@ impl GameWorld {
         }
 
         for chunk_pos in button_mesh_refresh {
-            self.debug_hud.chunk_uploads_last_frame +=
-                self.upload_chunk_mesh_and_neighbors(chunk_pos);
+            self.renderer.request_remesh(chunk_pos);
         }
 
         let raw_projectile_damage = projectile_damage_generic_to_player
@@ -15472,12 +14860,12 @@ impl GameWorld {
         if let Some((x, _y, z)) = ender_pearl_impact {
             if self.player_state == PlayerState::Alive && !self.player_health.is_dead() {
                 if let Some(feet) = self.find_safe_spawn_near(x as f32, z as f32) {
-                    let eye_height = self.player_physics.eye_height;
+                    let eye_height = self.player_physics.controller.eye_height;
                     let camera = self.renderer.camera_mut();
                     camera.position = feet + glam::Vec3::new(0.0, eye_height, 0.0);
-                    self.player_physics.velocity = glam::Vec3::ZERO;
-                    self.player_physics.on_ground = false;
-                    self.player_physics.last_ground_y = feet.y;
+                    self.player_physics.controller.velocity = glam::Vec3::ZERO;
+                    self.player_physics.controller.on_ground = false;
+                    self.player_physics.controller.fall.reset();
 
                     // Vanilla-ish: ender pearls deal 5 damage (2.5 hearts).
                     let actual_damage = self.player_armor.take_damage(5.0, DamageKind::Fall);
@@ -16036,7 +15424,7 @@ impl GameWorld {
 
             // Critical hit detection: 1.5x damage if player is falling
             // Check if player has significant downward velocity
-            let is_critical = self.player_physics.velocity.y < -0.1;
+            let is_critical = self.player_physics.controller.velocity.y < -0.1;
             if is_critical {
                 damage *= 1.5;
             }
@@ -16257,7 +15645,7 @@ impl GameWorld {
 
         let camera_pos = self.renderer.camera().position;
         let x = camera_pos.x as f64;
-        let y = (camera_pos.y - self.player_physics.eye_height) as f64 + 0.5;
+        let y = (camera_pos.y - self.player_physics.controller.eye_height) as f64 + 0.5;
         let z = camera_pos.z as f64;
 
         self.item_manager.spawn_item_with_metadata(
@@ -16310,7 +15698,7 @@ impl GameWorld {
         let (forward, _) = Self::flat_directions(camera);
 
         let base_x = camera.position.x as f64;
-        let base_y = (camera.position.y - self.player_physics.eye_height) as f64 + 0.5;
+        let base_y = (camera.position.y - self.player_physics.controller.eye_height) as f64 + 0.5;
         let base_z = camera.position.z as f64;
 
         // Spawn just outside pickup radius, in front of the player (vanilla-ish).
@@ -17104,6 +16492,7 @@ impl GameWorld {
     }
 
     fn close_pause_menu(&mut self) {
+        self.save_pause_controls();
         self.pause_menu_open = false;
         self.pause_menu_view = PauseMenuView::Main;
         self.ui_drag_state.reset();
@@ -17111,6 +16500,29 @@ impl GameWorld {
         tracing::info!("Pause menu closed");
     }
 
+    /// Apply options changed from the pause menu without restarting the game.
+    ///
+    /// FOV, crosshair and volume take effect here; mouse sensitivity is read
+    /// from `self.controls` on the next look update.
+    fn apply_live_controls(&mut self) {
+        Self::apply_controls_to_camera(self.renderer.camera_mut(), &self.controls);
+        Self::apply_controls_to_ui(&self.renderer, &self.controls);
+        self.audio
+            .update_settings(Self::audio_settings_from_controls(&self.controls));
+    }
+
+    /// Persist options changed from the pause menu so they survive a restart.
+    fn save_pause_controls(&mut self) {
+        if !self.pause_controls_dirty {
+            return;
+        }
+        if let Err(err) = self.controls.as_ref().save() {
+            tracing::warn!(?err, "Failed to save settings");
+        } else {
+            self.pause_controls_dirty = false;
+        }
+    }
+
     fn handle_escape_pressed(&mut self) {
         if self.player_state != PlayerState::Alive {
             return;
@@ -17164,6 +16576,7 @@ impl GameWorld {
 
         if self.pause_menu_open {
             if self.pause_menu_view == PauseMenuView::Options {
+                self.save_pause_controls();
                 self.pause_menu_view = PauseMenuView::Main;
             } else {
                 self.close_pause_menu();
@@ -17374,43 +16787,22 @@ impl GameWorld {
 
     /// Get block ID at world position
     fn get_block_at(&self, pos: IVec3) -> Option<BlockId> {
-        let chunk_pos = ChunkPos::new(
-            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-        );
-        let chunk = self.chunks.get(&chunk_pos)?;
-
-        let local_y = world_y_to_local_y(pos.y)?;
-        let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-        let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-
-        Some(chunk.voxel(local_x, local_y, local_z).id)
+        self.get_voxel_at(pos).map(|voxel| voxel.id)
     }
 
     fn get_voxel_at(&self, pos: IVec3) -> Option<Voxel> {
-        let chunk_pos = ChunkPos::new(
-            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-        );
-        let chunk = self.chunks.get(&chunk_pos)?;
-
-        let local_y = world_y_to_local_y(pos.y)?;
-        let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-        let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-        Some(chunk.voxel(local_x, local_y, local_z))
+        self.chunks.voxel_at(pos.x, pos.y, pos.z)
     }
 
     /// Update all furnaces in the world
-    fn update_furnaces(&mut self, dt: f32) {
+    fn update_furnaces(&mut self) {
         let mut lit_changes: Vec<(IVec3, bool)> = Vec::new();
 
         for (key, furnace) in &mut self.furnaces {
             if key.dimension != self.active_dimension {
                 continue;
             }
-            let was_lit = furnace.is_lit;
-            furnace.update(dt);
-            if was_lit != furnace.is_lit {
+            if furnace.tick(1, &self.smelting).lit_changed {
                 lit_changes.push((IVec3::new(key.x, key.y, key.z), furnace.is_lit));
             }
         }
@@ -17514,16 +16906,7 @@ impl GameWorld {
             droppers,
         } = ctx;
         let voxel_at = |chunks: &HashMap<ChunkPos, Chunk>, pos: IVec3| -> Option<Voxel> {
-            let local_y = world_y_to_local_y(pos.y)?;
-
-            let chunk_pos = ChunkPos::new(
-                pos.x.div_euclid(CHUNK_SIZE_X as i32),
-                pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-            );
-            let chunk = chunks.get(&chunk_pos)?;
-            let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-            let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-            Some(chunk.voxel(local_x, local_y, local_z))
+            chunks.voxel_at(pos.x, pos.y, pos.z)
         };
 
         let mut changed_positions: Vec<IVec3> = Vec::new();
@@ -17721,6 +17104,7 @@ impl GameWorld {
                                 face_normal: IVec3::ZERO,
                                 distance: 0.0,
                                 hit_pos: glam::Vec3::ZERO,
+                                face_uv: glam::Vec2::ZERO,
                             }
                         } else {
                             RaycastHit {
@@ -17728,12 +17112,18 @@ impl GameWorld {
                                 face_normal,
                                 distance: 0.0,
                                 hit_pos: glam::Vec3::ZERO,
+                                face_uv: glam::Vec2::ZERO,
                             }
                         };
 
-                        if let Some((new_bucket_id, mut changed)) =
-                            try_bucket_interaction(key.dimension, bucket_id, hit, chunks, fluid_sim)
-                        {
+                        if let Some((new_bucket_id, mut changed)) = try_bucket_interaction(
+                            key.dimension,
+                            bucket_id,
+                            hit,
+                            chunks,
+                            fluid_sim,
+                            &mut |_, _| true,
+                        ) {
                             restore_one_into_core_slot(
                                 &mut dispenser.slots,
                                 source_idx,
@@ -18184,9 +17574,9 @@ impl GameWorld {
         // Tick all effects and remove expired ones
         // Note: StatusEffects.tick() handles one tick per call, independent of dt
         // In a real implementation, we'd track tick timing
-        let expired = self.status_effects.tick();
-        for effect_type in expired {
-            tracing::info!("Status effect {:?} expired", effect_type);
+        let expired = self.status_effects.tick(1);
+        for event in expired {
+            tracing::info!("Status effect {:?} expired", event.effect_type);
         }
     }
 
@@ -18839,24 +18229,19 @@ impl GameWorld {
     }
 }
 
+/// Fill or empty `held_bucket_id` against `hit`.
+///
+/// `can_edit` is asked before the world is changed; a denied edit leaves the
+/// bucket and the world untouched.
 fn try_bucket_interaction(
     dimension: DimensionId,
     held_bucket_id: u16,
     hit: RaycastHit,
     chunks: &mut HashMap<ChunkPos, Chunk>,
     fluid_sim: &mut FluidSimulator,
+    can_edit: &mut dyn FnMut(EditKind, IVec3) -> bool,
 ) -> Option<(u16, Vec<IVec3>)> {
-    let chunk_and_local = |pos: IVec3| -> Option<(ChunkPos, usize, usize, usize)> {
-        let local_y = world_y_to_local_y(pos.y)?;
-
-        let chunk_pos = ChunkPos::new(
-            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-        );
-        let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-        let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-        Some((chunk_pos, local_x, local_y, local_z))
-    };
+    let chunk_and_local = |pos: IVec3| chunk_local_coords(pos.x, pos.y, pos.z);
 
     match held_bucket_id {
         CORE_ITEM_BUCKET => {
@@ -18875,6 +18260,9 @@ fn try_bucket_interaction(
                 } else {
                     return None;
                 };
+            if !can_edit(EditKind::Break, target_pos) {
+                return None;
+            }
 
             {
                 let chunk = chunks.get_mut(&chunk_pos)?;
@@ -18923,6 +18311,9 @@ fn try_bucket_interaction(
                 if mdminecraft_world::block_supports_waterlogging(voxel.id)
                     && !mdminecraft_world::is_waterlogged(voxel.state)
                 {
+                    if !can_edit(EditKind::Place, target_pos) {
+                        return None;
+                    }
                     {
                         let chunk = chunks.get_mut(&chunk_pos)?;
                         let new_state = mdminecraft_world::set_waterlogged(voxel.state, true);
@@ -18949,6 +18340,9 @@ fn try_bucket_interaction(
             let place_pos = hit.block_pos + hit.face_normal;
             let (chunk_pos, local_x, local_y, local_z) = chunk_and_local(place_pos)?;
             let voxel = chunks.get(&chunk_pos)?.voxel(local_x, local_y, local_z);
+            if !can_edit(EditKind::Place, place_pos) {
+                return None;
+            }
 
             if fluid_type == FluidType::Water
                 && mdminecraft_world::block_supports_waterlogging(voxel.id)
@@ -19036,22 +18430,9 @@ fn try_activate_nether_portal(
     chunks: &mut HashMap<ChunkPos, Chunk>,
     ignition_pos: IVec3,
 ) -> Option<Vec<IVec3>> {
-    let chunk_and_local = |pos: IVec3| -> Option<(ChunkPos, usize, usize, usize)> {
-        let local_y = world_y_to_local_y(pos.y)?;
-
-        let chunk_pos = ChunkPos::new(
-            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-        );
-        let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-        let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-        Some((chunk_pos, local_x, local_y, local_z))
-    };
+    let chunk_and_local = |pos: IVec3| chunk_local_coords(pos.x, pos.y, pos.z);
 
-    let voxel_id_at = |pos: IVec3| -> Option<BlockId> {
-        let (chunk_pos, local_x, local_y, local_z) = chunk_and_local(pos)?;
-        Some(chunks.get(&chunk_pos)?.voxel(local_x, local_y, local_z).id)
-    };
+    let voxel_id_at = |pos: IVec3| chunks.voxel_at(pos.x, pos.y, pos.z).map(|voxel| voxel.id);
 
     let set_voxel = |chunks: &mut HashMap<ChunkPos, Chunk>,
                      pos: IVec3,
@@ -19156,29 +18537,11 @@ fn try_activate_end_portal(
     chunks: &mut HashMap<ChunkPos, Chunk>,
     frame_pos: IVec3,
 ) -> Option<Vec<IVec3>> {
-    let chunk_and_local = |pos: IVec3| -> Option<(ChunkPos, usize, usize, usize)> {
-        let local_y = world_y_to_local_y(pos.y)?;
-
-        let chunk_pos = ChunkPos::new(
-            pos.x.div_euclid(CHUNK_SIZE_X as i32),
-            pos.z.div_euclid(CHUNK_SIZE_Z as i32),
-        );
-        let local_x = pos.x.rem_euclid(CHUNK_SIZE_X as i32) as usize;
-        let local_z = pos.z.rem_euclid(CHUNK_SIZE_Z as i32) as usize;
-        Some((chunk_pos, local_x, local_y, local_z))
-    };
-
-    let voxel_at = |pos: IVec3| -> Option<Voxel> {
-        let (chunk_pos, local_x, local_y, local_z) = chunk_and_local(pos)?;
-        Some(chunks.get(&chunk_pos)?.voxel(local_x, local_y, local_z))
-    };
+    let voxel_at = |pos: IVec3| chunks.voxel_at(pos.x, pos.y, pos.z);
 
     let set_voxel =
         |chunks: &mut HashMap<ChunkPos, Chunk>, pos: IVec3, voxel: Voxel| -> Option<()> {
-            let (chunk_pos, local_x, local_y, local_z) = chunk_and_local(pos)?;
-            let chunk = chunks.get_mut(&chunk_pos)?;
-            chunk.set_voxel(local_x, local_y, local_z, voxel);
-            Some(())
+            chunks.set_voxel_at(pos.x, pos.y, pos.z, voxel).then_some(())
         };
 
     let has_eye = |voxel: Voxel| -> bool { (voxel.state & 0x01) != 0 };
@@ -19797,6 +19160,18 @@ fn render_xp_bar(ctx: &egui::Context, xp: &PlayerXP) {
         });
 }
 
+fn render_edit_denied(ctx: &egui::Context, reason: EditDenied) {
+    egui::Area::new(egui::Id::new("edit_denied"))
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -110.0])
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(reason.to_string())
+                    .size(14.0)
+                    .color(egui::Color32::from_rgb(255, 120, 120)),
+            );
+        });
+}
+
 fn render_boss_bar(ctx: &egui::Context, name: &str, health: f32, max_health: f32) {
     let ratio = if max_health > 0.0 {
         (health / max_health).clamp(0.0, 1.0)
@@ -19963,6 +19338,12 @@ fn render_pause_menu(
                     ("Space", "Space"),
                     ("ShiftLeft", "Left Shift"),
                     ("ControlLeft", "Left Ctrl"),
+                    ("ShiftRight", "Right Shift"),
+                    ("ControlRight", "Right Ctrl"),
+                    ("ArrowUp", "Up Arrow"),
+                    ("ArrowDown", "Down Arrow"),
+                    ("ArrowLeft", "Left Arrow"),
+                    ("ArrowRight", "Right Arrow"),
                     ("Tab", "Tab"),
                     ("F3", "F3"),
                     ("F4", "F4"),
@@ -20725,10 +20106,6 @@ impl UiDragState {
     }
 }
 
-fn stacks_match_for_merge(a: &ItemStack, b: &ItemStack) -> bool {
-    a.item_type == b.item_type && a.durability == b.durability && a.enchantments == b.enchantments
-}
-
 fn restore_one_into_core_slot(slots: &mut [Option<ItemStack>], idx: usize, stack: ItemStack) {
     debug_assert_eq!(stack.count, 1);
 
@@ -20737,7 +20114,7 @@ fn restore_one_into_core_slot(slots: &mut [Option<ItemStack>], idx: usize, stack
     }
 
     match slots[idx].as_mut() {
-        Some(existing) if stacks_match_for_merge(existing, &stack) => {
+        Some(existing) if existing.can_merge_with(&stack) => {
             existing.count = existing.count.saturating_add(1);
         }
         None => {
@@ -20952,18 +20329,11 @@ fn apply_slot_primary_click(slot: &mut Option<ItemStack>, cursor: &mut Option<It
         return;
     };
 
-    if stacks_match_for_merge(slot_stack, cursor_stack) {
-        let max = slot_stack.max_stack_size();
-        if slot_stack.count < max {
-            let space = max - slot_stack.count;
-            let to_move = space.min(cursor_stack.count);
-            slot_stack.count += to_move;
-            cursor_stack.count -= to_move;
-            if cursor_stack.count == 0 {
-                *cursor = None;
-            }
-            return;
+    if slot_stack.try_merge(cursor_stack) > 0 {
+        if cursor_stack.count == 0 {
+            *cursor = None;
         }
+        return;
     }
 
     std::mem::swap(slot, cursor);
@@ -21005,7 +20375,7 @@ fn apply_slot_secondary_click(slot: &mut Option<ItemStack>, cursor: &mut Option<
         return;
     };
 
-    if !stacks_match_for_merge(slot_stack, cursor_stack) {
+    if !slot_stack.can_merge_with(cursor_stack) {
         return;
     }
 
@@ -21047,7 +20417,7 @@ fn try_drag_place_one_from_cursor(
     let Some(slot_stack) = slot.as_mut() else {
         return false;
     };
-    if !stacks_match_for_merge(slot_stack, cursor_stack) {
+    if !slot_stack.can_merge_with(cursor_stack) {
         return false;
     }
 
@@ -21382,26 +20752,11 @@ fn try_add_stack_to_cursor(
             return None;
         }
 
-        let mut placed = stack.clone();
-        placed.count = max;
-        stack.count -= max;
-        *cursor = Some(placed);
+        *cursor = stack.split(max);
         return Some(stack);
     };
 
-    if !stacks_match_for_merge(cursor_stack, &stack) {
-        return Some(stack);
-    }
-
-    let max = cursor_stack.max_stack_size();
-    if cursor_stack.count >= max {
-        return Some(stack);
-    }
-
-    let space = max - cursor_stack.count;
-    let to_add = space.min(stack.count);
-    cursor_stack.count += to_add;
-    stack.count -= to_add;
+    cursor_stack.try_merge(&mut stack);
 
     if stack.count == 0 {
         None
@@ -21974,19 +21329,7 @@ fn try_shift_move_core_stack_into_slots(
         if stack.count == 0 {
             break;
         }
-        if !stacks_match_for_merge(existing, stack) {
-            continue;
-        }
-
-        let max = existing.max_stack_size();
-        if existing.count >= max {
-            continue;
-        }
-
-        let space = max - existing.count;
-        let to_add = space.min(stack.count);
-        existing.count += to_add;
-        stack.count -= to_add;
+        existing.try_merge(stack);
     }
 
     // Then fill empty slots, splitting if needed.
@@ -21998,17 +21341,7 @@ fn try_shift_move_core_stack_into_slots(
             continue;
         }
 
-        let max = stack.max_stack_size();
-        if stack.count <= max {
-            *slot = Some(stack.clone());
-            stack.count = 0;
-            break;
-        }
-
-        let mut placed = stack.clone();
-        placed.count = max;
-        *slot = Some(placed);
-        stack.count -= max;
+        *slot = stack.split(stack.count.min(stack.max_stack_size()));
     }
 
     stack.count != before
@@ -23171,6 +22504,10 @@ fn recipe_definition_to_crafting_recipe(
         anyhow::bail!("Recipe '{}' output count must be > 0", def.name);
     }
 
+    if let Some(rows) = &def.pattern {
+        return shaped_pack_recipe(def, rows, blocks);
+    }
+
     let mut inputs = Vec::with_capacity(def.inputs.len());
     let mut total_required: u32 = 0;
     for input in &def.inputs {
@@ -23217,6 +22554,110 @@ fn recipe_definition_to_crafting_recipe(
     })
 }
 
+/// Build a shaped pack recipe; inputs are counted from the `key` characters in `rows`.
+///
+/// The layout follows [`mdminecraft_assets::ShapedPattern`]: rows must all be the
+/// same width, the pattern is trimmed to its bounding box, and its left-right
+/// mirror image also matches.
+fn shaped_pack_recipe(
+    def: &mdminecraft_assets::RecipeDefinition,
+    rows: &[String],
+    blocks: &BlockRegistry,
+) -> Result<CraftingRecipe> {
+    let key = def
+        .key
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Recipe '{}' has a pattern but no key", def.name))?;
+
+    // Lay the pattern out with one stand-in item per distinct ingredient so the
+    // assets crate validates and trims it; block tags have no `ItemType` of their own.
+    let mut ingredients: Vec<CraftingIngredient> = Vec::new();
+    let mut symbol_rows: Vec<Vec<Option<ItemType>>> = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut symbol_row = Vec::with_capacity(row.len());
+        for symbol in row.chars() {
+            if symbol == ' ' {
+                symbol_row.push(None);
+                continue;
+            }
+            let item = key.get(&symbol).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Recipe '{}' pattern uses '{}' which is not in its key",
+                    def.name,
+                    symbol
+                )
+            })?;
+            let ingredient = parse_pack_crafting_ingredient(item, blocks).ok_or_else(|| {
+                anyhow::anyhow!("Recipe '{}' has unknown input item '{}'", def.name, item)
+            })?;
+            let index = match ingredients
+                .iter()
+                .position(|existing| *existing == ingredient)
+            {
+                Some(index) => index,
+                None => {
+                    ingredients.push(ingredient);
+                    ingredients.len() - 1
+                }
+            };
+            symbol_row.push(Some(ItemType::Block(index as u16)));
+        }
+        symbol_rows.push(symbol_row);
+    }
+
+    let shape = mdminecraft_assets::ShapedPattern::new(symbol_rows).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Recipe '{}' pattern must be 1x1 to 3x3 with equal-width rows and at least one input",
+            def.name
+        )
+    })?;
+    let (width, height) = (shape.width(), shape.height());
+
+    let ingredient_at = |item: ItemType| match item {
+        ItemType::Block(index) => ingredients[index as usize].clone(),
+        _ => unreachable!("pattern cells are stand-in block indices"),
+    };
+    let mut cells: [[Option<CraftingIngredient>; 3]; 3] = Default::default();
+    for (r, row) in cells.iter_mut().enumerate().take(height) {
+        for (c, cell) in row.iter_mut().enumerate().take(width) {
+            *cell = shape.cell(c, r).map(ingredient_at);
+        }
+    }
+    let inputs: Vec<(CraftingIngredient, u32)> = shape
+        .inputs()
+        .into_iter()
+        .map(|(item, count)| (ingredient_at(item), count))
+        .collect();
+
+    let output = parse_pack_item_type(&def.output.item, blocks).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Recipe '{}' has unknown output item '{}'",
+            def.name,
+            def.output.item
+        )
+    })?;
+
+    let min_grid_size = if width <= 2 && height <= 2 {
+        CraftingGridSize::TwoByTwo
+    } else {
+        CraftingGridSize::ThreeByThree
+    };
+
+    Ok(CraftingRecipe {
+        inputs,
+        output,
+        output_count: def.output.count,
+        pattern: Some(CraftingPattern {
+            width,
+            height,
+            cells,
+        }),
+        allow_horizontal_mirror: true,
+        min_grid_size,
+        allow_extra_counts_of_required_types: false,
+    })
+}
+
 fn parse_pack_item_type(token: &str, blocks: &BlockRegistry) -> Option<ItemType> {
     let token = token.trim();
     if token.is_empty() {
@@ -26358,7 +25799,7 @@ fn cursor_can_accept_full_stack(cursor: &Option<ItemStack>, stack: &ItemStack) -
     match cursor {
         None => stack.count <= stack.max_stack_size(),
         Some(cursor_stack) => {
-            stacks_match_for_merge(cursor_stack, stack)
+            cursor_stack.can_merge_with(stack)
                 && cursor_stack.count + stack.count <= cursor_stack.max_stack_size()
         }
     }
@@ -26374,7 +25815,7 @@ fn cursor_add_full_stack(cursor: &mut Option<ItemStack>, stack: ItemStack) {
             *cursor = Some(stack);
         }
         Some(cursor_stack) => {
-            debug_assert!(stacks_match_for_merge(cursor_stack, &stack));
+            debug_assert!(cursor_stack.can_merge_with(&stack));
             cursor_stack.count += stack.count;
         }
     }
@@ -29100,8 +28541,8 @@ impl commands::CommandContext for GameWorld {
     fn teleport_player(&mut self, x: f64, y: f64, z: f64) -> anyhow::Result<()> {
         let camera = self.renderer.camera_mut();
         camera.position = glam::Vec3::new(x as f32, y as f32, z as f32);
-        self.player_physics.velocity = glam::Vec3::ZERO;
-        self.player_physics.on_ground = false;
+        self.player_physics.controller.velocity = glam::Vec3::ZERO;
+        self.player_physics.controller.on_ground = false;
 
         // Preload a bounded number of chunks around the new position so teleporting isn't a black
         // screen, without stalling on slower GPUs or defeating headless step control.
@@ -29403,16 +28844,13 @@ impl commands::CommandContext for GameWorld {
     fn set_weather(&mut self, state: WeatherState) {
         self.weather.set_state(state);
         // Deterministic reschedule.
-        let delay_ticks = Self::weather_delay_ticks(
-            self.world_seed,
-            self.sim_tick,
-            900..2400, // 45..120 seconds at 20 TPS
-            0x0057_4541_5448_4552_u64,
-        );
-        self.weather_next_change_tick = self.sim_tick.advance(delay_ticks);
+        self.weather.reschedule(self.sim_tick, self.world_seed);
     }
 
     fn set_gamemode(&mut self, mode: commands::Gamemode) {
+        self.gamemode = mode;
+        self.mining_progress = None;
+        self.debug_hud.mining_progress = None;
         let wants_physics = matches!(mode, commands::Gamemode::Survival);
         if self.player_physics.physics_enabled != wants_physics {
             self.player_physics.toggle_physics();
@@ -29926,20 +29364,20 @@ mod tests {
         BrewingStandState, ChestState, Chunk, ChunkPos, CraftingGridSize, DispenserState,
         DroppedItemType, EnchantingTableState, Enchantment, EnchantmentType, FluidSimulator,
         FluidType, FurnaceSlotKind, FurnaceState, GameWorld, HopperState, Hotbar, ItemManager,
-        ItemStack, ItemType, MainInventory, MobType, PlayerHealth, PlayerPhysics, StatusEffectType,
-        StatusEffects, ToolMaterial, ToolType, UiCoreSlotId, UiSlotClick, Voxel, AABB, BLOCK_AIR,
-        BLOCK_BOOKSHELF, BLOCK_BREWING_STAND, BLOCK_BROWN_MUSHROOM, BLOCK_COBBLESTONE,
-        BLOCK_CRAFTING_TABLE, BLOCK_CRYING_OBSIDIAN, BLOCK_ENCHANTING_TABLE, BLOCK_END_PORTAL,
-        BLOCK_END_PORTAL_FRAME, BLOCK_FURNACE, BLOCK_GLOWSTONE, BLOCK_NETHER_PORTAL, BLOCK_OAK_LOG,
-        BLOCK_OAK_PLANKS, BLOCK_OBSIDIAN, BLOCK_RESPAWN_ANCHOR, BLOCK_SUGAR_CANE,
-        CORE_ITEM_BLAZE_POWDER, CORE_ITEM_BOOK, CORE_ITEM_BUCKET, CORE_ITEM_ENDER_PEARL,
-        CORE_ITEM_EYE_OF_ENDER, CORE_ITEM_FERMENTED_SPIDER_EYE, CORE_ITEM_FLINT_AND_STEEL,
-        CORE_ITEM_GHAST_TEAR, CORE_ITEM_GLASS_BOTTLE, CORE_ITEM_GLISTERING_MELON,
-        CORE_ITEM_GLOWSTONE_DUST, CORE_ITEM_GUNPOWDER, CORE_ITEM_LAVA_BUCKET,
-        CORE_ITEM_MAGMA_CREAM, CORE_ITEM_NETHER_QUARTZ, CORE_ITEM_NETHER_WART, CORE_ITEM_PAPER,
-        CORE_ITEM_PHANTOM_MEMBRANE, CORE_ITEM_PUFFERFISH, CORE_ITEM_RABBIT_FOOT,
-        CORE_ITEM_REDSTONE_DUST, CORE_ITEM_SPIDER_EYE, CORE_ITEM_SUGAR, CORE_ITEM_WATER_BOTTLE,
-        CORE_ITEM_WATER_BUCKET, CORE_ITEM_WHEAT, CORE_ITEM_WHEAT_SEEDS,
+        ItemStack, ItemType, MainInventory, MobType, PlayerController, PlayerHealth,
+        StatusEffectType, StatusEffects, ToolMaterial, ToolType, UiCoreSlotId, UiSlotClick, Voxel,
+        AABB, BLOCK_AIR, BLOCK_BOOKSHELF, BLOCK_BREWING_STAND, BLOCK_BROWN_MUSHROOM,
+        BLOCK_COBBLESTONE, BLOCK_CRAFTING_TABLE, BLOCK_CRYING_OBSIDIAN, BLOCK_ENCHANTING_TABLE,
+        BLOCK_END_PORTAL, BLOCK_END_PORTAL_FRAME, BLOCK_FURNACE, BLOCK_GLOWSTONE,
+        BLOCK_NETHER_PORTAL, BLOCK_OAK_LOG, BLOCK_OAK_PLANKS, BLOCK_OBSIDIAN, BLOCK_RESPAWN_ANCHOR,
+        BLOCK_SUGAR_CANE, CORE_ITEM_BLAZE_POWDER, CORE_ITEM_BOOK, CORE_ITEM_BUCKET,
+        CORE_ITEM_ENDER_PEARL, CORE_ITEM_EYE_OF_ENDER, CORE_ITEM_FERMENTED_SPIDER_EYE,
+        CORE_ITEM_FLINT_AND_STEEL, CORE_ITEM_GHAST_TEAR, CORE_ITEM_GLASS_BOTTLE,
+        CORE_ITEM_GLISTERING_MELON, CORE_ITEM_GLOWSTONE_DUST, CORE_ITEM_GUNPOWDER,
+        CORE_ITEM_LAVA_BUCKET, CORE_ITEM_MAGMA_CREAM, CORE_ITEM_NETHER_QUARTZ,
+        CORE_ITEM_NETHER_WART, CORE_ITEM_PAPER, CORE_ITEM_PHANTOM_MEMBRANE, CORE_ITEM_PUFFERFISH,
+        CORE_ITEM_RABBIT_FOOT, CORE_ITEM_REDSTONE_DUST, CORE_ITEM_SPIDER_EYE, CORE_ITEM_SUGAR,
+        CORE_ITEM_WATER_BOTTLE, CORE_ITEM_WATER_BUCKET, CORE_ITEM_WHEAT, CORE_ITEM_WHEAT_SEEDS,
     };
     use crate::content_pack_loot;
     use mdminecraft_core::DimensionId;
@@ -30253,7 +29691,7 @@ mod tests {
 
         GameWorld::resolve_mob_world_collisions(&chunks, &block_properties, &mut mob, before_pos);
 
-        let expected_y = 0.0 + 1.0 + PlayerPhysics::GROUND_EPS as f64;
+        let expected_y = 0.0 + 1.0 + PlayerController::GROUND_EPS as f64;
         assert!(
             (mob.y - expected_y).abs() < 1e-6,
             "mob.y = {}, expected {}",
@@ -30294,7 +29732,7 @@ mod tests {
 
         let block_properties = BlockPropertiesRegistry::new();
 
-        let start_y = 0.0 + 1.0 + PlayerPhysics::GROUND_EPS as f64;
+        let start_y = 0.0 + 1.0 + PlayerController::GROUND_EPS as f64;
         let before_pos = (0.5, start_y, 0.5);
         let mut mob =
             mdminecraft_world::Mob::new(1.5, start_y, 0.5, mdminecraft_world::MobType::Zombie);
@@ -32815,10 +32253,10 @@ mod tests {
 
         let move_right = glam::Vec3::new(0.6, 0.0, 0.0);
 
-        let (no_step_offset, _) = GameWorld::move_with_collision(
+        let (no_step_offset, _) = mdminecraft_world::move_with_collision(
             &chunks,
             &block_properties,
-            &current_aabb,
+            &current_aabb.into(),
             move_right,
             0.0,
         );
@@ -32827,12 +32265,12 @@ mod tests {
             "Without step-up, the slab should block horizontal movement"
         );
 
-        let (step_offset, _) = GameWorld::move_with_collision(
+        let (step_offset, _) = mdminecraft_world::move_with_collision(
             &chunks,
             &block_properties,
-            &current_aabb,
+            &current_aabb.into(),
             move_right,
-            PlayerPhysics::STEP_HEIGHT,
+            PlayerController::STEP_HEIGHT,
         );
         assert!(
             step_offset.x > 0.5,
@@ -32886,12 +32324,12 @@ mod tests {
 
         let move_right = glam::Vec3::new(0.6, 0.0, 0.0);
 
-        let (step_offset, _) = GameWorld::move_with_collision(
+        let (step_offset, _) = mdminecraft_world::move_with_collision(
             &chunks,
             &block_properties,
-            &current_aabb,
+            &current_aabb.into(),
             move_right,
-            PlayerPhysics::STEP_HEIGHT,
+            PlayerController::STEP_HEIGHT,
         );
         assert!(
             step_offset.x > 0.5,
@@ -33090,7 +32528,11 @@ mod tests {
             glam::Vec3::new(0.6, 1.8, 0.6),
         );
 
-        assert!(GameWorld::aabb_touches_ladder(&chunks, &player_aabb));
+        assert!(mdminecraft_world::touches_ladder(
+            &chunks,
+            &block_properties,
+            &player_aabb.into()
+        ));
         assert!(!GameWorld::aabb_collides_with_world(
             &chunks,
             &block_properties,
@@ -33098,6 +32540,114 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn furnace_and_log_placement_states_are_oriented() {
+        let state = GameWorld::placement_state_for_block(
+            BLOCK_FURNACE,
+            std::f32::consts::PI,
+            glam::IVec3::new(0, 1, 0),
+            0.0,
+        )
+        .expect("furnace should be placeable on a top face");
+        assert_eq!(state, mdminecraft_world::Facing::North.to_state());
+
+        let state = GameWorld::placement_state_for_block(
+            BLOCK_OAK_LOG,
+            0.0,
+            glam::IVec3::new(1, 0, 0),
+            0.5,
+        )
+        .expect("log should be placeable on a side face");
+        assert_eq!(
+            mdminecraft_world::Axis::from_state(state),
+            mdminecraft_world::Axis::X
+        );
+    }
+
+    #[test]
+    fn pause_options_fov_applies_to_camera_immediately() {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("mdminecraft_live_fov_{timestamp}"));
+        let mut world = GameWorld::new_headless(
+            std::sync::Arc::new(crate::config::ControlsConfig::default()),
+            None,
+            None,
+            super::GameWorldOptions::default(),
+            dir.clone(),
+            Some(1),
+            true,
+            true,
+        )
+        .expect("headless world");
+        let initial_fov = world.renderer.camera().fov;
+
+        // Same update the Options slider performs.
+        let mut next_controls = world.controls.as_ref().clone();
+        next_controls.fov_degrees = 100.0;
+        world.controls = std::sync::Arc::new(next_controls);
+        world.apply_live_controls();
+
+        let fov = world.renderer.camera().fov;
+        assert!((fov - 100.0_f32.to_radians()).abs() < 1e-6);
+        assert!((fov - initial_fov).abs() > f32::EPSILON);
+
+        drop(world);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn creative_breaks_instantly_without_mining_progress() {
+        let pos = glam::IVec3::new(3, 64, -2);
+        let mut progress = None;
+        let done = GameWorld::advance_mining(
+            &mut progress,
+            crate::commands::Gamemode::Creative,
+            pos,
+            0.0,
+            || panic!("creative must not compute mining time"),
+        );
+        assert!(done);
+        assert!(progress.is_none());
+
+        let done = GameWorld::advance_mining(
+            &mut progress,
+            crate::commands::Gamemode::Survival,
+            pos,
+            0.5,
+            || 1.0,
+        );
+        assert!(!done);
+        assert!(progress.as_ref().is_some_and(|p| p.block_pos == pos));
+
+        let done = GameWorld::advance_mining(
+            &mut progress,
+            crate::commands::Gamemode::Survival,
+            pos,
+            0.5,
+            || unreachable!("progress continues on the same block"),
+        );
+        assert!(done);
+    }
+
+    #[test]
+    fn creative_use_keeps_hotbar_counts() {
+        let mut hotbar = Hotbar::new();
+        hotbar.select_slot(6);
+        let before = hotbar.selected_item().map(|item| item.count);
+
+        hotbar.consume_selected(crate::commands::Gamemode::Creative);
+        assert_eq!(hotbar.selected_item().map(|item| item.count), before);
+
+        hotbar.consume_selected(crate::commands::Gamemode::Survival);
+        assert_eq!(
+            hotbar.selected_item().map(|item| item.count),
+            before.map(|count| count - 1)
+        );
+    }
+
     #[test]
     fn ladder_placement_state_is_opposite_face_normal() {
         let state = GameWorld::placement_state_for_block(
@@ -34599,6 +34149,43 @@ mod tests {
         assert_eq!(chunk.voxel(1, 65, 1).id, mdminecraft_world::BLOCK_AIR);
     }
 
+    #[test]
+    fn newer_world_meta_aborts_load_without_overwriting_it() {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("mdminecraft_newer_meta_{timestamp}"));
+        let store = mdminecraft_world::RegionStore::new(&dir).expect("region store");
+        store
+            .save_world_meta(&mdminecraft_world::WorldMeta {
+                world_seed: 99,
+                end_boss_defeated: false,
+                format_version: mdminecraft_world::CHUNK_FORMAT_VERSION + 1,
+            })
+            .expect("save world meta");
+        let meta_path = dir.join("world.meta");
+        let before = std::fs::read(&meta_path).expect("read world meta");
+
+        let result = GameWorld::new_headless(
+            std::sync::Arc::new(crate::config::ControlsConfig::default()),
+            None,
+            None,
+            super::GameWorldOptions::default(),
+            dir.clone(),
+            None,
+            true,
+            true,
+        );
+        let Err(err) = result else {
+            panic!("opened a world saved by a newer build");
+        };
+        assert!(format!("{err:#}").contains("Game upgrade required"));
+        assert_eq!(std::fs::read(&meta_path).expect("reread world meta"), before);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn stage3_first_night_scenario_survives_save_load() {
         let mut hotbar = Hotbar {
@@ -34752,6 +34339,7 @@ mod tests {
         let store = mdminecraft_world::RegionStore::new(&dir).expect("region store");
 
         let player_save = mdminecraft_world::PlayerSave {
+            version: mdminecraft_world::PLAYER_SAVE_VERSION,
             transform: mdminecraft_world::PlayerTransform {
                 dimension: mdminecraft_core::DimensionId::DEFAULT,
                 x: 0.0,
@@ -35590,6 +35178,7 @@ mod tests {
             face_normal: glam::IVec3::ZERO,
             distance: 0.0,
             hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
         };
 
         let result = super::try_bucket_interaction(
@@ -35598,6 +35187,7 @@ mod tests {
             hit,
             &mut chunks,
             &mut fluid_sim,
+            &mut |_, _| true,
         );
         assert_eq!(
             result,
@@ -35634,6 +35224,7 @@ mod tests {
             face_normal: glam::IVec3::ZERO,
             distance: 0.0,
             hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
         };
 
         let result = super::try_bucket_interaction(
@@ -35642,6 +35233,7 @@ mod tests {
             hit,
             &mut chunks,
             &mut fluid_sim,
+            &mut |_, _| true,
         );
         assert_eq!(
             result,
@@ -35680,6 +35272,7 @@ mod tests {
             face_normal: glam::IVec3::ZERO,
             distance: 0.0,
             hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
         };
 
         let result = super::try_bucket_interaction(
@@ -35688,6 +35281,7 @@ mod tests {
             hit,
             &mut chunks,
             &mut fluid_sim,
+            &mut |_, _| true,
         );
         assert_eq!(
             result,
@@ -35725,6 +35319,7 @@ mod tests {
             face_normal: glam::IVec3::new(1, 0, 0),
             distance: 0.0,
             hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
         };
 
         let result = super::try_bucket_interaction(
@@ -35733,6 +35328,7 @@ mod tests {
             hit,
             &mut chunks,
             &mut fluid_sim,
+            &mut |_, _| true,
         );
         assert_eq!(
             result,
@@ -35747,6 +35343,65 @@ mod tests {
         assert_eq!(fluid_sim.pending_count(), 1);
     }
 
+    #[test]
+    fn bucket_leaves_world_untouched_when_edit_is_denied() {
+        use mdminecraft_world::EditKind;
+
+        let mut chunk = Chunk::new(ChunkPos::new(0, 0));
+        chunk.set_voxel(
+            1,
+            local_y(64),
+            1,
+            Voxel {
+                id: FluidType::Water.source_block_id(),
+                ..Default::default()
+            },
+        );
+
+        let mut chunks = std::collections::HashMap::new();
+        chunks.insert(ChunkPos::new(0, 0), chunk);
+        let mut fluid_sim = FluidSimulator::new();
+
+        let hit = super::RaycastHit {
+            block_pos: glam::IVec3::new(1, 64, 1),
+            face_normal: glam::IVec3::new(1, 0, 0),
+            distance: 0.0,
+            hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
+        };
+
+        let mut asked = Vec::new();
+        for bucket in [CORE_ITEM_BUCKET, CORE_ITEM_WATER_BUCKET] {
+            let result = super::try_bucket_interaction(
+                DimensionId::Overworld,
+                bucket,
+                hit,
+                &mut chunks,
+                &mut fluid_sim,
+                &mut |kind, pos| {
+                    asked.push((kind, pos));
+                    false
+                },
+            );
+            assert_eq!(result, None);
+        }
+        assert_eq!(
+            asked,
+            vec![
+                (EditKind::Break, glam::IVec3::new(1, 64, 1)),
+                (EditKind::Place, glam::IVec3::new(2, 64, 1)),
+            ]
+        );
+
+        let chunk = chunks.get(&ChunkPos::new(0, 0)).unwrap();
+        assert_eq!(
+            chunk.voxel(1, local_y(64), 1).id,
+            FluidType::Water.source_block_id()
+        );
+        assert_eq!(chunk.voxel(2, local_y(64), 1).id, BLOCK_AIR);
+        assert_eq!(fluid_sim.pending_count(), 0);
+    }
+
     #[test]
     fn water_bucket_extinguishes_fire_block() {
         let mut chunk = Chunk::new(ChunkPos::new(0, 0));
@@ -35778,6 +35433,7 @@ mod tests {
             face_normal: glam::IVec3::new(1, 0, 0),
             distance: 0.0,
             hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
         };
 
         let result = super::try_bucket_interaction(
@@ -35786,6 +35442,7 @@ mod tests {
             hit,
             &mut chunks,
             &mut fluid_sim,
+            &mut |_, _| true,
         );
         assert_eq!(
             result,
@@ -35822,6 +35479,7 @@ mod tests {
             face_normal: glam::IVec3::new(1, 0, 0),
             distance: 0.0,
             hit_pos: glam::Vec3::ZERO,
+            face_uv: glam::Vec2::ZERO,
         };
 
         let result = super::try_bucket_interaction(
@@ -35830,6 +35488,7 @@ mod tests {
             hit,
             &mut chunks,
             &mut fluid_sim,
+            &mut |_, _| true,
         );
         assert_eq!(result, Some((CORE_ITEM_BUCKET, Vec::new())));
 
@@ -36365,6 +36024,46 @@ mod tests {
         assert_eq!(pattern.cells[1][1], Some(ItemType::Block(1).into()));
     }
 
+    #[test]
+    fn pack_shaped_recipes_mirror_and_reject_ragged_rows() {
+        let blocks = super::test_blocks();
+        let defs = mdminecraft_assets::load_recipes_from_str(
+            r#"[
+                {
+                    "name": "test_hook",
+                    "pattern": ["S ", "SD"],
+                    "key": { "S": "stone", "D": "dirt" },
+                    "output": { "item": "sand" }
+                },
+                {
+                    "name": "test_ragged",
+                    "pattern": ["SS", "S"],
+                    "key": { "S": "stone" },
+                    "output": { "item": "sand" }
+                }
+            ]"#,
+        )
+        .expect("recipe json parses");
+
+        let hook = super::recipe_definition_to_crafting_recipe(&defs[0], blocks)
+            .expect("mirrorable recipe should load");
+        assert!(hook.allow_horizontal_mirror);
+        let mirrored = hook
+            .pattern
+            .as_ref()
+            .expect("recipe should be shaped")
+            .mirrored_horizontal();
+        let stone: super::CraftingIngredient =
+            ItemType::Block(mdminecraft_world::BLOCK_STONE).into();
+        let dirt: super::CraftingIngredient = ItemType::Block(mdminecraft_world::BLOCK_DIRT).into();
+        assert_eq!(mirrored.cells[0], [None, Some(stone.clone()), None]);
+        assert_eq!(mirrored.cells[1], [Some(dirt), Some(stone), None]);
+
+        let err = super::recipe_definition_to_crafting_recipe(&defs[1], blocks)
+            .expect_err("ragged pattern rows should be rejected");
+        assert!(err.to_string().contains("equal-width rows"));
+    }
+
     #[test]
     fn pack_crafting_ingredient_parses_block_tags() {
         let blocks = mdminecraft_assets::BlockRegistry::new(vec![
@@ -36380,6 +36079,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
         ]);
@@ -36415,6 +36115,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
         ]);
@@ -36449,6 +36150,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
         ]);
@@ -36478,6 +36180,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
             mdminecraft_assets::BlockDescriptor::from_definition(
@@ -36492,6 +36195,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
         ]);
@@ -36524,6 +36228,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
             mdminecraft_assets::BlockDescriptor::from_definition(
@@ -36538,6 +36243,7 @@ mod tests {
                     texture: None,
                     textures: None,
                     harvest_level: None,
+                    collision: None,
                 },
             ),
         ]);
@@ -3,6 +3,7 @@ use crate::config::ControlsConfig;
 use crate::commentary::CommentaryConfig;
 use crate::game::{GameWorld, GameWorldOptions, RecordConfig, ScreenshotConfig};
 use anyhow::{Context, Result};
+use mdminecraft_world::EditRules;
 use rand::RngCore;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -16,6 +17,8 @@ pub struct HeadlessConfig {
     pub screenshot: Option<ScreenshotConfig>,
     pub record: Option<RecordConfig>,
     pub commentary: Option<CommentaryConfig>,
+    pub edit_rules: EditRules,
+    pub player_is_op: bool,
     pub automation: Option<AutomationEndpoint>,
     pub save_dir: Option<PathBuf>,
     pub no_save: bool,
@@ -40,6 +43,8 @@ pub fn run(cfg: HeadlessConfig) -> Result<()> {
         screenshot: cfg.screenshot,
         record: cfg.record,
         commentary: cfg.commentary,
+        edit_rules: cfg.edit_rules,
+        player_is_op: cfg.player_is_op,
     };
 
     let mut world = GameWorld::new_headless(
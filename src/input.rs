@@ -390,6 +390,12 @@ fn parse_key_code(name: &str) -> Option<KeyCode> {
         "Space" => KeyCode::Space,
         "ShiftLeft" => KeyCode::ShiftLeft,
         "ControlLeft" => KeyCode::ControlLeft,
+        "ShiftRight" => KeyCode::ShiftRight,
+        "ControlRight" => KeyCode::ControlRight,
+        "ArrowUp" => KeyCode::ArrowUp,
+        "ArrowDown" => KeyCode::ArrowDown,
+        "ArrowLeft" => KeyCode::ArrowLeft,
+        "ArrowRight" => KeyCode::ArrowRight,
         "Tab" => KeyCode::Tab,
         "F3" => KeyCode::F3,
         "F4" => KeyCode::F4,
@@ -434,3 +440,54 @@ fn parse_action(name: &str) -> Option<Action> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdminecraft_render::InputState;
+
+    fn snapshot_with_keys(keys: &[KeyCode]) -> InputSnapshot {
+        let mut state = InputState::new();
+        state.context = InputContext::Gameplay;
+        state.keys_pressed.extend(keys.iter().copied());
+        state.snapshot_view()
+    }
+
+    #[test]
+    fn default_bindings_use_wasd_layout() {
+        let mut input = InputProcessor::new(&ControlsConfig::default());
+
+        let state = input.process(&snapshot_with_keys(&[
+            KeyCode::KeyW,
+            KeyCode::KeyA,
+            KeyCode::Space,
+        ]));
+        assert_eq!((state.move_x, state.move_y, state.move_z), (-1.0, 1.0, 1.0));
+
+        let state = input.process(&snapshot_with_keys(&[KeyCode::KeyW, KeyCode::KeyS]));
+        assert_eq!(state.move_y, 0.0);
+    }
+
+    #[test]
+    fn rebinding_forward_moves_with_new_key() {
+        let forward =
+            |input: &mut InputProcessor, key| input.process(&snapshot_with_keys(&[key])).move_y;
+
+        let mut config = ControlsConfig::default();
+        config
+            .bindings
+            .base
+            .insert("MoveForward".to_string(), vec!["ArrowUp".to_string()]);
+        let mut input = InputProcessor::new(&config);
+        assert_eq!(forward(&mut input, KeyCode::KeyW), 0.0);
+        assert_eq!(forward(&mut input, KeyCode::ArrowUp), 1.0);
+
+        // An empty override unbinds the action entirely.
+        config
+            .bindings
+            .base
+            .insert("MoveForward".to_string(), Vec::new());
+        let mut input = InputProcessor::new(&config);
+        assert_eq!(forward(&mut input, KeyCode::ArrowUp), 0.0);
+    }
+}
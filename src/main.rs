@@ -20,6 +20,7 @@ use anyhow::Result;
 use commentary::{CommentaryConfig, CommentaryStyle};
 use config::ControlsConfig;
 use game::{GameWorld, GameWorldOptions, RecordConfig, ScreenshotConfig};
+use mdminecraft_world::EditRules;
 use menu::MenuState;
 use std::net::SocketAddr;
 use std::{env, path::PathBuf, sync::Arc};
@@ -58,6 +59,18 @@ fn main() -> Result<()> {
     }
     let controls = Arc::new(controls);
 
+    let default_rules = EditRules::default();
+    let edit_rules = EditRules {
+        spawn_protection_radius: cli
+            .spawn_protection
+            .unwrap_or(default_rules.spawn_protection_radius),
+        min_build_y: cli.min_build_y.unwrap_or(default_rules.min_build_y),
+        max_build_y: cli.max_build_y.unwrap_or(default_rules.max_build_y),
+    };
+    if edit_rules.min_build_y > edit_rules.max_build_y {
+        anyhow::bail!("--min-build-y must not be above --max-build-y");
+    }
+
     let screenshot = match cli.screenshot_dir.clone() {
         Some(dir) => Some(ScreenshotConfig {
             dir,
@@ -237,6 +250,8 @@ fn main() -> Result<()> {
             screenshot,
             record,
             commentary,
+            edit_rules,
+            player_is_op: cli.op,
             automation: automation_endpoint,
             save_dir: cli.save_dir.clone(),
             no_save: cli.no_save,
@@ -264,6 +279,8 @@ fn main() -> Result<()> {
             screenshot: screenshot.clone(),
             record: record.clone(),
             commentary: commentary.clone(),
+            edit_rules,
+            player_is_op: cli.op,
         };
         match GameWorld::new(
             &event_loop,
@@ -302,6 +319,8 @@ fn main() -> Result<()> {
                             screenshot: screenshot.clone(),
                             record: record.clone(),
                             commentary: commentary.clone(),
+                            edit_rules,
+                            player_is_op: cli.op,
                         };
                         match GameWorld::new(
                             elwt,
@@ -394,6 +413,10 @@ struct CliOptions {
     commentary_min_interval_ms: Option<u64>,
     commentary_max_interval_ms: Option<u64>,
     headless_render_distance: Option<i32>,
+    spawn_protection: Option<u32>,
+    min_build_y: Option<i32>,
+    max_build_y: Option<i32>,
+    op: bool,
 }
 
 impl CliOptions {
@@ -431,6 +454,10 @@ impl CliOptions {
             commentary_min_interval_ms: None,
             commentary_max_interval_ms: None,
             headless_render_distance: None,
+            spawn_protection: None,
+            min_build_y: None,
+            max_build_y: None,
+            op: false,
         };
 
         while let Some(arg) = args.next() {
@@ -690,6 +717,43 @@ impl CliOptions {
                         tracing::error!("--commentary-max-interval-ms requires an integer");
                     }
                 }
+                "--spawn-protection" => {
+                    if let Some(raw) = args.next() {
+                        match raw.parse::<u32>() {
+                            Ok(value) => opts.spawn_protection = Some(value),
+                            Err(err) => {
+                                tracing::error!(%err, value = %raw, "--spawn-protection must be a non-negative integer");
+                            }
+                        }
+                    } else {
+                        tracing::error!("--spawn-protection requires a radius in blocks");
+                    }
+                }
+                "--min-build-y" => {
+                    if let Some(raw) = args.next() {
+                        match raw.parse::<i32>() {
+                            Ok(value) => opts.min_build_y = Some(value),
+                            Err(err) => {
+                                tracing::error!(%err, value = %raw, "--min-build-y must be an integer");
+                            }
+                        }
+                    } else {
+                        tracing::error!("--min-build-y requires an integer");
+                    }
+                }
+                "--max-build-y" => {
+                    if let Some(raw) = args.next() {
+                        match raw.parse::<i32>() {
+                            Ok(value) => opts.max_build_y = Some(value),
+                            Err(err) => {
+                                tracing::error!(%err, value = %raw, "--max-build-y must be an integer");
+                            }
+                        }
+                    } else {
+                        tracing::error!("--max-build-y requires an integer");
+                    }
+                }
+                "--op" => opts.op = true,
                 _ => {}
             }
         }
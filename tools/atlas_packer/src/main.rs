@@ -33,6 +33,11 @@ struct Args {
     #[arg(long, default_value_t = 2)]
     padding: u32,
 
+    /// Pixels of padding filled by replicating tile edges (default: all of it);
+    /// the rest of the gutter stays transparent
+    #[arg(long)]
+    extrude: Option<u32>,
+
     /// Maximum atlas dimension (width & height) in pixels
     #[arg(long, default_value_t = 4096)]
     max_atlas_size: u32,
@@ -44,6 +49,11 @@ struct Args {
     /// Allow resizing textures that don't match the expected tile size
     #[arg(long)]
     allow_mixed_sizes: bool,
+
+    /// Shelf-pack sprites of any size instead of using a square grid
+    /// (`--tile-size` and `--columns` are ignored)
+    #[arg(long)]
+    allow_rectangular: bool,
 }
 
 #[derive(Debug)]
@@ -60,9 +70,22 @@ struct AtlasMetadata {
     rows: u32,
     atlas_width: u32,
     atlas_height: u32,
+    layout: AtlasLayout,
+    /// Hash of texture names, pixels and packing options; unchanged inputs
+    /// always produce the same value.
+    content_hash: u64,
     entries: Vec<AtlasEntry>,
 }
 
+/// Mirrors `mdminecraft_assets::AtlasLayout` so the runtime validates each
+/// packing mode with the right rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AtlasLayout {
+    Grid,
+    Shelf,
+}
+
 #[derive(Debug, Serialize)]
 struct AtlasEntry {
     name: String,
@@ -85,12 +108,19 @@ fn run(args: Args) -> Result<()> {
     if !args.input.is_dir() {
         bail!("Input path {:?} is not a directory", args.input);
     }
+    if let Some(extrude) = args.extrude.filter(|&extrude| extrude > args.padding) {
+        bail!("--extrude {} exceeds --padding {}", extrude, args.padding);
+    }
     let (textures, tile_size) = load_textures(&args)?;
     if textures.is_empty() {
         bail!("No textures found in {}", args.input.display());
     }
 
-    let atlas = build_atlas(&textures, tile_size, &args)?;
+    let atlas = if args.allow_rectangular {
+        build_rectangular_atlas(&textures, &args)?
+    } else {
+        build_atlas(&textures, tile_size, &args)?
+    };
 
     if let Some(parent) = args.output_image.parent() {
         if !parent.as_os_str().is_empty() {
@@ -123,6 +153,7 @@ fn run(args: Args) -> Result<()> {
         atlas.metadata.atlas_height
     );
     println!("Metadata written to {}", args.output_meta.display());
+    println!("Content hash: {:016x}", atlas.metadata.content_hash);
 
     Ok(())
 }
@@ -164,8 +195,7 @@ fn build_atlas(textures: &[Texture], tile_size: u32, args: &Args) -> Result<Pack
         let dest_x = col * stride + padding;
         let dest_y = row * stride + padding;
 
-        copy_into(&tex.image, &mut atlas, dest_x, dest_y);
-        bleed_padding(&mut atlas, dest_x, dest_y, tile_size, padding);
+        copy_into_extruded(&tex.image, &mut atlas, dest_x, dest_y, extrude_width(args));
 
         let u0 = dest_x as f32 / width as f32;
         let v0 = dest_y as f32 / height as f32;
@@ -192,6 +222,8 @@ fn build_atlas(textures: &[Texture], tile_size: u32, args: &Args) -> Result<Pack
         rows,
         atlas_width: width,
         atlas_height: height,
+        layout: AtlasLayout::Grid,
+        content_hash: content_hash(textures, args),
         entries,
     };
 
@@ -201,6 +233,137 @@ fn build_atlas(textures: &[Texture], tile_size: u32, args: &Args) -> Result<Pack
     })
 }
 
+/// Pack mixed-size sprites into shelves, tallest first.
+///
+/// The atlas width is the smallest power of two that fits the widest sprite
+/// and roughly the square root of the total area. In the metadata,
+/// `tile_size` is the largest sprite side, `columns` the most sprites on one
+/// shelf and `rows` the shelf count; consumers should read each entry's rect.
+fn build_rectangular_atlas(textures: &[Texture], args: &Args) -> Result<PackedAtlas> {
+    let padding = args.padding;
+    let padded = |tex: &Texture| {
+        (
+            tex.image.width() + padding * 2,
+            tex.image.height() + padding * 2,
+        )
+    };
+
+    let widest = textures.iter().map(|tex| padded(tex).0).max().unwrap_or(1);
+    let area: u64 = textures
+        .iter()
+        .map(|tex| {
+            let (w, h) = padded(tex);
+            u64::from(w) * u64::from(h)
+        })
+        .sum();
+    let width = widest
+        .max((area as f64).sqrt().ceil() as u32)
+        .next_power_of_two();
+    if width > args.max_atlas_size {
+        bail!("Atlas width {} exceeds max {}", width, args.max_atlas_size);
+    }
+
+    // Tallest first keeps shelves tight; ties fall back to name order.
+    let mut order: Vec<usize> = (0..textures.len()).collect();
+    order.sort_by_key(|&idx| std::cmp::Reverse(textures[idx].image.height()));
+
+    let mut positions = vec![(0, 0); textures.len()];
+    let (mut cursor_x, mut shelf_y, mut shelf_height) = (0, 0, 0);
+    let (mut shelves, mut shelf_count, mut columns) = (1, 0, 0);
+    for idx in order {
+        let (w, h) = padded(&textures[idx]);
+        if cursor_x + w > width {
+            shelf_y += shelf_height;
+            cursor_x = 0;
+            shelf_height = 0;
+            shelves += 1;
+            shelf_count = 0;
+        }
+        positions[idx] = (cursor_x + padding, shelf_y + padding);
+        cursor_x += w;
+        shelf_height = shelf_height.max(h);
+        shelf_count += 1;
+        columns = columns.max(shelf_count);
+    }
+    let height = shelf_y + shelf_height;
+    if height > args.max_atlas_size {
+        bail!(
+            "Atlas size {}x{} exceeds max {}",
+            width,
+            height,
+            args.max_atlas_size
+        );
+    }
+
+    let mut atlas = RgbaImage::new(width, height);
+    let mut entries = Vec::with_capacity(textures.len());
+    for (tex, &(dest_x, dest_y)) in textures.iter().zip(&positions) {
+        let (w, h) = tex.image.dimensions();
+        copy_into_extruded(&tex.image, &mut atlas, dest_x, dest_y, extrude_width(args));
+
+        entries.push(AtlasEntry {
+            name: tex.name.clone(),
+            x: dest_x,
+            y: dest_y,
+            width: w,
+            height: h,
+            u0: dest_x as f32 / width as f32,
+            v0: dest_y as f32 / height as f32,
+            u1: (dest_x + w) as f32 / width as f32,
+            v1: (dest_y + h) as f32 / height as f32,
+        });
+    }
+
+    let tile_size = textures
+        .iter()
+        .map(|tex| tex.image.width().max(tex.image.height()))
+        .max()
+        .unwrap_or(0);
+    let metadata = AtlasMetadata {
+        tile_size,
+        padding,
+        columns,
+        rows: shelves,
+        atlas_width: width,
+        atlas_height: height,
+        layout: AtlasLayout::Shelf,
+        content_hash: content_hash(textures, args),
+        entries,
+    };
+
+    Ok(PackedAtlas {
+        image: atlas,
+        metadata,
+    })
+}
+
+/// Hash the name-sorted texture stream plus the options that affect layout.
+fn content_hash(textures: &[Texture], args: &Args) -> u64 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&args.padding.to_le_bytes());
+    hasher.update(&extrude_width(args).to_le_bytes());
+    hasher.update(&[u8::from(args.allow_rectangular)]);
+    if !args.allow_rectangular {
+        hasher.update(&args.columns.unwrap_or(0).to_le_bytes());
+    }
+
+    for tex in textures {
+        hasher.update(&(tex.name.len() as u64).to_le_bytes());
+        hasher.update(tex.name.as_bytes());
+        hasher.update(&tex.image.width().to_le_bytes());
+        hasher.update(&tex.image.height().to_le_bytes());
+        hasher.update(tex.image.as_raw());
+    }
+
+    let hash = hasher.finalize();
+    u64::from_le_bytes(hash.as_bytes()[0..8].try_into().unwrap())
+}
+
+/// Number of gutter pixels to fill with replicated edges.
+fn extrude_width(args: &Args) -> u32 {
+    args.extrude.unwrap_or(args.padding).min(args.padding)
+}
+
 fn copy_into(src: &RgbaImage, dst: &mut RgbaImage, x: u32, y: u32) {
     for yy in 0..src.height() {
         for xx in 0..src.width() {
@@ -210,17 +373,24 @@ fn copy_into(src: &RgbaImage, dst: &mut RgbaImage, x: u32, y: u32) {
     }
 }
 
-fn bleed_padding(dst: &mut RgbaImage, x: u32, y: u32, tile_size: u32, padding: u32) {
-    if padding == 0 || tile_size == 0 {
+/// Copy `src` and replicate its edge pixels `extrude` pixels outward so
+/// lower mip levels don't blend in neighbouring tiles or transparent black.
+fn copy_into_extruded(src: &RgbaImage, dst: &mut RgbaImage, x: u32, y: u32, extrude: u32) {
+    copy_into(src, dst, x, y);
+    bleed_padding(dst, x, y, src.width(), src.height(), extrude);
+}
+
+fn bleed_padding(dst: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, padding: u32) {
+    if padding == 0 || width == 0 || height == 0 {
         return;
     }
 
     let x0 = x;
     let y0 = y;
-    let x1 = x + tile_size - 1;
-    let y1 = y + tile_size - 1;
+    let x1 = x + width - 1;
+    let y1 = y + height - 1;
 
-    for yy in 0..tile_size {
+    for yy in 0..height {
         let left = *dst.get_pixel(x0, y0 + yy);
         for dx in 1..=padding {
             dst.put_pixel(x0 - dx, y0 + yy, left);
@@ -232,7 +402,7 @@ fn bleed_padding(dst: &mut RgbaImage, x: u32, y: u32, tile_size: u32, padding: u
         }
     }
 
-    for xx in 0..tile_size {
+    for xx in 0..width {
         let top = *dst.get_pixel(x0 + xx, y0);
         for dy in 1..=padding {
             dst.put_pixel(x0 + xx, y0 - dy, top);
@@ -297,6 +467,15 @@ fn load_textures(args: &Args) -> Result<(Vec<Texture>, u32)> {
             .to_rgba8();
 
         let (w, h) = image.dimensions();
+        if args.allow_rectangular {
+            let name = texture_name(path, &args.input);
+            if textures.contains_key(&name) {
+                bail!("Duplicate texture name detected: {name}");
+            }
+            inferred_size = Some(inferred_size.unwrap_or(0).max(w).max(h));
+            textures.insert(name, image);
+            continue;
+        }
         if w != h {
             bail!("Texture {} is not square ({}x{})", path.display(), w, h);
         }
@@ -388,6 +567,23 @@ mod tests {
         img
     }
 
+    /// Arguments with CLI defaults, capped at a 64px atlas.
+    fn test_args(input: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Args {
+        let output_image = output.into();
+        Args {
+            input: input.into(),
+            output_meta: output_image.with_extension("json"),
+            output_image,
+            tile_size: None,
+            padding: 2,
+            extrude: None,
+            max_atlas_size: 64,
+            columns: None,
+            allow_mixed_sizes: false,
+            allow_rectangular: false,
+        }
+    }
+
     fn temp_dir(label: &str) -> PathBuf {
         let nanos = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -428,14 +624,10 @@ mod tests {
             },
         ];
         let args = Args {
-            input: PathBuf::from("."),
-            output_image: PathBuf::from("atlas.png"),
-            output_meta: PathBuf::from("atlas.json"),
             tile_size: Some(2),
             padding: 1,
-            max_atlas_size: 64,
             columns: Some(2),
-            allow_mixed_sizes: false,
+            ..test_args(".", "atlas.png")
         };
 
         let atlas = build_atlas(&textures, 2, &args).expect("atlas");
@@ -451,6 +643,168 @@ mod tests {
         assert_eq!(*pixel, Rgba([255, 0, 0, 255]));
     }
 
+    #[test]
+    fn rectangular_atlas_packs_mixed_sizes_without_overlap() {
+        let sizes = [(6, 2), (3, 3), (1, 5)];
+        let textures: Vec<Texture> = sizes
+            .iter()
+            .enumerate()
+            .map(|(i, &(w, h))| Texture {
+                name: format!("sprite{i}"),
+                image: RgbaImage::from_pixel(w, h, Rgba([i as u8 * 80, 0, 0, 255])),
+            })
+            .collect();
+        let args = Args {
+            padding: 1,
+            allow_rectangular: true,
+            ..test_args(".", "atlas.png")
+        };
+
+        let atlas = build_rectangular_atlas(&textures, &args).expect("atlas");
+        let meta = &atlas.metadata;
+        assert_eq!(meta.entries.len(), 3);
+        assert_eq!(meta.tile_size, 6);
+
+        for (entry, &(w, h)) in meta.entries.iter().zip(&sizes) {
+            assert_eq!((entry.width, entry.height), (w, h));
+            assert!(entry.x >= 1 && entry.x + w < meta.atlas_width);
+            assert!(entry.y >= 1 && entry.y + h < meta.atlas_height);
+            assert_eq!(entry.u0, entry.x as f32 / meta.atlas_width as f32);
+            assert_eq!(entry.v0, entry.y as f32 / meta.atlas_height as f32);
+            assert_eq!(entry.u1, (entry.x + w) as f32 / meta.atlas_width as f32);
+            assert_eq!(entry.v1, (entry.y + h) as f32 / meta.atlas_height as f32);
+            assert_eq!(
+                atlas.image.get_pixel(entry.x, entry.y),
+                &sprite_color(entry)
+            );
+        }
+
+        // Padded rects must not overlap.
+        let pad = meta.padding;
+        for (i, a) in meta.entries.iter().enumerate() {
+            for b in &meta.entries[i + 1..] {
+                let apart = a.x + a.width + pad <= b.x - pad
+                    || b.x + b.width + pad <= a.x - pad
+                    || a.y + a.height + pad <= b.y - pad
+                    || b.y + b.height + pad <= a.y - pad;
+                assert!(apart, "{} overlaps {}", a.name, b.name);
+            }
+        }
+    }
+
+    #[test]
+    fn packed_metadata_passes_runtime_validation() {
+        let textures: Vec<Texture> = [(4, 4), (2, 3), (4, 1)]
+            .iter()
+            .enumerate()
+            .map(|(i, &(w, h))| Texture {
+                name: format!("sprite{i}"),
+                image: RgbaImage::from_pixel(w, h, Rgba([255, 0, 0, 255])),
+            })
+            .collect();
+        let mut args = Args {
+            allow_rectangular: true,
+            ..test_args(".", "atlas.png")
+        };
+
+        let round_trip = |atlas: &PackedAtlas| {
+            let json = serde_json::to_string(&atlas.metadata).expect("serialize metadata");
+            mdminecraft_assets::TextureAtlasMetadata::parse_str(&json)
+                .expect("packer output should validate")
+        };
+
+        let shelf = round_trip(&build_rectangular_atlas(&textures, &args).expect("atlas"));
+        assert_eq!(shelf.layout, mdminecraft_assets::AtlasLayout::Shelf);
+        assert_eq!(
+            shelf.entry("sprite1").map(|e| (e.width, e.height)),
+            Some((2, 3))
+        );
+
+        let tiles: Vec<Texture> = (0..3)
+            .map(|i| Texture {
+                name: format!("tile{i}"),
+                image: solid_image(4, [0, 255, 0, 255]),
+            })
+            .collect();
+        args.allow_rectangular = false;
+        let grid = round_trip(&build_atlas(&tiles, 4, &args).expect("atlas"));
+        assert_eq!(grid.layout, mdminecraft_assets::AtlasLayout::Grid);
+        assert_eq!(grid.entries.len(), 3);
+    }
+
+    fn sprite_color(entry: &AtlasEntry) -> Rgba<u8> {
+        let index: u8 = entry.name.trim_start_matches("sprite").parse().unwrap();
+        Rgba([index * 80, 0, 0, 255])
+    }
+
+    #[test]
+    fn extruded_gutter_repeats_nearest_edge_pixel() {
+        // 2x2 tile with a distinct colour in each corner.
+        let mut image = RgbaImage::new(2, 2);
+        image.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        image.put_pixel(1, 0, Rgba([0, 255, 0, 255]));
+        image.put_pixel(0, 1, Rgba([0, 0, 255, 255]));
+        image.put_pixel(1, 1, Rgba([255, 255, 0, 255]));
+        let textures = vec![Texture {
+            name: "tile".to_string(),
+            image: image.clone(),
+        }];
+        let args = Args {
+            tile_size: Some(2),
+            padding: 3,
+            extrude: Some(2),
+            columns: Some(1),
+            ..test_args(".", "atlas.png")
+        };
+
+        let atlas = build_atlas(&textures, 2, &args).expect("atlas");
+        assert_eq!(atlas.metadata.atlas_width, 8);
+
+        // Tile occupies 3..5; the two pixels either side are extruded.
+        for y in 1..7 {
+            for x in 1..7 {
+                let nearest = image.get_pixel(x.clamp(3, 4) - 3, y.clamp(3, 4) - 3);
+                assert_eq!(atlas.image.get_pixel(x, y), nearest, "pixel ({x}, {y})");
+            }
+        }
+
+        // Padding beyond the extruded border stays transparent.
+        for i in 0..8 {
+            assert_eq!(atlas.image.get_pixel(0, i), &Rgba([0, 0, 0, 0]));
+            assert_eq!(atlas.image.get_pixel(i, 7), &Rgba([0, 0, 0, 0]));
+        }
+    }
+
+    #[test]
+    fn content_hash_tracks_pixel_changes() {
+        let textures = || {
+            vec![
+                Texture {
+                    name: "a".to_string(),
+                    image: solid_image(2, [255, 0, 0, 255]),
+                },
+                Texture {
+                    name: "b".to_string(),
+                    image: solid_image(2, [0, 255, 0, 255]),
+                },
+            ]
+        };
+        let args = Args {
+            tile_size: Some(2),
+            padding: 1,
+            ..test_args(".", "atlas.png")
+        };
+
+        let first = build_atlas(&textures(), 2, &args).expect("atlas");
+        let second = build_atlas(&textures(), 2, &args).expect("atlas");
+        assert_eq!(first.metadata.content_hash, second.metadata.content_hash);
+
+        let mut changed = textures();
+        changed[1].image.put_pixel(1, 1, Rgba([0, 254, 0, 255]));
+        let third = build_atlas(&changed, 2, &args).expect("atlas");
+        assert_ne!(first.metadata.content_hash, third.metadata.content_hash);
+    }
+
     #[test]
     fn load_textures_reads_pngs() {
         let dir = temp_dir("load");
@@ -459,16 +813,7 @@ mod tests {
             .save(&img_path)
             .expect("save image");
 
-        let args = Args {
-            input: dir.clone(),
-            output_image: PathBuf::from("atlas.png"),
-            output_meta: PathBuf::from("atlas.json"),
-            tile_size: None,
-            padding: 0,
-            max_atlas_size: 64,
-            columns: None,
-            allow_mixed_sizes: false,
-        };
+        let args = test_args(dir.clone(), "atlas.png");
 
         let (textures, tile_size) = load_textures(&args).expect("load");
         assert_eq!(textures.len(), 1);
@@ -485,16 +830,7 @@ mod tests {
         let img = RgbaImage::new(2, 3);
         img.save(&img_path).expect("save image");
 
-        let args = Args {
-            input: dir.clone(),
-            output_image: PathBuf::from("atlas.png"),
-            output_meta: PathBuf::from("atlas.json"),
-            tile_size: None,
-            padding: 0,
-            max_atlas_size: 64,
-            columns: None,
-            allow_mixed_sizes: false,
-        };
+        let args = test_args(dir.clone(), "atlas.png");
 
         let err = load_textures(&args).unwrap_err();
         assert!(err.to_string().contains("not square"));